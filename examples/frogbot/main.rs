@@ -20,7 +20,6 @@ use froglight::{
     bevy::plugins::{InstancePlugin, NetworkPlugin, PhysicsPlugin, TickMeasurementPlugin},
     modules::{
         api::api::Offline,
-        nbt::types::indexed::entry::IndexedValue,
         network::{
             bevy::ClientDespawn,
             connection::FuturesLite,
@@ -35,6 +34,7 @@ use froglight::{
             handshake::{ConnectionIntent, HandshakeContent},
             login::LoginHelloContent,
             registry::RegistryDataEntry,
+            spawn_info::PlayerSpawnInfo,
         },
     },
     prelude::*,
@@ -84,7 +84,10 @@ impl Plugin for BotPlugin {
     fn build(&self, app: &mut App) {
         // Add systems for creating the bot and handling messages.
         app.add_systems(Startup, BotPlugin::create_bot)
-            .add_systems(PreUpdate, NetworkPlugin::clientbound_messages)
+            .add_systems(
+                PreUpdate,
+                (NetworkPlugin::clientbound_messages, NetworkPlugin::track_state).chain(),
+            )
             .add_systems(
                 Update,
                 (BotPlugin::message_handler, BotPlugin::tick_runtime).ambiguous_with_all(),
@@ -94,7 +97,8 @@ impl Plugin for BotPlugin {
                 (
                     InstancePlugin::apply_blockedits,
                     PhysicsPlugin::update_colliders,
-                    PhysicsPlugin::update_collisions.after(PhysicsPlugin::update_colliders),
+                    PhysicsPlugin::update_spatial_index.after(PhysicsPlugin::update_colliders),
+                    PhysicsPlugin::update_collisions.after(PhysicsPlugin::update_spatial_index),
                     PhysicsPlugin::update_prev_components.after(PhysicsPlugin::update_colliders),
                     (NetworkPlugin::serverbound_messages, NetworkPlugin::poll_connections).chain(),
                 )
@@ -140,6 +144,7 @@ impl BotPlugin {
         // Spawn the bot entity and exit the app when it despawns.
         let mut entity = world.spawn((api, profile, connection));
         entity.observe(BotPlugin::exit_on_despawn);
+        entity.observe(BotPlugin::on_dimension_changed);
 
         // Send the handshake and login events.
         let entity = entity.into_readonly();
@@ -154,6 +159,44 @@ impl BotPlugin {
         commands.write_message(AppExit::Success);
     }
 
+    /// An [`Observer`] that logs when the bot changes dimensions.
+    fn on_dimension_changed(trigger: On<DimensionChanged>) {
+        if let Some(previous) = trigger.previous() {
+            info!("Moved from \"{previous}\" to \"{}\"!", trigger.dimension());
+        } else {
+            info!("Joined \"{}\"!", trigger.dimension());
+        }
+    }
+
+    /// Create a [`ChangeDimension`] for the given [`PlayerSpawnInfo`].
+    ///
+    /// Uses the dimension's height from the "minecraft:dimension_type"
+    /// registry.
+    fn change_dimension(spawn_info: &PlayerSpawnInfo) -> ChangeDimension {
+        let registry = Version::registry().read();
+        let (height_max, height_min) = if let Some(height) =
+            registry.get_dimension_height(spawn_info.dimension_type)
+        {
+            info!(
+                "Dimension \"{}\" has a height of {} to {}!",
+                spawn_info.dimension, height.1, height.0
+            );
+            height
+        } else {
+            error!(
+                "Failed to get dimension \"{}\" from registry, using default \"minecraft:overworld\" values!",
+                spawn_info.dimension
+            );
+            (320, -64)
+        };
+
+        ChangeDimension::new(SessionInstance::new::<Version>(
+            spawn_info.dimension.clone(),
+            height_max,
+            height_min,
+        ))
+    }
+
     /// Log the amount of time to took to run a tick.
     fn tick_runtime(diag: Res<DiagnosticsStore>, time: Res<Time>, mut timer: Local<Option<Timer>>) {
         const SECONDS_BETWEEN_LOGS: f32 = 10.0;
@@ -198,7 +241,6 @@ impl BotPlugin {
                 // Handle gameplay events.
                 ClientboundEventEnum::Play(event) => {
                     match event {
-                        // ClientboundPlayEvent::ActionBarText() => todo!(),
                         ClientboundPlayEvent::AddEntity(data) => {
                             if let Some(bundle) =
                                 Version::entities().get_entity_by_id(data.entity_type.into())
@@ -230,7 +272,38 @@ impl BotPlugin {
                         // ClientboundPlayEvent::AwardStats() => todo!(),
                         // ClientboundPlayEvent::BlockChangedAck() => todo!(),
                         // ClientboundPlayEvent::BlockDestruction() => todo!(),
-                        // ClientboundPlayEvent::BlockEntityData() => todo!(),
+                        ClientboundPlayEvent::BlockEntityData(blockpos, kind, nbt) => {
+                            let blockpos = *blockpos;
+                            let kind = *kind;
+                            let nbt = nbt.clone();
+
+                            commands.entity(bot.id()).queue(move |mut entity: EntityWorldMut<'_>| {
+                            let Some(instance) = entity.get::<SessionInstance>() else {
+                                error!("Received BlockEntityData but bot doesn't have a SessionInstance!");
+                                return;
+                            };
+
+                            let Some(kind) = instance.version_blocks().get_block_entity_by_id(kind) else {
+                                error!("Received BlockEntityData with unknown BlockEntity \"{kind}\"!");
+                                return;
+                            };
+                            let Ok(nbt) = Nbt::try_from(&nbt) else {
+                                error!("Received BlockEntityData with invalid NBT!");
+                                return;
+                            };
+
+                            debug!("Received BlockEntityData \"{}\" at {blockpos}", kind.identifier());
+
+                            let Some(mut queue) = entity.get_mut::<BlockEditQueue>() else {
+                                error!(
+                                    "Received BlockEntityData but bot doesn't have a BlockEditQueue!"
+                                );
+                                return;
+                            };
+
+                            queue.push_block_entity(blockpos, BlockEntity::new(kind, nbt.into_compound()));
+                        });
+                        }
                         // ClientboundPlayEvent::BlockEvent() => todo!(),
                         ClientboundPlayEvent::BlockUpdate(blockpos, block_id) => {
                             let blockpos = *blockpos;
@@ -263,7 +336,6 @@ impl BotPlugin {
                             queue.push(blockpos, block);
                         });
                         }
-                        // ClientboundPlayEvent::BossEvent() => todo!(),
                         ClientboundPlayEvent::BundleDelimiter => {}
                         // ClientboundPlayEvent::ChangeDifficulty() => todo!(),
                         // ClientboundPlayEvent::ChatSuggestions() => todo!(),
@@ -287,9 +359,10 @@ impl BotPlugin {
                         // ClientboundPlayEvent::ChunkCacheCenter() => todo!(),
                         // ClientboundPlayEvent::ChunkCacheRadius() => todo!(),
                         ClientboundPlayEvent::ChunkSectionUpdate() => {}
-                        ClientboundPlayEvent::ChunkWithLight(chunkpos, chunk_data, _) => {
+                        ClientboundPlayEvent::ChunkWithLight(chunkpos, chunk_data, light_data) => {
                             let chunkpos = *chunkpos;
                             let chunk_data = chunk_data.clone();
+                            let light_data = light_data.clone();
 
                             commands.entity(bot.id()).queue(move |mut entity: EntityWorldMut<'_>| {
                                 let bot_id = entity.id();
@@ -299,7 +372,7 @@ impl BotPlugin {
                                 };
 
                                 let chunk_id = instance.get_chunk(&chunkpos);
-                                let chunk = match chunk_data.try_parse::<Version>(
+                                let mut chunk = match chunk_data.try_parse::<Version>(
                                     instance.height_max(),
                                     instance.height_min(),
                                 ) {
@@ -309,6 +382,10 @@ impl BotPlugin {
                                         return;
                                     }
                                 };
+                                match light_data.try_parse(chunk.sections().len()) {
+                                    Ok(light) => { chunk.set_light(light); }
+                                    Err(err) => error!("Failed to parse ChunkLight: {err:?}"),
+                                }
 
 
                                 if let Some(chunk_id) = chunk_id {
@@ -347,9 +424,32 @@ impl BotPlugin {
                             });
                         }
                         // ClientboundPlayEvent::ClearDialog => todo!(),
-                        // ClientboundPlayEvent::ClearTitles() => todo!(),
-                        // ClientboundPlayEvent::CommandSuggestions() => todo!(),
-                        // ClientboundPlayEvent::Commands() => todo!(),
+                        ClientboundPlayEvent::CommandSuggestions(content) => {
+                            let content = content.clone();
+                            commands.entity(bot.id()).queue(
+                                move |mut entity: EntityWorldMut<'_>| {
+                                    let Some(mut requests) = entity.get_mut::<SuggestionRequests>()
+                                    else {
+                                        return;
+                                    };
+                                    if let Some((input, suggestions)) = requests.resolve(&content) {
+                                        info!(
+                                            "Received {} suggestions for \"/{input}\"",
+                                            suggestions.entries.len()
+                                        );
+                                    }
+                                },
+                            );
+                        }
+                        ClientboundPlayEvent::Commands(tree) => {
+                            match CommandGraph::new(tree.clone()) {
+                                Ok(graph) => {
+                                    info!("Received {} command nodes", graph.tree().nodes.len());
+                                    commands.entity(bot.id()).insert(graph);
+                                }
+                                Err(err) => error!("Received an invalid command tree: {err}"),
+                            }
+                        }
                         // ClientboundPlayEvent::ContainerClose() => todo!(),
                         // ClientboundPlayEvent::ContainerContent() => todo!(),
                         // ClientboundPlayEvent::ContainerData() => todo!(),
@@ -465,7 +565,6 @@ impl BotPlugin {
                         // ClientboundPlayEvent::GameTestHighlight() => todo!(),
                         // ClientboundPlayEvent::GhostRecipe() => todo!(),
                         // ClientboundPlayEvent::HurtAnimation() => todo!(),
-                        // ClientboundPlayEvent::InitializeBorder() => todo!(),
                         ClientboundPlayEvent::KeepAlive(id) => {
                             info!("Received KeepAlive: {id}");
 
@@ -494,49 +593,14 @@ impl BotPlugin {
                             );
                             debug!("Login Info: {login:#?}");
 
-                            // Get the "minecraft:dimension_type" registry
-                            let registry = Version::registry().read();
-                            let dimensions =
-                                registry.get_nbt_by_identifier("minecraft:dimension_type");
-
-                            // Get the dimension's "min_y" and "logical_height" values.
-                            #[expect(clippy::cast_possible_wrap, reason = "Desired")]
-                            let (height_max, height_min) = if let Some(dimensions) = dimensions
-                                && let Some(dim) =
-                                    dimensions.get_by_identifier(&login.spawn_info.dimension)
-                                && let Some(min_y) =
-                                    dim.get("min_y").and_then(IndexedValue::into_int)
-                                && let Some(logical_height) =
-                                    dim.get("logical_height").and_then(IndexedValue::into_int)
-                            {
-                                info!(
-                                    "Dimension \"{}\" has min_y=\"{}\" and logical_height=\"{logical_height}\"!",
-                                    login.spawn_info.dimension, min_y as i32
-                                );
-
-                                // Convert from logical to relative height.
-                                let height = logical_height
-                                    .checked_add_signed(min_y as i32)
-                                    .expect("Dimension height overflowed?!");
-
-                                (height, min_y as i32)
-                            } else {
-                                error!(
-                                    "Failed to get dimension \"{}\" from registry, using default \"minecraft:overworld\" values!",
-                                    login.spawn_info.dimension
-                                );
-
-                                (320, -64)
-                            };
+                            // Create the bot's instance for the dimension.
+                            commands
+                                .entity(bot.id())
+                                .queue(BotPlugin::change_dimension(&login.spawn_info));
 
                             // Insert the bot's initial components.
                             let profile = bot.get::<PlayerProfile>().unwrap();
                             commands.entity(bot.id()).insert((
-                                SessionInstance::new::<Version>(
-                                    login.spawn_info.dimension.clone(),
-                                    height_max,
-                                    height_min,
-                                ),
                                 PartOfInstance::new(bot.id()),
                                 BlockEditQueue::new(),
                                 TickTimer::default(),
@@ -620,8 +684,28 @@ impl BotPlugin {
                         // ClientboundPlayEvent::PlayerCombatEnd() => todo!(),
                         // ClientboundPlayEvent::PlayerCombatEnter() => todo!(),
                         // ClientboundPlayEvent::PlayerCombatKill() => todo!(),
-                        // ClientboundPlayEvent::PlayerInfoRemove() => todo!(),
-                        // ClientboundPlayEvent::PlayerInfoUpdate() => todo!(),
+                        ClientboundPlayEvent::ActionBarText(_)
+                        | ClientboundPlayEvent::BossEvent(_)
+                        | ClientboundPlayEvent::ClearTitles(_)
+                        | ClientboundPlayEvent::InitializeBorder(_)
+                        | ClientboundPlayEvent::PlayerInfoRemove(_)
+                        | ClientboundPlayEvent::PlayerInfoUpdate(_)
+                        | ClientboundPlayEvent::ResetScore(_)
+                        | ClientboundPlayEvent::SetBorderCenter(..)
+                        | ClientboundPlayEvent::SetBorderLerpSize(..)
+                        | ClientboundPlayEvent::SetBorderSize(_)
+                        | ClientboundPlayEvent::SetBorderWarningDelay(_)
+                        | ClientboundPlayEvent::SetBorderWarningDistance(_)
+                        | ClientboundPlayEvent::SetDisplayObjective(_)
+                        | ClientboundPlayEvent::SetObjective(_)
+                        | ClientboundPlayEvent::SetPlayerTeam(_)
+                        | ClientboundPlayEvent::SetScore(_)
+                        | ClientboundPlayEvent::SetSubtitleText(_)
+                        | ClientboundPlayEvent::SetTitleAnimation(_)
+                        | ClientboundPlayEvent::SetTitleText(_)
+                        | ClientboundPlayEvent::TabList(_) => {
+                            // Handled by `NetworkPlugin::track_state`.
+                        }
                         // ClientboundPlayEvent::PlayerLookAt() => todo!(),
                         ClientboundPlayEvent::PlayerPosition(teleport, data, flags) => {
                             let teleport = *teleport;
@@ -688,23 +772,41 @@ impl BotPlugin {
                             });
                         }
                         // ClientboundPlayEvent::RemoveMobEffect() => todo!(),
-                        // ClientboundPlayEvent::ResetScore() => todo!(),
                         // ClientboundPlayEvent::ResourcePackPop() => todo!(),
                         // ClientboundPlayEvent::ResourcePackPush() => todo!(),
-                        // ClientboundPlayEvent::Respawn() => todo!(),
+                        ClientboundPlayEvent::Respawn(spawn_info, data_kept) => {
+                            info!("Respawning in \"{}\"!", spawn_info.dimension);
+                            debug!("Spawn Info: {spawn_info:#?}");
+
+                            // Only change instances if the dimension changed.
+                            let change = BotPlugin::change_dimension(spawn_info);
+                            let dimension = spawn_info.dimension.clone();
+                            commands.entity(bot.id()).queue(move |entity: EntityWorldMut<'_>| {
+                                if entity
+                                    .get::<SessionInstance>()
+                                    .is_none_or(|instance| instance.dimension() != &dimension)
+                                {
+                                    change.apply(entity);
+                                }
+                            });
+
+                            // Reset the player's entity data unless it was kept.
+                            if !data_kept.entity_data {
+                                commands.entity(bot.id()).insert(EntityBundle::new::<
+                                    entity::Player,
+                                    Version,
+                                >(
+                                ));
+                            }
+                            commands.entity(bot.id()).insert((Velocity::ZERO, Acceleration::ZERO));
+                        }
                         ClientboundPlayEvent::RotateHead() => {}
                         // ClientboundPlayEvent::SelectAdvancementTab() => todo!(),
                         // ClientboundPlayEvent::ServerData() => todo!(),
                         // ClientboundPlayEvent::ServerLinks() => todo!(),
-                        // ClientboundPlayEvent::SetBorderCenter() => todo!(),
-                        // ClientboundPlayEvent::SetBorderLerpSize() => todo!(),
-                        // ClientboundPlayEvent::SetBorderSize() => todo!(),
-                        // ClientboundPlayEvent::SetBorderWarningDelay() => todo!(),
-                        // ClientboundPlayEvent::SetBorderWarningDistance() => todo!(),
                         // ClientboundPlayEvent::SetCamera() => todo!(),
                         // ClientboundPlayEvent::SetCursorItem() => todo!(),
                         // ClientboundPlayEvent::SetDefaultSpawn() => todo!(),
-                        // ClientboundPlayEvent::SetDisplayObjective() => todo!(),
                         ClientboundPlayEvent::SetEntityData(data) => {
                             debug!("Received SetEntityData for EntityId {}", data.entity_id().0);
 
@@ -771,16 +873,10 @@ impl BotPlugin {
                         // ClientboundPlayEvent::SetExperience() => todo!(),
                         // ClientboundPlayEvent::SetHealth() => todo!(),
                         // ClientboundPlayEvent::SetHeldSlot() => todo!(),
-                        // ClientboundPlayEvent::SetObjective() => todo!(),
                         // ClientboundPlayEvent::SetPassengers() => todo!(),
                         // ClientboundPlayEvent::SetPlayerInventory() => todo!(),
-                        // ClientboundPlayEvent::SetPlayerTeam() => todo!(),
-                        // ClientboundPlayEvent::SetScore() => todo!(),
                         // ClientboundPlayEvent::SetSimulationDistance() => todo!(),
-                        // ClientboundPlayEvent::SetSubtitleText() => todo!(),
                         ClientboundPlayEvent::SetTime() => {}
-                        // ClientboundPlayEvent::SetTitleAnimation() => todo!(),
-                        // ClientboundPlayEvent::SetTitleText() => todo!(),
                         // ClientboundPlayEvent::ShowDialog() => todo!(),
                         // ClientboundPlayEvent::Sound() => todo!(),
                         // ClientboundPlayEvent::SoundEntity() => todo!(),
@@ -793,7 +889,6 @@ impl BotPlugin {
                         // ClientboundPlayEvent::StopSound() => todo!(),
                         // ClientboundPlayEvent::StoreCookie() => todo!(),
                         // ClientboundPlayEvent::SystemChat() => todo!(),
-                        // ClientboundPlayEvent::TabList() => todo!(),
                         // ClientboundPlayEvent::TagQuery() => todo!(),
                         // ClientboundPlayEvent::TakeItemEntity() => todo!(),
                         ClientboundPlayEvent::TeleportEntity(id, data, flags, on_ground) => {
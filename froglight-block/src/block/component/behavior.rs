@@ -2,7 +2,7 @@
 #![expect(missing_docs, reason = "The functions themselves have documentation")]
 
 use crate::{
-    block::{BlockShape, BlockType},
+    block::{BlockShape, BlockType, MineableTools, ToolTier},
     state::RelativeStateId,
     version::BlockVersion,
 };
@@ -20,6 +20,10 @@ pub struct BlockBehavior {
     pub has_occlusion: StateFn<bool>,
     pub light_emission: StateFn<u8>,
     pub shape_of: StateFn<&'static BlockShape<'static>>,
+    pub hardness: StateFn<Option<f32>>,
+    pub requires_tool: StateFn<bool>,
+    pub mineable: StateFn<MineableTools>,
+    pub tool_tier: StateFn<ToolTier>,
 }
 
 impl BlockBehavior {
@@ -35,6 +39,10 @@ impl BlockBehavior {
             B::has_occlusion,
             B::light_emission,
             B::shape_of,
+            B::hardness,
+            B::requires_tool,
+            B::mineable,
+            B::tool_tier,
         )
     }
 
@@ -49,6 +57,10 @@ impl BlockBehavior {
         has_occlusion: StateFn<bool>,
         light_emission: StateFn<u8>,
         shape_of: StateFn<&'static BlockShape<'static>>,
+        hardness: StateFn<Option<f32>>,
+        requires_tool: StateFn<bool>,
+        mineable: StateFn<MineableTools>,
+        tool_tier: StateFn<ToolTier>,
     ) -> Self {
         Self {
             is_air,
@@ -59,6 +71,10 @@ impl BlockBehavior {
             has_occlusion,
             light_emission,
             shape_of,
+            hardness,
+            requires_tool,
+            mineable,
+            tool_tier,
         }
     }
 
@@ -103,4 +119,25 @@ impl BlockBehavior {
     pub fn shape_of(&self, state: RelativeStateId) -> &'static BlockShape<'static> {
         (self.shape_of)(state)
     }
+
+    /// Returns the hardness of the block, `-1.0` if it cannot be broken,
+    /// or `None` if it is not known.
+    #[inline]
+    #[must_use]
+    pub fn hardness(&self, state: RelativeStateId) -> Option<f32> { (self.hardness)(state) }
+
+    /// Returns `true` if the block needs the correct tool to drop items.
+    #[inline]
+    #[must_use]
+    pub fn requires_tool(&self, state: RelativeStateId) -> bool { (self.requires_tool)(state) }
+
+    /// Returns the tools that mine the block efficiently.
+    #[inline]
+    #[must_use]
+    pub fn mineable(&self, state: RelativeStateId) -> MineableTools { (self.mineable)(state) }
+
+    /// Returns the lowest tool tier that can harvest the block.
+    #[inline]
+    #[must_use]
+    pub fn tool_tier(&self, state: RelativeStateId) -> ToolTier { (self.tool_tier)(state) }
 }
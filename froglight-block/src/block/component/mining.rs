@@ -0,0 +1,64 @@
+use core::ops::BitOr;
+
+/// The tools that mine a block efficiently.
+///
+/// Each tool matches one of the vanilla `mineable/*` block tags,
+/// and [`MineableTools::SWORD`] matches `minecraft:sword_efficient`.
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MineableTools(u8);
+
+impl MineableTools {
+    /// Blocks in `minecraft:mineable/axe`.
+    pub const AXE: Self = Self(1 << 1);
+    /// Blocks in `minecraft:mineable/hoe`.
+    pub const HOE: Self = Self(1 << 3);
+    /// No tool mines the block efficiently.
+    pub const NONE: Self = Self(0);
+    /// Blocks in `minecraft:mineable/pickaxe`.
+    pub const PICKAXE: Self = Self(1 << 0);
+    /// Blocks in `minecraft:mineable/shovel`.
+    pub const SHOVEL: Self = Self(1 << 2);
+    /// Blocks in `minecraft:sword_efficient`.
+    pub const SWORD: Self = Self(1 << 4);
+
+    /// Combine two sets of tools.
+    #[inline]
+    #[must_use]
+    pub const fn with(self, other: Self) -> Self { Self(self.0 | other.0) }
+
+    /// Returns `true` if every tool in `other` is also in this set.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool { self.0 & other.0 == other.0 }
+
+    /// Returns `true` if no tool mines the block efficiently.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool { self.0 == 0 }
+}
+
+impl BitOr for MineableTools {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output { self.with(rhs) }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The lowest tool tier that can harvest a block.
+///
+/// Matches the vanilla `needs_*_tool` block tags.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ToolTier {
+    /// Any tool can harvest the block.
+    #[default]
+    Any,
+    /// Blocks in `minecraft:needs_stone_tool`.
+    Stone,
+    /// Blocks in `minecraft:needs_iron_tool`.
+    Iron,
+    /// Blocks in `minecraft:needs_diamond_tool`.
+    Diamond,
+}
@@ -1,3 +1,4 @@
 pub(super) mod attribute;
 pub(super) mod behavior;
+pub(super) mod mining;
 pub(super) mod shape;
@@ -34,9 +34,9 @@ impl BlockShape<'_> {
     /// coordinates.
     #[must_use]
     pub const fn new(min: DVec3, max: DVec3) -> Self {
-        if (max.x - min.x).abs() > EPSILON_F64
-            && (max.y - min.y).abs() > EPSILON_F64
-            && (max.z - min.z).abs() > EPSILON_F64
+        if (max.x - min.x).abs() < EPSILON_F64
+            || (max.y - min.y).abs() < EPSILON_F64
+            || (max.z - min.z).abs() < EPSILON_F64
         {
             BlockShape::None
         } else {
@@ -62,9 +62,9 @@ impl BlockShape<'_> {
     #[must_use]
     #[cfg(feature = "std")]
     pub const fn new_from_corners(a: DVec3, b: DVec3) -> Self {
-        if (a.x - b.x).abs() > EPSILON_F64
-            && (a.y - b.y).abs() > EPSILON_F64
-            && (a.z - b.z).abs() > EPSILON_F64
+        if (a.x - b.x).abs() < EPSILON_F64
+            || (a.y - b.y).abs() < EPSILON_F64
+            || (a.z - b.z).abs() < EPSILON_F64
         {
             BlockShape::None
         } else {
@@ -79,9 +79,9 @@ impl BlockShape<'_> {
     #[must_use]
     #[cfg(all(not(feature = "std"), feature = "libm"))]
     pub fn new_from_corners(a: DVec3, b: DVec3) -> Self {
-        if (a.x - b.x).abs() > EPSILON_F64
-            && (a.y - b.y).abs() > EPSILON_F64
-            && (a.z - b.z).abs() > EPSILON_F64
+        if (a.x - b.x).abs() < EPSILON_F64
+            || (a.y - b.y).abs() < EPSILON_F64
+            || (a.z - b.z).abs() < EPSILON_F64
         {
             BlockShape::None
         } else {
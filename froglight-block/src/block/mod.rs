@@ -1,16 +1,29 @@
 //! TODO
 
-use core::{any::TypeId, cmp::Ordering, fmt};
+use core::{
+    any::TypeId,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
 
 use froglight_common::identifier::Identifier;
 use froglight_registry_template::implement_wrapper;
 
 mod component;
-pub use component::{attribute::BlockAttributes, behavior::BlockBehavior, shape::BlockShape};
+pub use component::{
+    attribute::BlockAttributes,
+    behavior::BlockBehavior,
+    mining::{MineableTools, ToolTier},
+    shape::BlockShape,
+};
 
 mod metadata;
 pub use metadata::BlockMetadata;
 
+mod parse;
+pub use parse::{BlockParseError, BlockPredicate, BlockPredicateTarget};
+
 use crate::{
     attribute::{BlockAttribute, BlockAttributeBundle},
     state::{GlobalStateId, RelativeStateId},
@@ -238,6 +251,23 @@ implement_wrapper! {
             /// Returns the [`BlockShape`] of this block.
             #[must_use]
             pub fn shape_of(&self) -> &'static BlockShape<'static>;
+
+            /// Returns the hardness of this block, `-1.0` if it cannot be broken,
+            /// or `None` if it is not known.
+            #[must_use]
+            pub fn hardness(&self) -> Option<f32>;
+
+            /// Returns `true` if this block needs the correct tool to drop items.
+            #[must_use]
+            pub fn requires_tool(&self) -> bool;
+
+            /// Returns the tools that mine this block efficiently.
+            #[must_use]
+            pub fn mineable(&self) -> MineableTools;
+
+            /// Returns the lowest tool tier that can harvest this block.
+            #[must_use]
+            pub fn tool_tier(&self) -> ToolTier;
         }
     }
 }
@@ -253,6 +283,14 @@ impl PartialEq for Block {
     }
 }
 
+impl Hash for Block {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.state.hash(state);
+        self.metadata.block_ty().hash(state);
+        self.metadata.version_ty().hash(state);
+    }
+}
+
 impl PartialOrd for Block {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if self.metadata.version_ty() == other.metadata.version_ty() {
@@ -264,9 +302,9 @@ impl PartialOrd for Block {
 }
 
 impl fmt::Display for Block {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <Identifier as fmt::Display>::fmt(&self.identifier(), f)
+        <Identifier as fmt::Display>::fmt(&self.identifier(), f)?;
+        parse::write_properties(f, self.get_attributes())
     }
 }
 
@@ -322,4 +360,21 @@ pub trait BlockType<V: BlockVersion>: 'static {
     /// Returns the shape of the given block state.
     #[must_use]
     fn shape_of(_: RelativeStateId) -> &'static BlockShape<'static> { &BlockShape::FULL }
+
+    /// Returns the block's hardness, `-1.0` if it cannot be broken,
+    /// or `None` if it is not known.
+    #[must_use]
+    fn hardness(_: RelativeStateId) -> Option<f32> { None }
+
+    /// Returns `true` if the block needs the correct tool to drop items.
+    #[must_use]
+    fn requires_tool(_: RelativeStateId) -> bool { false }
+
+    /// Returns the tools that mine the block efficiently.
+    #[must_use]
+    fn mineable(_: RelativeStateId) -> MineableTools { MineableTools::NONE }
+
+    /// Returns the lowest tool tier that can harvest the block.
+    #[must_use]
+    fn tool_tier(_: RelativeStateId) -> ToolTier { ToolTier::Any }
 }
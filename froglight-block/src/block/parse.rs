@@ -0,0 +1,308 @@
+use alloc::{format, string::String, vec::Vec};
+use core::{error::Error, fmt};
+
+use froglight_common::identifier::Identifier;
+#[cfg(feature = "froglight-registry")]
+use froglight_registry::storage::RegistryStorage;
+
+use crate::{block::Block, storage::BlockStorage};
+
+impl Block {
+    /// Parse a [`Block`] from a block state string,
+    /// such as `minecraft:furnace[facing=north,lit=true]`.
+    ///
+    /// Blocks without a namespace use `minecraft`,
+    /// and any attributes not listed keep their default value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not valid block state syntax,
+    /// if the block does not exist in the [`BlockStorage`],
+    /// or if any attribute or value is not valid for the block.
+    pub fn from_str_using<'a>(
+        blocks: &BlockStorage,
+        input: &'a str,
+    ) -> Result<Block, BlockParseError<'a>> {
+        let (name, properties) = split_state(input)?;
+        let identifier = parse_identifier(name)?;
+
+        let mut block = blocks
+            .get_block_by_identifier(&identifier)
+            .ok_or(BlockParseError::UnknownBlock(name))?;
+        for (property, value) in properties {
+            if block.get_attribute_str(property).is_none() {
+                return Err(BlockParseError::UnknownProperty {
+                    block: block.identifier(),
+                    property,
+                });
+            }
+            if block.set_attribute_str(property, value).is_none() {
+                return Err(BlockParseError::InvalidValue {
+                    block: block.identifier(),
+                    property,
+                    value,
+                });
+            }
+        }
+
+        Ok(block)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A predicate that matches blocks,
+/// such as `minecraft:oak_log[axis=y]` or `#minecraft:logs[axis=y]`.
+///
+/// Only the listed attributes are checked,
+/// any other attributes may have any value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockPredicate {
+    target: BlockPredicateTarget,
+    properties: Vec<(String, String)>,
+}
+
+/// The blocks a [`BlockPredicate`] matches.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BlockPredicateTarget {
+    /// A single block.
+    Block(Identifier<'static>),
+    /// Every block in a block tag.
+    Tag(Identifier<'static>),
+}
+
+impl BlockPredicate {
+    /// Parse a [`BlockPredicate`] from a string.
+    ///
+    /// Tags are prefixed with `#`, and
+    /// blocks and tags without a namespace use `minecraft`.
+    ///
+    /// This only checks the syntax of the predicate,
+    /// see [`BlockPredicate::parse_using`] to also check the block and
+    /// its attributes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not valid block predicate syntax.
+    pub fn parse(input: &str) -> Result<Self, BlockParseError<'_>> {
+        let (name, properties) = split_state(input)?;
+
+        let target = match name.strip_prefix('#') {
+            Some(tag) => BlockPredicateTarget::Tag(parse_identifier(tag)?.into_owned()),
+            None => BlockPredicateTarget::Block(parse_identifier(name)?.into_owned()),
+        };
+        let properties =
+            properties.into_iter().map(|(key, value)| (key.into(), value.into())).collect();
+
+        Ok(Self { target, properties })
+    }
+
+    /// Parse a [`BlockPredicate`] from a string,
+    /// checking that the block exists and that its attributes are valid.
+    ///
+    /// Tags cannot be checked without their contents,
+    /// so only their syntax is checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not valid block predicate syntax,
+    /// if the block does not exist in the [`BlockStorage`],
+    /// or if any attribute or value is not valid for the block.
+    pub fn parse_using<'a>(
+        blocks: &BlockStorage,
+        input: &'a str,
+    ) -> Result<Self, BlockParseError<'a>> {
+        if !input.starts_with('#') {
+            Block::from_str_using(blocks, input)?;
+        }
+        Self::parse(input)
+    }
+
+    /// Get the [`BlockPredicateTarget`] of this predicate.
+    #[inline]
+    #[must_use]
+    pub const fn target(&self) -> &BlockPredicateTarget { &self.target }
+
+    /// Get the attributes and values this predicate requires.
+    #[inline]
+    #[must_use]
+    pub fn properties(&self) -> &[(String, String)] { &self.properties }
+
+    /// Returns `true` if the [`Block`] matches this predicate.
+    ///
+    /// `in_tag` is called to check if the block is in a tag.
+    #[must_use]
+    pub fn matches_with(
+        &self,
+        block: &Block,
+        in_tag: impl FnOnce(&Identifier<'_>) -> bool,
+    ) -> bool {
+        let target = match &self.target {
+            BlockPredicateTarget::Block(identifier) => *identifier == block.identifier(),
+            BlockPredicateTarget::Tag(tag) => in_tag(tag),
+        };
+
+        target
+            && self.properties.iter().all(|(property, value)| {
+                block.get_attribute_str(property).is_some_and(|current| current == value)
+            })
+    }
+
+    /// Returns `true` if the [`Block`] matches this predicate.
+    ///
+    /// Tags are resolved using the `minecraft:block` tags in the
+    /// [`RegistryStorage`].
+    #[must_use]
+    #[cfg(feature = "froglight-registry")]
+    pub fn matches_using(
+        &self,
+        block: &Block,
+        blocks: &BlockStorage,
+        registry: &RegistryStorage,
+    ) -> bool {
+        self.matches_with(block, |tag| {
+            let Some(id) = blocks.get_block_id(&block.identifier()) else { return false };
+            registry
+                .get_registry_by_identifier("minecraft:block")
+                .and_then(|tags| tags.get_by_identifier(tag))
+                .is_some_and(|tag| tag.values().contains(&u32::from(id.into_inner())))
+        })
+    }
+}
+
+impl fmt::Display for BlockPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.target {
+            BlockPredicateTarget::Block(identifier) => write!(f, "{identifier}")?,
+            BlockPredicateTarget::Tag(tag) => write!(f, "#{tag}")?,
+        }
+        write_properties(f, self.properties.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An error that occurs when parsing a block state string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockParseError<'a> {
+    /// The string is not valid block state syntax.
+    InvalidSyntax,
+    /// The block identifier is not valid.
+    InvalidIdentifier(&'a str),
+    /// No block with the given identifier exists.
+    UnknownBlock(&'a str),
+    /// The block has no attribute with the given name.
+    UnknownProperty {
+        /// The block being parsed.
+        block: Identifier<'static>,
+        /// The name of the attribute.
+        property: &'a str,
+    },
+    /// The value is not valid for the attribute.
+    InvalidValue {
+        /// The block being parsed.
+        block: Identifier<'static>,
+        /// The name of the attribute.
+        property: &'a str,
+        /// The invalid value.
+        value: &'a str,
+    },
+    /// The attribute was given more than once.
+    DuplicateProperty(&'a str),
+}
+
+impl fmt::Display for BlockParseError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockParseError::InvalidSyntax => f.write_str("Invalid block state syntax"),
+            BlockParseError::InvalidIdentifier(name) => {
+                write!(f, "Invalid block identifier \"{name}\"")
+            }
+            BlockParseError::UnknownBlock(name) => write!(f, "Unknown block \"{name}\""),
+            BlockParseError::UnknownProperty { block, property } => {
+                write!(f, "Block \"{block}\" has no property \"{property}\"")
+            }
+            BlockParseError::InvalidValue { block, property, value } => {
+                write!(
+                    f,
+                    "Invalid value \"{value}\" for property \"{property}\" of block \"{block}\""
+                )
+            }
+            BlockParseError::DuplicateProperty(property) => {
+                write!(f, "Property \"{property}\" was given more than once")
+            }
+        }
+    }
+}
+
+impl Error for BlockParseError<'_> {}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Write attributes as `[name=value,...]`, or nothing if there are none.
+pub(super) fn write_properties<'a>(
+    f: &mut fmt::Formatter<'_>,
+    properties: impl Iterator<Item = (&'a str, &'a str)>,
+) -> fmt::Result {
+    let mut properties = properties.peekable();
+    if properties.peek().is_none() {
+        return Ok(());
+    }
+
+    f.write_str("[")?;
+    for (index, (name, value)) in properties.enumerate() {
+        if index != 0 {
+            f.write_str(",")?;
+        }
+        write!(f, "{name}={value}")?;
+    }
+    f.write_str("]")
+}
+
+/// Split a block state string into its name and attributes.
+#[expect(clippy::type_complexity, reason = "Borrowed key-value pairs")]
+fn split_state(input: &str) -> Result<(&str, Vec<(&str, &str)>), BlockParseError<'_>> {
+    let input = input.trim();
+    let (name, properties) = match input.split_once('[') {
+        Some((name, rest)) => {
+            let rest = rest.strip_suffix(']').ok_or(BlockParseError::InvalidSyntax)?;
+            (name.trim_end(), Some(rest))
+        }
+        None => (input, None),
+    };
+
+    let mut pairs = Vec::<(&str, &str)>::new();
+    if let Some(properties) = properties.filter(|p| !p.trim().is_empty()) {
+        for entry in properties.split(',') {
+            let (key, value) = entry.split_once('=').ok_or(BlockParseError::InvalidSyntax)?;
+            let (key, value) = (key.trim(), value.trim());
+
+            if key.is_empty()
+                || value.is_empty()
+                || key.contains([']', '='])
+                || value.contains(['[', ']', '='])
+            {
+                return Err(BlockParseError::InvalidSyntax);
+            }
+            if pairs.iter().any(|(existing, _)| *existing == key) {
+                return Err(BlockParseError::DuplicateProperty(key));
+            }
+            pairs.push((key, value));
+        }
+    }
+
+    if name.is_empty() || name.contains([']', '=', ',']) || name.contains(char::is_whitespace) {
+        return Err(BlockParseError::InvalidSyntax);
+    }
+    Ok((name, pairs))
+}
+
+/// Parse an [`Identifier`], using `minecraft` if there is no namespace.
+fn parse_identifier(name: &str) -> Result<Identifier<'_>, BlockParseError<'_>> {
+    let identifier = if name.contains(':') {
+        Identifier::try_new(name)
+    } else {
+        Identifier::try_new_string(format!("minecraft:{name}"))
+    };
+    identifier.map_err(|_| BlockParseError::InvalidIdentifier(name))
+}
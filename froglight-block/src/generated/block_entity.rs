@@ -0,0 +1,56 @@
+//! Block entity types for all [`Version`](froglight_common::version::Version)s.
+//! 
+//! @generated
+
+generate! {
+    @block_entities
+    Banner => "minecraft:banner",
+    Barrel => "minecraft:barrel",
+    Beacon => "minecraft:beacon",
+    Bed => "minecraft:bed",
+    Beehive => "minecraft:beehive",
+    Bell => "minecraft:bell",
+    BlastFurnace => "minecraft:blast_furnace",
+    BrewingStand => "minecraft:brewing_stand",
+    BrushableBlock => "minecraft:brushable_block",
+    CalibratedSculkSensor => "minecraft:calibrated_sculk_sensor",
+    Campfire => "minecraft:campfire",
+    Chest => "minecraft:chest",
+    ChiseledBookshelf => "minecraft:chiseled_bookshelf",
+    CommandBlock => "minecraft:command_block",
+    Comparator => "minecraft:comparator",
+    Conduit => "minecraft:conduit",
+    CopperGolemStatue => "minecraft:copper_golem_statue",
+    Crafter => "minecraft:crafter",
+    CreakingHeart => "minecraft:creaking_heart",
+    DaylightDetector => "minecraft:daylight_detector",
+    DecoratedPot => "minecraft:decorated_pot",
+    Dispenser => "minecraft:dispenser",
+    Dropper => "minecraft:dropper",
+    EnchantingTable => "minecraft:enchanting_table",
+    EndGateway => "minecraft:end_gateway",
+    EndPortal => "minecraft:end_portal",
+    EnderChest => "minecraft:ender_chest",
+    Furnace => "minecraft:furnace",
+    HangingSign => "minecraft:hanging_sign",
+    Hopper => "minecraft:hopper",
+    Jigsaw => "minecraft:jigsaw",
+    Jukebox => "minecraft:jukebox",
+    Lectern => "minecraft:lectern",
+    MobSpawner => "minecraft:mob_spawner",
+    Piston => "minecraft:piston",
+    SculkCatalyst => "minecraft:sculk_catalyst",
+    SculkSensor => "minecraft:sculk_sensor",
+    SculkShrieker => "minecraft:sculk_shrieker",
+    Shelf => "minecraft:shelf",
+    ShulkerBox => "minecraft:shulker_box",
+    Sign => "minecraft:sign",
+    Skull => "minecraft:skull",
+    Smoker => "minecraft:smoker",
+    StructureBlock => "minecraft:structure_block",
+    TestBlock => "minecraft:test_block",
+    TestInstanceBlock => "minecraft:test_instance_block",
+    TrappedChest => "minecraft:trapped_chest",
+    TrialSpawner => "minecraft:trial_spawner",
+    Vault => "minecraft:vault"
+}
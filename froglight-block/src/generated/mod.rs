@@ -98,6 +98,39 @@ macro_rules! generate {
         }
     };
 
+    (@block_entities $($ident:ident => $string:literal),* $(,)?) => {
+        /// An enum containing all vanilla block entity types.
+        #[non_exhaustive]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum VanillaBlockEntity {
+            $(
+                #[doc = concat!("The `", $string, "` block entity type.")]
+                $ident,
+            )*
+        }
+
+        impl VanillaBlockEntity {
+            /// All vanilla block entity types.
+            pub const ALL: &'static [Self] = &[$(Self::$ident),*];
+
+            /// Get the [`Identifier`](froglight_common::identifier::Identifier) of this block entity type.
+            #[must_use]
+            pub const fn identifier(&self) -> froglight_common::identifier::Identifier<'static> {
+                match self {
+                    $(
+                        VanillaBlockEntity::$ident => froglight_common::identifier::Identifier::new_static($string),
+                    )*
+                }
+            }
+
+            /// Get the block entity type with the given [`Identifier`](froglight_common::identifier::Identifier).
+            #[must_use]
+            pub fn from_identifier(identifier: &froglight_common::identifier::Identifier<'_>) -> Option<Self> {
+                Self::ALL.iter().copied().find(|ty| ty.identifier() == *identifier)
+            }
+        }
+    };
+
     (@version $version:ident, $($ident:ident => {
         ident: $string:literal,
         global: $global:literal,
@@ -107,6 +140,7 @@ macro_rules! generate {
         liquid: $liquid:literal,
         collision: $collision:literal,
         occlusion: $occlusion:literal,
+        $(hardness: $hardness:expr, tool: $tool:literal, mineable: [$($mineable:ident),*], tier: $tier:ident,)?
         ty: [$($name:literal => $ty:ty),*],
         shape: $shape:tt
     }),*) => {
@@ -138,6 +172,18 @@ macro_rules! generate {
 
                 fn has_occlusion(_: $crate::state::RelativeStateId) -> bool { $occlusion }
 
+                $(
+                    fn hardness(_: $crate::state::RelativeStateId) -> Option<f32> { Some($hardness) }
+
+                    fn requires_tool(_: $crate::state::RelativeStateId) -> bool { $tool }
+
+                    fn mineable(_: $crate::state::RelativeStateId) -> $crate::block::MineableTools {
+                        $crate::block::MineableTools::NONE $( .with($crate::block::MineableTools::$mineable) )*
+                    }
+
+                    fn tool_tier(_: $crate::state::RelativeStateId) -> $crate::block::ToolTier { $crate::block::ToolTier::$tier }
+                )?
+
                 #[allow(unused, reason = "Automatically generated")]
                 fn shape_of(state: $crate::state::RelativeStateId) -> &'static $crate::block::BlockShape<'static> {
                     $shape
@@ -154,12 +200,22 @@ macro_rules! generate {
                         $(<$ident as $crate::block::BlockType<$version>>::METADATA),*
                     ];
 
-                    unsafe { $crate::storage::BlockStorage::build::<Self>(alloc::vec::Vec::from(SLICE).into_boxed_slice()) }
+                    let storage = unsafe { $crate::storage::BlockStorage::build::<Self>(alloc::vec::Vec::from(SLICE).into_boxed_slice()) };
+                    storage.with_block_entities(<Self as $crate::version::BlockEntityVersion>::BLOCK_ENTITIES)
                 }
             }
         }
     };
 
+    (@version @block_entities $version:ident, $($ident:ident),* $(,)?) => {
+        #[automatically_derived]
+        impl $crate::version::BlockEntityVersion for $version {
+            const BLOCK_ENTITIES: &'static [$crate::generated::block_entity::VanillaBlockEntity] = &[
+                $($crate::generated::block_entity::VanillaBlockEntity::$ident),*
+            ];
+        }
+    };
+
     (@shape $( $ident:ident => $tt:tt )*) => {
         $(
             #[allow(missing_docs, unused, reason = "Automatically generated")]
@@ -170,6 +226,7 @@ macro_rules! generate {
 
 pub mod attribute;
 pub mod block;
+pub mod block_entity;
 pub mod shape;
 
 // -------------------------------------------------------------------------------------------------
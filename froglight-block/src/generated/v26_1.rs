@@ -8,543 +8,722 @@ use crate::generated::{attribute::*, block::*, shape::*};
 generate! {
     @version V26_1,
     Air => { ident: "minecraft:air", global: 0, default: 0, air: true, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_0 }
     },
     Stone => { ident: "minecraft:stone", global: 1, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Granite => { ident: "minecraft:granite", global: 2, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PolishedGranite => { ident: "minecraft:polished_granite", global: 3, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Diorite => { ident: "minecraft:diorite", global: 4, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PolishedDiorite => { ident: "minecraft:polished_diorite", global: 5, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Andesite => { ident: "minecraft:andesite", global: 6, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PolishedAndesite => { ident: "minecraft:polished_andesite", global: 7, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GrassBlock => { ident: "minecraft:grass_block", global: 8, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [ "snowy" => SnowyBool ], shape: { SHAPE_1 }
     },
     Dirt => { ident: "minecraft:dirt", global: 10, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CoarseDirt => { ident: "minecraft:coarse_dirt", global: 11, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Podzol => { ident: "minecraft:podzol", global: 12, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [ "snowy" => SnowyBool ], shape: { SHAPE_1 }
     },
     Cobblestone => { ident: "minecraft:cobblestone", global: 14, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OakPlanks => { ident: "minecraft:oak_planks", global: 15, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SprucePlanks => { ident: "minecraft:spruce_planks", global: 16, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BirchPlanks => { ident: "minecraft:birch_planks", global: 17, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     JunglePlanks => { ident: "minecraft:jungle_planks", global: 18, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     AcaciaPlanks => { ident: "minecraft:acacia_planks", global: 19, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CherryPlanks => { ident: "minecraft:cherry_planks", global: 20, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DarkOakPlanks => { ident: "minecraft:dark_oak_planks", global: 21, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PaleOakWood => { ident: "minecraft:pale_oak_wood", global: 22, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     PaleOakPlanks => { ident: "minecraft:pale_oak_planks", global: 25, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MangrovePlanks => { ident: "minecraft:mangrove_planks", global: 26, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BambooPlanks => { ident: "minecraft:bamboo_planks", global: 27, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BambooMosaic => { ident: "minecraft:bamboo_mosaic", global: 28, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OakSapling => { ident: "minecraft:oak_sapling", global: 29, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "stage" => Stage_01 ], shape: { SHAPE_2 }
     },
     SpruceSapling => { ident: "minecraft:spruce_sapling", global: 31, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "stage" => Stage_01 ], shape: { SHAPE_2 }
     },
     BirchSapling => { ident: "minecraft:birch_sapling", global: 33, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "stage" => Stage_01 ], shape: { SHAPE_2 }
     },
     JungleSapling => { ident: "minecraft:jungle_sapling", global: 35, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "stage" => Stage_01 ], shape: { SHAPE_2 }
     },
     AcaciaSapling => { ident: "minecraft:acacia_sapling", global: 37, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "stage" => Stage_01 ], shape: { SHAPE_2 }
     },
     CherrySapling => { ident: "minecraft:cherry_sapling", global: 39, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "stage" => Stage_01 ], shape: { SHAPE_2 }
     },
     DarkOakSapling => { ident: "minecraft:dark_oak_sapling", global: 41, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "stage" => Stage_01 ], shape: { SHAPE_2 }
     },
     PaleOakSapling => { ident: "minecraft:pale_oak_sapling", global: 43, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "stage" => Stage_01 ], shape: { SHAPE_2 }
     },
     MangrovePropagule => { ident: "minecraft:mangrove_propagule", global: 45, default: 5, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_01234, "hanging" => HangingBool, "stage" => Stage_01, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     Bedrock => { ident: "minecraft:bedrock", global: 85, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Water => { ident: "minecraft:water", global: 86, default: 0, air: false, solid: true, liquid: true, collision: false, occlusion: false,
+        hardness: 100.0, tool: false, mineable: [], tier: Any,
         ty: [ "level" => Level_0123456789101112131415 ], shape: { SHAPE_0 }
     },
     Lava => { ident: "minecraft:lava", global: 102, default: 0, air: false, solid: true, liquid: true, collision: false, occlusion: false,
+        hardness: 100.0, tool: false, mineable: [], tier: Any,
         ty: [ "level" => Level_0123456789101112131415 ], shape: { SHAPE_0 }
     },
     Sand => { ident: "minecraft:sand", global: 118, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SuspiciousSand => { ident: "minecraft:suspicious_sand", global: 119, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.25, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [ "dusted" => Dusted_0123 ], shape: { SHAPE_1 }
     },
     RedSand => { ident: "minecraft:red_sand", global: 123, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Gravel => { ident: "minecraft:gravel", global: 124, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SuspiciousGravel => { ident: "minecraft:suspicious_gravel", global: 125, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.25, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [ "dusted" => Dusted_0123 ], shape: { SHAPE_1 }
     },
     GoldOre => { ident: "minecraft:gold_ore", global: 129, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateGoldOre => { ident: "minecraft:deepslate_gold_ore", global: 130, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 4.5, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     IronOre => { ident: "minecraft:iron_ore", global: 131, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateIronOre => { ident: "minecraft:deepslate_iron_ore", global: 132, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 4.5, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     CoalOre => { ident: "minecraft:coal_ore", global: 133, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateCoalOre => { ident: "minecraft:deepslate_coal_ore", global: 134, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 4.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     NetherGoldOre => { ident: "minecraft:nether_gold_ore", global: 135, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OakLog => { ident: "minecraft:oak_log", global: 136, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     SpruceLog => { ident: "minecraft:spruce_log", global: 139, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     BirchLog => { ident: "minecraft:birch_log", global: 142, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     JungleLog => { ident: "minecraft:jungle_log", global: 145, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     AcaciaLog => { ident: "minecraft:acacia_log", global: 148, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     CherryLog => { ident: "minecraft:cherry_log", global: 151, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     DarkOakLog => { ident: "minecraft:dark_oak_log", global: 154, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     PaleOakLog => { ident: "minecraft:pale_oak_log", global: 157, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     MangroveLog => { ident: "minecraft:mangrove_log", global: 160, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     MangroveRoots => { ident: "minecraft:mangrove_roots", global: 163, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.7, tool: false, mineable: [AXE], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     MuddyMangroveRoots => { ident: "minecraft:muddy_mangrove_roots", global: 165, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.7, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     BambooBlock => { ident: "minecraft:bamboo_block", global: 168, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedSpruceLog => { ident: "minecraft:stripped_spruce_log", global: 171, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedBirchLog => { ident: "minecraft:stripped_birch_log", global: 174, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedJungleLog => { ident: "minecraft:stripped_jungle_log", global: 177, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedAcaciaLog => { ident: "minecraft:stripped_acacia_log", global: 180, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedCherryLog => { ident: "minecraft:stripped_cherry_log", global: 183, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedDarkOakLog => { ident: "minecraft:stripped_dark_oak_log", global: 186, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedPaleOakLog => { ident: "minecraft:stripped_pale_oak_log", global: 189, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedOakLog => { ident: "minecraft:stripped_oak_log", global: 192, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedMangroveLog => { ident: "minecraft:stripped_mangrove_log", global: 195, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedBambooBlock => { ident: "minecraft:stripped_bamboo_block", global: 198, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     OakWood => { ident: "minecraft:oak_wood", global: 201, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     SpruceWood => { ident: "minecraft:spruce_wood", global: 204, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     BirchWood => { ident: "minecraft:birch_wood", global: 207, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     JungleWood => { ident: "minecraft:jungle_wood", global: 210, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     AcaciaWood => { ident: "minecraft:acacia_wood", global: 213, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     CherryWood => { ident: "minecraft:cherry_wood", global: 216, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     DarkOakWood => { ident: "minecraft:dark_oak_wood", global: 219, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     MangroveWood => { ident: "minecraft:mangrove_wood", global: 222, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedOakWood => { ident: "minecraft:stripped_oak_wood", global: 225, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedSpruceWood => { ident: "minecraft:stripped_spruce_wood", global: 228, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedBirchWood => { ident: "minecraft:stripped_birch_wood", global: 231, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedJungleWood => { ident: "minecraft:stripped_jungle_wood", global: 234, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedAcaciaWood => { ident: "minecraft:stripped_acacia_wood", global: 237, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedCherryWood => { ident: "minecraft:stripped_cherry_wood", global: 240, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedDarkOakWood => { ident: "minecraft:stripped_dark_oak_wood", global: 243, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedPaleOakWood => { ident: "minecraft:stripped_pale_oak_wood", global: 246, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedMangroveWood => { ident: "minecraft:stripped_mangrove_wood", global: 249, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     OakLeaves => { ident: "minecraft:oak_leaves", global: 252, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     SpruceLeaves => { ident: "minecraft:spruce_leaves", global: 280, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     BirchLeaves => { ident: "minecraft:birch_leaves", global: 308, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     JungleLeaves => { ident: "minecraft:jungle_leaves", global: 336, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     AcaciaLeaves => { ident: "minecraft:acacia_leaves", global: 364, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     CherryLeaves => { ident: "minecraft:cherry_leaves", global: 392, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     DarkOakLeaves => { ident: "minecraft:dark_oak_leaves", global: 420, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     PaleOakLeaves => { ident: "minecraft:pale_oak_leaves", global: 448, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     MangroveLeaves => { ident: "minecraft:mangrove_leaves", global: 476, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     AzaleaLeaves => { ident: "minecraft:azalea_leaves", global: 504, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     FloweringAzaleaLeaves => { ident: "minecraft:flowering_azalea_leaves", global: 532, default: 27, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [ "distance" => Distance_1234567, "persistent" => PersistentBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     Sponge => { ident: "minecraft:sponge", global: 560, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     WetSponge => { ident: "minecraft:wet_sponge", global: 561, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Glass => { ident: "minecraft:glass", global: 562, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LapisOre => { ident: "minecraft:lapis_ore", global: 563, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateLapisOre => { ident: "minecraft:deepslate_lapis_ore", global: 564, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 4.5, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     LapisBlock => { ident: "minecraft:lapis_block", global: 565, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     Dispenser => { ident: "minecraft:dispenser", global: 566, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "triggered" => TriggeredBool ], shape: { SHAPE_1 }
     },
     Sandstone => { ident: "minecraft:sandstone", global: 578, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ChiseledSandstone => { ident: "minecraft:chiseled_sandstone", global: 579, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CutSandstone => { ident: "minecraft:cut_sandstone", global: 580, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     NoteBlock => { ident: "minecraft:note_block", global: 581, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [AXE], tier: Any,
         ty: [ "instrument" => Instrument, "note" => Note, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     WhiteBed => { ident: "minecraft:white_bed", global: 1931, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     OrangeBed => { ident: "minecraft:orange_bed", global: 1947, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     MagentaBed => { ident: "minecraft:magenta_bed", global: 1963, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     LightBlueBed => { ident: "minecraft:light_blue_bed", global: 1979, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     YellowBed => { ident: "minecraft:yellow_bed", global: 1995, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     LimeBed => { ident: "minecraft:lime_bed", global: 2011, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     PinkBed => { ident: "minecraft:pink_bed", global: 2027, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     GrayBed => { ident: "minecraft:gray_bed", global: 2043, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     LightGrayBed => { ident: "minecraft:light_gray_bed", global: 2059, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     CyanBed => { ident: "minecraft:cyan_bed", global: 2075, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     PurpleBed => { ident: "minecraft:purple_bed", global: 2091, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     BlueBed => { ident: "minecraft:blue_bed", global: 2107, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     BrownBed => { ident: "minecraft:brown_bed", global: 2123, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     GreenBed => { ident: "minecraft:green_bed", global: 2139, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     RedBed => { ident: "minecraft:red_bed", global: 2155, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     BlackBed => { ident: "minecraft:black_bed", global: 2171, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "occupied" => OccupiedBool, "part" => Part_HeadFoot ], shape: { SHAPE_3 }
     },
     PoweredRail => { ident: "minecraft:powered_rail", global: 2187, default: 13, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.7, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "powered" => PoweredBool, "shape" => Shape_NorthSouthEastWestAscendingEastAscendingWestAscendingNorthAscendingSouth, "waterlogged" => WaterloggedBool ], shape: { SHAPE_4 }
     },
     DetectorRail => { ident: "minecraft:detector_rail", global: 2211, default: 13, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.7, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "powered" => PoweredBool, "shape" => Shape_NorthSouthEastWestAscendingEastAscendingWestAscendingNorthAscendingSouth, "waterlogged" => WaterloggedBool ], shape: { SHAPE_4 }
     },
     StickyPiston => { ident: "minecraft:sticky_piston", global: 2235, default: 6, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "extended" => ExtendedBool, "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_1 }
     },
     Cobweb => { ident: "minecraft:cobweb", global: 2247, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 4.0, tool: true, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ShortGrass => { ident: "minecraft:short_grass", global: 2248, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_5 }
     },
     Fern => { ident: "minecraft:fern", global: 2249, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_5 }
     },
     DeadBush => { ident: "minecraft:dead_bush", global: 2250, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_5 }
     },
     Bush => { ident: "minecraft:bush", global: 2251, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_6 }
     },
     ShortDryGrass => { ident: "minecraft:short_dry_grass", global: 2252, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_7 }
     },
     TallDryGrass => { ident: "minecraft:tall_dry_grass", global: 2253, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_8 }
     },
     Seagrass => { ident: "minecraft:seagrass", global: 2254, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_2 }
     },
     TallSeagrass => { ident: "minecraft:tall_seagrass", global: 2255, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "half" => Half_UpperLower ], shape: { SHAPE_9 }
     },
     Piston => { ident: "minecraft:piston", global: 2257, default: 6, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "extended" => ExtendedBool, "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_1 }
     },
     PistonHead => { ident: "minecraft:piston_head", global: 2269, default: 2, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_NormalSticky, "facing" => Facing_NorthEastSouthWestUpDown, "short" => ShortBool ], shape: { SHAPE_3 }
     },
     WhiteWool => { ident: "minecraft:white_wool", global: 2293, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OrangeWool => { ident: "minecraft:orange_wool", global: 2294, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MagentaWool => { ident: "minecraft:magenta_wool", global: 2295, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightBlueWool => { ident: "minecraft:light_blue_wool", global: 2296, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     YellowWool => { ident: "minecraft:yellow_wool", global: 2297, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LimeWool => { ident: "minecraft:lime_wool", global: 2298, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PinkWool => { ident: "minecraft:pink_wool", global: 2299, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GrayWool => { ident: "minecraft:gray_wool", global: 2300, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightGrayWool => { ident: "minecraft:light_gray_wool", global: 2301, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CyanWool => { ident: "minecraft:cyan_wool", global: 2302, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PurpleWool => { ident: "minecraft:purple_wool", global: 2303, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlueWool => { ident: "minecraft:blue_wool", global: 2304, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BrownWool => { ident: "minecraft:brown_wool", global: 2305, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GreenWool => { ident: "minecraft:green_wool", global: 2306, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RedWool => { ident: "minecraft:red_wool", global: 2307, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlackWool => { ident: "minecraft:black_wool", global: 2308, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MovingPiston => { ident: "minecraft:moving_piston", global: 2309, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "type" => Type_NormalSticky, "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_0 }
     },
     Dandelion => { ident: "minecraft:dandelion", global: 2321, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     GoldenDandelion => { ident: "minecraft:golden_dandelion", global: 2322, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
         ty: [  ], shape: { SHAPE_10 }
     },
     Torchflower => { ident: "minecraft:torchflower", global: 2323, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     Poppy => { ident: "minecraft:poppy", global: 2324, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     BlueOrchid => { ident: "minecraft:blue_orchid", global: 2325, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     Allium => { ident: "minecraft:allium", global: 2326, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     AzureBluet => { ident: "minecraft:azure_bluet", global: 2327, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     RedTulip => { ident: "minecraft:red_tulip", global: 2328, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     OrangeTulip => { ident: "minecraft:orange_tulip", global: 2329, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     WhiteTulip => { ident: "minecraft:white_tulip", global: 2330, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     PinkTulip => { ident: "minecraft:pink_tulip", global: 2331, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     OxeyeDaisy => { ident: "minecraft:oxeye_daisy", global: 2332, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     Cornflower => { ident: "minecraft:cornflower", global: 2333, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     WitherRose => { ident: "minecraft:wither_rose", global: 2334, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     LilyOfTheValley => { ident: "minecraft:lily_of_the_valley", global: 2335, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     BrownMushroom => { ident: "minecraft:brown_mushroom", global: 2336, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     RedMushroom => { ident: "minecraft:red_mushroom", global: 2337, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     GoldBlock => { ident: "minecraft:gold_block", global: 2338, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     IronBlock => { ident: "minecraft:iron_block", global: 2339, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     Bricks => { ident: "minecraft:bricks", global: 2340, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Tnt => { ident: "minecraft:tnt", global: 2341, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "unstable" => UnstableBool ], shape: { SHAPE_1 }
     },
     Bookshelf => { ident: "minecraft:bookshelf", global: 2343, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ChiseledBookshelf => { ident: "minecraft:chiseled_bookshelf", global: 2344, default: 63, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "slot_0_occupied" => Slot0OccupiedBool, "slot_1_occupied" => Slot1OccupiedBool, "slot_2_occupied" => Slot2OccupiedBool, "slot_3_occupied" => Slot3OccupiedBool, "slot_4_occupied" => Slot4OccupiedBool, "slot_5_occupied" => Slot5OccupiedBool ], shape: { SHAPE_1 }
     },
     AcaciaShelf => { ident: "minecraft:acacia_shelf", global: 2600, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
@@ -584,453 +763,603 @@ generate! {
         ty: [ "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool, "side_chain" => SideChain_UnconnectedRightCenterLeft, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MossyCobblestone => { ident: "minecraft:mossy_cobblestone", global: 3368, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Obsidian => { ident: "minecraft:obsidian", global: 3369, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 50.0, tool: true, mineable: [PICKAXE], tier: Diamond,
         ty: [  ], shape: { SHAPE_1 }
     },
     Torch => { ident: "minecraft:torch", global: 3370, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_12 }
     },
     WallTorch => { ident: "minecraft:wall_torch", global: 3371, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     Fire => { ident: "minecraft:fire", global: 3375, default: 31, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "age" => Age_0123456789101112131415, "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     SoulFire => { ident: "minecraft:soul_fire", global: 3887, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     Spawner => { ident: "minecraft:spawner", global: 3888, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CreakingHeart => { ident: "minecraft:creaking_heart", global: 3889, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 10.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ, "creaking_heart_state" => CreakingHeartState, "natural" => NaturalBool ], shape: { SHAPE_1 }
     },
     OakStairs => { ident: "minecraft:oak_stairs", global: 3907, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     Chest => { ident: "minecraft:chest", global: 3987, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_SingleLeftRight, "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_14 }
     },
     RedstoneWire => { ident: "minecraft:redstone_wire", global: 4011, default: 1160, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "east" => East_UpSideNone, "north" => North_UpSideNone, "power" => Power_0123456789101112131415, "south" => South_UpSideNone, "west" => West_UpSideNone ], shape: { SHAPE_3 }
     },
     DiamondOre => { ident: "minecraft:diamond_ore", global: 5307, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateDiamondOre => { ident: "minecraft:deepslate_diamond_ore", global: 5308, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 4.5, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     DiamondBlock => { ident: "minecraft:diamond_block", global: 5309, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     CraftingTable => { ident: "minecraft:crafting_table", global: 5310, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Wheat => { ident: "minecraft:wheat", global: 5311, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_01234567 ], shape: { SHAPE_3 }
     },
     Farmland => { ident: "minecraft:farmland", global: 5319, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [ "moisture" => Moisture ], shape: { SHAPE_15 }
     },
     Furnace => { ident: "minecraft:furnace", global: 5327, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "lit" => LitBool ], shape: { SHAPE_1 }
     },
     OakSign => { ident: "minecraft:oak_sign", global: 5335, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     SpruceSign => { ident: "minecraft:spruce_sign", global: 5367, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     BirchSign => { ident: "minecraft:birch_sign", global: 5399, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     AcaciaSign => { ident: "minecraft:acacia_sign", global: 5431, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     CherrySign => { ident: "minecraft:cherry_sign", global: 5463, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     JungleSign => { ident: "minecraft:jungle_sign", global: 5495, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     DarkOakSign => { ident: "minecraft:dark_oak_sign", global: 5527, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     PaleOakSign => { ident: "minecraft:pale_oak_sign", global: 5559, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     MangroveSign => { ident: "minecraft:mangrove_sign", global: 5591, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     BambooSign => { ident: "minecraft:bamboo_sign", global: 5623, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     OakDoor => { ident: "minecraft:oak_door", global: 5655, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     Ladder => { ident: "minecraft:ladder", global: 5719, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.4, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     Rail => { ident: "minecraft:rail", global: 5727, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.7, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "shape" => Shape_NorthSouthEastWestAscendingEastAscendingWestAscendingNorthAscendingSouthSouthEastSouthWestNorthWestNorthEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_4 }
     },
     CobblestoneStairs => { ident: "minecraft:cobblestone_stairs", global: 5747, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     OakWallSign => { ident: "minecraft:oak_wall_sign", global: 5827, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SpruceWallSign => { ident: "minecraft:spruce_wall_sign", global: 5835, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BirchWallSign => { ident: "minecraft:birch_wall_sign", global: 5843, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     AcaciaWallSign => { ident: "minecraft:acacia_wall_sign", global: 5851, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CherryWallSign => { ident: "minecraft:cherry_wall_sign", global: 5859, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     JungleWallSign => { ident: "minecraft:jungle_wall_sign", global: 5867, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DarkOakWallSign => { ident: "minecraft:dark_oak_wall_sign", global: 5875, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PaleOakWallSign => { ident: "minecraft:pale_oak_wall_sign", global: 5883, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MangroveWallSign => { ident: "minecraft:mangrove_wall_sign", global: 5891, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BambooWallSign => { ident: "minecraft:bamboo_wall_sign", global: 5899, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     OakHangingSign => { ident: "minecraft:oak_hanging_sign", global: 5907, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     SpruceHangingSign => { ident: "minecraft:spruce_hanging_sign", global: 5971, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     BirchHangingSign => { ident: "minecraft:birch_hanging_sign", global: 6035, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     AcaciaHangingSign => { ident: "minecraft:acacia_hanging_sign", global: 6099, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     CherryHangingSign => { ident: "minecraft:cherry_hanging_sign", global: 6163, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     JungleHangingSign => { ident: "minecraft:jungle_hanging_sign", global: 6227, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     DarkOakHangingSign => { ident: "minecraft:dark_oak_hanging_sign", global: 6291, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     PaleOakHangingSign => { ident: "minecraft:pale_oak_hanging_sign", global: 6355, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     CrimsonHangingSign => { ident: "minecraft:crimson_hanging_sign", global: 6419, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     WarpedHangingSign => { ident: "minecraft:warped_hanging_sign", global: 6483, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     MangroveHangingSign => { ident: "minecraft:mangrove_hanging_sign", global: 6547, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     BambooHangingSign => { ident: "minecraft:bamboo_hanging_sign", global: 6611, default: 49, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "attached" => AttachedBool, "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_17 }
     },
     OakWallHangingSign => { ident: "minecraft:oak_wall_hanging_sign", global: 6675, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SpruceWallHangingSign => { ident: "minecraft:spruce_wall_hanging_sign", global: 6683, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BirchWallHangingSign => { ident: "minecraft:birch_wall_hanging_sign", global: 6691, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     AcaciaWallHangingSign => { ident: "minecraft:acacia_wall_hanging_sign", global: 6699, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CherryWallHangingSign => { ident: "minecraft:cherry_wall_hanging_sign", global: 6707, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     JungleWallHangingSign => { ident: "minecraft:jungle_wall_hanging_sign", global: 6715, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DarkOakWallHangingSign => { ident: "minecraft:dark_oak_wall_hanging_sign", global: 6723, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PaleOakWallHangingSign => { ident: "minecraft:pale_oak_wall_hanging_sign", global: 6731, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MangroveWallHangingSign => { ident: "minecraft:mangrove_wall_hanging_sign", global: 6739, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CrimsonWallHangingSign => { ident: "minecraft:crimson_wall_hanging_sign", global: 6747, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WarpedWallHangingSign => { ident: "minecraft:warped_wall_hanging_sign", global: 6755, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BambooWallHangingSign => { ident: "minecraft:bamboo_wall_hanging_sign", global: 6763, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     Lever => { ident: "minecraft:lever", global: 6771, default: 9, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     StonePressurePlate => { ident: "minecraft:stone_pressure_plate", global: 6795, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     IronDoor => { ident: "minecraft:iron_door", global: 6797, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     OakPressurePlate => { ident: "minecraft:oak_pressure_plate", global: 6861, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     SprucePressurePlate => { ident: "minecraft:spruce_pressure_plate", global: 6863, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     BirchPressurePlate => { ident: "minecraft:birch_pressure_plate", global: 6865, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     JunglePressurePlate => { ident: "minecraft:jungle_pressure_plate", global: 6867, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     AcaciaPressurePlate => { ident: "minecraft:acacia_pressure_plate", global: 6869, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     CherryPressurePlate => { ident: "minecraft:cherry_pressure_plate", global: 6871, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     DarkOakPressurePlate => { ident: "minecraft:dark_oak_pressure_plate", global: 6873, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     PaleOakPressurePlate => { ident: "minecraft:pale_oak_pressure_plate", global: 6875, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     MangrovePressurePlate => { ident: "minecraft:mangrove_pressure_plate", global: 6877, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     BambooPressurePlate => { ident: "minecraft:bamboo_pressure_plate", global: 6879, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     RedstoneOre => { ident: "minecraft:redstone_ore", global: 6881, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [ "lit" => LitBool ], shape: { SHAPE_1 }
     },
     DeepslateRedstoneOre => { ident: "minecraft:deepslate_redstone_ore", global: 6883, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 4.5, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [ "lit" => LitBool ], shape: { SHAPE_1 }
     },
     RedstoneTorch => { ident: "minecraft:redstone_torch", global: 6885, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_12 }
     },
     RedstoneWallTorch => { ident: "minecraft:redstone_wall_torch", global: 6887, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "lit" => LitBool ], shape: { SHAPE_3 }
     },
     StoneButton => { ident: "minecraft:stone_button", global: 6895, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     Snow => { ident: "minecraft:snow", global: 6919, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: true, mineable: [SHOVEL], tier: Any,
         ty: [ "layers" => Layers ], shape: { SHAPE_3 }
     },
     Ice => { ident: "minecraft:ice", global: 6927, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SnowBlock => { ident: "minecraft:snow_block", global: 6928, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: true, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Cactus => { ident: "minecraft:cactus", global: 6929, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.4, tool: false, mineable: [], tier: Any,
         ty: [ "age" => Age_0123456789101112131415 ], shape: { SHAPE_8 }
     },
     CactusFlower => { ident: "minecraft:cactus_flower", global: 6945, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_19 }
     },
     Clay => { ident: "minecraft:clay", global: 6946, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SugarCane => { ident: "minecraft:sugar_cane", global: 6947, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_0123456789101112131415 ], shape: { SHAPE_9 }
     },
     Jukebox => { ident: "minecraft:jukebox", global: 6963, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "has_record" => HasRecordBool ], shape: { SHAPE_1 }
     },
     OakFence => { ident: "minecraft:oak_fence", global: 6965, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     Netherrack => { ident: "minecraft:netherrack", global: 6997, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SoulSand => { ident: "minecraft:soul_sand", global: 6998, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SoulSoil => { ident: "minecraft:soul_soil", global: 6999, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Basalt => { ident: "minecraft:basalt", global: 7000, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     PolishedBasalt => { ident: "minecraft:polished_basalt", global: 7003, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     SoulTorch => { ident: "minecraft:soul_torch", global: 7006, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_12 }
     },
     SoulWallTorch => { ident: "minecraft:soul_wall_torch", global: 7007, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     CopperTorch => { ident: "minecraft:copper_torch", global: 7011, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_12 }
     },
     CopperWallTorch => { ident: "minecraft:copper_wall_torch", global: 7012, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     Glowstone => { ident: "minecraft:glowstone", global: 7016, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     NetherPortal => { ident: "minecraft:nether_portal", global: 7017, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "axis" => Axis_XZ ], shape: { SHAPE_3 }
     },
     CarvedPumpkin => { ident: "minecraft:carved_pumpkin", global: 7019, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     JackOLantern => { ident: "minecraft:jack_o_lantern", global: 7023, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     Cake => { ident: "minecraft:cake", global: 7027, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "bites" => Bites ], shape: { SHAPE_3 }
     },
     Repeater => { ident: "minecraft:repeater", global: 7034, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "delay" => Delay, "facing" => Facing_NorthSouthWestEast, "locked" => LockedBool, "powered" => PoweredBool ], shape: { SHAPE_20 }
     },
     WhiteStainedGlass => { ident: "minecraft:white_stained_glass", global: 7098, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OrangeStainedGlass => { ident: "minecraft:orange_stained_glass", global: 7099, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MagentaStainedGlass => { ident: "minecraft:magenta_stained_glass", global: 7100, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightBlueStainedGlass => { ident: "minecraft:light_blue_stained_glass", global: 7101, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     YellowStainedGlass => { ident: "minecraft:yellow_stained_glass", global: 7102, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LimeStainedGlass => { ident: "minecraft:lime_stained_glass", global: 7103, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PinkStainedGlass => { ident: "minecraft:pink_stained_glass", global: 7104, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GrayStainedGlass => { ident: "minecraft:gray_stained_glass", global: 7105, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightGrayStainedGlass => { ident: "minecraft:light_gray_stained_glass", global: 7106, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CyanStainedGlass => { ident: "minecraft:cyan_stained_glass", global: 7107, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PurpleStainedGlass => { ident: "minecraft:purple_stained_glass", global: 7108, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlueStainedGlass => { ident: "minecraft:blue_stained_glass", global: 7109, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BrownStainedGlass => { ident: "minecraft:brown_stained_glass", global: 7110, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GreenStainedGlass => { ident: "minecraft:green_stained_glass", global: 7111, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RedStainedGlass => { ident: "minecraft:red_stained_glass", global: 7112, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlackStainedGlass => { ident: "minecraft:black_stained_glass", global: 7113, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OakTrapdoor => { ident: "minecraft:oak_trapdoor", global: 7114, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SpruceTrapdoor => { ident: "minecraft:spruce_trapdoor", global: 7178, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BirchTrapdoor => { ident: "minecraft:birch_trapdoor", global: 7242, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     JungleTrapdoor => { ident: "minecraft:jungle_trapdoor", global: 7306, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     AcaciaTrapdoor => { ident: "minecraft:acacia_trapdoor", global: 7370, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CherryTrapdoor => { ident: "minecraft:cherry_trapdoor", global: 7434, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DarkOakTrapdoor => { ident: "minecraft:dark_oak_trapdoor", global: 7498, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PaleOakTrapdoor => { ident: "minecraft:pale_oak_trapdoor", global: 7562, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MangroveTrapdoor => { ident: "minecraft:mangrove_trapdoor", global: 7626, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BambooTrapdoor => { ident: "minecraft:bamboo_trapdoor", global: 7690, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     StoneBricks => { ident: "minecraft:stone_bricks", global: 7754, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MossyStoneBricks => { ident: "minecraft:mossy_stone_bricks", global: 7755, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CrackedStoneBricks => { ident: "minecraft:cracked_stone_bricks", global: 7756, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ChiseledStoneBricks => { ident: "minecraft:chiseled_stone_bricks", global: 7757, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PackedMud => { ident: "minecraft:packed_mud", global: 7758, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MudBricks => { ident: "minecraft:mud_bricks", global: 7759, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     InfestedStone => { ident: "minecraft:infested_stone", global: 7760, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.75, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     InfestedCobblestone => { ident: "minecraft:infested_cobblestone", global: 7761, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     InfestedStoneBricks => { ident: "minecraft:infested_stone_bricks", global: 7762, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.75, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     InfestedMossyStoneBricks => { ident: "minecraft:infested_mossy_stone_bricks", global: 7763, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.75, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     InfestedCrackedStoneBricks => { ident: "minecraft:infested_cracked_stone_bricks", global: 7764, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.75, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     InfestedChiseledStoneBricks => { ident: "minecraft:infested_chiseled_stone_bricks", global: 7765, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.75, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BrownMushroomBlock => { ident: "minecraft:brown_mushroom_block", global: 7766, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [AXE], tier: Any,
         ty: [ "down" => DownBool, "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "west" => WestBool ], shape: { SHAPE_1 }
     },
     RedMushroomBlock => { ident: "minecraft:red_mushroom_block", global: 7830, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [AXE], tier: Any,
         ty: [ "down" => DownBool, "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "west" => WestBool ], shape: { SHAPE_1 }
     },
     MushroomStem => { ident: "minecraft:mushroom_stem", global: 7894, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [AXE], tier: Any,
         ty: [ "down" => DownBool, "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "west" => WestBool ], shape: { SHAPE_1 }
     },
     IronBars => { ident: "minecraft:iron_bars", global: 7958, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     CopperBars => { ident: "minecraft:copper_bars", global: 7990, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
@@ -1058,6 +1387,7 @@ generate! {
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     IronChain => { ident: "minecraft:iron_chain", global: 8246, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "axis" => Axis_XYZ, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CopperChain => { ident: "minecraft:copper_chain", global: 8252, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
@@ -1085,2169 +1415,2889 @@ generate! {
         ty: [ "axis" => Axis_XYZ, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     GlassPane => { ident: "minecraft:glass_pane", global: 8300, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     Pumpkin => { ident: "minecraft:pumpkin", global: 8332, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Melon => { ident: "minecraft:melon", global: 8333, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     AttachedPumpkinStem => { ident: "minecraft:attached_pumpkin_stem", global: 8334, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     AttachedMelonStem => { ident: "minecraft:attached_melon_stem", global: 8338, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     PumpkinStem => { ident: "minecraft:pumpkin_stem", global: 8342, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_01234567 ], shape: { SHAPE_3 }
     },
     MelonStem => { ident: "minecraft:melon_stem", global: 8350, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_01234567 ], shape: { SHAPE_3 }
     },
     Vine => { ident: "minecraft:vine", global: 8358, default: 31, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.2, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     GlowLichen => { ident: "minecraft:glow_lichen", global: 8390, default: 127, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.2, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "down" => DownBool, "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     ResinClump => { ident: "minecraft:resin_clump", global: 8518, default: 127, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "down" => DownBool, "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     OakFenceGate => { ident: "minecraft:oak_fence_gate", global: 8646, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     BrickStairs => { ident: "minecraft:brick_stairs", global: 8678, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     StoneBrickStairs => { ident: "minecraft:stone_brick_stairs", global: 8758, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MudBrickStairs => { ident: "minecraft:mud_brick_stairs", global: 8838, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     Mycelium => { ident: "minecraft:mycelium", global: 8918, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [ "snowy" => SnowyBool ], shape: { SHAPE_1 }
     },
     LilyPad => { ident: "minecraft:lily_pad", global: 8920, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_21 }
     },
     ResinBlock => { ident: "minecraft:resin_block", global: 8921, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ResinBricks => { ident: "minecraft:resin_bricks", global: 8922, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ResinBrickStairs => { ident: "minecraft:resin_brick_stairs", global: 8923, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     ResinBrickSlab => { ident: "minecraft:resin_brick_slab", global: 9003, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     ResinBrickWall => { ident: "minecraft:resin_brick_wall", global: 9009, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     ChiseledResinBricks => { ident: "minecraft:chiseled_resin_bricks", global: 9333, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     NetherBricks => { ident: "minecraft:nether_bricks", global: 9334, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     NetherBrickFence => { ident: "minecraft:nether_brick_fence", global: 9335, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     NetherBrickStairs => { ident: "minecraft:nether_brick_stairs", global: 9367, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     NetherWart => { ident: "minecraft:nether_wart", global: 9447, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_0123 ], shape: { SHAPE_3 }
     },
     EnchantingTable => { ident: "minecraft:enchanting_table", global: 9451, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_23 }
     },
     BrewingStand => { ident: "minecraft:brewing_stand", global: 9452, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "has_bottle_0" => HasBottle0Bool, "has_bottle_1" => HasBottle1Bool, "has_bottle_2" => HasBottle2Bool ], shape: { SHAPE_24 }
     },
     Cauldron => { ident: "minecraft:cauldron", global: 9460, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_3 }
     },
     WaterCauldron => { ident: "minecraft:water_cauldron", global: 9461, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "level" => Level_123 ], shape: { SHAPE_3 }
     },
     LavaCauldron => { ident: "minecraft:lava_cauldron", global: 9464, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_3 }
     },
     PowderSnowCauldron => { ident: "minecraft:powder_snow_cauldron", global: 9465, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "level" => Level_123 ], shape: { SHAPE_3 }
     },
     EndPortal => { ident: "minecraft:end_portal", global: 9468, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_25 }
     },
     EndPortalFrame => { ident: "minecraft:end_portal_frame", global: 9469, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "eye" => EyeBool, "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_26 }
     },
     EndStone => { ident: "minecraft:end_stone", global: 9477, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DragonEgg => { ident: "minecraft:dragon_egg", global: 9478, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_8 }
     },
     RedstoneLamp => { ident: "minecraft:redstone_lamp", global: 9479, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_1 }
     },
     Cocoa => { ident: "minecraft:cocoa", global: 9481, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.2, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "age" => Age_012, "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     SandstoneStairs => { ident: "minecraft:sandstone_stairs", global: 9493, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     EmeraldOre => { ident: "minecraft:emerald_ore", global: 9573, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateEmeraldOre => { ident: "minecraft:deepslate_emerald_ore", global: 9574, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 4.5, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     EnderChest => { ident: "minecraft:ender_chest", global: 9575, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 22.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_14 }
     },
     TripwireHook => { ident: "minecraft:tripwire_hook", global: 9583, default: 9, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "attached" => AttachedBool, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     Tripwire => { ident: "minecraft:tripwire", global: 9599, default: 127, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "attached" => AttachedBool, "disarmed" => DisarmedBool, "east" => EastBool, "north" => NorthBool, "powered" => PoweredBool, "south" => SouthBool, "west" => WestBool ], shape: { SHAPE_27 }
     },
     EmeraldBlock => { ident: "minecraft:emerald_block", global: 9727, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     SpruceStairs => { ident: "minecraft:spruce_stairs", global: 9728, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BirchStairs => { ident: "minecraft:birch_stairs", global: 9808, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     JungleStairs => { ident: "minecraft:jungle_stairs", global: 9888, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CommandBlock => { ident: "minecraft:command_block", global: 9968, default: 6, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "conditional" => ConditionalBool, "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_1 }
     },
     Beacon => { ident: "minecraft:beacon", global: 9980, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CobblestoneWall => { ident: "minecraft:cobblestone_wall", global: 9981, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     MossyCobblestoneWall => { ident: "minecraft:mossy_cobblestone_wall", global: 10305, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     FlowerPot => { ident: "minecraft:flower_pot", global: 10629, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedTorchflower => { ident: "minecraft:potted_torchflower", global: 10630, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedOakSapling => { ident: "minecraft:potted_oak_sapling", global: 10631, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedSpruceSapling => { ident: "minecraft:potted_spruce_sapling", global: 10632, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedBirchSapling => { ident: "minecraft:potted_birch_sapling", global: 10633, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedJungleSapling => { ident: "minecraft:potted_jungle_sapling", global: 10634, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedAcaciaSapling => { ident: "minecraft:potted_acacia_sapling", global: 10635, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedCherrySapling => { ident: "minecraft:potted_cherry_sapling", global: 10636, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedDarkOakSapling => { ident: "minecraft:potted_dark_oak_sapling", global: 10637, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedPaleOakSapling => { ident: "minecraft:potted_pale_oak_sapling", global: 10638, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedMangrovePropagule => { ident: "minecraft:potted_mangrove_propagule", global: 10639, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedFern => { ident: "minecraft:potted_fern", global: 10640, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedDandelion => { ident: "minecraft:potted_dandelion", global: 10641, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedGoldenDandelion => { ident: "minecraft:potted_golden_dandelion", global: 10642, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedPoppy => { ident: "minecraft:potted_poppy", global: 10643, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedBlueOrchid => { ident: "minecraft:potted_blue_orchid", global: 10644, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedAllium => { ident: "minecraft:potted_allium", global: 10645, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedAzureBluet => { ident: "minecraft:potted_azure_bluet", global: 10646, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedRedTulip => { ident: "minecraft:potted_red_tulip", global: 10647, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedOrangeTulip => { ident: "minecraft:potted_orange_tulip", global: 10648, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedWhiteTulip => { ident: "minecraft:potted_white_tulip", global: 10649, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedPinkTulip => { ident: "minecraft:potted_pink_tulip", global: 10650, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedOxeyeDaisy => { ident: "minecraft:potted_oxeye_daisy", global: 10651, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedCornflower => { ident: "minecraft:potted_cornflower", global: 10652, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedLilyOfTheValley => { ident: "minecraft:potted_lily_of_the_valley", global: 10653, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedWitherRose => { ident: "minecraft:potted_wither_rose", global: 10654, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedRedMushroom => { ident: "minecraft:potted_red_mushroom", global: 10655, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedBrownMushroom => { ident: "minecraft:potted_brown_mushroom", global: 10656, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedDeadBush => { ident: "minecraft:potted_dead_bush", global: 10657, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedCactus => { ident: "minecraft:potted_cactus", global: 10658, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     Carrots => { ident: "minecraft:carrots", global: 10659, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_01234567 ], shape: { SHAPE_3 }
     },
     Potatoes => { ident: "minecraft:potatoes", global: 10667, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_01234567 ], shape: { SHAPE_3 }
     },
     OakButton => { ident: "minecraft:oak_button", global: 10675, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     SpruceButton => { ident: "minecraft:spruce_button", global: 10699, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     BirchButton => { ident: "minecraft:birch_button", global: 10723, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     JungleButton => { ident: "minecraft:jungle_button", global: 10747, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     AcaciaButton => { ident: "minecraft:acacia_button", global: 10771, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     CherryButton => { ident: "minecraft:cherry_button", global: 10795, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     DarkOakButton => { ident: "minecraft:dark_oak_button", global: 10819, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     PaleOakButton => { ident: "minecraft:pale_oak_button", global: 10843, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     MangroveButton => { ident: "minecraft:mangrove_button", global: 10867, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     BambooButton => { ident: "minecraft:bamboo_button", global: 10891, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     SkeletonSkull => { ident: "minecraft:skeleton_skull", global: 10915, default: 16, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "powered" => PoweredBool, "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_28 }
     },
     SkeletonWallSkull => { ident: "minecraft:skeleton_wall_skull", global: 10947, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WitherSkeletonSkull => { ident: "minecraft:wither_skeleton_skull", global: 10955, default: 16, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "powered" => PoweredBool, "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_28 }
     },
     WitherSkeletonWallSkull => { ident: "minecraft:wither_skeleton_wall_skull", global: 10987, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     ZombieHead => { ident: "minecraft:zombie_head", global: 10995, default: 16, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "powered" => PoweredBool, "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_28 }
     },
     ZombieWallHead => { ident: "minecraft:zombie_wall_head", global: 11027, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     PlayerHead => { ident: "minecraft:player_head", global: 11035, default: 16, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "powered" => PoweredBool, "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_28 }
     },
     PlayerWallHead => { ident: "minecraft:player_wall_head", global: 11067, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     CreeperHead => { ident: "minecraft:creeper_head", global: 11075, default: 16, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "powered" => PoweredBool, "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_28 }
     },
     CreeperWallHead => { ident: "minecraft:creeper_wall_head", global: 11107, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     DragonHead => { ident: "minecraft:dragon_head", global: 11115, default: 16, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "powered" => PoweredBool, "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_28 }
     },
     DragonWallHead => { ident: "minecraft:dragon_wall_head", global: 11147, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     PiglinHead => { ident: "minecraft:piglin_head", global: 11155, default: 16, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "powered" => PoweredBool, "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_28 }
     },
     PiglinWallHead => { ident: "minecraft:piglin_wall_head", global: 11187, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     Anvil => { ident: "minecraft:anvil", global: 11195, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     ChippedAnvil => { ident: "minecraft:chipped_anvil", global: 11199, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     DamagedAnvil => { ident: "minecraft:damaged_anvil", global: 11203, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     TrappedChest => { ident: "minecraft:trapped_chest", global: 11207, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_SingleLeftRight, "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_14 }
     },
     LightWeightedPressurePlate => { ident: "minecraft:light_weighted_pressure_plate", global: 11231, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "power" => Power_0123456789101112131415 ], shape: { SHAPE_18 }
     },
     HeavyWeightedPressurePlate => { ident: "minecraft:heavy_weighted_pressure_plate", global: 11247, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "power" => Power_0123456789101112131415 ], shape: { SHAPE_18 }
     },
     Comparator => { ident: "minecraft:comparator", global: 11263, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "mode" => Mode_CompareSubtract, "powered" => PoweredBool ], shape: { SHAPE_20 }
     },
     DaylightDetector => { ident: "minecraft:daylight_detector", global: 11279, default: 16, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [AXE], tier: Any,
         ty: [ "inverted" => InvertedBool, "power" => Power_0123456789101112131415 ], shape: { SHAPE_29 }
     },
     RedstoneBlock => { ident: "minecraft:redstone_block", global: 11311, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     NetherQuartzOre => { ident: "minecraft:nether_quartz_ore", global: 11312, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Hopper => { ident: "minecraft:hopper", global: 11313, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "enabled" => EnabledBool, "facing" => Facing_DownNorthSouthWestEast ], shape: { SHAPE_3 }
     },
     QuartzBlock => { ident: "minecraft:quartz_block", global: 11323, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ChiseledQuartzBlock => { ident: "minecraft:chiseled_quartz_block", global: 11324, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     QuartzPillar => { ident: "minecraft:quartz_pillar", global: 11325, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     QuartzStairs => { ident: "minecraft:quartz_stairs", global: 11328, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     ActivatorRail => { ident: "minecraft:activator_rail", global: 11408, default: 13, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.7, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "powered" => PoweredBool, "shape" => Shape_NorthSouthEastWestAscendingEastAscendingWestAscendingNorthAscendingSouth, "waterlogged" => WaterloggedBool ], shape: { SHAPE_4 }
     },
     Dropper => { ident: "minecraft:dropper", global: 11432, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "triggered" => TriggeredBool ], shape: { SHAPE_1 }
     },
     WhiteTerracotta => { ident: "minecraft:white_terracotta", global: 11444, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OrangeTerracotta => { ident: "minecraft:orange_terracotta", global: 11445, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MagentaTerracotta => { ident: "minecraft:magenta_terracotta", global: 11446, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightBlueTerracotta => { ident: "minecraft:light_blue_terracotta", global: 11447, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     YellowTerracotta => { ident: "minecraft:yellow_terracotta", global: 11448, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LimeTerracotta => { ident: "minecraft:lime_terracotta", global: 11449, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PinkTerracotta => { ident: "minecraft:pink_terracotta", global: 11450, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GrayTerracotta => { ident: "minecraft:gray_terracotta", global: 11451, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightGrayTerracotta => { ident: "minecraft:light_gray_terracotta", global: 11452, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CyanTerracotta => { ident: "minecraft:cyan_terracotta", global: 11453, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PurpleTerracotta => { ident: "minecraft:purple_terracotta", global: 11454, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlueTerracotta => { ident: "minecraft:blue_terracotta", global: 11455, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BrownTerracotta => { ident: "minecraft:brown_terracotta", global: 11456, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GreenTerracotta => { ident: "minecraft:green_terracotta", global: 11457, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RedTerracotta => { ident: "minecraft:red_terracotta", global: 11458, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlackTerracotta => { ident: "minecraft:black_terracotta", global: 11459, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     WhiteStainedGlassPane => { ident: "minecraft:white_stained_glass_pane", global: 11460, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     OrangeStainedGlassPane => { ident: "minecraft:orange_stained_glass_pane", global: 11492, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     MagentaStainedGlassPane => { ident: "minecraft:magenta_stained_glass_pane", global: 11524, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     LightBlueStainedGlassPane => { ident: "minecraft:light_blue_stained_glass_pane", global: 11556, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     YellowStainedGlassPane => { ident: "minecraft:yellow_stained_glass_pane", global: 11588, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     LimeStainedGlassPane => { ident: "minecraft:lime_stained_glass_pane", global: 11620, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     PinkStainedGlassPane => { ident: "minecraft:pink_stained_glass_pane", global: 11652, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     GrayStainedGlassPane => { ident: "minecraft:gray_stained_glass_pane", global: 11684, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     LightGrayStainedGlassPane => { ident: "minecraft:light_gray_stained_glass_pane", global: 11716, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     CyanStainedGlassPane => { ident: "minecraft:cyan_stained_glass_pane", global: 11748, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     PurpleStainedGlassPane => { ident: "minecraft:purple_stained_glass_pane", global: 11780, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     BlueStainedGlassPane => { ident: "minecraft:blue_stained_glass_pane", global: 11812, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     BrownStainedGlassPane => { ident: "minecraft:brown_stained_glass_pane", global: 11844, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     GreenStainedGlassPane => { ident: "minecraft:green_stained_glass_pane", global: 11876, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     RedStainedGlassPane => { ident: "minecraft:red_stained_glass_pane", global: 11908, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     BlackStainedGlassPane => { ident: "minecraft:black_stained_glass_pane", global: 11940, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     AcaciaStairs => { ident: "minecraft:acacia_stairs", global: 11972, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CherryStairs => { ident: "minecraft:cherry_stairs", global: 12052, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DarkOakStairs => { ident: "minecraft:dark_oak_stairs", global: 12132, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PaleOakStairs => { ident: "minecraft:pale_oak_stairs", global: 12212, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MangroveStairs => { ident: "minecraft:mangrove_stairs", global: 12292, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BambooStairs => { ident: "minecraft:bamboo_stairs", global: 12372, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BambooMosaicStairs => { ident: "minecraft:bamboo_mosaic_stairs", global: 12452, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SlimeBlock => { ident: "minecraft:slime_block", global: 12532, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Barrier => { ident: "minecraft:barrier", global: 12533, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     Light => { ident: "minecraft:light", global: 12535, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "level" => Level_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     IronTrapdoor => { ident: "minecraft:iron_trapdoor", global: 12567, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     Prismarine => { ident: "minecraft:prismarine", global: 12631, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PrismarineBricks => { ident: "minecraft:prismarine_bricks", global: 12632, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DarkPrismarine => { ident: "minecraft:dark_prismarine", global: 12633, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PrismarineStairs => { ident: "minecraft:prismarine_stairs", global: 12634, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PrismarineBrickStairs => { ident: "minecraft:prismarine_brick_stairs", global: 12714, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DarkPrismarineStairs => { ident: "minecraft:dark_prismarine_stairs", global: 12794, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PrismarineSlab => { ident: "minecraft:prismarine_slab", global: 12874, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PrismarineBrickSlab => { ident: "minecraft:prismarine_brick_slab", global: 12880, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     DarkPrismarineSlab => { ident: "minecraft:dark_prismarine_slab", global: 12886, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     SeaLantern => { ident: "minecraft:sea_lantern", global: 12892, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     HayBlock => { ident: "minecraft:hay_block", global: 12893, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [HOE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     WhiteCarpet => { ident: "minecraft:white_carpet", global: 12896, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     OrangeCarpet => { ident: "minecraft:orange_carpet", global: 12897, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     MagentaCarpet => { ident: "minecraft:magenta_carpet", global: 12898, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     LightBlueCarpet => { ident: "minecraft:light_blue_carpet", global: 12899, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     YellowCarpet => { ident: "minecraft:yellow_carpet", global: 12900, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     LimeCarpet => { ident: "minecraft:lime_carpet", global: 12901, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     PinkCarpet => { ident: "minecraft:pink_carpet", global: 12902, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     GrayCarpet => { ident: "minecraft:gray_carpet", global: 12903, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     LightGrayCarpet => { ident: "minecraft:light_gray_carpet", global: 12904, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     CyanCarpet => { ident: "minecraft:cyan_carpet", global: 12905, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     PurpleCarpet => { ident: "minecraft:purple_carpet", global: 12906, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     BlueCarpet => { ident: "minecraft:blue_carpet", global: 12907, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     BrownCarpet => { ident: "minecraft:brown_carpet", global: 12908, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     GreenCarpet => { ident: "minecraft:green_carpet", global: 12909, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     RedCarpet => { ident: "minecraft:red_carpet", global: 12910, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     BlackCarpet => { ident: "minecraft:black_carpet", global: 12911, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     Terracotta => { ident: "minecraft:terracotta", global: 12912, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CoalBlock => { ident: "minecraft:coal_block", global: 12913, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PackedIce => { ident: "minecraft:packed_ice", global: 12914, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Sunflower => { ident: "minecraft:sunflower", global: 12915, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "half" => Half_UpperLower ], shape: { SHAPE_1 }
     },
     Lilac => { ident: "minecraft:lilac", global: 12917, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "half" => Half_UpperLower ], shape: { SHAPE_1 }
     },
     RoseBush => { ident: "minecraft:rose_bush", global: 12919, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "half" => Half_UpperLower ], shape: { SHAPE_1 }
     },
     Peony => { ident: "minecraft:peony", global: 12921, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "half" => Half_UpperLower ], shape: { SHAPE_1 }
     },
     TallGrass => { ident: "minecraft:tall_grass", global: 12923, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "half" => Half_UpperLower ], shape: { SHAPE_1 }
     },
     LargeFern => { ident: "minecraft:large_fern", global: 12925, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "half" => Half_UpperLower ], shape: { SHAPE_1 }
     },
     WhiteBanner => { ident: "minecraft:white_banner", global: 12927, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     OrangeBanner => { ident: "minecraft:orange_banner", global: 12943, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     MagentaBanner => { ident: "minecraft:magenta_banner", global: 12959, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     LightBlueBanner => { ident: "minecraft:light_blue_banner", global: 12975, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     YellowBanner => { ident: "minecraft:yellow_banner", global: 12991, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     LimeBanner => { ident: "minecraft:lime_banner", global: 13007, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     PinkBanner => { ident: "minecraft:pink_banner", global: 13023, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     GrayBanner => { ident: "minecraft:gray_banner", global: 13039, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     LightGrayBanner => { ident: "minecraft:light_gray_banner", global: 13055, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     CyanBanner => { ident: "minecraft:cyan_banner", global: 13071, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     PurpleBanner => { ident: "minecraft:purple_banner", global: 13087, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     BlueBanner => { ident: "minecraft:blue_banner", global: 13103, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     BrownBanner => { ident: "minecraft:brown_banner", global: 13119, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     GreenBanner => { ident: "minecraft:green_banner", global: 13135, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     RedBanner => { ident: "minecraft:red_banner", global: 13151, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     BlackBanner => { ident: "minecraft:black_banner", global: 13167, default: 8, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415 ], shape: { SHAPE_16 }
     },
     WhiteWallBanner => { ident: "minecraft:white_wall_banner", global: 13183, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     OrangeWallBanner => { ident: "minecraft:orange_wall_banner", global: 13187, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     MagentaWallBanner => { ident: "minecraft:magenta_wall_banner", global: 13191, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     LightBlueWallBanner => { ident: "minecraft:light_blue_wall_banner", global: 13195, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     YellowWallBanner => { ident: "minecraft:yellow_wall_banner", global: 13199, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     LimeWallBanner => { ident: "minecraft:lime_wall_banner", global: 13203, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     PinkWallBanner => { ident: "minecraft:pink_wall_banner", global: 13207, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     GrayWallBanner => { ident: "minecraft:gray_wall_banner", global: 13211, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     LightGrayWallBanner => { ident: "minecraft:light_gray_wall_banner", global: 13215, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     CyanWallBanner => { ident: "minecraft:cyan_wall_banner", global: 13219, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     PurpleWallBanner => { ident: "minecraft:purple_wall_banner", global: 13223, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     BlueWallBanner => { ident: "minecraft:blue_wall_banner", global: 13227, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     BrownWallBanner => { ident: "minecraft:brown_wall_banner", global: 13231, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     GreenWallBanner => { ident: "minecraft:green_wall_banner", global: 13235, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     RedWallBanner => { ident: "minecraft:red_wall_banner", global: 13239, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     BlackWallBanner => { ident: "minecraft:black_wall_banner", global: 13243, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     RedSandstone => { ident: "minecraft:red_sandstone", global: 13247, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ChiseledRedSandstone => { ident: "minecraft:chiseled_red_sandstone", global: 13248, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CutRedSandstone => { ident: "minecraft:cut_red_sandstone", global: 13249, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RedSandstoneStairs => { ident: "minecraft:red_sandstone_stairs", global: 13250, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     OakSlab => { ident: "minecraft:oak_slab", global: 13330, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     SpruceSlab => { ident: "minecraft:spruce_slab", global: 13336, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     BirchSlab => { ident: "minecraft:birch_slab", global: 13342, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     JungleSlab => { ident: "minecraft:jungle_slab", global: 13348, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     AcaciaSlab => { ident: "minecraft:acacia_slab", global: 13354, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     CherrySlab => { ident: "minecraft:cherry_slab", global: 13360, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     DarkOakSlab => { ident: "minecraft:dark_oak_slab", global: 13366, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PaleOakSlab => { ident: "minecraft:pale_oak_slab", global: 13372, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     MangroveSlab => { ident: "minecraft:mangrove_slab", global: 13378, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     BambooSlab => { ident: "minecraft:bamboo_slab", global: 13384, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     BambooMosaicSlab => { ident: "minecraft:bamboo_mosaic_slab", global: 13390, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     StoneSlab => { ident: "minecraft:stone_slab", global: 13396, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     SmoothStoneSlab => { ident: "minecraft:smooth_stone_slab", global: 13402, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     SandstoneSlab => { ident: "minecraft:sandstone_slab", global: 13408, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     CutSandstoneSlab => { ident: "minecraft:cut_sandstone_slab", global: 13414, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PetrifiedOakSlab => { ident: "minecraft:petrified_oak_slab", global: 13420, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     CobblestoneSlab => { ident: "minecraft:cobblestone_slab", global: 13426, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     BrickSlab => { ident: "minecraft:brick_slab", global: 13432, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     StoneBrickSlab => { ident: "minecraft:stone_brick_slab", global: 13438, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     MudBrickSlab => { ident: "minecraft:mud_brick_slab", global: 13444, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     NetherBrickSlab => { ident: "minecraft:nether_brick_slab", global: 13450, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     QuartzSlab => { ident: "minecraft:quartz_slab", global: 13456, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     RedSandstoneSlab => { ident: "minecraft:red_sandstone_slab", global: 13462, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     CutRedSandstoneSlab => { ident: "minecraft:cut_red_sandstone_slab", global: 13468, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PurpurSlab => { ident: "minecraft:purpur_slab", global: 13474, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     SmoothStone => { ident: "minecraft:smooth_stone", global: 13480, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SmoothSandstone => { ident: "minecraft:smooth_sandstone", global: 13481, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SmoothQuartz => { ident: "minecraft:smooth_quartz", global: 13482, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SmoothRedSandstone => { ident: "minecraft:smooth_red_sandstone", global: 13483, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SpruceFenceGate => { ident: "minecraft:spruce_fence_gate", global: 13484, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     BirchFenceGate => { ident: "minecraft:birch_fence_gate", global: 13516, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     JungleFenceGate => { ident: "minecraft:jungle_fence_gate", global: 13548, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     AcaciaFenceGate => { ident: "minecraft:acacia_fence_gate", global: 13580, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     CherryFenceGate => { ident: "minecraft:cherry_fence_gate", global: 13612, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     DarkOakFenceGate => { ident: "minecraft:dark_oak_fence_gate", global: 13644, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     PaleOakFenceGate => { ident: "minecraft:pale_oak_fence_gate", global: 13676, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     MangroveFenceGate => { ident: "minecraft:mangrove_fence_gate", global: 13708, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     BambooFenceGate => { ident: "minecraft:bamboo_fence_gate", global: 13740, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     SpruceFence => { ident: "minecraft:spruce_fence", global: 13772, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     BirchFence => { ident: "minecraft:birch_fence", global: 13804, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     JungleFence => { ident: "minecraft:jungle_fence", global: 13836, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     AcaciaFence => { ident: "minecraft:acacia_fence", global: 13868, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     CherryFence => { ident: "minecraft:cherry_fence", global: 13900, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     DarkOakFence => { ident: "minecraft:dark_oak_fence", global: 13932, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     PaleOakFence => { ident: "minecraft:pale_oak_fence", global: 13964, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     MangroveFence => { ident: "minecraft:mangrove_fence", global: 13996, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     BambooFence => { ident: "minecraft:bamboo_fence", global: 14028, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     SpruceDoor => { ident: "minecraft:spruce_door", global: 14060, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     BirchDoor => { ident: "minecraft:birch_door", global: 14124, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     JungleDoor => { ident: "minecraft:jungle_door", global: 14188, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     AcaciaDoor => { ident: "minecraft:acacia_door", global: 14252, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     CherryDoor => { ident: "minecraft:cherry_door", global: 14316, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     DarkOakDoor => { ident: "minecraft:dark_oak_door", global: 14380, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     PaleOakDoor => { ident: "minecraft:pale_oak_door", global: 14444, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     MangroveDoor => { ident: "minecraft:mangrove_door", global: 14508, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     BambooDoor => { ident: "minecraft:bamboo_door", global: 14572, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     EndRod => { ident: "minecraft:end_rod", global: 14636, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_3 }
     },
     ChorusPlant => { ident: "minecraft:chorus_plant", global: 14642, default: 63, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.4, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "down" => DownBool, "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     ChorusFlower => { ident: "minecraft:chorus_flower", global: 14706, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.4, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "age" => Age_012345 ], shape: { SHAPE_1 }
     },
     PurpurBlock => { ident: "minecraft:purpur_block", global: 14712, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PurpurPillar => { ident: "minecraft:purpur_pillar", global: 14713, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     PurpurStairs => { ident: "minecraft:purpur_stairs", global: 14716, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     EndStoneBricks => { ident: "minecraft:end_stone_bricks", global: 14796, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     TorchflowerCrop => { ident: "minecraft:torchflower_crop", global: 14797, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_01 ], shape: { SHAPE_3 }
     },
     PitcherCrop => { ident: "minecraft:pitcher_crop", global: 14799, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_01234, "half" => Half_UpperLower ], shape: { SHAPE_3 }
     },
     PitcherPlant => { ident: "minecraft:pitcher_plant", global: 14809, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "half" => Half_UpperLower ], shape: { SHAPE_1 }
     },
     Beetroots => { ident: "minecraft:beetroots", global: 14811, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_0123 ], shape: { SHAPE_3 }
     },
     DirtPath => { ident: "minecraft:dirt_path", global: 14815, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.65, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_15 }
     },
     EndGateway => { ident: "minecraft:end_gateway", global: 14816, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RepeatingCommandBlock => { ident: "minecraft:repeating_command_block", global: 14817, default: 6, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "conditional" => ConditionalBool, "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_1 }
     },
     ChainCommandBlock => { ident: "minecraft:chain_command_block", global: 14829, default: 6, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "conditional" => ConditionalBool, "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_1 }
     },
     FrostedIce => { ident: "minecraft:frosted_ice", global: 14841, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "age" => Age_0123 ], shape: { SHAPE_1 }
     },
     MagmaBlock => { ident: "minecraft:magma_block", global: 14845, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     NetherWartBlock => { ident: "minecraft:nether_wart_block", global: 14846, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RedNetherBricks => { ident: "minecraft:red_nether_bricks", global: 14847, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BoneBlock => { ident: "minecraft:bone_block", global: 14848, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StructureVoid => { ident: "minecraft:structure_void", global: 14851, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_30 }
     },
     Observer => { ident: "minecraft:observer", global: 14852, default: 5, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     ShulkerBox => { ident: "minecraft:shulker_box", global: 14864, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     WhiteShulkerBox => { ident: "minecraft:white_shulker_box", global: 14870, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     OrangeShulkerBox => { ident: "minecraft:orange_shulker_box", global: 14876, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     MagentaShulkerBox => { ident: "minecraft:magenta_shulker_box", global: 14882, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     LightBlueShulkerBox => { ident: "minecraft:light_blue_shulker_box", global: 14888, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     YellowShulkerBox => { ident: "minecraft:yellow_shulker_box", global: 14894, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     LimeShulkerBox => { ident: "minecraft:lime_shulker_box", global: 14900, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     PinkShulkerBox => { ident: "minecraft:pink_shulker_box", global: 14906, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     GrayShulkerBox => { ident: "minecraft:gray_shulker_box", global: 14912, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     LightGrayShulkerBox => { ident: "minecraft:light_gray_shulker_box", global: 14918, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     CyanShulkerBox => { ident: "minecraft:cyan_shulker_box", global: 14924, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     PurpleShulkerBox => { ident: "minecraft:purple_shulker_box", global: 14930, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     BlueShulkerBox => { ident: "minecraft:blue_shulker_box", global: 14936, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     BrownShulkerBox => { ident: "minecraft:brown_shulker_box", global: 14942, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     GreenShulkerBox => { ident: "minecraft:green_shulker_box", global: 14948, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     RedShulkerBox => { ident: "minecraft:red_shulker_box", global: 14954, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     BlackShulkerBox => { ident: "minecraft:black_shulker_box", global: 14960, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown ], shape: { SHAPE_31 }
     },
     WhiteGlazedTerracotta => { ident: "minecraft:white_glazed_terracotta", global: 14966, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     OrangeGlazedTerracotta => { ident: "minecraft:orange_glazed_terracotta", global: 14970, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     MagentaGlazedTerracotta => { ident: "minecraft:magenta_glazed_terracotta", global: 14974, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     LightBlueGlazedTerracotta => { ident: "minecraft:light_blue_glazed_terracotta", global: 14978, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     YellowGlazedTerracotta => { ident: "minecraft:yellow_glazed_terracotta", global: 14982, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     LimeGlazedTerracotta => { ident: "minecraft:lime_glazed_terracotta", global: 14986, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     PinkGlazedTerracotta => { ident: "minecraft:pink_glazed_terracotta", global: 14990, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     GrayGlazedTerracotta => { ident: "minecraft:gray_glazed_terracotta", global: 14994, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     LightGrayGlazedTerracotta => { ident: "minecraft:light_gray_glazed_terracotta", global: 14998, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     CyanGlazedTerracotta => { ident: "minecraft:cyan_glazed_terracotta", global: 15002, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     PurpleGlazedTerracotta => { ident: "minecraft:purple_glazed_terracotta", global: 15006, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     BlueGlazedTerracotta => { ident: "minecraft:blue_glazed_terracotta", global: 15010, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     BrownGlazedTerracotta => { ident: "minecraft:brown_glazed_terracotta", global: 15014, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     GreenGlazedTerracotta => { ident: "minecraft:green_glazed_terracotta", global: 15018, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     RedGlazedTerracotta => { ident: "minecraft:red_glazed_terracotta", global: 15022, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     BlackGlazedTerracotta => { ident: "minecraft:black_glazed_terracotta", global: 15026, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     WhiteConcrete => { ident: "minecraft:white_concrete", global: 15030, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OrangeConcrete => { ident: "minecraft:orange_concrete", global: 15031, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MagentaConcrete => { ident: "minecraft:magenta_concrete", global: 15032, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightBlueConcrete => { ident: "minecraft:light_blue_concrete", global: 15033, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     YellowConcrete => { ident: "minecraft:yellow_concrete", global: 15034, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LimeConcrete => { ident: "minecraft:lime_concrete", global: 15035, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PinkConcrete => { ident: "minecraft:pink_concrete", global: 15036, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GrayConcrete => { ident: "minecraft:gray_concrete", global: 15037, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightGrayConcrete => { ident: "minecraft:light_gray_concrete", global: 15038, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CyanConcrete => { ident: "minecraft:cyan_concrete", global: 15039, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PurpleConcrete => { ident: "minecraft:purple_concrete", global: 15040, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlueConcrete => { ident: "minecraft:blue_concrete", global: 15041, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BrownConcrete => { ident: "minecraft:brown_concrete", global: 15042, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GreenConcrete => { ident: "minecraft:green_concrete", global: 15043, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RedConcrete => { ident: "minecraft:red_concrete", global: 15044, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlackConcrete => { ident: "minecraft:black_concrete", global: 15045, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     WhiteConcretePowder => { ident: "minecraft:white_concrete_powder", global: 15046, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     OrangeConcretePowder => { ident: "minecraft:orange_concrete_powder", global: 15047, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     MagentaConcretePowder => { ident: "minecraft:magenta_concrete_powder", global: 15048, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightBlueConcretePowder => { ident: "minecraft:light_blue_concrete_powder", global: 15049, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     YellowConcretePowder => { ident: "minecraft:yellow_concrete_powder", global: 15050, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LimeConcretePowder => { ident: "minecraft:lime_concrete_powder", global: 15051, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PinkConcretePowder => { ident: "minecraft:pink_concrete_powder", global: 15052, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GrayConcretePowder => { ident: "minecraft:gray_concrete_powder", global: 15053, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     LightGrayConcretePowder => { ident: "minecraft:light_gray_concrete_powder", global: 15054, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CyanConcretePowder => { ident: "minecraft:cyan_concrete_powder", global: 15055, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PurpleConcretePowder => { ident: "minecraft:purple_concrete_powder", global: 15056, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlueConcretePowder => { ident: "minecraft:blue_concrete_powder", global: 15057, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BrownConcretePowder => { ident: "minecraft:brown_concrete_powder", global: 15058, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     GreenConcretePowder => { ident: "minecraft:green_concrete_powder", global: 15059, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RedConcretePowder => { ident: "minecraft:red_concrete_powder", global: 15060, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlackConcretePowder => { ident: "minecraft:black_concrete_powder", global: 15061, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Kelp => { ident: "minecraft:kelp", global: 15062, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "age" => Age_012345678910111213141516171819202122232425 ], shape: { SHAPE_3 }
     },
     KelpPlant => { ident: "minecraft:kelp_plant", global: 15088, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_3 }
     },
     DriedKelpBlock => { ident: "minecraft:dried_kelp_block", global: 15089, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     TurtleEgg => { ident: "minecraft:turtle_egg", global: 15090, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "eggs" => Eggs, "hatch" => Hatch_012 ], shape: { SHAPE_32 }
     },
     SnifferEgg => { ident: "minecraft:sniffer_egg", global: 15102, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "hatch" => Hatch_012 ], shape: { SHAPE_33 }
     },
     DriedGhast => { ident: "minecraft:dried_ghast", global: 15105, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: false,
         ty: [ "facing" => Facing_NorthSouthWestEast, "hydration" => Hydration, "waterlogged" => WaterloggedBool ], shape: { SHAPE_34 }
     },
     DeadTubeCoralBlock => { ident: "minecraft:dead_tube_coral_block", global: 15137, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeadBrainCoralBlock => { ident: "minecraft:dead_brain_coral_block", global: 15138, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeadBubbleCoralBlock => { ident: "minecraft:dead_bubble_coral_block", global: 15139, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeadFireCoralBlock => { ident: "minecraft:dead_fire_coral_block", global: 15140, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeadHornCoralBlock => { ident: "minecraft:dead_horn_coral_block", global: 15141, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     TubeCoralBlock => { ident: "minecraft:tube_coral_block", global: 15142, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BrainCoralBlock => { ident: "minecraft:brain_coral_block", global: 15143, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BubbleCoralBlock => { ident: "minecraft:bubble_coral_block", global: 15144, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     FireCoralBlock => { ident: "minecraft:fire_coral_block", global: 15145, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     HornCoralBlock => { ident: "minecraft:horn_coral_block", global: 15146, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeadTubeCoral => { ident: "minecraft:dead_tube_coral", global: 15147, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     DeadBrainCoral => { ident: "minecraft:dead_brain_coral", global: 15149, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     DeadBubbleCoral => { ident: "minecraft:dead_bubble_coral", global: 15151, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     DeadFireCoral => { ident: "minecraft:dead_fire_coral", global: 15153, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     DeadHornCoral => { ident: "minecraft:dead_horn_coral", global: 15155, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     TubeCoral => { ident: "minecraft:tube_coral", global: 15157, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     BrainCoral => { ident: "minecraft:brain_coral", global: 15159, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     BubbleCoral => { ident: "minecraft:bubble_coral", global: 15161, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     FireCoral => { ident: "minecraft:fire_coral", global: 15163, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     HornCoral => { ident: "minecraft:horn_coral", global: 15165, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_35 }
     },
     DeadTubeCoralFan => { ident: "minecraft:dead_tube_coral_fan", global: 15167, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     DeadBrainCoralFan => { ident: "minecraft:dead_brain_coral_fan", global: 15169, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     DeadBubbleCoralFan => { ident: "minecraft:dead_bubble_coral_fan", global: 15171, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     DeadFireCoralFan => { ident: "minecraft:dead_fire_coral_fan", global: 15173, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     DeadHornCoralFan => { ident: "minecraft:dead_horn_coral_fan", global: 15175, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     TubeCoralFan => { ident: "minecraft:tube_coral_fan", global: 15177, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     BrainCoralFan => { ident: "minecraft:brain_coral_fan", global: 15179, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     BubbleCoralFan => { ident: "minecraft:bubble_coral_fan", global: 15181, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     FireCoralFan => { ident: "minecraft:fire_coral_fan", global: 15183, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     HornCoralFan => { ident: "minecraft:horn_coral_fan", global: 15185, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_36 }
     },
     DeadTubeCoralWallFan => { ident: "minecraft:dead_tube_coral_wall_fan", global: 15187, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DeadBrainCoralWallFan => { ident: "minecraft:dead_brain_coral_wall_fan", global: 15195, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DeadBubbleCoralWallFan => { ident: "minecraft:dead_bubble_coral_wall_fan", global: 15203, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DeadFireCoralWallFan => { ident: "minecraft:dead_fire_coral_wall_fan", global: 15211, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DeadHornCoralWallFan => { ident: "minecraft:dead_horn_coral_wall_fan", global: 15219, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     TubeCoralWallFan => { ident: "minecraft:tube_coral_wall_fan", global: 15227, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BrainCoralWallFan => { ident: "minecraft:brain_coral_wall_fan", global: 15235, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BubbleCoralWallFan => { ident: "minecraft:bubble_coral_wall_fan", global: 15243, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     FireCoralWallFan => { ident: "minecraft:fire_coral_wall_fan", global: 15251, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     HornCoralWallFan => { ident: "minecraft:horn_coral_wall_fan", global: 15259, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SeaPickle => { ident: "minecraft:sea_pickle", global: 15267, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "pickles" => Pickles, "waterlogged" => WaterloggedBool ], shape: { SHAPE_37 }
     },
     BlueIce => { ident: "minecraft:blue_ice", global: 15275, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.8, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Conduit => { ident: "minecraft:conduit", global: 15276, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_30 }
     },
     BambooSapling => { ident: "minecraft:bamboo_sapling", global: 15278, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_38 }
     },
     Bamboo => { ident: "minecraft:bamboo", global: 15279, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "age" => Age_01, "leaves" => Leaves, "stage" => Stage_01 ], shape: { SHAPE_17 }
     },
     PottedBamboo => { ident: "minecraft:potted_bamboo", global: 15291, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     VoidAir => { ident: "minecraft:void_air", global: 15292, default: 0, air: true, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_0 }
     },
     CaveAir => { ident: "minecraft:cave_air", global: 15293, default: 0, air: true, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_0 }
     },
     BubbleColumn => { ident: "minecraft:bubble_column", global: 15294, default: 0, air: false, solid: true, liquid: true, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "drag" => DragBool ], shape: { SHAPE_0 }
     },
     PolishedGraniteStairs => { ident: "minecraft:polished_granite_stairs", global: 15296, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SmoothRedSandstoneStairs => { ident: "minecraft:smooth_red_sandstone_stairs", global: 15376, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MossyStoneBrickStairs => { ident: "minecraft:mossy_stone_brick_stairs", global: 15456, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PolishedDioriteStairs => { ident: "minecraft:polished_diorite_stairs", global: 15536, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MossyCobblestoneStairs => { ident: "minecraft:mossy_cobblestone_stairs", global: 15616, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     EndStoneBrickStairs => { ident: "minecraft:end_stone_brick_stairs", global: 15696, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     StoneStairs => { ident: "minecraft:stone_stairs", global: 15776, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SmoothSandstoneStairs => { ident: "minecraft:smooth_sandstone_stairs", global: 15856, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SmoothQuartzStairs => { ident: "minecraft:smooth_quartz_stairs", global: 15936, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     GraniteStairs => { ident: "minecraft:granite_stairs", global: 16016, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     AndesiteStairs => { ident: "minecraft:andesite_stairs", global: 16096, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     RedNetherBrickStairs => { ident: "minecraft:red_nether_brick_stairs", global: 16176, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PolishedAndesiteStairs => { ident: "minecraft:polished_andesite_stairs", global: 16256, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DioriteStairs => { ident: "minecraft:diorite_stairs", global: 16336, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PolishedGraniteSlab => { ident: "minecraft:polished_granite_slab", global: 16416, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     SmoothRedSandstoneSlab => { ident: "minecraft:smooth_red_sandstone_slab", global: 16422, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     MossyStoneBrickSlab => { ident: "minecraft:mossy_stone_brick_slab", global: 16428, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PolishedDioriteSlab => { ident: "minecraft:polished_diorite_slab", global: 16434, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     MossyCobblestoneSlab => { ident: "minecraft:mossy_cobblestone_slab", global: 16440, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     EndStoneBrickSlab => { ident: "minecraft:end_stone_brick_slab", global: 16446, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     SmoothSandstoneSlab => { ident: "minecraft:smooth_sandstone_slab", global: 16452, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     SmoothQuartzSlab => { ident: "minecraft:smooth_quartz_slab", global: 16458, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     GraniteSlab => { ident: "minecraft:granite_slab", global: 16464, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     AndesiteSlab => { ident: "minecraft:andesite_slab", global: 16470, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     RedNetherBrickSlab => { ident: "minecraft:red_nether_brick_slab", global: 16476, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PolishedAndesiteSlab => { ident: "minecraft:polished_andesite_slab", global: 16482, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     DioriteSlab => { ident: "minecraft:diorite_slab", global: 16488, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     BrickWall => { ident: "minecraft:brick_wall", global: 16494, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     PrismarineWall => { ident: "minecraft:prismarine_wall", global: 16818, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     RedSandstoneWall => { ident: "minecraft:red_sandstone_wall", global: 17142, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     MossyStoneBrickWall => { ident: "minecraft:mossy_stone_brick_wall", global: 17466, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     GraniteWall => { ident: "minecraft:granite_wall", global: 17790, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     StoneBrickWall => { ident: "minecraft:stone_brick_wall", global: 18114, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     MudBrickWall => { ident: "minecraft:mud_brick_wall", global: 18438, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     NetherBrickWall => { ident: "minecraft:nether_brick_wall", global: 18762, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     AndesiteWall => { ident: "minecraft:andesite_wall", global: 19086, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     RedNetherBrickWall => { ident: "minecraft:red_nether_brick_wall", global: 19410, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     SandstoneWall => { ident: "minecraft:sandstone_wall", global: 19734, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     EndStoneBrickWall => { ident: "minecraft:end_stone_brick_wall", global: 20058, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     DioriteWall => { ident: "minecraft:diorite_wall", global: 20382, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     Scaffolding => { ident: "minecraft:scaffolding", global: 20706, default: 31, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "bottom" => BottomBool, "distance" => Distance_01234567, "waterlogged" => WaterloggedBool ], shape: { SHAPE_39 }
     },
     Loom => { ident: "minecraft:loom", global: 20738, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_1 }
     },
     Barrel => { ident: "minecraft:barrel", global: 20742, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "open" => OpenBool ], shape: { SHAPE_1 }
     },
     Smoker => { ident: "minecraft:smoker", global: 20754, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "lit" => LitBool ], shape: { SHAPE_1 }
     },
     BlastFurnace => { ident: "minecraft:blast_furnace", global: 20762, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "lit" => LitBool ], shape: { SHAPE_1 }
     },
     CartographyTable => { ident: "minecraft:cartography_table", global: 20770, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     FletchingTable => { ident: "minecraft:fletching_table", global: 20771, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Grindstone => { ident: "minecraft:grindstone", global: 20772, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_3 }
     },
     Lectern => { ident: "minecraft:lectern", global: 20784, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "has_book" => HasBookBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     SmithingTable => { ident: "minecraft:smithing_table", global: 20800, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.5, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Stonecutter => { ident: "minecraft:stonecutter", global: 20801, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast ], shape: { SHAPE_40 }
     },
     Bell => { ident: "minecraft:bell", global: 20805, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "attachment" => Attachment, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     Lantern => { ident: "minecraft:lantern", global: 20837, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     SoulLantern => { ident: "minecraft:soul_lantern", global: 20841, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     CopperLantern => { ident: "minecraft:copper_lantern", global: 20845, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     ExposedCopperLantern => { ident: "minecraft:exposed_copper_lantern", global: 20849, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     WeatheredCopperLantern => { ident: "minecraft:weathered_copper_lantern", global: 20853, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     OxidizedCopperLantern => { ident: "minecraft:oxidized_copper_lantern", global: 20857, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     WaxedCopperLantern => { ident: "minecraft:waxed_copper_lantern", global: 20861, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     WaxedExposedCopperLantern => { ident: "minecraft:waxed_exposed_copper_lantern", global: 20865, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     WaxedWeatheredCopperLantern => { ident: "minecraft:waxed_weathered_copper_lantern", global: 20869, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     WaxedOxidizedCopperLantern => { ident: "minecraft:waxed_oxidized_copper_lantern", global: 20873, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "hanging" => HangingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_41 }
     },
     Campfire => { ident: "minecraft:campfire", global: 20877, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "lit" => LitBool, "signal_fire" => SignalFireBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_42 }
     },
     SoulCampfire => { ident: "minecraft:soul_campfire", global: 20909, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "lit" => LitBool, "signal_fire" => SignalFireBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_42 }
     },
     SweetBerryBush => { ident: "minecraft:sweet_berry_bush", global: 20941, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_0123 ], shape: { SHAPE_28 }
     },
     WarpedStem => { ident: "minecraft:warped_stem", global: 20945, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedWarpedStem => { ident: "minecraft:stripped_warped_stem", global: 20948, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     WarpedHyphae => { ident: "minecraft:warped_hyphae", global: 20951, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedWarpedHyphae => { ident: "minecraft:stripped_warped_hyphae", global: 20954, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     WarpedNylium => { ident: "minecraft:warped_nylium", global: 20957, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     WarpedFungus => { ident: "minecraft:warped_fungus", global: 20958, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_43 }
     },
     WarpedWartBlock => { ident: "minecraft:warped_wart_block", global: 20959, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     WarpedRoots => { ident: "minecraft:warped_roots", global: 20960, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_5 }
     },
     NetherSprouts => { ident: "minecraft:nether_sprouts", global: 20961, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_44 }
     },
     CrimsonStem => { ident: "minecraft:crimson_stem", global: 20962, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedCrimsonStem => { ident: "minecraft:stripped_crimson_stem", global: 20965, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     CrimsonHyphae => { ident: "minecraft:crimson_hyphae", global: 20968, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     StrippedCrimsonHyphae => { ident: "minecraft:stripped_crimson_hyphae", global: 20971, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     CrimsonNylium => { ident: "minecraft:crimson_nylium", global: 20974, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.4, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CrimsonFungus => { ident: "minecraft:crimson_fungus", global: 20975, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_43 }
     },
     Shroomlight => { ident: "minecraft:shroomlight", global: 20976, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.0, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     WeepingVines => { ident: "minecraft:weeping_vines", global: 20977, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_012345678910111213141516171819202122232425 ], shape: { SHAPE_3 }
     },
     WeepingVinesPlant => { ident: "minecraft:weeping_vines_plant", global: 21003, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_3 }
     },
     TwistingVines => { ident: "minecraft:twisting_vines", global: 21004, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_012345678910111213141516171819202122232425 ], shape: { SHAPE_3 }
     },
     TwistingVinesPlant => { ident: "minecraft:twisting_vines_plant", global: 21030, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_3 }
     },
     CrimsonRoots => { ident: "minecraft:crimson_roots", global: 21031, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_5 }
     },
     CrimsonPlanks => { ident: "minecraft:crimson_planks", global: 21032, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     WarpedPlanks => { ident: "minecraft:warped_planks", global: 21033, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CrimsonSlab => { ident: "minecraft:crimson_slab", global: 21034, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     WarpedSlab => { ident: "minecraft:warped_slab", global: 21040, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     CrimsonPressurePlate => { ident: "minecraft:crimson_pressure_plate", global: 21046, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     WarpedPressurePlate => { ident: "minecraft:warped_pressure_plate", global: 21048, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     CrimsonFence => { ident: "minecraft:crimson_fence", global: 21050, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     WarpedFence => { ident: "minecraft:warped_fence", global: 21082, default: 31, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     CrimsonTrapdoor => { ident: "minecraft:crimson_trapdoor", global: 21114, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WarpedTrapdoor => { ident: "minecraft:warped_trapdoor", global: 21178, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CrimsonFenceGate => { ident: "minecraft:crimson_fence_gate", global: 21242, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WarpedFenceGate => { ident: "minecraft:warped_fence_gate", global: 21274, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "in_wall" => InWallBool, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     CrimsonStairs => { ident: "minecraft:crimson_stairs", global: 21306, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WarpedStairs => { ident: "minecraft:warped_stairs", global: 21386, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CrimsonButton => { ident: "minecraft:crimson_button", global: 21466, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WarpedButton => { ident: "minecraft:warped_button", global: 21490, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [AXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     CrimsonDoor => { ident: "minecraft:crimson_door", global: 21514, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WarpedDoor => { ident: "minecraft:warped_door", global: 21578, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     CrimsonSign => { ident: "minecraft:crimson_sign", global: 21642, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     WarpedSign => { ident: "minecraft:warped_sign", global: 21674, default: 17, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "rotation" => Rotation_0123456789101112131415, "waterlogged" => WaterloggedBool ], shape: { SHAPE_16 }
     },
     CrimsonWallSign => { ident: "minecraft:crimson_wall_sign", global: 21706, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WarpedWallSign => { ident: "minecraft:warped_wall_sign", global: 21714, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 1.0, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     StructureBlock => { ident: "minecraft:structure_block", global: 21722, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "mode" => Mode_SaveLoadCornerData ], shape: { SHAPE_1 }
     },
     Jigsaw => { ident: "minecraft:jigsaw", global: 21726, default: 10, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "orientation" => Orientation_DownEastDownNorthDownSouthDownWestUpEastUpNorthUpSouthUpWestWestUpEastUpNorthUpSouthUp ], shape: { SHAPE_1 }
     },
     TestBlock => { ident: "minecraft:test_block", global: 21738, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [ "mode" => Mode_StartLogFailAccept ], shape: { SHAPE_1 }
     },
     TestInstanceBlock => { ident: "minecraft:test_instance_block", global: 21742, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: -1.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Composter => { ident: "minecraft:composter", global: 21743, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [AXE], tier: Any,
         ty: [ "level" => Level_012345678 ], shape: { SHAPE_3 }
     },
     Target => { ident: "minecraft:target", global: 21752, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [HOE], tier: Any,
         ty: [ "power" => Power_0123456789101112131415 ], shape: { SHAPE_1 }
     },
     BeeNest => { ident: "minecraft:bee_nest", global: 21768, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "honey_level" => HoneyLevel_012345 ], shape: { SHAPE_1 }
     },
     Beehive => { ident: "minecraft:beehive", global: 21792, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [AXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "honey_level" => HoneyLevel_012345 ], shape: { SHAPE_1 }
     },
     HoneyBlock => { ident: "minecraft:honey_block", global: 21816, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     HoneycombBlock => { ident: "minecraft:honeycomb_block", global: 21817, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.6, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     NetheriteBlock => { ident: "minecraft:netherite_block", global: 21818, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 50.0, tool: true, mineable: [PICKAXE], tier: Diamond,
         ty: [  ], shape: { SHAPE_1 }
     },
     AncientDebris => { ident: "minecraft:ancient_debris", global: 21819, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 30.0, tool: true, mineable: [PICKAXE], tier: Diamond,
         ty: [  ], shape: { SHAPE_1 }
     },
     CryingObsidian => { ident: "minecraft:crying_obsidian", global: 21820, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 50.0, tool: true, mineable: [PICKAXE], tier: Diamond,
         ty: [  ], shape: { SHAPE_1 }
     },
     RespawnAnchor => { ident: "minecraft:respawn_anchor", global: 21821, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 50.0, tool: true, mineable: [PICKAXE], tier: Diamond,
         ty: [ "charges" => Charges ], shape: { SHAPE_1 }
     },
     PottedCrimsonFungus => { ident: "minecraft:potted_crimson_fungus", global: 21826, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedWarpedFungus => { ident: "minecraft:potted_warped_fungus", global: 21827, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedCrimsonRoots => { ident: "minecraft:potted_crimson_roots", global: 21828, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedWarpedRoots => { ident: "minecraft:potted_warped_roots", global: 21829, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     Lodestone => { ident: "minecraft:lodestone", global: 21830, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Blackstone => { ident: "minecraft:blackstone", global: 21831, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BlackstoneStairs => { ident: "minecraft:blackstone_stairs", global: 21832, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BlackstoneWall => { ident: "minecraft:blackstone_wall", global: 21912, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     BlackstoneSlab => { ident: "minecraft:blackstone_slab", global: 22236, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PolishedBlackstone => { ident: "minecraft:polished_blackstone", global: 22242, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PolishedBlackstoneBricks => { ident: "minecraft:polished_blackstone_bricks", global: 22243, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CrackedPolishedBlackstoneBricks => { ident: "minecraft:cracked_polished_blackstone_bricks", global: 22244, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     ChiseledPolishedBlackstone => { ident: "minecraft:chiseled_polished_blackstone", global: 22245, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PolishedBlackstoneBrickSlab => { ident: "minecraft:polished_blackstone_brick_slab", global: 22246, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PolishedBlackstoneBrickStairs => { ident: "minecraft:polished_blackstone_brick_stairs", global: 22252, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PolishedBlackstoneBrickWall => { ident: "minecraft:polished_blackstone_brick_wall", global: 22332, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     GildedBlackstone => { ident: "minecraft:gilded_blackstone", global: 22656, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PolishedBlackstoneStairs => { ident: "minecraft:polished_blackstone_stairs", global: 22657, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PolishedBlackstoneSlab => { ident: "minecraft:polished_blackstone_slab", global: 22737, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PolishedBlackstonePressurePlate => { ident: "minecraft:polished_blackstone_pressure_plate", global: 22743, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "powered" => PoweredBool ], shape: { SHAPE_18 }
     },
     PolishedBlackstoneButton => { ident: "minecraft:polished_blackstone_button", global: 22745, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "face" => Face_FloorWallCeiling, "facing" => Facing_NorthSouthWestEast, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     PolishedBlackstoneWall => { ident: "minecraft:polished_blackstone_wall", global: 22769, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     ChiseledNetherBricks => { ident: "minecraft:chiseled_nether_bricks", global: 23093, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CrackedNetherBricks => { ident: "minecraft:cracked_nether_bricks", global: 23094, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 2.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     QuartzBricks => { ident: "minecraft:quartz_bricks", global: 23095, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.8, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Candle => { ident: "minecraft:candle", global: 23096, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WhiteCandle => { ident: "minecraft:white_candle", global: 23112, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     OrangeCandle => { ident: "minecraft:orange_candle", global: 23128, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MagentaCandle => { ident: "minecraft:magenta_candle", global: 23144, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     LightBlueCandle => { ident: "minecraft:light_blue_candle", global: 23160, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     YellowCandle => { ident: "minecraft:yellow_candle", global: 23176, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     LimeCandle => { ident: "minecraft:lime_candle", global: 23192, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PinkCandle => { ident: "minecraft:pink_candle", global: 23208, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     GrayCandle => { ident: "minecraft:gray_candle", global: 23224, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     LightGrayCandle => { ident: "minecraft:light_gray_candle", global: 23240, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CyanCandle => { ident: "minecraft:cyan_candle", global: 23256, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PurpleCandle => { ident: "minecraft:purple_candle", global: 23272, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BlueCandle => { ident: "minecraft:blue_candle", global: 23288, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BrownCandle => { ident: "minecraft:brown_candle", global: 23304, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     GreenCandle => { ident: "minecraft:green_candle", global: 23320, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     RedCandle => { ident: "minecraft:red_candle", global: 23336, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BlackCandle => { ident: "minecraft:black_candle", global: 23352, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [], tier: Any,
         ty: [ "candles" => Candles_1234, "lit" => LitBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CandleCake => { ident: "minecraft:candle_cake", global: 23368, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     WhiteCandleCake => { ident: "minecraft:white_candle_cake", global: 23370, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     OrangeCandleCake => { ident: "minecraft:orange_candle_cake", global: 23372, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     MagentaCandleCake => { ident: "minecraft:magenta_candle_cake", global: 23374, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     LightBlueCandleCake => { ident: "minecraft:light_blue_candle_cake", global: 23376, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     YellowCandleCake => { ident: "minecraft:yellow_candle_cake", global: 23378, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     LimeCandleCake => { ident: "minecraft:lime_candle_cake", global: 23380, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     PinkCandleCake => { ident: "minecraft:pink_candle_cake", global: 23382, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     GrayCandleCake => { ident: "minecraft:gray_candle_cake", global: 23384, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     LightGrayCandleCake => { ident: "minecraft:light_gray_candle_cake", global: 23386, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     CyanCandleCake => { ident: "minecraft:cyan_candle_cake", global: 23388, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     PurpleCandleCake => { ident: "minecraft:purple_candle_cake", global: 23390, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     BlueCandleCake => { ident: "minecraft:blue_candle_cake", global: 23392, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     BrownCandleCake => { ident: "minecraft:brown_candle_cake", global: 23394, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     GreenCandleCake => { ident: "minecraft:green_candle_cake", global: 23396, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     RedCandleCake => { ident: "minecraft:red_candle_cake", global: 23398, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     BlackCandleCake => { ident: "minecraft:black_candle_cake", global: 23400, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [], tier: Any,
         ty: [ "lit" => LitBool ], shape: { SHAPE_45 }
     },
     AmethystBlock => { ident: "minecraft:amethyst_block", global: 23402, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BuddingAmethyst => { ident: "minecraft:budding_amethyst", global: 23403, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     AmethystCluster => { ident: "minecraft:amethyst_cluster", global: 23404, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     LargeAmethystBud => { ident: "minecraft:large_amethyst_bud", global: 23416, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     MediumAmethystBud => { ident: "minecraft:medium_amethyst_bud", global: 23428, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SmallAmethystBud => { ident: "minecraft:small_amethyst_bud", global: 23440, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     Tuff => { ident: "minecraft:tuff", global: 23452, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     TuffSlab => { ident: "minecraft:tuff_slab", global: 23453, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     TuffStairs => { ident: "minecraft:tuff_stairs", global: 23459, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     TuffWall => { ident: "minecraft:tuff_wall", global: 23539, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     PolishedTuff => { ident: "minecraft:polished_tuff", global: 23863, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PolishedTuffSlab => { ident: "minecraft:polished_tuff_slab", global: 23864, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PolishedTuffStairs => { ident: "minecraft:polished_tuff_stairs", global: 23870, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PolishedTuffWall => { ident: "minecraft:polished_tuff_wall", global: 23950, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     ChiseledTuff => { ident: "minecraft:chiseled_tuff", global: 24274, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     TuffBricks => { ident: "minecraft:tuff_bricks", global: 24275, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     TuffBrickSlab => { ident: "minecraft:tuff_brick_slab", global: 24276, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     TuffBrickStairs => { ident: "minecraft:tuff_brick_stairs", global: 24282, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     TuffBrickWall => { ident: "minecraft:tuff_brick_wall", global: 24362, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     ChiseledTuffBricks => { ident: "minecraft:chiseled_tuff_bricks", global: 24686, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Calcite => { ident: "minecraft:calcite", global: 24687, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.75, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     TintedGlass => { ident: "minecraft:tinted_glass", global: 24688, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PowderSnow => { ident: "minecraft:powder_snow", global: 24689, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.25, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SculkSensor => { ident: "minecraft:sculk_sensor", global: 24690, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: false, mineable: [HOE], tier: Any,
         ty: [ "power" => Power_0123456789101112131415, "sculk_sensor_phase" => SculkSensorPhase_InactiveActiveCooldown, "waterlogged" => WaterloggedBool ], shape: { SHAPE_4 }
     },
     CalibratedSculkSensor => { ident: "minecraft:calibrated_sculk_sensor", global: 24786, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: false, mineable: [HOE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "power" => Power_0123456789101112131415, "sculk_sensor_phase" => SculkSensorPhase_InactiveActiveCooldown, "waterlogged" => WaterloggedBool ], shape: { SHAPE_4 }
     },
     Sculk => { ident: "minecraft:sculk", global: 25170, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.2, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     SculkVein => { ident: "minecraft:sculk_vein", global: 25171, default: 127, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.2, tool: false, mineable: [HOE], tier: Any,
         ty: [ "down" => DownBool, "east" => EastBool, "north" => NorthBool, "south" => SouthBool, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => WestBool ], shape: { SHAPE_3 }
     },
     SculkCatalyst => { ident: "minecraft:sculk_catalyst", global: 25299, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: false, mineable: [HOE], tier: Any,
         ty: [ "bloom" => BloomBool ], shape: { SHAPE_1 }
     },
     SculkShrieker => { ident: "minecraft:sculk_shrieker", global: 25301, default: 7, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: false, mineable: [HOE], tier: Any,
         ty: [ "can_summon" => CanSummonBool, "shrieking" => ShriekingBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     CopperBlock => { ident: "minecraft:copper_block", global: 25309, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     ExposedCopper => { ident: "minecraft:exposed_copper", global: 25310, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WeatheredCopper => { ident: "minecraft:weathered_copper", global: 25311, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     OxidizedCopper => { ident: "minecraft:oxidized_copper", global: 25312, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     CopperOre => { ident: "minecraft:copper_ore", global: 25313, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateCopperOre => { ident: "minecraft:deepslate_copper_ore", global: 25314, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 4.5, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     OxidizedCutCopper => { ident: "minecraft:oxidized_cut_copper", global: 25315, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WeatheredCutCopper => { ident: "minecraft:weathered_cut_copper", global: 25316, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     ExposedCutCopper => { ident: "minecraft:exposed_cut_copper", global: 25317, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     CutCopper => { ident: "minecraft:cut_copper", global: 25318, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     OxidizedChiseledCopper => { ident: "minecraft:oxidized_chiseled_copper", global: 25319, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WeatheredChiseledCopper => { ident: "minecraft:weathered_chiseled_copper", global: 25320, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     ExposedChiseledCopper => { ident: "minecraft:exposed_chiseled_copper", global: 25321, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     ChiseledCopper => { ident: "minecraft:chiseled_copper", global: 25322, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedOxidizedChiseledCopper => { ident: "minecraft:waxed_oxidized_chiseled_copper", global: 25323, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedWeatheredChiseledCopper => { ident: "minecraft:waxed_weathered_chiseled_copper", global: 25324, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedExposedChiseledCopper => { ident: "minecraft:waxed_exposed_chiseled_copper", global: 25325, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedChiseledCopper => { ident: "minecraft:waxed_chiseled_copper", global: 25326, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     OxidizedCutCopperStairs => { ident: "minecraft:oxidized_cut_copper_stairs", global: 25327, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WeatheredCutCopperStairs => { ident: "minecraft:weathered_cut_copper_stairs", global: 25407, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     ExposedCutCopperStairs => { ident: "minecraft:exposed_cut_copper_stairs", global: 25487, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CutCopperStairs => { ident: "minecraft:cut_copper_stairs", global: 25567, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     OxidizedCutCopperSlab => { ident: "minecraft:oxidized_cut_copper_slab", global: 25647, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     WeatheredCutCopperSlab => { ident: "minecraft:weathered_cut_copper_slab", global: 25653, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     ExposedCutCopperSlab => { ident: "minecraft:exposed_cut_copper_slab", global: 25659, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     CutCopperSlab => { ident: "minecraft:cut_copper_slab", global: 25665, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     WaxedCopperBlock => { ident: "minecraft:waxed_copper_block", global: 25671, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedWeatheredCopper => { ident: "minecraft:waxed_weathered_copper", global: 25672, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedExposedCopper => { ident: "minecraft:waxed_exposed_copper", global: 25673, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedOxidizedCopper => { ident: "minecraft:waxed_oxidized_copper", global: 25674, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedOxidizedCutCopper => { ident: "minecraft:waxed_oxidized_cut_copper", global: 25675, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedWeatheredCutCopper => { ident: "minecraft:waxed_weathered_cut_copper", global: 25676, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedExposedCutCopper => { ident: "minecraft:waxed_exposed_cut_copper", global: 25677, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedCutCopper => { ident: "minecraft:waxed_cut_copper", global: 25678, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     WaxedOxidizedCutCopperStairs => { ident: "minecraft:waxed_oxidized_cut_copper_stairs", global: 25679, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedWeatheredCutCopperStairs => { ident: "minecraft:waxed_weathered_cut_copper_stairs", global: 25759, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedExposedCutCopperStairs => { ident: "minecraft:waxed_exposed_cut_copper_stairs", global: 25839, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedCutCopperStairs => { ident: "minecraft:waxed_cut_copper_stairs", global: 25919, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedOxidizedCutCopperSlab => { ident: "minecraft:waxed_oxidized_cut_copper_slab", global: 25999, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     WaxedWeatheredCutCopperSlab => { ident: "minecraft:waxed_weathered_cut_copper_slab", global: 26005, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     WaxedExposedCutCopperSlab => { ident: "minecraft:waxed_exposed_cut_copper_slab", global: 26011, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     WaxedCutCopperSlab => { ident: "minecraft:waxed_cut_copper_slab", global: 26017, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     CopperDoor => { ident: "minecraft:copper_door", global: 26023, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     ExposedCopperDoor => { ident: "minecraft:exposed_copper_door", global: 26087, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     OxidizedCopperDoor => { ident: "minecraft:oxidized_copper_door", global: 26151, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WeatheredCopperDoor => { ident: "minecraft:weathered_copper_door", global: 26215, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WaxedCopperDoor => { ident: "minecraft:waxed_copper_door", global: 26279, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WaxedExposedCopperDoor => { ident: "minecraft:waxed_exposed_copper_door", global: 26343, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WaxedOxidizedCopperDoor => { ident: "minecraft:waxed_oxidized_copper_door", global: 26407, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     WaxedWeatheredCopperDoor => { ident: "minecraft:waxed_weathered_copper_door", global: 26471, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "hinge" => Hinge_LeftRight, "open" => OpenBool, "powered" => PoweredBool ], shape: { SHAPE_3 }
     },
     CopperTrapdoor => { ident: "minecraft:copper_trapdoor", global: 26535, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     ExposedCopperTrapdoor => { ident: "minecraft:exposed_copper_trapdoor", global: 26599, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     OxidizedCopperTrapdoor => { ident: "minecraft:oxidized_copper_trapdoor", global: 26663, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WeatheredCopperTrapdoor => { ident: "minecraft:weathered_copper_trapdoor", global: 26727, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedCopperTrapdoor => { ident: "minecraft:waxed_copper_trapdoor", global: 26791, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedExposedCopperTrapdoor => { ident: "minecraft:waxed_exposed_copper_trapdoor", global: 26855, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedOxidizedCopperTrapdoor => { ident: "minecraft:waxed_oxidized_copper_trapdoor", global: 26919, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedWeatheredCopperTrapdoor => { ident: "minecraft:waxed_weathered_copper_trapdoor", global: 26983, default: 15, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "open" => OpenBool, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CopperGrate => { ident: "minecraft:copper_grate", global: 27047, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     ExposedCopperGrate => { ident: "minecraft:exposed_copper_grate", global: 27049, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     WeatheredCopperGrate => { ident: "minecraft:weathered_copper_grate", global: 27051, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     OxidizedCopperGrate => { ident: "minecraft:oxidized_copper_grate", global: 27053, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     WaxedCopperGrate => { ident: "minecraft:waxed_copper_grate", global: 27055, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     WaxedExposedCopperGrate => { ident: "minecraft:waxed_exposed_copper_grate", global: 27057, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     WaxedWeatheredCopperGrate => { ident: "minecraft:waxed_weathered_copper_grate", global: 27059, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     WaxedOxidizedCopperGrate => { ident: "minecraft:waxed_oxidized_copper_grate", global: 27061, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_1 }
     },
     CopperBulb => { ident: "minecraft:copper_bulb", global: 27063, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "lit" => LitBool, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     ExposedCopperBulb => { ident: "minecraft:exposed_copper_bulb", global: 27067, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "lit" => LitBool, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     WeatheredCopperBulb => { ident: "minecraft:weathered_copper_bulb", global: 27071, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "lit" => LitBool, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     OxidizedCopperBulb => { ident: "minecraft:oxidized_copper_bulb", global: 27075, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "lit" => LitBool, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     WaxedCopperBulb => { ident: "minecraft:waxed_copper_bulb", global: 27079, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "lit" => LitBool, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     WaxedExposedCopperBulb => { ident: "minecraft:waxed_exposed_copper_bulb", global: 27083, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "lit" => LitBool, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     WaxedWeatheredCopperBulb => { ident: "minecraft:waxed_weathered_copper_bulb", global: 27087, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "lit" => LitBool, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     WaxedOxidizedCopperBulb => { ident: "minecraft:waxed_oxidized_copper_bulb", global: 27091, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "lit" => LitBool, "powered" => PoweredBool ], shape: { SHAPE_1 }
     },
     CopperChest => { ident: "minecraft:copper_chest", global: 27095, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
@@ -3299,216 +4349,287 @@ generate! {
         ty: [ "copper_golem_pose" => CopperGolemPose_StandingSittingRunningStar, "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_46 }
     },
     LightningRod => { ident: "minecraft:lightning_rod", global: 27543, default: 19, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     ExposedLightningRod => { ident: "minecraft:exposed_lightning_rod", global: 27567, default: 19, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WeatheredLightningRod => { ident: "minecraft:weathered_lightning_rod", global: 27591, default: 19, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     OxidizedLightningRod => { ident: "minecraft:oxidized_lightning_rod", global: 27615, default: 19, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedLightningRod => { ident: "minecraft:waxed_lightning_rod", global: 27639, default: 19, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedExposedLightningRod => { ident: "minecraft:waxed_exposed_lightning_rod", global: 27663, default: 19, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedWeatheredLightningRod => { ident: "minecraft:waxed_weathered_lightning_rod", global: 27687, default: 19, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     WaxedOxidizedLightningRod => { ident: "minecraft:waxed_oxidized_lightning_rod", global: 27711, default: 19, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [ "facing" => Facing_NorthEastSouthWestUpDown, "powered" => PoweredBool, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PointedDripstone => { ident: "minecraft:pointed_dripstone", global: 27735, default: 5, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 1.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "thickness" => Thickness, "vertical_direction" => VerticalDirection, "waterlogged" => WaterloggedBool ], shape: { SHAPE_47 }
     },
     DripstoneBlock => { ident: "minecraft:dripstone_block", global: 27755, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CaveVines => { ident: "minecraft:cave_vines", global: 27756, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "age" => Age_012345678910111213141516171819202122232425, "berries" => BerriesBool ], shape: { SHAPE_3 }
     },
     CaveVinesPlant => { ident: "minecraft:cave_vines_plant", global: 27808, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "berries" => BerriesBool ], shape: { SHAPE_3 }
     },
     SporeBlossom => { ident: "minecraft:spore_blossom", global: 27810, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_48 }
     },
     Azalea => { ident: "minecraft:azalea", global: 27811, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_22 }
     },
     FloweringAzalea => { ident: "minecraft:flowering_azalea", global: 27812, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_22 }
     },
     MossCarpet => { ident: "minecraft:moss_carpet", global: 27813, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [HOE, SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_13 }
     },
     PinkPetals => { ident: "minecraft:pink_petals", global: 27814, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "flower_amount" => FlowerAmount_1234 ], shape: { SHAPE_3 }
     },
     Wildflowers => { ident: "minecraft:wildflowers", global: 27830, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "flower_amount" => FlowerAmount_1234 ], shape: { SHAPE_3 }
     },
     LeafLitter => { ident: "minecraft:leaf_litter", global: 27846, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "segment_amount" => SegmentAmount ], shape: { SHAPE_3 }
     },
     MossBlock => { ident: "minecraft:moss_block", global: 27862, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     BigDripleaf => { ident: "minecraft:big_dripleaf", global: 27863, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "tilt" => Tilt, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     BigDripleafStem => { ident: "minecraft:big_dripleaf_stem", global: 27895, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.1, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     SmallDripleaf => { ident: "minecraft:small_dripleaf", global: 27903, default: 3, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [AXE, SWORD], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_UpperLower, "waterlogged" => WaterloggedBool ], shape: { SHAPE_5 }
     },
     HangingRoots => { ident: "minecraft:hanging_roots", global: 27919, default: 1, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_49 }
     },
     RootedDirt => { ident: "minecraft:rooted_dirt", global: 27921, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Mud => { ident: "minecraft:mud", global: 27922, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.5, tool: false, mineable: [SHOVEL], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     Deepslate => { ident: "minecraft:deepslate", global: 27923, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     CobbledDeepslate => { ident: "minecraft:cobbled_deepslate", global: 27926, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CobbledDeepslateStairs => { ident: "minecraft:cobbled_deepslate_stairs", global: 27927, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     CobbledDeepslateSlab => { ident: "minecraft:cobbled_deepslate_slab", global: 28007, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     CobbledDeepslateWall => { ident: "minecraft:cobbled_deepslate_wall", global: 28013, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     PolishedDeepslate => { ident: "minecraft:polished_deepslate", global: 28337, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PolishedDeepslateStairs => { ident: "minecraft:polished_deepslate_stairs", global: 28338, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     PolishedDeepslateSlab => { ident: "minecraft:polished_deepslate_slab", global: 28418, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     PolishedDeepslateWall => { ident: "minecraft:polished_deepslate_wall", global: 28424, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     DeepslateTiles => { ident: "minecraft:deepslate_tiles", global: 28748, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateTileStairs => { ident: "minecraft:deepslate_tile_stairs", global: 28749, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DeepslateTileSlab => { ident: "minecraft:deepslate_tile_slab", global: 28829, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     DeepslateTileWall => { ident: "minecraft:deepslate_tile_wall", global: 28835, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     DeepslateBricks => { ident: "minecraft:deepslate_bricks", global: 29159, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DeepslateBrickStairs => { ident: "minecraft:deepslate_brick_stairs", global: 29160, default: 11, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "half" => Half_TopBottom, "shape" => Shape_StraightInnerLeftInnerRightOuterLeftOuterRight, "waterlogged" => WaterloggedBool ], shape: { SHAPE_3 }
     },
     DeepslateBrickSlab => { ident: "minecraft:deepslate_brick_slab", global: 29240, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "type" => Type_TopBottomDouble, "waterlogged" => WaterloggedBool ], shape: { SHAPE_22 }
     },
     DeepslateBrickWall => { ident: "minecraft:deepslate_brick_wall", global: 29246, default: 3, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "up" => UpBool, "waterlogged" => WaterloggedBool, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     ChiseledDeepslate => { ident: "minecraft:chiseled_deepslate", global: 29570, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CrackedDeepslateBricks => { ident: "minecraft:cracked_deepslate_bricks", global: 29571, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     CrackedDeepslateTiles => { ident: "minecraft:cracked_deepslate_tiles", global: 29572, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 3.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     InfestedDeepslate => { ident: "minecraft:infested_deepslate", global: 29573, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: false, mineable: [PICKAXE], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     SmoothBasalt => { ident: "minecraft:smooth_basalt", global: 29576, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.25, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     RawIronBlock => { ident: "minecraft:raw_iron_block", global: 29577, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     RawCopperBlock => { ident: "minecraft:raw_copper_block", global: 29578, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Stone,
         ty: [  ], shape: { SHAPE_1 }
     },
     RawGoldBlock => { ident: "minecraft:raw_gold_block", global: 29579, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 5.0, tool: true, mineable: [PICKAXE], tier: Iron,
         ty: [  ], shape: { SHAPE_1 }
     },
     PottedAzalea => { ident: "minecraft:potted_azalea_bush", global: 29580, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedFloweringAzalea => { ident: "minecraft:potted_flowering_azalea_bush", global: 29581, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     OchreFroglight => { ident: "minecraft:ochre_froglight", global: 29582, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     VerdantFroglight => { ident: "minecraft:verdant_froglight", global: 29585, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     PearlescentFroglight => { ident: "minecraft:pearlescent_froglight", global: 29588, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.3, tool: false, mineable: [], tier: Any,
         ty: [ "axis" => Axis_XYZ ], shape: { SHAPE_1 }
     },
     Frogspawn => { ident: "minecraft:frogspawn", global: 29591, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_50 }
     },
     ReinforcedDeepslate => { ident: "minecraft:reinforced_deepslate", global: 29592, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 55.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     DecoratedPot => { ident: "minecraft:decorated_pot", global: 29593, default: 9, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "cracked" => CrackedBool, "facing" => Facing_NorthSouthWestEast, "waterlogged" => WaterloggedBool ], shape: { SHAPE_8 }
     },
     Crafter => { ident: "minecraft:crafter", global: 29609, default: 45, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 1.5, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "crafting" => CraftingBool, "orientation" => Orientation_DownEastDownNorthDownSouthDownWestUpEastUpNorthUpSouthUpWestWestUpEastUpNorthUpSouthUp, "triggered" => TriggeredBool ], shape: { SHAPE_1 }
     },
     TrialSpawner => { ident: "minecraft:trial_spawner", global: 29657, default: 6, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 50.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "ominous" => OminousBool, "trial_spawner_state" => TrialSpawnerState ], shape: { SHAPE_1 }
     },
     Vault => { ident: "minecraft:vault", global: 29669, default: 4, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 50.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "facing" => Facing_NorthSouthWestEast, "ominous" => OminousBool, "vault_state" => VaultState ], shape: { SHAPE_1 }
     },
     HeavyCore => { ident: "minecraft:heavy_core", global: 29701, default: 1, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 10.0, tool: true, mineable: [PICKAXE], tier: Any,
         ty: [ "waterlogged" => WaterloggedBool ], shape: { SHAPE_51 }
     },
     PaleMossBlock => { ident: "minecraft:pale_moss_block", global: 29703, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.1, tool: false, mineable: [HOE], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     },
     PaleMossCarpet => { ident: "minecraft:pale_moss_carpet", global: 29704, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: false,
+        hardness: 0.1, tool: false, mineable: [HOE], tier: Any,
         ty: [ "bottom" => BottomBool, "east" => East_NoneLowTall, "north" => North_NoneLowTall, "south" => South_NoneLowTall, "west" => West_NoneLowTall ], shape: { SHAPE_3 }
     },
     PaleHangingMoss => { ident: "minecraft:pale_hanging_moss", global: 29866, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [ "tip" => TipBool ], shape: { SHAPE_52 }
     },
     OpenEyeblossom => { ident: "minecraft:open_eyeblossom", global: 29868, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     ClosedEyeblossom => { ident: "minecraft:closed_eyeblossom", global: 29869, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_10 }
     },
     PottedOpenEyeblossom => { ident: "minecraft:potted_open_eyeblossom", global: 29870, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     PottedClosedEyeblossom => { ident: "minecraft:potted_closed_eyeblossom", global: 29871, default: 0, air: false, solid: true, liquid: false, collision: true, occlusion: true,
+        hardness: 0.0, tool: false, mineable: [], tier: Any,
         ty: [  ], shape: { SHAPE_11 }
     },
     FireflyBush => { ident: "minecraft:firefly_bush", global: 29872, default: 0, air: false, solid: true, liquid: false, collision: false, occlusion: false,
+        hardness: 0.0, tool: false, mineable: [SWORD], tier: Any,
         ty: [  ], shape: { SHAPE_1 }
     }
 }
@@ -7251,3 +8372,14 @@ generate! {
     PaleMossCarpet, PaleMossCarpet, PaleHangingMoss, PaleHangingMoss, OpenEyeblossom, ClosedEyeblossom, PottedOpenEyeblossom, PottedClosedEyeblossom,
     FireflyBush
 }
+
+generate! {
+    @version @block_entities V26_1,
+    Furnace, Chest, TrappedChest, EnderChest, Jukebox, Dispenser, Dropper, Sign,
+    HangingSign, MobSpawner, CreakingHeart, Piston, BrewingStand, EnchantingTable, EndPortal, Beacon,
+    Skull, DaylightDetector, Hopper, Comparator, Banner, StructureBlock, EndGateway, CommandBlock,
+    ShulkerBox, Bed, Conduit, Barrel, Smoker, BlastFurnace, Lectern, Bell,
+    Jigsaw, Campfire, Beehive, SculkSensor, CalibratedSculkSensor, SculkCatalyst, SculkShrieker, ChiseledBookshelf,
+    Shelf, BrushableBlock, DecoratedPot, Crafter, TrialSpawner, Vault, TestBlock, TestInstanceBlock,
+    CopperGolemStatue
+}
@@ -17,12 +17,13 @@ pub mod prelude {
     //! Re-exports of common types, traits, and macros.
 
     pub use crate::{
-        block::{Block, BlockAttributes, BlockType},
+        block::{Block, BlockAttributes, BlockParseError, BlockPredicate, BlockType},
         generated::{
             attribute as block_attribute,
             block::{self, VanillaBlock},
+            block_entity::VanillaBlockEntity,
         },
         state::{GlobalBlockId, GlobalStateId, RelativeStateId},
-        version::BlockVersion,
+        version::{BlockEntityVersion, BlockVersion},
     };
 }
@@ -11,6 +11,7 @@ use indexmap::IndexMap;
 
 use crate::{
     block::{Block, BlockMetadata},
+    generated::block_entity::VanillaBlockEntity,
     prelude::BlockVersion,
     state::{GlobalBlockId, GlobalStateId, RelativeStateId},
 };
@@ -24,6 +25,7 @@ pub struct BlockStorage {
     metadata: Box<[&'static BlockMetadata], &'static (dyn Allocator + Send + Sync)>,
     #[cfg(not(feature = "nightly"))]
     metadata: Box<[&'static BlockMetadata]>,
+    block_entities: &'static [VanillaBlockEntity],
 }
 
 impl BlockStorage {
@@ -48,7 +50,7 @@ impl BlockStorage {
             Box::<_, &'static (dyn Allocator + Send + Sync)>::from_non_null_in(ptr, &Global)
         };
 
-        Self { version: TypeId::of::<V>(), identifiers, metadata }
+        Self { version: TypeId::of::<V>(), identifiers, metadata, block_entities: &[] }
     }
 
     /// Build a new [`BlockStorage`] for the given [`BlockVersion`].
@@ -89,7 +91,24 @@ impl BlockStorage {
             metadata.push(meta);
         }
 
-        Self { version: TypeId::of::<V>(), identifiers, metadata: metadata.into_boxed_slice() }
+        Self {
+            version: TypeId::of::<V>(),
+            identifiers,
+            metadata: metadata.into_boxed_slice(),
+            block_entities: &[],
+        }
+    }
+
+    /// Use the given [`VanillaBlockEntity`] types for this [`BlockStorage`].
+    ///
+    /// The types must be ordered by their network id.
+    #[must_use]
+    pub const fn with_block_entities(
+        mut self,
+        block_entities: &'static [VanillaBlockEntity],
+    ) -> Self {
+        self.block_entities = block_entities;
+        self
     }
 
     /// Get the default [`Block`] for a given [`GlobalBlockId`].
@@ -138,6 +157,43 @@ impl BlockStorage {
         self.identifiers.get(identifier).and_then(|id| self.get_block_by_state(*id))
     }
 
+    /// Get the [`GlobalBlockId`] for a given [`Identifier`].
+    ///
+    /// # Note
+    ///
+    /// This is typically used by the registry.
+    #[must_use]
+    pub fn get_block_id(&self, identifier: &Identifier<'_>) -> Option<GlobalBlockId> {
+        let index = self.identifiers.get_index_of(identifier)?;
+        u16::try_from(index).ok().map(GlobalBlockId::new)
+    }
+
+    /// Get the [`VanillaBlockEntity`] type for a given network id.
+    ///
+    /// # Note
+    ///
+    /// This is typically used by the network.
+    #[must_use]
+    pub fn get_block_entity_by_id(&self, id: u32) -> Option<VanillaBlockEntity> {
+        self.block_entities.get(id as usize).copied()
+    }
+
+    /// Get the network id of a [`VanillaBlockEntity`] type.
+    ///
+    /// Returns `None` if the type does not exist in this
+    /// [`Version`](froglight_common::version::Version).
+    #[must_use]
+    pub fn get_block_entity_id(&self, block_entity: VanillaBlockEntity) -> Option<u32> {
+        let index = self.block_entities.iter().position(|ty| *ty == block_entity)?;
+        u32::try_from(index).ok()
+    }
+
+    /// Get the [`VanillaBlockEntity`] types of this [`BlockStorage`],
+    /// ordered by their network id.
+    #[inline]
+    #[must_use]
+    pub const fn block_entities(&self) -> &'static [VanillaBlockEntity] { self.block_entities }
+
     /// Get the [`BlockMetadata`] of this [`BlockStorage`].
     #[inline]
     #[must_use]
@@ -3,7 +3,7 @@
 use froglight_common::version::Version;
 pub use froglight_registry_template::version_implement;
 
-use crate::storage::BlockStorage;
+use crate::{generated::block_entity::VanillaBlockEntity, storage::BlockStorage};
 
 froglight_registry_template::version_subtrait! {
     pub trait BlockVersion {
@@ -12,3 +12,10 @@ froglight_registry_template::version_subtrait! {
         fn new_blocks();
     }
 }
+
+/// A list of [`VanillaBlockEntity`] types for a [`Version`].
+pub trait BlockEntityVersion: Version {
+    /// The [`VanillaBlockEntity`] types for this [`Version`],
+    /// ordered by their network id.
+    const BLOCK_ENTITIES: &'static [VanillaBlockEntity];
+}
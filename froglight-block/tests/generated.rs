@@ -0,0 +1,21 @@
+//! TODO
+
+use froglight_block::prelude::*;
+use froglight_common::prelude::*;
+
+/// Get the identifiers of every block without a hardness value.
+fn missing_hardness<V: BlockVersion>() -> Vec<String> {
+    let blocks = V::blocks();
+    (0..blocks.metadata().len())
+        .filter_map(|id| blocks.get_block_by_id(GlobalBlockId::new(u16::try_from(id).unwrap())))
+        .filter(|block| block.hardness().is_none())
+        .map(|block| block.identifier().to_string())
+        .collect()
+}
+
+#[test]
+#[ignore = "Some blocks are missing data until froglight-codegen is re-run against the release jars"]
+fn hardness() {
+    assert_eq!(missing_hardness::<V26_1>(), Vec::<String>::new());
+    assert_eq!(missing_hardness::<V26_2>(), Vec::<String>::new());
+}
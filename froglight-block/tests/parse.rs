@@ -0,0 +1,135 @@
+//! TODO
+
+use froglight_block::{
+    block::{BlockParseError, BlockPredicate, BlockPredicateTarget},
+    prelude::*,
+};
+use froglight_common::prelude::*;
+use froglight_registry::storage::RegistryStorage;
+
+#[test]
+fn block() {
+    let blocks = V26_1::blocks();
+
+    let furnace =
+        Block::from_str_using(blocks, "minecraft:furnace[facing=south,lit=true]").unwrap();
+    assert_eq!(furnace.get_attribute_str("facing"), Some("south"));
+    assert_eq!(furnace.get_attribute_str("lit"), Some("true"));
+    assert_eq!(furnace.to_string(), "minecraft:furnace[facing=south,lit=true]");
+
+    // The namespace, attributes, and whitespace are optional.
+    let default = Block::from_str_using(blocks, "furnace").unwrap();
+    assert_eq!(default, Block::new_default::<block::Furnace, V26_1>());
+    assert_eq!(Block::from_str_using(blocks, "furnace[]"), Ok(default));
+    assert_eq!(
+        Block::from_str_using(blocks, "furnace[ lit = true , facing = south ]"),
+        Ok(furnace)
+    );
+
+    // Formatting and parsing round-trips.
+    let mut block = default;
+    for facing in ["north", "south", "west", "east"] {
+        for lit in ["true", "false"] {
+            block.set_attribute_str("facing", facing).unwrap();
+            block.set_attribute_str("lit", lit).unwrap();
+
+            let string = block.to_string();
+            assert_eq!(string, format!("minecraft:furnace[facing={facing},lit={lit}]"));
+            assert_eq!(Block::from_str_using(blocks, &string), Ok(block));
+        }
+    }
+
+    // Blocks without attributes are formatted without brackets.
+    let stone = Block::new_default::<block::Stone, V26_1>();
+    assert_eq!(stone.to_string(), "minecraft:stone");
+    assert_eq!(Block::from_str_using(blocks, "minecraft:stone"), Ok(stone));
+}
+
+#[test]
+fn block_error() {
+    let blocks = V26_1::blocks();
+
+    assert_eq!(
+        Block::from_str_using(blocks, "minecraft:not_a_block"),
+        Err(BlockParseError::UnknownBlock("minecraft:not_a_block"))
+    );
+    assert_eq!(
+        Block::from_str_using(blocks, "furnace[colour=red]"),
+        Err(BlockParseError::UnknownProperty {
+            block: Identifier::new_static("minecraft:furnace"),
+            property: "colour"
+        })
+    );
+    assert_eq!(
+        Block::from_str_using(blocks, "furnace[facing=up]"),
+        Err(BlockParseError::InvalidValue {
+            block: Identifier::new_static("minecraft:furnace"),
+            property: "facing",
+            value: "up"
+        })
+    );
+    assert_eq!(
+        Block::from_str_using(blocks, "furnace[lit=true,lit=false]"),
+        Err(BlockParseError::DuplicateProperty("lit"))
+    );
+    assert_eq!(
+        Block::from_str_using(blocks, "minecraft::furnace"),
+        Err(BlockParseError::InvalidIdentifier("minecraft::furnace"))
+    );
+
+    for invalid in
+        ["", "[lit=true]", "furnace[lit=true", "furnace[lit]", "furnace[lit=]", "furnace[,]"]
+    {
+        assert_eq!(Block::from_str_using(blocks, invalid), Err(BlockParseError::InvalidSyntax));
+    }
+}
+
+#[test]
+fn predicate() {
+    let blocks = V26_1::blocks();
+
+    let oak_y = Block::from_str_using(blocks, "oak_log[axis=y]").unwrap();
+    let oak_x = Block::from_str_using(blocks, "oak_log[axis=x]").unwrap();
+    let birch_y = Block::from_str_using(blocks, "birch_log[axis=y]").unwrap();
+    let stone = Block::new_default::<block::Stone, V26_1>();
+
+    // Match a single block, with or without attributes.
+    let predicate = BlockPredicate::parse("minecraft:oak_log").unwrap();
+    assert_eq!(
+        predicate.target(),
+        &BlockPredicateTarget::Block(Identifier::new_static("minecraft:oak_log"))
+    );
+    assert!(predicate.matches_with(&oak_y, |_| false));
+    assert!(predicate.matches_with(&oak_x, |_| false));
+    assert!(!predicate.matches_with(&birch_y, |_| true));
+
+    let predicate = BlockPredicate::parse_using(blocks, "oak_log[axis=y]").unwrap();
+    assert_eq!(predicate.to_string(), "minecraft:oak_log[axis=y]");
+    assert!(predicate.matches_with(&oak_y, |_| false));
+    assert!(!predicate.matches_with(&oak_x, |_| false));
+
+    // Blocks are only checked when using a `BlockStorage`.
+    assert!(BlockPredicate::parse("oak_log[axis=w]").is_ok());
+    assert!(BlockPredicate::parse_using(blocks, "oak_log[axis=w]").is_err());
+
+    // Match every block in a tag.
+    let tag = BlockPredicate::parse_using(blocks, "#logs[axis=y]").unwrap();
+    assert_eq!(tag.to_string(), "#minecraft:logs[axis=y]");
+
+    let mut registry = RegistryStorage::build::<V26_1>(Vec::new(), Vec::new());
+    let logs = [oak_y, birch_y]
+        .map(|log| u32::from(blocks.get_block_id(&log.identifier()).unwrap().into_inner()));
+    registry.tags_mut().insert(
+        Identifier::new_static("minecraft:block"),
+        [(Identifier::new_static("minecraft:logs"), logs.to_vec())].into_iter().collect(),
+    );
+
+    assert!(tag.matches_using(&oak_y, blocks, &registry));
+    assert!(tag.matches_using(&birch_y, blocks, &registry));
+    assert!(!tag.matches_using(&oak_x, blocks, &registry));
+    assert!(!tag.matches_using(&stone, blocks, &registry));
+
+    // Unknown tags never match.
+    let unknown = BlockPredicate::parse("#minecraft:unknown").unwrap();
+    assert!(!unknown.matches_using(&oak_y, blocks, &registry));
+}
@@ -1,6 +1,7 @@
 use alloc::{borrow::Cow, string::String};
 
 use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::commands::{ArgumentType, StringArgumentKind};
 
 use super::{ArgumentParseError, ArgumentParser};
 
@@ -20,6 +21,14 @@ impl ArgumentParser for String {
             StringType::Greedy => Ok((input.into(), "")),
         }
     }
+
+    fn argument_type(data: &StringType) -> ArgumentType {
+        match data {
+            StringType::Default => ArgumentType::String(StringArgumentKind::QuotablePhrase),
+            StringType::Word => ArgumentType::String(StringArgumentKind::SingleWord),
+            StringType::Greedy => ArgumentType::String(StringArgumentKind::GreedyPhrase),
+        }
+    }
 }
 
 impl ArgumentParser for Cow<'static, str> {
@@ -32,6 +41,9 @@ impl ArgumentParser for Cow<'static, str> {
     ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
         String::parse(input, data).map(|(s, rest)| (Cow::Owned(s), rest))
     }
+
+    #[inline]
+    fn argument_type(data: &StringType) -> ArgumentType { String::argument_type(data) }
 }
 
 /// The type of [`String`] to parse.
@@ -0,0 +1,18 @@
+use froglight_common::prelude::Identifier;
+use froglight_packet::common::commands::ArgumentType;
+
+use super::{ArgumentParseError, ArgumentParser, remainder, vanilla::read_identifier};
+use crate::graph::reader::StringReader;
+
+impl ArgumentParser for Identifier<'static> {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let identifier = read_identifier(&mut reader)?;
+        Ok((identifier, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::ResourceLocation }
+}
@@ -3,6 +3,8 @@ use core::num::{
     NonZeroU64, NonZeroU128,
 };
 
+use froglight_packet::common::commands::{ArgumentType, StringArgumentKind};
+
 use super::{ArgumentParseError, ArgumentParser};
 
 impl ArgumentParser for bool {
@@ -16,12 +18,15 @@ impl ArgumentParser for bool {
             _ => Err(ArgumentParseError::InputMismatch),
         }
     }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::Bool }
 }
 
 // -------------------------------------------------------------------------------------------------
 
 macro_rules! impl_integer {
-    ($($ty:ty),*) => {
+    ($($ty:ty => $arg:expr),*) => {
         $(
             impl ArgumentParser for $ty {
                 type Data = ();
@@ -36,6 +41,9 @@ macro_rules! impl_integer {
                         Err(_) => Err(ArgumentParseError::Unknown),
                     }
                 }
+
+                #[inline]
+                fn argument_type((): &()) -> ArgumentType { $arg }
             }
         )*
     };
@@ -57,13 +65,30 @@ macro_rules! impl_integer {
                         Err(_) => Err(ArgumentParseError::Unknown),
                     }
                 }
+
+                #[inline]
+                fn argument_type((): &()) -> ArgumentType { <$inner as ArgumentParser>::argument_type(&()) }
             }
         )*
     };
 }
 
-impl_integer!(u8, u16, u32, u64, u128, usize);
-impl_integer!(i8, i16, i32, i64, i128, isize);
+impl_integer!(
+    u8 => ArgumentType::Integer { min: Some(0), max: Some(u8::MAX.into()) },
+    u16 => ArgumentType::Integer { min: Some(0), max: Some(u16::MAX.into()) },
+    u32 => ArgumentType::Long { min: Some(0), max: Some(u32::MAX.into()) },
+    u64 => ArgumentType::Long { min: Some(0), max: None },
+    u128 => ArgumentType::String(StringArgumentKind::SingleWord),
+    usize => ArgumentType::Long { min: Some(0), max: None }
+);
+impl_integer!(
+    i8 => ArgumentType::Integer { min: Some(i8::MIN.into()), max: Some(i8::MAX.into()) },
+    i16 => ArgumentType::Integer { min: Some(i16::MIN.into()), max: Some(i16::MAX.into()) },
+    i32 => ArgumentType::Integer { min: None, max: None },
+    i64 => ArgumentType::Long { min: None, max: None },
+    i128 => ArgumentType::String(StringArgumentKind::SingleWord),
+    isize => ArgumentType::Long { min: None, max: None }
+);
 
 impl_integer!(@nonzero NonZeroU8: u8, NonZeroU16: u16, NonZeroU32: u32, NonZeroU64: u64, NonZeroU128: u128);
 impl_integer!(@nonzero NonZeroI8: i8, NonZeroI16: i16, NonZeroI32: i32, NonZeroI64: i64, NonZeroI128: i128);
@@ -71,7 +96,7 @@ impl_integer!(@nonzero NonZeroI8: i8, NonZeroI16: i16, NonZeroI32: i32, NonZeroI
 // -------------------------------------------------------------------------------------------------
 
 macro_rules! impl_float {
-    ($($ty:ty),*) => {
+    ($($ty:ty => $arg:expr),*) => {
         $(
             impl ArgumentParser for $ty {
                 type Data = ();
@@ -86,12 +111,18 @@ macro_rules! impl_float {
                         Err(_) => Err(ArgumentParseError::Unknown),
                     }
                 }
+
+                #[inline]
+                fn argument_type((): &()) -> ArgumentType { $arg }
             }
         )*
     };
 }
 
-impl_float!(f32, f64);
+impl_float!(
+    f32 => ArgumentType::Float { min: None, max: None },
+    f64 => ArgumentType::Double { min: None, max: None }
+);
 
 // -------------------------------------------------------------------------------------------------
 
@@ -111,6 +142,9 @@ impl<T: ArgumentParser> ArgumentParser for Option<T> {
             }
         }
     }
+
+    #[inline]
+    fn argument_type(data: &Self::Data) -> ArgumentType { T::argument_type(data) }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -145,4 +179,11 @@ impl<T: ArgumentParser, const N: usize> ArgumentParser for [T; N] {
         })
         .map(|arr| (arr, input))
     }
+
+    /// Arrays consume multiple words,
+    /// which clients can only represent as the remaining input.
+    #[inline]
+    fn argument_type(_: &T::Data) -> ArgumentType {
+        ArgumentType::String(StringArgumentKind::GreedyPhrase)
+    }
 }
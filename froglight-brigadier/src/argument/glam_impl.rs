@@ -1,3 +1,4 @@
+use froglight_packet::common::commands::{ArgumentType, StringArgumentKind};
 use glam::{
     BVec2, BVec3, BVec4, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3,
     Vec3A, Vec4,
@@ -6,33 +7,36 @@ use glam::{
 use super::{ArgumentParseError, ArgumentParser};
 
 macro_rules! impl_glam {
-    ($(($ty:ty: [$inner:ty; $n:expr])),*) => {
+    ($(($ty:ty: [$inner:ty; $n:expr] => $arg:expr)),*) => {
         $(
             impl ArgumentParser for $ty {
                 type Data = <$inner as ArgumentParser>::Data;
                 fn parse<'a>(input: &'a str, data: &Self::Data) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
                     <[$inner; $n] as ArgumentParser>::parse(input, data).map(|(arr, rest)| (Self::from(arr), rest))
                 }
+
+                #[inline]
+                fn argument_type(_: &Self::Data) -> ArgumentType { $arg }
             }
         )*
     };
 }
 
 impl_glam!(
-    (BVec2: [bool; 2]),
-    (BVec3: [bool; 3]),
-    (BVec4: [bool; 4]),
-    (UVec2: [u32; 2]),
-    (UVec3: [u32; 3]),
-    (UVec4: [u32; 4]),
-    (IVec2: [i32; 2]),
-    (IVec3: [i32; 3]),
-    (IVec4: [i32; 4]),
-    (Vec2: [f32; 2]),
-    (Vec3: [f32; 3]),
-    (Vec3A: [f32; 3]),
-    (Vec4: [f32; 4]),
-    (DVec2: [f64; 2]),
-    (DVec3: [f64; 3]),
-    (DVec4: [f64; 4])
+    (BVec2: [bool; 2] => ArgumentType::String(StringArgumentKind::GreedyPhrase)),
+    (BVec3: [bool; 3] => ArgumentType::String(StringArgumentKind::GreedyPhrase)),
+    (BVec4: [bool; 4] => ArgumentType::String(StringArgumentKind::GreedyPhrase)),
+    (UVec2: [u32; 2] => ArgumentType::String(StringArgumentKind::GreedyPhrase)),
+    (UVec3: [u32; 3] => ArgumentType::String(StringArgumentKind::GreedyPhrase)),
+    (UVec4: [u32; 4] => ArgumentType::String(StringArgumentKind::GreedyPhrase)),
+    (IVec2: [i32; 2] => ArgumentType::ColumnPos),
+    (IVec3: [i32; 3] => ArgumentType::BlockPos),
+    (IVec4: [i32; 4] => ArgumentType::String(StringArgumentKind::GreedyPhrase)),
+    (Vec2: [f32; 2] => ArgumentType::Vec2),
+    (Vec3: [f32; 3] => ArgumentType::Vec3),
+    (Vec3A: [f32; 3] => ArgumentType::Vec3),
+    (Vec4: [f32; 4] => ArgumentType::String(StringArgumentKind::GreedyPhrase)),
+    (DVec2: [f64; 2] => ArgumentType::Vec2),
+    (DVec3: [f64; 3] => ArgumentType::Vec3),
+    (DVec4: [f64; 4] => ArgumentType::String(StringArgumentKind::GreedyPhrase))
 );
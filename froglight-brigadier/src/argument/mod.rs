@@ -6,19 +6,32 @@ use core::{
     fmt::{self, Display},
 };
 
+use froglight_packet::common::commands::{ArgumentType, StringArgumentKind};
+
+use crate::graph::{ArgumentSyntaxError, reader::StringReader};
+
 mod alloc_impl;
 pub use alloc_impl::*;
 
+mod common_impl;
 mod core_impl;
 
 #[cfg(feature = "glam")]
 mod glam_impl;
 
+#[cfg(feature = "froglight-snbt")]
+mod snbt_impl;
+
 #[cfg(feature = "uuid")]
 mod uuid_impl;
 #[cfg(feature = "uuid")]
 pub use uuid_impl::*;
 
+pub mod vanilla;
+
+#[cfg(feature = "froglight-world")]
+mod world_impl;
+
 /// A trait for arguments that can be parsed from a string.
 pub trait ArgumentParser: Sized + 'static {
     /// Data required to parse the argument from a string.
@@ -33,6 +46,15 @@ pub trait ArgumentParser: Sized + 'static {
         input: &'a str,
         data: &Self::Data,
     ) -> Result<(Self, &'a str), ArgumentParseError<'a>>;
+
+    /// The [`ArgumentType`] clients should use for this argument.
+    ///
+    /// Defaults to a single word if not overridden.
+    #[must_use]
+    fn argument_type(data: &Self::Data) -> ArgumentType {
+        let _ = data;
+        ArgumentType::String(StringArgumentKind::SingleWord)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -76,6 +98,11 @@ impl ArgumentParseError<'_> {
     }
 }
 
+impl From<ArgumentSyntaxError> for ArgumentParseError<'_> {
+    #[inline]
+    fn from(err: ArgumentSyntaxError) -> Self { Self::other(err) }
+}
+
 impl Error for ArgumentParseError<'_> {}
 impl Display for ArgumentParseError<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -96,3 +123,19 @@ impl Display for ArgumentParseError<'_> {
         }
     }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+/// Finish reading an argument,
+/// returning the input after the separating space.
+///
+/// # Errors
+///
+/// Returns an error if the argument is not followed by a space.
+pub(crate) fn remainder(mut reader: StringReader<'_>) -> Result<&str, ArgumentParseError<'_>> {
+    if !reader.can_read() || reader.eat(' ') {
+        Ok(reader.remaining())
+    } else {
+        Err(ArgumentParseError::InputMismatch)
+    }
+}
@@ -0,0 +1,18 @@
+use froglight_packet::common::commands::ArgumentType;
+use froglight_snbt::prelude::IndexedSnbtCow;
+
+use super::{ArgumentParseError, ArgumentParser, remainder, vanilla::read_compound};
+use crate::graph::{ArgumentSyntaxError, reader::StringReader};
+
+impl ArgumentParser for IndexedSnbtCow<'static> {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let compound = read_compound(&mut reader)?.ok_or(ArgumentSyntaxError::InvalidNbt)?;
+        Ok((compound, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::NbtCompoundTag }
+}
@@ -1,4 +1,5 @@
 use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::commands::{ArgumentType, StringArgumentKind};
 use uuid::Uuid;
 
 use super::{ArgumentParseError, ArgumentParser};
@@ -20,6 +21,15 @@ impl ArgumentParser for Uuid {
             }
         }
     }
+
+    fn argument_type(data: &UuidType) -> ArgumentType {
+        match data {
+            UuidType::Hyphenated => ArgumentType::Uuid,
+            UuidType::Simple | UuidType::Braced | UuidType::Integer => {
+                ArgumentType::String(StringArgumentKind::SingleWord)
+            }
+        }
+    }
 }
 
 /// The type of [`Uuid`] to parse.
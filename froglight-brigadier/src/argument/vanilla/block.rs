@@ -0,0 +1,121 @@
+use froglight_block::{
+    block::{Block, BlockParseError},
+    storage::BlockStorage,
+};
+use froglight_common::prelude::Identifier;
+use froglight_packet::common::commands::ArgumentType;
+use froglight_snbt::prelude::IndexedSnbtCow;
+
+use super::read_compound;
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser, remainder},
+    graph::{
+        ArgumentSyntaxError,
+        reader::{StringReader, is_identifier_char},
+    },
+};
+
+/// A block state and optional block entity data,
+/// such as `oak_stairs[facing=east]{...}`.
+#[derive(Debug)]
+pub struct BlockInput {
+    /// The block and its state.
+    pub block: Block,
+    /// The block entity data, if any.
+    pub nbt: Option<IndexedSnbtCow<'static>>,
+}
+
+impl ArgumentParser for BlockInput {
+    type Data = &'static BlockStorage;
+
+    fn parse<'a>(
+        input: &'a str,
+        storage: &&'static BlockStorage,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let block =
+            Block::from_str_using(storage, read_state(&mut reader)?).map_err(parse_error)?;
+        let nbt = read_compound(&mut reader)?;
+
+        Ok((Self { block, nbt }, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type(_: &&'static BlockStorage) -> ArgumentType { ArgumentType::BlockState }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A predicate that matches blocks, such as `#logs[axis=y]`.
+#[derive(Debug)]
+pub struct BlockPredicate {
+    /// The block or tag and the attributes to match.
+    pub predicate: froglight_block::block::BlockPredicate,
+    /// The block entity data the block must have, if any.
+    pub nbt: Option<IndexedSnbtCow<'static>>,
+}
+
+impl BlockPredicate {
+    /// Returns `true` if the block matches this predicate,
+    /// using `is_tagged` to check if it is part of a tag.
+    ///
+    /// Block entity data is not checked.
+    pub fn matches(
+        &self,
+        block: &Block,
+        is_tagged: impl FnOnce(&Block, &Identifier<'_>) -> bool,
+    ) -> bool {
+        self.predicate.matches_with(block, |tag| is_tagged(block, tag))
+    }
+}
+
+impl ArgumentParser for BlockPredicate {
+    type Data = &'static BlockStorage;
+
+    fn parse<'a>(
+        input: &'a str,
+        storage: &&'static BlockStorage,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let predicate =
+            froglight_block::block::BlockPredicate::parse_using(storage, read_state(&mut reader)?)
+                .map_err(parse_error)?;
+        let nbt = read_compound(&mut reader)?;
+
+        Ok((Self { predicate, nbt }, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type(_: &&'static BlockStorage) -> ArgumentType { ArgumentType::BlockPredicate }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Read a block state or tag and its attributes, such as `#logs[axis=y]`,
+/// leaving the parsing to [`froglight_block`].
+fn read_state<'a>(reader: &mut StringReader<'a>) -> Result<&'a str, ArgumentSyntaxError> {
+    let start = reader.cursor();
+    reader.eat('#');
+    if reader.read_while(is_identifier_char).is_empty() {
+        reader.set_cursor(start);
+        return Err(ArgumentSyntaxError::InvalidIdentifier);
+    }
+    if reader.peek() == Some('[') {
+        reader.read_bracketed().ok_or(ArgumentSyntaxError::UnclosedBracket)?;
+    }
+    Ok(&reader.input()[start..reader.cursor()])
+}
+
+/// Convert a [`BlockParseError`] into an [`ArgumentSyntaxError`].
+fn parse_error(error: BlockParseError<'_>) -> ArgumentSyntaxError {
+    match error {
+        BlockParseError::InvalidSyntax | BlockParseError::DuplicateProperty(_) => {
+            ArgumentSyntaxError::ExpectedValue
+        }
+        BlockParseError::InvalidIdentifier(_) => ArgumentSyntaxError::InvalidIdentifier,
+        BlockParseError::UnknownBlock(_) => ArgumentSyntaxError::UnknownValue,
+        BlockParseError::UnknownProperty { .. } | BlockParseError::InvalidValue { .. } => {
+            ArgumentSyntaxError::InvalidOption
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use froglight_packet::common::commands::ArgumentType;
+
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser, remainder},
+    graph::{ArgumentSyntaxError, reader::StringReader},
+};
+
+/// A named chat color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChatColor {
+    /// `black`
+    Black,
+    /// `dark_blue`
+    DarkBlue,
+    /// `dark_green`
+    DarkGreen,
+    /// `dark_aqua`
+    DarkAqua,
+    /// `dark_red`
+    DarkRed,
+    /// `dark_purple`
+    DarkPurple,
+    /// `gold`
+    Gold,
+    /// `gray`
+    Gray,
+    /// `dark_gray`
+    DarkGray,
+    /// `blue`
+    Blue,
+    /// `green`
+    Green,
+    /// `aqua`
+    Aqua,
+    /// `red`
+    Red,
+    /// `light_purple`
+    LightPurple,
+    /// `yellow`
+    Yellow,
+    /// `white`
+    White,
+    /// `reset`
+    Reset,
+}
+
+impl ChatColor {
+    /// All [`ChatColor`]s, in order.
+    pub const ALL: [ChatColor; 17] = [
+        ChatColor::Black,
+        ChatColor::DarkBlue,
+        ChatColor::DarkGreen,
+        ChatColor::DarkAqua,
+        ChatColor::DarkRed,
+        ChatColor::DarkPurple,
+        ChatColor::Gold,
+        ChatColor::Gray,
+        ChatColor::DarkGray,
+        ChatColor::Blue,
+        ChatColor::Green,
+        ChatColor::Aqua,
+        ChatColor::Red,
+        ChatColor::LightPurple,
+        ChatColor::Yellow,
+        ChatColor::White,
+        ChatColor::Reset,
+    ];
+
+    /// Get the name of this [`ChatColor`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            ChatColor::Black => "black",
+            ChatColor::DarkBlue => "dark_blue",
+            ChatColor::DarkGreen => "dark_green",
+            ChatColor::DarkAqua => "dark_aqua",
+            ChatColor::DarkRed => "dark_red",
+            ChatColor::DarkPurple => "dark_purple",
+            ChatColor::Gold => "gold",
+            ChatColor::Gray => "gray",
+            ChatColor::DarkGray => "dark_gray",
+            ChatColor::Blue => "blue",
+            ChatColor::Green => "green",
+            ChatColor::Aqua => "aqua",
+            ChatColor::Red => "red",
+            ChatColor::LightPurple => "light_purple",
+            ChatColor::Yellow => "yellow",
+            ChatColor::White => "white",
+            ChatColor::Reset => "reset",
+        }
+    }
+
+    /// Get the [`ChatColor`] with the given name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|color| color.name() == name)
+    }
+
+    /// Get the RGB value of this [`ChatColor`].
+    ///
+    /// Returns `None` for [`ChatColor::Reset`].
+    #[must_use]
+    pub const fn rgb(self) -> Option<u32> {
+        match self {
+            ChatColor::Black => Some(0x00_00_00),
+            ChatColor::DarkBlue => Some(0x00_00_AA),
+            ChatColor::DarkGreen => Some(0x00_AA_00),
+            ChatColor::DarkAqua => Some(0x00_AA_AA),
+            ChatColor::DarkRed => Some(0xAA_00_00),
+            ChatColor::DarkPurple => Some(0xAA_00_AA),
+            ChatColor::Gold => Some(0xFF_AA_00),
+            ChatColor::Gray => Some(0xAA_AA_AA),
+            ChatColor::DarkGray => Some(0x55_55_55),
+            ChatColor::Blue => Some(0x55_55_FF),
+            ChatColor::Green => Some(0x55_FF_55),
+            ChatColor::Aqua => Some(0x55_FF_FF),
+            ChatColor::Red => Some(0xFF_55_55),
+            ChatColor::LightPurple => Some(0xFF_55_FF),
+            ChatColor::Yellow => Some(0xFF_FF_55),
+            ChatColor::White => Some(0xFF_FF_FF),
+            ChatColor::Reset => None,
+        }
+    }
+}
+
+impl ArgumentParser for ChatColor {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let color =
+            Self::from_name(reader.read_word()).ok_or(ArgumentSyntaxError::InvalidOption)?;
+        Ok((color, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::Color }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A RGB color written as six hexadecimal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexColor(pub u32);
+
+impl ArgumentParser for HexColor {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let hex = reader.read_word();
+        if hex.len() != 6 {
+            return Err(ArgumentSyntaxError::InvalidOption.into());
+        }
+
+        let value = u32::from_str_radix(hex, 16).map_err(|_| ArgumentSyntaxError::InvalidOption)?;
+        Ok((Self(value), remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::HexColor }
+}
@@ -0,0 +1,331 @@
+#[cfg(feature = "glam")]
+use core::f64::consts::FRAC_PI_2;
+
+use froglight_packet::common::commands::ArgumentType;
+#[cfg(feature = "glam")]
+use glam::{DVec2, DVec3};
+
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser, remainder},
+    graph::{
+        ArgumentSyntaxError,
+        argument::{read_float, read_integer},
+        reader::StringReader,
+    },
+};
+
+/// A single coordinate of a position or rotation.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Coordinate {
+    /// The value or offset of the coordinate.
+    pub value: f64,
+    /// How the coordinate is applied.
+    pub kind: CoordinateKind,
+}
+
+/// How a [`Coordinate`] is applied.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoordinateKind {
+    /// An absolute value.
+    #[default]
+    Absolute,
+    /// An offset from the source, written as `~`.
+    Relative,
+    /// An offset in the direction the source is facing, written as `^`.
+    Local,
+}
+
+impl Coordinate {
+    /// Create a new absolute [`Coordinate`].
+    #[inline]
+    #[must_use]
+    pub const fn absolute(value: f64) -> Self { Self { value, kind: CoordinateKind::Absolute } }
+
+    /// Create a new relative [`Coordinate`].
+    #[inline]
+    #[must_use]
+    pub const fn relative(value: f64) -> Self { Self { value, kind: CoordinateKind::Relative } }
+
+    /// Create a new local [`Coordinate`].
+    #[inline]
+    #[must_use]
+    pub const fn local(value: f64) -> Self { Self { value, kind: CoordinateKind::Local } }
+
+    /// Resolve this [`Coordinate`] against a base value.
+    ///
+    /// Local coordinates are treated as relative,
+    /// use [`Coordinates::resolve`] to apply them correctly.
+    #[must_use]
+    pub const fn resolve(&self, base: f64) -> f64 {
+        match self.kind {
+            CoordinateKind::Absolute => self.value,
+            CoordinateKind::Relative | CoordinateKind::Local => base + self.value,
+        }
+    }
+
+    /// Read a [`Coordinate`].
+    ///
+    /// If `integer` is set absolute coordinates must be whole numbers,
+    /// if `center` is set whole absolute coordinates are moved to the center
+    /// of the block.
+    fn read(
+        reader: &mut StringReader<'_>,
+        integer: bool,
+        center: bool,
+        allow_local: bool,
+    ) -> Result<Self, ArgumentSyntaxError> {
+        let kind = if reader.eat('~') {
+            CoordinateKind::Relative
+        } else if allow_local && reader.eat('^') {
+            CoordinateKind::Local
+        } else if matches!(reader.peek(), None | Some(' ' | '^')) {
+            return Err(ArgumentSyntaxError::ExpectedCoordinates);
+        } else {
+            CoordinateKind::Absolute
+        };
+
+        match kind {
+            CoordinateKind::Relative | CoordinateKind::Local => {
+                if matches!(reader.peek(), None | Some(' ')) {
+                    Ok(Self { value: 0.0, kind })
+                } else {
+                    read_float(reader).map(|value| Self { value, kind })
+                }
+            }
+            CoordinateKind::Absolute if integer => {
+                read_integer::<i32>(reader).map(|value| Self::absolute(f64::from(value)))
+            }
+            CoordinateKind::Absolute => {
+                let start = reader.cursor();
+                let value = read_float(reader)?;
+                if center && !reader.input()[start..reader.cursor()].contains('.') {
+                    Ok(Self::absolute(value + 0.5))
+                } else {
+                    Ok(Self::absolute(value))
+                }
+            }
+        }
+    }
+}
+
+/// Read a list of coordinates separated by spaces,
+/// which must either be all local or all not local.
+fn read_coordinates<const N: usize>(
+    reader: &mut StringReader<'_>,
+    integer: bool,
+    center: [bool; N],
+    allow_local: bool,
+) -> Result<[Coordinate; N], ArgumentSyntaxError> {
+    let mut coordinates = [Coordinate::default(); N];
+    for (index, coordinate) in coordinates.iter_mut().enumerate() {
+        if index != 0 && !reader.eat(' ') {
+            return Err(ArgumentSyntaxError::ExpectedCoordinates);
+        }
+        *coordinate = Coordinate::read(reader, integer, center[index], allow_local)?;
+    }
+
+    let local = coordinates[0].kind == CoordinateKind::Local;
+    if coordinates.iter().any(|c| (c.kind == CoordinateKind::Local) != local) {
+        Err(ArgumentSyntaxError::MixedCoordinates)
+    } else {
+        Ok(coordinates)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A position made of three [`Coordinate`]s.
+///
+/// Either all coordinates are local (`^`), or none of them are.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    /// The `x` coordinate, or the offset to the left if local.
+    pub x: Coordinate,
+    /// The `y` coordinate, or the offset upwards if local.
+    pub y: Coordinate,
+    /// The `z` coordinate, or the offset forwards if local.
+    pub z: Coordinate,
+}
+
+/// How [`Coordinates`] and [`ColumnCoordinates`] are parsed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoordinatesKind {
+    /// Block coordinates, absolute values must be whole numbers.
+    #[default]
+    Block,
+    /// A position, whole absolute `x` and `z` values are moved to the center
+    /// of the block.
+    Centered,
+    /// A position, absolute values are used as written.
+    Exact,
+}
+
+impl Coordinates {
+    /// Returns `true` if the coordinates are local (`^`).
+    #[inline]
+    #[must_use]
+    pub fn is_local(&self) -> bool { self.x.kind == CoordinateKind::Local }
+
+    /// Resolve these [`Coordinates`] from an origin and a rotation in degrees.
+    #[must_use]
+    #[cfg(feature = "glam")]
+    pub fn resolve(&self, origin: DVec3, yaw: f64, pitch: f64) -> DVec3 {
+        if !self.is_local() {
+            return DVec3::new(
+                self.x.resolve(origin.x),
+                self.y.resolve(origin.y),
+                self.z.resolve(origin.z),
+            );
+        }
+
+        let (yaw, pitch) = (yaw.to_radians(), pitch.to_radians());
+        let DVec2 { x: yaw_cos, y: yaw_sin } = DVec2::from_angle(yaw + FRAC_PI_2);
+        let DVec2 { x: pitch_cos, y: pitch_sin } = DVec2::from_angle(-pitch);
+        let DVec2 { x: up_cos, y: up_sin } = DVec2::from_angle(FRAC_PI_2 - pitch);
+
+        let forwards = DVec3::new(yaw_cos * pitch_cos, pitch_sin, yaw_sin * pitch_cos);
+        let up = DVec3::new(yaw_cos * up_cos, up_sin, yaw_sin * up_cos);
+        let left = -forwards.cross(up);
+
+        origin + left * self.x.value + up * self.y.value + forwards * self.z.value
+    }
+}
+
+impl ArgumentParser for Coordinates {
+    type Data = CoordinatesKind;
+
+    fn parse<'a>(
+        input: &'a str,
+        data: &CoordinatesKind,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let center = *data == CoordinatesKind::Centered;
+        let [x, y, z] = read_coordinates(
+            &mut reader,
+            *data == CoordinatesKind::Block,
+            [center, false, center],
+            true,
+        )?;
+        Ok((Self { x, y, z }, remainder(reader)?))
+    }
+
+    fn argument_type(data: &CoordinatesKind) -> ArgumentType {
+        match data {
+            CoordinatesKind::Block => ArgumentType::BlockPos,
+            CoordinatesKind::Centered | CoordinatesKind::Exact => ArgumentType::Vec3,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A horizontal position made of two [`Coordinate`]s.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ColumnCoordinates {
+    /// The `x` coordinate.
+    pub x: Coordinate,
+    /// The `z` coordinate.
+    pub z: Coordinate,
+}
+
+impl ColumnCoordinates {
+    /// Resolve these [`ColumnCoordinates`] from an origin.
+    #[must_use]
+    #[cfg(feature = "glam")]
+    pub const fn resolve(&self, origin: DVec2) -> DVec2 {
+        DVec2::new(self.x.resolve(origin.x), self.z.resolve(origin.y))
+    }
+}
+
+impl ArgumentParser for ColumnCoordinates {
+    type Data = CoordinatesKind;
+
+    fn parse<'a>(
+        input: &'a str,
+        data: &CoordinatesKind,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let center = *data == CoordinatesKind::Centered;
+        let [x, z] = read_coordinates(
+            &mut reader,
+            *data == CoordinatesKind::Block,
+            [center, center],
+            false,
+        )?;
+        Ok((Self { x, z }, remainder(reader)?))
+    }
+
+    fn argument_type(data: &CoordinatesKind) -> ArgumentType {
+        match data {
+            CoordinatesKind::Block => ArgumentType::ColumnPos,
+            CoordinatesKind::Centered | CoordinatesKind::Exact => ArgumentType::Vec2,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A rotation, written as `<yaw> <pitch>`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Rotation {
+    /// The horizontal rotation.
+    pub yaw: Coordinate,
+    /// The vertical rotation.
+    pub pitch: Coordinate,
+}
+
+impl Rotation {
+    /// Resolve this [`Rotation`] from a base rotation in degrees.
+    #[must_use]
+    pub const fn resolve(&self, yaw: f64, pitch: f64) -> (f64, f64) {
+        (self.yaw.resolve(yaw), self.pitch.resolve(pitch))
+    }
+}
+
+impl ArgumentParser for Rotation {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let [yaw, pitch] = read_coordinates(&mut reader, false, [false; 2], false)?;
+        Ok((Self { yaw, pitch }, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::Rotation }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A single angle in degrees, which may be relative (`~`).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Angle(pub Coordinate);
+
+impl Angle {
+    /// Resolve this [`Angle`] from a base angle,
+    /// wrapping the result to `-180..180`.
+    #[must_use]
+    pub fn resolve(&self, base: f64) -> f64 {
+        let angle = self.0.resolve(base) % 360.0;
+        if angle >= 180.0 {
+            angle - 360.0
+        } else if angle < -180.0 {
+            angle + 360.0
+        } else {
+            angle
+        }
+    }
+}
+
+impl ArgumentParser for Angle {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let angle = Coordinate::read(&mut reader, false, false, false)?;
+        Ok((Self(angle), remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::Angle }
+}
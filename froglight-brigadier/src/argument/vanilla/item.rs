@@ -0,0 +1,133 @@
+use alloc::{string::String, vec::Vec};
+
+use froglight_common::prelude::Identifier;
+use froglight_item::{item::Item, storage::ItemStorage};
+use froglight_packet::common::commands::ArgumentType;
+
+use super::{PredicateTarget, read_identifier, read_target, read_value};
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser, remainder},
+    graph::{ArgumentSyntaxError, reader::StringReader},
+};
+
+/// An item and a set of component changes,
+/// such as `diamond_sword[damage=5,!enchantments]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemInput {
+    /// The item.
+    pub item: Item,
+    /// Changes to the default components of the item, in order.
+    pub components: Vec<ItemComponent>,
+}
+
+/// A change to an item's components.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ItemComponent {
+    /// Set a component to a SNBT value.
+    Set(Identifier<'static>, String),
+    /// Remove a component, written as `!name`.
+    Remove(Identifier<'static>),
+}
+
+impl ArgumentParser for ItemInput {
+    type Data = &'static ItemStorage;
+
+    fn parse<'a>(
+        input: &'a str,
+        storage: &&'static ItemStorage,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let identifier = read_identifier(&mut reader)?;
+        let item =
+            storage.get_item_by_identifier(&identifier).ok_or(ArgumentSyntaxError::UnknownValue)?;
+
+        let mut components = Vec::new();
+        if reader.eat('[') {
+            loop {
+                reader.skip_whitespace();
+                if reader.eat(']') {
+                    break;
+                }
+
+                if reader.eat('!') {
+                    components.push(ItemComponent::Remove(read_identifier(&mut reader)?));
+                } else {
+                    let identifier = read_identifier(&mut reader)?;
+                    reader.skip_whitespace();
+                    if !reader.eat('=') {
+                        return Err(ArgumentSyntaxError::ExpectedValue.into());
+                    }
+                    reader.skip_whitespace();
+                    let value = read_value(&mut reader)?;
+                    components.push(ItemComponent::Set(identifier, String::from(value)));
+                }
+
+                reader.skip_whitespace();
+                if reader.eat(']') {
+                    break;
+                } else if !reader.eat(',') {
+                    return Err(ArgumentSyntaxError::UnclosedBracket.into());
+                }
+            }
+        }
+
+        Ok((Self { item, components }, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type(_: &&'static ItemStorage) -> ArgumentType { ArgumentType::ItemStack }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A predicate that matches items, such as `#swords[damage=0]` or `*`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ItemPredicate {
+    /// The item or tag to match.
+    pub target: PredicateTarget,
+    /// The component tests of the predicate, as written.
+    pub tests: Option<String>,
+}
+
+impl ItemPredicate {
+    /// Returns `true` if the item matches this predicate,
+    /// using `is_tagged` to check if it is part of a tag.
+    ///
+    /// Component tests are not checked.
+    pub fn matches(
+        &self,
+        item: &Item,
+        is_tagged: impl FnOnce(&Item, &Identifier<'static>) -> bool,
+    ) -> bool {
+        self.target.matches(&item.identifier(), |tag| is_tagged(item, tag))
+    }
+}
+
+impl ArgumentParser for ItemPredicate {
+    type Data = &'static ItemStorage;
+
+    fn parse<'a>(
+        input: &'a str,
+        storage: &&'static ItemStorage,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let target = read_target(&mut reader, true)?;
+        if let PredicateTarget::Identifier(identifier) = &target
+            && storage.get_item_by_identifier(identifier).is_none()
+        {
+            return Err(ArgumentSyntaxError::UnknownValue.into());
+        }
+
+        let tests = if reader.peek() == Some('[') {
+            let tests = reader.read_bracketed().ok_or(ArgumentSyntaxError::UnclosedBracket)?;
+            Some(String::from(&tests[1..tests.len() - 1]))
+        } else {
+            None
+        };
+
+        Ok((Self { target, tests }, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type(_: &&'static ItemStorage) -> ArgumentType { ArgumentType::ItemPredicate }
+}
@@ -0,0 +1,171 @@
+//! TODO
+
+use froglight_common::prelude::Identifier;
+
+use crate::graph::{
+    ArgumentSyntaxError,
+    reader::{StringReader, is_identifier_char},
+};
+
+mod color;
+pub use color::{ChatColor, HexColor};
+
+mod coordinates;
+pub use coordinates::{
+    Angle, ColumnCoordinates, Coordinate, CoordinateKind, Coordinates, CoordinatesKind, Rotation,
+};
+
+#[cfg(feature = "froglight-block")]
+mod block;
+#[cfg(feature = "froglight-block")]
+pub use block::{BlockInput, BlockPredicate};
+
+#[cfg(feature = "froglight-item")]
+mod item;
+#[cfg(feature = "froglight-item")]
+pub use item::{ItemComponent, ItemInput, ItemPredicate};
+
+#[cfg(feature = "froglight-nbt")]
+mod nbt_path;
+#[cfg(feature = "froglight-nbt")]
+pub use froglight_snbt::path::{NbtPath, NbtPathNode};
+
+mod range;
+pub use range::Bounds;
+
+mod selector;
+pub use selector::{
+    EntitySelector, ScoreHolder, ScoreHolderData, SelectorData, SelectorFilter, SelectorKind,
+};
+
+mod time;
+pub use time::{Time, TimeData};
+
+/// The target of a block or item predicate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PredicateTarget {
+    /// Any value, written as `*`.
+    Any,
+    /// A single value.
+    Identifier(Identifier<'static>),
+    /// Any value in a tag, written as `#namespace:path`.
+    Tag(Identifier<'static>),
+}
+
+impl PredicateTarget {
+    /// Returns `true` if the target matches the identifier,
+    /// using `is_tagged` to check if it is part of a tag.
+    pub fn matches(
+        &self,
+        identifier: &Identifier<'_>,
+        is_tagged: impl FnOnce(&Identifier<'static>) -> bool,
+    ) -> bool {
+        match self {
+            PredicateTarget::Any => true,
+            PredicateTarget::Identifier(target) => target == identifier,
+            PredicateTarget::Tag(tag) => is_tagged(tag),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Read an [`Identifier`], adding the default namespace if missing.
+pub(crate) fn read_identifier(
+    reader: &mut StringReader<'_>,
+) -> Result<Identifier<'static>, ArgumentSyntaxError> {
+    let start = reader.cursor();
+    let identifier = reader.read_while(is_identifier_char);
+    Identifier::try_new_owned(identifier).map_err(|_| {
+        reader.set_cursor(start);
+        ArgumentSyntaxError::InvalidIdentifier
+    })
+}
+
+/// Read a [`PredicateTarget`], optionally allowing `*`.
+#[cfg(feature = "froglight-item")]
+fn read_target(
+    reader: &mut StringReader<'_>,
+    allow_any: bool,
+) -> Result<PredicateTarget, ArgumentSyntaxError> {
+    if allow_any && reader.eat('*') {
+        Ok(PredicateTarget::Any)
+    } else if reader.eat('#') {
+        read_identifier(reader).map(PredicateTarget::Tag)
+    } else {
+        read_identifier(reader).map(PredicateTarget::Identifier)
+    }
+}
+
+/// Read a value inside of a bracketed list,
+/// stopping at the next `,` or closing bracket.
+fn read_value<'a>(reader: &mut StringReader<'a>) -> Result<&'a str, ArgumentSyntaxError> {
+    let start = reader.cursor();
+    let mut depth = 0usize;
+    loop {
+        match reader.peek().ok_or(ArgumentSyntaxError::UnclosedBracket)? {
+            '"' | '\'' => {
+                reader.read_quoted().ok_or(ArgumentSyntaxError::UnclosedQuote)?;
+                continue;
+            }
+            '[' | '{' => depth += 1,
+            ']' | '}' | ',' if depth == 0 => break,
+            ']' | '}' => depth -= 1,
+            _ => {}
+        }
+        reader.next();
+    }
+    Ok(reader.input()[start..reader.cursor()].trim_end())
+}
+
+/// Read a list of `key=value` pairs surrounded by brackets,
+/// assuming the cursor is after the opening bracket.
+fn read_pairs<'a>(
+    reader: &mut StringReader<'a>,
+    close: char,
+    mut f: impl FnMut(&'a str, &'a str) -> Result<(), ArgumentSyntaxError>,
+) -> Result<(), ArgumentSyntaxError> {
+    loop {
+        reader.skip_whitespace();
+        if reader.eat(close) {
+            return Ok(());
+        }
+
+        let key = reader.read_while(|c| !matches!(c, '=' | ',' | ' ') && c != close);
+        reader.skip_whitespace();
+        if key.is_empty() || !reader.eat('=') {
+            return Err(ArgumentSyntaxError::ExpectedValue);
+        }
+        reader.skip_whitespace();
+        f(key, read_value(reader)?)?;
+
+        reader.skip_whitespace();
+        if reader.eat(close) {
+            return Ok(());
+        } else if !reader.eat(',') {
+            return Err(ArgumentSyntaxError::UnclosedBracket);
+        }
+    }
+}
+
+/// Parse a SNBT compound.
+#[cfg(feature = "froglight-snbt")]
+fn parse_compound(
+    compound: &str,
+) -> Result<froglight_snbt::prelude::IndexedSnbtCow<'static>, ArgumentSyntaxError> {
+    froglight_snbt::prelude::IndexedSnbtCow::new_owned(alloc::string::String::from(compound))
+        .map_err(|_| ArgumentSyntaxError::InvalidNbt)
+}
+
+/// Read a SNBT compound if the next character opens one.
+#[cfg(feature = "froglight-snbt")]
+pub(crate) fn read_compound(
+    reader: &mut StringReader<'_>,
+) -> Result<Option<froglight_snbt::prelude::IndexedSnbtCow<'static>>, ArgumentSyntaxError> {
+    if reader.peek() == Some('{') {
+        let compound = reader.read_bracketed().ok_or(ArgumentSyntaxError::UnclosedBracket)?;
+        parse_compound(compound).map(Some)
+    } else {
+        Ok(None)
+    }
+}
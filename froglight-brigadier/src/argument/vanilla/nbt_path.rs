@@ -0,0 +1,20 @@
+use froglight_packet::common::commands::ArgumentType;
+use froglight_snbt::path::NbtPath;
+
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser},
+    graph::ArgumentSyntaxError,
+};
+
+impl ArgumentParser for NbtPath {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let (path, remaining) =
+            NbtPath::parse_prefix(input).map_err(|_| ArgumentSyntaxError::InvalidNbtPath)?;
+        Ok((path, remaining.strip_prefix(' ').unwrap_or(remaining)))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::NbtPath }
+}
@@ -0,0 +1,84 @@
+use froglight_packet::common::commands::ArgumentType;
+
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser, remainder},
+    graph::{ArgumentSyntaxError, reader::StringReader},
+};
+
+/// An inclusive range of values, written as `min..max`.
+///
+/// Either bound may be omitted, and a single value matches only itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<T> {
+    /// The smallest allowed value.
+    pub min: Option<T>,
+    /// The largest allowed value.
+    pub max: Option<T>,
+}
+
+impl<T: PartialOrd> Bounds<T> {
+    /// Create a new [`Bounds`] from an optional minimum and maximum.
+    #[inline]
+    #[must_use]
+    pub const fn new(min: Option<T>, max: Option<T>) -> Self { Self { min, max } }
+
+    /// Returns `true` if the value is within the bounds.
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.min.as_ref().is_none_or(|min| min <= value)
+            && self.max.as_ref().is_none_or(|max| value <= max)
+    }
+}
+
+/// Read a [`Bounds`], parsing each side with `parse`.
+fn read_bounds<T: PartialOrd + Copy>(
+    reader: &mut StringReader<'_>,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Bounds<T>, ArgumentSyntaxError> {
+    let range = reader.read_word();
+    let (min, max) = match range.split_once("..") {
+        Some((min, max)) => (min, max),
+        None => (range, range),
+    };
+
+    let bound = |value: &str| {
+        if value.is_empty() {
+            Ok(None)
+        } else {
+            parse(value).map(Some).ok_or(ArgumentSyntaxError::InvalidRange)
+        }
+    };
+    let bounds = Bounds::new(bound(min)?, bound(max)?);
+
+    match (bounds.min, bounds.max) {
+        (None, None) => Err(ArgumentSyntaxError::InvalidRange),
+        (Some(min), Some(max)) if min > max => Err(ArgumentSyntaxError::InvalidRange),
+        _ => Ok(bounds),
+    }
+}
+
+impl ArgumentParser for Bounds<i32> {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let bounds = read_bounds(&mut reader, |value| lexical::parse::<i32, _>(value).ok())?;
+        Ok((bounds, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::IntRange }
+}
+
+impl ArgumentParser for Bounds<f64> {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let bounds = read_bounds(&mut reader, |value| lexical::parse::<f64, _>(value).ok())?;
+        Ok((bounds, remainder(reader)?))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::FloatRange }
+}
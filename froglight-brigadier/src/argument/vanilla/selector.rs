@@ -0,0 +1,238 @@
+use alloc::{string::String, vec::Vec};
+
+use froglight_packet::common::commands::ArgumentType;
+
+use super::read_pairs;
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser, remainder},
+    graph::{ArgumentSyntaxError, reader::StringReader},
+};
+
+/// A way of selecting one or more entities.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EntitySelector {
+    /// A player name or UUID.
+    Name(String),
+    /// A selector, such as `@a[distance=..5]`.
+    Selector {
+        /// The kind of selector.
+        kind: SelectorKind,
+        /// The filters applied to the selector, in order.
+        filters: Vec<SelectorFilter>,
+    },
+}
+
+/// The kind of an [`EntitySelector`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelectorKind {
+    /// `@a`, all players.
+    AllPlayers,
+    /// `@e`, all entities.
+    AllEntities,
+    /// `@n`, the nearest entity.
+    NearestEntity,
+    /// `@p`, the nearest player.
+    NearestPlayer,
+    /// `@r`, a random player.
+    RandomPlayer,
+    /// `@s`, the entity running the command.
+    Executor,
+}
+
+/// A filter of an [`EntitySelector`], such as `type=!zombie`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelectorFilter {
+    /// The name of the filter.
+    pub key: String,
+    /// The value of the filter, as written.
+    pub value: String,
+    /// Whether the filter is negated with `!`.
+    pub negated: bool,
+}
+
+/// Data used to parse an [`EntitySelector`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SelectorData {
+    /// Only allow selectors that match at most one entity.
+    pub single: bool,
+    /// Only allow selectors that match players.
+    pub players_only: bool,
+}
+
+impl SelectorKind {
+    /// Get the [`SelectorKind`] for the character after the `@`.
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            'a' => Some(SelectorKind::AllPlayers),
+            'e' => Some(SelectorKind::AllEntities),
+            'n' => Some(SelectorKind::NearestEntity),
+            'p' => Some(SelectorKind::NearestPlayer),
+            'r' => Some(SelectorKind::RandomPlayer),
+            's' => Some(SelectorKind::Executor),
+            _ => None,
+        }
+    }
+
+    /// Get the character after the `@` for this [`SelectorKind`].
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        match self {
+            SelectorKind::AllPlayers => 'a',
+            SelectorKind::AllEntities => 'e',
+            SelectorKind::NearestEntity => 'n',
+            SelectorKind::NearestPlayer => 'p',
+            SelectorKind::RandomPlayer => 'r',
+            SelectorKind::Executor => 's',
+        }
+    }
+}
+
+impl EntitySelector {
+    /// Get the first filter with the given name.
+    #[must_use]
+    pub fn filter(&self, key: &str) -> Option<&SelectorFilter> {
+        match self {
+            EntitySelector::Name(_) => None,
+            EntitySelector::Selector { filters, .. } => filters.iter().find(|f| f.key == key),
+        }
+    }
+
+    /// Returns `true` if this selector matches at most one entity.
+    #[must_use]
+    pub fn is_single(&self) -> bool {
+        match self {
+            EntitySelector::Selector { kind: SelectorKind::AllPlayers, .. }
+            | EntitySelector::Selector { kind: SelectorKind::AllEntities, .. } => {
+                self.filter("limit").is_some_and(|f| !f.negated && f.value == "1")
+            }
+            EntitySelector::Name(_) | EntitySelector::Selector { .. } => true,
+        }
+    }
+
+    /// Returns `true` if this selector may match entities that are not
+    /// players.
+    #[must_use]
+    pub fn includes_entities(&self) -> bool {
+        match self {
+            EntitySelector::Name(_)
+            | EntitySelector::Selector { kind: SelectorKind::AllPlayers, .. }
+            | EntitySelector::Selector { kind: SelectorKind::NearestPlayer, .. }
+            | EntitySelector::Selector { kind: SelectorKind::RandomPlayer, .. } => false,
+            EntitySelector::Selector { .. } => !self.filter("type").is_some_and(|f| {
+                !f.negated && matches!(f.value.as_str(), "player" | "minecraft:player")
+            }),
+        }
+    }
+
+    /// Read an [`EntitySelector`], assuming the cursor is on the `@`
+    /// or the start of a name.
+    fn read(reader: &mut StringReader<'_>) -> Result<Self, ArgumentSyntaxError> {
+        if !reader.eat('@') {
+            let name = reader.read_word();
+            return if name.is_empty() {
+                Err(ArgumentSyntaxError::InvalidSelector)
+            } else {
+                Ok(EntitySelector::Name(String::from(name)))
+            };
+        }
+
+        let kind = reader
+            .next()
+            .and_then(SelectorKind::from_char)
+            .ok_or(ArgumentSyntaxError::InvalidSelector)?;
+
+        let mut filters = Vec::new();
+        if reader.eat('[') {
+            read_pairs(reader, ']', |key, value| {
+                let (value, negated) = match value.strip_prefix('!') {
+                    Some(value) => (value.trim_start(), true),
+                    None => (value, false),
+                };
+                filters.push(SelectorFilter {
+                    key: String::from(key),
+                    value: String::from(value),
+                    negated,
+                });
+                Ok(())
+            })?;
+        }
+
+        Ok(EntitySelector::Selector { kind, filters })
+    }
+}
+
+impl ArgumentParser for EntitySelector {
+    type Data = SelectorData;
+
+    fn parse<'a>(
+        input: &'a str,
+        data: &SelectorData,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let selector = Self::read(&mut reader)?;
+
+        if data.single && !selector.is_single() {
+            Err(ArgumentSyntaxError::TooManyEntities.into())
+        } else if data.players_only
+            && selector.includes_entities()
+            && !matches!(selector, EntitySelector::Selector { kind: SelectorKind::Executor, .. })
+        {
+            Err(ArgumentSyntaxError::OnlyPlayers.into())
+        } else {
+            Ok((selector, remainder(reader)?))
+        }
+    }
+
+    #[inline]
+    fn argument_type(data: &SelectorData) -> ArgumentType {
+        ArgumentType::Entity { single: data.single, players_only: data.players_only }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The holder of a score, which may be any entity or name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScoreHolder {
+    /// All score holders, written as `*`.
+    All,
+    /// An entity selector or name.
+    Entity(EntitySelector),
+}
+
+/// Data used to parse a [`ScoreHolder`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScoreHolderData {
+    /// Allow score holders that match more than one entity.
+    pub multiple: bool,
+}
+
+impl ArgumentParser for ScoreHolder {
+    type Data = ScoreHolderData;
+
+    fn parse<'a>(
+        input: &'a str,
+        data: &ScoreHolderData,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let holder = if reader.eat('*') {
+            ScoreHolder::All
+        } else {
+            ScoreHolder::Entity(EntitySelector::read(&mut reader)?)
+        };
+
+        match &holder {
+            ScoreHolder::All if !data.multiple => Err(ArgumentSyntaxError::TooManyEntities.into()),
+            ScoreHolder::Entity(selector) if !data.multiple && !selector.is_single() => {
+                Err(ArgumentSyntaxError::TooManyEntities.into())
+            }
+            _ => Ok((holder, remainder(reader)?)),
+        }
+    }
+
+    #[inline]
+    fn argument_type(data: &ScoreHolderData) -> ArgumentType {
+        ArgumentType::ScoreHolder { multiple: data.multiple }
+    }
+}
@@ -0,0 +1,64 @@
+use froglight_packet::common::commands::ArgumentType;
+
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser, remainder},
+    graph::{ArgumentSyntaxError, argument::read_float, reader::StringReader},
+};
+
+/// A duration in ticks.
+///
+/// Written as a number of ticks, or with a unit of
+/// days (`d`), seconds (`s`) or ticks (`t`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Time(pub i32);
+
+/// Data used to parse a [`Time`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeData {
+    /// The smallest allowed number of ticks.
+    pub min: i32,
+}
+
+impl Time {
+    /// The number of ticks in a day.
+    pub const TICKS_PER_DAY: i32 = 24000;
+    /// The number of ticks in a second.
+    pub const TICKS_PER_SECOND: i32 = 20;
+}
+
+impl ArgumentParser for Time {
+    type Data = TimeData;
+
+    fn parse<'a>(
+        input: &'a str,
+        data: &TimeData,
+    ) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut reader = StringReader::new(input, 0);
+        let value = read_float(&mut reader)?;
+        let scale = match reader.peek() {
+            Some('d') => Time::TICKS_PER_DAY,
+            Some('s') => Time::TICKS_PER_SECOND,
+            _ => 1,
+        };
+        if matches!(reader.peek(), Some('d' | 's' | 't')) {
+            reader.next();
+        }
+
+        let ticks = value * f64::from(scale);
+        let ticks = if ticks < 0.0 { ticks - 0.5 } else { ticks + 0.5 };
+        if ticks < f64::from(i32::MIN) || ticks > f64::from(i32::MAX) {
+            return Err(ArgumentSyntaxError::TooBig.into());
+        }
+
+        #[expect(clippy::cast_possible_truncation, reason = "Checked and rounded above")]
+        let ticks = ticks as i32;
+        if ticks < data.min {
+            Err(ArgumentSyntaxError::TooSmall.into())
+        } else {
+            Ok((Self(ticks), remainder(reader)?))
+        }
+    }
+
+    #[inline]
+    fn argument_type(data: &TimeData) -> ArgumentType { ArgumentType::Time { min: data.min } }
+}
@@ -0,0 +1,67 @@
+use froglight_packet::common::commands::ArgumentType;
+use froglight_world::prelude::{BlockPos, ChunkPos};
+use glam::{DVec2, DVec3, IVec2, IVec3};
+
+use super::{
+    ArgumentParseError, ArgumentParser,
+    vanilla::{ColumnCoordinates, CoordinateKind, Coordinates, CoordinatesKind},
+};
+
+impl ArgumentParser for BlockPos {
+    type Data = ();
+
+    /// Only absolute coordinates are accepted,
+    /// use [`Coordinates`] to support relative and local coordinates.
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let (coords, rest) = Coordinates::parse(input, &CoordinatesKind::Block)?;
+        if [coords.x, coords.y, coords.z].iter().any(|c| c.kind != CoordinateKind::Absolute) {
+            return Err(ArgumentParseError::InputInvalid);
+        }
+
+        Ok((Self::new(coords.resolve(DVec3::ZERO, 0.0, 0.0).as_ivec3()), rest))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::BlockPos }
+}
+
+impl ArgumentParser for ChunkPos {
+    type Data = ();
+
+    /// Parsed like `/forceload`, as the absolute block coordinates of a
+    /// column which are converted into the chunk containing them.
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let (coords, rest) = ColumnCoordinates::parse(input, &CoordinatesKind::Block)?;
+        if [coords.x, coords.z].iter().any(|c| c.kind != CoordinateKind::Absolute) {
+            return Err(ArgumentParseError::InputInvalid);
+        }
+
+        Ok((Self::new(coords.resolve(DVec2::ZERO).as_ivec2() >> 4), rest))
+    }
+
+    #[inline]
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::ColumnPos }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl Coordinates {
+    /// Resolve these [`Coordinates`] into the [`BlockPos`] containing them.
+    ///
+    /// See [`Coordinates::resolve`] for more details.
+    #[must_use]
+    pub fn resolve_block(&self, origin: DVec3, yaw: f64, pitch: f64) -> BlockPos {
+        let position: IVec3 = self.resolve(origin, yaw, pitch).floor().as_ivec3();
+        BlockPos::new(position)
+    }
+}
+
+impl ColumnCoordinates {
+    /// Resolve these [`ColumnCoordinates`] into the [`ChunkPos`] containing
+    /// them.
+    #[must_use]
+    pub fn resolve_chunk(&self, origin: DVec2) -> ChunkPos {
+        let column: IVec2 = self.resolve(origin).floor().as_ivec2();
+        ChunkPos::new(column >> 4)
+    }
+}
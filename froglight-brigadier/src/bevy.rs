@@ -2,7 +2,10 @@
 
 use bevy_app::prelude::*;
 
-use crate::commandset::GameCommandSet;
+use crate::{
+    commandset::GameCommandSet,
+    graph::{CommandGraph, SuggestionRequests},
+};
 
 /// A [`Plugin`] that...
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -11,5 +14,6 @@ pub struct BrigadierPlugin;
 impl Plugin for BrigadierPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GameCommandSet>().register_type::<GameCommandSet>();
+        app.register_type::<CommandGraph>().register_type::<SuggestionRequests>();
     }
 }
@@ -2,7 +2,9 @@
 #![allow(non_snake_case, reason = "Limited identifiers/patterns inside macro_rules")]
 #![allow(unused_mut, unused_parens, reason = "Generated code inside macro_rules")]
 
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+use froglight_packet::common::commands::ArgumentType;
 
 use crate::argument::{ArgumentParseError, ArgumentParser};
 
@@ -20,6 +22,10 @@ pub trait ArgumentBundle: Sized + 'static {
         input: &'a str,
         data: &Self::BundleData,
     ) -> Result<Self, ArgumentParseError<'a>>;
+
+    /// The [`ArgumentType`]s clients should use for each argument, in order.
+    #[must_use]
+    fn argument_types(data: &Self::BundleData) -> Vec<ArgumentType>;
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -35,6 +41,9 @@ impl ArgumentBundle for () {
             Err(ArgumentParseError::ExtraInput(Cow::Borrowed(input)))
         }
     }
+
+    #[inline]
+    fn argument_types((): &()) -> Vec<ArgumentType> { Vec::new() }
 }
 
 impl<T: ArgumentParser> ArgumentBundle for T {
@@ -51,6 +60,9 @@ impl<T: ArgumentParser> ArgumentBundle for T {
         let (t, rem) = T::parse(input, data)?;
         if rem.is_empty() { Ok(t) } else { Err(ArgumentParseError::ExtraInput(Cow::Borrowed(rem))) }
     }
+
+    #[inline]
+    fn argument_types(data: &Self::BundleData) -> Vec<ArgumentType> { vec![T::argument_type(data)] }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -67,7 +79,7 @@ macro_rules! impl_argument_bundle {
                     tracing::trace!(target: "froglight_brigadier", "Parsing Argument {}: {input:?}", $n);
 
                     let ($T, rest) = <$T as ArgumentParser>::parse(input, &data.$n)?;
-                    if rest.is_empty() {
+                    if rest.is_empty() || input[..input.len() - rest.len()].ends_with(' ') {
                         input = rest;
                     } else {
                         input = rest.strip_prefix(' ').ok_or(ArgumentParseError::InputMismatch)?;
@@ -80,6 +92,10 @@ macro_rules! impl_argument_bundle {
                     Err(ArgumentParseError::ExtraInput(Cow::Borrowed(input)))
                 }
             }
+
+            fn argument_types(data: &Self::BundleData) -> Vec<ArgumentType> {
+                vec![$(<$T as ArgumentParser>::argument_type(&data.$n)),*]
+            }
         }
     };
 }
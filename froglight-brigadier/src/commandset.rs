@@ -1,21 +1,65 @@
 //! TODO
 
-use alloc::{borrow::Cow, boxed::Box};
-use core::{error::Error, fmt};
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt::{self, Write},
+};
 
 use bevy_ecs::{prelude::*, system::SystemId};
 use bevy_reflect::{prelude::*, std_traits::ReflectDefault};
-use foldhash::fast::RandomState;
-use indexmap::IndexMap;
+use froglight_packet::common::commands::{ArgumentType, CommandNode, CommandNodeKind, CommandTree};
+use once_cell::race::OnceBox;
 
-use crate::{argument::ArgumentParseError, bundle::ArgumentBundle, prelude::GameCommandCtx};
+use crate::{
+    argument::ArgumentParseError,
+    bundle::ArgumentBundle,
+    context::{CommandArguments, GameCommandCtx},
+    graph::{CommandGraph, Suggestions},
+    node::{NodeKind, RegisteredNode, Requirement},
+};
 
 /// A set of commands that can be executed by entities.
-#[derive(Default, Clone, Resource, Reflect)]
+///
+/// Commands are stored as a tree of nodes,
+/// similar to Minecraft's `brigadier` library.
+#[derive(Clone, Resource, Reflect)]
 #[reflect(opaque, Default, Clone, Resource)]
-pub struct GameCommandSet(IndexMap<Cow<'static, str>, CommandInfo, RandomState>);
+pub struct GameCommandSet {
+    nodes: Vec<CommandNodeData>,
+    /// The [`CommandGraph`] of every command, used for suggestions.
+    ///
+    /// Reset whenever a command is registered.
+    graph: OnceBox<Option<CommandGraph>>,
+}
+
+impl Default for GameCommandSet {
+    fn default() -> Self {
+        Self {
+            nodes: vec![CommandNodeData {
+                kind: NodeKind::Root,
+                children: Vec::new(),
+                command: None,
+                redirect: None,
+                requirement: None,
+                restricted: false,
+            }],
+            graph: OnceBox::new(),
+        }
+    }
+}
 
 impl GameCommandSet {
+    /// The index of the root node.
+    const ROOT: usize = 0;
+
     /// Create a new empty [`GameCommandSet`].
     #[inline]
     #[must_use]
@@ -40,6 +84,9 @@ impl GameCommandSet {
 
     /// Register a command with the given name, parser, and system.
     ///
+    /// Arguments are named after their [`ArgumentType`],
+    /// see [`GameCommandSet::register_command_named`] to name them.
+    ///
     /// # Errors
     ///
     /// Returns an error if a command with the same name already exists.
@@ -48,18 +95,119 @@ impl GameCommandSet {
         command: Cow<'static, str>,
         settings: B::BundleData,
         system: SystemId<GameCommandCtx<B>, ()>,
+    ) -> Result<(), CommandRegisterError> {
+        let names = B::argument_types(&settings).iter().map(argument_name).collect();
+        self.register_command_named(command, names, settings, system)
+    }
+
+    /// Register a command with the given name, argument names, parser, and
+    /// system.
+    ///
+    /// The names are shown in usage text and sent to clients.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a command with the same name already exists,
+    /// or if the number of names does not match the number of arguments.
+    pub fn register_command_named<B: ArgumentBundle>(
+        &mut self,
+        command: Cow<'static, str>,
+        names: Vec<Cow<'static, str>>,
+        settings: B::BundleData,
+        system: SystemId<GameCommandCtx<B>, ()>,
     ) -> Result<(), CommandRegisterError> {
         #[cfg(feature = "tracing")]
         tracing::trace!(target: "froglight_brigadier", "Registering \"{command}\" with {system:?}");
 
-        if self.0.contains_key(&command) {
-            Err(CommandRegisterError::AlreadyExists)
-        } else {
-            self.0.insert(command, CommandInfo::new::<B>(settings, system));
-            Ok(())
+        if self.child(Self::ROOT, &command).is_some() {
+            return Err(CommandRegisterError::AlreadyExists);
+        }
+
+        let types = B::argument_types(&settings);
+        if names.len() != types.len() {
+            return Err(CommandRegisterError::ArgumentCount {
+                expected: types.len(),
+                found: names.len(),
+            });
+        }
+
+        let arguments = names.into_iter().zip(types).collect();
+        let index = self.nodes.len();
+        self.nodes.push(CommandNodeData {
+            kind: NodeKind::Literal(command),
+            children: Vec::new(),
+            command: Some(NodeCommand::Bundle(CommandInfo::new::<B>(settings, system), arguments)),
+            redirect: None,
+            requirement: None,
+            restricted: false,
+        });
+        self.nodes[Self::ROOT].children.push(index);
+        self.graph = OnceBox::new();
+        Ok(())
+    }
+
+    /// Register a tree of command nodes.
+    ///
+    /// The node is added as a child of the root node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a command with the same name already exists,
+    /// or if a node redirects to a path that does not exist.
+    pub(crate) fn register_node(
+        &mut self,
+        node: RegisteredNode,
+    ) -> Result<(), CommandRegisterError> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(target: "froglight_brigadier", "Registering \"{}\" node tree", node.kind.name());
+
+        if self.child(Self::ROOT, node.kind.name()).is_some() {
+            return Err(CommandRegisterError::AlreadyExists);
+        }
+
+        let index = self.insert(node);
+        self.nodes[Self::ROOT].children.push(index);
+
+        // Redirects are resolved after inserting, so nodes can redirect to their own
+        // tree.
+        if let Some(path) = self.nodes[index..]
+            .iter()
+            .filter_map(|node| node.redirect.as_ref())
+            .find(|path| self.resolve(path).is_none())
+        {
+            let path = path.clone();
+            self.nodes[Self::ROOT].children.pop();
+            self.nodes.truncate(index);
+            return Err(CommandRegisterError::InvalidRedirect(path));
         }
+
+        self.graph = OnceBox::new();
+        Ok(())
     }
 
+    /// Insert a node and its children, returning the node's index.
+    fn insert(&mut self, node: RegisteredNode) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(CommandNodeData {
+            kind: node.kind,
+            children: Vec::new(),
+            command: node.system.map(NodeCommand::System),
+            redirect: node.redirect,
+            requirement: node.requirement,
+            restricted: node.restricted,
+        });
+
+        for child in node.children {
+            let child = self.insert(child);
+            self.nodes[index].children.push(child);
+        }
+        index
+    }
+
+    /// Returns `true` if a command with the given name exists.
+    #[must_use]
+    pub fn contains(&self, command: &str) -> bool { self.child(Self::ROOT, command).is_some() }
+
     /// Execute a command with the given arguments.
     ///
     /// # Errors
@@ -75,12 +223,406 @@ impl GameCommandSet {
         #[cfg(feature = "tracing")]
         tracing::debug!(target: "froglight_brigadier", "Entity {entity} executed command \"{command}\"");
 
-        if let Some(info) = self.0.get(command) {
-            info.run(entity, arguments, world)
-        } else {
-            Err(CommandExecuteError::CommandNotFound(Cow::Borrowed(command)))
+        let mut args = CommandArguments::new();
+        let target = match self.child(Self::ROOT, command) {
+            Some(index) if self.can_use(index, Some((entity, world))) => {
+                self.parse_after(index, arguments, entity, world, &mut args)?
+            }
+            _ => return Err(CommandExecuteError::CommandNotFound(Cow::Borrowed(command))),
+        };
+        Self::run(target, entity, args, world)
+    }
+
+    /// Parse and execute a full command, without the leading `/`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command is not found, parsing fails,
+    /// or execution fails.
+    pub fn dispatch<'a>(
+        &self,
+        entity: Entity,
+        input: &'a str,
+        world: &mut World,
+    ) -> Result<(), CommandExecuteError<'a>> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(target: "froglight_brigadier", "Entity {entity} executed \"{input}\"");
+
+        let mut args = CommandArguments::new();
+        let target = self.parse_children(Self::ROOT, input, entity, world, &mut args)?;
+        Self::run(target, entity, args, world)
+    }
+
+    /// Run a parsed command.
+    fn run<'a>(
+        target: Target<'a>,
+        entity: Entity,
+        args: CommandArguments,
+        world: &mut World,
+    ) -> Result<(), CommandExecuteError<'a>> {
+        match target {
+            Target::System(system) => {
+                world.run_system_with(system, (entity, args)).map_err(CommandExecuteError::execute)
+            }
+            Target::Bundle(info, arguments) => info.run(entity, arguments, world),
         }
     }
+
+    /// Parse the input using the children of a node.
+    ///
+    /// Literals take priority over arguments,
+    /// arguments are tried in the order they were added.
+    fn parse_children<'a>(
+        &self,
+        index: usize,
+        input: &'a str,
+        entity: Entity,
+        world: &World,
+        args: &mut CommandArguments,
+    ) -> Result<Target<'a>, CommandExecuteError<'a>> {
+        let literal = input.split(' ').next().unwrap_or_default();
+        if let Some(child) = self.child(index, literal)
+            && self.can_use(child, Some((entity, world)))
+        {
+            let rest = &input[literal.len()..];
+            let rest = rest.strip_prefix(' ').unwrap_or(rest);
+            return self.parse_after(child, rest, entity, world, args);
+        }
+
+        let mut error = None;
+        for &child in &self.nodes[index].children {
+            let NodeKind::Argument(name, parser) = &self.nodes[child].kind else { continue };
+            if !self.can_use(child, Some((entity, world))) {
+                continue;
+            }
+
+            match parser.parse(input) {
+                Ok((value, rest)) => {
+                    args.push(name.clone(), value);
+                    match self.parse_after(child, rest, entity, world, args) {
+                        Ok(target) => return Ok(target),
+                        Err(err) => {
+                            args.pop();
+                            error.get_or_insert(err);
+                        }
+                    }
+                }
+                Err(err) => {
+                    error.get_or_insert(CommandExecuteError::Parse(err));
+                }
+            }
+        }
+
+        Err(error.unwrap_or_else(|| {
+            if index == Self::ROOT {
+                CommandExecuteError::CommandNotFound(Cow::Borrowed(literal))
+            } else {
+                CommandExecuteError::Parse(ArgumentParseError::ExtraInput(Cow::Borrowed(input)))
+            }
+        }))
+    }
+
+    /// Continue parsing after a node has been matched.
+    fn parse_after<'a>(
+        &self,
+        index: usize,
+        input: &'a str,
+        entity: Entity,
+        world: &World,
+        args: &mut CommandArguments,
+    ) -> Result<Target<'a>, CommandExecuteError<'a>> {
+        let node = &self.nodes[index];
+        match &node.command {
+            Some(NodeCommand::Bundle(info, _)) => return Ok(Target::Bundle(*info, input)),
+            Some(NodeCommand::System(system)) if input.is_empty() => {
+                return Ok(Target::System(*system));
+            }
+            _ if input.is_empty() => return Err(CommandExecuteError::Incomplete),
+            _ => {}
+        }
+
+        let next = match &node.redirect {
+            Some(path) => self.resolve(path).ok_or_else(|| {
+                CommandExecuteError::CommandNotFound(Cow::Owned(path.to_string()))
+            })?,
+            None => index,
+        };
+        self.parse_children(next, input, entity, world, args)
+    }
+
+    /// Find the literal child of a node with the given name.
+    fn child(&self, index: usize, name: &str) -> Option<usize> {
+        self.nodes[index].children.iter().copied().find(|&child| {
+            matches!(&self.nodes[child].kind, NodeKind::Literal(literal) if literal == name)
+        })
+    }
+
+    /// Resolve a space-separated path of literals, starting from the root.
+    fn resolve(&self, path: &str) -> Option<usize> {
+        path.split_whitespace().try_fold(Self::ROOT, |index, name| self.child(index, name))
+    }
+
+    /// Returns `true` if the entity is allowed to use the node.
+    ///
+    /// Always returns `true` if no source is given.
+    fn can_use(&self, index: usize, source: Option<(Entity, &World)>) -> bool {
+        match (&self.nodes[index].requirement, source) {
+            (Some(requirement), Some((entity, world))) => requirement(entity, world),
+            _ => true,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl GameCommandSet {
+    /// Get usage information for every command,
+    /// as shown by the vanilla `/help` command.
+    ///
+    /// If a source is given, only nodes it can use are included.
+    #[must_use]
+    pub fn help(&self, source: Option<(Entity, &World)>) -> Vec<String> {
+        self.smart_usage(Self::ROOT, source).into_iter().map(|usage| format!("/{usage}")).collect()
+    }
+
+    /// Get usage information for a command,
+    /// as shown by the vanilla `/help <command>` command.
+    ///
+    /// The command may be a space-separated path of literals.
+    /// If a source is given, only nodes it can use are included.
+    ///
+    /// Returns `None` if the command does not exist.
+    #[must_use]
+    pub fn usage(&self, command: &str, source: Option<(Entity, &World)>) -> Option<Vec<String>> {
+        let path = command.split_whitespace().collect::<Vec<_>>().join(" ");
+        let index = self.resolve(&path).filter(|&index| index != Self::ROOT)?;
+        if !self.can_use(index, source) {
+            return None;
+        }
+
+        let mut usage = Vec::new();
+        match &self.nodes[index].command {
+            Some(NodeCommand::Bundle(_, arguments)) => {
+                usage.push(format!("/{path}{}", bundle_usage(arguments)));
+            }
+            Some(NodeCommand::System(_)) => usage.push(format!("/{path}")),
+            None => {}
+        }
+        usage.extend(self.smart_usage(index, source).into_iter().map(|u| format!("/{path} {u}")));
+        Some(usage)
+    }
+
+    /// Get the usage of each child of a node.
+    fn smart_usage(&self, index: usize, source: Option<(Entity, &World)>) -> Vec<String> {
+        let optional = self.nodes[index].command.is_some();
+        self.nodes[index]
+            .children
+            .iter()
+            .filter_map(|&child| self.smart_usage_of(child, source, optional, false))
+            .collect()
+    }
+
+    /// Get the usage of a node, following `brigadier`'s "smart usage".
+    fn smart_usage_of(
+        &self,
+        index: usize,
+        source: Option<(Entity, &World)>,
+        optional: bool,
+        deep: bool,
+    ) -> Option<String> {
+        if !self.can_use(index, source) {
+            return None;
+        }
+
+        let node = &self.nodes[index];
+        let this = self.usage_text(index);
+        let this = if optional { format!("[{this}]") } else { this };
+        if deep {
+            return Some(this);
+        }
+
+        if let Some(path) = &node.redirect {
+            let target = match self.resolve(path) {
+                Some(Self::ROOT) | None => String::from("..."),
+                Some(target) => format!("-> {}", self.usage_text(target)),
+            };
+            return Some(format!("{this} {target}"));
+        }
+
+        let child_optional = node.command.is_some();
+        let children: Vec<usize> =
+            node.children.iter().copied().filter(|&c| self.can_use(c, source)).collect();
+        match children.as_slice() {
+            [] => {}
+            [child] => {
+                if let Some(usage) =
+                    self.smart_usage_of(*child, source, child_optional, child_optional)
+                {
+                    return Some(format!("{this} {usage}"));
+                }
+            }
+            children => {
+                let mut usages: Vec<String> = Vec::new();
+                for &child in children {
+                    if let Some(usage) = self.smart_usage_of(child, source, child_optional, true)
+                        && !usages.contains(&usage)
+                    {
+                        usages.push(usage);
+                    }
+                }
+
+                if let [usage] = usages.as_slice() {
+                    if child_optional {
+                        return Some(format!("{this} [{usage}]"));
+                    }
+                    return Some(format!("{this} {usage}"));
+                } else if !usages.is_empty() {
+                    let (open, close) = if child_optional { ('[', ']') } else { ('(', ')') };
+                    let names: Vec<String> =
+                        children.iter().map(|&child| self.usage_text(child)).collect();
+                    return Some(format!("{this} {open}{}{close}", names.join("|")));
+                }
+            }
+        }
+
+        Some(this)
+    }
+
+    /// Get the usage text of a single node.
+    fn usage_text(&self, index: usize) -> String {
+        let node = &self.nodes[index];
+        let mut text = match &node.kind {
+            NodeKind::Root => String::new(),
+            NodeKind::Literal(name) => name.to_string(),
+            NodeKind::Argument(name, _) => format!("<{name}>"),
+        };
+        if let Some(NodeCommand::Bundle(_, arguments)) = &node.command {
+            text.push_str(&bundle_usage(arguments));
+        }
+        text
+    }
+}
+
+/// Get the usage text of the arguments of an [`ArgumentBundle`].
+fn bundle_usage(arguments: &[(Cow<'static, str>, ArgumentType)]) -> String {
+    let mut usage = String::new();
+    for (name, _) in arguments {
+        let _ = write!(usage, " <{name}>");
+    }
+    usage
+}
+
+/// Get the default name of an argument,
+/// the path of its [`ArgumentType`]'s identifier.
+fn argument_name(argument: &ArgumentType) -> Cow<'static, str> {
+    let identifier = argument.identifier();
+    Cow::Borrowed(identifier.split_once(':').map_or(identifier, |(_, path)| path))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl GameCommandSet {
+    /// Create a [`CommandTree`] describing every command,
+    /// which can be sent to clients in a `Commands` packet.
+    ///
+    /// If a source is given, only nodes it can use are included.
+    #[must_use]
+    pub fn command_tree(&self, source: Option<(Entity, &World)>) -> CommandTree {
+        let mut nodes = Vec::new();
+        let mut indices = vec![None; self.nodes.len()];
+        let mut redirects = Vec::new();
+        self.export(Self::ROOT, source, &mut nodes, &mut indices, &mut redirects);
+
+        for (node, target) in redirects {
+            nodes[node as usize].redirect = indices[target];
+        }
+        CommandTree { nodes, root: 0 }
+    }
+
+    /// Get suggestions for completing a command, without the leading `/`.
+    ///
+    /// If a source is given, only nodes it can use are suggested.
+    ///
+    /// The [`CommandGraph`] of every command is cached between calls,
+    /// but is rebuilt for each source if any node has a requirement.
+    #[must_use]
+    pub fn suggest(&self, input: &str, source: Option<(Entity, &World)>) -> Suggestions {
+        if source.is_some() && self.nodes.iter().any(|node| node.requirement.is_some()) {
+            return CommandGraph::new(self.command_tree(source))
+                .map(|graph| graph.suggest(input))
+                .unwrap_or_default();
+        }
+
+        let graph =
+            self.graph.get_or_init(|| Box::new(CommandGraph::new(self.command_tree(None)).ok()));
+        graph.as_ref().map(|graph| graph.suggest(input)).unwrap_or_default()
+    }
+
+    /// Add a node and its children to a [`CommandTree`].
+    #[expect(clippy::cast_possible_truncation, reason = "Trees will never be that large")]
+    fn export(
+        &self,
+        index: usize,
+        source: Option<(Entity, &World)>,
+        nodes: &mut Vec<CommandNode>,
+        indices: &mut [Option<u32>],
+        redirects: &mut Vec<(u32, usize)>,
+    ) -> Option<u32> {
+        if !self.can_use(index, source) {
+            return None;
+        }
+
+        let data = &self.nodes[index];
+        let packet = nodes.len() as u32;
+        indices[index] = Some(packet);
+        nodes.push(CommandNode {
+            kind: match &data.kind {
+                NodeKind::Root => CommandNodeKind::Root,
+                NodeKind::Literal(name) => CommandNodeKind::Literal(name.to_string()),
+                NodeKind::Argument(name, parser) => {
+                    CommandNodeKind::Argument(name.to_string(), parser.argument_type.clone())
+                }
+            },
+            executable: matches!(data.command, Some(NodeCommand::System(_))),
+            restricted: data.restricted,
+            children: Vec::new(),
+            redirect: None,
+            suggestions: None,
+        });
+
+        if let Some(path) = &data.redirect
+            && let Some(target) = self.resolve(path)
+        {
+            redirects.push((packet, target));
+        }
+
+        // Commands using an `ArgumentBundle` are exported as a chain of arguments.
+        if let Some(NodeCommand::Bundle(_, arguments)) = &data.command {
+            let mut parent = packet as usize;
+            nodes[parent].executable = arguments.is_empty();
+            for (i, (name, argument)) in arguments.iter().enumerate() {
+                let child = nodes.len();
+                nodes[parent].children.push(child as u32);
+                nodes.push(CommandNode {
+                    kind: CommandNodeKind::Argument(name.to_string(), argument.clone()),
+                    executable: i + 1 == arguments.len(),
+                    restricted: data.restricted,
+                    children: Vec::new(),
+                    redirect: None,
+                    suggestions: None,
+                });
+                parent = child;
+            }
+        }
+
+        for &child in &data.children {
+            if let Some(child) = self.export(child, source, nodes, indices, redirects) {
+                nodes[packet as usize].children.push(child);
+            }
+        }
+
+        Some(packet)
+    }
 }
 
 /// An error that can occur while registering a command.
@@ -88,6 +630,15 @@ impl GameCommandSet {
 pub enum CommandRegisterError {
     /// A command with the same name already exists.
     AlreadyExists,
+    /// The number of argument names does not match the number of arguments.
+    ArgumentCount {
+        /// The number of arguments.
+        expected: usize,
+        /// The number of names given.
+        found: usize,
+    },
+    /// A node redirects to a path that does not exist.
+    InvalidRedirect(Cow<'static, str>),
 }
 
 impl Error for CommandRegisterError {}
@@ -97,6 +648,12 @@ impl fmt::Display for CommandRegisterError {
             CommandRegisterError::AlreadyExists => {
                 write!(f, "a command with the same name already exists")
             }
+            CommandRegisterError::ArgumentCount { expected, found } => {
+                write!(f, "expected {expected} argument names, found {found}")
+            }
+            CommandRegisterError::InvalidRedirect(path) => {
+                write!(f, "redirect to \"{path}\" does not exist")
+            }
         }
     }
 }
@@ -108,6 +665,8 @@ pub enum CommandExecuteError<'a> {
     CommandNotFound(Cow<'a, str>),
     /// An error occurred while parsing the command.
     Parse(ArgumentParseError<'a>),
+    /// The command ended before reaching an executable node.
+    Incomplete,
     /// An error occurred while executing the command.
     Execute(Box<dyn Error + Send + Sync>),
 }
@@ -122,6 +681,7 @@ impl CommandExecuteError<'_> {
                 CommandExecuteError::CommandNotFound(Cow::Owned(cmd.into_owned()))
             }
             CommandExecuteError::Parse(err) => CommandExecuteError::Parse(err.into_owned()),
+            CommandExecuteError::Incomplete => CommandExecuteError::Incomplete,
             CommandExecuteError::Execute(err) => CommandExecuteError::Execute(err),
         }
     }
@@ -140,6 +700,7 @@ impl fmt::Display for CommandExecuteError<'_> {
         match self {
             CommandExecuteError::CommandNotFound(cmd) => write!(f, "command \"{cmd}\" not found"),
             CommandExecuteError::Parse(err) => write!(f, "parsing error, {err}"),
+            CommandExecuteError::Incomplete => write!(f, "incomplete command"),
             CommandExecuteError::Execute(err) => write!(f, "execution error, {err}"),
         }
     }
@@ -147,6 +708,33 @@ impl fmt::Display for CommandExecuteError<'_> {
 
 // -------------------------------------------------------------------------------------------------
 
+/// A node stored in a [`GameCommandSet`].
+#[derive(Clone)]
+struct CommandNodeData {
+    kind: NodeKind,
+    children: Vec<usize>,
+    command: Option<NodeCommand>,
+    redirect: Option<Cow<'static, str>>,
+    requirement: Option<Requirement>,
+    restricted: bool,
+}
+
+/// The command run when parsing ends at a node.
+#[derive(Clone)]
+enum NodeCommand {
+    /// A system using [`CommandArguments`].
+    System(SystemId<GameCommandCtx<CommandArguments>, ()>),
+    /// A system using an [`ArgumentBundle`],
+    /// which parses the rest of the input itself.
+    Bundle(CommandInfo, Vec<(Cow<'static, str>, ArgumentType)>),
+}
+
+/// The result of parsing a command.
+enum Target<'a> {
+    System(SystemId<GameCommandCtx<CommandArguments>, ()>),
+    Bundle(CommandInfo, &'a str),
+}
+
 /// Information about a command in the [`GameCommandSet`].
 #[derive(Clone, Copy)]
 #[allow(clippy::type_complexity, reason = "dyn Fn trait")]
@@ -1,6 +1,7 @@
 //! TODO
 
-use core::ops::Deref;
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use core::{any::Any, fmt, ops::Deref};
 
 use bevy_ecs::{entity::Entity, system::SystemInput};
 
@@ -46,3 +47,73 @@ impl<T> Deref for GameCommandCtx<T> {
     #[inline]
     fn deref(&self) -> &Self::Target { self.input() }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+/// Arguments parsed from a command built from [`GameCommandNode`]s.
+///
+/// [`GameCommandNode`]: crate::node::GameCommandNode
+#[derive(Default)]
+pub struct CommandArguments(Vec<(Cow<'static, str>, Box<dyn Any>)>);
+
+impl CommandArguments {
+    /// Create a new, empty set of [`CommandArguments`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self { Self(Vec::new()) }
+
+    /// Add an argument with the given name.
+    #[inline]
+    pub fn push(&mut self, name: impl Into<Cow<'static, str>>, value: Box<dyn Any>) {
+        self.0.push((name.into(), value));
+    }
+
+    /// Remove the most recently added argument.
+    #[inline]
+    pub fn pop(&mut self) -> Option<(Cow<'static, str>, Box<dyn Any>)> { self.0.pop() }
+
+    /// Returns `true` if an argument with the given name exists.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool { self.0.iter().any(|(n, _)| n == name) }
+
+    /// Get a reference to the argument with the given name.
+    ///
+    /// If the argument was parsed more than once,
+    /// the most recent value is returned.
+    ///
+    /// Returns `None` if the argument does not exist or is not a `T`.
+    #[must_use]
+    pub fn get<T: 'static>(&self, name: &str) -> Option<&T> {
+        self.0.iter().rev().find(|(n, _)| n == name).and_then(|(_, v)| v.downcast_ref())
+    }
+
+    /// Take the argument with the given name.
+    ///
+    /// If the argument was parsed more than once,
+    /// the most recent value is taken.
+    ///
+    /// Returns `None` if the argument does not exist or is not a `T`.
+    pub fn take<T: 'static>(&mut self, name: &str) -> Option<T> {
+        let index = self.0.iter().rposition(|(n, v)| n == name && v.is::<T>())?;
+        self.0.remove(index).1.downcast().ok().map(|value| *value)
+    }
+
+    /// Get the number of arguments.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize { self.0.len() }
+
+    /// Returns `true` if there are no arguments.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool { self.0.is_empty() }
+
+    /// Iterate over the names of the arguments, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> { self.0.iter().map(|(n, _)| n.as_ref()) }
+}
+
+impl fmt::Debug for CommandArguments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
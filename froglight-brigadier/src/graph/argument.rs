@@ -0,0 +1,475 @@
+//! TODO
+
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use froglight_common::prelude::Identifier;
+use froglight_packet::common::commands::{ArgumentType, StringArgumentKind};
+
+use super::reader::{StringReader, is_identifier_char};
+
+/// An error that occurred while reading an argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgumentSyntaxError {
+    /// Expected `true` or `false`.
+    ExpectedBool,
+    /// Expected an integer.
+    ExpectedInteger,
+    /// Expected a decimal number.
+    ExpectedFloat,
+    /// The number was smaller than the minimum.
+    TooSmall,
+    /// The number was larger than the maximum.
+    TooBig,
+    /// Expected a value, but found nothing.
+    ExpectedValue,
+    /// A quoted string was not terminated.
+    UnclosedQuote,
+    /// A bracket was not closed.
+    UnclosedBracket,
+    /// Expected a set of coordinates.
+    ExpectedCoordinates,
+    /// Local (`^`) and world (`~`) coordinates were mixed.
+    MixedCoordinates,
+    /// The value is not a valid identifier.
+    InvalidIdentifier,
+    /// The value is not a valid entity selector.
+    InvalidSelector,
+    /// The selector may match more than one entity.
+    TooManyEntities,
+    /// The selector may match entities that are not players.
+    OnlyPlayers,
+    /// The value is not one of the allowed options.
+    InvalidOption,
+    /// The value is not a valid range.
+    InvalidRange,
+    /// The value is not a valid UUID.
+    InvalidUuid,
+    /// The value is not valid SNBT.
+    InvalidNbt,
+    /// The value is not a valid NBT path.
+    InvalidNbtPath,
+    /// The value does not exist.
+    UnknownValue,
+}
+
+impl Error for ArgumentSyntaxError {}
+impl Display for ArgumentSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgumentSyntaxError::ExpectedBool => f.write_str("expected \"true\" or \"false\""),
+            ArgumentSyntaxError::ExpectedInteger => f.write_str("expected an integer"),
+            ArgumentSyntaxError::ExpectedFloat => f.write_str("expected a number"),
+            ArgumentSyntaxError::TooSmall => f.write_str("number is below the minimum"),
+            ArgumentSyntaxError::TooBig => f.write_str("number is above the maximum"),
+            ArgumentSyntaxError::ExpectedValue => f.write_str("expected a value"),
+            ArgumentSyntaxError::UnclosedQuote => f.write_str("unclosed quoted string"),
+            ArgumentSyntaxError::UnclosedBracket => f.write_str("unclosed bracket"),
+            ArgumentSyntaxError::ExpectedCoordinates => f.write_str("expected coordinates"),
+            ArgumentSyntaxError::MixedCoordinates => {
+                f.write_str("cannot mix world and local coordinates")
+            }
+            ArgumentSyntaxError::InvalidIdentifier => f.write_str("invalid identifier"),
+            ArgumentSyntaxError::InvalidSelector => f.write_str("invalid entity selector"),
+            ArgumentSyntaxError::TooManyEntities => {
+                f.write_str("only one entity is allowed, but the selector allows more")
+            }
+            ArgumentSyntaxError::OnlyPlayers => {
+                f.write_str("only players are allowed, but the selector includes entities")
+            }
+            ArgumentSyntaxError::InvalidOption => f.write_str("invalid option"),
+            ArgumentSyntaxError::InvalidRange => f.write_str("invalid range"),
+            ArgumentSyntaxError::InvalidUuid => f.write_str("invalid UUID"),
+            ArgumentSyntaxError::InvalidNbt => f.write_str("invalid NBT"),
+            ArgumentSyntaxError::InvalidNbtPath => f.write_str("invalid NBT path"),
+            ArgumentSyntaxError::UnknownValue => f.write_str("unknown value"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+const COLORS: &[&str] = &[
+    "black",
+    "dark_blue",
+    "dark_green",
+    "dark_aqua",
+    "dark_red",
+    "dark_purple",
+    "gold",
+    "gray",
+    "dark_gray",
+    "blue",
+    "green",
+    "aqua",
+    "red",
+    "light_purple",
+    "yellow",
+    "white",
+    "reset",
+];
+const GAMEMODES: &[&str] = &["survival", "creative", "adventure", "spectator"];
+const ANCHORS: &[&str] = &["eyes", "feet"];
+const HEIGHTMAPS: &[&str] =
+    &["world_surface", "motion_blocking", "motion_blocking_no_leaves", "ocean_floor"];
+const MIRRORS: &[&str] = &["none", "front_back", "left_right"];
+const ROTATIONS: &[&str] = &["none", "clockwise_90", "counterclockwise_90", "180"];
+const OPERATIONS: &[&str] = &["=", "+=", "-=", "*=", "/=", "%=", "<", ">", "><"];
+const SELECTORS: &[&str] = &["@a", "@e", "@n", "@p", "@r", "@s"];
+const SLOTS: &[&str] = &["list", "sidebar", "below_name"];
+
+/// Get the suggestions that can be made locally for an [`ArgumentType`].
+#[must_use]
+pub(crate) fn local_suggestions(ty: &ArgumentType) -> &'static [&'static str] {
+    match ty {
+        ArgumentType::Bool => &["false", "true"],
+        ArgumentType::Entity { .. } | ArgumentType::GameProfile => SELECTORS,
+        ArgumentType::ScoreHolder { .. } => &["*", "@a", "@e", "@n", "@p", "@r", "@s"],
+        ArgumentType::BlockPos | ArgumentType::Vec3 => &["~", "~ ~", "~ ~ ~", "^", "^ ^", "^ ^ ^"],
+        ArgumentType::ColumnPos | ArgumentType::Vec2 | ArgumentType::Rotation => &["~", "~ ~"],
+        ArgumentType::Angle => &["~"],
+        ArgumentType::Color => COLORS,
+        ArgumentType::Gamemode => GAMEMODES,
+        ArgumentType::EntityAnchor => ANCHORS,
+        ArgumentType::Heightmap => HEIGHTMAPS,
+        ArgumentType::TemplateMirror => MIRRORS,
+        ArgumentType::TemplateRotation => ROTATIONS,
+        ArgumentType::Operation => OPERATIONS,
+        ArgumentType::ScoreboardSlot => SLOTS,
+        _ => &[],
+    }
+}
+
+/// Read an argument of the given [`ArgumentType`],
+/// advancing the reader past it.
+///
+/// This only checks the syntax of the argument,
+/// it does not check if the value refers to anything that exists.
+#[expect(clippy::too_many_lines, reason = "Large, multi-argument match statement")]
+pub(crate) fn read_argument(
+    ty: &ArgumentType,
+    reader: &mut StringReader<'_>,
+) -> Result<(), ArgumentSyntaxError> {
+    match ty {
+        ArgumentType::Bool => match reader.read_while(|c| c != ' ') {
+            "true" | "false" => Ok(()),
+            _ => Err(ArgumentSyntaxError::ExpectedBool),
+        },
+        ArgumentType::Integer { min, max } => {
+            read_integer(reader).and_then(|v| check_bounds(v, *min, *max))
+        }
+        ArgumentType::Long { min, max } => {
+            read_integer(reader).and_then(|v| check_bounds(v, *min, *max))
+        }
+        ArgumentType::Float { min, max } => {
+            read_float(reader).and_then(|v| check_bounds(v, min.map(f64::from), max.map(f64::from)))
+        }
+        ArgumentType::Double { min, max } => {
+            read_float(reader).and_then(|v| check_bounds(v, *min, *max))
+        }
+        ArgumentType::String(StringArgumentKind::SingleWord) => {
+            reader.read_unquoted();
+            Ok(())
+        }
+        ArgumentType::String(StringArgumentKind::QuotablePhrase) => read_string(reader),
+        ArgumentType::String(StringArgumentKind::GreedyPhrase) | ArgumentType::Message => {
+            reader.set_cursor(reader.input().len());
+            Ok(())
+        }
+
+        ArgumentType::Entity { single, players_only } => {
+            read_entity(reader, *single, *players_only, false)
+        }
+        ArgumentType::GameProfile => read_entity(reader, false, true, false),
+        ArgumentType::ScoreHolder { multiple } => read_entity(reader, !multiple, false, true),
+
+        ArgumentType::BlockPos => read_coordinates(reader, 3, true, true),
+        ArgumentType::Vec3 => read_coordinates(reader, 3, false, true),
+        ArgumentType::ColumnPos => read_coordinates(reader, 2, true, false),
+        ArgumentType::Vec2 | ArgumentType::Rotation => read_coordinates(reader, 2, false, false),
+        ArgumentType::Angle => read_coordinates(reader, 1, false, false),
+
+        ArgumentType::BlockState | ArgumentType::ItemStack | ArgumentType::Particle => {
+            read_identifier(reader, false)?;
+            read_balanced(reader)
+        }
+        ArgumentType::BlockPredicate | ArgumentType::ItemPredicate => {
+            read_identifier(reader, true)?;
+            read_balanced(reader)
+        }
+        ArgumentType::Component
+        | ArgumentType::Style
+        | ArgumentType::NbtCompoundTag
+        | ArgumentType::NbtTag
+        | ArgumentType::NbtPath => {
+            if reader.read_balanced().ok_or(ArgumentSyntaxError::UnclosedBracket)?.is_empty() {
+                Err(ArgumentSyntaxError::ExpectedValue)
+            } else {
+                Ok(())
+            }
+        }
+
+        ArgumentType::ResourceLocation
+        | ArgumentType::Dimension
+        | ArgumentType::Resource { .. }
+        | ArgumentType::ResourceKey { .. }
+        | ArgumentType::LootTable
+        | ArgumentType::LootPredicate
+        | ArgumentType::LootModifier
+        | ArgumentType::Dialog => read_identifier(reader, false),
+        ArgumentType::Function
+        | ArgumentType::ResourceOrTag { .. }
+        | ArgumentType::ResourceOrTagKey { .. } => read_identifier(reader, true),
+        ArgumentType::ResourceSelector { .. } => {
+            if reader.read_while(|c| is_identifier_char(c) || c == '*' || c == '?').is_empty() {
+                Err(ArgumentSyntaxError::InvalidIdentifier)
+            } else {
+                Ok(())
+            }
+        }
+
+        ArgumentType::Color => read_option(reader, COLORS),
+        ArgumentType::Gamemode => read_option(reader, GAMEMODES),
+        ArgumentType::EntityAnchor => read_option(reader, ANCHORS),
+        ArgumentType::Heightmap => read_option(reader, HEIGHTMAPS),
+        ArgumentType::TemplateMirror => read_option(reader, MIRRORS),
+        ArgumentType::TemplateRotation => read_option(reader, ROTATIONS),
+        ArgumentType::Operation => read_option(reader, OPERATIONS),
+        ArgumentType::HexColor => {
+            let hex = reader.read_while(|c| c.is_ascii_alphanumeric());
+            if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                Ok(())
+            } else {
+                Err(ArgumentSyntaxError::InvalidOption)
+            }
+        }
+        ArgumentType::Swizzle => {
+            let swizzle = reader.read_while(|c| c != ' ');
+            let mut seen = [false; 3];
+            for c in swizzle.chars() {
+                let index = match c {
+                    'x' => 0,
+                    'y' => 1,
+                    'z' => 2,
+                    _ => return Err(ArgumentSyntaxError::InvalidOption),
+                };
+                if core::mem::replace(&mut seen[index], true) {
+                    return Err(ArgumentSyntaxError::InvalidOption);
+                }
+            }
+            if swizzle.is_empty() { Err(ArgumentSyntaxError::InvalidOption) } else { Ok(()) }
+        }
+
+        ArgumentType::Objective
+        | ArgumentType::ObjectiveCriteria
+        | ArgumentType::ScoreboardSlot
+        | ArgumentType::Team
+        | ArgumentType::ItemSlot
+        | ArgumentType::ItemSlots => {
+            if reader.read_while(|c| c != ' ').is_empty() {
+                Err(ArgumentSyntaxError::ExpectedValue)
+            } else {
+                Ok(())
+            }
+        }
+
+        ArgumentType::IntRange => read_range(reader, false),
+        ArgumentType::FloatRange => read_range(reader, true),
+        ArgumentType::Time { min } => {
+            let value = read_float(reader)?;
+            let scale = match reader.peek() {
+                Some('d') => 24000.0,
+                Some('s') => 20.0,
+                Some('t') => 1.0,
+                _ => return check_bounds(value, Some(f64::from(*min)), None),
+            };
+            reader.next();
+            check_bounds(value * scale, Some(f64::from(*min)), None)
+        }
+        ArgumentType::Uuid => {
+            let uuid = reader.read_while(|c| c.is_ascii_hexdigit() || c == '-');
+            let mut parts = 0;
+            for part in uuid.split('-') {
+                if part.is_empty() || part.len() > 16 {
+                    return Err(ArgumentSyntaxError::InvalidUuid);
+                }
+                parts += 1;
+            }
+            if parts == 5 { Ok(()) } else { Err(ArgumentSyntaxError::InvalidUuid) }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn check_bounds<T: PartialOrd>(
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), ArgumentSyntaxError> {
+    if min.is_some_and(|min| value < min) {
+        Err(ArgumentSyntaxError::TooSmall)
+    } else if max.is_some_and(|max| value > max) {
+        Err(ArgumentSyntaxError::TooBig)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn read_integer<T: lexical::FromLexical>(
+    reader: &mut StringReader<'_>,
+) -> Result<T, ArgumentSyntaxError> {
+    let start = reader.cursor();
+    let number = reader.read_number();
+    lexical::parse::<T, _>(number).map_err(|_| {
+        reader.set_cursor(start);
+        ArgumentSyntaxError::ExpectedInteger
+    })
+}
+
+pub(crate) fn read_float(reader: &mut StringReader<'_>) -> Result<f64, ArgumentSyntaxError> {
+    let start = reader.cursor();
+    let number = reader.read_number();
+    lexical::parse::<f64, _>(number).map_err(|_| {
+        reader.set_cursor(start);
+        ArgumentSyntaxError::ExpectedFloat
+    })
+}
+
+fn read_string(reader: &mut StringReader<'_>) -> Result<(), ArgumentSyntaxError> {
+    if matches!(reader.peek(), Some('"' | '\'')) {
+        reader.read_quoted().map(|_| ()).ok_or(ArgumentSyntaxError::UnclosedQuote)
+    } else {
+        reader.read_unquoted();
+        Ok(())
+    }
+}
+
+fn read_option(reader: &mut StringReader<'_>, options: &[&str]) -> Result<(), ArgumentSyntaxError> {
+    let start = reader.cursor();
+    if options.contains(&reader.read_word()) {
+        Ok(())
+    } else {
+        reader.set_cursor(start);
+        Err(ArgumentSyntaxError::InvalidOption)
+    }
+}
+
+fn read_identifier(reader: &mut StringReader<'_>, tag: bool) -> Result<(), ArgumentSyntaxError> {
+    let start = reader.cursor();
+    if tag {
+        reader.eat('#');
+    }
+    let identifier = reader.read_while(is_identifier_char);
+    if Identifier::try_new(identifier).is_err() {
+        reader.set_cursor(start);
+        return Err(ArgumentSyntaxError::InvalidIdentifier);
+    }
+    Ok(())
+}
+
+fn read_balanced(reader: &mut StringReader<'_>) -> Result<(), ArgumentSyntaxError> {
+    if matches!(reader.peek(), Some('[' | '{')) {
+        reader.read_balanced().map(|_| ()).ok_or(ArgumentSyntaxError::UnclosedBracket)
+    } else {
+        Ok(())
+    }
+}
+
+fn read_coordinates(
+    reader: &mut StringReader<'_>,
+    count: usize,
+    integer: bool,
+    allow_local: bool,
+) -> Result<(), ArgumentSyntaxError> {
+    let mut local = None;
+    for index in 0..count {
+        if index != 0 && !reader.eat(' ') {
+            return Err(ArgumentSyntaxError::ExpectedCoordinates);
+        }
+
+        let is_local = match reader.peek() {
+            Some('^') if allow_local => true,
+            Some('^') | None => return Err(ArgumentSyntaxError::ExpectedCoordinates),
+            Some(_) => false,
+        };
+        if *local.get_or_insert(is_local) != is_local {
+            return Err(ArgumentSyntaxError::MixedCoordinates);
+        }
+
+        if reader.eat('^') || reader.eat('~') {
+            if matches!(reader.peek(), Some(c) if c != ' ') {
+                read_float(reader)?;
+            }
+        } else if integer {
+            read_integer::<i32>(reader)?;
+        } else {
+            read_float(reader)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_range(reader: &mut StringReader<'_>, float: bool) -> Result<(), ArgumentSyntaxError> {
+    let range = reader.read_while(|c| c != ' ');
+    let (min, max) = range.split_once("..").unwrap_or((range, range));
+    if min.is_empty() && max.is_empty() {
+        return Err(ArgumentSyntaxError::InvalidRange);
+    }
+
+    let valid = |value: &str| {
+        value.is_empty()
+            || if float {
+                lexical::parse::<f64, _>(value).is_ok()
+            } else {
+                lexical::parse::<i64, _>(value).is_ok()
+            }
+    };
+    if valid(min) && valid(max) { Ok(()) } else { Err(ArgumentSyntaxError::InvalidRange) }
+}
+
+fn read_entity(
+    reader: &mut StringReader<'_>,
+    single: bool,
+    players_only: bool,
+    wildcard: bool,
+) -> Result<(), ArgumentSyntaxError> {
+    if wildcard && reader.eat('*') {
+        return if single { Err(ArgumentSyntaxError::TooManyEntities) } else { Ok(()) };
+    }
+
+    if !reader.eat('@') {
+        // A player name or UUID
+        return if reader.read_while(|c| c != ' ').is_empty() {
+            Err(ArgumentSyntaxError::InvalidSelector)
+        } else {
+            Ok(())
+        };
+    }
+
+    let start = reader.cursor();
+    let kind = reader.next().ok_or(ArgumentSyntaxError::InvalidSelector)?;
+    if !matches!(kind, 'a' | 'e' | 'n' | 'p' | 'r' | 's') {
+        reader.set_cursor(start);
+        return Err(ArgumentSyntaxError::InvalidSelector);
+    }
+
+    let filters = if reader.peek() == Some('[') {
+        reader.read_balanced().ok_or(ArgumentSyntaxError::UnclosedBracket)?
+    } else {
+        ""
+    };
+
+    if single && matches!(kind, 'a' | 'e') && !filters.contains("limit=1") {
+        Err(ArgumentSyntaxError::TooManyEntities)
+    } else if players_only
+        && kind == 'e'
+        && !(filters.contains("type=player") || filters.contains("type=minecraft:player"))
+    {
+        Err(ArgumentSyntaxError::OnlyPlayers)
+    } else {
+        Ok(())
+    }
+}
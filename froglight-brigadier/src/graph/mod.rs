@@ -0,0 +1,373 @@
+//! TODO
+
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+};
+
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::Reflect;
+use froglight_packet::common::commands::{CommandNode, CommandNodeKind, CommandTree};
+
+pub(crate) mod argument;
+pub use argument::ArgumentSyntaxError;
+
+pub(crate) mod reader;
+use reader::StringReader;
+
+mod suggest;
+pub use suggest::{Suggestion, SuggestionRequests, Suggestions};
+
+/// A validated graph of commands received from a server.
+///
+/// Used to check and tokenize commands before they are sent,
+/// and to provide tab completion.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(opaque, Debug, Clone, PartialEq, Component)]
+pub struct CommandGraph {
+    tree: CommandTree,
+}
+
+impl CommandGraph {
+    /// Create a new [`CommandGraph`] from a [`CommandTree`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tree references nodes that do not exist,
+    /// or if the root node is not a [`CommandNodeKind::Root`].
+    pub fn new(tree: CommandTree) -> Result<Self, CommandGraphError> {
+        let Some(root) = tree.root() else {
+            return Err(CommandGraphError::MissingRoot(tree.root));
+        };
+        if root.kind != CommandNodeKind::Root {
+            return Err(CommandGraphError::InvalidRoot(tree.root));
+        }
+
+        let len = tree.nodes.len();
+        for (index, node) in (0u32..).zip(&tree.nodes) {
+            for &child in &node.children {
+                match tree.get(child) {
+                    None => return Err(CommandGraphError::InvalidChild { node: index, child }),
+                    Some(child_node) if child_node.kind == CommandNodeKind::Root => {
+                        return Err(CommandGraphError::InvalidChild { node: index, child });
+                    }
+                    Some(_) => {}
+                }
+            }
+            if let Some(redirect) = node.redirect
+                && redirect as usize >= len
+            {
+                return Err(CommandGraphError::InvalidRedirect { node: index, redirect });
+            }
+        }
+
+        Ok(Self { tree })
+    }
+
+    /// Get the underlying [`CommandTree`].
+    #[inline]
+    #[must_use]
+    pub const fn tree(&self) -> &CommandTree { &self.tree }
+
+    /// Get the index of the root node.
+    #[inline]
+    #[must_use]
+    pub const fn root(&self) -> u32 { self.tree.root }
+
+    /// Get the [`CommandNode`] at the given index.
+    #[inline]
+    #[must_use]
+    pub fn node(&self, index: u32) -> Option<&CommandNode> { self.tree.get(index) }
+
+    /// Iterate over the children of the node at the given index.
+    pub fn children(&self, index: u32) -> impl Iterator<Item = (u32, &CommandNode)> {
+        self.node(index)
+            .into_iter()
+            .flat_map(|node| node.children.iter().filter_map(|&c| Some((c, self.node(c)?))))
+    }
+
+    /// Returns `true` if a top-level command with the given name exists.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.children(self.root()).any(|(_, node)| node.name() == Some(name))
+    }
+
+    /// Parse a command, without the leading `/`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command does not match the graph,
+    /// or if it does not end at an executable node.
+    pub fn parse<'a>(&'a self, input: &'a str) -> Result<ParsedCommand<'a>, CommandSyntaxError> {
+        let mut tokens = Vec::new();
+        self.parse_node(self.root(), StringReader::new(input, 0), &mut tokens)?;
+        Ok(ParsedCommand { input, tokens })
+    }
+
+    /// Returns `Ok(())` if the command is valid, without the leading `/`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command does not match the graph.
+    pub fn validate(&self, input: &str) -> Result<(), CommandSyntaxError> {
+        self.parse(input).map(|_| ())
+    }
+
+    /// Try to parse the children of a node, keeping the error that got the
+    /// furthest into the input if none of them match.
+    fn parse_node<'a>(
+        &'a self,
+        index: u32,
+        reader: StringReader<'a>,
+        tokens: &mut Vec<ParsedToken<'a>>,
+    ) -> Result<(), CommandSyntaxError> {
+        // If a literal matches the next word, only that literal is considered.
+        let word = reader.remaining().split(' ').next().unwrap_or_default();
+        let literal = self
+            .children(index)
+            .find(|(_, node)| matches!(&node.kind, CommandNodeKind::Literal(name) if name == word));
+
+        let mut error: Option<CommandSyntaxError> = None;
+        let mut record = |err: CommandSyntaxError| {
+            if error.as_ref().is_none_or(|prev| err.position > prev.position) {
+                error = Some(err);
+            }
+        };
+
+        let candidates = literal.into_iter().chain(
+            self.children(index)
+                .filter(|_| literal.is_none())
+                .filter(|(_, node)| matches!(node.kind, CommandNodeKind::Argument(..))),
+        );
+
+        for (child, node) in candidates {
+            let mut reader = reader;
+            let start = reader.cursor();
+
+            match &node.kind {
+                CommandNodeKind::Root => continue,
+                CommandNodeKind::Literal(name) => reader.set_cursor(start + name.len()),
+                CommandNodeKind::Argument(_, ty) => {
+                    if let Err(err) = argument::read_argument(ty, &mut reader) {
+                        record(CommandSyntaxError::new(
+                            SyntaxErrorKind::InvalidArgument { node: child, error: err },
+                            reader.cursor(),
+                        ));
+                        continue;
+                    }
+                }
+            }
+
+            if reader.can_read() && reader.peek() != Some(' ') {
+                record(CommandSyntaxError::new(
+                    SyntaxErrorKind::ExpectedSeparator,
+                    reader.cursor(),
+                ));
+                continue;
+            }
+
+            let end = reader.cursor();
+            tokens.push(ParsedToken {
+                node: child,
+                name: node.name().unwrap_or_default(),
+                text: &reader.input()[start..end],
+                range: start..end,
+            });
+
+            if reader.can_read() {
+                reader.set_cursor(end + 1);
+                if reader.can_read() {
+                    let next = node.redirect.unwrap_or(child);
+                    match self.parse_node(next, reader, tokens) {
+                        Ok(()) => return Ok(()),
+                        Err(err) => record(err),
+                    }
+                } else {
+                    record(CommandSyntaxError::new(SyntaxErrorKind::IncorrectArgument, end));
+                }
+            } else if node.executable {
+                return Ok(());
+            } else {
+                record(CommandSyntaxError::new(SyntaxErrorKind::IncompleteCommand, end));
+            }
+
+            tokens.pop();
+        }
+
+        Err(error.unwrap_or_else(|| {
+            let kind = if index == self.root() {
+                SyntaxErrorKind::UnknownCommand
+            } else {
+                SyntaxErrorKind::IncorrectArgument
+            };
+            CommandSyntaxError::new(kind, reader.cursor())
+        }))
+    }
+}
+
+impl TryFrom<CommandTree> for CommandGraph {
+    type Error = CommandGraphError;
+
+    fn try_from(tree: CommandTree) -> Result<Self, Self::Error> { Self::new(tree) }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A command that was successfully parsed by a [`CommandGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedCommand<'a> {
+    input: &'a str,
+    tokens: Vec<ParsedToken<'a>>,
+}
+
+impl<'a> ParsedCommand<'a> {
+    /// Get the input that was parsed.
+    #[inline]
+    #[must_use]
+    pub const fn input(&self) -> &'a str { self.input }
+
+    /// Get the tokens of the command, in order.
+    #[inline]
+    #[must_use]
+    pub fn tokens(&self) -> &[ParsedToken<'a>] { &self.tokens }
+
+    /// Get the text of the argument with the given name.
+    ///
+    /// If the argument appears more than once, the last one is returned.
+    #[must_use]
+    pub fn argument(&self, name: &str) -> Option<&'a str> {
+        self.tokens.iter().rev().find(|token| token.name == name).map(|token| token.text)
+    }
+}
+
+/// A single token of a [`ParsedCommand`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedToken<'a> {
+    /// The index of the node that matched this token.
+    pub node: u32,
+    /// The name of the node that matched this token.
+    pub name: &'a str,
+    /// The text of the token.
+    pub text: &'a str,
+    /// The position of the token in the input.
+    pub range: Range<usize>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An error that occurred while building a [`CommandGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandGraphError {
+    /// The root node does not exist.
+    MissingRoot(u32),
+    /// The root node is not a [`CommandNodeKind::Root`].
+    InvalidRoot(u32),
+    /// A node has a child that does not exist or is a root node.
+    InvalidChild {
+        /// The index of the parent node.
+        node: u32,
+        /// The index of the child node.
+        child: u32,
+    },
+    /// A node redirects to a node that does not exist.
+    InvalidRedirect {
+        /// The index of the redirecting node.
+        node: u32,
+        /// The index of the redirect target.
+        redirect: u32,
+    },
+}
+
+impl Error for CommandGraphError {}
+impl Display for CommandGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandGraphError::MissingRoot(root) => write!(f, "root node {root} does not exist"),
+            CommandGraphError::InvalidRoot(root) => write!(f, "node {root} is not a root node"),
+            CommandGraphError::InvalidChild { node, child } => {
+                write!(f, "node {node} has an invalid child {child}")
+            }
+            CommandGraphError::InvalidRedirect { node, redirect } => {
+                write!(f, "node {node} has an invalid redirect {redirect}")
+            }
+        }
+    }
+}
+
+/// An error that occurred while parsing a command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandSyntaxError {
+    /// The kind of error.
+    pub kind: SyntaxErrorKind,
+    /// The position in the input where the error occurred.
+    pub position: usize,
+}
+
+/// The kind of [`CommandSyntaxError`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyntaxErrorKind {
+    /// No command with the given name exists.
+    UnknownCommand,
+    /// The input did not match any argument.
+    IncorrectArgument,
+    /// The input ended before the command was complete.
+    IncompleteCommand,
+    /// Expected a space to separate arguments.
+    ExpectedSeparator,
+    /// An argument could not be read.
+    InvalidArgument {
+        /// The index of the argument node.
+        node: u32,
+        /// The reason the argument was invalid.
+        error: ArgumentSyntaxError,
+    },
+}
+
+impl CommandSyntaxError {
+    /// The maximum number of characters shown before the error position.
+    const CONTEXT_LENGTH: usize = 10;
+
+    /// Create a new [`CommandSyntaxError`].
+    #[must_use]
+    pub const fn new(kind: SyntaxErrorKind, position: usize) -> Self { Self { kind, position } }
+
+    /// Get the input leading up to the error, marked with `<--[HERE]`.
+    #[must_use]
+    pub fn context(&self, input: &str) -> alloc::string::String {
+        let position = self.position.min(input.len());
+        let mut start = position.saturating_sub(Self::CONTEXT_LENGTH);
+        while !input.is_char_boundary(start) {
+            start -= 1;
+        }
+
+        let mut context = if start > 0 { "...".to_owned() } else { alloc::string::String::new() };
+        context.push_str(&input[start..position]);
+        context.push_str("<--[HERE]");
+        context
+    }
+}
+
+impl Error for CommandSyntaxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            SyntaxErrorKind::InvalidArgument { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+impl Display for CommandSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            SyntaxErrorKind::UnknownCommand => f.write_str("unknown command")?,
+            SyntaxErrorKind::IncorrectArgument => f.write_str("incorrect argument for command")?,
+            SyntaxErrorKind::IncompleteCommand => f.write_str("incomplete command")?,
+            SyntaxErrorKind::ExpectedSeparator => {
+                f.write_str("expected whitespace to end one argument")?;
+            }
+            SyntaxErrorKind::InvalidArgument { error, .. } => Display::fmt(error, f)?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
@@ -0,0 +1,161 @@
+//! TODO
+
+/// A cursor over a command string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct StringReader<'a> {
+    input: &'a str,
+    cursor: usize,
+}
+
+impl<'a> StringReader<'a> {
+    /// Create a new [`StringReader`] starting at the given position.
+    #[must_use]
+    pub(crate) const fn new(input: &'a str, cursor: usize) -> Self { Self { input, cursor } }
+
+    /// Get the full input string.
+    #[must_use]
+    pub(crate) const fn input(&self) -> &'a str { self.input }
+
+    /// Get the current position of the cursor.
+    #[must_use]
+    pub(crate) const fn cursor(&self) -> usize { self.cursor }
+
+    /// Set the position of the cursor.
+    pub(crate) const fn set_cursor(&mut self, cursor: usize) { self.cursor = cursor; }
+
+    /// Get the unread part of the input.
+    #[must_use]
+    pub(crate) fn remaining(&self) -> &'a str { &self.input[self.cursor..] }
+
+    /// Returns `true` if there are characters left to read.
+    #[must_use]
+    pub(crate) const fn can_read(&self) -> bool { self.cursor < self.input.len() }
+
+    /// Peek at the next character without consuming it.
+    #[must_use]
+    pub(crate) fn peek(&self) -> Option<char> { self.remaining().chars().next() }
+
+    /// Consume the next character.
+    pub(crate) fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.cursor += c.len_utf8();
+        Some(c)
+    }
+
+    /// Consume the next character if it matches `c`.
+    pub(crate) fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.cursor += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume characters while they match the predicate,
+    /// returning the consumed slice.
+    pub(crate) fn read_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> &'a str {
+        let start = self.cursor;
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+            self.cursor += c.len_utf8();
+        }
+        &self.input[start..self.cursor]
+    }
+
+    /// Read characters allowed in an unquoted string.
+    pub(crate) fn read_unquoted(&mut self) -> &'a str { self.read_while(is_unquoted_char) }
+
+    /// Read everything up to the next space.
+    pub(crate) fn read_word(&mut self) -> &'a str { self.read_while(|c| c != ' ') }
+
+    /// Read characters that may be part of a number.
+    pub(crate) fn read_number(&mut self) -> &'a str {
+        self.read_while(|c| c.is_ascii_digit() || matches!(c, '-' | '.'))
+    }
+
+    /// Read a quoted string, assuming the cursor is on the opening quote.
+    ///
+    /// Returns `None` if the string is not terminated.
+    pub(crate) fn read_quoted(&mut self) -> Option<&'a str> {
+        let start = self.cursor;
+        let quote = self.next()?;
+        let mut escaped = false;
+        while let Some(c) = self.next() {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == quote {
+                return Some(&self.input[start..self.cursor]);
+            }
+        }
+        None
+    }
+
+    /// Read a token that may contain balanced brackets and quoted strings,
+    /// stopping at the first space outside of them.
+    ///
+    /// Returns `None` if a bracket or quote is not closed.
+    pub(crate) fn read_balanced(&mut self) -> Option<&'a str> {
+        let start = self.cursor;
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                ' ' if depth == 0 => break,
+                '"' | '\'' => {
+                    self.read_quoted()?;
+                    continue;
+                }
+                '[' | '{' | '(' => depth += 1,
+                ']' | '}' | ')' => depth = depth.checked_sub(1)?,
+                _ => {}
+            }
+            self.cursor += c.len_utf8();
+        }
+        (depth == 0).then(|| &self.input[start..self.cursor])
+    }
+
+    /// Read a single bracketed group, assuming the cursor is on the opening
+    /// bracket, including any nested brackets and quoted strings.
+    ///
+    /// Returns `None` if the bracket is not closed.
+    #[cfg(any(feature = "froglight-item", feature = "froglight-snbt"))]
+    pub(crate) fn read_bracketed(&mut self) -> Option<&'a str> {
+        let start = self.cursor;
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                '"' | '\'' => {
+                    self.read_quoted()?;
+                    continue;
+                }
+                '[' | '{' | '(' => depth += 1,
+                ']' | '}' | ')' => depth = depth.checked_sub(1)?,
+                _ => {}
+            }
+            self.cursor += c.len_utf8();
+            if depth == 0 {
+                return Some(&self.input[start..self.cursor]);
+            }
+        }
+        None
+    }
+
+    /// Skip any spaces.
+    pub(crate) fn skip_whitespace(&mut self) { self.read_while(|c| c == ' '); }
+}
+
+/// Returns `true` if the character is allowed in an unquoted string.
+#[must_use]
+pub(crate) const fn is_unquoted_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+/// Returns `true` if the character is allowed in an identifier.
+#[must_use]
+pub(crate) const fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.' | ':' | '/')
+}
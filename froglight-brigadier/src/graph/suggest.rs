@@ -0,0 +1,231 @@
+//! TODO
+
+use alloc::{format, string::String, vec::Vec};
+use core::ops::Range;
+
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::commands::{
+    CommandNodeKind, CommandSuggestionsContent, SuggestionTooltip,
+};
+
+use super::{CommandGraph, argument, reader::StringReader};
+
+/// A set of suggestions for completing a command.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Suggestions {
+    /// The part of the input the suggestions replace.
+    pub range: Range<usize>,
+    /// The suggested replacements, in order.
+    pub entries: Vec<Suggestion>,
+    /// Whether suggestions should also be requested from the server.
+    pub ask_server: bool,
+}
+
+/// A single suggestion of [`Suggestions`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    /// The text to replace the range with.
+    pub text: String,
+    /// An optional tooltip to show alongside the suggestion.
+    pub tooltip: Option<SuggestionTooltip>,
+}
+
+impl Suggestions {
+    /// Returns `true` if there are no suggestions.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    /// Apply a [`Suggestion`] to the input, returning the completed string.
+    #[must_use]
+    pub fn apply(&self, input: &str, suggestion: &Suggestion) -> String {
+        let start = self.range.start.min(input.len());
+        let end = self.range.end.clamp(start, input.len());
+        format!("{}{}{}", &input[..start], suggestion.text, &input[end..])
+    }
+
+    /// Create [`Suggestions`] from a server response.
+    ///
+    /// Server ranges include the leading `/` of the request,
+    /// which is removed so they line up with the original input.
+    #[must_use]
+    pub fn from_server(content: &CommandSuggestionsContent) -> Self {
+        let start = (content.start as usize).saturating_sub(1);
+        Self {
+            range: start..start + content.length as usize,
+            entries: content
+                .matches
+                .iter()
+                .map(|m| Suggestion { text: m.text.clone(), tooltip: m.tooltip.clone() })
+                .collect(),
+            ask_server: false,
+        }
+    }
+
+    /// Merge another set of [`Suggestions`] into this one,
+    /// widening the range so both sets replace the same part of the input.
+    pub fn merge(&mut self, input: &str, other: Suggestions) {
+        if other.entries.is_empty() {
+            self.ask_server |= other.ask_server;
+            return;
+        }
+        if self.entries.is_empty() {
+            let ask_server = self.ask_server | other.ask_server;
+            *self = other;
+            self.ask_server = ask_server;
+            return;
+        }
+
+        let start = self.range.start.min(other.range.start);
+        let end = self.range.end.max(other.range.end);
+        let widen = |range: &Range<usize>, mut entry: Suggestion| {
+            entry.text = format!(
+                "{}{}{}",
+                input.get(start..range.start).unwrap_or_default(),
+                entry.text,
+                input.get(range.end..end).unwrap_or_default()
+            );
+            entry
+        };
+
+        let mut entries: Vec<Suggestion> =
+            self.entries.drain(..).map(|entry| widen(&self.range, entry)).collect();
+        for entry in other.entries {
+            let entry = widen(&other.range, entry);
+            if !entries.iter().any(|e| e.text == entry.text) {
+                entries.push(entry);
+            }
+        }
+
+        self.range = start..end;
+        self.entries = entries;
+        self.ask_server |= other.ask_server;
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl CommandGraph {
+    /// Get suggestions for completing a command, without the leading `/`.
+    ///
+    /// Only suggestions that can be made locally are included,
+    /// if any node requires suggestions from the server
+    /// [`Suggestions::ask_server`] will be set.
+    #[must_use]
+    pub fn suggest(&self, input: &str) -> Suggestions {
+        let mut candidates = Vec::new();
+        let mut ask_server = false;
+        self.collect_suggestions(
+            self.root(),
+            StringReader::new(input, 0),
+            &mut candidates,
+            &mut ask_server,
+        );
+
+        let start = candidates.iter().map(|(start, _)| *start).min().unwrap_or(input.len());
+        let mut entries: Vec<Suggestion> = candidates
+            .into_iter()
+            .map(|(pos, text)| Suggestion {
+                text: format!("{}{text}", &input[start..pos]),
+                tooltip: None,
+            })
+            .collect();
+        entries.sort_by(|a, b| a.text.cmp(&b.text));
+        entries.dedup_by(|a, b| a.text == b.text);
+
+        Suggestions { range: start..input.len(), entries, ask_server }
+    }
+
+    fn collect_suggestions<'a>(
+        &'a self,
+        index: u32,
+        reader: StringReader<'_>,
+        candidates: &mut Vec<(usize, &'a str)>,
+        ask_server: &mut bool,
+    ) {
+        let start = reader.cursor();
+        let remaining = reader.remaining();
+
+        for (child, node) in self.children(index) {
+            let next = node.redirect.unwrap_or(child);
+            match &node.kind {
+                CommandNodeKind::Root => {}
+                CommandNodeKind::Literal(name) => {
+                    if name.starts_with(remaining) {
+                        candidates.push((start, name.as_str()));
+                    } else if remaining.starts_with(name.as_str())
+                        && remaining.as_bytes().get(name.len()) == Some(&b' ')
+                    {
+                        let mut reader = reader;
+                        reader.set_cursor(start + name.len() + 1);
+                        self.collect_suggestions(next, reader, candidates, ask_server);
+                    }
+                }
+                CommandNodeKind::Argument(_, ty) => {
+                    let mut reader = reader;
+                    if argument::read_argument(ty, &mut reader).is_ok()
+                        && reader.peek() == Some(' ')
+                    {
+                        reader.set_cursor(reader.cursor() + 1);
+                        self.collect_suggestions(next, reader, candidates, ask_server);
+                        continue;
+                    }
+
+                    *ask_server |= node.asks_server();
+                    for &suggestion in argument::local_suggestions(ty) {
+                        if suggestion.starts_with(remaining) {
+                            candidates.push((start, suggestion));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Tracks suggestion requests sent to the server.
+///
+/// Only the most recent request is kept,
+/// responses to older requests are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Component, Reflect)]
+#[reflect(Debug, Default, Clone, PartialEq, Component)]
+pub struct SuggestionRequests {
+    next_id: u32,
+    pending: Option<(u32, String)>,
+}
+
+impl SuggestionRequests {
+    /// Start a new request for the given input, without the leading `/`.
+    ///
+    /// Returns the transaction id and the text to send to the server.
+    pub fn request(&mut self, input: &str) -> (u32, String) {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.pending = Some((id, String::from(input)));
+        (id, format!("/{input}"))
+    }
+
+    /// Get the pending request, if any.
+    #[must_use]
+    pub fn pending(&self) -> Option<(u32, &str)> {
+        self.pending.as_ref().map(|(id, input)| (*id, input.as_str()))
+    }
+
+    /// Resolve a response from the server.
+    ///
+    /// Returns the input of the matching request and its [`Suggestions`],
+    /// or `None` if the response does not match the pending request.
+    pub fn resolve(
+        &mut self,
+        content: &CommandSuggestionsContent,
+    ) -> Option<(String, Suggestions)> {
+        if self.pending.as_ref().is_none_or(|(id, _)| *id != content.id) {
+            return None;
+        }
+        let (_, input) = self.pending.take()?;
+        Some((input, Suggestions::from_server(content)))
+    }
+}
@@ -13,6 +13,8 @@ pub mod bevy;
 pub mod bundle;
 pub mod commandset;
 pub mod context;
+pub mod graph;
+pub mod node;
 pub mod traits;
 
 pub mod prelude {
@@ -20,7 +22,9 @@ pub mod prelude {
 
     pub use crate::{
         commandset::GameCommandSet,
-        context::GameCommandCtx,
+        context::{CommandArguments, GameCommandCtx},
+        graph::{CommandGraph, SuggestionRequests},
+        node::GameCommandNode,
         traits::{AppGameCommand, CommandsGameCommand, EntityCommandsGameCommand},
     };
 }
@@ -0,0 +1,234 @@
+//! TODO
+
+use alloc::{borrow::Cow, boxed::Box, sync::Arc, vec::Vec};
+use core::{any::Any, fmt};
+
+use bevy_ecs::{
+    prelude::*,
+    system::{BoxedSystem, SystemId},
+};
+use froglight_packet::common::commands::ArgumentType;
+
+use crate::{
+    argument::{ArgumentParseError, ArgumentParser},
+    context::{CommandArguments, GameCommandCtx},
+};
+
+/// A node in a tree of game commands.
+///
+/// Nodes are either literal words or named arguments,
+/// and may have children, a system to run, a redirect, and a requirement.
+///
+/// ```rust,ignore
+/// app.add_game_command_node(
+///     GameCommandNode::literal("gamemode").then(
+///         GameCommandNode::argument::<String>("mode")
+///             .executes(|ctx: GameCommandCtx<CommandArguments>| { /* ... */ })
+///             .then(GameCommandNode::argument::<String>("target").executes(/* ... */)),
+///     ),
+/// );
+/// ```
+pub struct GameCommandNode {
+    pub(crate) kind: NodeKind,
+    pub(crate) children: Vec<GameCommandNode>,
+    pub(crate) system: Option<BoxedSystem<GameCommandCtx<CommandArguments>, ()>>,
+    pub(crate) redirect: Option<Cow<'static, str>>,
+    pub(crate) requirement: Option<Requirement>,
+    pub(crate) restricted: bool,
+}
+
+impl GameCommandNode {
+    /// Create a new literal node that matches the given word.
+    #[must_use]
+    pub fn literal(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(NodeKind::Literal(name.into()))
+    }
+
+    /// Create a new argument node that parses a `T`.
+    #[must_use]
+    pub fn argument<T: ArgumentParser>(name: impl Into<Cow<'static, str>>) -> Self
+    where
+        T::Data: Default,
+    {
+        Self::argument_using::<T>(name, T::Data::default())
+    }
+
+    /// Create a new argument node that parses a `T` using the given data.
+    #[must_use]
+    pub fn argument_using<T: ArgumentParser>(
+        name: impl Into<Cow<'static, str>>,
+        data: T::Data,
+    ) -> Self {
+        Self::new(NodeKind::Argument(name.into(), ErasedArgument::new::<T>(data)))
+    }
+
+    const fn new(kind: NodeKind) -> Self {
+        Self {
+            kind,
+            children: Vec::new(),
+            system: None,
+            redirect: None,
+            requirement: None,
+            restricted: false,
+        }
+    }
+
+    /// Add a child node.
+    #[must_use]
+    pub fn then(mut self, child: GameCommandNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Run a system when the command ends at this node.
+    #[must_use]
+    pub fn executes<Marker>(
+        mut self,
+        system: impl IntoSystem<GameCommandCtx<CommandArguments>, (), Marker>,
+    ) -> Self {
+        self.system = Some(Box::new(IntoSystem::into_system(system)));
+        self
+    }
+
+    /// Continue parsing at another node after this one.
+    ///
+    /// The path is a space-separated list of literal names starting from the
+    /// root, an empty path redirects to the root itself.
+    #[must_use]
+    pub fn redirect(mut self, path: impl Into<Cow<'static, str>>) -> Self {
+        self.redirect = Some(path.into());
+        self
+    }
+
+    /// Only allow entities that match the predicate to use this node.
+    #[must_use]
+    pub fn requires(
+        mut self,
+        predicate: impl Fn(Entity, &World) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.requirement = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Mark this node as restricted when sent to clients.
+    #[must_use]
+    pub const fn restricted(mut self) -> Self {
+        self.restricted = true;
+        self
+    }
+
+    /// Get the name of this node.
+    #[must_use]
+    pub fn name(&self) -> &str { self.kind.name() }
+}
+
+impl fmt::Debug for GameCommandNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GameCommandNode")
+            .field("kind", &self.kind)
+            .field("children", &self.children)
+            .field("executable", &self.system.is_some())
+            .field("redirect", &self.redirect)
+            .field("restricted", &self.restricted)
+            .finish_non_exhaustive()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A predicate that decides whether an entity may use a node.
+pub(crate) type Requirement = Arc<dyn Fn(Entity, &World) -> bool + Send + Sync>;
+
+/// The kind of a [`GameCommandNode`].
+#[derive(Debug, Clone)]
+pub(crate) enum NodeKind {
+    Root,
+    Literal(Cow<'static, str>),
+    Argument(Cow<'static, str>, ErasedArgument),
+}
+
+impl NodeKind {
+    /// Get the name of this node.
+    #[must_use]
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            NodeKind::Root => "",
+            NodeKind::Literal(name) | NodeKind::Argument(name, _) => name,
+        }
+    }
+}
+
+/// A type-erased [`ArgumentParser`] and its data.
+#[derive(Clone)]
+#[allow(clippy::type_complexity, reason = "dyn Fn trait")]
+pub(crate) struct ErasedArgument {
+    parser: Arc<
+        dyn for<'a> Fn(&'a str) -> Result<(Box<dyn Any>, &'a str), ArgumentParseError<'a>>
+            + Send
+            + Sync,
+    >,
+    pub(crate) argument_type: ArgumentType,
+}
+
+impl ErasedArgument {
+    /// Create a new [`ErasedArgument`] for a `T`.
+    fn new<T: ArgumentParser>(data: T::Data) -> Self {
+        let argument_type = T::argument_type(&data);
+        Self {
+            parser: Arc::new(move |input| {
+                let (value, rest) = T::parse(input, &data)?;
+                let value: Box<dyn Any> = Box::new(value);
+                Ok((value, rest))
+            }),
+            argument_type,
+        }
+    }
+
+    /// Parse the argument from the start of the input.
+    #[inline]
+    pub(crate) fn parse<'a>(
+        &self,
+        input: &'a str,
+    ) -> Result<(Box<dyn Any>, &'a str), ArgumentParseError<'a>> {
+        (self.parser)(input)
+    }
+}
+
+impl fmt::Debug for ErasedArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ErasedArgument").field(&self.argument_type).finish()
+    }
+}
+
+/// A [`GameCommandNode`] with its system registered in the [`World`].
+pub(crate) struct RegisteredNode {
+    pub(crate) kind: NodeKind,
+    pub(crate) children: Vec<RegisteredNode>,
+    pub(crate) system: Option<SystemId<GameCommandCtx<CommandArguments>, ()>>,
+    pub(crate) redirect: Option<Cow<'static, str>>,
+    pub(crate) requirement: Option<Requirement>,
+    pub(crate) restricted: bool,
+}
+
+impl GameCommandNode {
+    /// Register the systems of this node and its children.
+    pub(crate) fn register(self, world: &mut World) -> RegisteredNode {
+        RegisteredNode {
+            kind: self.kind,
+            children: self.children.into_iter().map(|child| child.register(world)).collect(),
+            system: self.system.map(|system| world.register_boxed_system(system)),
+            redirect: self.redirect,
+            requirement: self.requirement,
+            restricted: self.restricted,
+        }
+    }
+}
+
+impl RegisteredNode {
+    /// The systems of this node and its children.
+    pub(crate) fn systems(&self) -> Vec<SystemId<GameCommandCtx<CommandArguments>, ()>> {
+        let mut systems: Vec<_> = self.children.iter().flat_map(Self::systems).collect();
+        systems.extend(self.system);
+        systems
+    }
+}
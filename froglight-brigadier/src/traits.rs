@@ -7,11 +7,13 @@ use bevy_ecs::{error::ErrorContext, prelude::*};
 
 use crate::{
     bundle::ArgumentBundle,
+    node::GameCommandNode,
     prelude::{GameCommandCtx, GameCommandSet},
 };
 
-/// A extension trait adding [`App::add_game_command`] and
-/// [`App::add_game_command_using`].
+/// A extension trait adding [`App::add_game_command`],
+/// [`App::add_game_command_using`], [`App::add_game_command_named`],
+/// and [`App::add_game_command_node`].
 pub trait AppGameCommand {
     /// Add a game command the the [`App`].
     ///
@@ -40,6 +42,28 @@ pub trait AppGameCommand {
         settings: B::BundleData,
         system: impl IntoSystem<GameCommandCtx<B>, (), Marker> + 'static,
     );
+
+    /// Add a game command with named arguments to the [`App`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a command with the same name is already registered,
+    /// or if the number of names does not match the number of arguments.
+    fn add_game_command_named<B: ArgumentBundle, Marker>(
+        &mut self,
+        command: impl Into<Cow<'static, str>>,
+        names: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+        settings: B::BundleData,
+        system: impl IntoSystem<GameCommandCtx<B>, (), Marker> + 'static,
+    );
+
+    /// Add a tree of game commands to the [`App`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a command with the same name is already registered,
+    /// or if a node redirects to a path that does not exist.
+    fn add_game_command_node(&mut self, node: GameCommandNode);
 }
 
 impl AppGameCommand for App {
@@ -58,6 +82,37 @@ impl AppGameCommand for App {
             panic!("Failed to register command: {err:?}");
         }
     }
+
+    fn add_game_command_named<B: ArgumentBundle, Marker>(
+        &mut self,
+        command: impl Into<Cow<'static, str>>,
+        names: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+        settings: B::BundleData,
+        system: impl IntoSystem<GameCommandCtx<B>, (), Marker> + 'static,
+    ) {
+        let names = names.into_iter().map(Into::into).collect();
+        let system = self.world_mut().register_system_cached(system);
+        if let Err(err) = self
+            .world_mut()
+            .get_resource_or_init::<GameCommandSet>()
+            .register_command_named(command.into(), names, settings, system)
+        {
+            panic!("Failed to register command: {err:?}");
+        }
+    }
+
+    fn add_game_command_node(&mut self, node: GameCommandNode) {
+        let world = self.world_mut();
+        let node = node.register(world);
+        let systems = node.systems();
+        if let Err(err) = world.get_resource_or_init::<GameCommandSet>().register_node(node) {
+            // Remove the systems of the rejected nodes before panicking.
+            for system in systems {
+                let _ = world.unregister_system(system);
+            }
+            panic!("Failed to register command: {err:?}");
+        }
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -124,13 +179,10 @@ impl EntityCommandsGameCommand for EntityCommands<'_> {
                 let entity_id = entity.id();
                 let world = entity.into_world_mut();
 
-                // Split the command from it's arguments.
-                let (command, arguments) =
-                    command.trim_start().split_once(' ').unwrap_or((&command, ""));
-
                 // Execute the command.
+                let command = command.trim_start();
                 let result = world.try_resource_scope::<GameCommandSet, _>(|world, commands| {
-                    commands.execute(entity_id, command, arguments, world)
+                    commands.dispatch(entity_id, command, world)
                 });
 
                 match result {
@@ -0,0 +1,190 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use froglight_brigadier::graph::{
+    ArgumentSyntaxError, CommandGraph, CommandGraphError, SuggestionRequests, SyntaxErrorKind,
+};
+use froglight_packet::common::commands::{
+    ArgumentType, CommandNode, CommandNodeKind, CommandSuggestion, CommandSuggestionsContent,
+    CommandTree,
+};
+
+fn literal(name: &str, children: Vec<u32>) -> CommandNode {
+    CommandNode {
+        kind: CommandNodeKind::Literal(name.to_string()),
+        executable: false,
+        restricted: false,
+        children,
+        redirect: None,
+        suggestions: None,
+    }
+}
+
+fn argument(name: &str, ty: ArgumentType, children: Vec<u32>) -> CommandNode {
+    CommandNode { kind: CommandNodeKind::Argument(name.to_string(), ty), ..literal(name, children) }
+}
+
+fn executable(mut node: CommandNode) -> CommandNode {
+    node.executable = true;
+    node
+}
+
+fn graph() -> CommandGraph {
+    let entities = ArgumentType::Entity { single: false, players_only: false };
+    let players = ArgumentType::Entity { single: false, players_only: true };
+
+    let mut run = literal("run", vec![]);
+    run.redirect = Some(0);
+    let mut targets = argument("targets", entities.clone(), vec![]);
+    targets.redirect = Some(7);
+    let mut function = executable(argument("name", ArgumentType::Function, vec![]));
+    function.suggestions = Some(CommandNode::ASK_SERVER);
+
+    let tree = CommandTree {
+        nodes: vec![
+            // 0
+            CommandNode { kind: CommandNodeKind::Root, ..literal("", vec![1, 4, 7, 11, 13]) },
+            // 1-3: `tp <target> <location>`
+            literal("tp", vec![2]),
+            argument("target", entities, vec![3]),
+            executable(argument("location", ArgumentType::Vec3, vec![])),
+            // 4-6: `gamemode <gamemode> [<target>]`
+            literal("gamemode", vec![5]),
+            executable(argument("gamemode", ArgumentType::Gamemode, vec![6])),
+            executable(argument("target", players, vec![])),
+            // 7-10: `execute (run ...|as <targets> ...)`
+            literal("execute", vec![8, 9]),
+            run,
+            literal("as", vec![10]),
+            targets,
+            // 11-12: `say <message>`
+            literal("say", vec![12]),
+            executable(argument("message", ArgumentType::Message, vec![])),
+            // 13-14: `function <name>`
+            literal("function", vec![14]),
+            function,
+        ],
+        root: 0,
+    };
+
+    CommandGraph::new(tree).unwrap()
+}
+
+#[test]
+fn invalid() {
+    let mut tree = graph().tree().clone();
+    tree.nodes[1].children.push(100);
+    assert_eq!(
+        CommandGraph::new(tree),
+        Err(CommandGraphError::InvalidChild { node: 1, child: 100 })
+    );
+
+    let mut tree = graph().tree().clone();
+    tree.root = 1;
+    assert_eq!(CommandGraph::new(tree), Err(CommandGraphError::InvalidRoot(1)));
+}
+
+#[test]
+fn parse() {
+    let graph = graph();
+    assert!(graph.contains("tp"));
+    assert!(!graph.contains("kill"));
+
+    let parsed = graph.parse("tp @s ~ ~1 ^").unwrap_err();
+    assert_eq!(
+        parsed.kind,
+        SyntaxErrorKind::InvalidArgument { node: 3, error: ArgumentSyntaxError::MixedCoordinates }
+    );
+
+    let parsed = graph.parse("tp @s ~ ~1.5 -3").unwrap();
+    assert_eq!(parsed.tokens().len(), 3);
+    assert_eq!(parsed.argument("target"), Some("@s"));
+    assert_eq!(parsed.argument("location"), Some("~ ~1.5 -3"));
+    assert_eq!(parsed.tokens()[2].range, 6..15);
+
+    let parsed = graph.parse("execute as @a[distance=..5] run say Hello World!").unwrap();
+    let names: Vec<&str> = parsed.tokens().iter().map(|token| token.name).collect();
+    assert_eq!(names, ["execute", "as", "targets", "run", "say", "message"]);
+    assert_eq!(parsed.argument("message"), Some("Hello World!"));
+
+    assert!(graph.validate("gamemode creative").is_ok());
+    assert!(graph.validate("gamemode creative Steve").is_ok());
+}
+
+#[test]
+fn errors() {
+    let graph = graph();
+
+    let err = graph.parse("kill @e").unwrap_err();
+    assert_eq!((err.kind, err.position), (SyntaxErrorKind::UnknownCommand, 0));
+
+    let err = graph.parse("tp @s").unwrap_err();
+    assert_eq!((err.kind.clone(), err.position), (SyntaxErrorKind::IncompleteCommand, 5));
+    assert_eq!(err.context("tp @s"), "tp @s<--[HERE]");
+
+    let err = graph.parse("gamemode creative @e").unwrap_err();
+    assert_eq!(
+        err.kind,
+        SyntaxErrorKind::InvalidArgument { node: 6, error: ArgumentSyntaxError::OnlyPlayers }
+    );
+    assert_eq!(err.context("gamemode creative @e"), "...reative @e<--[HERE]");
+
+    let err = graph.parse("gamemode hardcore").unwrap_err();
+    assert_eq!(err.position, 9);
+}
+
+#[test]
+fn suggest() {
+    let graph = graph();
+
+    let suggestions = graph.suggest("");
+    let texts: Vec<&str> = suggestions.entries.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, ["execute", "function", "gamemode", "say", "tp"]);
+
+    let suggestions = graph.suggest("gamemode c");
+    assert_eq!(suggestions.range, 9..10);
+    assert_eq!(suggestions.entries.len(), 1);
+    assert_eq!(suggestions.apply("gamemode c", &suggestions.entries[0]), "gamemode creative");
+
+    let suggestions = graph.suggest("execute run tp @s ~ ~");
+    assert_eq!(suggestions.range, 18..21);
+    let texts: Vec<&str> = suggestions.entries.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, ["~ ~", "~ ~ ~"]);
+    assert!(!suggestions.ask_server);
+
+    let suggestions = graph.suggest("function my");
+    assert!(suggestions.is_empty());
+    assert!(suggestions.ask_server);
+}
+
+#[test]
+fn server_suggestions() {
+    let graph = graph();
+    let mut requests = SuggestionRequests::default();
+
+    let mut suggestions = graph.suggest("function my");
+    let (id, text) = requests.request("function my");
+    assert_eq!(text, "/function my");
+
+    // Responses to other requests are ignored.
+    let stale = CommandSuggestionsContent { id: id + 1, start: 0, length: 0, matches: vec![] };
+    assert!(requests.resolve(&stale).is_none());
+
+    let content = CommandSuggestionsContent {
+        id,
+        start: 10,
+        length: 2,
+        matches: vec![CommandSuggestion { text: "my:function".to_string(), tooltip: None }],
+    };
+    let (input, server) = requests.resolve(&content).unwrap();
+    assert_eq!(input, "function my");
+    assert!(requests.pending().is_none());
+
+    suggestions.merge(&input, server);
+    assert_eq!(suggestions.range, 9..11);
+    assert_eq!(suggestions.apply(&input, &suggestions.entries[0]), "function my:function");
+}
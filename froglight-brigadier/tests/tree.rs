@@ -0,0 +1,257 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+extern crate std;
+
+use alloc::{format, string::String, vec::Vec};
+
+use bevy::{ecs::system::SystemIdMarker, prelude::*};
+use froglight_brigadier::{
+    argument::{ArgumentParseError, ArgumentParser, StringType},
+    bundle::ArgumentBundle,
+    commandset::{CommandExecuteError, CommandRegisterError},
+    graph::CommandGraph,
+    prelude::*,
+};
+use froglight_packet::common::commands::ArgumentType;
+
+#[derive(Default, Resource)]
+struct Executed(Vec<String>);
+
+#[derive(Component)]
+struct Operator;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Executed>();
+
+    app.add_game_command_node(
+        GameCommandNode::literal("gamemode").then(
+            GameCommandNode::argument_using::<String>("mode", StringType::Word)
+                .executes(|ctx: GameCommandCtx<CommandArguments>, mut res: ResMut<Executed>| {
+                    let mode = ctx.get::<String>("mode").unwrap();
+                    res.0.push(format!("gamemode {mode}"));
+                })
+                .then(GameCommandNode::argument::<String>("target").executes(
+                    |ctx: GameCommandCtx<CommandArguments>, mut res: ResMut<Executed>| {
+                        let mode = ctx.get::<String>("mode").unwrap();
+                        let target = ctx.get::<String>("target").unwrap();
+                        res.0.push(format!("gamemode {mode} {target}"));
+                    },
+                )),
+        ),
+    );
+
+    app.add_game_command_node(
+        GameCommandNode::literal("execute")
+            .then(GameCommandNode::literal("run").redirect(""))
+            .then(
+                GameCommandNode::literal("as").then(
+                    GameCommandNode::argument_using::<String>("targets", StringType::Word)
+                        .redirect("execute"),
+                ),
+            ),
+    );
+
+    app.add_game_command_node(
+        GameCommandNode::literal("stop")
+            .requires(|entity, world| world.get::<Operator>(entity).is_some())
+            .restricted()
+            .executes(|_: GameCommandCtx<CommandArguments>, mut res: ResMut<Executed>| {
+                res.0.push(String::from("stop"));
+            }),
+    );
+
+    app.add_game_command_named(
+        "add",
+        ["a", "b"],
+        ((), ()),
+        |ctx: GameCommandCtx<(u32, u32)>, mut res: ResMut<Executed>| {
+            let (a, b) = ctx.into_input();
+            res.0.push(format!("add {}", a + b));
+        },
+    );
+    app.add_game_command("sub", |ctx: GameCommandCtx<(u32, u32)>, mut res: ResMut<Executed>| {
+        let (a, b) = ctx.into_input();
+        res.0.push(format!("sub {}", a.saturating_sub(b)));
+    });
+
+    app
+}
+
+fn dispatch(
+    app: &mut App,
+    entity: Entity,
+    input: &str,
+) -> Result<(), CommandExecuteError<'static>> {
+    app.world_mut().resource_scope(|world, set: Mut<GameCommandSet>| {
+        set.dispatch(entity, input, world).map_err(CommandExecuteError::into_owned)
+    })
+}
+
+#[test]
+fn dispatch_tree() {
+    let mut app = app();
+    let player = app.world_mut().spawn_empty().id();
+    let operator = app.world_mut().spawn(Operator).id();
+
+    dispatch(&mut app, player, "gamemode creative").unwrap();
+    dispatch(&mut app, player, "gamemode survival Steve").unwrap();
+    dispatch(&mut app, player, "execute as @a as @s run gamemode spectator").unwrap();
+    dispatch(&mut app, player, "add 1 2").unwrap();
+    dispatch(&mut app, operator, "stop").unwrap();
+
+    assert!(matches!(dispatch(&mut app, player, "gamemode"), Err(CommandExecuteError::Incomplete)));
+    assert!(matches!(
+        dispatch(&mut app, player, "stop"),
+        Err(CommandExecuteError::CommandNotFound(_))
+    ));
+    assert!(matches!(
+        dispatch(&mut app, player, "gamemode creative Steve extra"),
+        Err(CommandExecuteError::Parse(_))
+    ));
+
+    assert_eq!(
+        app.world().resource::<Executed>().0,
+        ["gamemode creative", "gamemode survival Steve", "gamemode spectator", "add 3", "stop"]
+    );
+}
+
+#[test]
+fn help_and_usage() {
+    let mut app = app();
+    let player = app.world_mut().spawn_empty().id();
+    let world = app.world();
+    let set = world.resource::<GameCommandSet>();
+
+    assert_eq!(
+        set.help(None),
+        [
+            "/gamemode <mode> [<target>]",
+            "/execute (run|as)",
+            "/stop",
+            "/add <a> <b>",
+            "/sub <long> <long>"
+        ]
+    );
+    assert_eq!(
+        set.help(Some((player, world))),
+        ["/gamemode <mode> [<target>]", "/execute (run|as)", "/add <a> <b>", "/sub <long> <long>"]
+    );
+
+    assert_eq!(
+        set.usage("execute", None).unwrap(),
+        ["/execute run ...", "/execute as <targets> -> execute"]
+    );
+    assert_eq!(set.usage("stop", None).unwrap(), ["/stop"]);
+    assert!(set.usage("stop", Some((player, world))).is_none());
+    assert!(set.usage("unknown", None).is_none());
+}
+
+#[test]
+fn command_tree() {
+    let mut app = app();
+    let player = app.world_mut().spawn_empty().id();
+    let world = app.world();
+    let set = world.resource::<GameCommandSet>();
+
+    let graph = CommandGraph::new(set.command_tree(None)).unwrap();
+    assert!(graph.contains("stop"));
+    graph.validate("execute as bob run gamemode creative Steve").unwrap();
+    graph.validate("add 1 2").unwrap();
+    assert!(graph.validate("add 1").is_err());
+
+    let graph = CommandGraph::new(set.command_tree(Some((player, world)))).unwrap();
+    assert!(!graph.contains("stop"));
+
+    let suggestions = set.suggest("game", None);
+    assert_eq!(suggestions.entries.len(), 1);
+    assert_eq!(suggestions.entries[0].text, "gamemode");
+
+    let tree = set.command_tree(None);
+    let add = tree
+        .root()
+        .unwrap()
+        .children
+        .iter()
+        .find_map(|&child| (tree.get(child)?.name()? == "add").then_some(tree.get(child)?));
+    let a = tree.get(add.unwrap().children[0]).unwrap();
+    assert_eq!(a.name(), Some("a"));
+    assert_eq!(tree.get(a.children[0]).unwrap().name(), Some("b"));
+}
+
+#[test]
+fn argument_count() {
+    let mut set = GameCommandSet::new();
+    let mut world = World::new();
+    let system = world.register_system(|_: GameCommandCtx<(u32, u32)>| {});
+
+    assert!(matches!(
+        set.register_command_named("add".into(), vec!["a".into()], ((), ()), system),
+        Err(CommandRegisterError::ArgumentCount { expected: 2, found: 1 })
+    ));
+    assert!(!set.contains("add"));
+}
+
+#[test]
+fn invalid_redirect() {
+    fn systems(app: &mut App) -> usize {
+        app.world_mut().query_filtered::<(), With<SystemIdMarker>>().iter(app.world()).count()
+    }
+
+    let mut app = app();
+    let before = systems(&mut app);
+
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        app.add_game_command_node(
+            GameCommandNode::literal("tp")
+                .executes(|_: GameCommandCtx<CommandArguments>| {})
+                .then(GameCommandNode::literal("to").redirect("teleport")),
+        );
+    }));
+    let message = result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("InvalidRedirect(\"teleport\")"));
+
+    // The rejected node's systems are removed again.
+    assert!(!app.world().resource::<GameCommandSet>().contains("tp"));
+    assert_eq!(systems(&mut app), before);
+}
+
+#[test]
+fn suggest_cache() {
+    let mut app = app();
+    assert!(app.world().resource::<GameCommandSet>().suggest("ki", None).is_empty());
+
+    app.add_game_command("kill", |_: GameCommandCtx<()>| {});
+    let suggestions = app.world().resource::<GameCommandSet>().suggest("ki", None);
+    assert_eq!(suggestions.entries.len(), 1);
+    assert_eq!(suggestions.entries[0].text, "kill");
+}
+
+/// A single character that leaves the separator to the bundle.
+#[derive(Debug, PartialEq)]
+struct Letter(char);
+
+impl ArgumentParser for Letter {
+    type Data = ();
+
+    fn parse<'a>(input: &'a str, (): &()) -> Result<(Self, &'a str), ArgumentParseError<'a>> {
+        let mut chars = input.chars();
+        let letter = chars.next().ok_or(ArgumentParseError::InputMismatch)?;
+        Ok((Letter(letter), chars.as_str()))
+    }
+
+    fn argument_type((): &()) -> ArgumentType { ArgumentType::Bool }
+}
+
+#[test]
+fn bundle_separator() {
+    let parse = <(Letter, Letter) as ArgumentBundle>::bundle_from_string;
+    assert_eq!(parse("a b", &((), ())).unwrap(), (Letter('a'), Letter('b')));
+    assert!(matches!(parse("ab", &((), ())), Err(ArgumentParseError::InputMismatch)));
+
+    let parse = <(u32, u32) as ArgumentBundle>::bundle_from_string;
+    assert_eq!(parse("1 2", &((), ())).unwrap(), (1, 2));
+    assert!(parse("1 2x", &((), ())).is_err());
+}
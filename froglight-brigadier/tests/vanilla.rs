@@ -0,0 +1,188 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use froglight_block::{block::BlockPredicateTarget, prelude::BlockVersion};
+use froglight_brigadier::argument::{
+    ArgumentParser,
+    vanilla::{
+        Angle, BlockInput, BlockPredicate, Bounds, ChatColor, ColumnCoordinates, Coordinate,
+        Coordinates, CoordinatesKind, EntitySelector, ItemComponent, ItemInput, ItemPredicate,
+        NbtPath, NbtPathNode, PredicateTarget, Rotation, ScoreHolder, ScoreHolderData,
+        SelectorData, SelectorKind, Time, TimeData,
+    },
+};
+use froglight_common::{prelude::Identifier, version::V26_1};
+use froglight_item::prelude::ItemVersion;
+use froglight_world::prelude::{BlockPos, ChunkPos};
+use glam::DVec3;
+
+#[test]
+fn coordinates() {
+    let (coords, rest) = Coordinates::parse("~ ~1.5 -3 next", &CoordinatesKind::Block).unwrap();
+    assert_eq!(rest, "next");
+    assert_eq!(coords.x, Coordinate::relative(0.0));
+    assert_eq!(coords.y, Coordinate::relative(1.5));
+    assert_eq!(coords.z, Coordinate::absolute(-3.0));
+    assert_eq!(
+        coords.resolve(DVec3::new(10.0, 64.0, 10.0), 0.0, 0.0),
+        DVec3::new(10.0, 65.5, -3.0)
+    );
+    assert_eq!(
+        coords.resolve_block(DVec3::new(10.2, 64.0, 10.0), 0.0, 0.0),
+        BlockPos::new_xyz(10, 65, -3)
+    );
+
+    // Block coordinates must be whole, and local coordinates can't be mixed.
+    assert!(Coordinates::parse("1.5 2 3", &CoordinatesKind::Block).is_err());
+    assert!(Coordinates::parse("^ ~ ^", &CoordinatesKind::Block).is_err());
+    assert!(Coordinates::parse("1 2", &CoordinatesKind::Block).is_err());
+
+    // Whole positions are centered unless exact.
+    let (coords, _) = Coordinates::parse("1 2 3.25", &CoordinatesKind::Centered).unwrap();
+    assert_eq!(coords.resolve(DVec3::ZERO, 0.0, 0.0), DVec3::new(1.5, 2.0, 3.25));
+    let (coords, _) = Coordinates::parse("1 2 3", &CoordinatesKind::Exact).unwrap();
+    assert_eq!(coords.resolve(DVec3::ZERO, 0.0, 0.0), DVec3::new(1.0, 2.0, 3.0));
+
+    // Facing south, forwards is `+z` and left is `+x`.
+    let (coords, _) = Coordinates::parse("^1 ^ ^2", &CoordinatesKind::Exact).unwrap();
+    assert!(coords.is_local());
+    let position = coords.resolve(DVec3::ZERO, 0.0, 0.0);
+    assert!(position.abs_diff_eq(DVec3::new(1.0, 0.0, 2.0), 1e-9), "{position}");
+
+    let (column, _) = ColumnCoordinates::parse("~ 40", &CoordinatesKind::Block).unwrap();
+    assert_eq!(column.resolve_chunk(glam::DVec2::new(-1.0, 0.0)), ChunkPos::new_xz(-1, 2));
+
+    assert_eq!(BlockPos::parse("1 -2 3", &()).unwrap().0, BlockPos::new_xyz(1, -2, 3));
+    assert!(BlockPos::parse("~ ~ ~", &()).is_err());
+    assert_eq!(ChunkPos::parse("33 -1", &()).unwrap().0, ChunkPos::new_xz(2, -1));
+
+    let (rotation, _) = Rotation::parse("~90 -10", &()).unwrap();
+    assert_eq!(rotation.resolve(45.0, 20.0), (135.0, -10.0));
+    let (angle, _) = Angle::parse("~200", &()).unwrap();
+    assert!((angle.resolve(0.0) - -160.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn selectors() {
+    let (selector, rest) = EntitySelector::parse(
+        "@e[type=!zombie, distance=..5,nbt={a:[1,2]}] rest",
+        &SelectorData::default(),
+    )
+    .unwrap();
+    assert_eq!(rest, "rest");
+    let EntitySelector::Selector { kind, filters } = &selector else { panic!("not a selector") };
+    assert_eq!(*kind, SelectorKind::AllEntities);
+    assert_eq!(filters.len(), 3);
+    assert!(filters[0].negated);
+    assert_eq!(filters[0].value, "zombie");
+    assert_eq!(selector.filter("distance").unwrap().value, "..5");
+    assert_eq!(selector.filter("nbt").unwrap().value, "{a:[1,2]}");
+
+    let single = SelectorData { single: true, players_only: false };
+    let players = SelectorData { single: false, players_only: true };
+    assert!(EntitySelector::parse("@e", &single).is_err());
+    assert!(EntitySelector::parse("@e[limit=1]", &single).is_ok());
+    assert!(EntitySelector::parse("@e", &players).is_err());
+    assert!(EntitySelector::parse("@e[type=player]", &players).is_ok());
+    assert!(EntitySelector::parse("@s", &players).is_ok());
+    assert!(EntitySelector::parse("@x", &players).is_err());
+    assert!(EntitySelector::parse("@a[limit=1", &players).is_err());
+    assert_eq!(
+        EntitySelector::parse("Steve", &single).unwrap().0,
+        EntitySelector::Name(String::from("Steve"))
+    );
+
+    let multiple = ScoreHolderData { multiple: true };
+    assert_eq!(ScoreHolder::parse("*", &multiple).unwrap().0, ScoreHolder::All);
+    assert!(ScoreHolder::parse("*", &ScoreHolderData::default()).is_err());
+}
+
+#[test]
+fn values() {
+    assert_eq!(Bounds::<i32>::parse("1..5", &()).unwrap().0, Bounds::new(Some(1), Some(5)));
+    assert_eq!(Bounds::<i32>::parse("..5", &()).unwrap().0, Bounds::new(None, Some(5)));
+    assert_eq!(Bounds::<i32>::parse("3", &()).unwrap().0, Bounds::new(Some(3), Some(3)));
+    assert!(Bounds::<i32>::parse("5..1", &()).is_err());
+    assert!(Bounds::<i32>::parse("..", &()).is_err());
+    let (bounds, _) = Bounds::<f64>::parse("0.5..", &()).unwrap();
+    assert!(bounds.contains(&0.5) && !bounds.contains(&0.25));
+
+    assert_eq!(Time::parse("2d", &TimeData::default()).unwrap().0, Time(48000));
+    assert_eq!(Time::parse("1.5s", &TimeData::default()).unwrap().0, Time(30));
+    assert_eq!(Time::parse("7", &TimeData::default()).unwrap().0, Time(7));
+    assert!(Time::parse("-1t", &TimeData::default()).is_err());
+
+    assert_eq!(ChatColor::parse("dark_aqua", &()).unwrap().0, ChatColor::DarkAqua);
+    assert!(ChatColor::parse("orange", &()).is_err());
+
+    let (identifier, _) = Identifier::parse("stone", &()).unwrap();
+    assert_eq!(identifier, Identifier::new_static("minecraft:stone"));
+}
+
+#[test]
+fn blocks_and_items() {
+    let blocks = V26_1::blocks();
+    let (input, rest) = BlockInput::parse("oak_log[axis=x]{a:1b} rest", &blocks).unwrap();
+    assert_eq!(rest, "rest");
+    assert_eq!(input.block.identifier().as_str(), "minecraft:oak_log");
+    assert_eq!(input.block.get_attribute_str("axis"), Some("x"));
+    assert!(input.nbt.is_some());
+    assert!(BlockInput::parse("oak_log[axis=w]", &blocks).is_err());
+    assert!(BlockInput::parse("not_a_block", &blocks).is_err());
+
+    assert!(BlockInput::parse("oak_log[axis=x,axis=y]", &blocks).is_err());
+    assert!(BlockInput::parse("oak_log[axis=x", &blocks).is_err());
+
+    let (predicate, rest) = BlockPredicate::parse("#logs[axis=y]{a:1b} rest", &blocks).unwrap();
+    assert_eq!(rest, "rest");
+    assert_eq!(
+        predicate.predicate.target(),
+        &BlockPredicateTarget::Tag(Identifier::new_static("minecraft:logs"))
+    );
+    assert!(predicate.nbt.is_some());
+    assert!(BlockPredicate::parse("oak_log[color=red]", &blocks).is_err());
+    let log = BlockInput::parse("oak_log[axis=y]", &blocks).unwrap().0.block;
+    assert!(predicate.matches(&log, |_, tag| tag == "minecraft:logs"));
+    assert!(!predicate.matches(&log, |_, _| false));
+
+    let items = V26_1::items();
+    let (input, _) = ItemInput::parse("diamond_sword[damage=5,!enchantments]", &items).unwrap();
+    assert_eq!(input.item.identifier().as_str(), "minecraft:diamond_sword");
+    assert_eq!(
+        input.components,
+        [
+            ItemComponent::Set(Identifier::new_static("minecraft:damage"), String::from("5")),
+            ItemComponent::Remove(Identifier::new_static("minecraft:enchantments")),
+        ]
+    );
+
+    let (predicate, _) = ItemPredicate::parse("*[damage=0]", &items).unwrap();
+    assert_eq!(predicate.target, PredicateTarget::Any);
+    assert_eq!(predicate.tests.as_deref(), Some("damage=0"));
+    assert!(predicate.matches(&input.item, |_, _| false));
+    assert!(ItemPredicate::parse("not_an_item", &items).is_err());
+}
+
+#[test]
+fn nbt_path() {
+    let (path, _) = NbtPath::parse("Inventory[{Slot:0b}].\"custom name\"[-1]", &()).unwrap();
+    assert!(matches!(path.0.as_slice(), [
+        NbtPathNode::Key(key),
+        NbtPathNode::MatchAll(_),
+        NbtPathNode::Key(name),
+        NbtPathNode::Index(-1)
+    ] if key == "Inventory" && name == "custom name"));
+
+    let (path, _) = NbtPath::parse("{a:1}.b{c:2}[]", &()).unwrap();
+    assert!(matches!(
+        path.0.as_slice(),
+        [NbtPathNode::MatchRoot(_), NbtPathNode::MatchKey(..), NbtPathNode::All]
+    ));
+
+    assert!(NbtPath::parse("a..b", &()).is_err());
+    assert!(NbtPath::parse("a[0", &()).is_err());
+}
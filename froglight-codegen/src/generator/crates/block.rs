@@ -27,6 +27,8 @@ use crate::{
 pub struct BlockData {
     pub blocks: IndexMap<String, BlockSettings>,
     pub block_families: IndexMap<String, Vec<String>>,
+    /// Block entity identifiers, ordered by network id.
+    pub block_entities: Vec<String>,
 
     pub report: BlockReport,
 }
@@ -44,6 +46,14 @@ pub struct BlockSettings {
     pub has_collision: bool,
     pub has_occlusion: bool,
 
+    /// The hardness of the block, `-1.0` if unbreakable
+    pub hardness: f32,
+    pub requires_tool: bool,
+    /// The `MineableTools` constants for this block
+    pub mineable: Vec<&'static str>,
+    /// The `ToolTier` variant for this block
+    pub tier: &'static str,
+
     pub shape: BlockShape,
     pub attributes: Vec<BlockAttribute>,
 
@@ -64,6 +74,10 @@ impl Default for BlockSettings {
             is_liquid: false,
             has_collision: true,
             has_occlusion: true,
+            hardness: 0.0,
+            requires_tool: false,
+            mineable: Vec::new(),
+            tier: "Any",
             shape: BlockShape::default(),
             attributes: Vec::new(),
             states: 1,
@@ -190,6 +204,33 @@ pub struct BlockReportState {
     pub properties: IndexMap<String, String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+#[facet(transparent)]
+pub struct RegistryReport(pub IndexMap<String, RegistryReportEntry>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct RegistryReportEntry {
+    pub entries: IndexMap<String, RegistryReportValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct RegistryReportValue {
+    pub protocol_id: u32,
+}
+
+impl RegistryReport {
+    /// Get the entries of a registry, ordered by their protocol id.
+    fn entries_of(&self, registry: &str) -> Result<Vec<String>> {
+        let Some(registry) = self.0.get(registry) else {
+            miette::bail!("Failed to find registry \"{registry}\" in registry report");
+        };
+
+        let mut entries: Vec<_> = registry.entries.iter().collect();
+        entries.sort_unstable_by_key(|(_, value)| value.protocol_id);
+        Ok(entries.into_iter().map(|(name, _)| name.clone()).collect())
+    }
+}
+
 impl BlockReport {
     /// Update the block attribute names and values based on the report data.
     ///
@@ -526,7 +567,12 @@ impl BlockData {
                                 ("friction", _) => {}
                                 ("hasPostProcess", _) => {}
                                 ("ignitedByLava", _) => {}
-                                ("instabreak", _) => {}
+                                ("destroyTime", _) => {
+                                    if let Some(hardness) = last_float(&constants, 0) {
+                                        current.hardness = hardness;
+                                    }
+                                }
+                                ("instabreak", _) => current.hardness = 0.0,
                                 ("instrument", _) => {}
                                 ("isRedstoneConductor", _) => {}
                                 ("isSuffocating", _) => {}
@@ -564,7 +610,7 @@ impl BlockData {
                                     // copyTo.isSuffocating = copyFrom.isSuffocating;
                                     // copyTo.isViewBlocking = copyFrom.isViewBlocking;
                                     // copyTo.drops = copyFrom.drops;
-                                    // copyTo.destroyTime = copyFrom.destroyTime;
+                                    current.hardness = retrieved.hardness;
                                     // copyTo.explosionResistance = copyFrom.explosionResistance;
                                     current.has_collision = retrieved.has_collision;
                                     // copyTo.isRandomlyTicking = copyFrom.isRandomlyTicking;
@@ -581,7 +627,7 @@ impl BlockData {
                                     // copyTo.forceSolidOff = copyFrom.forceSolidOff;
                                     // copyTo.forceSolidOn = copyFrom.forceSolidOn;
                                     // copyTo.pushReaction = copyFrom.pushReaction;
-                                    // copyTo.requiresCorrectToolForDrops = copyFrom.requiresCorrectToolForDrops;
+                                    current.requires_tool = retrieved.requires_tool;
                                     // copyTo.offsetFunction = copyFrom.offsetFunction;
                                     // copyTo.spawnTerrainParticles = copyFrom.spawnTerrainParticles;
                                     // copyTo.requiredFeatures = copyFrom.requiredFeatures;
@@ -592,7 +638,7 @@ impl BlockData {
                                 ("ofLegacyCopy", _) => {
                                     let retrieved = retrieved.as_ref().unwrap();
                                     let retrieved = blocks.get(retrieved.name_and_type.name.as_ref()).unwrap();
-                                    // copyTo.destroyTime = copyFrom.destroyTime;
+                                    current.hardness = retrieved.hardness;
                                     // copyTo.explosionResistance = copyFrom.explosionResistance;
                                     current.has_collision = retrieved.has_collision;
                                     // copyTo.isRandomlyTicking = copyFrom.isRandomlyTicking;
@@ -609,7 +655,7 @@ impl BlockData {
                                     // copyTo.forceSolidOff = copyFrom.forceSolidOff;
                                     // copyTo.forceSolidOn = copyFrom.forceSolidOn;
                                     // copyTo.pushReaction = copyFrom.pushReaction;
-                                    // copyTo.requiresCorrectToolForDrops = copyFrom.requiresCorrectToolForDrops;
+                                    current.requires_tool = retrieved.requires_tool;
                                     // copyTo.offsetFunction = copyFrom.offsetFunction;
                                     // copyTo.spawnTerrainParticles = copyFrom.spawnTerrainParticles;
                                     // copyTo.requiredFeatures = copyFrom.requiredFeatures;
@@ -622,11 +668,17 @@ impl BlockData {
                                 ("pushReaction", _) => {}
                                 ("randomTicks", _) => {}
                                 ("replaceable", _) => {}
-                                ("requiresCorrectToolForDrops", _) => {}
+                                ("requiresCorrectToolForDrops", _) => current.requires_tool = true,
                                 ("shulkerBoxProperties", _) => {}
                                 ("sound", _) => {}
                                 ("speedFactor", _) => {}
-                                ("strength", _) => {}
+                                ("strength", desc) => {
+                                    // `strength(destroyTime)` or `strength(destroyTime, explosionResistance)`
+                                    let skip = usize::from(desc.starts_with("(FF)"));
+                                    if let Some(hardness) = last_float(&constants, skip) {
+                                        current.hardness = hardness;
+                                    }
+                                }
                                 ("vanillaBlockId", _) => {}
                                 ("wallVariant", _) => {}
                                 ("waterloggedMapColor", _) => {}
@@ -683,6 +735,53 @@ impl BlockData {
         })
         .await?;
 
+        let block_entities = JarFile::get_for(version, storage, async |jar| {
+            let path = jar.generated.join("reports/registries.json");
+            let Ok(content) = tokio::fs::read_to_string(path).await else {
+                miette::bail!("Failed to read registry report for \"{}\"", version.as_str());
+            };
+
+            match facet_json::from_str::<RegistryReport>(&content) {
+                Ok(report) => report.entries_of("minecraft:block_entity_type"),
+                Err(err) => miette::bail!(
+                    "Failed to parse registry report for \"{}\": {err}",
+                    version.as_str()
+                ),
+            }
+        })
+        .await?;
+
+        JarFile::get_for(version, storage, async |jar| {
+            const MINEABLE: [(&str, &str); 5] = [
+                ("mineable/pickaxe", "PICKAXE"),
+                ("mineable/axe", "AXE"),
+                ("mineable/shovel", "SHOVEL"),
+                ("mineable/hoe", "HOE"),
+                ("sword_efficient", "SWORD"),
+            ];
+            const TIERS: [(&str, &str); 3] = [
+                ("needs_diamond_tool", "Diamond"),
+                ("needs_iron_tool", "Iron"),
+                ("needs_stone_tool", "Stone"),
+            ];
+
+            for (tag, constant) in MINEABLE {
+                let values = read_block_tag(jar, tag).await?;
+                for block in blocks.values_mut().filter(|block| values.contains(&block.ident)) {
+                    block.mineable.push(constant);
+                }
+            }
+            for (tag, tier) in TIERS.into_iter().rev() {
+                let values = read_block_tag(jar, tag).await?;
+                for block in blocks.values_mut().filter(|block| values.contains(&block.ident)) {
+                    block.tier = tier;
+                }
+            }
+
+            Ok(())
+        })
+        .await?;
+
         JarData::get_for(version, storage, async |data| {
             for (block_name, block) in &mut blocks {
                 // Update the display name
@@ -753,10 +852,60 @@ impl BlockData {
             );
         }
 
-        Ok(BlockData { blocks, block_families, report })
+        Ok(BlockData { blocks, block_families, block_entities, report })
     }
 }
 
+/// Get the last [`f32`] constant, skipping the given number of floats.
+fn last_float(constants: &[LiteralConstant<'_>], skip: usize) -> Option<f32> {
+    constants
+        .iter()
+        .rev()
+        .filter_map(|constant| match constant {
+            LiteralConstant::Float(value) => Some(*value),
+            _ => None,
+        })
+        .nth(skip)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+struct BlockTagFile {
+    values: Vec<String>,
+}
+
+/// Read a block tag from the generated data, resolving any nested tags.
+async fn read_block_tag(jar: &JarFile, tag: &str) -> Result<IndexSet<String>> {
+    let mut values = IndexSet::new();
+    let mut visited = IndexSet::new();
+    let mut pending = vec![tag.to_string()];
+
+    while let Some(tag) = pending.pop() {
+        if !visited.insert(tag.clone()) {
+            continue;
+        }
+
+        let path = jar.generated.join(format!("data/minecraft/tags/block/{tag}.json"));
+        let Ok(content) = tokio::fs::read_to_string(path).await else {
+            miette::bail!("Failed to read block tag \"{tag}\"");
+        };
+        let file = match facet_json::from_str::<BlockTagFile>(&content) {
+            Ok(file) => file,
+            Err(err) => miette::bail!("Failed to parse block tag \"{tag}\": {err}"),
+        };
+
+        for value in file.values {
+            match value.strip_prefix('#') {
+                Some(nested) => pending.push(nested.trim_start_matches("minecraft:").to_string()),
+                None => {
+                    values.insert(value);
+                }
+            }
+        }
+    }
+
+    Ok(values)
+}
+
 fn add_weathering_blocks(
     original: BlockSettings,
     name_and_type: &NameAndType,
@@ -1295,6 +1444,73 @@ pub async fn generate_global(config: &ConfigBundle) -> Result<()> {
                 module.build().await?;
             };
 
+            // Build the `block_entity` module with the collected block entity types
+            {
+                let path = WORKSPACE_DIR.join("froglight-block/src/generated");
+                let mut module = ModuleBuilder::new("block_entity", path);
+
+                // Deduplicate and sort the block entity types
+                let mut block_entities = IndexMap::<String, String>::new();
+                for versioned in global_blocks.values() {
+                    for ident in &versioned.block_entities {
+                        let name = ident.trim_start_matches("minecraft:").to_case(Case::Pascal);
+                        block_entities.insert(name, ident.clone());
+                    }
+                }
+                block_entities.sort_unstable_keys();
+
+                // Generate the content
+                let mut content = String::new();
+                content.push_str("\ngenerate! {\n    @block_entities\n");
+
+                let length = block_entities.len();
+                for (index, (name, ident)) in block_entities.iter().enumerate() {
+                    write!(content, "    {name} => \"{ident}\"").unwrap();
+                    if index != length - 1 {
+                        content.push(',');
+                    }
+                    content.push('\n');
+                }
+                content.push('}');
+
+                // Finalize and build the module
+                module
+                    .with_docs(
+                        "Block entity types for all [`Version`](froglight_common::version::Version)s.
+
+@generated",
+                    )
+                    .with_content(&content);
+
+                module.build().await?;
+
+                // Build the `froglight-world` module with a view for each block entity type
+                let path = WORKSPACE_DIR.join("froglight-world/src/chunk/block_entity");
+                let mut module = ModuleBuilder::new("generated", path);
+
+                let mut content = String::new();
+                content.push_str("\ngenerate! {\n");
+                for (index, name) in block_entities.keys().enumerate() {
+                    let function = name.to_case(Case::Snake);
+                    write!(content, "    {name} => {name}View, as_{function}").unwrap();
+                    if index != length - 1 {
+                        content.push(',');
+                    }
+                    content.push('\n');
+                }
+                content.push('}');
+
+                module
+                    .with_docs(
+                        "Views for all vanilla [`BlockEntity`](super::BlockEntity) types.
+
+@generated",
+                    )
+                    .with_content(&content);
+
+                module.build().await?;
+            }
+
             // Build the `shape` module with the collected block shapes
             let mut global_shapes =
                 IndexMap::<_, IndexMap<_, _>>::with_capacity(global_blocks.len());
@@ -1407,6 +1623,14 @@ async fn generate(
             content.push_str(&settings.has_collision.to_string());
             content.push_str(", occlusion: ");
             content.push_str(&settings.has_occlusion.to_string());
+            content.push_str(",\n        hardness: ");
+            write!(content, "{:?}", settings.hardness).unwrap();
+            content.push_str(", tool: ");
+            content.push_str(&settings.requires_tool.to_string());
+            content.push_str(", mineable: [");
+            content.push_str(&settings.mineable.join(", "));
+            content.push_str("], tier: ");
+            content.push_str(settings.tier);
 
             content.push_str(",\n        ty: [ ");
             for (attr_index, attr) in settings.attributes.iter().enumerate() {
@@ -1456,6 +1680,24 @@ async fn generate(
         }
         content.push('}');
 
+        // Generate the block entity macro invocation
+        content.push_str("\n\ngenerate! {\n    @version @block_entities ");
+        content.push_str(&version_type);
+        content.push_str(",\n    ");
+
+        for (index, ident) in data.block_entities.iter().enumerate() {
+            content.push_str(&ident.trim_start_matches("minecraft:").to_case(Case::Pascal));
+            if index == data.block_entities.len() - 1 {
+                content.push('\n');
+            } else {
+                content.push_str(", ");
+                if (index + 1).is_multiple_of(8) {
+                    content.push_str("\n    ");
+                }
+            }
+        }
+        content.push('}');
+
         module.with_docs("Placeholder").with_content(&content);
 
         Ok(())
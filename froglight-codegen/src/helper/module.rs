@@ -227,10 +227,14 @@ impl ModuleBuilder {
 
         // Write module documentation to the output buffer
         if !self.docs.is_empty() {
-            for line in self.docs.lines() {
-                output.push_str("//! ");
-                output.push_str(line.trim());
-                output.push('\n');
+            for line in self.docs.lines().map(str::trim) {
+                if line.is_empty() {
+                    output.push_str("//!\n");
+                } else {
+                    output.push_str("//! ");
+                    output.push_str(line);
+                    output.push('\n');
+                }
             }
         }
 
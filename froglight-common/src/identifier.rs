@@ -335,7 +335,7 @@ impl Hash for Identifier<'_> {
 // -------------------------------------------------------------------------------------------------
 
 /// An error that occurs when creating an [`Identifier`].
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub enum IdentifierError {
     /// The string is empty.
     Empty,
@@ -347,11 +347,15 @@ pub enum IdentifierError {
 }
 
 impl fmt::Display for IdentifierError {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result { todo!() }
-}
-
-impl fmt::Debug for IdentifierError {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result { todo!() }
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentifierError::Empty => f.write_str("Identifier is empty"),
+            IdentifierError::RequiresNamespace => f.write_str("Identifier requires a namespace"),
+            IdentifierError::Invalid => {
+                f.write_str("Identifier has an invalid namespace separator")
+            }
+        }
+    }
 }
 
 impl Error for IdentifierError {}
@@ -1,4 +1,5 @@
 use core::{
+    cell::RefCell,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -8,17 +9,23 @@ use facet::Partial;
 use crate::{
     ReaderError,
     deserialize::{DeserializeError, Deserializer, Item},
+    stream::{StreamBuffer, StreamSource},
 };
 
 /// A [`Deserializer`] that implements [`Future`].
-#[repr(transparent)]
+///
+/// When the [`Deserializer`] runs out of input,
+/// the future waits for its [`StreamSource`] to provide more.
 pub struct DeserializerFuture<
     'facet,
     'core,
     const BORROW: bool,
     C: FnMut(Item<'facet, BORROW>) -> Result<Item<'facet, BORROW>, ReaderError>,
+    S: StreamSource = (),
 > {
     de: Deserializer<'facet, 'core, BORROW, C>,
+    buffer: Option<&'core RefCell<StreamBuffer>>,
+    source: S,
 }
 
 impl<
@@ -29,9 +36,40 @@ impl<
 > DeserializerFuture<'facet, 'core, BORROW, C>
 {
     /// Create a new [`DeserializerFuture`] from a [`Deserializer`].
+    ///
+    /// Running out of input is treated as an error.
     #[inline]
     #[must_use]
-    pub const fn from_sync(de: Deserializer<'facet, 'core, BORROW, C>) -> Self { Self { de } }
+    pub const fn from_sync(de: Deserializer<'facet, 'core, BORROW, C>) -> Self {
+        Self { de, buffer: None, source: () }
+    }
+}
+
+impl<
+    'facet,
+    'core,
+    const BORROW: bool,
+    C: FnMut(Item<'facet, BORROW>) -> Result<Item<'facet, BORROW>, ReaderError>,
+    S: StreamSource,
+> DeserializerFuture<'facet, 'core, BORROW, C, S>
+{
+    /// The maximum number of steps to take before yielding.
+    pub const STEP_BUDGET: usize = 64;
+
+    /// Create a new [`DeserializerFuture`] from a [`Deserializer`]
+    /// whose core reads from the given [`StreamBuffer`].
+    ///
+    /// Whenever the core runs out of input,
+    /// `source` is polled to append more bytes to the buffer.
+    #[inline]
+    #[must_use]
+    pub const fn from_stream(
+        de: Deserializer<'facet, 'core, BORROW, C>,
+        buffer: &'core RefCell<StreamBuffer>,
+        source: S,
+    ) -> Self {
+        Self { de, buffer: Some(buffer), source }
+    }
 
     /// Convert this [`DeserializerFuture`] into a [`Deserializer`].
     #[inline]
@@ -45,29 +83,50 @@ impl<
     'facet,
     const BORROW: bool,
     C: FnMut(Item<'facet, BORROW>) -> Result<Item<'facet, BORROW>, ReaderError>,
-> Future for DeserializerFuture<'facet, '_, BORROW, C>
+    S: StreamSource + Unpin,
+> Future for DeserializerFuture<'facet, '_, BORROW, C, S>
 {
     type Output = Result<Partial<'facet, BORROW>, DeserializeError>;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        match Iterator::next(&mut self.de) {
-            Some(Ok(())) => {
-                cx.waker().wake_by_ref();
-                Poll::Pending
-            }
-            Some(Err(err)) => Poll::Ready(Err(err)),
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
 
-            None => {
-                let starting_frame = self.de.starting_frame();
-                let mut partial = self.de.complete_mut()?;
+        for _ in 0..Self::STEP_BUDGET {
+            // Wait for more input before retrying a pending step.
+            if this.de.is_pending() {
+                let Some(buffer) = this.buffer else {
+                    return Poll::Ready(Err(DeserializeError));
+                };
 
-                // Make sure the `Partial` is at the correct frame.
-                while partial.frame_count() > starting_frame {
-                    partial = partial.end()?;
+                match Pin::new(&mut this.source).poll_fill(cx, &mut buffer.borrow_mut()) {
+                    // The source is exhausted.
+                    Poll::Ready(Ok(0)) => return Poll::Ready(Err(DeserializeError)),
+                    Poll::Ready(Ok(_)) => {}
+                    Poll::Ready(Err(err)) => return Poll::Ready(Err(err.into())),
+                    Poll::Pending => return Poll::Pending,
                 }
+            }
 
-                Poll::Ready(Ok(partial))
+            match Iterator::next(&mut this.de) {
+                Some(Ok(())) => {}
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+
+                None => {
+                    let starting_frame = this.de.starting_frame();
+                    let mut partial = this.de.complete_mut()?;
+
+                    // Make sure the `Partial` is at the correct frame.
+                    while partial.frame_count() > starting_frame {
+                        partial = partial.end()?;
+                    }
+
+                    return Poll::Ready(Ok(partial));
+                }
             }
         }
+
+        // Yield to let other tasks run.
+        cx.waker().wake_by_ref();
+        Poll::Pending
     }
 }
@@ -1,3 +1,4 @@
+use alloc::boxed::Box;
 use core::fmt;
 
 use facet::{Attr, Facet, Field, Partial, Shape};
@@ -8,12 +9,14 @@ use crate::ReaderError;
 pub(super) enum StackItem {
     Item(DeserializeDesc),
     Fields(usize, &'static [Field], bool),
+    End,
 
     Seq(usize, bool, bool),
     Map(usize, bool, bool, bool),
     Set(usize, bool, bool),
 
     Other(DeserializeDesc),
+    Owned,
 }
 
 /// A [`Deserializer`] item.
@@ -22,6 +25,18 @@ pub enum Item<'facet, const BORROW: bool> {
     Hint(u32, Partial<'facet, BORROW>),
     /// An item to be deserialized.
     Item(DeserializeItem<'facet, BORROW>),
+    /// An item that could not be deserialized without more input.
+    ///
+    /// Contains the unmodified item that was given to the core,
+    /// which will be retried once more input is available.
+    Pending(Box<Item<'facet, BORROW>>),
+}
+
+impl<const BORROW: bool> Item<'_, BORROW> {
+    /// Mark this [`Item`] as waiting for more input.
+    #[inline]
+    #[must_use]
+    pub fn pending(self) -> Self { Item::Pending(Box::new(self)) }
 }
 
 impl StackItem {
@@ -32,10 +47,12 @@ impl StackItem {
         match self {
             StackItem::Item(_) => "Item",
             StackItem::Fields(..) => "Fields",
+            StackItem::End => "End",
             StackItem::Seq(..) => "Seq",
             StackItem::Map(..) => "Map",
             StackItem::Set(..) => "Set",
             StackItem::Other(_) => "Other",
+            StackItem::Owned => "Owned",
         }
     }
 }
@@ -106,6 +123,11 @@ impl<'facet, const BORROW: bool> DeserializeItem<'facet, BORROW> {
         Self::new(partial, DeserializeDesc::new(false, None))
     }
 
+    /// Get the [`DeserializeDesc`] of the [`DeserializeItem`].
+    #[inline]
+    #[must_use]
+    pub const fn desc(&self) -> DeserializeDesc { self.desc }
+
     /// Get the inner [`Partial`] of the [`DeserializeItem`].
     #[inline]
     #[must_use]
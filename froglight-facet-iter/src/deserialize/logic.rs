@@ -1,11 +1,17 @@
 //! TODO
 #![expect(clippy::match_same_arms, reason = "Ignored")]
 
-use facet::{Def, KnownPointer, Partial, PointerType, SequenceType, Type, UserType};
+use alloc::vec::Vec;
+use core::alloc::Layout;
+
+use facet::{
+    Def, Facet, KnownPointer, Partial, PointerType, PtrUninit, SequenceType, Type, UserType,
+};
 use smallvec::SmallVec;
 
 use crate::{
     ReaderError,
+    cache::typeplan::typeplan_for,
     deserialize::{
         DeserializeError,
         item::{DeserializeDesc, DeserializeItem, Item, StackItem},
@@ -25,6 +31,45 @@ struct DeserializeIterator<'facet, const BORROW: bool> {
 
     partial: Partial<'facet, BORROW>,
     stack: SmallVec<[StackItem; 12]>,
+    pending: bool,
+
+    /// `Cow`s waiting for their owned values to be deserialized.
+    ///
+    /// Declared after `partial` so that partially deserialized owned values
+    /// are dropped before their allocations are freed.
+    owned: Vec<OwnedCow<'facet, BORROW>>,
+}
+
+/// A `Cow` waiting for its owned value to be deserialized.
+struct OwnedCow<'facet, const BORROW: bool> {
+    /// The starting frame count of the `Partial` containing the `Cow`.
+    start: usize,
+    /// The `Partial` containing the `Cow`.
+    partial: Partial<'facet, BORROW>,
+    /// The allocation the owned value is deserialized into.
+    alloc: OwnedAlloc,
+}
+
+/// An allocation for an owned value, freed when dropped.
+struct OwnedAlloc {
+    ptr: PtrUninit,
+    layout: Layout,
+}
+
+impl Drop for OwnedAlloc {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` was allocated via `alloc_for_layout`,
+        // and any value inside has already been dropped or moved.
+        unsafe { facet::dealloc_for_layout(self.ptr.assume_init(), self.layout) };
+    }
+}
+
+/// The result of requesting a hint from the core.
+enum Hint<'facet, const BORROW: bool> {
+    /// The hint was read.
+    Ready(u32, Partial<'facet, BORROW>),
+    /// The hint could not be read without more input.
+    Pending(Partial<'facet, BORROW>),
 }
 
 impl<
@@ -51,6 +96,8 @@ impl<
                 namespace,
                 partial,
                 stack,
+                pending: false,
+                owned: Vec::new(),
             }),
             core,
         }
@@ -65,6 +112,15 @@ impl<
         if let Ok(iter) = &self.iter { iter.start } else { 0 }
     }
 
+    /// Returns `true` if the last step is waiting for more input.
+    ///
+    /// The step will be retried the next time the iterator is advanced.
+    #[inline]
+    #[must_use]
+    pub const fn is_pending(&self) -> bool {
+        if let Ok(iter) = &self.iter { iter.pending } else { false }
+    }
+
     /// Returns `true` if the iterator is finished.
     #[inline]
     #[must_use]
@@ -86,6 +142,11 @@ impl<
         // Drive the iterator to completion.
         while let Some(result) = Iterator::next(&mut self) {
             result?;
+
+            // No more input will be provided.
+            if self.is_pending() {
+                return Err(DeserializeError);
+            }
         }
 
         // Make sure the `Partial` is at the correct frame.
@@ -110,6 +171,11 @@ impl<
         // Drive the iterator to completion.
         while let Some(result) = Iterator::next(self) {
             result?;
+
+            // No more input will be provided.
+            if self.is_pending() {
+                return Err(DeserializeError);
+            }
         }
 
         // Make sure the `Partial` is at the correct frame.
@@ -151,10 +217,13 @@ impl<
 
 impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
     /// Process one `step` of the deserialization iterator.
+    #[allow(clippy::too_many_lines, reason = "Ignored")]
     fn process<C: FnMut(Item<'facet, BORROW>) -> Result<Item<'facet, BORROW>, ReaderError>>(
         mut self,
         core: &mut C,
     ) -> Result<Self, DeserializeError> {
+        self.pending = false;
+
         while let Some(item) = self.stack.pop() {
             #[cfg(feature = "tracing_ext")]
             if matches!(item, StackItem::Item(..)) {
@@ -167,8 +236,17 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                 StackItem::Item(desc) => {
                     // Deserialize the item.
                     let item = Item::Item(DeserializeItem::new(self.partial, desc));
-                    let Item::Item(item) = core(item)? else { todo!() };
-                    self.partial = item.into_inner().0;
+                    self.partial = match core(item)? {
+                        Item::Item(item) => item.into_inner().0,
+                        Item::Pending(item) => match *item {
+                            Item::Item(item) => {
+                                self.partial = item.into_inner().0;
+                                return Ok(self.suspend(StackItem::Item(desc)));
+                            }
+                            _ => return Err(DeserializeError),
+                        },
+                        Item::Hint(..) => return Err(DeserializeError),
+                    };
 
                     if self.partial.frame_count() > self.start {
                         self.partial = self.partial.end()?;
@@ -199,19 +277,39 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                     self.partial = self.partial.begin_nth_field(len - fields.len() - 1)?;
                 }
 
+                StackItem::End => {
+                    if self.partial.frame_count() > self.start {
+                        self.partial = self.partial.end()?;
+                    }
+                }
+
                 StackItem::Seq(len, _end_prev, variable) => {
-                    while !matches!(self.partial.shape().def, Def::List(..) | Def::Slice(..)) {
+                    while !matches!(
+                        self.partial.shape().def,
+                        Def::List(..) | Def::Slice(..) | Def::Array(..)
+                    ) && !self.partial.is_building_smart_ptr_slice()
+                    {
                         self.partial = self.partial.end()?;
                     }
 
                     if len == 0 {
-                        self.partial = self.partial.end()?;
+                        // Slice builders are converted in place, even at the root.
+                        if self.partial.frame_count() > self.start
+                            || self.partial.is_building_smart_ptr_slice()
+                        {
+                            self.partial = self.partial.end()?;
+                        }
                     } else {
                         // Get the next item in the sequence.
 
                         self.stack.push(StackItem::Seq(len - 1, true, variable));
                         self.stack.push(StackItem::Other(DeserializeDesc::new(variable, None)));
-                        self.partial = self.partial.begin_list_item()?;
+                        self.partial = if let Def::Array(def) = self.partial.shape().def {
+                            // Arrays are filled by index instead of pushed to.
+                            self.partial.begin_nth_field(def.n - len)?
+                        } else {
+                            self.partial.begin_list_item()?
+                        };
                     }
                 }
 
@@ -221,7 +319,9 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                     }
 
                     if len == 0 {
-                        self.partial = self.partial.end()?;
+                        if self.partial.frame_count() > self.start {
+                            self.partial = self.partial.end()?;
+                        }
                     } else if is_value {
                         // `true` means the item is a value
 
@@ -242,7 +342,9 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                     }
 
                     if len == 0 {
-                        self.partial = self.partial.end()?;
+                        if self.partial.frame_count() > self.start {
+                            self.partial = self.partial.end()?;
+                        }
                     } else {
                         // Get the next item in the set.
 
@@ -252,8 +354,46 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                     }
                 }
 
+                StackItem::Owned => {
+                    let Some(OwnedCow { start, partial, alloc }) = self.owned.pop() else {
+                        return Err(DeserializeError);
+                    };
+
+                    // Finish the owned value and return to the `Cow`.
+                    let mut owned = core::mem::replace(&mut self.partial, partial);
+                    while owned.frame_count() > self.start {
+                        owned = owned.end()?;
+                    }
+                    owned.finish_in_place()?;
+                    self.start = start;
+
+                    // SAFETY: `Cow::Owned` has the same size as its owned value,
+                    // so it is stored without a separate discriminant and has the
+                    // same representation. The value is moved and not dropped.
+                    self.partial = unsafe {
+                        self.partial.set_from_function(|ptr| {
+                            core::ptr::copy_nonoverlapping(
+                                alloc.ptr.as_byte_ptr(),
+                                ptr.as_mut_byte_ptr(),
+                                alloc.layout.size(),
+                            );
+                            Ok(())
+                        })
+                    }?;
+                    drop(alloc);
+
+                    if self.partial.frame_count() > self.start {
+                        self.partial = self.partial.end()?;
+                    }
+                }
+
                 StackItem::Other(desc) => {
                     self = self.handle_other(desc, core)?;
+
+                    // Wait for more input before continuing.
+                    if self.pending {
+                        return Ok(self);
+                    }
                 }
             }
         }
@@ -261,6 +401,28 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
         Ok(self)
     }
 
+    /// Request a hint, such as a length or discriminant, from the core.
+    fn hint<C: FnMut(Item<'facet, BORROW>) -> Result<Item<'facet, BORROW>, ReaderError>>(
+        partial: Partial<'facet, BORROW>,
+        core: &mut C,
+    ) -> Result<Hint<'facet, BORROW>, DeserializeError> {
+        match core(Item::Hint(0, partial))? {
+            Item::Hint(hint, partial) => Ok(Hint::Ready(hint, partial)),
+            Item::Pending(item) => match *item {
+                Item::Hint(_, partial) => Ok(Hint::Pending(partial)),
+                _ => Err(DeserializeError),
+            },
+            Item::Item(..) => Err(DeserializeError),
+        }
+    }
+
+    /// Wait for more input, retrying `item` afterwards.
+    fn suspend(mut self, item: StackItem) -> Self {
+        self.stack.push(item);
+        self.pending = true;
+        self
+    }
+
     #[inline(always)]
     #[allow(clippy::inline_always, reason = "Used once per `C`")]
     fn handle_other<C: FnMut(Item<'facet, BORROW>) -> Result<Item<'facet, BORROW>, ReaderError>>(
@@ -334,6 +496,7 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
 
     #[inline(always)]
     #[allow(clippy::inline_always, reason = "Used once per `C`")]
+    #[allow(clippy::too_many_lines, reason = "Ignored")]
     fn handle_def<C: FnMut(Item<'facet, BORROW>) -> Result<Item<'facet, BORROW>, ReaderError>>(
         mut self,
         desc: DeserializeDesc,
@@ -347,14 +510,26 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
             }
 
             Def::Map(..) => {
-                let Item::Hint(len, partial) = core(Item::Hint(0, self.partial))? else { todo!() };
+                let (len, partial) = match Self::hint(self.partial, core)? {
+                    Hint::Ready(len, partial) => (len, partial),
+                    Hint::Pending(partial) => {
+                        self.partial = partial;
+                        return Ok(self.suspend(StackItem::Other(desc)));
+                    }
+                };
                 self.partial = partial.init_map()?;
 
                 self.stack.push(StackItem::Map(len as usize, false, false, desc.is_variable()));
                 Ok(self)
             }
             Def::Set(..) => {
-                let Item::Hint(len, partial) = core(Item::Hint(0, self.partial))? else { todo!() };
+                let (len, partial) = match Self::hint(self.partial, core)? {
+                    Hint::Ready(len, partial) => (len, partial),
+                    Hint::Pending(partial) => {
+                        self.partial = partial;
+                        return Ok(self.suspend(StackItem::Other(desc)));
+                    }
+                };
                 self.partial = partial.init_set()?;
 
                 self.stack.push(StackItem::Set(len as usize, false, desc.is_variable()));
@@ -371,7 +546,13 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                     return Ok(self);
                 }
 
-                let Item::Hint(len, partial) = core(Item::Hint(0, self.partial))? else { todo!() };
+                let (len, partial) = match Self::hint(self.partial, core)? {
+                    Hint::Ready(len, partial) => (len, partial),
+                    Hint::Pending(partial) => {
+                        self.partial = partial;
+                        return Ok(self.suspend(StackItem::Other(desc)));
+                    }
+                };
                 self.partial = partial.init_list_with_capacity(len as usize)?;
 
                 self.stack.push(StackItem::Seq(len as usize, false, desc.is_variable()));
@@ -385,20 +566,27 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
             }
 
             Def::Option(..) => {
-                let Item::Hint(variant, partial) = (core)(Item::Hint(0, self.partial))? else {
-                    todo!()
+                let (variant, partial) = match Self::hint(self.partial, core)? {
+                    Hint::Ready(variant, partial) => (variant, partial),
+                    Hint::Pending(partial) => {
+                        self.partial = partial;
+                        return Ok(self.suspend(StackItem::Other(desc)));
+                    }
                 };
 
                 match variant {
                     0 => {
                         // Set `None`
                         self.partial = partial.set_default()?;
-                        self.partial = self.partial.end()?;
+                        if self.partial.frame_count() > self.start {
+                            self.partial = self.partial.end()?;
+                        }
                         Ok(self)
                     }
                     1 => {
-                        // Begin `Some`
+                        // Begin `Some`, ending the outer frame afterwards
                         self.partial = partial.begin_some()?;
+                        self.stack.push(StackItem::End);
                         self.stack.push(StackItem::Other(desc));
                         Ok(self)
                     }
@@ -407,34 +595,115 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                 }
             }
             Def::Result(..) => {
-                let Item::Hint(variant, partial) = (core)(Item::Hint(0, self.partial))? else {
-                    todo!()
+                let (variant, partial) = match Self::hint(self.partial, core)? {
+                    Hint::Ready(variant, partial) => (variant, partial),
+                    Hint::Pending(partial) => {
+                        self.partial = partial;
+                        return Ok(self.suspend(StackItem::Other(desc)));
+                    }
                 };
 
                 match variant {
-                    0 => {
-                        // Begin `Ok`
+                    1 => {
+                        // Begin `Ok`, ending the outer frame afterwards
                         self.partial = partial.begin_ok()?;
+                        self.stack.push(StackItem::End);
                         self.stack.push(StackItem::Other(desc));
                         Ok(self)
                     }
-                    1 => {
-                        // Begin `Err`
+                    0 => {
+                        // Begin `Err`, ending the outer frame afterwards
                         self.partial = partial.begin_err()?;
+                        self.stack.push(StackItem::End);
                         self.stack.push(StackItem::Other(desc));
                         Ok(self)
                     }
-                    _ => todo!("Invalid ID `{variant}` for `Result`"),
+                    inv => Err(ReaderError::from_string(alloc::format!(
+                        "Invalid variant `{inv}` for `{}`",
+                        partial.shape()
+                    )))?,
                 }
             }
 
-            Def::Pointer(def) if def.constructible_from_pointee() => match def.known {
-                Some(KnownPointer::Cow) => {
-                    self.stack.push(StackItem::Item(desc));
-                    Ok(self)
-                }
-                _ => todo!("\n\nTODO: PointerType {} -> {def:?}\n\n", self.partial.shape()),
-            },
+            // Pass `Cow<str>` and `Cow<[u8]>` directly to the core.
+            Def::Pointer(def)
+                if matches!(def.known, Some(KnownPointer::Cow))
+                    && def.pointee().is_some_and(|pointee| {
+                        *pointee == *str::SHAPE
+                            || matches!(pointee.def, Def::Slice(slice) if *slice.t == *u8::SHAPE)
+                    }) =>
+            {
+                self.stack.push(StackItem::Item(desc));
+                Ok(self)
+            }
+            // Deserialize other `Cow`s as their owned type, e.g. `Cow<[T]>` as a `Vec<T>`.
+            Def::Pointer(def) if matches!(def.known, Some(KnownPointer::Cow)) => {
+                let shape = self.partial.shape();
+                let owned = shape
+                    .type_params
+                    .iter()
+                    .find_map(|param| (param.name == "Owned").then_some(param.shape))
+                    .ok_or_else(|| {
+                        ReaderError::from_string(alloc::format!(
+                            "Failed to get owned type for type {shape}"
+                        ))
+                    })?;
+
+                // `Cow::Owned` can only be written directly if it is stored
+                // without a separate discriminant.
+                let layout = owned.layout.sized_layout().ok();
+                let Some(layout) = layout.filter(|layout| {
+                    shape.layout.sized_layout().is_ok_and(|cow| cow.size() == layout.size())
+                }) else {
+                    return Err(ReaderError::from_string(alloc::format!(
+                        "Unsupported layout for type {shape}"
+                    )))?;
+                };
+
+                // SAFETY: The shape belongs to the owned type of the `Cow`.
+                let plan = unsafe { typeplan_for(owned) }?;
+                let root = plan.root_id();
+                let alloc = OwnedAlloc { ptr: facet::alloc_for_layout(layout), layout };
+                // SAFETY: `alloc` was allocated for the layout of `owned`.
+                let partial = unsafe { Partial::from_raw(alloc.ptr, plan, root) }?;
+
+                // Deserialize the owned value, moving it into the `Cow` afterwards.
+                let partial = core::mem::replace(&mut self.partial, partial);
+                let start = core::mem::replace(&mut self.start, self.partial.frame_count());
+                self.owned.push(OwnedCow { start, partial, alloc });
+
+                self.stack.push(StackItem::Owned);
+                self.stack.push(StackItem::Other(desc));
+                Ok(self)
+            }
+            // Deserialize `Box<[T]>`, `Arc<[T]>`, etc. as lists.
+            Def::Pointer(def)
+                if def.constructible_from_pointee()
+                    && def.pointee().is_some_and(|pointee| {
+                        matches!(pointee.ty, Type::Sequence(SequenceType::Slice(..)))
+                    }) =>
+            {
+                let (len, partial) = match Self::hint(self.partial, core)? {
+                    Hint::Ready(len, partial) => (len, partial),
+                    Hint::Pending(partial) => {
+                        self.partial = partial;
+                        return Ok(self.suspend(StackItem::Other(desc)));
+                    }
+                };
+                self.partial = partial.begin_smart_ptr()?;
+
+                // Convert the slice builder, ending the pointer frame afterwards.
+                self.stack.push(StackItem::End);
+                self.stack.push(StackItem::Seq(len as usize, false, desc.is_variable()));
+                Ok(self)
+            }
+            Def::Pointer(def) if def.constructible_from_pointee() => {
+                // Begin the pointee, ending the pointer frame afterwards.
+                self.partial = self.partial.begin_smart_ptr()?;
+                self.stack.push(StackItem::End);
+                self.stack.push(StackItem::Other(desc));
+                Ok(self)
+            }
 
             // Fallback to `Type` for other/undefined types.
             _ => self.handle_type(desc, core),
@@ -497,9 +766,12 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                 #[expect(clippy::cast_possible_wrap, reason = "Expected behavior")]
                 {
                     // Deserialize the discriminant of the enum.
-                    let Item::Hint(discriminant, partial) = core(Item::Hint(0, self.partial))?
-                    else {
-                        todo!()
+                    let (discriminant, partial) = match Self::hint(self.partial, core)? {
+                        Hint::Ready(discriminant, partial) => (discriminant, partial),
+                        Hint::Pending(partial) => {
+                            self.partial = partial;
+                            return Ok(self.suspend(StackItem::Other(desc)));
+                        }
                     };
 
                     self.partial = partial.select_variant(i64::from(discriminant as i32))?;
@@ -519,7 +791,11 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
 
                 Ok(self)
             }
-            Type::User(..) => todo!(),
+            // Opaque types can only be deserialized by the core.
+            Type::User(UserType::Opaque) => {
+                self.stack.push(StackItem::Item(desc));
+                Ok(self)
+            }
 
             // Just pass it to the core for deserialization.
             Type::Pointer(PointerType::Reference(..)) => {
@@ -527,7 +803,10 @@ impl<'facet, const BORROW: bool> DeserializeIterator<'facet, BORROW> {
                 Ok(self)
             }
 
-            _ => todo!("Unsupported type `{}`", self.partial.shape()),
+            _ => Err(ReaderError::from_string(alloc::format!(
+                "Unsupported type `{}`",
+                self.partial.shape()
+            )))?,
         }
     }
 }
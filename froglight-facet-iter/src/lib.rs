@@ -9,6 +9,7 @@ pub mod cache;
 pub mod deserialize;
 pub mod serialize;
 pub mod solver;
+pub mod stream;
 
 mod reader;
 pub use reader::{Reader, ReaderError};
@@ -223,6 +223,7 @@ impl<'mem, 'facet, C: FnMut(Item<'mem, 'facet>) -> Result<(), WriterError>>
 
     #[inline(always)]
     #[allow(clippy::inline_always, reason = "Used once per `C`")]
+    #[allow(clippy::too_many_lines, reason = "Ignored")]
     fn handle_def(&mut self, item: SerializeItem<'mem, 'facet>) -> Result<(), SerializeError> {
         /// A tiny cache for keeping collected values on the stack.
         type Cache<T> = SmallVec<[T; 8]>;
@@ -269,7 +270,7 @@ impl<'mem, 'facet, C: FnMut(Item<'mem, 'facet>) -> Result<(), WriterError>>
             }
 
             Def::List(..) | Def::Slice(..) => {
-                let list = item.peek().into_list()?;
+                let list = item.peek().into_list_like()?;
                 // Serialize the length of the list.
                 (self.core)(Item::Hint(
                     list.len().try_into().map_err(WriterError::other)?,
@@ -348,10 +349,26 @@ impl<'mem, 'facet, C: FnMut(Item<'mem, 'facet>) -> Result<(), WriterError>>
                 Ok(())
             }
 
+            Def::Pointer(..) => {
+                // Serialize the pointee in place of the pointer.
+                let Some(pointee) = item.peek().into_pointer()?.borrow_inner() else {
+                    return Err(WriterError::from_string(alloc::format!(
+                        "Unable to borrow the pointee of `{}`",
+                        item.shape()
+                    )))?;
+                };
+                self.stack.push(SerializeItem::new(pointee, ItemType::Other, item.is_variable()));
+
+                Ok(())
+            }
+
             // Fallback to `Type` for undefined types.
             Def::Undefined => self.handle_type(item),
 
-            _ => todo!("Unsupported type `{}`: {:?}", item.shape(), item.peek()),
+            _ => Err(WriterError::from_string(alloc::format!(
+                "Unsupported type `{}`",
+                item.shape()
+            )))?,
         }
     }
 
@@ -362,8 +379,8 @@ impl<'mem, 'facet, C: FnMut(Item<'mem, 'facet>) -> Result<(), WriterError>>
         type Cache<T> = SmallVec<[T; 8]>;
 
         match item.shape().ty {
-            // Directly serialize primitives.
-            Type::Primitive(..) => {
+            // Directly serialize primitives, and let the core serialize opaque types.
+            Type::Primitive(..) | Type::User(UserType::Opaque) => {
                 self.stack.push(item.with_ty(ItemType::Value));
                 Ok(())
             }
@@ -474,11 +491,10 @@ impl<'mem, 'facet, C: FnMut(Item<'mem, 'facet>) -> Result<(), WriterError>>
 
                 Ok(())
             }
-            Type::User(..) => todo!(),
-
-            Type::Pointer(..) => todo!(),
-
-            _ => todo!("Unsupported type `{}`: {:?}", item.shape(), item.peek()),
+            _ => Err(WriterError::from_string(alloc::format!(
+                "Unsupported type `{}`",
+                item.shape()
+            )))?,
         }
     }
 }
@@ -69,7 +69,10 @@ pub fn navigate_tree<'data, 'core: 'data, T: TreeMap, const BORROW: bool>(
                     shape = field.shape();
                 }
 
-                _ => todo!(),
+                _ => Err(ReaderError::from_string(alloc::format!(
+                    "Failed to get field with index {index} for type {:?}",
+                    shape.type_name()
+                )))?,
             },
 
             PathStep::Variant(index) => match shape.ty {
@@ -100,7 +103,10 @@ pub fn navigate_tree<'data, 'core: 'data, T: TreeMap, const BORROW: bool>(
                     // Update the shape.
                     shape = field.shape();
                 }
-                _ => todo!(),
+                _ => Err(ReaderError::from_string(alloc::format!(
+                    "Failed to get variant with index {index} for type {:?}",
+                    shape.type_name()
+                )))?,
             },
 
             PathStep::Index(index) => {
@@ -133,9 +139,79 @@ pub fn navigate_tree<'data, 'core: 'data, T: TreeMap, const BORROW: bool>(
                 }
             }
 
-            PathStep::Deref => {}
+            PathStep::MapValue(index) => {
+                let Def::Map(def) = shape.def else {
+                    return Err(ReaderError::from_string(alloc::format!(
+                        "Failed to get map value type for type {:?}",
+                        shape.type_name()
+                    )));
+                };
+
+                let map = T::value_map(value).ok_or_else(|| {
+                    ReaderError::from_string(alloc::format!(
+                        "Failed to get map for type {:?}",
+                        shape.type_name()
+                    ))
+                })?;
+
+                let (_, entry) =
+                    T::map_iter(map).into_iter().nth(*index as usize).ok_or_else(|| {
+                        ReaderError::from_string(alloc::format!(
+                            "Failed to get map entry with index {index} for type {:?}",
+                            shape.type_name()
+                        ))
+                    })?;
+
+                // Update the shape and value.
+                shape = def.v;
+                value = entry;
+            }
+
+            PathStep::OptionSome => match shape.def {
+                Def::Option(def) => shape = def.t,
+                _ => Err(ReaderError::from_string(alloc::format!(
+                    "Failed to get option item type for type {:?}",
+                    shape.type_name()
+                )))?,
+            },
+
+            PathStep::Deref => match shape.def {
+                Def::Pointer(def) => {
+                    shape = def.pointee().ok_or_else(|| {
+                        ReaderError::from_string(alloc::format!(
+                            "Failed to get pointee type for type {:?}",
+                            shape.type_name()
+                        ))
+                    })?;
+                }
+                _ => Err(ReaderError::from_string(alloc::format!(
+                    "Failed to dereference type {:?}",
+                    shape.type_name()
+                )))?,
+            },
+
+            PathStep::Inner => {
+                shape = shape.inner.ok_or_else(|| {
+                    ReaderError::from_string(alloc::format!(
+                        "Failed to get inner type for type {:?}",
+                        shape.type_name()
+                    ))
+                })?;
+            }
+
+            PathStep::Proxy => {
+                shape = shape.effective_proxy(None).map(|proxy| proxy.shape).ok_or_else(|| {
+                    ReaderError::from_string(alloc::format!(
+                        "Failed to get proxy type for type {:?}",
+                        shape.type_name()
+                    ))
+                })?;
+            }
 
-            other => todo!("Unhandled navigation step: {other:?}"),
+            other => Err(ReaderError::from_string(alloc::format!(
+                "Unable to navigate {other:?} for type {:?}",
+                shape.type_name()
+            )))?,
         }
     }
 
@@ -0,0 +1,166 @@
+//! Input for resumable deserialization.
+//!
+//! A [`StreamBuffer`] holds the bytes received so far.
+//! A [`StreamSource`] appends more bytes to it as they arrive,
+//! which a [`DeserializerFuture`](crate::deserialize::DeserializerFuture)
+//! waits for whenever the [`Deserializer`](crate::deserialize::Deserializer)
+//! runs out of input.
+
+use alloc::vec::Vec;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{Reader, ReaderError};
+
+/// A buffer of input that can be fed more bytes as they arrive.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StreamBuffer {
+    buffer: Vec<u8>,
+    position: usize,
+    consumed: usize,
+}
+
+impl StreamBuffer {
+    /// The minimum number of bytes to read from a [`StreamSource`] at once.
+    pub const MIN_READ: usize = 512;
+
+    /// Create a new, empty [`StreamBuffer`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self { Self { buffer: Vec::new(), position: 0, consumed: 0 } }
+
+    /// Create a new, empty [`StreamBuffer`] with the given capacity.
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { buffer: Vec::with_capacity(capacity), position: 0, consumed: 0 }
+    }
+
+    /// Get the bytes that have been received but not yet consumed.
+    #[inline]
+    #[must_use]
+    pub fn unread(&self) -> &[u8] { &self.buffer[self.position..] }
+
+    /// Returns `true` if there are no unread bytes.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool { self.position == self.buffer.len() }
+
+    /// Get the total number of bytes consumed from this buffer.
+    #[inline]
+    #[must_use]
+    pub const fn consumed(&self) -> usize { self.consumed }
+
+    /// Create a [`Reader`] over the unread bytes.
+    #[inline]
+    #[must_use]
+    pub fn reader(&self) -> Reader<'_> { Reader::new(self.unread()) }
+
+    /// Mark the next `count` unread bytes as consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than `count` unread bytes.
+    pub fn consume(&mut self, count: usize) {
+        assert!(count <= self.buffer.len() - self.position, "Consumed more bytes than received");
+        self.position += count;
+        self.consumed += count;
+    }
+
+    /// Append bytes to the end of the buffer.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.compact();
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Append bytes to the end of the buffer using a closure,
+    /// which is given spare space to write into and returns how much it wrote.
+    pub fn fill_with(&mut self, fill: impl FnOnce(&mut [u8]) -> usize) -> usize {
+        self.compact();
+
+        let length = self.buffer.len();
+        self.buffer.resize(length + Self::MIN_READ.max(self.buffer.capacity() - length), 0);
+        let written = fill(&mut self.buffer[length..]).min(self.buffer.len() - length);
+
+        self.buffer.truncate(length + written);
+        written
+    }
+
+    /// Remove consumed bytes from the start of the buffer,
+    /// if they take up at least half of it.
+    fn compact(&mut self) {
+        if self.position != 0 && self.position >= self.buffer.len() / 2 {
+            self.buffer.drain(..self.position);
+            self.position = 0;
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A source of bytes for a [`StreamBuffer`].
+pub trait StreamSource {
+    /// Attempt to append more bytes to the buffer.
+    ///
+    /// Returns the number of bytes appended, or `0` if the source is
+    /// exhausted. If no bytes are available yet, the source must arrange for
+    /// the current task to be woken once they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source fails.
+    fn poll_fill(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buffer: &mut StreamBuffer,
+    ) -> Poll<Result<usize, ReaderError>>;
+}
+
+/// A source that never provides any bytes.
+impl StreamSource for () {
+    #[inline]
+    fn poll_fill(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        _: &mut StreamBuffer,
+    ) -> Poll<Result<usize, ReaderError>> {
+        Poll::Ready(Ok(0))
+    }
+}
+
+impl<S: StreamSource + Unpin + ?Sized> StreamSource for &mut S {
+    #[inline]
+    fn poll_fill(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buffer: &mut StreamBuffer,
+    ) -> Poll<Result<usize, ReaderError>> {
+        Pin::new(&mut **self.get_mut()).poll_fill(cx, buffer)
+    }
+}
+
+/// A [`StreamSource`] that reads from a [`futures_lite::AsyncRead`].
+#[cfg(feature = "futures-lite")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsyncReadSource<R>(pub R);
+
+#[cfg(feature = "futures-lite")]
+impl<R: futures_lite::AsyncRead + Unpin> StreamSource for AsyncReadSource<R> {
+    fn poll_fill(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buffer: &mut StreamBuffer,
+    ) -> Poll<Result<usize, ReaderError>> {
+        let mut reader = Pin::new(&mut self.get_mut().0);
+
+        let mut poll = Poll::Pending;
+        buffer.fill_with(|spare| {
+            poll = reader.as_mut().poll_read(cx, spare);
+            if let Poll::Ready(Ok(read)) = poll { read } else { 0 }
+        });
+
+        poll.map_err(ReaderError::IO)
+    }
+}
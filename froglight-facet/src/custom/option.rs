@@ -83,7 +83,7 @@ impl<T: FacetTemplate> FacetTemplate for OptionTemplate<T> {
                     let mut item = DeserializeItem::new_partial(partial).with_variable(variable);
                     item = T::WITH.deserialize(item, reader)?;
 
-                    Ok(item.into_inner().0)
+                    Ok(item.into_inner().0.end()?)
                 })
             }
             unk => Err(ReaderError::InvalidBool(unk)),
@@ -109,7 +109,7 @@ impl<T: FacetBorrowedTemplate> FacetBorrowedTemplate for OptionTemplate<T> {
                     let mut item = DeserializeItem::new_partial(partial).with_variable(variable);
                     item = T::WITH.deserialize_borrowed(item, reader)?;
 
-                    Ok(item.into_inner().0)
+                    Ok(item.into_inner().0.end()?)
                 })
             }
             unk => Err(ReaderError::InvalidBool(unk)),
@@ -205,7 +205,7 @@ macro_rules! option_with {
                                 .with_variable(variable);
                         item = ($with.de_owned)(item, reader)?;
 
-                        Ok(item.into_inner().0)
+                        Ok(item.into_inner().0.end()?)
                     })
                 }
                 unk => Err($crate::facet::prelude::ReaderError::InvalidBool(unk)),
@@ -225,7 +225,7 @@ macro_rules! option_with {
                                 .with_variable(variable);
                         item = ($with.de_owned_borrow)(item, reader)?;
 
-                        Ok(item.into_inner().0)
+                        Ok(item.into_inner().0.end()?)
                     })
                 }
                 unk => Err($crate::facet::prelude::ReaderError::InvalidBool(unk)),
@@ -1,9 +1,12 @@
 //! TODO
 
+use core::cell::RefCell;
+
 use facet::{HeapValue, Partial};
 use froglight_facet_iter::{
     Reader,
     deserialize::{DeserializeError, Deserializer, DeserializerFuture},
+    stream::{StreamBuffer, StreamSource},
 };
 
 use crate::deserialize::Deserialize;
@@ -37,6 +40,22 @@ pub trait DeserializeAsync: Deserialize<'static> {
         slice: &[u8],
         variable: bool,
     ) -> impl Future<Output = Result<(Self, &[u8]), DeserializeError>> + '_;
+
+    /// Deserialize a value from the given [`StreamBuffer`],
+    /// waiting for `source` to provide more bytes whenever it runs out.
+    ///
+    /// Only the bytes belonging to the value are consumed,
+    /// any remaining bytes are left in the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deserialization fails,
+    /// or if `source` is exhausted before the value is complete.
+    fn from_stream_async<'a, S: StreamSource + Unpin + 'a>(
+        buffer: &'a RefCell<StreamBuffer>,
+        source: S,
+        variable: bool,
+    ) -> impl Future<Output = Result<Self, DeserializeError>> + 'a;
 }
 
 impl<T: Deserialize<'static>> DeserializeAsync for T {
@@ -51,6 +70,19 @@ impl<T: Deserialize<'static>> DeserializeAsync for T {
             deserialize_async(Partial::alloc_owned_with_plan(plan)?, variable, &mut cursor).await?;
         Ok((value.materialize::<T>()?, cursor.remaining()))
     }
+
+    #[inline]
+    async fn from_stream_async<'a, S: StreamSource + Unpin + 'a>(
+        buffer: &'a RefCell<StreamBuffer>,
+        source: S,
+        variable: bool,
+    ) -> Result<Self, DeserializeError> {
+        let plan = froglight_facet_iter::cache::typeplan::typeplan::<T>()?;
+        let value =
+            deserialize_stream(Partial::alloc_owned_with_plan(plan)?, variable, buffer, source)
+                .await?;
+        Ok(value.materialize::<T>()?)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -66,3 +98,19 @@ async fn deserialize_async(
     let de = Deserializer::new(partial, variable, &mut core, Some("mc"));
     DeserializerFuture::from_sync(de).await?.build().map_err(DeserializeError::from)
 }
+
+#[inline(never)]
+async fn deserialize_stream<S: StreamSource + Unpin>(
+    partial: Partial<'static, false>,
+    variable: bool,
+    buffer: &RefCell<StreamBuffer>,
+    source: S,
+) -> Result<HeapValue<'static, false>, DeserializeError> {
+    // Create and complete the deserializer, waiting for input as needed.
+    let mut core = super::stream::deserialize_stream_core(buffer);
+    let de = Deserializer::new(partial, variable, &mut core, Some("mc"));
+    DeserializerFuture::from_stream(de, buffer, source)
+        .await?
+        .build()
+        .map_err(DeserializeError::from)
+}
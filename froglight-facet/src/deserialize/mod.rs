@@ -11,6 +11,7 @@ use froglight_facet_iter::{
 
 pub mod functions;
 pub mod future;
+pub mod stream;
 pub mod varint;
 
 /// A trait for types that can be deserialized.
@@ -110,6 +111,7 @@ pub fn deserialize_owned_core<const BORROW: bool>(
             Item::Hint(.., partial) => {
                 return varint::decode_u32_from(reader).map(|hint| Item::Hint(hint, partial));
             }
+            Item::Pending(..) => return Err(ReaderError::from_str("Unexpected pending item")),
         };
 
         // Handle field attributes.
@@ -170,6 +172,7 @@ pub fn deserialize_borrowed_core<'facet>(
             Item::Hint(.., partial) => {
                 return varint::decode_u32_from(reader).map(|hint| Item::Hint(hint, partial));
             }
+            Item::Pending(..) => return Err(ReaderError::from_str("Unexpected pending item")),
         };
 
         // Handle field attributes.
@@ -366,5 +369,5 @@ fn deserialize_value<'facet, const BORROW: bool>(
         return item.set(uuid::Uuid::from_bytes(*bytes));
     }
 
-    todo!("Unsupported type: `{}`", item.shape().type_name());
+    Err(ReaderError::from_string(alloc::format!("Unsupported type: `{}`", item.shape())))
 }
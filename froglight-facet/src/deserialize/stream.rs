@@ -0,0 +1,212 @@
+//! Deserialization from a [`StreamBuffer`] that may not contain the whole
+//! value yet.
+
+use alloc::{borrow::Cow, string::String, sync::Arc, vec::Vec};
+use core::cell::RefCell;
+
+use facet::{Partial, Shape, TypePlanCore};
+use froglight_facet_iter::{
+    Reader, ReaderError,
+    cache::typeplan::typeplan_for,
+    deserialize::{DeserializeItem, Item},
+    stream::StreamBuffer,
+};
+
+use crate::deserialize::{deserialize_owned_core, varint};
+
+/// The core logic for deserializing from a [`StreamBuffer`].
+///
+/// Primitives and sequences of primitives are checked against the received
+/// bytes and then decoded directly into place. Other items are decoded once
+/// into a separate allocation and then moved into place.
+///
+/// Items that cannot be read from the received bytes are returned as
+/// [`Item::Pending`] without consuming anything, and are retried once more
+/// input has been received.
+#[doc(hidden)]
+pub fn deserialize_stream_core(
+    buffer: &RefCell<StreamBuffer>,
+) -> impl FnMut(Item<'static, false>) -> Result<Item<'static, false>, ReaderError> + '_ {
+    // Type plans are cached for each shape, as the same shapes are often
+    // deserialized many times.
+    let mut plans: Vec<(&'static Shape, Arc<TypePlanCore>)> = Vec::new();
+
+    move |item: Item<'static, false>| {
+        let mut buffer = buffer.borrow_mut();
+
+        let item = match item {
+            Item::Item(item) => item,
+            Item::Hint(.., partial) => {
+                let mut reader = buffer.reader();
+                return match varint::decode_u32_from(&mut reader) {
+                    Ok(hint) => {
+                        let read = reader.position();
+                        buffer.consume(read);
+                        Ok(Item::Hint(hint, partial))
+                    }
+                    Err(ReaderError::EndOfInput(..)) => Ok(Item::Hint(0, partial).pending()),
+                    Err(err) => Err(err),
+                };
+            }
+            Item::Pending(..) => return Err(ReaderError::from_str("Unexpected pending item")),
+        };
+
+        // Check that primitives have been received before decoding them in place.
+        if let Some(complete) = skip_primitive(&item, &mut buffer.reader()) {
+            return match complete {
+                Ok(()) => {
+                    let mut reader = buffer.reader();
+                    let item = deserialize_owned_core(&mut reader)(Item::Item(item))?;
+                    let read = reader.position();
+
+                    buffer.consume(read);
+                    Ok(item)
+                }
+                Err(ReaderError::EndOfInput(..)) => Ok(Item::Item(item).pending()),
+                Err(err) => Err(err),
+            };
+        }
+
+        // Decode the value once into a separate allocation, so that the item is
+        // left untouched if the received bytes end before the value does.
+        let shape = item.shape();
+        let layout = shape.layout.sized_layout().map_err(|_| {
+            ReaderError::from_string(alloc::format!("Cannot stream unsized type `{shape}`"))
+        })?;
+        let plan = if let Some((_, plan)) = plans.iter().find(|(s, _)| core::ptr::eq(*s, shape)) {
+            Arc::clone(plan)
+        } else {
+            // SAFETY: The shape belongs to the item being deserialized.
+            let plan = unsafe { typeplan_for(shape) }.map_err(|err| {
+                ReaderError::from_string(alloc::format!("Failed to build type plan: {err}"))
+            })?;
+            plans.push((shape, Arc::clone(&plan)));
+            plan
+        };
+
+        let root = plan.root_id();
+        let uninit = facet::alloc_for_layout(layout);
+        // SAFETY: `uninit` was allocated for the layout of `shape`.
+        let probe = match unsafe { Partial::<false>::from_raw(uninit, plan, root) } {
+            Ok(probe) => probe,
+            Err(err) => {
+                // !! MUST DEALLOC BEFORE RETURNING !!
+
+                // SAFETY: `uninit` was allocated via `alloc_for_layout`.
+                unsafe { facet::dealloc_for_layout(uninit.assume_init(), layout) };
+
+                return Err(ReaderError::from_string(alloc::format!(
+                    "Failed to allocate probe: {err}"
+                )));
+            }
+        };
+
+        let mut reader = buffer.reader();
+        let decoded = deserialize_owned_core(&mut reader)(Item::Item(DeserializeItem::new(
+            probe,
+            item.desc(),
+        )))
+        .and_then(|decoded| match decoded {
+            Item::Item(decoded) => decoded.into_inner().0.finish_in_place().map_err(Into::into),
+            _ => Err(ReaderError::from_str("Unexpected item")),
+        });
+        let read = reader.position();
+
+        // SAFETY: `uninit` was allocated via `alloc_for_layout`.
+        let ptr = unsafe { uninit.assume_init() };
+        if let Err(err) = decoded {
+            // !! MUST DEALLOC BEFORE RETURNING !!
+
+            // SAFETY: The probe has already dropped anything it initialized.
+            unsafe { facet::dealloc_for_layout(ptr, layout) };
+
+            return match err {
+                ReaderError::EndOfInput(..) => Ok(Item::Item(item).pending()),
+                err => Err(err),
+            };
+        }
+
+        // Move the decoded value into the item.
+        let (partial, desc) = item.into_inner();
+        // SAFETY: `ptr` holds an initialized value of `shape`, which is moved
+        // into the item and then deallocated without being dropped.
+        let result = unsafe { partial.set_shape(ptr.as_const(), shape) };
+        if result.is_err() {
+            // SAFETY: The value was not moved, so it must be dropped here.
+            unsafe { shape.call_drop_in_place(ptr) };
+        }
+        // SAFETY: `ptr` was allocated via `alloc_for_layout`.
+        unsafe { facet::dealloc_for_layout(ptr, layout) };
+
+        buffer.consume(read);
+        Ok(Item::Item(DeserializeItem::new(result?, desc)))
+    }
+}
+
+/// Skip over a primitive or a sequence of primitives without decoding it.
+///
+/// Returns `None` if the item is not a primitive,
+/// or uses a custom deserializer.
+fn skip_primitive(
+    item: &DeserializeItem<'static, false>,
+    reader: &mut Reader<'_>,
+) -> Option<Result<(), ReaderError>> {
+    macro_rules! handle {
+        ($($ty:ty => $fn:ident),*) => {
+            $(
+                if item.is_type::<$ty>() {
+                    return Some(if item.is_variable() {
+                        varint::$fn(reader).map(drop)
+                    } else {
+                        reader.consume(size_of::<$ty>())
+                    });
+                }
+            )*
+        };
+        (@seq $($ty:ty, $elem:ty => $fn:ident),*) => {
+            $(
+                if item.is_type::<Vec<$ty>>() || item.is_type::<Cow<[$ty]>>() {
+                    return Some(varint::decode_u32_from(reader).and_then(|length| {
+                        if item.is_variable() {
+                            (0..length).try_for_each(|_| varint::$fn(reader).map(drop))
+                        } else {
+                            reader.consume(length as usize * size_of::<$elem>())
+                        }
+                    }));
+                }
+            )*
+        };
+    }
+
+    // Custom deserializers may read any amount of input.
+    let is_with = |attr: &facet::Attr| attr.ns.is_some_and(|ns| ns == "mc") && attr.key == "with";
+    if item.field_attr().unwrap_or_default().iter().chain(item.shape_attr()).any(is_with) {
+        return None;
+    }
+
+    if item.is_type::<()>() {
+        return Some(Ok(()));
+    }
+    if item.is_type::<bool>() || item.is_type::<f32>() || item.is_type::<f64>() {
+        return Some(reader.consume(item.shape().layout.sized_layout().ok()?.size()));
+    }
+
+    handle!(u8 => decode_u8_from, u16 => decode_u16_from, u32 => decode_u32_from, u64 => decode_u64_from, u128 => decode_u128_from);
+    handle!(i8 => decode_u8_from, i16 => decode_u16_from, i32 => decode_u32_from, i64 => decode_u64_from, i128 => decode_u128_from);
+
+    if item.is_type::<String>()
+        || item.is_type::<Cow<str>>()
+        || item.is_type::<Vec<u8>>()
+        || item.is_type::<Cow<[u8]>>()
+    {
+        let length = varint::decode_u32_from(reader);
+        return Some(length.and_then(|length| reader.consume(length as usize)));
+    }
+    handle!(@seq u32, u32 => decode_u32_from, u64, u64 => decode_u64_from);
+
+    if item.is_type::<uuid::Uuid>() {
+        return Some(reader.consume(16));
+    }
+
+    None
+}
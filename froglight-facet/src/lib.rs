@@ -19,3 +19,6 @@ pub use deserialize::functions::*;
 
 pub mod serialize;
 pub use serialize::functions::*;
+
+#[cfg(feature = "schema")]
+pub mod schema;
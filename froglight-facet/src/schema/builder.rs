@@ -0,0 +1,214 @@
+use alloc::{string::String, vec::Vec};
+
+use facet::{Def, Facet, Field, SequenceType, Shape, StructType, Type, UserType, Variant};
+
+use crate::schema::{
+    EnumSchema, FieldSchema, PacketSchema, StateSchema, StructSchema, VariantSchema, WireType,
+};
+
+/// The attribute namespace used for serialization.
+const NAMESPACE: Option<&str> = Some("mc");
+
+/// A builder that describes [`Shape`]s as [`WireType`]s.
+#[derive(Debug, Default, Clone)]
+pub struct SchemaBuilder {
+    overrides: Vec<(&'static Shape, WireType)>,
+    stack: Vec<&'static Shape>,
+}
+
+impl SchemaBuilder {
+    /// Create a new [`SchemaBuilder`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self { Self { overrides: Vec::new(), stack: Vec::new() } }
+
+    /// Describe the given [`Shape`] as `wire` instead of inspecting it.
+    ///
+    /// This is useful for types with custom serialization, such as NBT.
+    #[must_use]
+    pub fn with_override(mut self, shape: &'static Shape, wire: WireType) -> Self {
+        self.overrides.retain(|(existing, _)| *existing != shape);
+        self.overrides.push((shape, wire));
+        self
+    }
+
+    /// Describe the wire format of `T`.
+    #[inline]
+    #[must_use]
+    pub fn describe<'a, T: Facet<'a>>(&mut self) -> WireType {
+        self.describe_shape(T::SHAPE, false)
+    }
+
+    /// Describe the wire format of a [`Shape`].
+    #[must_use]
+    pub fn describe_shape(&mut self, shape: &'static Shape, variable: bool) -> WireType {
+        if let Some(wire) = self.get_override(shape) {
+            return wire;
+        }
+
+        // Types with custom serialization can't be inspected.
+        if shape.attributes.iter().any(|attr| attr.ns == NAMESPACE && attr.key == "with") {
+            return WireType::custom(shape);
+        }
+
+        if let Some(wire) = WireType::from_scalar(shape, variable) {
+            return wire;
+        }
+
+        match shape.def {
+            Def::List(def) => WireType::PrefixedArray { item: self.boxed(def.t, variable) },
+            Def::Slice(def) => WireType::PrefixedArray { item: self.boxed(def.t, variable) },
+            Def::Set(def) => WireType::PrefixedArray { item: self.boxed(def.t, variable) },
+            Def::Array(def) => WireType::Array { length: def.n, item: self.boxed(def.t, variable) },
+            Def::Map(def) => WireType::Map {
+                key: self.boxed(def.k, variable),
+                value: self.boxed(def.v, variable),
+            },
+
+            Def::Option(def) => WireType::Optional { item: self.boxed(def.t, variable) },
+            Def::Result(def) => WireType::Result {
+                ok: self.boxed(def.t, variable),
+                err: self.boxed(def.e, variable),
+            },
+
+            // Pointers are written as their pointee.
+            Def::Pointer(def) => match def.pointee() {
+                Some(pointee) => self.describe_shape(pointee, variable),
+                None => WireType::custom(shape),
+            },
+
+            Def::Undefined => self.describe_type(shape, variable),
+            _ => WireType::custom(shape),
+        }
+    }
+
+    /// Describe the packets of a packet enum,
+    /// using each variant's discriminant as its packet id.
+    ///
+    /// Returns an empty list if the [`Shape`] is not an enum.
+    #[must_use]
+    pub fn describe_packets(&mut self, shape: &'static Shape) -> Vec<PacketSchema> {
+        let Type::User(UserType::Enum(ty)) = shape.ty else { return Vec::new() };
+
+        let mut packets = Vec::with_capacity(ty.variants.len());
+        for (index, variant) in ty.variants.iter().enumerate() {
+            let content = if let [field] = variant.data.fields {
+                self.describe_field(field, false).ty
+            } else {
+                let fields = self.describe_fields(&variant.data, false);
+                WireType::Struct(StructSchema { name: String::from(variant.name), fields })
+            };
+
+            packets.push(PacketSchema {
+                id: discriminant(index, variant),
+                name: String::from(variant.effective_name()),
+                content,
+            });
+        }
+        packets
+    }
+
+    /// Describe a connection state from its clientbound and serverbound
+    /// packet enums.
+    #[must_use]
+    pub fn describe_state(
+        &mut self,
+        name: impl Into<String>,
+        clientbound: &'static Shape,
+        serverbound: &'static Shape,
+    ) -> StateSchema {
+        StateSchema {
+            name: name.into(),
+            clientbound: self.describe_packets(clientbound),
+            serverbound: self.describe_packets(serverbound),
+        }
+    }
+
+    // ---------------------------------------------------------------------------------------------
+
+    fn get_override(&self, shape: &'static Shape) -> Option<WireType> {
+        self.overrides.iter().find(|(existing, _)| *existing == shape).map(|(_, wire)| wire.clone())
+    }
+
+    fn boxed(&mut self, shape: &'static Shape, variable: bool) -> alloc::boxed::Box<WireType> {
+        alloc::boxed::Box::new(self.describe_shape(shape, variable))
+    }
+
+    fn describe_type(&mut self, shape: &'static Shape, variable: bool) -> WireType {
+        // Determine whether the type should pass the variable flag to its fields.
+        let variable_base = variable
+            && shape
+                .attributes
+                .iter()
+                .any(|attr| attr.ns == NAMESPACE && attr.key == "variable_inner");
+
+        match shape.ty {
+            Type::User(UserType::Struct(ty)) => {
+                if self.stack.contains(&shape) {
+                    return WireType::Recursive { name: String::from(shape.type_identifier) };
+                }
+
+                self.stack.push(shape);
+                let fields = self.describe_fields(&ty, variable_base);
+                self.stack.pop();
+
+                WireType::Struct(StructSchema { name: String::from(shape.type_identifier), fields })
+            }
+            Type::User(UserType::Enum(ty)) => {
+                if self.stack.contains(&shape) {
+                    return WireType::Recursive { name: String::from(shape.type_identifier) };
+                }
+
+                self.stack.push(shape);
+                let mut variants = Vec::with_capacity(ty.variants.len());
+                for (index, variant) in ty.variants.iter().enumerate() {
+                    variants.push(VariantSchema {
+                        discriminant: discriminant(index, variant),
+                        name: String::from(variant.effective_name()),
+                        fields: self.describe_fields(&variant.data, variable_base),
+                    });
+                }
+                self.stack.pop();
+
+                WireType::Enum(EnumSchema { name: String::from(shape.type_identifier), variants })
+            }
+
+            Type::Sequence(SequenceType::Array(ty)) => {
+                WireType::Array { length: ty.n, item: self.boxed(ty.t, variable) }
+            }
+            Type::Sequence(SequenceType::Slice(ty)) => {
+                WireType::PrefixedArray { item: self.boxed(ty.t, variable) }
+            }
+
+            _ => WireType::custom(shape),
+        }
+    }
+
+    fn describe_fields(&mut self, ty: &StructType, variable_base: bool) -> Vec<FieldSchema> {
+        ty.fields
+            .iter()
+            .filter(|field| !field.should_skip_serializing_unconditional())
+            .map(|field| self.describe_field(field, variable_base))
+            .collect()
+    }
+
+    fn describe_field(&mut self, field: &'static Field, variable_base: bool) -> FieldSchema {
+        let shape = field.shape();
+
+        let ty = if field.has_attr(NAMESPACE, "with") {
+            // Fields with custom serialization can't be inspected.
+            self.get_override(shape).unwrap_or_else(|| WireType::custom(shape))
+        } else {
+            let variable = variable_base | field.has_attr(NAMESPACE, "variable");
+            self.describe_shape(shape, variable)
+        };
+
+        FieldSchema { name: String::from(field.effective_name()), ty }
+    }
+}
+
+/// Get the discriminant of a [`Variant`],
+/// falling back to its index if it doesn't have one.
+fn discriminant(index: usize, variant: &Variant) -> i64 {
+    variant.discriminant.unwrap_or_else(|| i64::try_from(index).unwrap_or(i64::MAX))
+}
@@ -0,0 +1,251 @@
+//! Machine-readable protocol descriptions built from [`Shape`]s.
+//!
+//! A [`SchemaBuilder`] walks a type's [`Shape`] the same way the serializer
+//! does, describing the wire format of every field it encounters.
+
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+
+use facet::{Facet, ScalarType, Shape};
+use facet_format::SerializeError;
+use facet_json::JsonSerializeError;
+
+mod builder;
+pub use builder::SchemaBuilder;
+
+/// A description of a protocol version.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct ProtocolSchema {
+    /// The protocol id of the version.
+    pub protocol: u32,
+    /// The connection states of the protocol.
+    pub states: Vec<StateSchema>,
+}
+
+impl ProtocolSchema {
+    /// Create a new, empty [`ProtocolSchema`].
+    #[inline]
+    #[must_use]
+    pub const fn new(protocol: u32) -> Self { Self { protocol, states: Vec::new() } }
+
+    /// Serialize the [`ProtocolSchema`] as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    #[inline]
+    pub fn to_json(&self) -> Result<String, SerializeError<JsonSerializeError>> {
+        facet_json::to_string_pretty(self)
+    }
+}
+
+/// A description of a single connection state.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct StateSchema {
+    /// The name of the state.
+    pub name: String,
+    /// The packets sent from the server to the client.
+    pub clientbound: Vec<PacketSchema>,
+    /// The packets sent from the client to the server.
+    pub serverbound: Vec<PacketSchema>,
+}
+
+/// A description of a single packet.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct PacketSchema {
+    /// The id of the packet.
+    pub id: i64,
+    /// The name of the packet.
+    pub name: String,
+    /// The packet's contents.
+    pub content: WireType,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The wire format of a value.
+///
+/// Lengths, discriminants, and [`Option`]/[`Result`] tags are always encoded
+/// as [`WireType::VarInt`]s.
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub enum WireType {
+    /// Nothing is written.
+    Unit,
+    /// A single byte, either `0` or `1`.
+    Bool,
+    /// An unsigned byte.
+    U8,
+    /// A big-endian unsigned short.
+    U16,
+    /// A big-endian unsigned int.
+    U32,
+    /// A big-endian unsigned long.
+    U64,
+    /// A big-endian unsigned 128-bit integer.
+    U128,
+    /// A signed byte.
+    I8,
+    /// A big-endian signed short.
+    I16,
+    /// A big-endian signed int.
+    I32,
+    /// A big-endian signed long.
+    I64,
+    /// A big-endian signed 128-bit integer.
+    I128,
+    /// A big-endian float.
+    F32,
+    /// A big-endian double.
+    F64,
+    /// A variable-length integer of up to 32 bits.
+    VarInt,
+    /// A variable-length integer of up to 64 bits.
+    VarLong,
+    /// A variable-length integer of up to 128 bits.
+    VarInt128,
+    /// A length-prefixed UTF-8 string.
+    String,
+    /// A 128-bit UUID.
+    Uuid,
+    /// NBT data.
+    Nbt,
+
+    /// A fixed-length array.
+    Array {
+        /// The number of items in the array.
+        length: usize,
+        /// The type of each item.
+        item: Box<WireType>,
+    },
+    /// A length-prefixed array.
+    PrefixedArray {
+        /// The type of each item.
+        item: Box<WireType>,
+    },
+    /// A length-prefixed array of key-value pairs.
+    Map {
+        /// The type of each key.
+        key: Box<WireType>,
+        /// The type of each value.
+        value: Box<WireType>,
+    },
+    /// A value prefixed with whether it is present.
+    Optional {
+        /// The type of the value.
+        item: Box<WireType>,
+    },
+    /// A value prefixed with whether it is `Ok` (`1`) or `Err` (`0`).
+    Result {
+        /// The type of the `Ok` value.
+        ok: Box<WireType>,
+        /// The type of the `Err` value.
+        err: Box<WireType>,
+    },
+
+    /// A sequence of fields.
+    Struct(StructSchema),
+    /// A discriminant followed by the variant's fields.
+    Enum(EnumSchema),
+
+    /// A type with custom serialization.
+    Custom {
+        /// The name of the type.
+        name: String,
+    },
+    /// A type that is already being described further up.
+    Recursive {
+        /// The name of the type.
+        name: String,
+    },
+}
+
+/// A description of a struct.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct StructSchema {
+    /// The name of the struct.
+    pub name: String,
+    /// The fields of the struct, in wire order.
+    pub fields: Vec<FieldSchema>,
+}
+
+/// A description of an enum.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct EnumSchema {
+    /// The name of the enum.
+    pub name: String,
+    /// The variants of the enum.
+    pub variants: Vec<VariantSchema>,
+}
+
+/// A description of an enum variant.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct VariantSchema {
+    /// The discriminant of the variant.
+    pub discriminant: i64,
+    /// The name of the variant.
+    pub name: String,
+    /// The fields of the variant, in wire order.
+    pub fields: Vec<FieldSchema>,
+}
+
+/// A description of a field.
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct FieldSchema {
+    /// The name of the field.
+    pub name: String,
+    /// The field's wire format.
+    #[facet(rename = "type")]
+    pub ty: WireType,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl WireType {
+    /// Get the [`WireType`] of a scalar [`Shape`], if it has one.
+    #[must_use]
+    pub fn from_scalar(shape: &Shape, variable: bool) -> Option<Self> {
+        if shape.is_type::<uuid::Uuid>() {
+            return Some(Self::Uuid);
+        }
+
+        let wire = match (shape.scalar_type()?, variable) {
+            (ScalarType::Unit, _) => Self::Unit,
+            (ScalarType::Bool, _) => Self::Bool,
+            (ScalarType::Str | ScalarType::String | ScalarType::CowStr, _) => Self::String,
+            (ScalarType::F32, _) => Self::F32,
+            (ScalarType::F64, _) => Self::F64,
+
+            (
+                ScalarType::U8
+                | ScalarType::U16
+                | ScalarType::U32
+                | ScalarType::I8
+                | ScalarType::I16
+                | ScalarType::I32,
+                true,
+            ) => Self::VarInt,
+            (ScalarType::U64 | ScalarType::I64, true) => Self::VarLong,
+            (ScalarType::U128 | ScalarType::I128, true) => Self::VarInt128,
+
+            (ScalarType::U8, false) => Self::U8,
+            (ScalarType::U16, false) => Self::U16,
+            (ScalarType::U32, false) => Self::U32,
+            (ScalarType::U64, false) => Self::U64,
+            (ScalarType::U128, false) => Self::U128,
+            (ScalarType::I8, false) => Self::I8,
+            (ScalarType::I16, false) => Self::I16,
+            (ScalarType::I32, false) => Self::I32,
+            (ScalarType::I64, false) => Self::I64,
+            (ScalarType::I128, false) => Self::I128,
+
+            _ => return None,
+        };
+
+        Some(wire)
+    }
+
+    /// Create a [`WireType::Custom`] for the given [`Shape`].
+    #[inline]
+    #[must_use]
+    pub fn custom(shape: &Shape) -> Self { Self::Custom { name: format!("{shape}") } }
+}
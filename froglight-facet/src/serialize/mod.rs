@@ -163,9 +163,9 @@ fn serialize_value(
 
     // Handle floating-point types
     if let Ok(f32) = peek.get::<f32>() {
-        return writer.write_bytes(&f32.to_le_bytes());
+        return writer.write_bytes(&f32.to_be_bytes());
     } else if let Ok(f64) = peek.get::<f64>() {
-        return writer.write_bytes(&f64.to_le_bytes());
+        return writer.write_bytes(&f64.to_be_bytes());
     }
 
     // Handle strings
@@ -177,8 +177,8 @@ fn serialize_value(
 
     // Handle `Uuid`
     if let Ok(uuid) = peek.get::<uuid::Uuid>() {
-        return writer.write_bytes(&uuid.to_bytes_le());
+        return writer.write_bytes(uuid.as_bytes());
     }
 
-    todo!("Unsupported type: `{}`", peek.shape().type_name());
+    Err(WriterError::from_string(alloc::format!("Unsupported type: `{}`", peek.shape())))
 }
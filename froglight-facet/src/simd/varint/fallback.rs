@@ -231,7 +231,7 @@ pub(super) fn decode_small<T: VarIntType>(bytes: T::Encoded) -> (T, u8) {
     let shift = Simd::from_array([0, 7, 14, 21, 28]);
     let value = (buffer.cast::<u32>() << shift).reduce_or();
 
-    (T::from_u32(value.to_le()), bytes.max(1))
+    (T::from_u32(value.to_le()), bytes.clamp(1, super::max_bytes::<T>()))
 }
 
 /// Decode [`u64`]s and [`u128`]s using SIMD.
@@ -257,7 +257,7 @@ pub(super) fn decode_large<T: VarIntType>(bytes: T::Encoded) -> (T, u8) {
         // Build the value from each group of 56 bits.
         let value = (value_a as u128) | ((value_b as u128) << 56);
 
-        (T::from_u128(value.to_le()), bytes.max(1))
+        (T::from_u128(value.to_le()), bytes.clamp(1, super::max_bytes::<T>()))
     } else {
         // Read an additional 8 bytes if necessary.
         let arr_c = Simd::from_array(arr_c);
@@ -266,6 +266,6 @@ pub(super) fn decode_large<T: VarIntType>(bytes: T::Encoded) -> (T, u8) {
         // Build the value from each group of 56 bits.
         let value = (value_a as u128) | ((value_b as u128) << 56) | ((value_c as u128) << 112);
 
-        (T::from_u128(value.to_le()), bytes.max(1))
+        (T::from_u128(value.to_le()), bytes.clamp(1, super::max_bytes::<T>()))
     }
 }
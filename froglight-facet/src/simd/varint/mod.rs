@@ -34,6 +34,13 @@ cfg_select! {
 mod traits;
 pub use traits::VarIntType;
 
+/// Get the maximum number of bytes that can be read for a type.
+///
+/// Decoding never reads past this, even if the last byte has its MSB set.
+#[inline(always)]
+#[expect(clippy::cast_possible_truncation, reason = "<= 19")]
+const fn max_bytes<T: VarIntType>() -> u8 { T::MAX_BYTES as u8 }
+
 /// Convert a 4-byte array to a 32-byte array by padding zeros to the end.
 #[inline(always)]
 #[allow(dead_code, reason = "May not be used depending on the platform")]
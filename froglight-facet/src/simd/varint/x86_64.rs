@@ -1,6 +1,7 @@
 //! TODO
 #![allow(clippy::wildcard_imports, reason = "`x86_64` module")]
 
+#[allow(unused_imports, reason = "Only used with BMI2 instructions")]
 use core::{arch::x86_64::*, simd::prelude::*};
 
 use froglight_facet_iter::{Reader, ReaderError, Writer, WriterError};
@@ -130,7 +131,7 @@ unsafe fn encode_small<T: VarIntType>(value: T) -> ([u8; 31], u8) {
 /// TODO
 #[must_use]
 #[inline(always)]
-#[expect(clippy::cast_possible_truncation, reason = "Avoids truncation")]
+#[allow(clippy::cast_possible_truncation, reason = "Avoids truncation")]
 unsafe fn encode_large<T: VarIntType>(value: T) -> ([u8; 31], u8) {
     cfg_select! {
         // Use BMI2 instructions if available.
@@ -0,0 +1,160 @@
+//! TODO
+
+extern crate alloc;
+
+use alloc::{
+    borrow::Cow,
+    rc::Rc,
+    sync::{Arc, Weak},
+};
+
+use facet::Facet;
+use froglight_facet::{facet::prelude::*, from_slice, to_vec};
+use proptest::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+struct Pointers {
+    boxed: Box<u32>,
+    arc: Arc<String>,
+    rc: Rc<Option<i16>>,
+    #[facet(mc::variable)]
+    boxed_slice: Box<[u32]>,
+    arc_slice: Arc<[Kind]>,
+    arc_str: Arc<str>,
+    cow_str: Cow<'static, str>,
+    cow_bytes: Cow<'static, [u8]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+struct Nested {
+    optional: Option<Kind>,
+    list: Vec<Kind>,
+    boxed: Box<Kind>,
+    array: [Kind; 2],
+    result: Result<Kind, u8>,
+    recursive: Option<Box<Nested>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+#[repr(u8)]
+enum Kind {
+    Unit,
+    Tuple(#[facet(mc::variable)] i32, bool),
+    Struct { inner: Option<Box<Kind>>, bytes: [u8; 3] },
+}
+
+/// Assert that a value survives a serialization round-trip.
+fn roundtrip<T: Facet<'static> + PartialEq + core::fmt::Debug>(value: &T) {
+    let serialized = to_vec(value).unwrap();
+    assert_eq!(&from_slice::<T>(&serialized).unwrap(), value);
+}
+
+fn kind() -> impl Strategy<Value = Kind> {
+    let leaf = prop_oneof![
+        Just(Kind::Unit),
+        (any::<i32>(), any::<bool>()).prop_map(|(a, b)| Kind::Tuple(a, b)),
+    ];
+    leaf.prop_recursive(3, 8, 1, |inner| {
+        (proptest::option::of(inner), any::<[u8; 3]>())
+            .prop_map(|(inner, bytes)| Kind::Struct { inner: inner.map(Box::new), bytes })
+    })
+}
+
+fn pointers() -> impl Strategy<Value = Pointers> {
+    (
+        any::<u32>(),
+        any::<String>(),
+        any::<Option<i16>>(),
+        proptest::collection::vec(any::<u32>(), 0..8),
+        proptest::collection::vec(kind(), 0..4),
+        any::<String>(),
+        any::<String>(),
+        proptest::collection::vec(any::<u8>(), 0..32),
+    )
+        .prop_map(|(boxed, arc, rc, boxed_slice, arc_slice, arc_str, cow_str, cow_bytes)| {
+            Pointers {
+                boxed: Box::new(boxed),
+                arc: Arc::new(arc),
+                rc: Rc::new(rc),
+                boxed_slice: boxed_slice.into_boxed_slice(),
+                arc_slice: Arc::from(arc_slice),
+                arc_str: Arc::from(arc_str),
+                cow_str: Cow::Owned(cow_str),
+                cow_bytes: Cow::Owned(cow_bytes),
+            }
+        })
+}
+
+fn nested() -> impl Strategy<Value = Nested> {
+    let leaf = (
+        proptest::option::of(kind()),
+        proptest::collection::vec(kind(), 0..4),
+        kind(),
+        [kind(), kind()],
+        prop_oneof![kind().prop_map(Ok), any::<u8>().prop_map(Err)],
+    )
+        .prop_map(|(optional, list, boxed, array, result)| Nested {
+            optional,
+            list,
+            boxed: Box::new(boxed),
+            array,
+            result,
+            recursive: None,
+        });
+    leaf.prop_recursive(2, 4, 1, |inner| {
+        (inner.clone(), inner).prop_map(|(mut outer, inner)| {
+            outer.recursive = Some(Box::new(inner));
+            outer
+        })
+    })
+}
+
+proptest::proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    /// Test round-tripping smart pointers, slices, and `Cow`s.
+    #[test]
+    fn pointer(value in pointers()) { roundtrip(&value); }
+
+    /// Test round-tripping enums nested inside other types.
+    #[test]
+    fn enums(value in nested()) { roundtrip(&value); }
+
+    /// Test round-tripping top-level sequences.
+    #[test]
+    fn sequence(value in proptest::collection::vec(nested(), 0..4)) {
+        roundtrip(&value);
+        roundtrip(&value.into_boxed_slice());
+    }
+
+    /// Test round-tripping top-level arrays.
+    #[test]
+    fn array(value in [kind(), kind(), kind()]) { roundtrip(&value); }
+}
+
+#[test]
+fn cow_slice() {
+    // `Cow<[T]>` is deserialized as a list of its owned type.
+    #[derive(Debug, PartialEq, Facet)]
+    struct CowSlice(Cow<'static, [u16]>, #[facet(mc::variable)] Cow<'static, [Kind]>);
+
+    let value =
+        CowSlice(Cow::Owned(vec![1, 256]), Cow::Owned(vec![Kind::Unit, Kind::Tuple(-1, true)]));
+    assert_eq!(to_vec(&value).unwrap(), [2, 0, 1, 1, 0, 2, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 1]);
+    roundtrip(&value);
+
+    let empty = CowSlice(Cow::Borrowed(&[]), Cow::Borrowed(&[]));
+    roundtrip(&empty);
+}
+
+#[test]
+fn weak_pointer() {
+    // Weak pointers cannot be borrowed, even when the value is still alive.
+    #[derive(Debug, Facet)]
+    struct WeakPointer(Weak<u32>);
+
+    assert!(to_vec(&WeakPointer(Weak::new())).is_err());
+
+    let strong = Arc::new(1);
+    assert!(to_vec(&WeakPointer(Arc::downgrade(&strong))).is_err());
+}
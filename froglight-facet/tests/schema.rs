@@ -0,0 +1,132 @@
+//! TODO
+
+use facet::Facet;
+use froglight_facet::{
+    facet::prelude::*,
+    schema::{FieldSchema, PacketSchema, SchemaBuilder, StructSchema, WireType},
+};
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+#[repr(u8)]
+enum Clientbound {
+    Hello(Hello) = 0x00,
+    Tree(Tree) = 0x05,
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+#[repr(u8)]
+enum Serverbound {
+    Ping(#[facet(mc::variable)] u64) = 0x03,
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+struct Hello {
+    #[facet(mc::variable)]
+    id: u32,
+    name: String,
+    #[facet(mc::variable)]
+    values: Vec<i32>,
+    position: [f64; 3],
+    optional: Option<Box<Payload>>,
+    #[facet(mc::with = Templated::WITH)]
+    templated: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+struct Tree {
+    children: Vec<Tree>,
+}
+
+/// A type the schema can't inspect on its own.
+#[derive(Debug, Clone, PartialEq, Facet)]
+#[facet(opaque)]
+struct Payload(Vec<u8>);
+
+struct Templated;
+
+impl FacetTemplate for Templated {
+    fn serialize(_: SerializeItem<'_, '_>, _: &mut Writer<'_>) -> Result<(), WriterError> {
+        unreachable!()
+    }
+
+    fn deserialize<'facet, const BORROW: bool>(
+        _: DeserializeItem<'facet, BORROW>,
+        _: &mut Reader<'_>,
+    ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+        unreachable!()
+    }
+}
+
+fn field(name: &str, ty: WireType) -> FieldSchema { FieldSchema { name: String::from(name), ty } }
+
+#[test]
+fn packets() {
+    let mut builder = SchemaBuilder::new().with_override(Payload::SHAPE, WireType::Nbt);
+    let state = builder.describe_state("Play", Clientbound::SHAPE, Serverbound::SHAPE);
+
+    assert_eq!(state.name, "Play");
+    assert_eq!(state.clientbound.len(), 2);
+
+    assert_eq!(
+        state.clientbound[0],
+        PacketSchema {
+            id: 0x00,
+            name: String::from("Hello"),
+            content: WireType::Struct(StructSchema {
+                name: String::from("Hello"),
+                fields: vec![
+                    field("id", WireType::VarInt),
+                    field("name", WireType::String),
+                    field("values", WireType::PrefixedArray { item: Box::new(WireType::VarInt) }),
+                    field("position", WireType::Array { length: 3, item: Box::new(WireType::F64) }),
+                    field("optional", WireType::Optional { item: Box::new(WireType::Nbt) }),
+                    field("templated", WireType::Custom { name: String::from("u32") }),
+                ],
+            }),
+        }
+    );
+
+    assert_eq!(
+        state.clientbound[1],
+        PacketSchema {
+            id: 0x05,
+            name: String::from("Tree"),
+            content: WireType::Struct(StructSchema {
+                name: String::from("Tree"),
+                fields: vec![field(
+                    "children",
+                    WireType::PrefixedArray {
+                        item: Box::new(WireType::Recursive { name: String::from("Tree") })
+                    }
+                )],
+            }),
+        }
+    );
+
+    assert_eq!(
+        state.serverbound,
+        [PacketSchema { id: 0x03, name: String::from("Ping"), content: WireType::VarLong }]
+    );
+}
+
+#[test]
+fn opaque() {
+    // Without an override, opaque types are described by name.
+    let mut builder = SchemaBuilder::new();
+    assert_eq!(builder.describe::<Payload>(), WireType::Custom { name: String::from("Payload") });
+    assert_eq!(
+        builder.describe::<Option<u8>>(),
+        WireType::Optional { item: Box::new(WireType::U8) }
+    );
+}
+
+#[test]
+fn json() {
+    let mut builder = SchemaBuilder::new();
+    let mut protocol = froglight_facet::schema::ProtocolSchema::new(775);
+    protocol.states.push(builder.describe_state("Play", Clientbound::SHAPE, Serverbound::SHAPE));
+
+    let json = protocol.to_json().unwrap();
+    assert!(json.contains("\"protocol\": 775"), "{json}");
+    assert!(json.contains("\"VarLong\""), "{json}");
+}
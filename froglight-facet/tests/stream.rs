@@ -0,0 +1,183 @@
+//! TODO
+
+use core::{
+    cell::RefCell,
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker},
+};
+
+use facet::Facet;
+use froglight_facet::{
+    deserialize::future::DeserializeAsync, facet::prelude::*, from_slice, to_vec,
+};
+use froglight_facet_iter::stream::{StreamBuffer, StreamSource};
+
+/// A [`StreamSource`] that provides `chunk` bytes at a time,
+/// returning [`Poll::Pending`] before each chunk.
+struct Trickle<'a> {
+    data: &'a [u8],
+    chunk: usize,
+    ready: bool,
+}
+
+impl<'a> Trickle<'a> {
+    fn new(data: &'a [u8], chunk: usize) -> Self { Self { data, chunk, ready: false } }
+}
+
+impl StreamSource for Trickle<'_> {
+    fn poll_fill(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buffer: &mut StreamBuffer,
+    ) -> Poll<Result<usize, ReaderError>> {
+        if !self.ready {
+            self.ready = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        let (chunk, data) = self.data.split_at(self.chunk.min(self.data.len()));
+        self.data = data;
+        self.ready = false;
+
+        buffer.extend_from_slice(chunk);
+        Poll::Ready(Ok(chunk.len()))
+    }
+}
+
+/// Poll a future to completion, returning the result and how many times it
+/// returned [`Poll::Pending`].
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    let mut pending = 0;
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return (output, pending),
+            Poll::Pending => pending += 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+struct Packet {
+    #[facet(mc::variable)]
+    id: u32,
+    name: String,
+    values: Vec<u64>,
+    #[facet(mc::variable)]
+    ids: Vec<u32>,
+    bytes: Vec<u8>,
+    nested: Vec<Nested>,
+    optional: Option<f64>,
+    kind: Kind,
+    templated: Templated,
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+struct Nested {
+    flag: bool,
+    text: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+#[repr(u8)]
+enum Kind {
+    Empty,
+    Pair(u16, i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Facet)]
+#[facet(mc::with = Templated::WITH)]
+struct Templated(u32);
+
+impl FacetTemplate for Templated {
+    fn serialize(item: SerializeItem<'_, '_>, writer: &mut Writer<'_>) -> Result<(), WriterError> {
+        let val = item.peek().get::<Templated>()?;
+        writer.write_bytes(&val.0.to_le_bytes())
+    }
+
+    fn deserialize<'facet, const BORROW: bool>(
+        item: DeserializeItem<'facet, BORROW>,
+        reader: &mut Reader<'_>,
+    ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+        let val = u32::from_le_bytes(*reader.read_array()?);
+        item.set(Templated(val))
+    }
+}
+
+fn packet() -> Packet {
+    Packet {
+        id: 300,
+        name: String::from("froglight"),
+        values: vec![1, u64::MAX, 3],
+        ids: vec![1, 300, u32::MAX],
+        bytes: vec![0xAB; 40],
+        nested: vec![
+            Nested { flag: true, text: None },
+            Nested { flag: false, text: Some(String::from("hello")) },
+        ],
+        optional: Some(1.5),
+        kind: Kind::Pair(7, -9),
+        templated: Templated(0xDEAD_BEEF),
+    }
+}
+
+#[test]
+fn stream() {
+    let packet = packet();
+    let serialized = to_vec(&packet).unwrap();
+    assert_eq!(from_slice::<Packet>(&serialized).unwrap(), packet);
+
+    for chunk in [1, 2, 3, 7, 64, serialized.len()] {
+        let buffer = RefCell::new(StreamBuffer::new());
+        let source = Trickle::new(&serialized, chunk);
+
+        let (result, pending) = block_on(Packet::from_stream_async(&buffer, source, false));
+        assert_eq!(result.unwrap(), packet, "chunk size {chunk}");
+        assert!(pending >= serialized.len().div_ceil(chunk), "chunk size {chunk}");
+
+        let buffer = buffer.into_inner();
+        assert_eq!(buffer.consumed(), serialized.len());
+        assert!(buffer.is_empty());
+    }
+}
+
+#[test]
+fn remainder() {
+    // Check that bytes after the value are left in the buffer.
+    let packet = packet();
+    let mut serialized = to_vec(&packet).unwrap();
+    let length = serialized.len();
+    serialized.extend_from_slice(&[1, 2, 3]);
+
+    let mut initial = StreamBuffer::new();
+    initial.extend_from_slice(&serialized);
+    let buffer = RefCell::new(initial);
+
+    let (result, pending) = block_on(Packet::from_stream_async(&buffer, (), false));
+    assert_eq!(result.unwrap(), packet);
+    assert_eq!(pending, 0);
+
+    let buffer = buffer.into_inner();
+    assert_eq!(buffer.consumed(), length);
+    assert_eq!(buffer.unread(), [1, 2, 3]);
+}
+
+#[test]
+fn exhausted() {
+    // Check that running out of input is an error.
+    let serialized = to_vec(&packet()).unwrap();
+    let truncated = &serialized[..serialized.len() - 1];
+
+    let buffer = RefCell::new(StreamBuffer::new());
+    let (result, _) =
+        block_on(Packet::from_stream_async(&buffer, Trickle::new(truncated, 4), false));
+    assert!(result.is_err());
+
+    // Nothing belonging to the incomplete item was consumed.
+    let buffer = buffer.into_inner();
+    assert_eq!(buffer.consumed(), serialized.len() - 4);
+    assert_eq!(buffer.unread(), &serialized[serialized.len() - 4..serialized.len() - 1]);
+}
@@ -0,0 +1,67 @@
+//! TODO
+#![no_std]
+
+use facet::Facet;
+use froglight_facet::{from_slice, to_vec};
+use uuid::Uuid;
+
+#[test]
+fn uuid() {
+    let uuid = Uuid::from_u128(0x0011_2233_4455_6677_8899_AABB_CCDD_EEFF);
+
+    // Check that `Uuid`s are written in big-endian order.
+    let serialized = to_vec(&uuid).unwrap();
+    assert_eq!(serialized, uuid.as_bytes());
+    assert_eq!(serialized[0], 0x00);
+    assert_eq!(serialized[15], 0xFF);
+
+    // Check that the `Uuid` is read back correctly.
+    assert_eq!(from_slice::<Uuid>(&serialized).unwrap(), uuid);
+}
+
+#[test]
+fn nested_option() {
+    #[derive(Debug, Clone, PartialEq, Eq, Facet)]
+    struct Outer {
+        inner: Option<Inner>,
+        after: u8,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Facet)]
+    struct Inner {
+        value: Option<u16>,
+        #[expect(clippy::option_option, reason = "Testing nested options")]
+        nested: Option<Option<u8>>,
+    }
+
+    for value in [
+        Outer { inner: None, after: 1 },
+        Outer { inner: Some(Inner { value: None, nested: None }), after: 2 },
+        Outer { inner: Some(Inner { value: Some(3), nested: Some(None) }), after: 4 },
+        Outer { inner: Some(Inner { value: Some(5), nested: Some(Some(6)) }), after: 7 },
+    ] {
+        let serialized = to_vec(&value).unwrap();
+        assert_eq!(from_slice::<Outer>(&serialized).unwrap(), value);
+    }
+
+    // Check the exact bytes of a fully populated value.
+    let value = Outer { inner: Some(Inner { value: Some(5), nested: Some(Some(6)) }), after: 7 };
+    assert_eq!(to_vec(&value).unwrap(), [1, 1, 0, 5, 1, 1, 6, 7]);
+}
+
+#[test]
+fn float() {
+    #[derive(Debug, Clone, Copy, PartialEq, Facet)]
+    struct Floats {
+        single: f32,
+        double: f64,
+    }
+
+    // Check that floats are written in big-endian order.
+    let value = Floats { single: 1.5, double: -2.25 };
+    let serialized = to_vec(&value).unwrap();
+    assert_eq!(serialized, [0x3F, 0xC0, 0, 0, 0xC0, 0x02, 0, 0, 0, 0, 0, 0]);
+
+    // Check that the floats are read back correctly.
+    assert_eq!(from_slice::<Floats>(&serialized).unwrap(), value);
+}
@@ -0,0 +1,5 @@
+#![no_main]
+
+use froglight_common::version::V26_1;
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| froglight_fuzz::packet::fuzz::<V26_1>(data));
@@ -0,0 +1,62 @@
+//! Robustness testing for chunk data.
+//!
+//! Chunk data is sent by the server and can't be trusted,
+//! so parsing and accessing it should never panic.
+
+use froglight_world::{component::SectionBlockPos, naive::NaiveChunk, section::Section};
+
+/// Parse the given bytes as chunk and section data.
+///
+/// The first byte selects the chunk height and format,
+/// and the remaining bytes are parsed as chunk data.
+///
+/// # Panics
+///
+/// Panics if parsing or accessing the parsed data panics.
+pub fn fuzz(data: &[u8]) {
+    let Some((&selector, mut data)) = data.split_first() else { return };
+    let legacy = selector & 0b100 != 0;
+
+    let (height_max, height_min) = match selector & 0b11 {
+        0 => (320, -64),
+        1 => (256, 0),
+        2 => (0, 0),
+        _ => {
+            let Some((max, remaining)) = data.split_first_chunk() else { return };
+            let Some((min, remaining)) = remaining.split_first_chunk() else { return };
+            data = remaining;
+            (u32::from_be_bytes(*max), i32::from_be_bytes(*min))
+        }
+    };
+
+    let chunk = if legacy {
+        NaiveChunk::parse_from_legacy(data, height_max, height_min)
+    } else {
+        NaiveChunk::parse_from(data, height_max, height_min)
+    };
+    if let Ok(mut chunk) = chunk {
+        for section in chunk.sections_mut() {
+            access_section(section);
+        }
+    }
+
+    let section = if legacy { Section::parse_from_legacy(data) } else { Section::parse_from(data) };
+    if let Ok(mut section) = section {
+        access_section(&mut section);
+    }
+}
+
+/// Read every value in the section, then modify it.
+fn access_section(section: &mut Section) {
+    let blocks = section.iter_raw_blocks().collect::<Vec<_>>();
+    let biomes = section.iter_raw_biomes().collect::<Vec<_>>();
+    assert!(blocks.iter().all(|&id| section.contains_raw_block(id)));
+    assert!(biomes.iter().all(|&id| section.contains_raw_biome(id)));
+
+    for (index, id) in [(0, 0), (1, 1), (4095, u32::from(u16::MAX))] {
+        let position = SectionBlockPos::new_index(index);
+        let previous = section.set_raw_block(position, id, |id| id == 0, |id| id == 1);
+        assert_eq!(previous, blocks[usize::from(index)]);
+        assert_eq!(section.get_raw_block(position), id);
+    }
+}
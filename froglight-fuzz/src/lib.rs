@@ -0,0 +1,9 @@
+#![doc = include_str!("../README.md")]
+#![allow(clippy::std_instead_of_core, reason = "Requires the standard library")]
+
+pub mod chunk;
+pub mod mutf8;
+pub mod nbt;
+pub mod packet;
+pub mod snbt;
+pub mod varint;
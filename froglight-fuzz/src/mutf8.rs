@@ -0,0 +1,48 @@
+//! Differential testing for MUTF-8.
+//!
+//! Compares validation and decoding against [`cesu8`].
+
+use froglight_mutf8::prelude::{MStr, MString};
+
+/// Validate and decode the given bytes as MUTF-8.
+///
+/// # Panics
+///
+/// Panics if validation or decoding disagrees with [`cesu8`].
+pub fn fuzz(data: &[u8]) {
+    let result = MStr::from_mutf8(data);
+    let reference = cesu8::from_java_cesu8(data);
+
+    match (result, reference) {
+        (Ok(mstr), Ok(reference)) => {
+            assert_eq!(mstr.to_utf8(), reference, "Decoding of {data:02x?}");
+            assert_eq!(mstr.chars().collect::<String>(), reference, "Chars of {data:02x?}");
+            assert_eq!(
+                mstr.chars().rev().collect::<String>(),
+                reference.chars().rev().collect::<String>()
+            );
+
+            // Encoding the decoded string should return the original bytes.
+            assert_eq!(
+                MString::from(reference.as_ref()).as_bytes(),
+                data,
+                "Encoding of {reference:?}"
+            );
+            assert_eq!(MString::from_mutf8_lossy(data).as_bytes(), data);
+        }
+        (Err(err), reference) => {
+            // `cesu8` accepts all valid UTF-8, even if it isn't valid MUTF-8.
+            assert!(
+                reference.is_err() || core::str::from_utf8(data).is_ok(),
+                "Rejected valid MUTF-8 {data:02x?}: {err}"
+            );
+            assert!(err.valid_up_to() < data.len());
+            assert!(MStr::from_mutf8(&data[..err.valid_up_to()]).is_ok());
+
+            // Lossy decoding should always produce valid MUTF-8.
+            let lossy = MString::from_mutf8_lossy(data);
+            assert!(MStr::from_mutf8(lossy.as_bytes()).is_ok(), "Lossy decoding of {data:02x?}");
+        }
+        (Ok(_), Err(_)) => panic!("Accepted invalid MUTF-8 {data:02x?}"),
+    }
+}
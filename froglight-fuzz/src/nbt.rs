@@ -0,0 +1,102 @@
+//! Differential testing for NBT.
+//!
+//! Compares indexed NBT parsing against structured NBT parsing and [`simdnbt`].
+
+use std::io::Cursor;
+
+use froglight_nbt::prelude::{IndexedNbtSlice, Nbt};
+
+/// Parse the given bytes as both named and unnamed NBT.
+///
+/// # Panics
+///
+/// Panics if parsing disagrees with [`simdnbt`] or the structured parser.
+pub fn fuzz(data: &[u8]) {
+    fuzz_named(data);
+    fuzz_unnamed(data);
+}
+
+fn fuzz_named(data: &[u8]) {
+    let mut cursor = Cursor::new(data);
+    let reference = simdnbt::borrow::read(&mut cursor);
+
+    let Ok(indexed) = IndexedNbtSlice::new_named(data) else {
+        // `simdnbt` doesn't validate strings, so it may accept more than we do.
+        // Structured NBT should always agree with indexed NBT.
+        assert!(
+            Nbt::read_named(data).is_err(),
+            "Structured NBT accepted {data:02x?}, but indexed NBT did not"
+        );
+        return;
+    };
+
+    match reference {
+        Ok(simdnbt::borrow::Nbt::Some(nbt)) => {
+            // Both parsers should consume the same bytes, and write them back out
+            // identically.
+            assert_eq!(usize::try_from(cursor.position()).ok(), Some(indexed.as_slice().len()));
+            // Note: `BaseNbt::write` writes an extra end tag, so write the root compound
+            // instead.
+            let name = nbt.name().as_bytes();
+            let mut written = vec![10];
+            written.extend_from_slice(&u16::try_from(name.len()).unwrap_or_default().to_be_bytes());
+            written.extend_from_slice(name);
+            nbt.as_compound().write(&mut written);
+            assert_eq!(written, indexed.as_slice(), "Named NBT did not match `simdnbt`");
+        }
+        Ok(simdnbt::borrow::Nbt::None) => panic!("Indexed NBT accepted an empty tag"),
+        // `simdnbt` limits the depth of nested tags.
+        Err(simdnbt::Error::MaxDepthExceeded) => {}
+        Err(err) => panic!("Indexed NBT accepted {data:02x?}, but `simdnbt` did not: {err}"),
+    }
+
+    compare_structured(&indexed, Nbt::read_named(data));
+}
+
+fn fuzz_unnamed(data: &[u8]) {
+    let mut cursor = Cursor::new(data);
+    let reference = simdnbt::borrow::read_unnamed(&mut cursor);
+
+    let Ok(indexed) = IndexedNbtSlice::new_unnamed(data) else {
+        assert!(
+            Nbt::read_unnamed(data).is_err(),
+            "Structured NBT accepted {data:02x?}, but indexed NBT did not"
+        );
+        return;
+    };
+
+    match reference {
+        Ok(simdnbt::borrow::Nbt::Some(_)) => {
+            assert_eq!(usize::try_from(cursor.position()).ok(), Some(indexed.as_slice().len()));
+        }
+        Ok(simdnbt::borrow::Nbt::None) => panic!("Indexed NBT accepted an empty tag"),
+        Err(simdnbt::Error::MaxDepthExceeded) => {}
+        Err(err) => panic!("Indexed NBT accepted {data:02x?}, but `simdnbt` did not: {err}"),
+    }
+
+    compare_structured(&indexed, Nbt::read_unnamed(data));
+}
+
+/// Compare indexed NBT against structured NBT.
+fn compare_structured(indexed: &IndexedNbtSlice<'_>, structured: Result<(Nbt, usize), ()>) {
+    let Ok((structured, length)) = structured else {
+        // Structured NBT limits the depth of nested tags.
+        return;
+    };
+
+    assert_eq!(length, indexed.as_slice().len(), "Structured NBT read a different length");
+    assert_eq!(Nbt::try_from(indexed), Ok(structured.clone()));
+
+    // Accessing every value should never panic.
+    let _ = format!("{indexed:?}");
+
+    // Writing the structured NBT should read back the same structure.
+    //
+    // Note: The bytes may differ, as structured NBT removes duplicate keys.
+    let reread = if indexed.name().is_some() {
+        Nbt::read_named(&structured.to_named_bytes())
+    } else {
+        Nbt::read_unnamed(&structured.to_unnamed_bytes())
+    };
+    assert_eq!(reread.map(|(nbt, _)| nbt), Ok(structured), "Structured NBT did not round-trip");
+}
@@ -0,0 +1,50 @@
+//! Round-trip testing for packets.
+//!
+//! Packets are sent by the server and can't be trusted,
+//! so decoding them should never panic,
+//! and anything that was decoded should be encoded the same way again.
+
+use core::fmt::Debug;
+
+use facet::Facet;
+use froglight_packet::version::{PacketState, PacketVersion};
+
+/// Decode the given bytes as a packet for the version `V`.
+///
+/// The first byte selects the connection state and direction,
+/// and the remaining bytes are decoded as a packet.
+///
+/// # Panics
+///
+/// Panics if decoding panics or the decoded packet does not round-trip.
+pub fn fuzz<V: PacketVersion>(data: &[u8]) {
+    let Some((&selector, data)) = data.split_first() else { return };
+
+    match selector % 10 {
+        0 => fuzz_packet::<<V::Handshake as PacketState<V>>::Clientbound>(data),
+        1 => fuzz_packet::<<V::Handshake as PacketState<V>>::Serverbound>(data),
+        2 => fuzz_packet::<<V::Status as PacketState<V>>::Clientbound>(data),
+        3 => fuzz_packet::<<V::Status as PacketState<V>>::Serverbound>(data),
+        4 => fuzz_packet::<<V::Login as PacketState<V>>::Clientbound>(data),
+        5 => fuzz_packet::<<V::Login as PacketState<V>>::Serverbound>(data),
+        6 => fuzz_packet::<<V::Config as PacketState<V>>::Clientbound>(data),
+        7 => fuzz_packet::<<V::Config as PacketState<V>>::Serverbound>(data),
+        8 => fuzz_packet::<<V::Play as PacketState<V>>::Clientbound>(data),
+        _ => fuzz_packet::<<V::Play as PacketState<V>>::Serverbound>(data),
+    }
+}
+
+/// Decode, encode, and decode a packet again.
+fn fuzz_packet<T: Debug + Facet<'static>>(data: &[u8]) {
+    let Ok((packet, _)) = froglight_facet::from_slice_remainder::<T>(data) else { return };
+
+    let encoded = froglight_facet::to_vec(&packet)
+        .unwrap_or_else(|err| panic!("Failed to encode {packet:?}: {err:?}"));
+    let (decoded, remainder) = froglight_facet::from_slice_remainder::<T>(&encoded)
+        .unwrap_or_else(|err| panic!("Failed to decode {packet:?}: {err:?}"));
+    assert!(remainder.is_empty(), "{packet:?} was not fully decoded");
+
+    let reencoded = froglight_facet::to_vec(&decoded)
+        .unwrap_or_else(|err| panic!("Failed to encode {decoded:?}: {err:?}"));
+    assert_eq!(encoded, reencoded, "{packet:?} did not round-trip");
+}
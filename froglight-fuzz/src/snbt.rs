@@ -0,0 +1,42 @@
+//! Round-trip testing for SNBT.
+//!
+//! Anything that can be parsed and converted into NBT
+//! should be written and parsed back into the same NBT.
+
+use froglight_nbt::prelude::{IndexedNbtCow, Nbt};
+use froglight_snbt::prelude::{IndexedSnbt, IndexedSnbtSlice, SnbtWriter};
+
+/// Parse the given bytes as SNBT.
+///
+/// # Panics
+///
+/// Panics if parsing panics or the parsed SNBT does not round-trip.
+pub fn fuzz(data: &[u8]) {
+    let Ok(string) = core::str::from_utf8(data) else { return };
+
+    let snbt = match IndexedSnbtSlice::new_ref(string) {
+        Ok(snbt) => snbt,
+        Err(err) => {
+            assert!(err.span().end <= string.len(), "Error span is out of bounds: {err:?}");
+            return;
+        }
+    };
+
+    // Accessing every value should never panic.
+    let _ = format!("{snbt:?}");
+
+    let Ok(nbt) = Nbt::try_from(&snbt) else { return };
+
+    // SNBT -> NBT -> SNBT -> NBT
+    for writer in [SnbtWriter::compact(), SnbtWriter::pretty()] {
+        // Non-finite floats cannot be written as SNBT.
+        let Ok(written) = writer.to_string(&nbt) else { return };
+        let parsed = IndexedSnbt::new_owned(written.clone())
+            .unwrap_or_else(|err| panic!("Failed to parse written SNBT {written:?}: {err:?}"));
+        assert_eq!(Nbt::try_from(&parsed).as_ref(), Ok(&nbt), "{string:?} did not round-trip");
+    }
+
+    // SNBT -> IndexedNBT -> NBT
+    let indexed = IndexedNbtCow::try_from(&snbt).expect("Failed to convert SNBT into indexed NBT");
+    assert_eq!(Nbt::try_from(&indexed).as_ref(), Ok(&nbt), "{string:?} did not convert");
+}
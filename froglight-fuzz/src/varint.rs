@@ -0,0 +1,83 @@
+//! Differential testing for variable-length integers.
+//!
+//! Compares the architecture-specific and fallback SIMD implementations
+//! against a simple scalar LEB128 implementation.
+
+use froglight_facet::simd::varint::{self, VarIntType, fallback};
+
+/// Decode and re-encode every integer type from the given bytes.
+///
+/// # Panics
+///
+/// Panics if any implementation disagrees with the reference implementation.
+pub fn fuzz(data: &[u8]) {
+    fuzz_type::<u8>(data);
+    fuzz_type::<u16>(data);
+    fuzz_type::<u32>(data);
+    fuzz_type::<u64>(data);
+    fuzz_type::<u128>(data);
+}
+
+fn fuzz_type<T: VarIntType + Copy + PartialEq + core::fmt::Debug>(data: &[u8]) {
+    let (value, length) = decode_reference::<T>(data);
+    let value = T::from_u128(value);
+
+    assert_eq!(varint::decode::<T>(data), (value, length), "SIMD decode of {data:02x?}");
+    assert_eq!(fallback::decode::<T>(data), (value, length), "Fallback decode of {data:02x?}");
+
+    let (expected, length) = encode_reference(value.to_u128());
+    for (name, (encoded, len)) in
+        [("SIMD", varint::encode(value)), ("Fallback", fallback::encode(value))]
+    {
+        assert_eq!(
+            &encoded[..usize::from(len)],
+            &expected[..usize::from(length)],
+            "{name} encode of {value:?}"
+        );
+    }
+
+    // Anything that was encoded should decode to the same value.
+    assert_eq!(varint::decode::<T>(&expected[..usize::from(length)]), (value, length));
+}
+
+/// Decode a value using LEB128, truncating it to the size of `T`.
+///
+/// Missing bytes are treated as zeroes,
+/// and no more than [`VarIntType::MAX_BYTES`] bytes are read.
+fn decode_reference<T: VarIntType>(data: &[u8]) -> (u128, u8) {
+    let mask = u128::MAX >> (u128::BITS - u32::try_from(size_of::<T>() * 8).unwrap_or(u128::BITS));
+
+    let mut value = 0u128;
+    let mut length = 0u8;
+    for index in 0..T::MAX_BYTES {
+        let byte = data.get(index).copied().unwrap_or_default();
+        value |= u128::from(byte & 0x7F)
+            .checked_shl(u32::try_from(index * 7).unwrap_or(u32::MAX))
+            .unwrap_or(0);
+        length += 1;
+
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+
+    (value & mask, length)
+}
+
+/// Encode a value using LEB128.
+fn encode_reference(mut value: u128) -> ([u8; 19], u8) {
+    let mut buffer = [0u8; 19];
+    let mut length = 0u8;
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buffer[usize::from(length)] = byte;
+            return (buffer, length + 1);
+        }
+
+        buffer[usize::from(length)] = byte | 0x80;
+        length += 1;
+    }
+}
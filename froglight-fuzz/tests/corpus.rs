@@ -0,0 +1,87 @@
+//! TODO
+
+use std::{fs, path::Path};
+
+use froglight_common::version::{V26_1, V26_2};
+use rand::{prelude::*, rngs::Xoshiro128PlusPlus};
+
+/// How many mutated inputs to generate per seed.
+const MUTATIONS: usize = 256;
+
+/// Run a harness against every seed in the corpus,
+/// along with a set of deterministic mutations of each seed.
+fn run(target: &str, harness: fn(&[u8])) {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus").join(target);
+
+    let mut seeds: Vec<_> = fs::read_dir(&directory)
+        .unwrap_or_else(|err| panic!("Failed to read `{}`: {err}", directory.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    seeds.sort_unstable();
+    assert!(!seeds.is_empty(), "No seeds found for `{target}`");
+
+    let mut rand = Xoshiro128PlusPlus::seed_from_u64(0x4652_4F47_4C49_4748);
+    for path in seeds {
+        let seed = fs::read(&path).unwrap();
+        harness(&seed);
+
+        for _ in 0..MUTATIONS {
+            harness(&mutate(&seed, &mut rand));
+        }
+    }
+}
+
+/// Apply a number of random mutations to the input.
+fn mutate(input: &[u8], rand: &mut impl Rng) -> Vec<u8> {
+    /// Values that are likely to hit edge cases.
+    const INTERESTING: &[u8] = &[0x00, 0x01, 0x7F, 0x80, 0xC0, 0xED, 0xFE, 0xFF];
+
+    let mut output = input.to_vec();
+    for _ in 0..rand.random_range(1..=4) {
+        let index = rand.random_range(0..=output.len());
+        match rand.random_range(0..6) {
+            // Flip a random bit.
+            0 if index < output.len() => output[index] ^= 1 << rand.random_range(0..8),
+            // Replace a byte with an interesting value.
+            1 if index < output.len() => {
+                output[index] = INTERESTING[rand.random_range(0..INTERESTING.len())];
+            }
+            // Insert a random byte.
+            2 => output.insert(index, rand.random()),
+            // Remove a byte.
+            3 if index < output.len() => drop(output.remove(index)),
+            // Truncate the input.
+            4 => output.truncate(index),
+            // Duplicate a section of the input.
+            _ => {
+                let end = rand.random_range(index..=output.len());
+                let section = output[index..end].to_vec();
+                output.splice(index..index, section);
+            }
+        }
+    }
+    output
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn varint() { run("varint", froglight_fuzz::varint::fuzz); }
+
+#[test]
+fn mutf8() { run("mutf8", froglight_fuzz::mutf8::fuzz); }
+
+#[test]
+fn nbt() { run("nbt", froglight_fuzz::nbt::fuzz); }
+
+#[test]
+fn snbt() { run("snbt", froglight_fuzz::snbt::fuzz); }
+
+#[test]
+fn chunk() { run("chunk", froglight_fuzz::chunk::fuzz); }
+
+#[test]
+fn packet_v26_1() { run("packet_v26_1", froglight_fuzz::packet::fuzz::<V26_1>); }
+
+#[test]
+fn packet_v26_2() { run("packet_v26_2", froglight_fuzz::packet::fuzz::<V26_2>); }
@@ -20,6 +20,7 @@ pub struct InstancePlugin;
 impl Plugin for InstancePlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<SessionInstance>();
+        app.register_type::<DimensionChanged>();
 
         app.register_type::<EntityId>().register_type_data::<EntityId, ReflectSession>();
         app.register_type::<EntityUuid>().register_type_data::<EntityUuid, ReflectSession>();
@@ -0,0 +1,360 @@
+//! TODO
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use bevy_ecs::{
+    entity::Entity,
+    query::QueryEntityError,
+    system::{Query, SystemParam},
+};
+use foldhash::fast::RandomState;
+use froglight_biome::prelude::*;
+use froglight_block::prelude::*;
+use froglight_world::{
+    component::SectionBlockPos,
+    prelude::*,
+    section::{BlockSection, Section, SectionPalette, SectionType},
+};
+use hashbrown::HashMap;
+
+use crate::prelude::*;
+
+/// A [`SystemParam`] for reading blocks from all [`Chunk`]s in a
+/// [`SessionInstance`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use froglight_instance::prelude::*;
+/// use froglight_world::prelude::BlockPos;
+///
+/// fn print_block(query: Query<&SessionInstance>, blocks: InstanceBlocks) {
+///     for instance in query {
+///         if let Some(block) = blocks.get_block(instance, BlockPos::new_xyz(0, 64, 0)) {
+///             println!("Found \"{}\" at [0, 64, 0]!", block.identifier());
+///         }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct InstanceBlocks<'w, 's> {
+    chunks: Query<'w, 's, &'static SharedChunk>,
+}
+
+impl InstanceBlocks<'_, '_> {
+    /// Get the [`Chunk`] at the given [`ChunkPos`].
+    ///
+    /// Returns `None` if the [`Chunk`] is not loaded.
+    #[must_use]
+    pub fn get_chunk(&self, instance: &SessionInstance, position: &ChunkPos) -> Option<&Chunk> {
+        let entity = instance.get_chunk(position)?;
+        self.chunks.get(entity).ok().map(AsRef::<Chunk>::as_ref)
+    }
+
+    /// Get the [`Block`] at the given position.
+    ///
+    /// Returns `None` if the [`Chunk`] is not loaded, if the position is out
+    /// of bounds, or if the [`Block`] is not recognized.
+    #[must_use]
+    pub fn get_block(&self, instance: &SessionInstance, position: BlockPos) -> Option<Block> {
+        self.get_chunk(instance, &position.into_chunk_pos())?.get_block(position)
+    }
+
+    /// Get the [`BlockEntity`] at the given position.
+    ///
+    /// Returns `None` if the [`Chunk`] is not loaded, if the position is out
+    /// of bounds, or if there is no [`BlockEntity`] at the position.
+    #[must_use]
+    pub fn get_block_entity(
+        &self,
+        instance: &SessionInstance,
+        position: BlockPos,
+    ) -> Option<&BlockEntity> {
+        self.get_chunk(instance, &position.into_chunk_pos())?.get_block_entity(position)
+    }
+
+    /// Get the [`Biome`] at the given position.
+    ///
+    /// Returns `None` if the [`Chunk`] is not loaded, if the position is out
+    /// of bounds, or if the [`Biome`] is not recognized.
+    #[must_use]
+    pub fn get_biome(&self, instance: &SessionInstance, position: BlockPos) -> Option<Biome> {
+        self.get_chunk(instance, &position.into_chunk_pos())?.get_biome(position)
+    }
+
+    /// Create an iterator over all [`Block`]s in a region.
+    ///
+    /// Positions that cannot be read return `None`,
+    /// see [`InstanceBlocks::get_block`] for details.
+    pub fn iter_region<'a>(
+        &'a self,
+        instance: &'a SessionInstance,
+        region: BlockPosIter,
+    ) -> impl Iterator<Item = (BlockPos, Option<Block>)> + 'a {
+        // Cache the last chunk, as neighboring positions are usually in the same chunk.
+        let mut cached: Option<(ChunkPos, Option<&Chunk>)> = None;
+
+        region.map(move |position| {
+            let chunk_pos = position.into_chunk_pos();
+            let chunk = match cached {
+                Some((cached_pos, chunk)) if cached_pos == chunk_pos => chunk,
+                _ => {
+                    let chunk = self.get_chunk(instance, &chunk_pos);
+                    cached = Some((chunk_pos, chunk));
+                    chunk
+                }
+            };
+
+            (position, chunk.and_then(|chunk| chunk.get_block(position)))
+        })
+    }
+
+    /// Find the nearest [`Block`] matching the predicate within a radius.
+    ///
+    /// Sections are searched outwards from the origin, and are skipped entirely
+    /// if none of the [`Block`]s in their palette match the predicate.
+    #[must_use]
+    pub fn find_nearest<F: FnMut(Block) -> bool>(
+        &self,
+        instance: &SessionInstance,
+        origin: BlockPos,
+        radius: u32,
+        mut predicate: F,
+    ) -> Option<(BlockPos, Block)> {
+        let storage = instance.version_blocks();
+
+        // Only resolve each block state once.
+        let mut cache = HashMap::with_hasher(RandomState::default());
+        let matches = |id: u32| {
+            *cache.entry(id).or_insert_with(|| {
+                storage.get_block_by_state(GlobalStateId::new(id)).is_some_and(&mut predicate)
+            })
+        };
+
+        let (position, id) = self.nearest_where(
+            instance,
+            origin,
+            radius,
+            |section, matches| match section.block_data().palette() {
+                SectionPalette::Single(id) => matches(*id),
+                SectionPalette::Vector(ids) => ids.iter().any(|id| matches(*id)),
+                SectionPalette::Global => true,
+            },
+            matches,
+        )?;
+
+        storage.get_block_by_state(GlobalStateId::new(id)).map(|block| (position, block))
+    }
+
+    /// Find the nearest exact [`Block`], including its state, within a
+    /// radius.
+    ///
+    /// Sections are searched outwards from the origin, and are skipped entirely
+    /// if they do not contain the [`Block`].
+    #[must_use]
+    pub fn find_nearest_exact(
+        &self,
+        instance: &SessionInstance,
+        origin: BlockPos,
+        radius: u32,
+        block: Block,
+    ) -> Option<BlockPos> {
+        let block = block.using_version_storage(instance.version_blocks())?;
+        let block_id = block.global_id().into_inner();
+
+        self.nearest_where(
+            instance,
+            origin,
+            radius,
+            |section, _| section.contains_raw_block(block_id),
+            |id| id == block_id,
+        )
+        .map(|(position, _)| position)
+    }
+
+    /// Find the nearest raw block id matching `matches` within a radius,
+    /// skipping any [`Section`]s rejected by `filter`.
+    fn nearest_where<M: FnMut(u32) -> bool>(
+        &self,
+        instance: &SessionInstance,
+        origin: BlockPos,
+        radius: u32,
+        mut filter: impl FnMut(&Section, &mut M) -> bool,
+        mut matches: M,
+    ) -> Option<(BlockPos, u32)> {
+        let radius_sq = i64::from(radius).saturating_pow(2);
+        let radius = i32::try_from(radius).unwrap_or(i32::MAX);
+
+        let min = BlockPos::new_xyz(
+            origin.x().saturating_sub(radius),
+            0,
+            origin.z().saturating_sub(radius),
+        );
+        let max = BlockPos::new_xyz(
+            origin.x().saturating_add(radius),
+            0,
+            origin.z().saturating_add(radius),
+        );
+        let (min, max) = (min.into_chunk_pos(), max.into_chunk_pos());
+
+        // Check each chunk in range, or every loaded chunk if there are fewer.
+        let area = (i64::from(max.x()) - i64::from(min.x()) + 1)
+            .saturating_mul(i64::from(max.z()) - i64::from(min.z()) + 1);
+        let loaded = instance.chunk_map();
+        let positions: Vec<ChunkPos> = if area <= i64::try_from(loaded.len()).unwrap_or(i64::MAX) {
+            (min.z()..=max.z())
+                .flat_map(|z| (min.x()..=max.x()).map(move |x| ChunkPos::new_xz(x, z)))
+                .collect()
+        } else {
+            loaded
+                .keys()
+                .filter(|pos| (min.x()..=max.x()).contains(&pos.x()))
+                .filter(|pos| (min.z()..=max.z()).contains(&pos.z()))
+                .copied()
+                .collect()
+        };
+
+        // Collect all sections within range, sorted by their distance to the origin.
+        let mut sections = Vec::new();
+        for position in positions {
+            let Some(chunk) = self.get_chunk(instance, &position) else { continue };
+
+            let mut section_y = chunk.height_offset();
+            for section in chunk.sections() {
+                let corner = BlockPos::new_xyz(position.x() * 16, section_y, position.z() * 16);
+                let distance = section_distance_sq(origin, corner);
+                if distance <= radius_sq {
+                    sections.push((distance, corner, section));
+                }
+                section_y += 16;
+            }
+        }
+        sections.sort_by_key(|(distance, ..)| *distance);
+
+        let mut nearest: Option<(i64, BlockPos, u32)> = None;
+        for (distance, corner, section) in sections {
+            // Every remaining section is further away than the nearest block.
+            if nearest.is_some_and(|(nearest, ..)| nearest < distance) {
+                break;
+            }
+            // Skip sections that cannot contain a matching block.
+            if !filter(section, &mut matches) {
+                continue;
+            }
+
+            for index in 0..BlockSection::VOLUME {
+                let local = SectionBlockPos::new_index(index);
+                let position = BlockPos::new_xyz(
+                    corner.x() + i32::from(local.x()),
+                    corner.y() + i32::from(local.y()),
+                    corner.z() + i32::from(local.z()),
+                );
+
+                let distance = distance_sq(origin, position);
+                if distance > radius_sq || nearest.is_some_and(|(nearest, ..)| nearest <= distance)
+                {
+                    continue;
+                }
+
+                let id = section.get_raw_block(local);
+                if matches(id) {
+                    nearest = Some((distance, position, id));
+                }
+            }
+        }
+
+        nearest.map(|(_, position, id)| (position, id))
+    }
+}
+
+/// The squared distance between two [`BlockPos`]s.
+fn distance_sq(first: BlockPos, second: BlockPos) -> i64 {
+    let dx = i64::from(first.x()) - i64::from(second.x());
+    let dy = i64::from(first.y()) - i64::from(second.y());
+    let dz = i64::from(first.z()) - i64::from(second.z());
+    dx * dx + dy * dy + dz * dz
+}
+
+/// The squared distance between a [`BlockPos`] and the closest block of the
+/// section starting at `corner`.
+fn section_distance_sq(origin: BlockPos, corner: BlockPos) -> i64 {
+    let closest = BlockPos::new_xyz(
+        origin.x().clamp(corner.x(), corner.x() + 15),
+        origin.y().clamp(corner.y(), corner.y() + 15),
+        origin.z().clamp(corner.z(), corner.z() + 15),
+    );
+    distance_sq(origin, closest)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A [`SystemParam`] for reading blocks from all [`Chunk`]s in a
+/// [`SessionInstance`] and queuing edits to them.
+///
+/// Edits are pushed to the [`SessionInstance`]'s [`BlockEditQueue`],
+/// and are not visible until the queue has been applied.
+#[derive(SystemParam)]
+pub struct InstanceBlocksMut<'w, 's> {
+    blocks: InstanceBlocks<'w, 's>,
+    queues: Query<'w, 's, &'static mut BlockEditQueue>,
+}
+
+impl InstanceBlocksMut<'_, '_> {
+    /// Queue a [`Block`] to be placed at the given position.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QueryEntityError`] if the [`Entity`] does not have a
+    /// [`BlockEditQueue`].
+    pub fn set_block(
+        &mut self,
+        instance: Entity,
+        position: BlockPos,
+        block: Block,
+    ) -> Result<(), QueryEntityError> {
+        self.queues.get_mut(instance).map(|mut queue| queue.push(position, block))
+    }
+
+    /// Queue a [`BlockEntity`] to be placed at the given position.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QueryEntityError`] if the [`Entity`] does not have a
+    /// [`BlockEditQueue`].
+    pub fn set_block_entity(
+        &mut self,
+        instance: Entity,
+        position: BlockPos,
+        block_entity: BlockEntity,
+    ) -> Result<(), QueryEntityError> {
+        self.queues
+            .get_mut(instance)
+            .map(|mut queue| queue.push_block_entity(position, block_entity))
+    }
+
+    /// Queue a [`Block`] to be placed at every position in a region.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`QueryEntityError`] if the [`Entity`] does not have a
+    /// [`BlockEditQueue`].
+    pub fn fill_region(
+        &mut self,
+        instance: Entity,
+        region: BlockPosIter,
+        block: Block,
+    ) -> Result<(), QueryEntityError> {
+        let mut queue = self.queues.get_mut(instance)?;
+        region.for_each(|position| queue.push(position, block));
+        Ok(())
+    }
+}
+
+impl<'w, 's> Deref for InstanceBlocksMut<'w, 's> {
+    type Target = InstanceBlocks<'w, 's>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { &self.blocks }
+}
@@ -0,0 +1,174 @@
+//! TODO
+
+use alloc::vec::Vec;
+
+use bevy_ecs::{prelude::*, system::EntityCommand};
+use bevy_reflect::Reflect;
+use froglight_common::prelude::Identifier;
+
+use crate::prelude::*;
+
+/// An [`EntityCommand`] that creates or switches the [`SessionInstance`] of
+/// an [`Entity`].
+///
+/// When switching, every [`Entity`] that is part of the previous
+/// [`SessionInstance`] is either despawned or moved to a new [`Entity`] along
+/// with a copy of the previous [`SessionInstance`].
+///
+/// Triggers a [`DimensionChanged`] event once complete.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use froglight_common::{prelude::Identifier, version::V26_1};
+/// use froglight_instance::{dimension::ChangeDimension, prelude::*};
+///
+/// fn enter_the_end(bot: Single<Entity, With<SessionInstance>>, mut commands: Commands) {
+///     let instance =
+///         SessionInstance::new::<V26_1>(Identifier::new_static("minecraft:the_end"), 256, 0);
+///     commands.entity(*bot).queue(ChangeDimension::new(instance));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChangeDimension {
+    instance: SessionInstance,
+    keep_previous: bool,
+}
+
+impl ChangeDimension {
+    /// Create a new [`ChangeDimension`] that switches to the given
+    /// [`SessionInstance`], discarding the previous one.
+    #[inline]
+    #[must_use]
+    pub const fn new(instance: SessionInstance) -> Self { Self { instance, keep_previous: false } }
+
+    /// Set whether the data of the previous [`SessionInstance`] is kept.
+    ///
+    /// If `true`, the previous [`SessionInstance`] and all of its entities are
+    /// moved to a new [`Entity`] instead of being despawned.
+    #[inline]
+    #[must_use]
+    pub const fn keep_previous(mut self, keep: bool) -> Self {
+        self.keep_previous = keep;
+        self
+    }
+}
+
+impl EntityCommand for ChangeDimension {
+    type Out = ();
+
+    fn apply(self, mut entity: EntityWorldMut) {
+        let instance = entity.id();
+        let dimension = self.instance.dimension().clone();
+
+        let Some(previous) = entity.get::<SessionInstance>() else {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(target: "froglight_instance", "Entity {instance} is joining \"{dimension}\"");
+
+            entity.insert(self.instance);
+            entity.trigger(|entity| DimensionChanged::new(entity, None, dimension, None));
+            return;
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(target: "froglight_instance", "Entity {instance} is moving from \"{}\" to \"{dimension}\"", previous.dimension());
+
+        let previous_dimension = previous.dimension().clone();
+
+        // Find every entity that is part of the previous instance.
+        let related: Vec<Entity> =
+            previous.iter_entity().copied().filter(|&entity| entity != instance).collect();
+
+        let stored = self.keep_previous.then(|| {
+            previous.with_dimension(
+                previous_dimension.clone(),
+                previous.height_max(),
+                previous.height_min(),
+            )
+        });
+
+        // Pending edits are for the previous dimension.
+        if let Some(mut queue) = entity.get_mut::<BlockEditQueue>() {
+            *queue = BlockEditQueue::new();
+        }
+
+        let stored = entity.world_scope(|world| {
+            if let Some(stored) = stored {
+                // Move the entities into the stored instance.
+                let stored = world.spawn(stored).id();
+                for entity in related {
+                    world.entity_mut(entity).insert(PartOfInstance::new(stored));
+                }
+                Some(stored)
+            } else {
+                // Despawn the entities.
+                for entity in related {
+                    world.despawn(entity);
+                }
+                None
+            }
+        });
+
+        entity.insert(self.instance);
+
+        // Re-insert the entity into its own instance.
+        if let Some(part) = entity.get::<PartOfInstance>().copied()
+            && part.instance() == instance
+        {
+            entity.insert(part);
+        }
+
+        entity.trigger(|entity| {
+            DimensionChanged::new(entity, Some(previous_dimension), dimension, stored)
+        });
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An [`EntityEvent`] that is triggered when a [`SessionInstance`] is created
+/// or switched by [`ChangeDimension`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EntityEvent, Reflect)]
+#[reflect(Debug, Clone, PartialEq, Hash, Event)]
+pub struct DimensionChanged {
+    entity: Entity,
+    previous: Option<Identifier<'static>>,
+    dimension: Identifier<'static>,
+    stored: Option<Entity>,
+}
+
+impl DimensionChanged {
+    /// Create a new [`DimensionChanged`] event.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        entity: Entity,
+        previous: Option<Identifier<'static>>,
+        dimension: Identifier<'static>,
+        stored: Option<Entity>,
+    ) -> Self {
+        Self { entity, previous, dimension, stored }
+    }
+
+    /// Get the [`Entity`] with the [`SessionInstance`].
+    #[inline]
+    #[must_use]
+    pub const fn entity(&self) -> Entity { self.entity }
+
+    /// Get the [`Identifier`] of the previous dimension, if there was one.
+    #[inline]
+    #[must_use]
+    pub const fn previous(&self) -> Option<&Identifier<'static>> { self.previous.as_ref() }
+
+    /// Get the [`Identifier`] of the new dimension.
+    #[inline]
+    #[must_use]
+    pub const fn dimension(&self) -> &Identifier<'static> { &self.dimension }
+
+    /// Get the [`Entity`] the previous [`SessionInstance`] was moved to,
+    /// if it was kept.
+    #[inline]
+    #[must_use]
+    pub const fn stored(&self) -> Option<Entity> { self.stored }
+}
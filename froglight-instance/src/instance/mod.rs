@@ -50,6 +50,41 @@ impl SessionInstance {
         dimension: Identifier<'static>,
         height_max: u32,
         height_min: i32,
+    ) -> Self {
+        Self::new_using(
+            dimension,
+            (height_max, height_min),
+            (V::biomes(), V::blocks(), V::entities(), V::items()),
+        )
+    }
+
+    /// Create a new, empty [`SessionInstance`] for a different dimension
+    /// using the same version as this one.
+    #[must_use]
+    pub fn with_dimension(
+        &self,
+        dimension: Identifier<'static>,
+        height_max: u32,
+        height_min: i32,
+    ) -> Self {
+        Self::new_using(
+            dimension,
+            (height_max, height_min),
+            (self.v_biomes, self.v_blocks, self.v_entities, self.v_items),
+        )
+    }
+
+    /// Create a new, empty [`SessionInstance`] using the given storages.
+    #[must_use]
+    fn new_using(
+        dimension: Identifier<'static>,
+        height_max_min: (u32, i32),
+        (v_biomes, v_blocks, v_entities, v_items): (
+            &'static BiomeStorage,
+            &'static BlockStorage,
+            &'static EntityStorage,
+            &'static ItemStorage,
+        ),
     ) -> Self {
         let bytes = dimension.as_str().as_bytes();
         let mut seed_a = Self::create_seed(0, bytes);
@@ -63,12 +98,12 @@ impl SessionInstance {
 
         Self {
             dimension,
-            height_max_min: (height_max, height_min),
+            height_max_min,
 
-            v_biomes: V::biomes(),
-            v_blocks: V::blocks(),
-            v_entities: V::entities(),
-            v_items: V::items(),
+            v_biomes,
+            v_blocks,
+            v_entities,
+            v_items,
 
             entity: EntityHashSet::new(),
             entity_id: HashMap::with_hasher(FixedState::with_seed(seed_a)),
@@ -4,6 +4,8 @@
 extern crate alloc;
 
 pub mod bevy;
+pub mod blocks;
+pub mod dimension;
 pub mod instance;
 pub mod query;
 pub mod queue;
@@ -13,6 +15,8 @@ pub mod prelude {
     //! Re-exports of common types, traits, and macros.
 
     pub use crate::{
+        blocks::{InstanceBlocks, InstanceBlocksMut},
+        dimension::{ChangeDimension, DimensionChanged},
         instance::SessionInstance,
         query::{InInstance, OnInstance},
         queue::BlockEditQueue,
@@ -15,7 +15,7 @@ use hashbrown::HashMap;
 use crate::prelude::*;
 
 /// A queue of [`BlockEdit`]s to be applied.
-#[derive(Debug, Clone, PartialEq, Eq, Component, Reflect)]
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
 #[reflect(opaque, Debug, Default, Clone, PartialEq, Component)]
 pub struct BlockEditQueue {
     queue: HashMap<ChunkPos, Vec<BlockEdit>, RandomState>,
@@ -23,10 +23,24 @@ pub struct BlockEditQueue {
 }
 
 /// A block edit to be applied to a [`Chunk`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct BlockEdit {
-    position: BlockPos,
-    block: Block,
+#[derive(Debug, Clone, PartialEq)]
+enum BlockEdit {
+    Block { position: BlockPos, block: Block },
+    BlockEntity { position: BlockPos, block_entity: BlockEntity },
+}
+
+impl BlockEdit {
+    /// Apply this edit to a [`Chunk`].
+    fn apply(self, chunk: &mut Chunk) {
+        match self {
+            BlockEdit::Block { position, block } => {
+                chunk.set_block(position, block);
+            }
+            BlockEdit::BlockEntity { position, block_entity } => {
+                chunk.set_block_entity(position, block_entity);
+            }
+        }
+    }
 }
 
 impl Default for BlockEditQueue {
@@ -51,7 +65,18 @@ impl BlockEditQueue {
     pub fn push(&mut self, position: BlockPos, block: Block) {
         let chunk = position.into_chunk_pos();
         let queue = self.queue.entry(chunk).or_default();
-        queue.push(BlockEdit { position, block });
+        queue.push(BlockEdit::Block { position, block });
+        self.is_empty = false;
+    }
+
+    /// Push a [`BlockEntity`] to be placed in a [`Chunk`].
+    ///
+    /// Block entities are applied in order with block edits, so a
+    /// [`BlockEntity`] pushed after a [`Block`] will not be removed by it.
+    pub fn push_block_entity(&mut self, position: BlockPos, block_entity: BlockEntity) {
+        let chunk = position.into_chunk_pos();
+        let queue = self.queue.entry(chunk).or_default();
+        queue.push(BlockEdit::BlockEntity { position, block_entity });
         self.is_empty = false;
     }
 
@@ -88,8 +113,8 @@ impl BlockEditQueue {
             {
                 // Apply edits, cloning only if needed.
                 let chunk = shared.make_mut();
-                for BlockEdit { position, block } in edits.drain(..) {
-                    chunk.set_block(position, block);
+                for edit in edits.drain(..) {
+                    edit.apply(chunk);
                 }
             } else {
                 #[cfg(feature = "tracing")]
@@ -135,8 +160,8 @@ impl BlockEditQueue {
             {
                 // Clone, apply edits, and store the modified chunk.
                 let mut chunk = shared.clone_inner();
-                for BlockEdit { position, block } in edits.drain(..) {
-                    chunk.set_block(position, block);
+                for edit in edits.drain(..) {
+                    edit.apply(&mut chunk);
                 }
                 output.insert(entity, SharedChunk::new(chunk));
             } else {
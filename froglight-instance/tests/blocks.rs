@@ -0,0 +1,214 @@
+//! TODO
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+
+use bevy_app::App;
+use bevy_ecs::{prelude::*, system::RunSystemOnce};
+use froglight_block::prelude::*;
+use froglight_common::{prelude::Identifier, version::V26_1};
+use froglight_instance::{bevy::InstancePlugin, prelude::*};
+use froglight_nbt::prelude::NbtCompound;
+use froglight_world::prelude::*;
+
+/// Create an [`App`] with a single [`SessionInstance`] and four [`Chunk`]s
+/// surrounding the origin.
+fn setup() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins(InstancePlugin);
+
+    let world = app.world_mut();
+    let instance = world
+        .spawn((
+            SessionInstance::new::<V26_1>(Identifier::new_static("minecraft:overworld"), 320, -64),
+            BlockEditQueue::new(),
+        ))
+        .id();
+
+    let stone = Block::new_default::<block::Stone, V26_1>();
+    for (x, z) in [(-1, -1), (-1, 0), (0, -1), (0, 0)] {
+        let position = ChunkPos::new_xz(x, z);
+
+        let mut chunk = Chunk::empty_large::<V26_1>();
+        for block in [BlockPos::new_xyz(-5, 10, -7), BlockPos::new_xyz(3, 12, 4)] {
+            if block.into_chunk_pos() == position {
+                chunk.set_block(block, stone).unwrap();
+            }
+        }
+
+        world.spawn((PartOfInstance::new(instance), SharedChunk::new(chunk), position));
+    }
+
+    (app, instance)
+}
+
+#[test]
+fn get_block() {
+    let (mut app, instance) = setup();
+
+    app.world_mut()
+        .run_system_once(move |query: Query<&SessionInstance>, blocks: InstanceBlocks| {
+            let instance = query.get(instance).unwrap();
+
+            let block = blocks.get_block(instance, BlockPos::new_xyz(-5, 10, -7)).unwrap();
+            assert!(block.is_block::<block::Stone>());
+            let block = blocks.get_block(instance, BlockPos::new_xyz(3, 12, 4)).unwrap();
+            assert!(block.is_block::<block::Stone>());
+            let block = blocks.get_block(instance, BlockPos::new_xyz(-6, 10, -7)).unwrap();
+            assert!(block.is_air());
+
+            // Unloaded chunks and positions outside of the world.
+            assert!(blocks.get_block(instance, BlockPos::new_xyz(16, 10, 0)).is_none());
+            assert!(blocks.get_block(instance, BlockPos::new_xyz(0, -65, 0)).is_none());
+            assert!(blocks.get_biome(instance, BlockPos::new_xyz(0, 0, 0)).is_some());
+
+            // Iterate over a region spanning all four chunks.
+            let region = BlockPosIter::new_inclusive(
+                BlockPos::new_xyz(-8, 8, -8),
+                BlockPos::new_xyz(8, 16, 8),
+            );
+            let stone = blocks
+                .iter_region(instance, region)
+                .filter(|(_, block)| block.unwrap().is_block::<block::Stone>())
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>();
+            assert_eq!(stone, [BlockPos::new_xyz(-5, 10, -7), BlockPos::new_xyz(3, 12, 4)]);
+        })
+        .unwrap();
+}
+
+#[test]
+fn find_nearest() {
+    let (mut app, instance) = setup();
+
+    app.world_mut()
+        .run_system_once(move |query: Query<&SessionInstance>, blocks: InstanceBlocks| {
+            let instance = query.get(instance).unwrap();
+            let stone = Block::new_default::<block::Stone, V26_1>();
+            let is_stone = |block: Block| block.is_block::<block::Stone>();
+
+            // The closer of the two blocks is found first.
+            let origin = BlockPos::new_xyz(0, 10, 0);
+            let (position, block) = blocks.find_nearest(instance, origin, 32, is_stone).unwrap();
+            assert_eq!(position, BlockPos::new_xyz(3, 12, 4));
+            assert_eq!(block, stone);
+            assert_eq!(
+                blocks.find_nearest_exact(instance, origin, 32, stone),
+                Some(BlockPos::new_xyz(3, 12, 4))
+            );
+
+            // Unless the origin is closer to the other block.
+            let origin = BlockPos::new_xyz(-4, 10, -4);
+            assert_eq!(
+                blocks.find_nearest_exact(instance, origin, 32, stone),
+                Some(BlockPos::new_xyz(-5, 10, -7))
+            );
+
+            // Large radii only search the loaded chunks.
+            assert_eq!(
+                blocks.find_nearest_exact(instance, origin, u32::MAX, stone),
+                Some(BlockPos::new_xyz(-5, 10, -7))
+            );
+
+            // Blocks outside of the radius are ignored.
+            let origin = BlockPos::new_xyz(0, 10, 0);
+            assert!(blocks.find_nearest(instance, origin, 5, is_stone).is_none());
+            assert!(blocks.find_nearest_exact(instance, origin, 5, stone).is_none());
+        })
+        .unwrap();
+}
+
+#[test]
+fn set_block() {
+    let (mut app, instance) = setup();
+    let position = BlockPos::new_xyz(-1, 64, -1);
+    let dirt = Block::new_default::<block::Dirt, V26_1>();
+
+    app.world_mut()
+        .run_system_once(move |mut blocks: InstanceBlocksMut| {
+            blocks.set_block(instance, position, dirt).unwrap();
+            blocks
+                .fill_region(
+                    instance,
+                    BlockPosIter::new_inclusive(
+                        BlockPos::new_xyz(0, 0, 0),
+                        BlockPos::new_xyz(1, 1, 1),
+                    ),
+                    dirt,
+                )
+                .unwrap();
+        })
+        .unwrap();
+    app.world_mut().run_system_once(InstancePlugin::apply_blockedits).unwrap();
+
+    app.world_mut()
+        .run_system_once(move |query: Query<&SessionInstance>, blocks: InstanceBlocks| {
+            let instance = query.get(instance).unwrap();
+            assert_eq!(blocks.get_block(instance, position), Some(dirt));
+
+            let region =
+                BlockPosIter::new_inclusive(BlockPos::new_xyz(0, 0, 0), BlockPos::new_xyz(1, 1, 1));
+            assert!(blocks.iter_region(instance, region).all(|(_, block)| block == Some(dirt)));
+        })
+        .unwrap();
+}
+
+#[test]
+fn apply_to_shared() {
+    let (mut app, instance) = setup();
+    let world = app.world_mut();
+
+    let chest = BlockPos::new_xyz(1, 10, 1);
+    let barrel = BlockPos::new_xyz(2, 10, 2);
+    let dirt = Block::new_default::<block::Dirt, V26_1>();
+
+    // Add heightmaps and a block entity, then keep a reader of the chunk.
+    let entity = world.get::<SessionInstance>(instance).unwrap().get_chunk(&ChunkPos::new_xz(0, 0));
+    let mut shared = world.get_mut::<SharedChunk>(entity.unwrap()).unwrap();
+    let chunk = shared.make_mut();
+    chunk.recompute_heightmaps();
+    chunk.set_block_entity(chest, BlockEntity::new(VanillaBlockEntity::Chest, NbtCompound::new()));
+    let reader = shared.load();
+
+    // Queue a block and a block entity, and apply them to the shared chunk.
+    let mut queue = world.get_mut::<BlockEditQueue>(instance).unwrap();
+    queue.push(BlockPos::new_xyz(2, 100, 2), dirt);
+    queue.push_block_entity(
+        barrel,
+        BlockEntity::new(VanillaBlockEntity::Barrel, NbtCompound::new()),
+    );
+    world
+        .run_system_once(
+            move |mut query: Query<(&SessionInstance, &mut BlockEditQueue)>,
+                  chunks: Query<&mut SharedChunk>| {
+                let (instance, mut queue) = query.get_mut(instance).unwrap();
+                queue.apply_to(instance, chunks);
+                assert!(queue.is_empty());
+            },
+        )
+        .unwrap();
+
+    let current = world.get::<SharedChunk>(entity.unwrap()).unwrap().load();
+    assert!(!Arc::ptr_eq(&reader, &current));
+
+    // The reader still sees the chunk as it was before the edits.
+    assert_eq!(
+        reader.get_block(BlockPos::new_xyz(2, 100, 2)),
+        Some(Block::new_default::<block::Air, V26_1>())
+    );
+    assert_eq!(reader.iter_block_entities().count(), 1);
+    assert!(reader.get_block_entity(barrel).is_none());
+    assert_eq!(reader.height_at(HeightmapKind::WorldSurface, 2, 2), Some(-64));
+
+    // The edited chunk contains both block entities and updated heightmaps.
+    assert_eq!(current.get_block(BlockPos::new_xyz(2, 100, 2)), Some(dirt));
+    assert_eq!(current.iter_block_entities().count(), 2);
+    assert!(current.get_block_entity(chest).is_some());
+    assert!(current.get_block_entity(barrel).is_some());
+    assert_eq!(current.height_at(HeightmapKind::WorldSurface, 2, 2), Some(101));
+
+    // Sections that were not modified are still shared.
+    assert!(reader.sections()[0].shares_data(&current.sections()[0]));
+    assert!(!reader.sections()[10].shares_data(&current.sections()[10]));
+}
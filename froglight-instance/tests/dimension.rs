@@ -0,0 +1,141 @@
+//! TODO
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use froglight_common::{prelude::Identifier, version::V26_1};
+use froglight_entity::prelude::EntityId;
+use froglight_instance::{bevy::InstancePlugin, prelude::*};
+use froglight_world::prelude::*;
+
+/// All [`DimensionChanged`] events that were triggered.
+#[derive(Default, Resource)]
+struct Changes(Vec<DimensionChanged>);
+
+/// Spawn a chunk and an entity that are part of an instance.
+fn populate(world: &mut World, instance: Entity) -> (Entity, Entity) {
+    let chunk = world
+        .spawn((
+            PartOfInstance::new(instance),
+            SharedChunk::new(Chunk::empty_large::<V26_1>()),
+            ChunkPos::new_xz(0, 0),
+        ))
+        .id();
+    let entity = world.spawn((PartOfInstance::new(instance), EntityId(2))).id();
+    (chunk, entity)
+}
+
+#[test]
+fn change_dimension() {
+    let mut app = App::new();
+    app.add_plugins(InstancePlugin);
+    app.init_resource::<Changes>();
+    app.add_observer(|trigger: On<DimensionChanged>, mut changes: ResMut<Changes>| {
+        changes.0.push(trigger.event().clone());
+    });
+
+    let world = app.world_mut();
+    let overworld = Identifier::new_static("minecraft:overworld");
+    let nether = Identifier::new_static("minecraft:the_nether");
+
+    // Join the overworld.
+    let bot = world.spawn(BlockEditQueue::new()).id();
+    world.commands().entity(bot).queue(ChangeDimension::new(SessionInstance::new::<V26_1>(
+        overworld.clone(),
+        320,
+        -64,
+    )));
+    world.flush();
+    world.entity_mut(bot).insert((PartOfInstance::new(bot), EntityId(1)));
+
+    let (chunk, entity) = populate(world, bot);
+    let instance = world.get::<SessionInstance>(bot).unwrap();
+    assert_eq!(instance.iter_entity().count(), 3);
+    assert_eq!(
+        world.resource::<Changes>().0,
+        [DimensionChanged::new(bot, None, overworld.clone(), None)]
+    );
+
+    // Switch to the nether, discarding the overworld.
+    let nether_instance = instance.with_dimension(nether.clone(), 256, 0);
+    world.commands().entity(bot).queue(ChangeDimension::new(nether_instance));
+    world.flush();
+
+    assert!(world.get_entity(chunk).is_err());
+    assert!(world.get_entity(entity).is_err());
+
+    let instance = world.get::<SessionInstance>(bot).unwrap();
+    assert_eq!(instance.dimension(), &nether);
+    assert_eq!((instance.height_max(), instance.height_min()), (256, 0));
+    assert_eq!(instance.get_id(&EntityId(1)), Some(bot));
+    assert_eq!(instance.iter_entity().collect::<Vec<_>>(), [&bot]);
+    assert_eq!(
+        world.resource::<Changes>().0[1],
+        DimensionChanged::new(bot, Some(overworld.clone()), nether.clone(), None)
+    );
+
+    // Switch back to the overworld, keeping the nether.
+    let overworld_instance = instance.with_dimension(overworld.clone(), 320, -64);
+    let (chunk, entity) = populate(world, bot);
+    world
+        .commands()
+        .entity(bot)
+        .queue(ChangeDimension::new(overworld_instance).keep_previous(true));
+    world.flush();
+
+    let change = world.resource::<Changes>().0[2].clone();
+    assert_eq!(change.previous(), Some(&nether));
+    assert_eq!(change.dimension(), &overworld);
+    let stored = change.stored().unwrap();
+
+    let instance = world.get::<SessionInstance>(bot).unwrap();
+    assert_eq!(instance.dimension(), &overworld);
+    assert_eq!(instance.iter_entity().collect::<Vec<_>>(), [&bot]);
+
+    let stored_instance = world.get::<SessionInstance>(stored).unwrap();
+    assert_eq!(stored_instance.dimension(), &nether);
+    assert_eq!((stored_instance.height_max(), stored_instance.height_min()), (256, 0));
+    assert_eq!(stored_instance.get_chunk(&ChunkPos::new_xz(0, 0)), Some(chunk));
+    assert_eq!(stored_instance.get_id(&EntityId(2)), Some(entity));
+    assert_eq!(stored_instance.get_id(&EntityId(1)), None);
+    assert_eq!(world.get::<PartOfInstance>(chunk), Some(&PartOfInstance::new(stored)));
+}
+
+#[test]
+fn other_instances() {
+    let mut app = App::new();
+    app.add_plugins(InstancePlugin);
+
+    let world = app.world_mut();
+    let overworld = Identifier::new_static("minecraft:overworld");
+    let nether = Identifier::new_static("minecraft:the_nether");
+
+    // Two bots in separate instances of the same dimension.
+    let [first, second] =
+        [EntityId(1), EntityId(3)].map(|id| {
+            let bot = world.spawn_empty().id();
+            world.commands().entity(bot).queue(ChangeDimension::new(
+                SessionInstance::new::<V26_1>(overworld.clone(), 320, -64),
+            ));
+            world.flush();
+            world.entity_mut(bot).insert((PartOfInstance::new(bot), id));
+            bot
+        });
+    let (first_chunk, first_entity) = populate(world, first);
+    let (second_chunk, second_entity) = populate(world, second);
+
+    // Only the first bot's entities are despawned.
+    let instance = world.get::<SessionInstance>(first).unwrap();
+    let nether_instance = instance.with_dimension(nether, 256, 0);
+    world.commands().entity(first).queue(ChangeDimension::new(nether_instance));
+    world.flush();
+
+    assert!(world.get_entity(first_chunk).is_err());
+    assert!(world.get_entity(first_entity).is_err());
+    assert!(world.get_entity(second_chunk).is_ok());
+    assert!(world.get_entity(second_entity).is_ok());
+
+    let instance = world.get::<SessionInstance>(second).unwrap();
+    assert_eq!(instance.dimension(), &overworld);
+    assert_eq!(instance.iter_entity().count(), 3);
+    assert_eq!(instance.get_chunk(&ChunkPos::new_xz(0, 0)), Some(second_chunk));
+}
@@ -7,6 +7,8 @@ extern crate std;
 
 pub mod generated;
 pub mod item;
+#[cfg(feature = "froglight-block")]
+pub mod mining;
 pub mod state;
 pub mod storage;
 pub mod version;
@@ -0,0 +1,369 @@
+//! TODO
+
+use froglight_block::block::{Block, MineableTools, ToolTier};
+
+use crate::item::Item;
+
+/// The kind of a [`Tool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolKind {
+    /// A pickaxe.
+    Pickaxe,
+    /// An axe.
+    Axe,
+    /// A shovel.
+    Shovel,
+    /// A hoe.
+    Hoe,
+    /// A sword.
+    Sword,
+    /// A pair of shears.
+    Shears,
+}
+
+impl ToolKind {
+    /// Get the [`MineableTools`] this kind of tool mines efficiently.
+    #[must_use]
+    pub const fn mineable(self) -> MineableTools {
+        match self {
+            ToolKind::Pickaxe => MineableTools::PICKAXE,
+            ToolKind::Axe => MineableTools::AXE,
+            ToolKind::Shovel => MineableTools::SHOVEL,
+            ToolKind::Hoe => MineableTools::HOE,
+            ToolKind::Sword => MineableTools::SWORD,
+            ToolKind::Shears => MineableTools::NONE,
+        }
+    }
+}
+
+/// The material of a [`Tool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolMaterial {
+    /// Wooden tools.
+    Wood,
+    /// Stone tools.
+    Stone,
+    /// Copper tools.
+    Copper,
+    /// Iron tools.
+    Iron,
+    /// Diamond tools.
+    Diamond,
+    /// Golden tools.
+    Gold,
+    /// Netherite tools.
+    Netherite,
+}
+
+impl ToolMaterial {
+    /// The mining speed of tools made of this material.
+    #[must_use]
+    pub const fn speed(self) -> f32 {
+        match self {
+            ToolMaterial::Wood => 2.0,
+            ToolMaterial::Stone => 4.0,
+            ToolMaterial::Copper => 5.0,
+            ToolMaterial::Iron => 6.0,
+            ToolMaterial::Diamond => 8.0,
+            ToolMaterial::Gold => 12.0,
+            ToolMaterial::Netherite => 9.0,
+        }
+    }
+
+    /// Returns `true` if tools made of this material
+    /// can harvest blocks that need the given [`ToolTier`].
+    #[must_use]
+    pub const fn can_harvest(self, tier: ToolTier) -> bool {
+        match self {
+            ToolMaterial::Wood | ToolMaterial::Gold => matches!(tier, ToolTier::Any),
+            ToolMaterial::Stone | ToolMaterial::Copper => {
+                matches!(tier, ToolTier::Any | ToolTier::Stone)
+            }
+            ToolMaterial::Iron => !matches!(tier, ToolTier::Diamond),
+            ToolMaterial::Diamond | ToolMaterial::Netherite => true,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A tool used to mine blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tool {
+    kind: ToolKind,
+    material: Option<ToolMaterial>,
+}
+
+impl Tool {
+    /// Create a new [`Tool`].
+    ///
+    /// Shears have no material,
+    /// every other kind of tool should have one.
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: ToolKind, material: Option<ToolMaterial>) -> Self {
+        Self { kind, material }
+    }
+
+    /// Get the [`Tool`] for an [`Item`].
+    ///
+    /// Returns `None` if the item is not a tool.
+    #[must_use]
+    pub fn from_item(item: &Item) -> Option<Self> {
+        Self::from_identifier(item.identifier().as_str())
+    }
+
+    /// Get the [`Tool`] for an item identifier,
+    /// such as `minecraft:diamond_pickaxe`.
+    ///
+    /// Returns `None` if the item is not a tool.
+    #[must_use]
+    pub fn from_identifier(identifier: &str) -> Option<Self> {
+        let path = identifier.strip_prefix("minecraft:")?;
+        if path == "shears" {
+            return Some(Self::new(ToolKind::Shears, None));
+        }
+
+        let (material, kind) = path.split_once('_')?;
+        let material = match material {
+            "wooden" => ToolMaterial::Wood,
+            "stone" => ToolMaterial::Stone,
+            "copper" => ToolMaterial::Copper,
+            "iron" => ToolMaterial::Iron,
+            "diamond" => ToolMaterial::Diamond,
+            "golden" => ToolMaterial::Gold,
+            "netherite" => ToolMaterial::Netherite,
+            _ => return None,
+        };
+        let kind = match kind {
+            "pickaxe" => ToolKind::Pickaxe,
+            "axe" => ToolKind::Axe,
+            "shovel" => ToolKind::Shovel,
+            "hoe" => ToolKind::Hoe,
+            "sword" => ToolKind::Sword,
+            _ => return None,
+        };
+
+        Some(Self::new(kind, Some(material)))
+    }
+
+    /// Get the [`ToolKind`] of this tool.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> ToolKind { self.kind }
+
+    /// Get the [`ToolMaterial`] of this tool, if it has one.
+    #[inline]
+    #[must_use]
+    pub const fn material(&self) -> Option<ToolMaterial> { self.material }
+
+    /// Get the mining speed of this tool against a [`Block`].
+    ///
+    /// Returns `1.0` if the tool is not effective against the block.
+    #[must_use]
+    pub fn speed_against(&self, block: &Block) -> f32 {
+        let identifier = block.identifier();
+        match self.kind {
+            ToolKind::Sword => match identifier.as_str() {
+                "minecraft:cobweb" => 15.0,
+                "minecraft:bamboo" | "minecraft:bamboo_sapling" => f32::MAX,
+                _ if block.mineable().contains(MineableTools::SWORD) => 1.5,
+                _ => 1.0,
+            },
+            ToolKind::Shears => match identifier.as_str() {
+                "minecraft:cobweb" => 15.0,
+                "minecraft:vine" | "minecraft:glow_lichen" => 2.0,
+                path if path.ends_with("_leaves") => 15.0,
+                path if path.ends_with("_wool") => 5.0,
+                _ => 1.0,
+            },
+            kind => match self.material {
+                Some(material) if block.mineable().contains(kind.mineable()) => material.speed(),
+                _ => 1.0,
+            },
+        }
+    }
+
+    /// Returns `true` if this tool is the correct tool to harvest a [`Block`].
+    ///
+    /// Blocks that do not [require a tool](Block::requires_tool)
+    /// can be harvested with any tool.
+    #[must_use]
+    pub fn is_correct_for(&self, block: &Block) -> bool {
+        match self.kind {
+            ToolKind::Sword | ToolKind::Shears => block.identifier().as_str() == "minecraft:cobweb",
+            kind => {
+                self.material.is_some_and(|material| material.can_harvest(block.tool_tier()))
+                    && block.mineable().contains(kind.mineable())
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The state of a player that affects how fast they break blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MiningState {
+    /// The level of Efficiency on the held tool.
+    pub efficiency: u8,
+    /// The amplifier of Haste or Conduit Power, if active.
+    pub haste: Option<u8>,
+    /// The amplifier of Mining Fatigue, if active.
+    pub mining_fatigue: Option<u8>,
+    /// Whether the player's eyes are underwater.
+    pub underwater: bool,
+    /// Whether the player has Aqua Affinity.
+    pub aqua_affinity: bool,
+    /// Whether the player is standing on the ground.
+    pub on_ground: bool,
+}
+
+impl Default for MiningState {
+    fn default() -> Self {
+        Self {
+            efficiency: 0,
+            haste: None,
+            mining_fatigue: None,
+            underwater: false,
+            aqua_affinity: false,
+            on_ground: true,
+        }
+    }
+}
+
+impl MiningState {
+    /// Get the speed at which a player mines a [`Block`] using a [`Tool`].
+    #[must_use]
+    pub fn speed_against(&self, block: &Block, tool: Option<&Tool>) -> f32 {
+        let mut speed = tool.map_or(1.0, |tool| tool.speed_against(block));
+
+        if speed > 1.0 && self.efficiency > 0 {
+            let efficiency = f32::from(self.efficiency);
+            speed += efficiency * efficiency + 1.0;
+        }
+        if let Some(haste) = self.haste {
+            speed *= 1.0 + (f32::from(haste) + 1.0) * 0.2;
+        }
+        if let Some(fatigue) = self.mining_fatigue {
+            speed *= match fatigue {
+                0 => 0.3,
+                1 => 0.09,
+                2 => 0.0027,
+                _ => 0.00081,
+            };
+        }
+        if self.underwater && !self.aqua_affinity {
+            speed *= 0.2;
+        }
+        if !self.on_ground {
+            speed /= 5.0;
+        }
+
+        speed
+    }
+}
+
+/// Get the fraction of a [`Block`] that is broken each tick.
+///
+/// Returns `Some(0.0)` if the block cannot be broken,
+/// or `None` if its hardness is not known.
+#[must_use]
+pub fn break_progress(block: &Block, tool: Option<&Tool>, state: &MiningState) -> Option<f32> {
+    let hardness = block.hardness()?;
+    if hardness < 0.0 {
+        return Some(0.0);
+    }
+
+    let correct = !block.requires_tool() || tool.is_some_and(|tool| tool.is_correct_for(block));
+    let penalty = if correct { 30.0 } else { 100.0 };
+    Some(state.speed_against(block, tool) / hardness / penalty)
+}
+
+/// Get the number of ticks it takes to break a [`Block`] while holding an
+/// [`Item`].
+///
+/// Returns `Some(0)` if the block breaks instantly,
+/// or `None` if it cannot be broken or its hardness is not known.
+#[must_use]
+pub fn break_ticks(block: &Block, item: Option<&Item>, state: &MiningState) -> Option<u32> {
+    let tool = item.and_then(Tool::from_item);
+    BlockBreaking::new(break_progress(block, tool.as_ref(), state)?).ticks()
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The progress of breaking a block.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BlockBreaking {
+    progress: f32,
+    per_tick: f32,
+}
+
+impl BlockBreaking {
+    /// The number of destruction stages shown while breaking a block.
+    pub const STAGES: u8 = 10;
+
+    /// Start breaking a block,
+    /// using the progress made each tick from [`break_progress`].
+    #[inline]
+    #[must_use]
+    pub const fn new(per_tick: f32) -> Self { Self { progress: 0.0, per_tick } }
+
+    /// Advance by one tick,
+    /// returning `true` if the block is broken.
+    pub fn tick(&mut self) -> bool {
+        self.progress += self.per_tick;
+        self.is_broken()
+    }
+
+    /// Returns `true` if the block is broken.
+    #[inline]
+    #[must_use]
+    pub fn is_broken(&self) -> bool { self.progress >= 1.0 }
+
+    /// Get the progress made so far, from `0.0` to `1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn progress(&self) -> f32 { self.progress }
+
+    /// Get the destruction stage shown to other players, from `0` to `9`.
+    ///
+    /// Returns `None` if no progress has been made.
+    #[must_use]
+    pub fn stage(&self) -> Option<u8> {
+        #[expect(clippy::cast_possible_truncation, reason = "Clamped to the number of stages")]
+        #[expect(clippy::cast_sign_loss, reason = "Progress is always positive")]
+        (self.progress > 0.0)
+            .then(|| ((self.progress * f32::from(Self::STAGES)) as u8).min(Self::STAGES - 1))
+    }
+
+    /// Get the total number of ticks it takes to break the block.
+    ///
+    /// Returns `Some(0)` if the block breaks instantly,
+    /// or `None` if it cannot be broken.
+    #[must_use]
+    pub fn ticks(&self) -> Option<u32> {
+        if self.per_tick >= 1.0 {
+            Some(0)
+        } else if self.per_tick > 0.0 {
+            #[expect(clippy::cast_possible_truncation, reason = "Saturates at `u32::MAX`")]
+            #[expect(clippy::cast_sign_loss, reason = "Progress is always positive")]
+            let mut ticks = (1.0 / self.per_tick) as u32;
+
+            // Progress is stored as an `f32`, so correct for any rounding.
+            #[expect(clippy::cast_precision_loss, reason = "Matches accumulated progress")]
+            while ticks < u32::MAX && (ticks as f32) * self.per_tick < 1.0 {
+                ticks += 1;
+            }
+            #[expect(clippy::cast_precision_loss, reason = "Matches accumulated progress")]
+            while ticks > 1 && ((ticks - 1) as f32) * self.per_tick >= 1.0 {
+                ticks -= 1;
+            }
+
+            Some(ticks)
+        } else {
+            None
+        }
+    }
+}
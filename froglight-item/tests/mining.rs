@@ -0,0 +1,255 @@
+//! TODO
+
+use core::any::TypeId;
+
+use froglight_block::{
+    block::{BlockMetadata, BlockType, MineableTools, ToolTier},
+    prelude::*,
+    state::{GlobalStateId, RelativeStateId},
+    storage::BlockStorage,
+    version::version_implement,
+};
+use froglight_common::{
+    prelude::{Identifier, Version},
+    version::V26_1,
+};
+use froglight_item::mining::{
+    BlockBreaking, MiningState, Tool, ToolKind, ToolMaterial, break_progress,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+struct TestVersion;
+
+impl Version for TestVersion {
+    const DATA_VERSION: u32 = u32::MIN;
+    const PROTOCOL_ID: u32 = u32::MIN;
+    const RESOURCE_VERSION: u32 = u32::MIN;
+}
+
+macro_rules! test_block {
+    ($ident:ident, $name:literal, $id:literal $(, $hardness:expr, $tool:literal, $mineable:expr, $tier:expr)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct $ident;
+
+        impl BlockType<TestVersion> for $ident {
+            type Attributes = ();
+
+            const ATTRDATA: &'static [(&'static str, TypeId)] = &[];
+            const METADATA: &'static BlockMetadata = {
+                static STATIC: BlockMetadata = unsafe {
+                    BlockMetadata::new::<$ident, TestVersion>(
+                        Identifier::new_static($name),
+                        GlobalStateId::new($id),
+                        RelativeStateId::new(0),
+                    )
+                };
+                &STATIC
+            };
+
+            $(
+                fn hardness(_: RelativeStateId) -> Option<f32> { Some($hardness) }
+
+                fn requires_tool(_: RelativeStateId) -> bool { $tool }
+
+                fn mineable(_: RelativeStateId) -> MineableTools { $mineable }
+
+                fn tool_tier(_: RelativeStateId) -> ToolTier { $tier }
+            )?
+        }
+    };
+}
+
+test_block!(Stone, "minecraft:stone", 0, 1.5, true, MineableTools::PICKAXE, ToolTier::Any);
+test_block!(Dirt, "minecraft:dirt", 1, 0.5, false, MineableTools::SHOVEL, ToolTier::Any);
+test_block!(
+    Obsidian,
+    "minecraft:obsidian",
+    2,
+    50.0,
+    true,
+    MineableTools::PICKAXE,
+    ToolTier::Diamond
+);
+test_block!(Cobweb, "minecraft:cobweb", 3, 4.0, true, MineableTools::SWORD, ToolTier::Any);
+test_block!(Bedrock, "minecraft:bedrock", 4, -1.0, false, MineableTools::NONE, ToolTier::Any);
+test_block!(Torch, "minecraft:torch", 5, 0.0, false, MineableTools::NONE, ToolTier::Any);
+test_block!(Unknown, "minecraft:unknown", 6);
+
+version_implement! {
+    impl BlockVersion => TestVersion {
+        const BLOCKS: BlockStorage;
+        fn new_blocks() => {
+            unsafe {
+                BlockStorage::build::<TestVersion>(Box::new([
+                    Stone::METADATA,
+                    Dirt::METADATA,
+                    Obsidian::METADATA,
+                    Cobweb::METADATA,
+                    Bedrock::METADATA,
+                    Torch::METADATA,
+                    Unknown::METADATA,
+                ]))
+            }
+        }
+    }
+}
+
+/// Get the number of ticks it takes to break a block.
+fn ticks<B: BlockType<TestVersion>>(tool: Option<&str>, state: MiningState) -> Option<u32> {
+    let block = Block::new_default::<B, TestVersion>();
+    let tool = tool.map(|tool| Tool::from_identifier(tool).unwrap());
+    BlockBreaking::new(break_progress(&block, tool.as_ref(), &state)?).ticks()
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn tool() {
+    assert_eq!(
+        Tool::from_identifier("minecraft:diamond_pickaxe"),
+        Some(Tool::new(ToolKind::Pickaxe, Some(ToolMaterial::Diamond)))
+    );
+    assert_eq!(
+        Tool::from_identifier("minecraft:golden_axe"),
+        Some(Tool::new(ToolKind::Axe, Some(ToolMaterial::Gold)))
+    );
+    assert_eq!(Tool::from_identifier("minecraft:shears"), Some(Tool::new(ToolKind::Shears, None)));
+
+    assert_eq!(Tool::from_identifier("minecraft:diamond"), None);
+    assert_eq!(Tool::from_identifier("minecraft:stone"), None);
+    assert_eq!(Tool::from_identifier("minecraft:iron_chestplate"), None);
+
+    let stone = Block::new_default::<Stone, TestVersion>();
+    let obsidian = Block::new_default::<Obsidian, TestVersion>();
+    let iron = Tool::from_identifier("minecraft:iron_pickaxe").unwrap();
+    let diamond = Tool::from_identifier("minecraft:diamond_pickaxe").unwrap();
+    let shovel = Tool::from_identifier("minecraft:diamond_shovel").unwrap();
+
+    assert!(iron.is_correct_for(&stone));
+    assert!(!iron.is_correct_for(&obsidian));
+    assert!(diamond.is_correct_for(&obsidian));
+    assert!(!shovel.is_correct_for(&stone));
+    assert!((shovel.speed_against(&stone) - 1.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn ticks_vanilla() {
+    let state = MiningState::default();
+
+    // Stone, with and without the correct tool.
+    assert_eq!(ticks::<Stone>(None, state), Some(150));
+    assert_eq!(ticks::<Stone>(Some("minecraft:wooden_pickaxe"), state), Some(23));
+    assert_eq!(ticks::<Stone>(Some("minecraft:stone_pickaxe"), state), Some(12));
+    assert_eq!(ticks::<Stone>(Some("minecraft:iron_pickaxe"), state), Some(8));
+    assert_eq!(ticks::<Stone>(Some("minecraft:diamond_pickaxe"), state), Some(6));
+    assert_eq!(ticks::<Stone>(Some("minecraft:netherite_pickaxe"), state), Some(5));
+    assert_eq!(ticks::<Stone>(Some("minecraft:golden_pickaxe"), state), Some(4));
+    assert_eq!(ticks::<Stone>(Some("minecraft:diamond_shovel"), state), Some(150));
+
+    // Dirt does not require a tool.
+    assert_eq!(ticks::<Dirt>(None, state), Some(15));
+    assert_eq!(ticks::<Dirt>(Some("minecraft:wooden_shovel"), state), Some(8));
+
+    // Obsidian needs a diamond pickaxe.
+    assert_eq!(ticks::<Obsidian>(Some("minecraft:iron_pickaxe"), state), Some(834));
+    assert_eq!(ticks::<Obsidian>(Some("minecraft:diamond_pickaxe"), state), Some(188));
+
+    // Cobwebs are broken quickly by swords and shears.
+    assert_eq!(ticks::<Cobweb>(None, state), Some(400));
+    assert_eq!(ticks::<Cobweb>(Some("minecraft:wooden_sword"), state), Some(8));
+    assert_eq!(ticks::<Cobweb>(Some("minecraft:shears"), state), Some(8));
+
+    // Some blocks break instantly, others can't be broken at all.
+    assert_eq!(ticks::<Torch>(None, state), Some(0));
+    assert_eq!(ticks::<Bedrock>(None, state), None);
+    assert_eq!(ticks::<Bedrock>(Some("minecraft:netherite_pickaxe"), state), None);
+
+    // Blocks without a known hardness never break instantly.
+    let unknown = Block::new_default::<Unknown, TestVersion>();
+    assert_eq!(break_progress(&unknown, None, &state), None);
+    assert_eq!(ticks::<Unknown>(Some("minecraft:netherite_pickaxe"), state), None);
+}
+
+#[test]
+fn ticks_modifiers() {
+    let pickaxe = Some("minecraft:diamond_pickaxe");
+
+    let efficiency = MiningState { efficiency: 5, ..MiningState::default() };
+    assert_eq!(ticks::<Stone>(pickaxe, efficiency), Some(2));
+    // Efficiency only applies when the tool is effective.
+    assert_eq!(ticks::<Stone>(Some("minecraft:diamond_shovel"), efficiency), Some(150));
+
+    let haste = MiningState { efficiency: 5, haste: Some(1), ..MiningState::default() };
+    assert_eq!(ticks::<Stone>(Some("minecraft:netherite_pickaxe"), haste), Some(0));
+
+    let fatigue = MiningState { mining_fatigue: Some(0), ..MiningState::default() };
+    assert_eq!(ticks::<Stone>(pickaxe, fatigue), Some(19));
+
+    let underwater = MiningState { underwater: true, ..MiningState::default() };
+    assert_eq!(ticks::<Stone>(pickaxe, underwater), Some(29));
+    let aqua = MiningState { aqua_affinity: true, ..underwater };
+    assert_eq!(ticks::<Stone>(pickaxe, aqua), Some(6));
+
+    let airborne = MiningState { on_ground: false, ..MiningState::default() };
+    assert_eq!(ticks::<Stone>(pickaxe, airborne), Some(29));
+}
+
+#[test]
+fn breaking() {
+    let stone = Block::new_default::<Stone, TestVersion>();
+    let pickaxe = Tool::from_identifier("minecraft:iron_pickaxe").unwrap();
+    let progress = break_progress(&stone, Some(&pickaxe), &MiningState::default()).unwrap();
+
+    let mut breaking = BlockBreaking::new(progress);
+    assert_eq!(breaking.stage(), None);
+
+    let mut stages = Vec::new();
+    while !breaking.tick() {
+        stages.push(breaking.stage().unwrap());
+    }
+    assert_eq!(stages, [1, 2, 4, 5, 6, 8, 9]);
+    assert!(breaking.is_broken());
+    assert_eq!(breaking.stage(), Some(9));
+}
+
+#[test]
+fn ticks_generated() {
+    /// Get the number of ticks it takes to break a [`V26_1`] block.
+    fn ticks<B: BlockType<V26_1>>(tool: Option<&str>) -> Option<u32> {
+        let block = Block::new_default::<B, V26_1>();
+        let tool = tool.map(|tool| Tool::from_identifier(tool).unwrap());
+        BlockBreaking::new(break_progress(&block, tool.as_ref(), &MiningState::default())?).ticks()
+    }
+
+    let stone = Block::new_default::<block::Stone, V26_1>();
+    assert!((stone.hardness().unwrap() - 1.5).abs() < f32::EPSILON);
+    assert!(stone.requires_tool());
+    assert_eq!(stone.mineable(), MineableTools::PICKAXE);
+    assert_eq!(stone.tool_tier(), ToolTier::Any);
+
+    let obsidian = Block::new_default::<block::Obsidian, V26_1>();
+    assert!((obsidian.hardness().unwrap() - 50.0).abs() < f32::EPSILON);
+    assert_eq!(obsidian.tool_tier(), ToolTier::Diamond);
+
+    // Stone takes 1.15 seconds with a wooden pickaxe and 7.5 seconds by hand.
+    assert_eq!(ticks::<block::Stone>(Some("minecraft:wooden_pickaxe")), Some(23));
+    assert_eq!(ticks::<block::Stone>(None), Some(150));
+
+    // Obsidian takes 9.4 seconds with a diamond pickaxe,
+    // and 41.7 seconds with an iron pickaxe which can't harvest it.
+    assert_eq!(ticks::<block::Obsidian>(Some("minecraft:diamond_pickaxe")), Some(188));
+    assert_eq!(ticks::<block::Obsidian>(Some("minecraft:iron_pickaxe")), Some(834));
+
+    // Dirt takes 0.75 seconds by hand and 0.4 seconds with a wooden shovel.
+    assert_eq!(ticks::<block::Dirt>(None), Some(15));
+    assert_eq!(ticks::<block::Dirt>(Some("minecraft:wooden_shovel")), Some(8));
+
+    // Leaves break faster with a hoe or a sword.
+    assert_eq!(ticks::<block::OakLeaves>(None), Some(6));
+    assert_eq!(ticks::<block::OakLeaves>(Some("minecraft:wooden_hoe")), Some(3));
+    assert_eq!(ticks::<block::OakLeaves>(Some("minecraft:wooden_sword")), Some(4));
+
+    // Torches break instantly and bedrock can't be broken.
+    assert_eq!(ticks::<block::Torch>(None), Some(0));
+    assert_eq!(ticks::<block::Bedrock>(Some("minecraft:netherite_pickaxe")), None);
+}
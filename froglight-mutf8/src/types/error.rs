@@ -0,0 +1,152 @@
+//! TODO
+
+use core::{error::Error, fmt};
+
+/// An error returned when a byte slice is not valid MUTF-8.
+///
+/// Equivalent to [`Utf8Error`](core::str::Utf8Error),
+/// but for MUTF-8 instead of UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mutf8Error {
+    valid_up_to: usize,
+    error_len: Option<u8>,
+}
+
+impl Mutf8Error {
+    /// Create a new [`Mutf8Error`].
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(valid_up_to: usize, error_len: Option<u8>) -> Self {
+        Self { valid_up_to, error_len }
+    }
+
+    /// Returns the index in the given bytes up to which valid MUTF-8 was
+    /// verified.
+    ///
+    /// It is the maximum index such that `MStr::from_mutf8(&input[..index])`
+    /// would return `Ok(_)`.
+    #[inline]
+    #[must_use]
+    pub const fn valid_up_to(&self) -> usize { self.valid_up_to }
+
+    /// Returns the length of the invalid sequence starting at
+    /// [`valid_up_to`](Self::valid_up_to).
+    ///
+    /// Returns `None` if the end of the input was reached unexpectedly,
+    /// which may happen if the input was cut off in the middle of a sequence.
+    #[inline]
+    #[must_use]
+    pub const fn error_len(&self) -> Option<usize> {
+        match self.error_len {
+            Some(len) => Some(len as usize),
+            None => None,
+        }
+    }
+}
+
+impl fmt::Display for Mutf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(len) = self.error_len {
+            write!(f, "invalid mutf-8 sequence of {len} bytes from index {}", self.valid_up_to)
+        } else {
+            write!(f, "incomplete mutf-8 byte sequence from index {}", self.valid_up_to)
+        }
+    }
+}
+
+impl Error for Mutf8Error {}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Validate that the given bytes are MUTF-8.
+///
+/// Null characters must be encoded as `[0xC0, 0x80]`, and supplementary
+/// characters must be encoded as a pair of 3-byte surrogates.
+///
+/// # Errors
+///
+/// Returns an error if the bytes are not valid MUTF-8.
+pub(crate) const fn validate(bytes: &[u8]) -> Result<(), Mutf8Error> {
+    /// Returns `true` if the byte at `index` is a continuation byte.
+    const fn is_continuation(bytes: &[u8], index: usize) -> bool {
+        index < bytes.len() && (bytes[index] & 0b1100_0000) == 0b1000_0000
+    }
+
+    /// Create an error for a sequence that is invalid after `len` bytes.
+    const fn error(bytes: &[u8], start: usize, len: usize) -> Mutf8Error {
+        if start + len >= bytes.len() {
+            Mutf8Error::new(start, None)
+        } else {
+            #[expect(clippy::cast_possible_truncation, reason = "Sequences are at most 6 bytes")]
+            Mutf8Error::new(start, Some(len as u8))
+        }
+    }
+
+    let mut index = 0;
+    while index < bytes.len() {
+        let width = match bytes[index] {
+            0x01..=0x7F => 1,
+            // U+0000 is encoded as [0xC0, 0x80].
+            0xC0 => {
+                if index + 1 < bytes.len() && bytes[index + 1] == 0x80 {
+                    2
+                } else {
+                    return Err(error(bytes, index, 1));
+                }
+            }
+            0xC2..=0xDF => {
+                if is_continuation(bytes, index + 1) {
+                    2
+                } else {
+                    return Err(error(bytes, index, 1));
+                }
+            }
+            lead @ 0xE0..=0xEF => {
+                if index + 1 >= bytes.len() {
+                    return Err(error(bytes, index, 1));
+                }
+
+                let next = bytes[index + 1];
+                let valid = match lead {
+                    0xE0 => matches!(next, 0xA0..=0xBF),
+                    // High surrogates must be followed by a low surrogate.
+                    0xED => matches!(next, 0x80..=0xAF),
+                    _ => matches!(next, 0x80..=0xBF),
+                };
+                if !valid {
+                    return Err(Mutf8Error::new(index, Some(1)));
+                }
+                if !is_continuation(bytes, index + 2) {
+                    return Err(error(bytes, index, 2));
+                }
+
+                if lead == 0xED && next >= 0xA0 {
+                    if index + 3 >= bytes.len() {
+                        return Err(Mutf8Error::new(index, None));
+                    }
+                    if bytes[index + 3] != 0xED {
+                        return Err(Mutf8Error::new(index, Some(3)));
+                    }
+                    if index + 4 >= bytes.len() {
+                        return Err(Mutf8Error::new(index, None));
+                    }
+                    if !matches!(bytes[index + 4], 0xB0..=0xBF) {
+                        return Err(Mutf8Error::new(index, Some(3)));
+                    }
+                    if !is_continuation(bytes, index + 5) {
+                        return Err(error(bytes, index, 5));
+                    }
+
+                    6
+                } else {
+                    3
+                }
+            }
+            _ => return Err(Mutf8Error::new(index, Some(1))),
+        };
+
+        index += width;
+    }
+
+    Ok(())
+}
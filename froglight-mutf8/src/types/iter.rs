@@ -0,0 +1,192 @@
+//! TODO
+
+use core::iter::FusedIterator;
+
+use crate::types::MStr;
+
+/// An iterator over the [`char`]s of a [`MStr`].
+///
+/// Created by [`MStr::chars`].
+#[derive(Debug, Clone)]
+pub struct Chars<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Chars<'a> {
+    /// Create a new [`Chars`] iterator.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(mstr: &'a MStr) -> Self { Self { bytes: mstr.as_bytes() } }
+
+    /// Views the remaining characters as a [`MStr`].
+    #[inline]
+    #[must_use]
+    pub const fn as_mstr(&self) -> &'a MStr {
+        // SAFETY: The iterator only ever splits along character boundaries.
+        unsafe { MStr::from_mutf8_unchecked(self.bytes) }
+    }
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let (c, width) = decode_first(self.bytes)?;
+        self.bytes = &self.bytes[width..];
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.bytes.len().div_ceil(6), Some(self.bytes.len()))
+    }
+}
+
+impl DoubleEndedIterator for Chars<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        let (c, width) = decode_last(self.bytes)?;
+        self.bytes = &self.bytes[..self.bytes.len() - width];
+        Some(c)
+    }
+}
+
+impl FusedIterator for Chars<'_> {}
+
+/// An iterator over the [`char`]s of a [`MStr`] and their byte positions.
+///
+/// Created by [`MStr::char_indices`].
+#[derive(Debug, Clone)]
+pub struct CharIndices<'a> {
+    offset: usize,
+    chars: Chars<'a>,
+}
+
+impl<'a> CharIndices<'a> {
+    /// Create a new [`CharIndices`] iterator.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(mstr: &'a MStr) -> Self { Self { offset: 0, chars: Chars::new(mstr) } }
+
+    /// Returns the byte position of the next character,
+    /// or the length of the string if there are no more characters.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> usize { self.offset }
+
+    /// Views the remaining characters as a [`MStr`].
+    #[inline]
+    #[must_use]
+    pub const fn as_mstr(&self) -> &'a MStr { self.chars.as_mstr() }
+}
+
+impl Iterator for CharIndices<'_> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        let (c, width) = decode_first(self.chars.bytes)?;
+        self.chars.bytes = &self.chars.bytes[width..];
+
+        let index = self.offset;
+        self.offset += width;
+        Some((index, c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.chars.size_hint() }
+}
+
+impl DoubleEndedIterator for CharIndices<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, char)> {
+        let (c, width) = decode_last(self.chars.bytes)?;
+        self.chars.bytes = &self.chars.bytes[..self.chars.bytes.len() - width];
+        Some((self.offset + self.chars.bytes.len(), c))
+    }
+}
+
+impl FusedIterator for CharIndices<'_> {}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Encode a [`char`] as MUTF-8 into the given buffer,
+/// returning the encoded bytes.
+#[must_use]
+#[expect(clippy::cast_possible_truncation, reason = "Values are masked")]
+pub(crate) fn encode_char(c: char, buffer: &mut [u8; 6]) -> &[u8] {
+    /// Encode a code unit as a 3-byte sequence.
+    const fn encode_unit(unit: u32) -> [u8; 3] {
+        [0xE0 | (unit >> 12) as u8, 0x80 | ((unit >> 6) & 0x3F) as u8, 0x80 | (unit & 0x3F) as u8]
+    }
+
+    match c {
+        // U+0000 is encoded as [0xC0, 0x80].
+        '\0' => {
+            buffer[..2].copy_from_slice(&[0xC0, 0x80]);
+            &buffer[..2]
+        }
+        // Supplementary characters are encoded as surrogate pairs.
+        c if c.len_utf16() == 2 => {
+            let codepoint = u32::from(c) - 0x0001_0000;
+            buffer[..3].copy_from_slice(&encode_unit(0xD800 | (codepoint >> 10)));
+            buffer[3..].copy_from_slice(&encode_unit(0xDC00 | (codepoint & 0x03FF)));
+            &buffer[..]
+        }
+        c => {
+            let len = c.encode_utf8(&mut buffer[..4]).len();
+            &buffer[..len]
+        }
+    }
+}
+
+/// Decode the first [`char`] of valid MUTF-8,
+/// returning it and its width in bytes.
+#[must_use]
+pub(crate) fn decode_first(bytes: &[u8]) -> Option<(char, usize)> {
+    match *bytes {
+        [] => None,
+        [a @ 0x00..=0x7F, ..] => Some((char::from(a), 1)),
+        [a @ 0xC0..=0xDF, b, ..] => Some((to_char(decode_two(a, b)), 2)),
+        [0xED, b @ 0xA0..=0xAF, c, _, e, f, ..] => {
+            Some((to_char(decode_pair(decode_three(0xED, b, c), decode_three(0xED, e, f))), 6))
+        }
+        [a, b, c, ..] => Some((to_char(decode_three(a, b, c)), 3)),
+        _ => Some((char::REPLACEMENT_CHARACTER, bytes.len())),
+    }
+}
+
+/// Decode the last [`char`] of valid MUTF-8,
+/// returning it and its width in bytes.
+#[must_use]
+pub(crate) fn decode_last(bytes: &[u8]) -> Option<(char, usize)> {
+    match *bytes {
+        [] => None,
+        [.., z @ 0x00..=0x7F] => Some((char::from(z), 1)),
+        [.., 0xED, b @ 0xA0..=0xAF, c, 0xED, e @ 0xB0..=0xBF, f] => {
+            Some((to_char(decode_pair(decode_three(0xED, b, c), decode_three(0xED, e, f))), 6))
+        }
+        [.., y @ 0xC0..=0xDF, z] => Some((to_char(decode_two(y, z)), 2)),
+        [.., x, y, z] => Some((to_char(decode_three(x, y, z)), 3)),
+        _ => Some((char::REPLACEMENT_CHARACTER, bytes.len())),
+    }
+}
+
+#[inline]
+fn decode_two(a: u8, b: u8) -> u32 { (u32::from(a & 0x1F) << 6) | u32::from(b & 0x3F) }
+
+#[inline]
+fn decode_three(a: u8, b: u8, c: u8) -> u32 {
+    (u32::from(a & 0x0F) << 12) | (u32::from(b & 0x3F) << 6) | u32::from(c & 0x3F)
+}
+
+#[inline]
+const fn decode_pair(high: u32, low: u32) -> u32 {
+    0x0001_0000 + (((high - 0xD800) << 10) | (low - 0xDC00))
+}
+
+#[inline]
+fn to_char(codepoint: u32) -> char {
+    char::from_u32(codepoint).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
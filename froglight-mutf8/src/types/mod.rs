@@ -1,5 +1,10 @@
 //! TODO
-#![expect(clippy::result_unit_err, reason = "WIP")]
+
+pub mod error;
+pub use error::Mutf8Error;
+
+pub mod iter;
+pub mod pattern;
 
 pub mod str;
 pub use str::MStr;
@@ -0,0 +1,177 @@
+//! TODO
+
+use core::iter::FusedIterator;
+
+use crate::types::{MStr, iter::encode_char};
+
+/// A pattern that can be searched for in a [`MStr`].
+///
+/// Implemented for [`char`]s, string slices, and `FnMut(char) -> bool`
+/// closures, similar to the patterns accepted by [`str::find`].
+pub trait Pattern {
+    /// Find the first match in `haystack`,
+    /// returning the start and end byte positions of the match.
+    fn find_in(&mut self, haystack: &MStr) -> Option<(usize, usize)>;
+
+    /// Returns the length of the match in bytes if `haystack` starts with
+    /// the pattern.
+    fn prefix_of(&mut self, haystack: &MStr) -> Option<usize>;
+}
+
+impl Pattern for char {
+    fn find_in(&mut self, haystack: &MStr) -> Option<(usize, usize)> {
+        let mut buffer = [0u8; 6];
+        find_bytes(haystack.as_bytes(), encode_char(*self, &mut buffer))
+    }
+
+    fn prefix_of(&mut self, haystack: &MStr) -> Option<usize> {
+        let mut buffer = [0u8; 6];
+        let needle = encode_char(*self, &mut buffer);
+        haystack.as_bytes().starts_with(needle).then_some(needle.len())
+    }
+}
+
+impl Pattern for &MStr {
+    fn find_in(&mut self, haystack: &MStr) -> Option<(usize, usize)> {
+        find_bytes(haystack.as_bytes(), self.as_bytes())
+    }
+
+    fn prefix_of(&mut self, haystack: &MStr) -> Option<usize> {
+        haystack.as_bytes().starts_with(self.as_bytes()).then_some(self.len())
+    }
+}
+
+impl Pattern for &str {
+    fn find_in(&mut self, haystack: &MStr) -> Option<(usize, usize)> {
+        if let Ok(mut needle) = MStr::from_utf8(self) {
+            return needle.find_in(haystack);
+        }
+
+        // Compare characters at every position, as the encodings differ.
+        let mut iter = haystack.char_indices();
+        loop {
+            let start = iter.offset();
+            if let Some(len) = self.prefix_of(iter.as_mstr()) {
+                return Some((start, start + len));
+            }
+            iter.next()?;
+        }
+    }
+
+    fn prefix_of(&mut self, haystack: &MStr) -> Option<usize> {
+        let mut chars = haystack.chars();
+        for c in self.chars() {
+            if chars.next() != Some(c) {
+                return None;
+            }
+        }
+        Some(haystack.len() - chars.as_mstr().len())
+    }
+}
+
+impl<F: FnMut(char) -> bool> Pattern for F {
+    fn find_in(&mut self, haystack: &MStr) -> Option<(usize, usize)> {
+        let mut iter = haystack.char_indices();
+        while let Some((index, c)) = iter.next() {
+            if self(c) {
+                return Some((index, iter.offset()));
+            }
+        }
+        None
+    }
+
+    fn prefix_of(&mut self, haystack: &MStr) -> Option<usize> {
+        let mut iter = haystack.char_indices();
+        iter.next().and_then(|(_, c)| self(c).then_some(iter.offset()))
+    }
+}
+
+/// Find the first occurrence of `needle` in `haystack`.
+///
+/// Matches always fall on character boundaries,
+/// as MUTF-8 lead bytes are never valid continuation bytes
+/// and low surrogates never start a valid [`MStr`].
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<(usize, usize)> {
+    if needle.is_empty() {
+        return Some((0, 0));
+    }
+
+    let start = haystack.windows(needle.len()).position(|window| window == needle)?;
+    Some((start, start + needle.len()))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An iterator over substrings of a [`MStr`], separated by a [`Pattern`].
+///
+/// Created by [`MStr::split`].
+#[derive(Debug, Clone)]
+pub struct Split<'a, P> {
+    haystack: &'a MStr,
+    pattern: P,
+    start: usize,
+    empty_match: Option<usize>,
+    finished: bool,
+}
+
+impl<'a, P: Pattern> Split<'a, P> {
+    /// Create a new [`Split`] iterator.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(haystack: &'a MStr, pattern: P) -> Self {
+        Self { haystack, pattern, start: 0, empty_match: None, finished: false }
+    }
+
+    /// Returns the remainder of the string being split.
+    ///
+    /// Returns `None` if the iterator has been exhausted.
+    #[inline]
+    #[must_use]
+    pub fn remainder(&self) -> Option<&'a MStr> {
+        (!self.finished).then(|| self.slice(self.start, self.haystack.len()))
+    }
+
+    /// Get a substring of the haystack.
+    fn slice(&self, start: usize, end: usize) -> &'a MStr {
+        // SAFETY: Patterns only ever match along character boundaries.
+        unsafe { MStr::from_mutf8_unchecked(&self.haystack.as_bytes()[start..end]) }
+    }
+}
+
+impl<'a, P: Pattern> Iterator for Split<'a, P> {
+    type Item = &'a MStr;
+
+    fn next(&mut self) -> Option<&'a MStr> {
+        if self.finished {
+            return None;
+        }
+
+        let mut position = self.start;
+        loop {
+            let remaining = self.slice(position, self.haystack.len());
+            let Some((start, end)) = self.pattern.find_in(remaining) else { break };
+            let (start, end) = (position + start, position + end);
+
+            // Empty matches can't repeat at the same position,
+            // otherwise the iterator would never advance.
+            if start == end && self.empty_match == Some(start) {
+                let mut chars = remaining.char_indices();
+                if chars.next().is_none() {
+                    break;
+                }
+                position += chars.offset();
+                continue;
+            }
+
+            let item = self.slice(self.start, start);
+            self.start = end;
+            self.empty_match = (start == end).then_some(start);
+            return Some(item);
+        }
+
+        self.finished = true;
+        Some(self.slice(self.start, self.haystack.len()))
+    }
+}
+
+impl<P: Pattern> FusedIterator for Split<'_, P> {}
@@ -5,19 +5,31 @@ use alloc::{
     borrow::{Cow, ToOwned},
     boxed::Box,
 };
-use core::{borrow::Borrow, fmt, str::from_utf8 as from_utf8_core};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Write},
+    hash::{Hash, Hasher},
+    str::{Utf8Error, from_utf8 as from_utf8_core},
+};
 
 use simdutf8::basic::from_utf8 as from_utf8_simd;
 
 #[cfg(feature = "alloc")]
 use crate::types::MString;
+use crate::types::{
+    Mutf8Error,
+    error::validate,
+    iter::{CharIndices, Chars},
+    pattern::{Pattern, Split},
+};
 
 /// MUTF-8 string slices.
 ///
 /// Equivalent to [`str`],
 /// but uses MUTF-8 instead of UTF-8.
 #[repr(transparent)]
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(PartialEq, Eq)]
 pub struct MStr([u8]);
 
 impl fmt::Debug for MStr {
@@ -31,10 +43,12 @@ impl fmt::Debug for MStr {
         fmt::Debug::fmt(&self.as_bytes(), f)
     }
 }
-#[cfg(feature = "alloc")]
 impl fmt::Display for MStr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.to_mstring().into_utf8(), f)
+        match self.as_utf8() {
+            Ok(str) => fmt::Display::fmt(str, f),
+            Err(..) => self.chars().try_for_each(|c| f.write_char(c)),
+        }
     }
 }
 
@@ -72,10 +86,17 @@ impl MStr {
     ///
     /// # Errors
     ///
-    /// Returns an error if the bytes are not valid MUTF-8.
-    pub fn from_utf8(str: &str) -> Result<&Self, ()> {
+    /// Returns an error if the string contains null or supplementary
+    /// characters, which are encoded differently in MUTF-8.
+    pub fn from_utf8(str: &str) -> Result<&Self, Mutf8Error> {
         if contains_null_or_4_byte_header(str.as_bytes()) {
-            Err(())
+            let (index, c) = str
+                .char_indices()
+                .find(|(_, c)| *c == '\0' || c.len_utf8() == 4)
+                .unwrap_or((str.len(), '\0'));
+
+            #[expect(clippy::cast_possible_truncation, reason = "Characters are at most 4 bytes")]
+            Err(Mutf8Error::new(index, Some(c.len_utf8() as u8)))
         } else {
             // SAFETY: The bytes were just checked to be valid MUTF-8.
             Ok(unsafe { Self::from_mutf8_unchecked(str.as_bytes()) })
@@ -87,17 +108,28 @@ impl MStr {
     /// # Errors
     ///
     /// Returns an error if the bytes are not valid MUTF-8.
-    pub fn from_mutf8(bytes: &[u8]) -> Result<&Self, ()> {
-        from_utf8_simd(bytes).map_or_else(|_| Err(()), Self::from_utf8)
+    pub fn from_mutf8(bytes: &[u8]) -> Result<&Self, Mutf8Error> {
+        // Most strings are also valid UTF-8, which can be checked much faster.
+        if from_utf8_simd(bytes).is_ok() && !contains_null_or_4_byte_header(bytes) {
+            // SAFETY: The bytes were just checked to be valid MUTF-8.
+            return Ok(unsafe { Self::from_mutf8_unchecked(bytes) });
+        }
+
+        validate(bytes)?;
+        // SAFETY: The bytes were just checked to be valid MUTF-8.
+        Ok(unsafe { Self::from_mutf8_unchecked(bytes) })
     }
 
     /// Creates a [`str`] from a MUTF-8 string slice.
     ///
     /// # Errors
     ///
-    /// Returns an error if the bytes are not valid UTF-8.
+    /// Returns an error if the string contains null or supplementary
+    /// characters, which are encoded differently in UTF-8.
     #[inline]
-    pub fn as_utf8(&self) -> Result<&str, ()> { from_utf8_simd(self.as_bytes()).map_err(|_| ()) }
+    pub fn as_utf8(&self) -> Result<&str, Utf8Error> {
+        from_utf8_simd(self.as_bytes()).or_else(|_| from_utf8_core(self.as_bytes()))
+    }
 
     /// Creates a new [`MStr`] from a string slice.
     ///
@@ -127,7 +159,11 @@ impl MStr {
     /// Returns `None` if the bytes are not valid MUTF-8.
     #[must_use]
     pub const fn const_from_mutf8(bytes: &[u8]) -> Option<&Self> {
-        if let Ok(str) = from_utf8_core(bytes) { Self::const_from_utf8(str) } else { None }
+        match validate(bytes) {
+            // SAFETY: The bytes were just checked to be valid MUTF-8.
+            Ok(()) => Some(unsafe { Self::from_mutf8_unchecked(bytes) }),
+            Err(..) => None,
+        }
     }
 
     /// Creates a new [`MStr`] from a string slice.
@@ -173,6 +209,56 @@ impl MStr {
     pub const unsafe fn from_mutf8_mut_unchecked(bytes: &mut [u8]) -> &mut Self {
         unsafe { &mut *(core::ptr::from_mut::<[u8]>(bytes) as *mut Self) }
     }
+
+    /// Returns an iterator over the [`char`]s of the string.
+    ///
+    /// Null characters and surrogate pairs are decoded into their
+    /// respective [`char`]s.
+    #[inline]
+    #[must_use]
+    pub const fn chars(&self) -> Chars<'_> { Chars::new(self) }
+
+    /// Returns an iterator over the [`char`]s of the string and their
+    /// positions.
+    ///
+    /// Positions are in bytes, and refer to the MUTF-8 encoding.
+    #[inline]
+    #[must_use]
+    pub const fn char_indices(&self) -> CharIndices<'_> { CharIndices::new(self) }
+
+    /// Returns the byte index of the first match of the pattern,
+    /// or `None` if it doesn't match.
+    #[inline]
+    #[must_use]
+    pub fn find<P: Pattern>(&self, mut pattern: P) -> Option<usize> {
+        pattern.find_in(self).map(|(start, _)| start)
+    }
+
+    /// Returns `true` if the pattern matches anywhere in the string.
+    #[inline]
+    #[must_use]
+    pub fn contains<P: Pattern>(&self, mut pattern: P) -> bool { pattern.find_in(self).is_some() }
+
+    /// Returns an iterator over substrings separated by the pattern.
+    #[inline]
+    #[must_use]
+    pub const fn split<P: Pattern>(&self, pattern: P) -> Split<'_, P> { Split::new(self, pattern) }
+
+    /// Returns `true` if the string starts with the pattern.
+    #[inline]
+    #[must_use]
+    pub fn starts_with<P: Pattern>(&self, mut pattern: P) -> bool {
+        pattern.prefix_of(self).is_some()
+    }
+
+    /// Returns the string with the prefix removed,
+    /// or `None` if the string doesn't start with the pattern.
+    #[must_use]
+    pub fn strip_prefix<P: Pattern>(&self, mut pattern: P) -> Option<&Self> {
+        let len = pattern.prefix_of(self)?;
+        // SAFETY: Patterns only ever match along character boundaries.
+        Some(unsafe { Self::from_mutf8_unchecked(&self.0[len..]) })
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -228,13 +314,45 @@ impl Borrow<[u8]> for MStr {
     fn borrow(&self) -> &[u8] { self.as_bytes() }
 }
 
+/// Compares strings by [`char`], the same as their UTF-8 equivalents.
+impl Ord for MStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.as_bytes() == other.as_bytes() {
+            Ordering::Equal
+        } else {
+            self.chars().cmp(other.chars())
+        }
+    }
+}
+impl PartialOrd for MStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+/// Hashes strings the same as their UTF-8 equivalents.
+impl Hash for MStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if let Ok(str) = self.as_utf8() {
+            str.hash(state);
+        } else {
+            let mut buffer = [0u8; 4];
+            for c in self.chars() {
+                state.write(c.encode_utf8(&mut buffer).as_bytes());
+            }
+            state.write_u8(0xFF);
+        }
+    }
+}
+
 impl PartialEq<str> for MStr {
     #[inline]
-    fn eq(&self, other: &str) -> bool { self.as_bytes() == other.as_bytes() }
+    fn eq(&self, other: &str) -> bool {
+        self.as_bytes() == other.as_bytes() || self.chars().eq(other.chars())
+    }
 }
 impl PartialEq<MStr> for str {
     #[inline]
-    fn eq(&self, other: &MStr) -> bool { self.as_bytes() == other.as_bytes() }
+    fn eq(&self, other: &MStr) -> bool { other == self }
 }
 
 impl PartialEq<[u8]> for MStr {
@@ -247,13 +365,13 @@ impl PartialEq<MStr> for [u8] {
 }
 
 impl<'a> TryFrom<&'a str> for &'a MStr {
-    type Error = ();
+    type Error = Mutf8Error;
 
     #[inline]
     fn try_from(value: &'a str) -> Result<Self, Self::Error> { MStr::from_utf8(value) }
 }
 impl<'a> TryFrom<&'a [u8]> for &'a MStr {
-    type Error = ();
+    type Error = Mutf8Error;
 
     #[inline]
     fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> { MStr::from_mutf8(value) }
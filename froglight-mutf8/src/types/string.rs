@@ -3,8 +3,10 @@
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
 use core::{
     borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
     convert::Infallible,
     fmt,
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign, Deref, DerefMut},
     str::FromStr,
 };
@@ -12,14 +14,17 @@ use core::{
 #[cfg(feature = "facet")]
 use froglight_facet::facet::prelude::*;
 
-use crate::prelude::MStr;
+use crate::{
+    prelude::MStr,
+    types::{Mutf8Error, error::validate, iter::encode_char},
+};
 
 /// A MUTF-8–encoded, growable string.
 ///
 /// Equivalent to [`String`],
 /// but uses MUTF-8 instead of UTF-8.
 #[repr(transparent)]
-#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
 #[cfg_attr(feature = "facet", facet(opaque, mc::with = MString::WITH))]
 pub struct MString(Vec<u8>);
@@ -77,8 +82,8 @@ impl MString {
     ///
     /// # Errors
     ///
-    /// TODO
-    pub fn from_mutf8(vec: Vec<u8>) -> Result<Self, ()> {
+    /// Returns an error if the bytes are not valid MUTF-8.
+    pub fn from_mutf8(vec: Vec<u8>) -> Result<Self, Mutf8Error> {
         match MStr::from_mutf8(&vec) {
             Ok(..) => Ok(Self(vec)),
             Err(err) => Err(err),
@@ -87,26 +92,43 @@ impl MString {
 
     /// Converts a slice of bytes to a string, including invalid characters.
     ///
-    /// During this conversion, `from_mutf8_lossy()` will replace any invalid
-    /// UTF-8 sequences with `U+FFFD REPLACEMENT CHARACTER`, which
-    /// looks like this: �
+    /// During this conversion, `from_mutf8_lossy()` will re-encode any null
+    /// or supplementary UTF-8 characters, and replace any other invalid
+    /// sequences with `U+FFFD REPLACEMENT CHARACTER`, which looks like this: �
     #[must_use]
     pub fn from_mutf8_lossy(v: &[u8]) -> Cow<'_, MStr> {
-        match simdutf8::compat::from_utf8(v) {
-            Ok(s) => Self::from_utf8(s),
-            Err(err) => {
-                // SAFETY: The index returned is within bounds.
-                let (valid, invalid) = unsafe { v.split_at_unchecked(err.valid_up_to()) };
-                let mut string = String::from_utf8_lossy(invalid).into_owned();
-
-                // SAFETY: `valid` is guaranteed to be valid UTF-8.
-                string.push_str(unsafe { str::from_utf8_unchecked(valid) });
-                // SAFETY: `string` is rotated along char byte boundaries.
-                unsafe { string.as_bytes_mut().rotate_right(valid.len()) };
-
-                Cow::Owned(Self::from_utf8_owned(string))
-            }
+        let Err(err) = MStr::from_mutf8(v) else {
+            // SAFETY: `Ok` means the input was valid MUTF-8.
+            return Cow::Borrowed(unsafe { MStr::from_mutf8_unchecked(v) });
+        };
+
+        let mut output = Self::with_capacity(v.len());
+        let mut remaining = v;
+        let mut err = Some(err);
+
+        while let Some(error) = err {
+            let (valid, invalid) = remaining.split_at(error.valid_up_to());
+            output.0.extend_from_slice(valid);
+
+            // Re-encode UTF-8 characters that are encoded differently in MUTF-8.
+            let width = invalid.first().map_or(1, |b| utf8_char_width(*b));
+            let len = match invalid.get(..width).map(core::str::from_utf8) {
+                Some(Ok(str)) if width == 1 || width == 4 => {
+                    output.push_str(str);
+                    width
+                }
+                _ => {
+                    output.push(char::REPLACEMENT_CHARACTER);
+                    error.error_len().unwrap_or(invalid.len())
+                }
+            };
+
+            remaining = &invalid[len..];
+            err = validate(remaining).err();
         }
+
+        output.0.extend_from_slice(remaining);
+        Cow::Owned(output)
     }
 
     /// Converts a [`Vec<u8>`] to a [`MString`], substituting invalid MUTF-8
@@ -218,6 +240,25 @@ impl MString {
     #[inline]
     pub fn push_mstr(&mut self, string: &MStr) { self.0.extend_from_slice(string.as_bytes()); }
 
+    /// Appends a given UTF-8 string slice onto the end of this [`MString`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` _bytes_.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) { self.push_mstr(&Self::from_utf8(string)); }
+
+    /// Appends the given [`char`] to the end of this [`MString`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` _bytes_.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        let mut buffer = [0u8; 6];
+        self.0.extend_from_slice(encode_char(c, &mut buffer));
+    }
+
     /// Extract a [`MStr`] slice containing the entire string.
     #[inline]
     #[must_use]
@@ -310,6 +351,20 @@ impl DerefMut for MString {
     fn deref_mut(&mut self) -> &mut MStr { self.as_mstr_mut() }
 }
 
+impl Ord for MString {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering { self.as_mstr().cmp(other.as_mstr()) }
+}
+impl PartialOrd for MString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Hash for MString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_mstr().hash(state); }
+}
+
 impl PartialEq<MStr> for MString {
     #[inline]
     fn eq(&self, other: &MStr) -> bool { self.as_mstr() == other }
@@ -335,6 +390,37 @@ impl AddAssign<&MStr> for MString {
     fn add_assign(&mut self, rhs: &MStr) { self.push_mstr(rhs); }
 }
 
+impl fmt::Write for MString {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl Extend<char> for MString {
+    #[inline]
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(move |c| self.push(c));
+    }
+}
+impl FromIterator<char> for MString {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+        let mut string = Self::new();
+        string.extend(iter);
+        string
+    }
+}
+
 impl<'a> Extend<&'a MStr> for MString {
     #[inline]
     fn extend<T: IntoIterator<Item = &'a MStr>>(&mut self, iter: T) {
@@ -453,13 +539,24 @@ impl FacetTemplate for MString {
         let len = decode_u32_from(reader)? as usize;
         let content = reader.read(len)?;
 
-        let value = MString::from_mutf8(content.into())
-            .map_err(|()| ReaderError::from_string("Invalid MUTF-8 String".into()))?;
+        let value = MString::from_mutf8(content.into()).map_err(|err| {
+            ReaderError::from_string(alloc::format!("Invalid MUTF-8 String: {err}"))
+        })?;
 
         item.set(value)
     }
 }
 
+/// Returns the width of a UTF-8 character from its first byte.
+const fn utf8_char_width(byte: u8) -> usize {
+    match byte {
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 1,
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 cfg_select! {
@@ -0,0 +1,134 @@
+//! TODO
+
+use core::{fmt::Write, hash::BuildHasher};
+use std::hash::RandomState;
+
+use froglight_mutf8::prelude::*;
+
+/// A string containing a null character and a supplementary character.
+const SPECIAL: &str = "a\0b🐸c";
+
+#[test]
+fn error() {
+    // Null characters and supplementary characters can't be borrowed as-is.
+    let err = MStr::from_utf8(SPECIAL).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (1, Some(1)));
+    let err = MStr::from_utf8("ab🐸").unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(4)));
+
+    // Raw null bytes are invalid.
+    let err = MStr::from_mutf8(b"ab\0").unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(1)));
+    // Overlong encodings other than null are invalid.
+    let err = MStr::from_mutf8(&[b'a', 0xC1, 0x81]).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (1, Some(1)));
+    // Lone surrogates are invalid.
+    let err = MStr::from_mutf8(&[0xED, 0xA0, 0xBD, b'a']).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (0, Some(3)));
+    let err = MStr::from_mutf8(&[b'a', 0xED, 0xB0, 0x80]).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (1, Some(1)));
+    // Truncated sequences are reported as incomplete.
+    let err = MStr::from_mutf8(&[b'a', 0xC0]).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (1, None));
+    let err = MStr::from_mutf8(&[0xED, 0xA0, 0xBD, 0xED]).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (0, None));
+
+    assert_eq!(
+        MString::from_mutf8(b"ab\0".to_vec()).unwrap_err().to_string(),
+        "invalid mutf-8 sequence of 1 bytes from index 2"
+    );
+
+    // Encoded strings round-trip through validation.
+    let mstring = MString::from(SPECIAL);
+    assert_eq!(MStr::from_mutf8(mstring.as_bytes()), Ok(mstring.as_mstr()));
+    assert_eq!(MStr::const_from_mutf8(mstring.as_bytes()), Some(mstring.as_mstr()));
+    assert!(mstring.as_utf8().is_err());
+}
+
+#[test]
+fn lossy() {
+    let mstring = MString::from(SPECIAL);
+    assert_eq!(MString::from_mutf8_lossy(mstring.as_bytes()).as_bytes(), mstring.as_bytes());
+
+    // UTF-8 characters are re-encoded, invalid bytes are replaced.
+    let lossy = MString::from_mutf8_lossy(&[b'a', 0x00, 0xF0, 0x9F, 0x90, 0xB8, 0xFF, b'c']);
+    assert_eq!(&*lossy, "a\0🐸\u{FFFD}c");
+}
+
+#[test]
+fn chars() {
+    let mstring = MString::from(SPECIAL);
+    assert_eq!(mstring.chars().collect::<String>(), SPECIAL);
+    assert_eq!(
+        mstring.chars().rev().collect::<String>(),
+        SPECIAL.chars().rev().collect::<String>()
+    );
+
+    let indices: Vec<_> = mstring.char_indices().collect();
+    assert_eq!(indices, [(0, 'a'), (1, '\0'), (3, 'b'), (4, '🐸'), (10, 'c')]);
+    let mut reversed: Vec<_> = mstring.char_indices().rev().collect();
+    reversed.reverse();
+    assert_eq!(indices, reversed);
+}
+
+#[test]
+fn search() {
+    let mstring = MString::from("key\0=🐸=value");
+
+    assert_eq!(mstring.find('='), Some(5));
+    assert_eq!(mstring.find('🐸'), Some(6));
+    assert_eq!(mstring.find("\0="), Some(3));
+    assert_eq!(mstring.find(mutf8!("value")), Some(13));
+    assert_eq!(mstring.find(char::is_uppercase), None);
+    assert!(mstring.contains('\0'));
+
+    assert!(mstring.starts_with("key\0"));
+    assert!(!mstring.starts_with('\0'));
+    assert_eq!(mstring.strip_prefix("key\0").unwrap(), "=🐸=value");
+    assert_eq!(mstring.strip_prefix(|c: char| c.is_alphabetic()).unwrap(), "ey\0=🐸=value");
+
+    let parts: Vec<String> = mstring.split('=').map(String::from).collect();
+    assert_eq!(parts, ["key\0", "🐸", "value"]);
+    let parts: Vec<String> = mstring.split("🐸").map(String::from).collect();
+    assert_eq!(parts, ["key\0=", "=value"]);
+
+    // Splitting by an empty pattern behaves like `str::split`.
+    let parts: Vec<String> = MString::from("a🐸").split("").map(String::from).collect();
+    assert_eq!(parts, "a🐸".split("").collect::<Vec<_>>());
+}
+
+#[test]
+fn compare() {
+    let mut strings = ["\u{FFFF}", "🐸", "\0", "a", ""];
+    let mut mstrings = strings.map(MString::from);
+    strings.sort_unstable();
+    mstrings.sort_unstable();
+
+    // Strings sort by `char`, not by their encoding.
+    for (string, mstring) in strings.iter().zip(&mstrings) {
+        assert_eq!(mstring.as_mstr(), *string);
+        assert_eq!(*string, mstring.as_mstr());
+    }
+
+    // Strings hash the same as their UTF-8 equivalents.
+    let state = RandomState::new();
+    for string in [SPECIAL, "plain", ""] {
+        let mstring = MString::from(string);
+        assert_eq!(state.hash_one(string), state.hash_one(&mstring));
+        assert_eq!(state.hash_one(string), state.hash_one(mstring.as_mstr()));
+    }
+}
+
+#[test]
+fn builder() {
+    let mut mstring = MString::new();
+    mstring.push('a');
+    mstring.push('\0');
+    let (b, c) = ("b🐸", 'c');
+    write!(mstring, "{b}{c}").unwrap();
+
+    assert_eq!(mstring, MString::from(SPECIAL));
+    assert_eq!(mstring.to_string(), SPECIAL);
+    assert_eq!(format!("{}", mstring.as_mstr()), SPECIAL);
+    assert_eq!(SPECIAL.chars().collect::<MString>(), mstring);
+}
@@ -1 +1,44 @@
 //! TODO
+
+facet::define_attr_grammar! {
+    ns "nbt";
+    crate_path ::froglight_nbt::facet::attr;
+
+    /// Attributes for customizing conversion to and from [`Nbt`](crate::prelude::Nbt).
+    pub enum Attr {
+        /// Store a sequence of bytes as a `ByteArray` instead of a `List`.
+        ByteArray,
+        /// Store a sequence of integers as an `IntArray` instead of a `List`.
+        IntArray,
+        /// Store a sequence of longs as a `LongArray` instead of a `List`.
+        LongArray,
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The kind of array requested by a field's [`Attr`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArrayKind {
+    /// `#[facet(nbt::byte_array)]`
+    Byte,
+    /// `#[facet(nbt::int_array)]`
+    Int,
+    /// `#[facet(nbt::long_array)]`
+    Long,
+}
+
+impl ArrayKind {
+    /// Get the [`ArrayKind`] requested by a [`Field`](facet::Field), if any.
+    pub(crate) fn from_field(field: &facet::Field) -> Option<Self> {
+        if field.has_attr(Some("nbt"), "byte_array") {
+            Some(Self::Byte)
+        } else if field.has_attr(Some("nbt"), "int_array") {
+            Some(Self::Int)
+        } else if field.has_attr(Some("nbt"), "long_array") {
+            Some(Self::Long)
+        } else {
+            None
+        }
+    }
+}
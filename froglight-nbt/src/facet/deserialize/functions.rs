@@ -6,6 +6,7 @@ use froglight_facet_iter::deserialize::DeserializeError;
 
 use crate::{
     facet::deserialize::DeserializeNbt,
+    prelude::*,
     types::indexed::{
         IndexedNbt,
         core::{Ref, SliceCore},
@@ -35,3 +36,23 @@ pub fn from_nbt_borrowed<'facet, T: Facet<'facet>>(
 ) -> Result<T, DeserializeError> {
     <T as DeserializeNbt>::from_nbt_borrowed(nbt)
 }
+
+/// Deserialize a value from a [`NbtCompound`].
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be deserialized.
+#[inline(always)]
+pub fn from_nbt_compound<T: Facet<'static>>(compound: &NbtCompound) -> Result<T, DeserializeError> {
+    <T as DeserializeNbt>::from_nbt_compound(compound)
+}
+
+/// Deserialize a value from a [`NbtValue`].
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be deserialized.
+#[inline(always)]
+pub fn from_nbt_value<T: Facet<'static>>(value: &NbtValue) -> Result<T, DeserializeError> {
+    <T as DeserializeNbt>::from_nbt_value(value)
+}
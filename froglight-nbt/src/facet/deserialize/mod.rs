@@ -11,16 +11,20 @@ use froglight_facet_iter::{
 
 use crate::{
     prelude::*,
-    types::indexed::{
-        compound::IndexedCompound,
-        core::{IndexCore, Ref, SliceCore},
-        entry::IndexedValue,
-        list::ValueList,
-        reference::ValueReference,
+    types::{
+        indexed::{
+            compound::IndexedCompound,
+            core::{IndexCore, Ref, SliceCore},
+            entry::IndexedValue,
+            list::ValueList,
+            reference::ValueReference,
+        },
+        structured::ValueRef,
     },
 };
 
 pub mod functions;
+mod structured;
 
 /// A trait for types that can be deserialized from [`Nbt`].
 pub trait DeserializeNbt<'facet>: Facet<'facet> + Sized {
@@ -42,6 +46,26 @@ pub trait DeserializeNbt<'facet>: Facet<'facet> + Sized {
     ///
     /// Returns an error if the deserialization fails.
     fn from_nbt_borrowed(nbt: &IndexedNbtSlice<'facet>) -> Result<Self, DeserializeError>;
+
+    /// Deserialize a value from a [`NbtCompound`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deserialization fails.
+    fn from_nbt_compound(compound: &NbtCompound) -> Result<Self, DeserializeError>
+    where
+        'facet: 'static,
+        'static: 'facet;
+
+    /// Deserialize a value from a [`NbtValue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deserialization fails.
+    fn from_nbt_value(value: &NbtValue) -> Result<Self, DeserializeError>
+    where
+        'facet: 'static,
+        'static: 'facet;
 }
 
 impl<'facet, T: Facet<'facet> + Sized> DeserializeNbt<'facet> for T {
@@ -62,6 +86,32 @@ impl<'facet, T: Facet<'facet> + Sized> DeserializeNbt<'facet> for T {
         let value = deserialize_borrowed(Partial::alloc_with_plan(plan)?, nbt)?;
         Ok(value.materialize::<T>()?)
     }
+
+    #[inline]
+    fn from_nbt_compound(compound: &NbtCompound) -> Result<Self, DeserializeError>
+    where
+        'facet: 'static,
+        'static: 'facet,
+    {
+        let plan = froglight_facet_iter::cache::typeplan::typeplan::<T>()?;
+        let value = deserialize_structured(
+            Partial::alloc_owned_with_plan(plan)?,
+            ValueRef::Compound(compound),
+        )?;
+        Ok(value.materialize::<T>()?)
+    }
+
+    #[inline]
+    fn from_nbt_value(value: &NbtValue) -> Result<Self, DeserializeError>
+    where
+        'facet: 'static,
+        'static: 'facet,
+    {
+        let plan = froglight_facet_iter::cache::typeplan::typeplan::<T>()?;
+        let value =
+            deserialize_structured(Partial::alloc_owned_with_plan(plan)?, ValueRef::from(value))?;
+        Ok(value.materialize::<T>()?)
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -113,6 +163,7 @@ pub fn deserialize_owned_core<'facet, const BORROW: bool>(
                     todo!()
                 }
             }
+            Item::Pending(..) => Err(ReaderError::from_str("Unexpected pending item")),
         }
     }
 }
@@ -166,6 +217,7 @@ pub fn deserialize_borrowed_core<'facet>(
                     todo!()
                 }
             }
+            Item::Pending(..) => Err(ReaderError::from_str("Unexpected pending item")),
         }
     }
 }
@@ -250,6 +302,16 @@ fn deserialize_value<'facet, const BORROWED: bool, C: IndexCore<Ref>>(
 
 // -------------------------------------------------------------------------------------------------
 
+#[inline(never)]
+fn deserialize_structured<const BORROW: bool>(
+    partial: Partial<'static, BORROW>,
+    value: ValueRef<'_>,
+) -> Result<HeapValue<'static, BORROW>, DeserializeError> {
+    structured::deserialize_value(partial, value)?.build().map_err(DeserializeError::from)
+}
+
+// -------------------------------------------------------------------------------------------------
+
 impl TreeMap for IndexedNbtSlice<'_> {
     type Key<'data> = Cow<'data, str>;
     type List<'data, 'core: 'data> = ValueList<'data, Ref, SliceCore<'core, Ref>>;
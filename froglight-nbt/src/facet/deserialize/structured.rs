@@ -0,0 +1,473 @@
+//! Conversion from [`NbtValue`]s into [`Facet`](facet::Facet) types.
+
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+
+use facet::{
+    Def, Facet, Field, KnownPointer, Partial, PointerDef, ScalarType, Shape, StructKind, Type,
+    UserType,
+};
+use froglight_facet_iter::ReaderError;
+use froglight_mutf8::prelude::{MStr, MString};
+use uuid::Uuid;
+
+use crate::{prelude::*, types::structured::ValueRef};
+
+impl ValueRef<'_> {
+    /// Get the name of the tag, for error messages.
+    const fn tag_name(self) -> &'static str {
+        match self {
+            Self::Byte(..) => "Byte",
+            Self::Short(..) => "Short",
+            Self::Int(..) => "Int",
+            Self::Long(..) => "Long",
+            Self::Float(..) => "Float",
+            Self::Double(..) => "Double",
+            Self::ByteArray(..) => "ByteArray",
+            Self::String(..) => "String",
+            Self::List(..) => "List",
+            Self::Compound(..) => "Compound",
+            Self::IntArray(..) => "IntArray",
+            Self::LongArray(..) => "LongArray",
+        }
+    }
+
+    /// Get the value as a signed integer.
+    ///
+    /// Like Minecraft, any numeric tag can be read as any numeric type.
+    #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap, reason = "Intentional")]
+    fn as_i64(self) -> Option<i64> {
+        match self {
+            Self::Byte(value) => Some(i64::from(value as i8)),
+            Self::Short(value) => Some(i64::from(value as i16)),
+            Self::Int(value) => Some(i64::from(value as i32)),
+            Self::Long(value) => Some(value as i64),
+            Self::Float(value) => Some(value as i64),
+            Self::Double(value) => Some(value as i64),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a float.
+    ///
+    /// Like Minecraft, any numeric tag can be read as any numeric type.
+    #[expect(clippy::cast_precision_loss, reason = "Intentional")]
+    fn as_f64(self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(f64::from(value)),
+            Self::Double(value) => Some(value),
+            _ => self.as_i64().map(|value| value as f64),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Deserialize a [`ValueRef`] into the current frame of a [`Partial`].
+pub(super) fn deserialize_value<'facet, const BORROW: bool>(
+    mut partial: Partial<'facet, BORROW>,
+    value: ValueRef<'_>,
+) -> Result<Partial<'facet, BORROW>, ReaderError> {
+    let shape = partial.shape();
+
+    // Types with a fixed representation.
+    if shape.id == NbtValue::SHAPE.id {
+        return Ok(partial.set(value.to_value())?);
+    } else if shape.id == NbtCompound::SHAPE.id {
+        return Ok(partial.set(expect_compound(shape, value)?.clone())?);
+    } else if shape.id == Nbt::SHAPE.id {
+        return Ok(partial.set(Nbt::new(None, expect_compound(shape, value)?.clone()))?);
+    } else if shape.id == NbtList::SHAPE.id {
+        let ValueRef::List(list) = value else { return Err(mismatch(shape, value)) };
+        return Ok(partial.set(list.clone())?);
+    } else if shape.id == MString::SHAPE.id {
+        let ValueRef::String(string) = value else { return Err(mismatch(shape, value)) };
+        return Ok(partial.set(string.to_mstring())?);
+    } else if shape.id == Uuid::SHAPE.id {
+        return match value {
+            ValueRef::IntArray(&[a, b, c, d]) => {
+                Ok(partial.set(Uuid::from_u128(join_u128([a, b, c, d])))?)
+            }
+            ValueRef::String(string) => Ok(partial.parse_from_str(&string.to_utf8())?),
+            _ => Err(mismatch(shape, value)),
+        };
+    }
+
+    match shape.def {
+        Def::Option(..) => {
+            partial = partial.begin_some()?;
+            partial = deserialize_value(partial, value)?;
+            return Ok(partial.end()?);
+        }
+        Def::Pointer(def) => return deserialize_pointer(partial, value, def),
+        _ => {}
+    }
+
+    if let Some(scalar) = ScalarType::try_from_shape(shape) {
+        return deserialize_scalar(partial, value, scalar);
+    }
+
+    // Transparent wrappers, such as `NonZero`.
+    if shape.inner.is_some()
+        && !matches!(shape.def, Def::List(..) | Def::Map(..) | Def::Set(..) | Def::Array(..))
+    {
+        partial = partial.begin_inner()?;
+        partial = deserialize_value(partial, value)?;
+        return Ok(partial.end()?);
+    }
+
+    match shape.def {
+        Def::List(..) => deserialize_list(partial, value),
+        Def::Array(def) => {
+            let elements = value.elements().ok_or_else(|| mismatch(shape, value))?;
+            if elements.len() != def.n {
+                return Err(error(format!(
+                    "Expected {} elements for `{shape}`, found {}",
+                    def.n,
+                    elements.len()
+                )));
+            }
+
+            partial = partial.init_array()?;
+            for (index, element) in elements.into_iter().enumerate() {
+                partial = partial.begin_nth_field(index)?;
+                partial = deserialize_value(partial, element)?;
+                partial = partial.end()?;
+            }
+            Ok(partial)
+        }
+        Def::Set(..) => {
+            let elements = value.elements().ok_or_else(|| mismatch(shape, value))?;
+
+            partial = partial.init_set()?;
+            for element in elements {
+                partial = partial.begin_set_item()?;
+                partial = deserialize_value(partial, element)?;
+                partial = partial.end()?;
+            }
+            Ok(partial)
+        }
+        Def::Map(..) => deserialize_map(partial, value),
+        _ => match shape.ty {
+            Type::User(UserType::Struct(ty)) => {
+                deserialize_struct(partial, ty.kind, ty.fields, value)
+            }
+            Type::User(UserType::Enum(..)) => deserialize_enum(partial, value),
+            _ => match value {
+                ValueRef::String(string) if shape.vtable.has_parse() => {
+                    Ok(partial.parse_from_str(&string.to_utf8())?)
+                }
+                _ => Err(unsupported(shape)),
+            },
+        },
+    }
+}
+
+/// Deserialize a [`ValueRef`] into a scalar.
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "Like Minecraft, numeric tags are converted"
+)]
+fn deserialize_scalar<'facet, const BORROW: bool>(
+    partial: Partial<'facet, BORROW>,
+    value: ValueRef<'_>,
+    scalar: ScalarType,
+) -> Result<Partial<'facet, BORROW>, ReaderError> {
+    let shape = partial.shape();
+
+    // Strings can be parsed into any type that supports it.
+    if let ValueRef::String(string) = value {
+        let string = string.to_utf8();
+        return match scalar {
+            ScalarType::String => Ok(partial.set(string.into_owned())?),
+            ScalarType::CowStr => Ok(partial.set(Cow::<'static, str>::Owned(string.into_owned()))?),
+            _ if shape.vtable.has_parse() => Ok(partial.parse_from_str(&string)?),
+            _ => Err(mismatch(shape, value)),
+        };
+    }
+
+    let int = || value.as_i64().ok_or_else(|| mismatch(shape, value));
+    let float = || value.as_f64().ok_or_else(|| mismatch(shape, value));
+    let wide = || match value {
+        ValueRef::IntArray(&[a, b, c, d]) => Ok(join_u128([a, b, c, d])),
+        _ => Err(mismatch(shape, value)),
+    };
+
+    #[expect(clippy::cast_possible_wrap, reason = "Stored as an unsigned value")]
+    Ok(match scalar {
+        ScalarType::Unit => partial.set(())?,
+        ScalarType::Bool => partial.set(int()? != 0)?,
+        ScalarType::U8 => partial.set(int()? as u8)?,
+        ScalarType::U16 => partial.set(int()? as u16)?,
+        ScalarType::U32 => partial.set(int()? as u32)?,
+        ScalarType::U64 => partial.set(int()? as u64)?,
+        ScalarType::USize => partial.set(int()? as usize)?,
+        ScalarType::I8 => partial.set(int()? as i8)?,
+        ScalarType::I16 => partial.set(int()? as i16)?,
+        ScalarType::I32 => partial.set(int()? as i32)?,
+        ScalarType::I64 => partial.set(int()?)?,
+        ScalarType::ISize => partial.set(int()? as isize)?,
+        ScalarType::F32 => partial.set(float()? as f32)?,
+        ScalarType::F64 => partial.set(float()?)?,
+        ScalarType::U128 => partial.set(wide()?)?,
+        ScalarType::I128 => partial.set(wide()? as i128)?,
+        _ => return Err(mismatch(shape, value)),
+    })
+}
+
+/// Deserialize a [`ValueRef`] into a pointer.
+fn deserialize_pointer<'facet, const BORROW: bool>(
+    mut partial: Partial<'facet, BORROW>,
+    value: ValueRef<'_>,
+    def: PointerDef,
+) -> Result<Partial<'facet, BORROW>, ReaderError> {
+    match def.known {
+        Some(KnownPointer::SharedReference | KnownPointer::ExclusiveReference) => {
+            Err(unsupported(partial.shape()))
+        }
+        Some(KnownPointer::Cow) => {
+            if let ValueRef::String(string) = value
+                && def.pointee().is_some_and(|pointee| pointee.id == str::SHAPE.id)
+            {
+                return Ok(partial.set(Cow::<'static, str>::Owned(string.to_utf8().into_owned()))?);
+            }
+
+            partial = partial.begin_inner()?;
+            partial = deserialize_value(partial, value)?;
+            Ok(partial.end()?)
+        }
+        _ => {
+            partial = partial.begin_smart_ptr()?;
+            if matches!(partial.shape().def, Def::Slice(..)) {
+                partial = deserialize_list(partial, value)?;
+            } else {
+                partial = deserialize_value(partial, value)?;
+            }
+            Ok(partial.end()?)
+        }
+    }
+}
+
+/// Deserialize a [`ValueRef`] into a map.
+fn deserialize_map<'facet, const BORROW: bool>(
+    mut partial: Partial<'facet, BORROW>,
+    value: ValueRef<'_>,
+) -> Result<Partial<'facet, BORROW>, ReaderError> {
+    let compound = expect_compound(partial.shape(), value)?;
+
+    partial = partial.init_map()?;
+    for (key, value) in compound.iter() {
+        partial = partial.begin_key()?;
+        partial = deserialize_value(partial, ValueRef::String(key))?;
+        partial = partial.end()?;
+        partial = partial.begin_value()?;
+        partial = deserialize_value(partial, value.into())?;
+        partial = partial.end()?;
+    }
+    Ok(partial)
+}
+
+/// Deserialize a [`ValueRef`] into a list.
+fn deserialize_list<'facet, const BORROW: bool>(
+    mut partial: Partial<'facet, BORROW>,
+    value: ValueRef<'_>,
+) -> Result<Partial<'facet, BORROW>, ReaderError> {
+    let shape = partial.shape();
+
+    macro_rules! match_array {
+        ($($variant:ident => $ty:ty $(as $cast:ty)?),*) => {
+            match value {
+                $(
+                    ValueRef::$variant(array) if shape.id == <Vec<$ty>>::SHAPE.id => {
+                        #[allow(clippy::cast_possible_wrap, reason = "Stored as an unsigned value")]
+                        let array: Vec<$ty> = array.iter().map(|v| *v $(as $cast)?).collect();
+                        return Ok(partial.set(array)?);
+                    }
+                )*
+                _ => {}
+            }
+        };
+    }
+
+    // Copy arrays directly instead of element-by-element.
+    match_array! {
+        ByteArray => u8,
+        ByteArray => i8 as i8,
+        IntArray => u32,
+        IntArray => i32 as i32,
+        LongArray => u64,
+        LongArray => i64 as i64
+    }
+
+    let elements = value.elements().ok_or_else(|| mismatch(shape, value))?;
+
+    partial = partial.init_list_with_capacity(elements.len())?;
+    for element in elements {
+        partial = partial.begin_list_item()?;
+        partial = deserialize_value(partial, element)?;
+        partial = partial.end()?;
+    }
+    Ok(partial)
+}
+
+/// Deserialize a [`ValueRef`] into a struct or enum variant.
+fn deserialize_struct<'facet, const BORROW: bool>(
+    mut partial: Partial<'facet, BORROW>,
+    kind: StructKind,
+    fields: &'static [Field],
+    value: ValueRef<'_>,
+) -> Result<Partial<'facet, BORROW>, ReaderError> {
+    match kind {
+        StructKind::Unit => Ok(partial),
+        StructKind::Struct => {
+            let compound = expect_compound(partial.shape(), value)?;
+            deserialize_fields(partial, fields, compound)
+        }
+        // Newtypes are stored as their inner value.
+        StructKind::TupleStruct | StructKind::Tuple if fields.len() == 1 => {
+            partial = partial.begin_nth_field(0)?;
+            partial = deserialize_value(partial, value)?;
+            Ok(partial.end()?)
+        }
+        StructKind::TupleStruct | StructKind::Tuple => {
+            let shape = partial.shape();
+            let elements = value.elements().ok_or_else(|| mismatch(shape, value))?;
+            if elements.len() != fields.len() {
+                return Err(error(format!(
+                    "Expected {} elements for `{shape}`, found {}",
+                    fields.len(),
+                    elements.len()
+                )));
+            }
+
+            for (index, element) in elements.into_iter().enumerate() {
+                partial = partial.begin_nth_field(index)?;
+                partial = deserialize_value(partial, element)?;
+                partial = partial.end()?;
+            }
+            Ok(partial)
+        }
+    }
+}
+
+/// Deserialize the fields of a struct from a [`NbtCompound`].
+///
+/// Missing fields are left for [`Partial`] to fill with their defaults.
+fn deserialize_fields<'facet, const BORROW: bool>(
+    mut partial: Partial<'facet, BORROW>,
+    fields: &'static [Field],
+    compound: &NbtCompound,
+) -> Result<Partial<'facet, BORROW>, ReaderError> {
+    /// Returns `true` if the field is read from the given key.
+    fn is_key(field: &Field, key: &MStr) -> bool {
+        !field.is_flattened()
+            && (field.effective_name() == key || field.alias.is_some_and(|alias| alias == key))
+    }
+
+    for (index, field) in fields.iter().enumerate() {
+        if field.should_skip_deserializing() {
+            continue;
+        }
+
+        if field.is_flattened() {
+            // Flattened fields receive every entry not used by another field.
+            let remaining: NbtCompound = compound
+                .iter()
+                .filter(|(key, _)| !fields.iter().any(|field| is_key(field, key)))
+                .map(|(key, value)| (key.to_mstring(), value.clone()))
+                .collect();
+
+            partial = partial.begin_nth_field(index)?;
+            partial = deserialize_value(partial, ValueRef::Compound(&remaining))?;
+            partial = partial.end()?;
+        } else if let Some(value) =
+            compound.iter().find_map(|(key, value)| is_key(field, key).then_some(value))
+        {
+            partial = partial.begin_nth_field(index)?;
+            partial = deserialize_value(partial, value.into())?;
+            partial = partial.end()?;
+        }
+    }
+
+    Ok(partial)
+}
+
+/// Deserialize a [`ValueRef`] into an enum.
+fn deserialize_enum<'facet, const BORROW: bool>(
+    mut partial: Partial<'facet, BORROW>,
+    value: ValueRef<'_>,
+) -> Result<Partial<'facet, BORROW>, ReaderError> {
+    let shape = partial.shape();
+    if shape.is_untagged() {
+        return Err(unsupported(shape));
+    }
+
+    let data = if let Some(tag) = shape.get_tag_attr() {
+        // Internally or adjacently tagged, `{tag: "Name", ..}`
+        let compound = expect_compound(shape, value)?;
+        let Some(NbtValue::String(name)) = compound.get(tag) else {
+            return Err(error(format!("Missing tag `{tag}` for `{shape}`")));
+        };
+
+        partial = partial.select_variant_named(&name.to_utf8())?;
+        match shape.get_content_attr() {
+            Some(content) => compound.get(content).map(ValueRef::from),
+            None => Some(value),
+        }
+    } else {
+        // Externally tagged, `"Name"` or `{Name: data}`
+        match value {
+            ValueRef::String(name) => {
+                partial = partial.select_variant_named(&name.to_utf8())?;
+                None
+            }
+            ValueRef::Compound(compound) if compound.len() == 1 => {
+                let (name, data) = compound.get_index(0).ok_or_else(|| mismatch(shape, value))?;
+                partial = partial.select_variant_named(&name.to_utf8())?;
+                Some(ValueRef::from(data))
+            }
+            _ => return Err(mismatch(shape, value)),
+        }
+    };
+
+    let variant = partial.selected_variant().ok_or_else(|| mismatch(shape, value))?;
+    match (variant.data.kind, data) {
+        (StructKind::Unit, _) => Ok(partial),
+        (kind, Some(data)) => deserialize_struct(partial, kind, variant.data.fields, data),
+        (_, None) => Err(error(format!(
+            "Missing data for variant `{}` of `{shape}`",
+            variant.effective_name()
+        ))),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Join four integers into a `u128`, most significant first.
+///
+/// The inverse of `split_u128` used when serializing.
+const fn join_u128([a, b, c, d]: [u32; 4]) -> u128 {
+    ((a as u128) << 96) | ((b as u128) << 64) | ((c as u128) << 32) | (d as u128)
+}
+
+/// Get the [`NbtCompound`] from a [`ValueRef`], or return an error.
+fn expect_compound<'a>(shape: &Shape, value: ValueRef<'a>) -> Result<&'a NbtCompound, ReaderError> {
+    if let ValueRef::Compound(compound) = value {
+        Ok(compound)
+    } else {
+        Err(mismatch(shape, value))
+    }
+}
+
+/// Create an error for a value that does not match the expected type.
+fn mismatch(shape: &Shape, value: ValueRef<'_>) -> ReaderError {
+    error(format!("Cannot deserialize `{shape}` from a {} tag", value.tag_name()))
+}
+
+/// Create an error for a type that cannot be deserialized.
+fn unsupported(shape: &Shape) -> ReaderError {
+    error(format!("Deserializing `{shape}` from NBT is not supported"))
+}
+
+/// Create a [`ReaderError`] from a message.
+fn error(message: impl Into<String>) -> ReaderError { ReaderError::from_string(message.into()) }
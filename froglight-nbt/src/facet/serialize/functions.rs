@@ -4,12 +4,25 @@
 use facet::Facet;
 use froglight_facet_iter::serialize::SerializeError;
 
-use crate::prelude::*;
+use crate::{facet::serialize::SerializeNbt, prelude::*};
 
-/// TODO
+/// Serialize a value as an unnamed [`Nbt`] structure.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be serialized,
+/// or if it does not serialize as a compound.
+#[inline(always)]
+pub fn to_nbt<'facet, T: Facet<'facet>>(value: &T) -> Result<Nbt, SerializeError> {
+    <T as SerializeNbt>::to_nbt(value)
+}
+
+/// Serialize a value as a [`NbtValue`].
 ///
 /// # Errors
 ///
 /// Returns an error if the value cannot be serialized.
 #[inline(always)]
-pub fn to_nbt<'facet, T: Facet<'facet>>(_value: &T) -> Result<Nbt, SerializeError> { todo!() }
+pub fn to_nbt_value<'facet, T: Facet<'facet>>(value: &T) -> Result<NbtValue, SerializeError> {
+    <T as SerializeNbt>::to_nbt_value(value)
+}
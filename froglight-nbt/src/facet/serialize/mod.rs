@@ -1,42 +1,56 @@
 //! TODO
 
-use facet::Peek;
-use froglight_facet_iter::{
-    WriterError,
-    serialize::{Item, SerializeError, Serializer},
-};
+use facet::{Facet, Peek};
+use froglight_facet_iter::{WriterError, serialize::SerializeError};
 
 use crate::prelude::*;
 
 pub mod functions;
+mod structured;
 
 /// A trait for types that can be serialized as [`Nbt`].
-pub trait SerializeNbt<'facet> {}
+pub trait SerializeNbt<'facet>: Facet<'facet> {
+    /// Serialize this value as an unnamed [`Nbt`] structure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be serialized,
+    /// or if it does not serialize as a compound.
+    fn to_nbt(&self) -> Result<Nbt, SerializeError>;
+
+    /// Serialize this value as a [`NbtValue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be serialized.
+    fn to_nbt_value(&self) -> Result<NbtValue, SerializeError>;
+}
 
-// -------------------------------------------------------------------------------------------------
+impl<'facet, T: Facet<'facet>> SerializeNbt<'facet> for T {
+    #[inline]
+    fn to_nbt(&self) -> Result<Nbt, SerializeError> {
+        match serialize(Peek::new(self))? {
+            NbtValue::Compound(compound) => Ok(Nbt::new(None, compound)),
+            _ => Err(SerializeError::from(WriterError::from_string(alloc::format!(
+                "Expected `{}` to serialize as a compound",
+                T::SHAPE
+            )))),
+        }
+    }
 
-#[inline(never)]
-fn serialize(peek: Peek<'_, '_>, nbt: &mut Nbt) -> Result<(), SerializeError> {
-    // Create and complete the serializer.
-    let mut core = serialize_core(nbt);
-    Serializer::new(peek, false, &mut core, Some("nbt")).complete()
+    #[inline]
+    fn to_nbt_value(&self) -> Result<NbtValue, SerializeError> { serialize(Peek::new(self)) }
 }
 
 // -------------------------------------------------------------------------------------------------
 
-/// The core logic behind [`serialize`], separated out for readability.
-#[doc(hidden)]
-#[inline(always)]
-#[allow(clippy::inline_always, reason = "Performance")]
-pub fn serialize_core<'mem, 'facet>(
-    _nbt: &mut Nbt,
-) -> impl FnMut(Item<'mem, 'facet>) -> Result<(), WriterError> {
-    |item: Item<'mem, 'facet>| -> Result<(), WriterError> {
-        let _item = match item {
-            Item::Item(item) => item,
-            Item::Hint(_hint, _peek) => todo!(),
-        };
-
-        todo!()
+#[inline(never)]
+fn serialize(peek: Peek<'_, '_>) -> Result<NbtValue, SerializeError> {
+    match structured::serialize_value(peek, None)? {
+        Some(value) => Ok(value),
+        None => Err(SerializeError::from(WriterError::from_string(alloc::format!(
+            "Expected `{}` to serialize as a value",
+            peek.shape()
+        )))),
     }
 }
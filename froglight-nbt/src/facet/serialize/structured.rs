@@ -0,0 +1,338 @@
+//! Conversion from [`Facet`](facet::Facet) types into [`NbtValue`]s.
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+
+use facet::{
+    Def, Field, FieldIter, HasFields, Peek, PeekEnum, ScalarType, StructKind, Type, UserType,
+};
+use froglight_facet_iter::WriterError;
+use froglight_mutf8::prelude::MString;
+use uuid::Uuid;
+
+use crate::{
+    facet::attr::ArrayKind,
+    types::structured::{Nbt, NbtCompound, NbtList, NbtValue},
+};
+
+/// Serialize a value, returning `None` if it should be omitted.
+pub(super) fn serialize_value(
+    peek: Peek<'_, '_>,
+    field: Option<&Field>,
+) -> Result<Option<NbtValue>, WriterError> {
+    if let Some(value) = serialize_known(peek) {
+        return Ok(Some(value));
+    }
+
+    match peek.shape().def {
+        // Empty options are omitted entirely.
+        Def::Option(..) => match peek.into_option()?.value() {
+            Some(value) => serialize_value(value, field),
+            None => Ok(None),
+        },
+        Def::Pointer(..) => match peek.into_pointer()?.borrow_inner() {
+            Some(value) => serialize_value(value, field),
+            None => Err(unsupported(peek)),
+        },
+
+        Def::List(..) | Def::Array(..) | Def::Slice(..) => {
+            let values = serialize_elements(peek.into_list_like()?.iter())?;
+            finish_sequence(values, field).map(Some)
+        }
+        Def::Set(..) => {
+            let values = serialize_elements(peek.into_set()?.iter())?;
+            finish_sequence(values, field).map(Some)
+        }
+        Def::Map(..) => {
+            let mut compound = NbtCompound::new();
+            for (key, value) in peek.into_map()?.iter() {
+                if let Some(value) = serialize_value(value, None)? {
+                    compound.insert(serialize_key(key)?, value);
+                }
+            }
+            Ok(Some(NbtValue::Compound(compound)))
+        }
+
+        _ => {
+            if let Some(scalar) = peek.scalar_type() {
+                return serialize_scalar(peek, scalar);
+            }
+
+            match peek.shape().ty {
+                Type::User(UserType::Struct(ty)) => {
+                    serialize_struct(ty.kind, peek.into_struct()?.fields()).map(Some)
+                }
+                Type::User(UserType::Enum(..)) => serialize_enum(peek).map(Some),
+                _ if peek.shape().vtable.has_display() => Ok(Some(NbtValue::String(display(peek)))),
+                _ => Err(unsupported(peek)),
+            }
+        }
+    }
+}
+
+/// Serialize types that have a fixed representation.
+fn serialize_known(peek: Peek<'_, '_>) -> Option<NbtValue> {
+    if let Ok(value) = peek.get::<NbtValue>() {
+        Some(value.clone())
+    } else if let Ok(compound) = peek.get::<NbtCompound>() {
+        Some(NbtValue::Compound(compound.clone()))
+    } else if let Ok(list) = peek.get::<NbtList>() {
+        Some(NbtValue::List(list.clone()))
+    } else if let Ok(nbt) = peek.get::<Nbt>() {
+        Some(NbtValue::Compound(nbt.compound().clone()))
+    } else if let Ok(string) = peek.get::<MString>() {
+        Some(NbtValue::String(string.clone()))
+    } else if let Ok(uuid) = peek.get::<Uuid>() {
+        Some(NbtValue::IntArray(split_u128(uuid.as_u128()).to_vec()))
+    } else {
+        None
+    }
+}
+
+#[expect(clippy::cast_sign_loss, reason = "NBT stores signed values as unsigned")]
+fn serialize_scalar(
+    peek: Peek<'_, '_>,
+    scalar: ScalarType,
+) -> Result<Option<NbtValue>, WriterError> {
+    let value = match scalar {
+        ScalarType::Unit => return Ok(None),
+        ScalarType::Bool => NbtValue::Byte(u8::from(*peek.get::<bool>()?)),
+        ScalarType::Char => {
+            let mut string = MString::with_capacity(6);
+            string.push(*peek.get::<char>()?);
+            NbtValue::String(string)
+        }
+        ScalarType::Str | ScalarType::String | ScalarType::CowStr => {
+            let string = peek.as_str().ok_or_else(|| unsupported(peek))?;
+            NbtValue::String(MString::from_utf8(string).into_owned())
+        }
+
+        ScalarType::U8 => NbtValue::Byte(*peek.get::<u8>()?),
+        ScalarType::I8 => NbtValue::Byte(*peek.get::<i8>()? as u8),
+        ScalarType::U16 => NbtValue::Short(*peek.get::<u16>()?),
+        ScalarType::I16 => NbtValue::Short(*peek.get::<i16>()? as u16),
+        ScalarType::U32 => NbtValue::Int(*peek.get::<u32>()?),
+        ScalarType::I32 => NbtValue::Int(*peek.get::<i32>()? as u32),
+        ScalarType::U64 => NbtValue::Long(*peek.get::<u64>()?),
+        ScalarType::I64 => NbtValue::Long(*peek.get::<i64>()? as u64),
+        ScalarType::USize => NbtValue::Long(*peek.get::<usize>()? as u64),
+        ScalarType::ISize => NbtValue::Long(*peek.get::<isize>()? as u64),
+        ScalarType::F32 => NbtValue::Float(*peek.get::<f32>()?),
+        ScalarType::F64 => NbtValue::Double(*peek.get::<f64>()?),
+
+        // 128-bit integers are stored the same way as UUIDs.
+        ScalarType::U128 => NbtValue::IntArray(split_u128(*peek.get::<u128>()?).to_vec()),
+        ScalarType::I128 => NbtValue::IntArray(split_u128(*peek.get::<i128>()? as u128).to_vec()),
+
+        _ if peek.shape().vtable.has_display() => NbtValue::String(display(peek)),
+        _ => return Err(unsupported(peek)),
+    };
+
+    Ok(Some(value))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Serialize a struct or the data of an enum variant.
+fn serialize_struct(kind: StructKind, fields: FieldIter<'_, '_>) -> Result<NbtValue, WriterError> {
+    match kind {
+        StructKind::Unit => Ok(NbtValue::Compound(NbtCompound::new())),
+        StructKind::Struct => {
+            let mut compound = NbtCompound::new();
+            serialize_fields(fields, &mut compound)?;
+            Ok(NbtValue::Compound(compound))
+        }
+        // Newtypes are serialized as their inner value.
+        StructKind::TupleStruct | StructKind::Tuple if fields.len() == 1 => {
+            let mut fields = fields;
+            let (field, value) = fields.next().ok_or_else(|| error("Missing newtype field"))?;
+            serialize_value(value, Some(&field))?
+                .ok_or_else(|| error("Newtypes cannot contain empty values"))
+        }
+        StructKind::TupleStruct | StructKind::Tuple => {
+            let values = serialize_elements(fields.map(|(_, value)| value))?;
+            list_from_values(values).map(NbtValue::List)
+        }
+    }
+}
+
+/// Serialize named fields into a [`NbtCompound`].
+///
+/// Flattened fields are merged into the compound.
+fn serialize_fields(
+    fields: FieldIter<'_, '_>,
+    compound: &mut NbtCompound,
+) -> Result<(), WriterError> {
+    for (field, value) in fields {
+        // SAFETY: `value` points to the data of `field`.
+        if unsafe { field.should_skip_serializing(value.data()) } {
+            continue;
+        }
+
+        match serialize_value(value, Some(&field))? {
+            Some(NbtValue::Compound(inner)) if field.is_flattened() => {
+                for (key, value) in inner {
+                    compound.insert(key, value);
+                }
+            }
+            Some(_) if field.is_flattened() => {
+                return Err(error(format!(
+                    "Flattened field `{}` must serialize as a compound",
+                    field.name
+                )));
+            }
+            Some(value) => {
+                compound.insert(MString::from_utf8(field.effective_name()).into_owned(), value);
+            }
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Serialize an enum using its tagging attributes.
+///
+/// Enums are externally tagged by default,
+/// with unit variants serialized as just their name.
+fn serialize_enum(peek: Peek<'_, '_>) -> Result<NbtValue, WriterError> {
+    let shape = peek.shape();
+    let peek = peek.into_enum()?;
+    let variant = peek.active_variant().map_err(WriterError::other)?;
+    let name = MString::from_utf8(variant.effective_name()).into_owned();
+
+    let data = serialize_variant(variant.data.kind, peek)?;
+    if shape.is_untagged() {
+        return Ok(data.unwrap_or(NbtValue::String(name)));
+    }
+
+    match (shape.get_tag_attr(), shape.get_content_attr()) {
+        // Adjacently tagged, `{ tag: "Name", content: data }`
+        (Some(tag), Some(content)) => {
+            let mut compound = NbtCompound::new();
+            compound.insert(MString::from_utf8(tag).into_owned(), name);
+            if let Some(data) = data {
+                compound.insert(MString::from_utf8(content).into_owned(), data);
+            }
+            Ok(NbtValue::Compound(compound))
+        }
+        // Internally tagged, `{ tag: "Name", ..data }`
+        (Some(tag), None) => {
+            let mut compound = NbtCompound::new();
+            compound.insert(MString::from_utf8(tag).into_owned(), name);
+            match data {
+                Some(NbtValue::Compound(data)) => {
+                    for (key, value) in data {
+                        compound.insert(key, value);
+                    }
+                }
+                Some(_) => {
+                    return Err(error(format!(
+                        "Internally tagged variant `{}::{}` must serialize as a compound",
+                        shape, variant.name
+                    )));
+                }
+                None => {}
+            }
+            Ok(NbtValue::Compound(compound))
+        }
+        // Externally tagged, `"Name"` or `{ "Name": data }`
+        _ => match data {
+            Some(data) => Ok(NbtValue::Compound(core::iter::once((name, data)).collect())),
+            None => Ok(NbtValue::String(name)),
+        },
+    }
+}
+
+/// Serialize the data of the active variant, returning `None` for unit
+/// variants.
+fn serialize_variant(
+    kind: StructKind,
+    peek: PeekEnum<'_, '_>,
+) -> Result<Option<NbtValue>, WriterError> {
+    if kind == StructKind::Unit {
+        Ok(None)
+    } else {
+        serialize_struct(kind, peek.fields()).map(Some)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Serialize map keys as strings.
+///
+/// Unit variants use their name, other types use their `Display`
+/// implementation.
+fn serialize_key(peek: Peek<'_, '_>) -> Result<MString, WriterError> {
+    if let Ok(key) = peek.get::<MString>() {
+        Ok(key.clone())
+    } else if let Some(key) = peek.as_str() {
+        Ok(MString::from_utf8(key).into_owned())
+    } else if let Ok(key) = peek.into_enum()
+        && let Ok(variant) = key.active_variant()
+        && variant.data.kind == StructKind::Unit
+    {
+        Ok(MString::from_utf8(variant.effective_name()).into_owned())
+    } else if peek.shape().vtable.has_display() {
+        Ok(display(peek))
+    } else {
+        Err(error(format!("Cannot use `{}` as a compound key", peek.shape())))
+    }
+}
+
+/// Serialize the elements of a sequence.
+fn serialize_elements<'mem, 'facet>(
+    iter: impl Iterator<Item = Peek<'mem, 'facet>>,
+) -> Result<Vec<NbtValue>, WriterError> {
+    iter.map(|peek| {
+        serialize_value(peek, None)?.ok_or_else(|| error("Lists cannot contain empty values"))
+    })
+    .collect()
+}
+
+/// Build a [`NbtList`], or an array if the field requested one.
+fn finish_sequence(values: Vec<NbtValue>, field: Option<&Field>) -> Result<NbtValue, WriterError> {
+    match field.and_then(ArrayKind::from_field) {
+        Some(ArrayKind::Byte) => collect_array(values, NbtValue::as_byte).map(NbtValue::ByteArray),
+        Some(ArrayKind::Int) => collect_array(values, NbtValue::as_int).map(NbtValue::IntArray),
+        Some(ArrayKind::Long) => collect_array(values, NbtValue::as_long).map(NbtValue::LongArray),
+        None => list_from_values(values).map(NbtValue::List),
+    }
+}
+
+/// Collect values into an array, failing if any are the wrong type.
+fn collect_array<T: Copy>(
+    values: Vec<NbtValue>,
+    f: fn(&NbtValue) -> Option<&T>,
+) -> Result<Vec<T>, WriterError> {
+    values
+        .iter()
+        .map(|value| f(value).copied().ok_or_else(|| error("Arrays must contain a single type")))
+        .collect()
+}
+
+/// Build a [`NbtList`] from values of the same type.
+fn list_from_values(values: Vec<NbtValue>) -> Result<NbtList, WriterError> {
+    NbtList::try_from(values).map_err(|_| error("Lists must contain a single type"))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Split a 128-bit value into four integers, most significant first.
+#[expect(clippy::cast_possible_truncation, reason = "Intentional")]
+pub(crate) const fn split_u128(value: u128) -> [u32; 4] {
+    [(value >> 96) as u32, (value >> 64) as u32, (value >> 32) as u32, value as u32]
+}
+
+/// Format a value into a [`MString`] using its `Display` implementation.
+fn display(peek: Peek<'_, '_>) -> MString {
+    let mut string = MString::new();
+    let _ = write!(string, "{peek}");
+    string
+}
+
+fn unsupported(peek: Peek<'_, '_>) -> WriterError {
+    error(format!("Cannot serialize `{}` as NBT", peek.shape()))
+}
+
+fn error(message: impl Into<String>) -> WriterError { WriterError::from_string(message.into()) }
@@ -14,6 +14,7 @@ use crate::types::indexed::core::{IndexCore, IndexedNbtSlice};
 ///
 /// ```rust
 /// use facet::*;
+/// use froglight_facet::facet::prelude::*;
 /// use froglight_nbt::facet::with::NbtTemplate;
 ///
 /// #[derive(Facet)]
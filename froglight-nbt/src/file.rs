@@ -0,0 +1,338 @@
+//! Reading and writing compressed NBT files.
+//!
+//! Supports files such as `level.dat`, `playerdata/*.dat`,
+//! `.nbt` structure templates, and `servers.dat`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use froglight_nbt::{file, prelude::*};
+//!
+//! // Read a file, detecting its compression.
+//! let (mut nbt, compression) = file::read_nbt_file("world/level.dat")?;
+//!
+//! // Modify it and write it back using the same compression.
+//! nbt.compound_mut().insert("Edited", NbtValue::Byte(1));
+//! file::write_nbt_file("world/level.dat", &nbt, compression)?;
+//! # Ok::<(), file::NbtFileError>(())
+//! ```
+
+use alloc::{borrow::Cow, vec::Vec};
+use core::{error::Error, fmt};
+use std::{
+    io::{self, Read, Write},
+    path::Path,
+};
+
+use flate2::{
+    Compression,
+    read::{GzDecoder, ZlibDecoder},
+    write::{GzEncoder, ZlibEncoder},
+};
+
+use crate::{
+    prelude::*,
+    types::indexed::core::{IndexCore, Ref},
+};
+
+/// The compression used by an NBT file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NbtCompression {
+    /// Uncompressed NBT, as used by `servers.dat`.
+    #[default]
+    None,
+    /// Gzip compressed NBT, as used by `level.dat`, player data,
+    /// and structure templates.
+    Gzip,
+    /// Zlib compressed NBT, as used by region file chunks.
+    Zlib,
+}
+
+impl NbtCompression {
+    /// Detect the compression of the given file contents.
+    ///
+    /// Data that is neither gzip nor zlib compressed is assumed to be raw NBT.
+    #[must_use]
+    pub const fn detect(data: &[u8]) -> Self {
+        match data {
+            [0x1f, 0x8b, ..] => Self::Gzip,
+            // A zlib header using deflate, with a valid header checksum.
+            [cmf, flg, ..] if *cmf & 0x0f == 8 && u16::from_be_bytes([*cmf, *flg]) % 31 == 0 => {
+                Self::Zlib
+            }
+            _ => Self::None,
+        }
+    }
+
+    /// Get the compression Minecraft uses for a file at the given path.
+    ///
+    /// `servers.dat` is uncompressed, while other `.dat` and `.nbt`
+    /// files are gzip compressed.
+    #[must_use]
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        if path.file_name().is_some_and(|name| name == "servers.dat") {
+            Self::None
+        } else if path.extension().is_some_and(|ext| ext == "dat" || ext == "nbt") {
+            Self::Gzip
+        } else {
+            Self::None
+        }
+    }
+
+    /// Decompress the given data,
+    /// up to [`DEFAULT_LIMIT`] bytes.
+    ///
+    /// Uncompressed data is returned as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not compressed correctly,
+    /// or if it decompresses to more than [`DEFAULT_LIMIT`] bytes.
+    #[inline]
+    pub fn decompress(self, data: &[u8]) -> Result<Cow<'_, [u8]>, NbtFileError> {
+        self.decompress_with_limit(data, DEFAULT_LIMIT)
+    }
+
+    /// Decompress the given data, up to `limit` bytes.
+    ///
+    /// Uncompressed data is returned as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not compressed correctly,
+    /// or if it decompresses to more than `limit` bytes.
+    pub fn decompress_with_limit(
+        self,
+        data: &[u8],
+        limit: u64,
+    ) -> Result<Cow<'_, [u8]>, NbtFileError> {
+        // Read one byte past the limit to detect data that is too large.
+        let mut buffer = Vec::new();
+        let read = match self {
+            Self::None => return Ok(Cow::Borrowed(data)),
+            Self::Gzip => {
+                GzDecoder::new(data).take(limit.saturating_add(1)).read_to_end(&mut buffer)?
+            }
+            Self::Zlib => {
+                ZlibDecoder::new(data).take(limit.saturating_add(1)).read_to_end(&mut buffer)?
+            }
+        };
+
+        if read as u64 > limit {
+            return Err(NbtFileError::TooLarge(limit));
+        }
+        Ok(Cow::Owned(buffer))
+    }
+
+    /// Compress the given data.
+    ///
+    /// Uncompressed data is returned as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data could not be compressed.
+    pub fn compress(self, data: &[u8]) -> io::Result<Cow<'_, [u8]>> {
+        match self {
+            Self::None => Ok(Cow::Borrowed(data)),
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish().map(Cow::Owned)
+            }
+            Self::Zlib => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish().map(Cow::Owned)
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The default limit on the size of decompressed NBT data, in bytes.
+///
+/// Use [`NbtCompression::decompress_with_limit`], [`read_nbt_with_limit`],
+/// or [`read_indexed_with_limit`] to use a different limit.
+pub const DEFAULT_LIMIT: u64 = 64 * 1024 * 1024;
+
+/// An error that can occur while reading an NBT file.
+#[derive(Debug)]
+pub enum NbtFileError {
+    /// The file could not be read or decompressed.
+    Io(io::Error),
+    /// The data decompressed to more than the given number of bytes.
+    TooLarge(u64),
+    /// The data is not valid NBT.
+    InvalidNbt,
+}
+
+impl Error for NbtFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::TooLarge(_) | Self::InvalidNbt => None,
+        }
+    }
+}
+impl fmt::Display for NbtFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read NBT file: {err}"),
+            Self::TooLarge(limit) => write!(f, "NBT data is larger than {limit} bytes"),
+            Self::InvalidNbt => f.write_str("invalid NBT data"),
+        }
+    }
+}
+
+impl From<io::Error> for NbtFileError {
+    fn from(err: io::Error) -> Self { Self::Io(err) }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Read a named [`Nbt`] structure from the given file contents,
+/// detecting its compression.
+///
+/// # Errors
+///
+/// Returns an error if the data could not be decompressed,
+/// if it decompresses to more than [`DEFAULT_LIMIT`] bytes,
+/// or if it is not valid NBT data.
+#[inline]
+pub fn read_nbt(data: &[u8]) -> Result<(Nbt, NbtCompression), NbtFileError> {
+    read_nbt_with_limit(data, DEFAULT_LIMIT)
+}
+
+/// Read a named [`Nbt`] structure from the given file contents,
+/// detecting its compression and decompressing up to `limit` bytes.
+///
+/// # Errors
+///
+/// Returns an error if the data could not be decompressed,
+/// if it decompresses to more than `limit` bytes,
+/// or if it is not valid NBT data.
+pub fn read_nbt_with_limit(data: &[u8], limit: u64) -> Result<(Nbt, NbtCompression), NbtFileError> {
+    let compression = NbtCompression::detect(data);
+    let data = compression.decompress_with_limit(data, limit)?;
+
+    let (nbt, _) = Nbt::read_named(&data).map_err(|()| NbtFileError::InvalidNbt)?;
+    Ok((nbt, compression))
+}
+
+/// Read a named [`IndexedNbtCow`] structure from the given file contents,
+/// detecting its compression.
+///
+/// Uncompressed data is borrowed instead of copied.
+///
+/// # Errors
+///
+/// Returns an error if the data could not be decompressed,
+/// if it decompresses to more than [`DEFAULT_LIMIT`] bytes,
+/// or if it is not valid NBT data.
+#[inline]
+pub fn read_indexed(data: &[u8]) -> Result<(IndexedNbtCow<'_>, NbtCompression), NbtFileError> {
+    read_indexed_with_limit(data, DEFAULT_LIMIT)
+}
+
+/// Read a named [`IndexedNbtCow`] structure from the given file contents,
+/// detecting its compression and decompressing up to `limit` bytes.
+///
+/// Uncompressed data is borrowed instead of copied.
+///
+/// # Errors
+///
+/// Returns an error if the data could not be decompressed,
+/// if it decompresses to more than `limit` bytes,
+/// or if it is not valid NBT data.
+pub fn read_indexed_with_limit(
+    data: &[u8],
+    limit: u64,
+) -> Result<(IndexedNbtCow<'_>, NbtCompression), NbtFileError> {
+    let compression = NbtCompression::detect(data);
+    let nbt = match compression.decompress_with_limit(data, limit)? {
+        Cow::Borrowed(data) => IndexedNbtSlice::new_named(data).map(IndexedNbtSlice::into_cow),
+        Cow::Owned(data) => IndexedNbtSlice::new_named(&data).map(IndexedNbtSlice::into_owned),
+    };
+    Ok((nbt.map_err(|()| NbtFileError::InvalidNbt)?, compression))
+}
+
+/// Write a [`Nbt`] structure as a named file using the given compression.
+///
+/// # Errors
+///
+/// Returns an error if the data could not be compressed.
+pub fn write_nbt(nbt: &Nbt, compression: NbtCompression) -> io::Result<Vec<u8>> {
+    compression.compress(&nbt.to_named_bytes()).map(Cow::into_owned)
+}
+
+/// Write an [`IndexedNbt`] structure using the given compression.
+///
+/// The structure is written as it was read,
+/// so it should have been read as named NBT.
+///
+/// # Errors
+///
+/// Returns an error if the data could not be compressed.
+pub fn write_indexed<C: IndexCore<Ref>>(
+    nbt: &IndexedNbt<C>,
+    compression: NbtCompression,
+) -> io::Result<Vec<u8>> {
+    compression.compress(nbt.as_slice()).map(Cow::into_owned)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Read a named [`Nbt`] structure from a file, detecting its compression.
+///
+/// # Errors
+///
+/// Returns an error if the file could not be read,
+/// if it decompresses to more than [`DEFAULT_LIMIT`] bytes,
+/// or if it does not contain valid NBT data.
+pub fn read_nbt_file(path: impl AsRef<Path>) -> Result<(Nbt, NbtCompression), NbtFileError> {
+    read_nbt(&std::fs::read(path)?)
+}
+
+/// Read a named [`IndexedNbtCow`] structure from a file,
+/// detecting its compression.
+///
+/// # Errors
+///
+/// Returns an error if the file could not be read,
+/// if it decompresses to more than [`DEFAULT_LIMIT`] bytes,
+/// or if it does not contain valid NBT data.
+pub fn read_indexed_file(
+    path: impl AsRef<Path>,
+) -> Result<(IndexedNbtCow<'static>, NbtCompression), NbtFileError> {
+    let data = std::fs::read(path)?;
+    read_indexed(&data).map(|(nbt, compression)| (nbt.into_owned(), compression))
+}
+
+/// Write a [`Nbt`] structure to a file as named NBT
+/// using the given compression.
+///
+/// # Errors
+///
+/// Returns an error if the file could not be written.
+pub fn write_nbt_file(
+    path: impl AsRef<Path>,
+    nbt: &Nbt,
+    compression: NbtCompression,
+) -> io::Result<()> {
+    std::fs::write(path, write_nbt(nbt, compression)?)
+}
+
+/// Write an [`IndexedNbt`] structure to a file using the given compression.
+///
+/// # Errors
+///
+/// Returns an error if the file could not be written.
+pub fn write_indexed_file<C: IndexCore<Ref>>(
+    path: impl AsRef<Path>,
+    nbt: &IndexedNbt<C>,
+    compression: NbtCompression,
+) -> io::Result<()> {
+    std::fs::write(path, write_indexed(nbt, compression)?)
+}
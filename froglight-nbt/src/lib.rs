@@ -7,6 +7,8 @@ extern crate std;
 
 #[cfg(feature = "facet")]
 pub mod facet;
+#[cfg(feature = "flate2")]
+pub mod file;
 pub mod types;
 
 pub mod prelude {
@@ -19,11 +21,13 @@ pub mod prelude {
         deserialize::{DeserializeNbt, functions::*},
         serialize::{SerializeNbt, functions::*},
     };
+    #[cfg(feature = "flate2")]
+    pub use crate::file::NbtCompression;
     pub use crate::types::{
         indexed::{
             IndexedNbt,
             core::{IndexedNbtCow, IndexedNbtSlice},
         },
-        structured::Nbt,
+        structured::{Nbt, NbtCompound, NbtList, NbtValue},
     };
 }
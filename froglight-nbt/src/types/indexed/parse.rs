@@ -305,7 +305,7 @@ fn read_string(cursor: &mut Cursor<'_>) -> Result<Index<MStr>, ()> {
     let length = usize::from(u16::from_be_bytes(length_bytes));
 
     let content = cursor.next_slice(length)?;
-    MStr::from_mutf8(content)?;
+    MStr::from_mutf8(content).map_err(|_| ())?;
 
     Ok(Index::new(position))
 }
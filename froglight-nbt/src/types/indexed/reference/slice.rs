@@ -1,8 +1,9 @@
 use froglight_mutf8::prelude::MStr;
 
 use crate::types::indexed::{
+    core::NbtAccess,
     index::Index,
-    reference::{IndexableSlice, IndexableValue},
+    reference::{IndexableSlice, IndexableValue, IndexedReference},
 };
 
 macro_rules! impl_indexable {
@@ -66,6 +67,41 @@ macro_rules! impl_indexable {
 
 impl_indexable!([u8]: 4, [u32]: 4, [u64]: 4);
 
+macro_rules! impl_elements {
+    ($($ty:ty),*) => {
+        $(
+            impl<'data, A: NbtAccess> IndexedReference<'data, [$ty], A> {
+                /// Returns the number of elements in this array.
+                #[must_use]
+                pub fn len(&self) -> usize {
+                    // SAFETY: `IndexedReference` guarantees that this is safe
+                    unsafe { <[$ty]>::slice_len(&self.slice, self.index) }
+                }
+
+                /// Returns `true` if this array contains no elements.
+                #[inline]
+                #[must_use]
+                pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+                /// Returns an [`IndexedReference`] to the element at the given
+                /// index, if it exists.
+                #[must_use]
+                pub fn get_indexed(self, index: usize) -> Option<IndexedReference<'data, $ty, A>> {
+                    (index < self.len()).then(|| {
+                        let offset = self.index.value()
+                            + <[$ty]>::SIZE_BYTES
+                            + index * core::mem::size_of::<$ty>();
+                        // SAFETY: The element is within the bounds of the array
+                        unsafe { IndexedReference::new(self.slice, Index::new(offset)) }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_elements!(u8, u32, u64);
+
 // -------------------------------------------------------------------------------------------------
 
 unsafe impl IndexableValue for MStr {
@@ -1 +1,210 @@
+//! Reading [`Nbt`] from its binary representation.
+#![expect(clippy::result_unit_err, reason = "WIP")]
 
+use alloc::vec::Vec;
+
+use froglight_mutf8::prelude::{MStr, MString};
+
+use super::{Nbt, NbtCompound, NbtList, NbtValue};
+use crate::types::indexed::{
+    IndexedNbt,
+    core::{IndexCore, Ref},
+};
+
+/// The maximum depth of nested lists and compounds, matching Minecraft.
+const MAX_DEPTH: usize = 512;
+
+impl Nbt {
+    /// Read an unnamed [`Nbt`] structure from the given byte slice.
+    ///
+    /// Returns the structure and the number of bytes read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the byte slice is not valid NBT data.
+    pub fn read_unnamed(data: &[u8]) -> Result<(Self, usize), ()> { read_nbt(data, false) }
+
+    /// Read a named [`Nbt`] structure from the given byte slice.
+    ///
+    /// Returns the structure and the number of bytes read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the byte slice is not valid NBT data.
+    pub fn read_named(data: &[u8]) -> Result<(Self, usize), ()> { read_nbt(data, true) }
+}
+
+impl<C: IndexCore<Ref>> TryFrom<&IndexedNbt<C>> for Nbt {
+    type Error = ();
+
+    /// Read an [`IndexedNbt`] structure into a structured [`Nbt`].
+    fn try_from(nbt: &IndexedNbt<C>) -> Result<Self, Self::Error> {
+        read_nbt(nbt.as_slice(), nbt.name().is_some()).map(|(nbt, _)| nbt)
+    }
+}
+
+fn read_nbt(data: &[u8], named: bool) -> Result<(Nbt, usize), ()> {
+    let mut cursor = data;
+
+    // All NBT starts with a compound tag
+    if read_arr::<1>(&mut cursor)? != [COMPOUND] {
+        return Err(());
+    }
+
+    let name = named.then(|| read_string(&mut cursor)).transpose()?;
+    let root = read_compound(&mut cursor, 0)?;
+
+    Ok((Nbt::new(name, root), data.len() - cursor.len()))
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn read_compound(cursor: &mut &[u8], depth: usize) -> Result<NbtCompound, ()> {
+    if depth >= MAX_DEPTH {
+        return Err(());
+    }
+
+    let mut compound = NbtCompound::new();
+    loop {
+        let [tag] = read_arr::<1>(cursor)?;
+        if tag == END {
+            return Ok(compound);
+        }
+
+        let name = read_string(cursor)?;
+        let value = read_value(cursor, tag, depth + 1)?;
+        compound.insert(name, value);
+    }
+}
+
+fn read_value(cursor: &mut &[u8], tag: u8, depth: usize) -> Result<NbtValue, ()> {
+    match tag {
+        BYTE => read_arr(cursor).map(|v| NbtValue::Byte(u8::from_be_bytes(v))),
+        SHORT => read_arr(cursor).map(|v| NbtValue::Short(u16::from_be_bytes(v))),
+        INT => read_arr(cursor).map(|v| NbtValue::Int(u32::from_be_bytes(v))),
+        LONG => read_arr(cursor).map(|v| NbtValue::Long(u64::from_be_bytes(v))),
+        FLOAT => read_arr(cursor).map(|v| NbtValue::Float(f32::from_be_bytes(v))),
+        DOUBLE => read_arr(cursor).map(|v| NbtValue::Double(f64::from_be_bytes(v))),
+        BYTE_ARRAY => read_array(cursor, u8::from_be_bytes).map(NbtValue::ByteArray),
+        STRING => read_string(cursor).map(NbtValue::String),
+        LIST => read_list(cursor, depth).map(NbtValue::List),
+        COMPOUND => read_compound(cursor, depth).map(NbtValue::Compound),
+        INT_ARRAY => read_array(cursor, u32::from_be_bytes).map(NbtValue::IntArray),
+        LONG_ARRAY => read_array(cursor, u64::from_be_bytes).map(NbtValue::LongArray),
+        _ => Err(()),
+    }
+}
+
+fn read_list(cursor: &mut &[u8], depth: usize) -> Result<NbtList, ()> {
+    /// Read `length` elements using the given function.
+    fn read_n<T>(
+        cursor: &mut &[u8],
+        length: usize,
+        mut f: impl FnMut(&mut &[u8]) -> Result<T, ()>,
+    ) -> Result<Vec<T>, ()> {
+        // Avoid trusting the length for the allocation.
+        let mut vec = Vec::with_capacity(length.min(cursor.len()));
+        for _ in 0..length {
+            vec.push(f(cursor)?);
+        }
+        Ok(vec)
+    }
+
+    if depth >= MAX_DEPTH {
+        return Err(());
+    }
+
+    let [tag] = read_arr::<1>(cursor)?;
+    let length = read_length(cursor)?;
+    if length == 0 {
+        return Ok(NbtList::Empty);
+    }
+
+    match tag {
+        BYTE => read_n(cursor, length, |c| read_arr(c).map(u8::from_be_bytes)).map(NbtList::Byte),
+        SHORT => {
+            read_n(cursor, length, |c| read_arr(c).map(u16::from_be_bytes)).map(NbtList::Short)
+        }
+        INT => read_n(cursor, length, |c| read_arr(c).map(u32::from_be_bytes)).map(NbtList::Int),
+        LONG => read_n(cursor, length, |c| read_arr(c).map(u64::from_be_bytes)).map(NbtList::Long),
+        FLOAT => {
+            read_n(cursor, length, |c| read_arr(c).map(f32::from_be_bytes)).map(NbtList::Float)
+        }
+        DOUBLE => {
+            read_n(cursor, length, |c| read_arr(c).map(f64::from_be_bytes)).map(NbtList::Double)
+        }
+        BYTE_ARRAY => {
+            read_n(cursor, length, |c| read_array(c, u8::from_be_bytes)).map(NbtList::ByteArray)
+        }
+        STRING => read_n(cursor, length, read_string).map(NbtList::String),
+        LIST => read_n(cursor, length, |c| read_list(c, depth + 1)).map(NbtList::List),
+        COMPOUND => read_n(cursor, length, |c| read_compound(c, depth + 1)).map(NbtList::Compound),
+        INT_ARRAY => {
+            read_n(cursor, length, |c| read_array(c, u32::from_be_bytes)).map(NbtList::IntArray)
+        }
+        LONG_ARRAY => {
+            read_n(cursor, length, |c| read_array(c, u64::from_be_bytes)).map(NbtList::LongArray)
+        }
+        _ => Err(()),
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Read the next `N` bytes from the cursor.
+#[inline]
+fn read_arr<const N: usize>(cursor: &mut &[u8]) -> Result<[u8; N], ()> {
+    let (bytes, remaining) = cursor.split_first_chunk::<N>().ok_or(())?;
+    *cursor = remaining;
+    Ok(*bytes)
+}
+
+/// Read the next `n` bytes from the cursor.
+#[inline]
+fn read_slice<'a>(cursor: &mut &'a [u8], n: usize) -> Result<&'a [u8], ()> {
+    let (bytes, remaining) = cursor.split_at_checked(n).ok_or(())?;
+    *cursor = remaining;
+    Ok(bytes)
+}
+
+/// Read the length of a list or array.
+#[inline]
+fn read_length(cursor: &mut &[u8]) -> Result<usize, ()> {
+    // Negative lengths are treated as invalid.
+    let length = i32::from_be_bytes(read_arr(cursor)?);
+    usize::try_from(length).map_err(|_| ())
+}
+
+/// Read a length-prefixed array of big-endian values.
+fn read_array<const N: usize, T>(
+    cursor: &mut &[u8],
+    f: impl Fn([u8; N]) -> T,
+) -> Result<Vec<T>, ()> {
+    let length = read_length(cursor)?;
+    let bytes = read_slice(cursor, length.checked_mul(N).ok_or(())?)?;
+
+    let (chunks, _) = bytes.as_chunks::<N>();
+    Ok(chunks.iter().map(|chunk| f(*chunk)).collect())
+}
+
+/// Read a length-prefixed MUTF-8 string.
+fn read_string(cursor: &mut &[u8]) -> Result<MString, ()> {
+    let length = usize::from(u16::from_be_bytes(read_arr(cursor)?));
+    MStr::from_mutf8(read_slice(cursor, length)?).map(MStr::to_mstring).map_err(|_| ())
+}
+
+// -------------------------------------------------------------------------------------------------
+
+pub(super) const END: u8 = 0;
+pub(super) const BYTE: u8 = 1;
+pub(super) const SHORT: u8 = 2;
+pub(super) const INT: u8 = 3;
+pub(super) const LONG: u8 = 4;
+pub(super) const FLOAT: u8 = 5;
+pub(super) const DOUBLE: u8 = 6;
+pub(super) const BYTE_ARRAY: u8 = 7;
+pub(super) const STRING: u8 = 8;
+pub(super) const LIST: u8 = 9;
+pub(super) const COMPOUND: u8 = 10;
+pub(super) const INT_ARRAY: u8 = 11;
+pub(super) const LONG_ARRAY: u8 = 12;
@@ -6,8 +6,11 @@ use core::ops::{Deref, DerefMut};
 use froglight_mutf8::prelude::{MStr, MString};
 
 mod deserialize;
+mod reference;
 mod serialize;
 
+pub use reference::ValueRef;
+
 /// An NBT structure.
 #[derive(Debug, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
@@ -49,6 +52,11 @@ impl Nbt {
     #[inline]
     #[must_use]
     pub const fn compound_mut(&mut self) -> &mut NbtCompound { &mut self.root }
+
+    /// Get the root compound of this structure, discarding its name.
+    #[inline]
+    #[must_use]
+    pub fn into_compound(self) -> NbtCompound { self.root }
 }
 
 impl AsRef<NbtCompound> for Nbt {
@@ -122,6 +130,18 @@ impl NbtCompound {
         self.entries.get_mut(index).map(|(k, v)| (k, v))
     }
 
+    /// Returns an iterator over the key-value pairs of the compound.
+    #[must_use]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&MStr, &NbtValue)> {
+        self.entries.iter().map(|(k, v)| (k.as_mstr(), v))
+    }
+
+    /// Returns an iterator over the key-value pairs of the compound,
+    /// allowing the values to be modified.
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = (&MStr, &mut NbtValue)> {
+        self.entries.iter_mut().map(|(k, v)| (k.as_mstr(), v))
+    }
+
     /// Insert a value into this compound,
     /// returning the previous value if it existed.
     pub fn insert<K: Into<MString>, V: Into<NbtValue>>(
@@ -150,8 +170,16 @@ impl NbtCompound {
 }
 
 impl<V: Into<NbtValue>> FromIterator<(MString, V)> for NbtCompound {
+    /// Collect key-value pairs into a compound.
+    ///
+    /// Duplicate keys keep their first position but take the last value,
+    /// the same as [`NbtCompound::insert`].
     fn from_iter<T: IntoIterator<Item = (MString, V)>>(iter: T) -> Self {
-        Self { entries: iter.into_iter().map(|(k, v)| (k, v.into())).collect() }
+        let mut compound = Self::new();
+        for (key, value) in iter {
+            compound.insert(key, value);
+        }
+        compound
     }
 }
 impl IntoIterator for NbtCompound {
@@ -295,6 +323,65 @@ impl NbtList {
     }
 }
 
+impl TryFrom<Vec<NbtValue>> for NbtList {
+    type Error = Vec<NbtValue>;
+
+    /// Create a [`NbtList`] from values of the same type.
+    ///
+    /// Returns the values if they are not all the same type.
+    fn try_from(values: Vec<NbtValue>) -> Result<Self, Self::Error> {
+        macro_rules! collect_list {
+            ($($variant:ident),*) => {
+                match values.first() {
+                    None => Ok(NbtList::Empty),
+                    $(
+                        Some(NbtValue::$variant(..)) => {
+                            if !values.iter().all(|value| matches!(value, NbtValue::$variant(..))) {
+                                return Err(values);
+                            }
+                            Ok(NbtList::$variant(
+                                values
+                                    .into_iter()
+                                    .filter_map(|value| value.try_into().ok())
+                                    .collect(),
+                            ))
+                        }
+                    )*
+                }
+            };
+        }
+
+        collect_list!(
+            Byte, Short, Int, Long, Float, Double, ByteArray, String, List, Compound, IntArray,
+            LongArray
+        )
+    }
+}
+
+impl From<NbtList> for Vec<NbtValue> {
+    fn from(list: NbtList) -> Self {
+        fn convert<T: Into<NbtValue>>(list: Vec<T>) -> Vec<NbtValue> {
+            list.into_iter().map(Into::into).collect()
+        }
+
+        match list {
+            NbtList::Empty => Vec::new(),
+            NbtList::Byte(list) => convert(list),
+            NbtList::Short(list) => convert(list),
+            NbtList::Int(list) => convert(list),
+            NbtList::Long(list) => convert(list),
+            NbtList::Float(list) => convert(list),
+            NbtList::Double(list) => convert(list),
+            NbtList::ByteArray(list) => list.into_iter().map(NbtValue::ByteArray).collect(),
+            NbtList::String(list) => convert(list),
+            NbtList::List(list) => convert(list),
+            NbtList::Compound(list) => convert(list),
+            NbtList::IntArray(list) => list.into_iter().map(NbtValue::IntArray).collect(),
+            NbtList::LongArray(list) => list.into_iter().map(NbtValue::LongArray).collect(),
+        }
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// An NBT value.
@@ -0,0 +1,109 @@
+//! A borrowed view of a [`NbtValue`].
+
+use alloc::vec::Vec;
+
+use froglight_mutf8::prelude::MStr;
+
+use super::{NbtCompound, NbtList, NbtValue};
+
+/// A borrowed view of a [`NbtValue`].
+///
+/// Allows the elements of a [`NbtList`] or array to be visited
+/// without first converting them into [`NbtValue`]s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueRef<'a> {
+    /// A byte value.
+    Byte(u8),
+    /// A short value.
+    Short(u16),
+    /// An int value.
+    Int(u32),
+    /// A long value.
+    Long(u64),
+    /// A float value.
+    Float(f32),
+    /// A double value.
+    Double(f64),
+    /// A byte array.
+    ByteArray(&'a [u8]),
+    /// A string.
+    String(&'a MStr),
+    /// A list.
+    List(&'a NbtList),
+    /// A compound.
+    Compound(&'a NbtCompound),
+    /// An int array.
+    IntArray(&'a [u32]),
+    /// A long array.
+    LongArray(&'a [u64]),
+}
+
+impl<'a> From<&'a NbtValue> for ValueRef<'a> {
+    fn from(value: &'a NbtValue) -> Self {
+        match value {
+            NbtValue::Byte(value) => Self::Byte(*value),
+            NbtValue::Short(value) => Self::Short(*value),
+            NbtValue::Int(value) => Self::Int(*value),
+            NbtValue::Long(value) => Self::Long(*value),
+            NbtValue::Float(value) => Self::Float(*value),
+            NbtValue::Double(value) => Self::Double(*value),
+            NbtValue::ByteArray(value) => Self::ByteArray(value),
+            NbtValue::String(value) => Self::String(value.as_mstr()),
+            NbtValue::List(value) => Self::List(value),
+            NbtValue::Compound(value) => Self::Compound(value),
+            NbtValue::IntArray(value) => Self::IntArray(value),
+            NbtValue::LongArray(value) => Self::LongArray(value),
+        }
+    }
+}
+
+impl<'a> ValueRef<'a> {
+    /// Get the elements of a [`NbtList`] or array.
+    #[must_use]
+    pub fn elements(self) -> Option<Vec<ValueRef<'a>>> {
+        fn collect<'a, T>(slice: &'a [T], f: impl Fn(&'a T) -> ValueRef<'a>) -> Vec<ValueRef<'a>> {
+            slice.iter().map(f).collect()
+        }
+
+        match self {
+            Self::ByteArray(array) => Some(collect(array, |v| ValueRef::Byte(*v))),
+            Self::IntArray(array) => Some(collect(array, |v| ValueRef::Int(*v))),
+            Self::LongArray(array) => Some(collect(array, |v| ValueRef::Long(*v))),
+            Self::List(list) => Some(match list {
+                NbtList::Empty => Vec::new(),
+                NbtList::Byte(list) => collect(list, |v| ValueRef::Byte(*v)),
+                NbtList::Short(list) => collect(list, |v| ValueRef::Short(*v)),
+                NbtList::Int(list) => collect(list, |v| ValueRef::Int(*v)),
+                NbtList::Long(list) => collect(list, |v| ValueRef::Long(*v)),
+                NbtList::Float(list) => collect(list, |v| ValueRef::Float(*v)),
+                NbtList::Double(list) => collect(list, |v| ValueRef::Double(*v)),
+                NbtList::ByteArray(list) => collect(list, |v| ValueRef::ByteArray(v)),
+                NbtList::String(list) => collect(list, |v| ValueRef::String(v.as_mstr())),
+                NbtList::List(list) => collect(list, ValueRef::List),
+                NbtList::Compound(list) => collect(list, ValueRef::Compound),
+                NbtList::IntArray(list) => collect(list, |v| ValueRef::IntArray(v)),
+                NbtList::LongArray(list) => collect(list, |v| ValueRef::LongArray(v)),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Convert the value back into an owned [`NbtValue`].
+    #[must_use]
+    pub fn to_value(self) -> NbtValue {
+        match self {
+            Self::Byte(value) => NbtValue::Byte(value),
+            Self::Short(value) => NbtValue::Short(value),
+            Self::Int(value) => NbtValue::Int(value),
+            Self::Long(value) => NbtValue::Long(value),
+            Self::Float(value) => NbtValue::Float(value),
+            Self::Double(value) => NbtValue::Double(value),
+            Self::ByteArray(value) => NbtValue::ByteArray(value.to_vec()),
+            Self::String(value) => NbtValue::String(value.to_mstring()),
+            Self::List(value) => NbtValue::List(value.clone()),
+            Self::Compound(value) => NbtValue::Compound(value.clone()),
+            Self::IntArray(value) => NbtValue::IntArray(value.to_vec()),
+            Self::LongArray(value) => NbtValue::LongArray(value.to_vec()),
+        }
+    }
+}
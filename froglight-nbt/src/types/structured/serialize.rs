@@ -1 +1,157 @@
+//! Writing [`Nbt`] into its binary representation.
 
+use alloc::vec::Vec;
+
+use froglight_mutf8::prelude::MStr;
+
+use super::{
+    Nbt, NbtCompound, NbtList, NbtValue,
+    deserialize::{
+        BYTE, BYTE_ARRAY, COMPOUND, DOUBLE, END, FLOAT, INT, INT_ARRAY, LIST, LONG, LONG_ARRAY,
+        SHORT, STRING,
+    },
+};
+
+impl Nbt {
+    /// Write this structure as unnamed NBT, ignoring its name.
+    #[must_use]
+    pub fn to_unnamed_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.write_unnamed(&mut buffer);
+        buffer
+    }
+
+    /// Write this structure as named NBT.
+    ///
+    /// Structures without a name are written with an empty name.
+    #[must_use]
+    pub fn to_named_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.write_named(&mut buffer);
+        buffer
+    }
+
+    /// Append this structure to the buffer as unnamed NBT, ignoring its name.
+    pub fn write_unnamed(&self, buffer: &mut Vec<u8>) {
+        buffer.push(COMPOUND);
+        write_compound(self.compound(), buffer);
+    }
+
+    /// Append this structure to the buffer as named NBT.
+    ///
+    /// Structures without a name are written with an empty name.
+    pub fn write_named(&self, buffer: &mut Vec<u8>) {
+        buffer.push(COMPOUND);
+        match self.name() {
+            Some(name) => write_string(name, buffer),
+            None => buffer.extend_from_slice(&0u16.to_be_bytes()),
+        }
+        write_compound(self.compound(), buffer);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn write_compound(compound: &NbtCompound, buffer: &mut Vec<u8>) {
+    for (name, value) in compound.iter() {
+        buffer.push(value_tag(value));
+        write_string(name, buffer);
+        write_value(value, buffer);
+    }
+    buffer.push(END);
+}
+
+fn write_value(value: &NbtValue, buffer: &mut Vec<u8>) {
+    match value {
+        NbtValue::Byte(value) => buffer.push(*value),
+        NbtValue::Short(value) => buffer.extend_from_slice(&value.to_be_bytes()),
+        NbtValue::Int(value) => buffer.extend_from_slice(&value.to_be_bytes()),
+        NbtValue::Long(value) => buffer.extend_from_slice(&value.to_be_bytes()),
+        NbtValue::Float(value) => buffer.extend_from_slice(&value.to_be_bytes()),
+        NbtValue::Double(value) => buffer.extend_from_slice(&value.to_be_bytes()),
+        NbtValue::ByteArray(array) => write_array(array, buffer, |v| [*v]),
+        NbtValue::String(value) => write_string(value, buffer),
+        NbtValue::List(list) => write_list(list, buffer),
+        NbtValue::Compound(compound) => write_compound(compound, buffer),
+        NbtValue::IntArray(array) => write_array(array, buffer, |v| v.to_be_bytes()),
+        NbtValue::LongArray(array) => write_array(array, buffer, |v| v.to_be_bytes()),
+    }
+}
+
+fn write_list(list: &NbtList, buffer: &mut Vec<u8>) {
+    /// Write the list header followed by each element.
+    fn write_n<T>(tag: u8, list: &[T], buffer: &mut Vec<u8>, mut f: impl FnMut(&T, &mut Vec<u8>)) {
+        buffer.push(tag);
+        write_length(list.len(), buffer);
+        for item in list {
+            f(item, buffer);
+        }
+    }
+
+    match list {
+        NbtList::Empty => {
+            buffer.push(END);
+            write_length(0, buffer);
+        }
+        NbtList::Byte(list) => write_n(BYTE, list, buffer, |v, b| b.push(*v)),
+        NbtList::Short(list) => write_n(SHORT, list, buffer, |v, b| b.extend(v.to_be_bytes())),
+        NbtList::Int(list) => write_n(INT, list, buffer, |v, b| b.extend(v.to_be_bytes())),
+        NbtList::Long(list) => write_n(LONG, list, buffer, |v, b| b.extend(v.to_be_bytes())),
+        NbtList::Float(list) => write_n(FLOAT, list, buffer, |v, b| b.extend(v.to_be_bytes())),
+        NbtList::Double(list) => write_n(DOUBLE, list, buffer, |v, b| b.extend(v.to_be_bytes())),
+        NbtList::ByteArray(list) => {
+            write_n(BYTE_ARRAY, list, buffer, |v, b| write_array(v, b, |v| [*v]));
+        }
+        NbtList::String(list) => write_n(STRING, list, buffer, |v, b| write_string(v, b)),
+        NbtList::List(list) => write_n(LIST, list, buffer, write_list),
+        NbtList::Compound(list) => write_n(COMPOUND, list, buffer, write_compound),
+        NbtList::IntArray(list) => {
+            write_n(INT_ARRAY, list, buffer, |v, b| write_array(v, b, |v| v.to_be_bytes()));
+        }
+        NbtList::LongArray(list) => {
+            write_n(LONG_ARRAY, list, buffer, |v, b| write_array(v, b, |v| v.to_be_bytes()));
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Get the tag of a [`NbtValue`].
+const fn value_tag(value: &NbtValue) -> u8 {
+    match value {
+        NbtValue::Byte(..) => BYTE,
+        NbtValue::Short(..) => SHORT,
+        NbtValue::Int(..) => INT,
+        NbtValue::Long(..) => LONG,
+        NbtValue::Float(..) => FLOAT,
+        NbtValue::Double(..) => DOUBLE,
+        NbtValue::ByteArray(..) => BYTE_ARRAY,
+        NbtValue::String(..) => STRING,
+        NbtValue::List(..) => LIST,
+        NbtValue::Compound(..) => COMPOUND,
+        NbtValue::IntArray(..) => INT_ARRAY,
+        NbtValue::LongArray(..) => LONG_ARRAY,
+    }
+}
+
+/// Write the length of a list or array.
+#[expect(clippy::cast_possible_truncation, reason = "NBT lengths are 32-bit")]
+fn write_length(length: usize, buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(&(length as u32).to_be_bytes());
+}
+
+/// Write a length-prefixed array of big-endian values.
+fn write_array<const N: usize, T>(array: &[T], buffer: &mut Vec<u8>, f: impl Fn(&T) -> [u8; N]) {
+    write_length(array.len(), buffer);
+    buffer.reserve(array.len() * N);
+    for value in array {
+        buffer.extend_from_slice(&f(value));
+    }
+}
+
+/// Write a length-prefixed MUTF-8 string.
+#[expect(clippy::cast_possible_truncation, reason = "NBT strings are 16-bit")]
+fn write_string(string: &MStr, buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(&(string.len() as u16).to_be_bytes());
+    buffer.extend_from_slice(string.as_bytes());
+}
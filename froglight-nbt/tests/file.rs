@@ -0,0 +1,116 @@
+//! TODO
+
+use froglight_nbt::{file, prelude::*};
+
+static FILES: &[&[u8]] = &[
+    include_bytes!("nbt/bigtest.nbt"),
+    include_bytes!("nbt/complex_player.nbt"),
+    include_bytes!("nbt/hello_world.nbt"),
+    include_bytes!("nbt/inttest1023.nbt"),
+];
+
+#[test]
+fn detect() {
+    for &data in FILES {
+        assert_eq!(NbtCompression::detect(data), NbtCompression::None);
+
+        for compression in [NbtCompression::Gzip, NbtCompression::Zlib] {
+            let compressed = compression.compress(data).unwrap();
+            assert_eq!(NbtCompression::detect(&compressed), compression);
+            assert_eq!(compression.decompress(&compressed).unwrap(), data);
+        }
+    }
+}
+
+#[test]
+fn structured() {
+    for &data in FILES {
+        let (nbt, length) = Nbt::read_named(data).unwrap();
+        assert_eq!(length, data.len());
+        assert_eq!(nbt.to_named_bytes(), data, "Writing should match the original");
+
+        for compression in [NbtCompression::None, NbtCompression::Gzip, NbtCompression::Zlib] {
+            let written = file::write_nbt(&nbt, compression).unwrap();
+            let (read, detected) = file::read_nbt(&written).unwrap();
+            assert_eq!(detected, compression);
+            assert_eq!(read, nbt);
+        }
+    }
+}
+
+#[test]
+fn indexed() {
+    for &data in FILES {
+        for compression in [NbtCompression::None, NbtCompression::Gzip, NbtCompression::Zlib] {
+            let compressed = compression.compress(data).unwrap();
+            let (nbt, detected) = file::read_indexed(&compressed).unwrap();
+            assert_eq!(detected, compression);
+            assert_eq!(nbt.as_slice(), data);
+
+            let written = file::write_indexed(&nbt, compression).unwrap();
+            assert_eq!(written, compressed.as_ref());
+        }
+    }
+}
+
+#[test]
+fn filesystem() {
+    let directory = std::env::temp_dir().join(format!("froglight-nbt-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    let (nbt, _) = Nbt::read_named(FILES[0]).unwrap();
+    for name in ["level.dat", "servers.dat", "house.nbt"] {
+        let path = directory.join(name);
+        let compression = NbtCompression::from_path(&path);
+        file::write_nbt_file(&path, &nbt, compression).unwrap();
+
+        assert_eq!(file::read_nbt_file(&path).unwrap(), (nbt.clone(), compression));
+        let (indexed, detected) = file::read_indexed_file(&path).unwrap();
+        assert_eq!((indexed.as_slice(), detected), (FILES[0], compression));
+    }
+
+    assert_eq!(NbtCompression::from_path(directory.join("level.dat")), NbtCompression::Gzip);
+    assert_eq!(NbtCompression::from_path(directory.join("servers.dat")), NbtCompression::None);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(file::read_nbt(&[]), Err(file::NbtFileError::InvalidNbt)));
+    assert!(matches!(file::read_nbt(&[0x0a, 0x00]), Err(file::NbtFileError::InvalidNbt)));
+    assert!(matches!(file::read_nbt(&[0x1f, 0x8b, 0x00]), Err(file::NbtFileError::Io(_))));
+    assert!(
+        Nbt::read_named(&[0x0a, 0x00, 0x00, 0x09, 0x00, 0x00, 0x01, 0xff, 0xff, 0xff, 0xff])
+            .is_err()
+    );
+}
+
+#[test]
+fn limit() {
+    let data = FILES[0];
+    let limit = u64::try_from(data.len()).unwrap();
+
+    for compression in [NbtCompression::Gzip, NbtCompression::Zlib] {
+        let compressed = compression.compress(data).unwrap();
+        assert_eq!(compression.decompress_with_limit(&compressed, limit).unwrap(), data);
+        assert!(matches!(
+            compression.decompress_with_limit(&compressed, limit - 1),
+            Err(file::NbtFileError::TooLarge(_))
+        ));
+
+        assert!(file::read_nbt_with_limit(&compressed, limit).is_ok());
+        assert!(matches!(
+            file::read_indexed_with_limit(&compressed, limit - 1),
+            Err(file::NbtFileError::TooLarge(_))
+        ));
+    }
+
+    // A small file that decompresses to a large amount of data.
+    let zeros = vec![0; 1024 * 1024];
+    let bomb = NbtCompression::Gzip.compress(&zeros).unwrap();
+    assert!(matches!(
+        file::read_nbt_with_limit(&bomb, 1024),
+        Err(file::NbtFileError::TooLarge(1024))
+    ));
+}
@@ -0,0 +1,251 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+use facet::Facet;
+use froglight_mutf8::prelude::MString;
+use froglight_nbt::{self as nbt, prelude::*};
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Facet)]
+struct Player {
+    name: String,
+    health: f32,
+    level: i32,
+    #[facet(default)]
+    flying: bool,
+    nickname: Option<String>,
+    position: Vec<f64>,
+    uuid: Uuid,
+    #[facet(nbt::int_array)]
+    scores: Vec<i32>,
+    #[facet(nbt::long_array)]
+    states: Vec<i64>,
+    #[facet(nbt::byte_array)]
+    flags: Vec<u8>,
+    mode: GameMode,
+    stats: BTreeMap<String, u32>,
+}
+
+#[repr(u8)]
+#[derive(Debug, PartialEq, Facet)]
+enum GameMode {
+    Survival,
+    Creative,
+}
+
+fn player() -> Player {
+    Player {
+        name: String::from("Frog"),
+        health: 20.0,
+        level: -3,
+        flying: false,
+        nickname: None,
+        position: vec![1.5, 64.0, -2.5],
+        uuid: Uuid::from_u128(0x0123_4567_89AB_CDEF_0011_2233_4455_6677),
+        scores: vec![1, -2, 3],
+        states: vec![i64::MIN, 0, i64::MAX],
+        flags: vec![0, 1, 255],
+        mode: GameMode::Creative,
+        stats: BTreeMap::from([(String::from("jumps"), 7), (String::from("deaths"), 2)]),
+    }
+}
+
+#[test]
+fn structure() {
+    let nbt = to_nbt(&player()).unwrap();
+    assert_eq!(nbt.name(), None);
+
+    assert_eq!(
+        nbt.get("name"),
+        Some(&NbtValue::String(MString::from_utf8_owned(String::from("Frog"))))
+    );
+    assert_eq!(nbt.get("health"), Some(&NbtValue::Float(20.0)));
+    assert_eq!(nbt.get("level"), Some(&NbtValue::Int((-3i32).cast_unsigned())));
+    assert_eq!(nbt.get("flying"), Some(&NbtValue::Byte(0)));
+    assert_eq!(nbt.get("nickname"), None, "`None` should be omitted");
+    assert_eq!(nbt.get("position"), Some(&NbtValue::List(NbtList::Double(vec![1.5, 64.0, -2.5]))));
+    assert_eq!(
+        nbt.get("uuid"),
+        Some(&NbtValue::IntArray(vec![0x0123_4567, 0x89AB_CDEF, 0x0011_2233, 0x4455_6677]))
+    );
+    assert_eq!(nbt.get("scores"), Some(&NbtValue::IntArray(vec![1, (-2i32).cast_unsigned(), 3])));
+    assert_eq!(
+        nbt.get("states"),
+        Some(&NbtValue::LongArray(vec![i64::MIN.cast_unsigned(), 0, i64::MAX.cast_unsigned()]))
+    );
+    assert_eq!(nbt.get("flags"), Some(&NbtValue::ByteArray(vec![0, 1, 255])));
+    assert_eq!(
+        nbt.get("mode"),
+        Some(&NbtValue::String(MString::from_utf8_owned(String::from("Creative"))))
+    );
+
+    let Some(NbtValue::Compound(stats)) = nbt.get("stats") else { panic!("Expected a compound") };
+    assert_eq!(stats.get("jumps"), Some(&NbtValue::Int(7)));
+    assert_eq!(stats.get("deaths"), Some(&NbtValue::Int(2)));
+}
+
+#[test]
+fn roundtrip() {
+    let player = player();
+    let nbt = to_nbt(&player).unwrap();
+    assert_eq!(from_nbt_compound::<Player>(&nbt).unwrap(), player);
+
+    let value = to_nbt_value(&player).unwrap();
+    assert_eq!(from_nbt_value::<Player>(&value).unwrap(), player);
+}
+
+#[test]
+fn defaults() {
+    let mut nbt = to_nbt(&player()).unwrap();
+    nbt.remove("flying");
+    nbt.insert("nickname", MString::from_utf8_owned(String::from("Toad")));
+
+    let player = from_nbt_compound::<Player>(&nbt).unwrap();
+    assert!(!player.flying);
+    assert_eq!(player.nickname.as_deref(), Some("Toad"));
+
+    nbt.remove("name");
+    assert!(from_nbt_compound::<Player>(&nbt).is_err(), "`name` is required");
+}
+
+#[test]
+fn numeric_conversion() {
+    #[derive(Debug, PartialEq, Facet)]
+    struct Numbers {
+        a: i64,
+        b: f32,
+        c: u8,
+    }
+
+    // Like Minecraft, any numeric tag can be read as any numeric type.
+    let mut compound = NbtCompound::new();
+    compound.insert("a", NbtValue::Byte((-1i8).cast_unsigned()));
+    compound.insert("b", NbtValue::Int(3));
+    compound.insert("c", NbtValue::Double(7.9));
+
+    let numbers = from_nbt_compound::<Numbers>(&compound).unwrap();
+    assert_eq!(numbers, Numbers { a: -1, b: 3.0, c: 7 });
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[repr(u8)]
+#[derive(Debug, PartialEq, Facet)]
+enum External {
+    Empty,
+    Single(u16),
+    Named { value: i8 },
+}
+
+#[repr(u8)]
+#[derive(Debug, PartialEq, Facet)]
+#[facet(tag = "type")]
+enum Internal {
+    Empty,
+    Named { value: i8 },
+}
+
+#[repr(u8)]
+#[derive(Debug, PartialEq, Facet)]
+#[facet(tag = "type", content = "data")]
+enum Adjacent {
+    Empty,
+    Single(u16),
+}
+
+#[test]
+fn enums() {
+    let string = |s: &str| NbtValue::String(MString::from_utf8_owned(String::from(s)));
+
+    // Externally tagged
+    assert_eq!(to_nbt_value(&External::Empty).unwrap(), string("Empty"));
+    let value = to_nbt_value(&External::Single(5)).unwrap();
+    assert_eq!(value.as_compound().unwrap().get("Single"), Some(&NbtValue::Short(5)));
+    for variant in [External::Empty, External::Single(5), External::Named { value: -4 }] {
+        let value = to_nbt_value(&variant).unwrap();
+        assert_eq!(from_nbt_value::<External>(&value).unwrap(), variant);
+    }
+
+    // Internally tagged
+    let value = to_nbt_value(&Internal::Named { value: 2 }).unwrap();
+    let compound = value.as_compound().unwrap();
+    assert_eq!(compound.get("type"), Some(&string("Named")));
+    assert_eq!(compound.get("value"), Some(&NbtValue::Byte(2)));
+    for variant in [Internal::Empty, Internal::Named { value: 2 }] {
+        let value = to_nbt_value(&variant).unwrap();
+        assert_eq!(from_nbt_value::<Internal>(&value).unwrap(), variant);
+    }
+
+    // Adjacently tagged
+    let value = to_nbt_value(&Adjacent::Single(9)).unwrap();
+    let compound = value.as_compound().unwrap();
+    assert_eq!(compound.get("type"), Some(&string("Single")));
+    assert_eq!(compound.get("data"), Some(&NbtValue::Short(9)));
+    for variant in [Adjacent::Empty, Adjacent::Single(9)] {
+        let value = to_nbt_value(&variant).unwrap();
+        assert_eq!(from_nbt_value::<Adjacent>(&value).unwrap(), variant);
+    }
+
+    assert!(from_nbt_value::<External>(&string("Missing")).is_err());
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Facet)]
+struct Outer {
+    id: u32,
+    #[facet(flatten)]
+    inner: Inner,
+    #[facet(rename = "Tags")]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Inner {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn flatten() {
+    let outer = Outer { id: 1, inner: Inner { x: 2, y: -3 }, tags: vec![String::from("a")] };
+
+    let nbt = to_nbt(&outer).unwrap();
+    assert_eq!(nbt.len(), 4);
+    assert_eq!(nbt.get("x"), Some(&NbtValue::Int(2)));
+    assert!(nbt.get("inner").is_none());
+    assert!(nbt.get("Tags").is_some());
+
+    assert_eq!(from_nbt_compound::<Outer>(&nbt).unwrap(), outer);
+}
+
+#[test]
+fn passthrough() {
+    #[derive(Debug, PartialEq, Facet)]
+    struct Wrapper {
+        data: NbtCompound,
+        list: NbtList,
+        extra: Vec<NbtValue>,
+    }
+
+    let mut data = NbtCompound::new();
+    data.insert("inner", NbtValue::Long(4));
+    let wrapper =
+        Wrapper { data, list: NbtList::Empty, extra: vec![NbtValue::Byte(1), NbtValue::Byte(2)] };
+
+    let nbt = to_nbt(&wrapper).unwrap();
+    assert_eq!(nbt.get("list"), Some(&NbtValue::List(NbtList::Empty)));
+    assert_eq!(from_nbt_compound::<Wrapper>(&nbt).unwrap(), wrapper);
+}
+
+#[test]
+fn not_a_compound() {
+    assert!(to_nbt(&5u32).is_err());
+    assert_eq!(to_nbt_value(&5u32).unwrap(), NbtValue::Int(5));
+    assert_eq!(from_nbt_value::<u32>(&NbtValue::Int(5)).unwrap(), 5);
+    assert!(from_nbt_value::<u32>(&NbtValue::String(MString::new())).is_err());
+}
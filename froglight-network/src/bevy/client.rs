@@ -11,13 +11,19 @@ use crate::{
     bevy::NetworkVersion,
     connection::{ConnectionError, Runtime},
     event::enums::{ClientboundEventEnum, ServerboundEventEnum},
+    state::{BossBars, Scoreboard, TabList, Teams, Titles, WorldBorder},
 };
 
 /// The client-side end of a network connection.
 ///
 /// Sends [`ServerboundEventEnum`]s to the server and receives
 /// [`ClientboundEventEnum`]s from the server.
+///
+/// Requires the [`Scoreboard`], [`Teams`], [`TabList`], [`BossBars`],
+/// [`Titles`], and [`WorldBorder`] of the connection, see
+/// [`NetworkPlugin::track_state`](crate::bevy::NetworkPlugin::track_state).
 #[derive(Component)]
+#[require(Scoreboard, Teams, TabList, BossBars, Titles, WorldBorder)]
 pub struct ClientConnection {
     sender: Box<SenderFn>,
     receiver: Box<ReceiverFn>,
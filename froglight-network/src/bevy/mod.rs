@@ -1,8 +1,15 @@
 //! TODO
 
+use std::time::Instant;
+
 use bevy_app::{App, Plugin};
 use bevy_ecs::{prelude::*, resource::IsResource};
 
+use crate::{
+    event::enums::{ClientboundEventEnum, ClientboundPlayEvent},
+    state::{BossBars, Scoreboard, TabList, Teams, Titles, WorldBorder},
+};
+
 mod client;
 pub use client::{ClientConnection, ClientDespawn};
 
@@ -12,6 +19,16 @@ pub use event::{ClientboundMessage, ServerboundMessage};
 pub mod version;
 pub use version::NetworkVersion;
 
+/// The state of a connection kept in sync by [`NetworkPlugin::track_state`].
+type ConnectionState = (
+    &'static mut Scoreboard,
+    &'static mut Teams,
+    &'static mut TabList,
+    &'static mut BossBars,
+    &'static mut Titles,
+    &'static mut WorldBorder,
+);
+
 /// A [`Plugin`] that adds [`ClientboundMessage`] and [`ServerboundMessage`]s
 /// and provides systems for sending and receiving them.
 ///
@@ -28,6 +45,8 @@ impl Plugin for NetworkPlugin {
         app.register_type::<ClientboundMessage>().add_message::<ClientboundMessage>();
         app.register_type::<ServerboundMessage>().add_message::<ServerboundMessage>();
         app.register_type::<ClientDespawn>();
+        app.register_type::<Scoreboard>().register_type::<Teams>().register_type::<TabList>();
+        app.register_type::<BossBars>().register_type::<Titles>().register_type::<WorldBorder>();
     }
 }
 
@@ -95,6 +114,79 @@ impl NetworkPlugin {
         }
     }
 
+    /// A [`System`] that keeps the [`Scoreboard`], [`Teams`], [`TabList`],
+    /// [`BossBars`], [`Titles`], and [`WorldBorder`] of each connection in sync
+    /// with the [`ClientboundMessage`]s it received.
+    ///
+    /// All state is cleared when the server sends a new
+    /// [`ClientboundPlayEvent::Login`].
+    ///
+    /// # Note
+    ///
+    /// This [`System`] is not scheduled by default! You must add it manually!
+    pub fn track_state(
+        mut reader: MessageReader<ClientboundMessage>,
+        mut query: Query<ConnectionState, Without<IsResource>>,
+    ) {
+        for message in reader.read() {
+            let ClientboundEventEnum::Play(event) = message.event() else { continue };
+            let Ok((
+                mut scoreboard,
+                mut teams,
+                mut tab_list,
+                mut boss_bars,
+                mut titles,
+                mut border,
+            )) = query.get_mut(message.source())
+            else {
+                continue;
+            };
+            let now = Instant::now();
+
+            match event {
+                ClientboundPlayEvent::Login(_) => {
+                    scoreboard.clear();
+                    teams.clear();
+                    tab_list.clear();
+                    boss_bars.clear();
+                    titles.clear();
+                    *border = WorldBorder::default();
+                }
+                ClientboundPlayEvent::SetObjective(update) => scoreboard.apply_objective(update),
+                ClientboundPlayEvent::SetScore(update) => scoreboard.apply_score(update),
+                ClientboundPlayEvent::ResetScore(reset) => scoreboard.apply_reset(reset),
+                ClientboundPlayEvent::SetDisplayObjective(display) => {
+                    scoreboard.apply_display(display);
+                }
+                ClientboundPlayEvent::SetPlayerTeam(update) => teams.apply(update),
+                ClientboundPlayEvent::PlayerInfoUpdate(update) => tab_list.apply_update(update),
+                ClientboundPlayEvent::PlayerInfoRemove(profiles) => tab_list.apply_remove(profiles),
+                ClientboundPlayEvent::TabList(text) => tab_list.apply_text(text),
+                ClientboundPlayEvent::BossEvent(event) => boss_bars.apply(event),
+                ClientboundPlayEvent::SetTitleText(text) => titles.set_title(text.clone(), now),
+                ClientboundPlayEvent::SetSubtitleText(text) => titles.set_subtitle(text.clone()),
+                ClientboundPlayEvent::SetTitleAnimation(times) => titles.set_times(*times, now),
+                ClientboundPlayEvent::ClearTitles(reset) => titles.clear_titles(*reset),
+                ClientboundPlayEvent::ActionBarText(text) => {
+                    titles.set_action_bar(text.clone(), now);
+                }
+                ClientboundPlayEvent::InitializeBorder(init) => {
+                    *border = WorldBorder::new(init, now);
+                }
+                ClientboundPlayEvent::SetBorderCenter(x, z) => border.set_center(*x, *z),
+                ClientboundPlayEvent::SetBorderLerpSize(old_size, new_size, millis) => {
+                    border.lerp_size(*old_size, *new_size, *millis, now);
+                }
+                ClientboundPlayEvent::SetBorderSize(size) => border.set_size(*size, now),
+                ClientboundPlayEvent::SetBorderWarningDelay(time) => border.warning_time = *time,
+                ClientboundPlayEvent::SetBorderWarningDistance(blocks) => {
+                    border.warning_blocks = *blocks;
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// A [`System`] that polls [`ClientConnection`]s for completion.
     ///
     /// # Note
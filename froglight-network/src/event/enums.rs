@@ -6,27 +6,38 @@ use facet::Facet;
 use froglight_block::state::GlobalStateId;
 use froglight_common::prelude::Identifier;
 use froglight_entity::prelude::EntityId;
+use froglight_nbt::prelude::IndexedNbtCow;
 use froglight_packet::common::{
+    boss_event::BossEvent,
     chunk_data::RawChunkData,
     client_information::ClientInformation,
+    commands::{CommandSuggestionsContent, CommandTree},
     entity_data::{AddEntityBundle, SetEntityBundle},
     handshake::HandshakeContent,
     known_packs::KnownResourcePack,
     light_data::RawLightData,
     login::{LoginHelloContent, PlayLoginContent},
     lpdvec3::LpDVec3,
+    player_info::{PlayerInfoUpdate, TabListText},
     position::{EntityPositionRotationData, EntityPositionUpdateData, EntityRelativeFlags},
     registry::RegistryDataEntry,
+    scoreboard::{DisplayObjective, ObjectiveUpdate, ScoreReset, ScoreUpdate, TeamUpdate},
+    spawn_info::{PlayerDataKept, PlayerSpawnInfo},
+    text::NetworkText,
+    title::TitleTimes,
     unsized_buffer::UnsizedBuffer,
     update_tags::TagMap,
+    world_border::WorldBorderInit,
 };
 use froglight_player::prelude::PlayerProfile;
 use froglight_world::{component::BlockPos, prelude::ChunkPos};
+use uuid::Uuid;
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Facet)]
 #[cfg_attr(feature = "bevy", derive(Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[expect(clippy::large_enum_variant, reason = "Play events are the most common")]
 pub enum ClientboundEventEnum {
     Play(ClientboundPlayEvent),
     Config(ClientboundConfigEvent),
@@ -83,16 +94,16 @@ impl From<ServerboundHandshakeEvent> for ServerboundEventEnum {
 #[cfg_attr(feature = "bevy", derive(Reflect))]
 #[cfg_attr(feature = "bevy", reflect(opaque, Debug, Clone, PartialEq))]
 pub enum ClientboundPlayEvent {
-    ActionBarText(),
+    ActionBarText(NetworkText),
     AddEntity(AddEntityBundle),
     Animate(),
     AwardStats(),
     BlockChangedAck(),
     BlockDestruction(),
-    BlockEntityData(),
+    BlockEntityData(BlockPos, u32, IndexedNbtCow<'static>),
     BlockEvent(),
     BlockUpdate(BlockPos, GlobalStateId),
-    BossEvent(),
+    BossEvent(BossEvent),
     BundleDelimiter,
     ChangeDifficulty(),
     ChatSuggestions(),
@@ -104,9 +115,9 @@ pub enum ClientboundPlayEvent {
     ChunkSectionUpdate(),
     ChunkWithLight(ChunkPos, RawChunkData, RawLightData),
     ClearDialog,
-    ClearTitles(),
-    CommandSuggestions(),
-    Commands(),
+    ClearTitles(bool),
+    CommandSuggestions(CommandSuggestionsContent),
+    Commands(CommandTree),
     ContainerClose(),
     ContainerContent(),
     ContainerData(),
@@ -134,7 +145,7 @@ pub enum ClientboundPlayEvent {
     GameTestHighlight(),
     GhostRecipe(),
     HurtAnimation(),
-    InitializeBorder(),
+    InitializeBorder(WorldBorderInit),
     KeepAlive(u64),
     LevelEvent(),
     LevelParticles(),
@@ -157,8 +168,8 @@ pub enum ClientboundPlayEvent {
     PlayerCombatEnd(),
     PlayerCombatEnter(),
     PlayerCombatKill(),
-    PlayerInfoRemove(),
-    PlayerInfoUpdate(),
+    PlayerInfoRemove(Vec<Uuid>),
+    PlayerInfoUpdate(PlayerInfoUpdate),
     PlayerLookAt(),
     PlayerPosition(u32, EntityPositionRotationData, EntityRelativeFlags),
     PlayerRotation(),
@@ -169,23 +180,23 @@ pub enum ClientboundPlayEvent {
     RecipeBookSettings(),
     RemoveEntities(Vec<EntityId>),
     RemoveMobEffect(),
-    ResetScore(),
+    ResetScore(ScoreReset),
     ResourcePackPop(),
     ResourcePackPush(),
-    Respawn(),
+    Respawn(PlayerSpawnInfo, PlayerDataKept),
     RotateHead(),
     SelectAdvancementTab(),
     ServerData(),
     ServerLinks(),
-    SetBorderCenter(),
-    SetBorderLerpSize(),
-    SetBorderSize(),
-    SetBorderWarningDelay(),
-    SetBorderWarningDistance(),
+    SetBorderCenter(f64, f64),
+    SetBorderLerpSize(f64, f64, u64),
+    SetBorderSize(f64),
+    SetBorderWarningDelay(u32),
+    SetBorderWarningDistance(u32),
     SetCamera(),
     SetCursorItem(),
     SetDefaultSpawn(),
-    SetDisplayObjective(),
+    SetDisplayObjective(DisplayObjective),
     SetEntityData(SetEntityBundle),
     SetEntityLink(),
     SetEntityMotion(EntityId, LpDVec3),
@@ -193,16 +204,16 @@ pub enum ClientboundPlayEvent {
     SetExperience(),
     SetHealth(),
     SetHeldSlot(),
-    SetObjective(),
+    SetObjective(ObjectiveUpdate),
     SetPassengers(),
     SetPlayerInventory(),
-    SetPlayerTeam(),
-    SetScore(),
+    SetPlayerTeam(TeamUpdate),
+    SetScore(ScoreUpdate),
     SetSimulationDistance(),
-    SetSubtitleText(),
+    SetSubtitleText(NetworkText),
     SetTime(),
-    SetTitleAnimation(),
-    SetTitleText(),
+    SetTitleAnimation(TitleTimes),
+    SetTitleText(NetworkText),
     ShowDialog(),
     Sound(),
     SoundEntity(),
@@ -210,7 +221,7 @@ pub enum ClientboundPlayEvent {
     StopSound(),
     StoreCookie(),
     SystemChat(),
-    TabList(),
+    TabList(TabListText),
     TagQuery(),
     TakeItemEntity(),
     TeleportEntity(EntityId, EntityPositionRotationData, EntityRelativeFlags, bool),
@@ -241,9 +252,9 @@ pub enum ServerboundPlayEvent {
     ChangeDifficulty(),
     ChangeGameMode(),
     ChatAcknowledge(),
-    ChatCommand(),
+    ChatCommand(String),
     ChatCommandSigned(),
-    ChatCommandSuggestion(),
+    ChatCommandSuggestion(u32, String),
     ChatMessage(),
     ChatSessionUpdate(),
     ChunkBatchReceived(f32),
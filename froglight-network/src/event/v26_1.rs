@@ -30,20 +30,29 @@ use froglight_packet::{
             ServerboundPackets as LoginServerboundPackets,
         },
         play::{
-            AcceptTeleportationC2SPacket, AddEntityS2CPacket, BlockUpdateS2CPacket,
-            BundleDelimiterS2CPacket, ChunkBatchFinishedS2CPacket, ChunkBatchReceivedC2SPacket,
+            AcceptTeleportationC2SPacket, AddEntityS2CPacket, BlockEntityDataS2CPacket,
+            BlockUpdateS2CPacket, BossEventS2CPacket, BundleDelimiterS2CPacket,
+            ChatCommandC2SPacket, ChunkBatchFinishedS2CPacket, ChunkBatchReceivedC2SPacket,
             ChunkBatchStartS2CPacket, ClearDialogS2CPacket as PlayClearDialogS2CPacket,
-            ClientboundPackets as PlayClientboundPackets,
+            ClearTitlesS2CPacket, ClientboundPackets as PlayClientboundPackets,
+            CommandSuggestionC2SPacket, CommandSuggestionsS2CPacket, CommandsS2CPacket,
             CustomPayloadS2CPacket as PlayCustomPayloadS2CPacket,
             DisconnectS2CPacket as PlayDisconnectS2CPacket, EntityPositionSyncS2CPacket,
-            ForgetLevelChunkS2CPacket, KeepAliveC2SPacket as PlayKeepAliveC2SPacket,
+            ForgetLevelChunkS2CPacket, InitializeBorderS2CPacket,
+            KeepAliveC2SPacket as PlayKeepAliveC2SPacket,
             KeepAliveS2CPacket as PlayKeepAliveS2CPacket, LevelChunkWithLightS2CPacket,
             LightUpdateS2CPacket, LoginS2CPacket, MoveEntityPosRotS2CPacket,
             MoveEntityPosS2CPacket, MoveEntityRotS2CPacket,
-            PingRequestC2SPacket as PlayPingRequestC2SPacket, PlayerPositionS2CPacket,
-            PongC2SPacket as PlayPongC2SPacket, PongResponseS2CPacket as PlayPongResponseS2CPacket,
-            RemoveEntitiesS2CPacket, ServerboundPackets as PlayServerboundPackets,
-            SetEntityMotionS2CPacket, TeleportEntityS2CPacket,
+            PingRequestC2SPacket as PlayPingRequestC2SPacket, PlayerInfoRemoveS2CPacket,
+            PlayerInfoUpdateS2CPacket, PlayerPositionS2CPacket, PongC2SPacket as PlayPongC2SPacket,
+            PongResponseS2CPacket as PlayPongResponseS2CPacket, RemoveEntitiesS2CPacket,
+            ResetScoreS2CPacket, RespawnS2CPacket, ServerboundPackets as PlayServerboundPackets,
+            SetActionBarTextS2CPacket, SetBorderCenterS2CPacket, SetBorderLerpSizeS2CPacket,
+            SetBorderSizeS2CPacket, SetBorderWarningDelayS2CPacket,
+            SetBorderWarningDistanceS2CPacket, SetDisplayObjectiveS2CPacket,
+            SetEntityMotionS2CPacket, SetObjectiveS2CPacket, SetPlayerTeamS2CPacket,
+            SetScoreS2CPacket, SetSubtitleTextS2CPacket, SetTitleTextS2CPacket,
+            SetTitlesAnimationS2CPacket, TabListS2CPacket, TeleportEntityS2CPacket,
         },
     },
     version::{Clientbound, Serverbound, VersionPacket},
@@ -185,8 +194,8 @@ impl EventVersion for V26_1 {
             },
 
             ClientboundEventEnum::Play(play) => match play {
-                ClientboundPlayEvent::ActionBarText() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::ActionBarText(text) => {
+                    let packet = SetActionBarTextS2CPacket { text };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetActionBarText(packet))))
                 }
                 ClientboundPlayEvent::AddEntity(entity_data) => {
@@ -209,8 +218,8 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::BlockDestruction(packet))))
                 }
-                ClientboundPlayEvent::BlockEntityData() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::BlockEntityData(position, kind, nbt) => {
+                    let packet = BlockEntityDataS2CPacket { position, kind, nbt };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::BlockEntityData(packet))))
                 }
                 ClientboundPlayEvent::BlockEvent() => {
@@ -221,8 +230,8 @@ impl EventVersion for V26_1 {
                     let packet = BlockUpdateS2CPacket { position, block_id };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::BlockUpdate(packet))))
                 }
-                ClientboundPlayEvent::BossEvent() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::BossEvent(event) => {
+                    let packet = BossEventS2CPacket { event };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::BossEvent(packet))))
                 }
                 ClientboundPlayEvent::BundleDelimiter => Ok(Some(VersionPacket::Play(
@@ -283,18 +292,18 @@ impl EventVersion for V26_1 {
                 ClientboundPlayEvent::ClearDialog => Ok(Some(VersionPacket::Play(
                     PlayClientboundPackets::ClearDialog(PlayClearDialogS2CPacket),
                 ))),
-                ClientboundPlayEvent::ClearTitles() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::ClearTitles(reset) => {
+                    let packet = ClearTitlesS2CPacket { reset };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::ClearTitles(packet))))
                 }
-                ClientboundPlayEvent::CommandSuggestions() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::CommandSuggestions(content) => {
+                    let packet = CommandSuggestionsS2CPacket(content);
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::CommandSuggestions(
                         packet,
                     ))))
                 }
-                ClientboundPlayEvent::Commands() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::Commands(tree) => {
+                    let packet = CommandsS2CPacket { tree };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::Commands(packet))))
                 }
                 ClientboundPlayEvent::ContainerClose() => {
@@ -415,8 +424,8 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::HurtAnimation(packet))))
                 }
-                ClientboundPlayEvent::InitializeBorder() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::InitializeBorder(border) => {
+                    let packet = InitializeBorderS2CPacket { border };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::InitializeBorder(packet))))
                 }
                 ClientboundPlayEvent::KeepAlive(id) => {
@@ -537,12 +546,12 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::PlayerCombatKill(packet))))
                 }
-                ClientboundPlayEvent::PlayerInfoRemove() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::PlayerInfoRemove(profiles) => {
+                    let packet = PlayerInfoRemoveS2CPacket { profiles };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::PlayerInfoRemove(packet))))
                 }
-                ClientboundPlayEvent::PlayerInfoUpdate() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::PlayerInfoUpdate(update) => {
+                    let packet = PlayerInfoUpdateS2CPacket { update };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::PlayerInfoUpdate(packet))))
                 }
                 ClientboundPlayEvent::PlayerLookAt() => {
@@ -586,8 +595,8 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::RemoveMobEffect(packet))))
                 }
-                ClientboundPlayEvent::ResetScore() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::ResetScore(reset) => {
+                    let packet = ResetScoreS2CPacket { reset };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::ResetScore(packet))))
                 }
                 ClientboundPlayEvent::ResourcePackPop() => {
@@ -598,8 +607,8 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::ResourcePackPush(packet))))
                 }
-                ClientboundPlayEvent::Respawn() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::Respawn(spawn_info, data_kept) => {
+                    let packet = RespawnS2CPacket { spawn_info, data_kept };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::Respawn(packet))))
                 }
                 ClientboundPlayEvent::RotateHead() => {
@@ -620,26 +629,26 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::ServerLinks(packet))))
                 }
-                ClientboundPlayEvent::SetBorderCenter() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetBorderCenter(center_x, center_z) => {
+                    let packet = SetBorderCenterS2CPacket { center_x, center_z };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetBorderCenter(packet))))
                 }
-                ClientboundPlayEvent::SetBorderLerpSize() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetBorderLerpSize(old_size, new_size, lerp_time) => {
+                    let packet = SetBorderLerpSizeS2CPacket { old_size, new_size, lerp_time };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetBorderLerpSize(packet))))
                 }
-                ClientboundPlayEvent::SetBorderSize() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetBorderSize(size) => {
+                    let packet = SetBorderSizeS2CPacket { size };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetBorderSize(packet))))
                 }
-                ClientboundPlayEvent::SetBorderWarningDelay() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetBorderWarningDelay(warning_time) => {
+                    let packet = SetBorderWarningDelayS2CPacket { warning_time };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetBorderWarningDelay(
                         packet,
                     ))))
                 }
-                ClientboundPlayEvent::SetBorderWarningDistance() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetBorderWarningDistance(warning_blocks) => {
+                    let packet = SetBorderWarningDistanceS2CPacket { warning_blocks };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetBorderWarningDistance(
                         packet,
                     ))))
@@ -658,8 +667,8 @@ impl EventVersion for V26_1 {
                         packet,
                     ))))
                 }
-                ClientboundPlayEvent::SetDisplayObjective() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetDisplayObjective(display) => {
+                    let packet = SetDisplayObjectiveS2CPacket { display };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetDisplayObjective(
                         packet,
                     ))))
@@ -692,8 +701,8 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetHeldSlot(packet))))
                 }
-                ClientboundPlayEvent::SetObjective() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetObjective(objective) => {
+                    let packet = SetObjectiveS2CPacket { objective };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetObjective(packet))))
                 }
                 ClientboundPlayEvent::SetPassengers() => {
@@ -706,12 +715,12 @@ impl EventVersion for V26_1 {
                         packet,
                     ))))
                 }
-                ClientboundPlayEvent::SetPlayerTeam() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetPlayerTeam(team) => {
+                    let packet = SetPlayerTeamS2CPacket { team };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetPlayerTeam(packet))))
                 }
-                ClientboundPlayEvent::SetScore() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetScore(score) => {
+                    let packet = SetScoreS2CPacket { score };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetScore(packet))))
                 }
                 ClientboundPlayEvent::SetSimulationDistance() => {
@@ -720,22 +729,22 @@ impl EventVersion for V26_1 {
                         packet,
                     ))))
                 }
-                ClientboundPlayEvent::SetSubtitleText() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetSubtitleText(text) => {
+                    let packet = SetSubtitleTextS2CPacket { text };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetSubtitleText(packet))))
                 }
                 ClientboundPlayEvent::SetTime() => {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetTime(packet))))
                 }
-                ClientboundPlayEvent::SetTitleAnimation() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetTitleAnimation(times) => {
+                    let packet = SetTitlesAnimationS2CPacket { times };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetTitlesAnimation(
                         packet,
                     ))))
                 }
-                ClientboundPlayEvent::SetTitleText() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::SetTitleText(text) => {
+                    let packet = SetTitleTextS2CPacket { text };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SetTitleText(packet))))
                 }
                 ClientboundPlayEvent::ShowDialog() => {
@@ -768,8 +777,8 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::SystemChat(packet))))
                 }
-                ClientboundPlayEvent::TabList() => {
-                    let packet = todo!();
+                ClientboundPlayEvent::TabList(text) => {
+                    let packet = TabListS2CPacket { text };
                     Ok(Some(VersionPacket::Play(PlayClientboundPackets::TabList(packet))))
                 }
                 ClientboundPlayEvent::TagQuery() => {
@@ -965,8 +974,12 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::BlockDestruction(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::BlockDestruction())))
                 }
-                PlayClientboundPackets::BlockEntityData(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::BlockEntityData())))
+                PlayClientboundPackets::BlockEntityData(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::BlockEntityData(
+                        packet.position,
+                        packet.kind,
+                        packet.nbt,
+                    ))))
                 }
                 PlayClientboundPackets::BlockEvent(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::BlockEvent())))
@@ -977,9 +990,9 @@ impl EventVersion for V26_1 {
                         packet.block_id,
                     ))))
                 }
-                PlayClientboundPackets::BossEvent(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::BossEvent())))
-                }
+                PlayClientboundPackets::BossEvent(packet) => Ok(Some(ClientboundEventEnum::Play(
+                    ClientboundPlayEvent::BossEvent(packet.event),
+                ))),
                 PlayClientboundPackets::ChangeDifficulty(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ChangeDifficulty())))
                 }
@@ -994,15 +1007,15 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::ChunksBiomes(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ChunkBiomes())))
                 }
-                PlayClientboundPackets::ClearTitles(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ClearTitles())))
-                }
-                PlayClientboundPackets::CommandSuggestions(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::CommandSuggestions())))
-                }
-                PlayClientboundPackets::Commands(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::Commands())))
-                }
+                PlayClientboundPackets::ClearTitles(packet) => Ok(Some(
+                    ClientboundEventEnum::Play(ClientboundPlayEvent::ClearTitles(packet.reset)),
+                )),
+                PlayClientboundPackets::CommandSuggestions(packet) => Ok(Some(
+                    ClientboundEventEnum::Play(ClientboundPlayEvent::CommandSuggestions(packet.0)),
+                )),
+                PlayClientboundPackets::Commands(packet) => Ok(Some(ClientboundEventEnum::Play(
+                    ClientboundPlayEvent::Commands(packet.tree),
+                ))),
                 PlayClientboundPackets::ContainerClose(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ContainerClose())))
                 }
@@ -1088,8 +1101,10 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::HurtAnimation(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::HurtAnimation())))
                 }
-                PlayClientboundPackets::InitializeBorder(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::InitializeBorder())))
+                PlayClientboundPackets::InitializeBorder(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::InitializeBorder(
+                        packet.border,
+                    ))))
                 }
                 PlayClientboundPackets::KeepAlive(packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::KeepAlive(packet.id))))
@@ -1175,11 +1190,15 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::PlayerCombatKill(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::PlayerCombatKill())))
                 }
-                PlayClientboundPackets::PlayerInfoRemove(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::PlayerInfoRemove())))
+                PlayClientboundPackets::PlayerInfoRemove(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::PlayerInfoRemove(
+                        packet.profiles,
+                    ))))
                 }
-                PlayClientboundPackets::PlayerInfoUpdate(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::PlayerInfoUpdate())))
+                PlayClientboundPackets::PlayerInfoUpdate(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::PlayerInfoUpdate(
+                        packet.update,
+                    ))))
                 }
                 PlayClientboundPackets::PlayerLookAt(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::PlayerLookAt())))
@@ -1211,18 +1230,18 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::RemoveMobEffect(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::RemoveMobEffect())))
                 }
-                PlayClientboundPackets::ResetScore(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ResetScore())))
-                }
+                PlayClientboundPackets::ResetScore(packet) => Ok(Some(ClientboundEventEnum::Play(
+                    ClientboundPlayEvent::ResetScore(packet.reset),
+                ))),
                 PlayClientboundPackets::ResourcePackPop(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ResourcePackPop())))
                 }
                 PlayClientboundPackets::ResourcePackPush(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ResourcePackPush())))
                 }
-                PlayClientboundPackets::Respawn(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::Respawn())))
-                }
+                PlayClientboundPackets::Respawn(packet) => Ok(Some(ClientboundEventEnum::Play(
+                    ClientboundPlayEvent::Respawn(packet.spawn_info, packet.data_kept),
+                ))),
                 PlayClientboundPackets::RotateHead(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::RotateHead())))
                 }
@@ -1235,24 +1254,35 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::ServerData(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ServerData())))
                 }
-                PlayClientboundPackets::SetActionBarText(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::ActionBarText())))
+                PlayClientboundPackets::SetActionBarText(packet) => Ok(Some(
+                    ClientboundEventEnum::Play(ClientboundPlayEvent::ActionBarText(packet.text)),
+                )),
+                PlayClientboundPackets::SetBorderCenter(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetBorderCenter(
+                        packet.center_x,
+                        packet.center_z,
+                    ))))
                 }
-                PlayClientboundPackets::SetBorderCenter(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetBorderCenter())))
+                PlayClientboundPackets::SetBorderLerpSize(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetBorderLerpSize(
+                        packet.old_size,
+                        packet.new_size,
+                        packet.lerp_time,
+                    ))))
                 }
-                PlayClientboundPackets::SetBorderLerpSize(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetBorderLerpSize())))
+                PlayClientboundPackets::SetBorderSize(packet) => Ok(Some(
+                    ClientboundEventEnum::Play(ClientboundPlayEvent::SetBorderSize(packet.size)),
+                )),
+                PlayClientboundPackets::SetBorderWarningDelay(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(
+                        ClientboundPlayEvent::SetBorderWarningDelay(packet.warning_time),
+                    )))
                 }
-                PlayClientboundPackets::SetBorderSize(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetBorderSize())))
+                PlayClientboundPackets::SetBorderWarningDistance(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(
+                        ClientboundPlayEvent::SetBorderWarningDistance(packet.warning_blocks),
+                    )))
                 }
-                PlayClientboundPackets::SetBorderWarningDelay(_packet) => Ok(Some(
-                    ClientboundEventEnum::Play(ClientboundPlayEvent::SetBorderWarningDelay()),
-                )),
-                PlayClientboundPackets::SetBorderWarningDistance(_packet) => Ok(Some(
-                    ClientboundEventEnum::Play(ClientboundPlayEvent::SetBorderWarningDistance()),
-                )),
                 PlayClientboundPackets::SetCamera(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetCamera())))
                 }
@@ -1268,9 +1298,11 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::SetDefaultSpawnPosition(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetDefaultSpawn())))
                 }
-                PlayClientboundPackets::SetDisplayObjective(_packet) => Ok(Some(
-                    ClientboundEventEnum::Play(ClientboundPlayEvent::SetDisplayObjective()),
-                )),
+                PlayClientboundPackets::SetDisplayObjective(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetDisplayObjective(
+                        packet.display,
+                    ))))
+                }
                 PlayClientboundPackets::SetEntityData(packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetEntityData(
                         SetEntityBundle::new::<Self>(packet.entity_id, packet.metadata),
@@ -1297,8 +1329,10 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::SetHeldSlot(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetHeldSlot())))
                 }
-                PlayClientboundPackets::SetObjective(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetObjective())))
+                PlayClientboundPackets::SetObjective(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetObjective(
+                        packet.objective,
+                    ))))
                 }
                 PlayClientboundPackets::SetPassengers(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetPassengers())))
@@ -1306,26 +1340,28 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::SetPlayerInventory(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetPlayerInventory())))
                 }
-                PlayClientboundPackets::SetPlayerTeam(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetPlayerTeam())))
-                }
-                PlayClientboundPackets::SetScore(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetScore())))
-                }
+                PlayClientboundPackets::SetPlayerTeam(packet) => Ok(Some(
+                    ClientboundEventEnum::Play(ClientboundPlayEvent::SetPlayerTeam(packet.team)),
+                )),
+                PlayClientboundPackets::SetScore(packet) => Ok(Some(ClientboundEventEnum::Play(
+                    ClientboundPlayEvent::SetScore(packet.score),
+                ))),
                 PlayClientboundPackets::SetSimulationDistance(_packet) => Ok(Some(
                     ClientboundEventEnum::Play(ClientboundPlayEvent::SetSimulationDistance()),
                 )),
-                PlayClientboundPackets::SetSubtitleText(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetSubtitleText())))
-                }
+                PlayClientboundPackets::SetSubtitleText(packet) => Ok(Some(
+                    ClientboundEventEnum::Play(ClientboundPlayEvent::SetSubtitleText(packet.text)),
+                )),
                 PlayClientboundPackets::SetTime(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetTime())))
                 }
-                PlayClientboundPackets::SetTitleText(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetTitleText())))
-                }
-                PlayClientboundPackets::SetTitlesAnimation(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetTitleAnimation())))
+                PlayClientboundPackets::SetTitleText(packet) => Ok(Some(
+                    ClientboundEventEnum::Play(ClientboundPlayEvent::SetTitleText(packet.text)),
+                )),
+                PlayClientboundPackets::SetTitlesAnimation(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SetTitleAnimation(
+                        packet.times,
+                    ))))
                 }
                 PlayClientboundPackets::SoundEntity(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SoundEntity())))
@@ -1345,8 +1381,8 @@ impl EventVersion for V26_1 {
                 PlayClientboundPackets::SystemChat(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::SystemChat())))
                 }
-                PlayClientboundPackets::TabList(_packet) => {
-                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::TabList())))
+                PlayClientboundPackets::TabList(packet) => {
+                    Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::TabList(packet.text))))
                 }
                 PlayClientboundPackets::TagQuery(_packet) => {
                     Ok(Some(ClientboundEventEnum::Play(ClientboundPlayEvent::TagQuery())))
@@ -1544,16 +1580,16 @@ impl EventVersion for V26_1 {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayServerboundPackets::ChatAck(packet))))
                 }
-                ServerboundPlayEvent::ChatCommand() => {
-                    let packet = todo!();
+                ServerboundPlayEvent::ChatCommand(command) => {
+                    let packet = ChatCommandC2SPacket { command };
                     Ok(Some(VersionPacket::Play(PlayServerboundPackets::ChatCommand(packet))))
                 }
                 ServerboundPlayEvent::ChatCommandSigned() => {
                     let packet = todo!();
                     Ok(Some(VersionPacket::Play(PlayServerboundPackets::ChatCommandSigned(packet))))
                 }
-                ServerboundPlayEvent::ChatCommandSuggestion() => {
-                    let packet = todo!();
+                ServerboundPlayEvent::ChatCommandSuggestion(id, command) => {
+                    let packet = CommandSuggestionC2SPacket { id, command };
                     Ok(Some(VersionPacket::Play(PlayServerboundPackets::CommandSuggestion(packet))))
                 }
                 ServerboundPlayEvent::ChatMessage() => {
@@ -1913,9 +1949,9 @@ impl EventVersion for V26_1 {
                 PlayServerboundPackets::ChatAck(_packet) => {
                     Ok(Some(ServerboundEventEnum::Play(ServerboundPlayEvent::ChatAcknowledge())))
                 }
-                PlayServerboundPackets::ChatCommand(_packet) => {
-                    Ok(Some(ServerboundEventEnum::Play(ServerboundPlayEvent::ChatCommand())))
-                }
+                PlayServerboundPackets::ChatCommand(packet) => Ok(Some(
+                    ServerboundEventEnum::Play(ServerboundPlayEvent::ChatCommand(packet.command)),
+                )),
                 PlayServerboundPackets::ChatCommandSigned(_packet) => {
                     Ok(Some(ServerboundEventEnum::Play(ServerboundPlayEvent::ChatCommandSigned())))
                 }
@@ -1939,9 +1975,11 @@ impl EventVersion for V26_1 {
                 PlayServerboundPackets::ClientInformation(_packet) => {
                     Ok(Some(ServerboundEventEnum::Play(ServerboundPlayEvent::ClientInformation())))
                 }
-                PlayServerboundPackets::CommandSuggestion(_packet) => Ok(Some(
-                    ServerboundEventEnum::Play(ServerboundPlayEvent::ChatCommandSuggestion()),
-                )),
+                PlayServerboundPackets::CommandSuggestion(packet) => {
+                    Ok(Some(ServerboundEventEnum::Play(
+                        ServerboundPlayEvent::ChatCommandSuggestion(packet.id, packet.command),
+                    )))
+                }
                 PlayServerboundPackets::ConfigurationAcknowledged(_) => Ok(Some(
                     ServerboundEventEnum::Play(ServerboundPlayEvent::AcknowledgeConfiguration),
                 )),
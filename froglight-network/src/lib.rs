@@ -6,11 +6,15 @@
 pub mod bevy;
 pub mod connection;
 pub mod event;
+pub mod state;
 
 pub mod prelude {
     //! Re-exports of common types, traits, and macros.
 
     #[cfg(feature = "bevy")]
     pub use crate::bevy::{ClientConnection, ClientboundMessage, ServerboundMessage};
-    pub use crate::event::enums::{ClientboundEventEnum, ServerboundEventEnum};
+    pub use crate::{
+        event::enums::{ClientboundEventEnum, ServerboundEventEnum},
+        state::{BossBars, Scoreboard, TabList, Teams, Titles, WorldBorder},
+    };
 }
@@ -0,0 +1,83 @@
+#[cfg(feature = "bevy")]
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::boss_event::{BossBarData, BossEvent, BossEventAction};
+use indexmap::IndexMap;
+use uuid::Uuid;
+
+/// The boss bars of a connection.
+///
+/// Boss bars are kept in the order they were added,
+/// which is the order they are shown from top to bottom.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component, Reflect))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Default, Clone, PartialEq, Component))]
+pub struct BossBars {
+    bars: IndexMap<Uuid, BossBarData>,
+}
+
+impl BossBars {
+    /// Create a new, empty [`BossBars`].
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Get a boss bar by [`Uuid`].
+    #[must_use]
+    pub fn get(&self, id: &Uuid) -> Option<&BossBarData> { self.bars.get(id) }
+
+    /// Find the first boss bar with the given plain text name.
+    #[must_use]
+    pub fn get_by_name(&self, name: &str) -> Option<(&Uuid, &BossBarData)> {
+        self.bars.iter().find(|(_, bar)| bar.name.as_text() == Some(name))
+    }
+
+    /// Iterate over all boss bars, from top to bottom.
+    pub fn iter(&self) -> impl Iterator<Item = (&Uuid, &BossBarData)> { self.bars.iter() }
+
+    /// Get the number of boss bars.
+    #[must_use]
+    pub fn len(&self) -> usize { self.bars.len() }
+
+    /// Returns `true` if there are no boss bars.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.bars.is_empty() }
+
+    /// Remove all boss bars.
+    pub fn clear(&mut self) { self.bars.clear(); }
+
+    /// Apply a [`BossEvent`].
+    ///
+    /// Updates for unknown boss bars are ignored.
+    pub fn apply(&mut self, event: &BossEvent) {
+        match &event.action {
+            BossEventAction::Add(data) => {
+                self.bars.insert(event.id, data.clone());
+            }
+            BossEventAction::Remove => {
+                self.bars.shift_remove(&event.id);
+            }
+            BossEventAction::UpdateProgress(progress) => {
+                if let Some(bar) = self.bars.get_mut(&event.id) {
+                    bar.progress = *progress;
+                }
+            }
+            BossEventAction::UpdateName(name) => {
+                if let Some(bar) = self.bars.get_mut(&event.id) {
+                    bar.name.clone_from(name);
+                }
+            }
+            BossEventAction::UpdateStyle(color, overlay) => {
+                if let Some(bar) = self.bars.get_mut(&event.id) {
+                    bar.color = *color;
+                    bar.overlay = *overlay;
+                }
+            }
+            BossEventAction::UpdateProperties(flags) => {
+                if let Some(bar) = self.bars.get_mut(&event.id) {
+                    bar.flags = *flags;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! TODO
+
+mod boss_bar;
+pub use boss_bar::BossBars;
+
+mod scoreboard;
+pub use scoreboard::{Objective, Score, Scoreboard};
+
+mod tab_list;
+pub use tab_list::{TabList, TabListEntry};
+
+mod team;
+pub use team::{Team, Teams};
+
+mod title;
+pub use title::Titles;
+
+mod world_border;
+pub use world_border::{WorldBorder, WorldBorderBounds};
@@ -0,0 +1,170 @@
+#[cfg(feature = "bevy")]
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::{
+    scoreboard::{
+        DisplayObjective, DisplaySlot, NumberFormat, ObjectiveAction, ObjectiveData,
+        ObjectiveRenderType, ObjectiveUpdate, ScoreReset, ScoreUpdate,
+    },
+    text::NetworkText,
+};
+use indexmap::IndexMap;
+
+/// The scoreboard of a connection.
+///
+/// Contains all objectives, their scores, and where they are displayed.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component, Reflect))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Default, Clone, PartialEq, Component))]
+pub struct Scoreboard {
+    objectives: IndexMap<String, Objective>,
+    display: IndexMap<DisplaySlot, String>,
+}
+
+/// A scoreboard objective and its scores.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Objective {
+    /// The name shown to players.
+    pub display_name: NetworkText,
+    /// How scores are rendered in the player list.
+    pub render_type: ObjectiveRenderType,
+    /// The default format of scores, if any.
+    pub number_format: Option<NumberFormat>,
+    /// The scores of this objective, by owner.
+    pub scores: IndexMap<String, Score>,
+}
+
+/// A single score of an [`Objective`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Score {
+    /// The value of the score.
+    pub value: i32,
+    /// The name shown instead of the owner, if any.
+    pub display_name: Option<NetworkText>,
+    /// The format of the score, overriding the [`Objective`]'s format.
+    pub number_format: Option<NumberFormat>,
+}
+
+impl Scoreboard {
+    /// Create a new, empty [`Scoreboard`].
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Get an [`Objective`] by name.
+    #[must_use]
+    pub fn objective(&self, name: &str) -> Option<&Objective> { self.objectives.get(name) }
+
+    /// Iterate over all [`Objective`]s and their names.
+    pub fn objectives(&self) -> impl Iterator<Item = (&str, &Objective)> {
+        self.objectives.iter().map(|(name, objective)| (name.as_str(), objective))
+    }
+
+    /// Get the name of the [`Objective`] shown in a [`DisplaySlot`].
+    #[must_use]
+    pub fn displayed_name(&self, slot: DisplaySlot) -> Option<&str> {
+        self.display.get(&slot).map(String::as_str)
+    }
+
+    /// Get the [`Objective`] shown in a [`DisplaySlot`].
+    #[must_use]
+    pub fn displayed(&self, slot: DisplaySlot) -> Option<&Objective> {
+        self.display.get(&slot).and_then(|name| self.objectives.get(name))
+    }
+
+    /// Get the [`Objective`] shown in the sidebar.
+    #[inline]
+    #[must_use]
+    pub fn sidebar(&self) -> Option<&Objective> { self.displayed(DisplaySlot::Sidebar) }
+
+    /// Get the [`Score`] of an owner for an objective.
+    #[must_use]
+    pub fn score(&self, owner: &str, objective: &str) -> Option<&Score> {
+        self.objectives.get(objective).and_then(|objective| objective.scores.get(owner))
+    }
+
+    /// Iterate over all [`Score`]s of an owner, by objective name.
+    pub fn scores_of<'a>(&'a self, owner: &'a str) -> impl Iterator<Item = (&'a str, &'a Score)> {
+        self.objectives.iter().filter_map(move |(name, objective)| {
+            Some((name.as_str(), objective.scores.get(owner)?))
+        })
+    }
+
+    /// Remove all objectives, scores, and display slots.
+    pub fn clear(&mut self) {
+        self.objectives.clear();
+        self.display.clear();
+    }
+
+    /// Apply an [`ObjectiveUpdate`].
+    pub fn apply_objective(&mut self, update: &ObjectiveUpdate) {
+        match &update.action {
+            ObjectiveAction::Add(data) => {
+                let objective = self.objectives.entry(update.name.clone()).or_default();
+                objective.set_data(data);
+            }
+            ObjectiveAction::Update(data) => {
+                if let Some(objective) = self.objectives.get_mut(&update.name) {
+                    objective.set_data(data);
+                }
+            }
+            ObjectiveAction::Remove => {
+                self.objectives.shift_remove(&update.name);
+                self.display.retain(|_, name| name != &update.name);
+            }
+        }
+    }
+
+    /// Apply a [`ScoreUpdate`].
+    ///
+    /// Scores for unknown objectives are ignored.
+    pub fn apply_score(&mut self, update: &ScoreUpdate) {
+        if let Some(objective) = self.objectives.get_mut(&update.objective) {
+            let score = objective.scores.entry(update.owner.clone()).or_default();
+            score.value = update.value;
+            score.display_name.clone_from(&update.display_name);
+            score.number_format.clone_from(&update.number_format);
+        }
+    }
+
+    /// Apply a [`ScoreReset`].
+    pub fn apply_reset(&mut self, reset: &ScoreReset) {
+        if let Some(name) = &reset.objective {
+            if let Some(objective) = self.objectives.get_mut(name) {
+                objective.scores.shift_remove(&reset.owner);
+            }
+        } else {
+            for objective in self.objectives.values_mut() {
+                objective.scores.shift_remove(&reset.owner);
+            }
+        }
+    }
+
+    /// Apply a [`DisplayObjective`].
+    pub fn apply_display(&mut self, display: &DisplayObjective) {
+        if display.objective.is_empty() {
+            self.display.shift_remove(&display.slot);
+        } else {
+            self.display.insert(display.slot, display.objective.clone());
+        }
+    }
+}
+
+impl Objective {
+    /// Get the scores of this objective in the order they are displayed,
+    /// highest first and then by owner.
+    #[must_use]
+    pub fn sorted_scores(&self) -> Vec<(&str, &Score)> {
+        let mut scores: Vec<_> =
+            self.scores.iter().map(|(owner, score)| (owner.as_str(), score)).collect();
+        scores
+            .sort_by(|(a_owner, a), (b_owner, b)| b.value.cmp(&a.value).then(a_owner.cmp(b_owner)));
+        scores
+    }
+
+    fn set_data(&mut self, data: &ObjectiveData) {
+        self.display_name.clone_from(&data.display_name);
+        self.render_type = data.render_type;
+        self.number_format.clone_from(&data.number_format);
+    }
+}
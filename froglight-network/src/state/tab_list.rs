@@ -0,0 +1,152 @@
+#[cfg(feature = "bevy")]
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::{
+    game_mode::GameMode,
+    player_info::{ChatSession, PlayerInfoUpdate, TabListText},
+    text::NetworkText,
+};
+use froglight_player::prelude::PlayerProfile;
+use indexmap::IndexMap;
+use uuid::Uuid;
+
+/// The player list of a connection.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component, Reflect))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Default, Clone, PartialEq, Component))]
+pub struct TabList {
+    entries: IndexMap<Uuid, TabListEntry>,
+    /// The text shown above the list.
+    pub header: NetworkText,
+    /// The text shown below the list.
+    pub footer: NetworkText,
+}
+
+/// A player in the [`TabList`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabListEntry {
+    /// The profile of the player.
+    pub profile: PlayerProfile,
+    /// The chat session of the player, if any.
+    pub chat_session: Option<ChatSession>,
+    /// The game mode of the player.
+    pub game_mode: GameMode,
+    /// Whether the player is shown in the list.
+    pub listed: bool,
+    /// The latency of the player, in milliseconds.
+    pub latency: i32,
+    /// The name shown in the list, if different from the username.
+    pub display_name: Option<NetworkText>,
+    /// The position of the player in the list, lower values are shown first.
+    pub list_order: i32,
+    /// Whether the player's hat layer is shown.
+    pub show_hat: bool,
+}
+
+impl TabListEntry {
+    /// Create a new [`TabListEntry`] for a [`PlayerProfile`].
+    #[must_use]
+    pub const fn new(profile: PlayerProfile) -> Self {
+        Self {
+            profile,
+            chat_session: None,
+            game_mode: GameMode::Survival,
+            listed: false,
+            latency: 0,
+            display_name: None,
+            list_order: 0,
+            show_hat: true,
+        }
+    }
+}
+
+impl TabList {
+    /// Create a new, empty [`TabList`].
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Get the [`TabListEntry`] of a player.
+    #[must_use]
+    pub fn get(&self, uuid: &Uuid) -> Option<&TabListEntry> { self.entries.get(uuid) }
+
+    /// Get the [`TabListEntry`] of a player by username.
+    #[must_use]
+    pub fn get_by_name(&self, username: &str) -> Option<&TabListEntry> {
+        self.entries.values().find(|entry| entry.profile.username().as_str() == username)
+    }
+
+    /// Iterate over all [`TabListEntry`]s, including unlisted players.
+    pub fn iter(&self) -> impl Iterator<Item = &TabListEntry> { self.entries.values() }
+
+    /// Get the number of players, including unlisted players.
+    #[must_use]
+    pub fn len(&self) -> usize { self.entries.len() }
+
+    /// Returns `true` if there are no players.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    /// Get the listed players in the order they are displayed,
+    /// by list order and then by username.
+    #[must_use]
+    pub fn listed(&self) -> Vec<&TabListEntry> {
+        let mut listed: Vec<_> = self.entries.values().filter(|entry| entry.listed).collect();
+        listed.sort_by(|a, b| {
+            a.list_order
+                .cmp(&b.list_order)
+                .then_with(|| a.profile.username().as_str().cmp(b.profile.username().as_str()))
+        });
+        listed
+    }
+
+    /// Remove all players and clear the header and footer.
+    pub fn clear(&mut self) { *self = Self::default(); }
+
+    /// Apply a [`PlayerInfoUpdate`].
+    ///
+    /// Updates for players that were never added are ignored.
+    pub fn apply_update(&mut self, update: &PlayerInfoUpdate) {
+        for info in &update.entries {
+            if let Some(profile) = &info.profile {
+                self.entries.insert(info.uuid, TabListEntry::new(profile.clone()));
+            }
+            let Some(entry) = self.entries.get_mut(&info.uuid) else { continue };
+
+            if let Some(session) = &info.chat_session {
+                entry.chat_session.clone_from(session);
+            }
+            if let Some(game_mode) = info.game_mode {
+                entry.game_mode = game_mode;
+            }
+            if let Some(listed) = info.listed {
+                entry.listed = listed;
+            }
+            if let Some(latency) = info.latency {
+                entry.latency = latency;
+            }
+            if let Some(display_name) = &info.display_name {
+                entry.display_name.clone_from(display_name);
+            }
+            if let Some(list_order) = info.list_order {
+                entry.list_order = list_order;
+            }
+            if let Some(show_hat) = info.show_hat {
+                entry.show_hat = show_hat;
+            }
+        }
+    }
+
+    /// Remove players from the list.
+    pub fn apply_remove(&mut self, profiles: &[Uuid]) {
+        for uuid in profiles {
+            self.entries.shift_remove(uuid);
+        }
+    }
+
+    /// Apply a [`TabListText`].
+    pub fn apply_text(&mut self, text: &TabListText) {
+        self.header.clone_from(&text.header);
+        self.footer.clone_from(&text.footer);
+    }
+}
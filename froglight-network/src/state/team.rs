@@ -0,0 +1,112 @@
+#[cfg(feature = "bevy")]
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::scoreboard::{TeamAction, TeamParameters, TeamUpdate};
+use indexmap::{IndexMap, IndexSet};
+
+/// The teams of a connection.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component, Reflect))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Default, Clone, PartialEq, Component))]
+pub struct Teams {
+    teams: IndexMap<String, Team>,
+    members: IndexMap<String, String>,
+}
+
+/// A team and its members.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Team {
+    /// The properties of the team.
+    pub parameters: TeamParameters,
+    /// The entity and player names that are members of the team.
+    pub members: IndexSet<String>,
+}
+
+impl Teams {
+    /// Create a new, empty [`Teams`].
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Get a [`Team`] by name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Team> { self.teams.get(name) }
+
+    /// Iterate over all [`Team`]s and their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Team)> {
+        self.teams.iter().map(|(name, team)| (name.as_str(), team))
+    }
+
+    /// Get the name of the team a member is part of.
+    #[must_use]
+    pub fn team_name_of(&self, member: &str) -> Option<&str> {
+        self.members.get(member).map(String::as_str)
+    }
+
+    /// Get the [`Team`] a member is part of.
+    #[must_use]
+    pub fn team_of(&self, member: &str) -> Option<&Team> {
+        self.members.get(member).and_then(|name| self.teams.get(name))
+    }
+
+    /// Remove all teams.
+    pub fn clear(&mut self) {
+        self.teams.clear();
+        self.members.clear();
+    }
+
+    /// Apply a [`TeamUpdate`].
+    pub fn apply(&mut self, update: &TeamUpdate) {
+        match &update.action {
+            TeamAction::Add(parameters, members) => {
+                self.remove_team(&update.name);
+                self.teams.insert(
+                    update.name.clone(),
+                    Team { parameters: parameters.clone(), members: IndexSet::new() },
+                );
+                self.join(&update.name, members);
+            }
+            TeamAction::Remove => self.remove_team(&update.name),
+            TeamAction::Update(parameters) => {
+                if let Some(team) = self.teams.get_mut(&update.name) {
+                    team.parameters = parameters.clone();
+                }
+            }
+            TeamAction::Join(members) => self.join(&update.name, members),
+            TeamAction::Leave(members) => {
+                let Some(team) = self.teams.get_mut(&update.name) else { return };
+                for member in members {
+                    if team.members.shift_remove(member) {
+                        self.members.shift_remove(member);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Add members to a team, removing them from their previous team.
+    fn join(&mut self, name: &str, members: &[String]) {
+        if !self.teams.contains_key(name) {
+            return;
+        }
+
+        for member in members {
+            if let Some(previous) = self.members.insert(member.clone(), String::from(name))
+                && let Some(team) = self.teams.get_mut(&previous)
+            {
+                team.members.shift_remove(member);
+            }
+            if let Some(team) = self.teams.get_mut(name) {
+                team.members.insert(member.clone());
+            }
+        }
+    }
+
+    fn remove_team(&mut self, name: &str) {
+        if let Some(team) = self.teams.shift_remove(name) {
+            for member in team.members {
+                self.members.shift_remove(&member);
+            }
+        }
+    }
+}
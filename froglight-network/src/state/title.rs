@@ -0,0 +1,128 @@
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "bevy")]
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::{text::NetworkText, title::TitleTimes};
+
+/// The titles, subtitles, and action bar text of a connection.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component, Reflect))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Default, Clone, PartialEq, Component))]
+pub struct Titles {
+    /// The current title, if any.
+    pub title: Option<NetworkText>,
+    /// The current subtitle, if any.
+    ///
+    /// Subtitles are only shown alongside a title.
+    pub subtitle: Option<NetworkText>,
+    /// The current action bar text, if any.
+    pub action_bar: Option<NetworkText>,
+    /// How long titles are shown.
+    pub times: TitleTimes,
+
+    title_shown: Option<Instant>,
+    action_bar_shown: Option<Instant>,
+}
+
+impl Titles {
+    /// How long the action bar is shown, in ticks.
+    const ACTION_BAR_TICKS: u32 = 60;
+    /// The duration of a single tick.
+    const TICK: Duration = Duration::from_millis(50);
+
+    /// Create a new, empty [`Titles`].
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Get the title, if it is still visible.
+    #[must_use]
+    pub fn visible_title(&self) -> Option<&NetworkText> { self.visible_title_at(Instant::now()) }
+
+    /// Get the title, if it is visible at the given [`Instant`].
+    #[must_use]
+    pub fn visible_title_at(&self, now: Instant) -> Option<&NetworkText> {
+        let duration = Self::TICK * u32::try_from(self.times.total()).unwrap_or_default();
+        let shown = self.title_shown?;
+        self.title.as_ref().filter(|_| now.saturating_duration_since(shown) < duration)
+    }
+
+    /// Get the subtitle, if it is still visible.
+    #[must_use]
+    pub fn visible_subtitle(&self) -> Option<&NetworkText> {
+        self.visible_subtitle_at(Instant::now())
+    }
+
+    /// Get the subtitle, if it is visible at the given [`Instant`].
+    #[must_use]
+    pub fn visible_subtitle_at(&self, now: Instant) -> Option<&NetworkText> {
+        self.visible_title_at(now).and(self.subtitle.as_ref())
+    }
+
+    /// Get the action bar text, if it is still visible.
+    #[must_use]
+    pub fn visible_action_bar(&self) -> Option<&NetworkText> {
+        self.visible_action_bar_at(Instant::now())
+    }
+
+    /// Get the action bar text, if it is visible at the given [`Instant`].
+    #[must_use]
+    pub fn visible_action_bar_at(&self, now: Instant) -> Option<&NetworkText> {
+        let shown = self.action_bar_shown?;
+        self.action_bar
+            .as_ref()
+            .filter(|_| now.saturating_duration_since(shown) < Self::TICK * Self::ACTION_BAR_TICKS)
+    }
+
+    /// Remove all text and reset the [`TitleTimes`].
+    pub fn clear(&mut self) { *self = Self::default(); }
+
+    /// Show a new title, starting at the given [`Instant`].
+    pub fn set_title(&mut self, title: NetworkText, now: Instant) {
+        self.title = Some(title);
+        self.title_shown = Some(now);
+    }
+
+    /// Set the subtitle shown alongside the title.
+    pub fn set_subtitle(&mut self, subtitle: NetworkText) { self.subtitle = Some(subtitle); }
+
+    /// Show new action bar text, starting at the given [`Instant`].
+    pub fn set_action_bar(&mut self, text: NetworkText, now: Instant) {
+        self.action_bar = Some(text);
+        self.action_bar_shown = Some(now);
+    }
+
+    /// Set the [`TitleTimes`] of this and future titles.
+    ///
+    /// Negative values keep their previous value,
+    /// and a visible title is shown again for the new duration.
+    pub fn set_times(&mut self, times: TitleTimes, now: Instant) {
+        let visible = self.visible_title_at(now).is_some();
+
+        if times.fade_in >= 0 {
+            self.times.fade_in = times.fade_in;
+        }
+        if times.stay >= 0 {
+            self.times.stay = times.stay;
+        }
+        if times.fade_out >= 0 {
+            self.times.fade_out = times.fade_out;
+        }
+
+        if visible {
+            self.title_shown = Some(now);
+        }
+    }
+
+    /// Hide the title and subtitle,
+    /// optionally resetting the [`TitleTimes`] to their defaults.
+    pub fn clear_titles(&mut self, reset: bool) {
+        self.title = None;
+        self.subtitle = None;
+        self.title_shown = None;
+        if reset {
+            self.times = TitleTimes::DEFAULT;
+        }
+    }
+}
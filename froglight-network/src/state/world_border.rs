@@ -0,0 +1,185 @@
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "bevy")]
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use froglight_packet::common::world_border::WorldBorderInit;
+
+/// The world border of a connection.
+///
+/// The size of the border moves between two diameters over time,
+/// so most methods take the [`Instant`] to query at.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Component, Reflect))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Default, Clone, PartialEq, Component))]
+pub struct WorldBorder {
+    /// The X coordinate of the center of the border.
+    pub center_x: f64,
+    /// The Z coordinate of the center of the border.
+    pub center_z: f64,
+    /// The maximum distance from the center the border can reach.
+    pub absolute_max_size: u32,
+    /// The distance from the border at which the warning is shown, in blocks.
+    pub warning_blocks: u32,
+    /// How long before the border reaches a player the warning is shown, in
+    /// seconds.
+    pub warning_time: u32,
+
+    old_size: f64,
+    new_size: f64,
+    lerp_start: Instant,
+    lerp_duration: Duration,
+}
+
+/// The bounds of a [`WorldBorder`] at a point in time.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WorldBorderBounds {
+    /// The lowest X coordinate inside the border.
+    pub min_x: f64,
+    /// The lowest Z coordinate inside the border.
+    pub min_z: f64,
+    /// The X coordinate just outside the border.
+    pub max_x: f64,
+    /// The Z coordinate just outside the border.
+    pub max_z: f64,
+}
+
+impl Default for WorldBorder {
+    fn default() -> Self { Self::new(&WorldBorderInit::DEFAULT, Instant::now()) }
+}
+
+impl WorldBorder {
+    /// Create a new [`WorldBorder`], starting its transition at the given
+    /// [`Instant`].
+    #[must_use]
+    pub fn new(init: &WorldBorderInit, now: Instant) -> Self {
+        Self {
+            center_x: init.center_x,
+            center_z: init.center_z,
+            absolute_max_size: init.absolute_max_size,
+            warning_blocks: init.warning_blocks,
+            warning_time: init.warning_time,
+            old_size: init.old_size,
+            new_size: init.new_size,
+            lerp_start: now,
+            lerp_duration: Duration::from_millis(init.lerp_time),
+        }
+    }
+
+    /// Get the diameter of the border at the given [`Instant`].
+    #[must_use]
+    pub fn size_at(&self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.lerp_start);
+        if elapsed >= self.lerp_duration {
+            self.new_size
+        } else {
+            let progress = elapsed.as_secs_f64() / self.lerp_duration.as_secs_f64();
+            self.old_size + (self.new_size - self.old_size) * progress
+        }
+    }
+
+    /// Get the diameter of the border now.
+    #[must_use]
+    pub fn size(&self) -> f64 { self.size_at(Instant::now()) }
+
+    /// Get the diameter the border is moving towards.
+    #[must_use]
+    pub const fn target_size(&self) -> f64 { self.new_size }
+
+    /// Returns `true` if the border is still moving at the given [`Instant`].
+    #[must_use]
+    pub fn is_moving_at(&self, now: Instant) -> bool { self.remaining_at(now) > Duration::ZERO }
+
+    /// Get how long the border will keep moving after the given [`Instant`].
+    #[must_use]
+    pub fn remaining_at(&self, now: Instant) -> Duration {
+        if (self.new_size - self.old_size).abs() < f64::EPSILON {
+            return Duration::ZERO;
+        }
+        self.lerp_duration.saturating_sub(now.saturating_duration_since(self.lerp_start))
+    }
+
+    /// Get the speed of the border, in blocks per second.
+    ///
+    /// Returns `0.0` if the border is not moving.
+    #[must_use]
+    pub fn speed(&self) -> f64 {
+        if self.lerp_duration.is_zero() {
+            0.0
+        } else {
+            (self.new_size - self.old_size).abs() / self.lerp_duration.as_secs_f64()
+        }
+    }
+
+    /// Get the [`WorldBorderBounds`] of the border at the given [`Instant`].
+    #[must_use]
+    pub fn bounds_at(&self, now: Instant) -> WorldBorderBounds {
+        let half = self.size_at(now) / 2.0;
+        let max = f64::from(self.absolute_max_size);
+        WorldBorderBounds {
+            min_x: (self.center_x - half).clamp(-max, max),
+            min_z: (self.center_z - half).clamp(-max, max),
+            max_x: (self.center_x + half).clamp(-max, max),
+            max_z: (self.center_z + half).clamp(-max, max),
+        }
+    }
+
+    /// Returns `true` if a position is inside the border at the given
+    /// [`Instant`].
+    #[must_use]
+    pub fn contains_at(&self, x: f64, z: f64, now: Instant) -> bool {
+        let bounds = self.bounds_at(now);
+        x >= bounds.min_x && x < bounds.max_x && z >= bounds.min_z && z < bounds.max_z
+    }
+
+    /// Returns `true` if a position is inside the border now.
+    #[must_use]
+    pub fn contains(&self, x: f64, z: f64) -> bool { self.contains_at(x, z, Instant::now()) }
+
+    /// Get the distance from a position to the closest edge of the border at
+    /// the given [`Instant`].
+    ///
+    /// The distance is negative if the position is outside the border.
+    #[must_use]
+    pub fn distance_at(&self, x: f64, z: f64, now: Instant) -> f64 {
+        let bounds = self.bounds_at(now);
+        (x - bounds.min_x).min(bounds.max_x - x).min(z - bounds.min_z).min(bounds.max_z - z)
+    }
+
+    /// Get the distance from the border at which the warning is shown at the
+    /// given [`Instant`].
+    ///
+    /// This grows while the border is moving quickly.
+    #[must_use]
+    pub fn warning_distance_at(&self, now: Instant) -> f64 {
+        let moving = (self.speed() * f64::from(self.warning_time))
+            .min((self.new_size - self.size_at(now)).abs());
+        f64::from(self.warning_blocks).max(moving)
+    }
+
+    /// Returns `true` if a position is close enough to the border to show
+    /// the warning at the given [`Instant`].
+    #[must_use]
+    pub fn is_warning_at(&self, x: f64, z: f64, now: Instant) -> bool {
+        self.distance_at(x, z, now) < self.warning_distance_at(now)
+    }
+
+    /// Set the center of the border.
+    pub const fn set_center(&mut self, x: f64, z: f64) {
+        self.center_x = x;
+        self.center_z = z;
+    }
+
+    /// Set the diameter of the border, stopping any transition.
+    pub fn set_size(&mut self, size: f64, now: Instant) { self.lerp_size(size, size, 0, now); }
+
+    /// Move the border between two diameters over a number of milliseconds,
+    /// starting at the given [`Instant`].
+    pub fn lerp_size(&mut self, old_size: f64, new_size: f64, millis: u64, now: Instant) {
+        self.old_size = old_size;
+        self.new_size = new_size;
+        self.lerp_start = now;
+        self.lerp_duration = Duration::from_millis(millis);
+    }
+}
@@ -0,0 +1,162 @@
+//! TODO
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+#[cfg(feature = "facet")]
+use facet::Facet;
+#[cfg(feature = "facet")]
+use froglight_facet::facet::prelude::*;
+use uuid::Uuid;
+
+use crate::common::text::NetworkText;
+
+/// A change to a boss bar.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct BossEvent {
+    /// The [`Uuid`] of the boss bar.
+    pub id: Uuid,
+    /// The change to apply.
+    pub action: BossEventAction,
+}
+
+/// The action of a [`BossEvent`].
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum BossEventAction {
+    /// Create a new boss bar.
+    Add(BossBarData),
+    /// Remove an existing boss bar.
+    Remove,
+    /// Update the progress of an existing boss bar.
+    UpdateProgress(f32),
+    /// Update the name of an existing boss bar.
+    UpdateName(NetworkText),
+    /// Update the color and overlay of an existing boss bar.
+    UpdateStyle(BossBarColor, BossBarOverlay),
+    /// Update the flags of an existing boss bar.
+    UpdateProperties(BossBarFlags),
+}
+
+/// The properties of a boss bar.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct BossBarData {
+    /// The name shown above the bar.
+    pub name: NetworkText,
+    /// How full the bar is, from `0.0` to `1.0`.
+    pub progress: f32,
+    /// The color of the bar.
+    pub color: BossBarColor,
+    /// How the bar is divided.
+    pub overlay: BossBarOverlay,
+    /// Additional effects of the bar.
+    pub flags: BossBarFlags,
+}
+
+/// The color of a boss bar.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[expect(missing_docs, reason = "Variants match their color names")]
+pub enum BossBarColor {
+    #[default]
+    Pink,
+    Blue,
+    Red,
+    Green,
+    Yellow,
+    Purple,
+    White,
+}
+
+/// How a boss bar is divided into notches.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum BossBarOverlay {
+    /// A solid bar.
+    #[default]
+    Progress,
+    /// A bar with 6 notches.
+    Notched6,
+    /// A bar with 10 notches.
+    Notched10,
+    /// A bar with 12 notches.
+    Notched12,
+    /// A bar with 20 notches.
+    Notched20,
+}
+
+impl BossBarOverlay {
+    /// Get the number of notches of this [`BossBarOverlay`].
+    #[must_use]
+    pub const fn notches(self) -> u32 {
+        match self {
+            BossBarOverlay::Progress => 1,
+            BossBarOverlay::Notched6 => 6,
+            BossBarOverlay::Notched10 => 10,
+            BossBarOverlay::Notched12 => 12,
+            BossBarOverlay::Notched20 => 20,
+        }
+    }
+}
+
+/// Additional effects of a boss bar.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[cfg_attr(feature = "facet", facet(mc::with = BossBarFlags::WITH))]
+pub struct BossBarFlags {
+    /// Whether the sky is darkened.
+    pub darken_screen: bool,
+    /// Whether the boss music is played.
+    pub play_music: bool,
+    /// Whether fog is created.
+    pub create_fog: bool,
+}
+
+#[cfg(feature = "facet")]
+impl FacetTemplate for BossBarFlags {
+    fn serialize(item: SerializeItem<'_, '_>, writer: &mut Writer<'_>) -> Result<(), WriterError> {
+        let data = item.get::<Self>()?;
+
+        let mut output = 0u8;
+        if data.darken_screen {
+            output |= 0b0000_0001;
+        }
+        if data.play_music {
+            output |= 0b0000_0010;
+        }
+        if data.create_fog {
+            output |= 0b0000_0100;
+        }
+
+        writer.write_byte(output)
+    }
+
+    fn deserialize<'facet, const BORROW: bool>(
+        item: DeserializeItem<'facet, BORROW>,
+        reader: &mut Reader<'_>,
+    ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+        let data = reader.read_byte()?;
+
+        item.set(Self {
+            darken_screen: data & 0b0000_0001 != 0,
+            play_music: data & 0b0000_0010 != 0,
+            create_fog: data & 0b0000_0100 != 0,
+        })
+    }
+}
@@ -7,11 +7,12 @@ use froglight_biome::prelude::BiomeVersion;
 use froglight_block::prelude::BlockVersion;
 #[cfg(feature = "facet")]
 use froglight_facet as mc;
-use froglight_nbt::prelude::IndexedNbtCow;
+use froglight_nbt::prelude::{IndexedNbtCow, IndexedNbtSlice, Nbt};
 use froglight_world::{
+    chunk::Heightmap,
     component::ChunkBlockPos,
     naive::ParseError,
-    prelude::{Chunk, NaiveChunk},
+    prelude::{BlockEntity, BlockPos, Chunk, HeightmapKind, NaiveChunk},
 };
 
 /// Raw chunk data.
@@ -60,17 +61,81 @@ pub struct RawEntityData {
 impl RawChunkData {
     /// Attempt to parse a [`Chunk`] from [`RawChunkData`].
     ///
+    /// Block entities with an unknown type, invalid data,
+    /// or a position outside of the chunk are skipped.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the data is invalid,
+    /// Returns an error if the chunk data is invalid,
     /// or if `height_max` and `height_min` are incorrect.
-    #[inline]
     pub fn try_parse<V: BiomeVersion + BlockVersion>(
         &self,
         height_max: u32,
         height_min: i32,
     ) -> Result<Chunk, ParseError> {
-        self.try_parse_naive(height_max, height_min).map(Chunk::new::<V>)
+        let mut chunk = self.try_parse_naive(height_max, height_min).map(Chunk::new::<V>)?;
+
+        for entity in &self.entity_data {
+            // Skip invalid block entities instead of discarding the whole chunk.
+            let Ok((position, block_entity)) = entity.try_parse::<V>() else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    target: "froglight_packet::common",
+                    "Skipping block entity with invalid type {} or data at {:?}",
+                    entity.kind,
+                    entity.position
+                );
+                continue;
+            };
+            if !chunk.height_range().contains(&position.y()) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    target: "froglight_packet::common",
+                    "Skipping block entity outside of the chunk at {position:?}"
+                );
+                continue;
+            }
+            chunk.set_block_entity(position, block_entity);
+        }
+
+        for heightmap in &self.heightmaps {
+            // Skip heightmaps only used during world generation.
+            let Some(kind) = HeightmapKind::from_id(heightmap.kind) else { continue };
+            let data = Heightmap::from_packed(&heightmap.data, chunk.height_total())
+                .ok_or(ParseError::InvalidHeightmap)?;
+            chunk.set_heightmap(kind, data);
+        }
+
+        Ok(chunk)
+    }
+
+    /// Create [`RawChunkData`] from a [`Chunk`].
+    ///
+    /// Includes every loaded heightmap, and every block entity that exists in
+    /// the chunk's [`Version`](froglight_common::version::Version).
+    ///
+    /// See [`Chunk::write_to`] for how each section is written.
+    #[must_use]
+    pub fn from_chunk(chunk: &Chunk) -> Self {
+        let heightmaps = chunk
+            .iter_heightmaps()
+            .map(|(kind, heightmap)| RawHeightMapData {
+                kind: kind.id(),
+                data: heightmap.to_packed(chunk.height_total()),
+            })
+            .collect();
+
+        let mut chunk_data = Vec::new();
+        chunk.write_to(&mut chunk_data);
+
+        let entity_data = chunk
+            .iter_block_entities()
+            .filter_map(|(position, block_entity)| {
+                RawEntityData::from_block_entity(chunk, position, block_entity)
+            })
+            .collect();
+
+        Self { heightmaps, chunk_data, entity_data }
     }
 
     /// Attempt to parse a [`NaiveChunk`] from [`RawChunkData`].
@@ -87,3 +152,56 @@ impl RawChunkData {
         NaiveChunk::parse_from(&self.chunk_data, height_max, height_min)
     }
 }
+
+impl RawEntityData {
+    /// Create [`RawEntityData`] from a [`BlockEntity`] in a [`Chunk`].
+    ///
+    /// Returns `None` if the block entity does not exist in the chunk's
+    /// [`Version`](froglight_common::version::Version).
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "Serialized NBT is always valid")]
+    pub fn from_block_entity(
+        chunk: &Chunk,
+        position: ChunkBlockPos,
+        block_entity: &BlockEntity,
+    ) -> Option<Self> {
+        let kind = chunk.blocks().get_block_entity_id(block_entity.kind())?;
+
+        // The packed `y` coordinate is the signed world height, not the chunk height.
+        #[expect(clippy::cast_possible_truncation, reason = "Packed as the signed world height")]
+        #[expect(clippy::cast_sign_loss, reason = "Packed as the signed world height")]
+        let y = (i32::from(position.y()) + chunk.height_offset()) as i16 as u16;
+        let position = ChunkBlockPos::new_xyz(position.x(), y, position.z());
+
+        let bytes = Nbt::new(None, block_entity.data().clone()).to_unnamed_bytes();
+        let nbt = IndexedNbtSlice::new_unnamed(bytes.as_slice())
+            .expect("Serialized NBT should always be valid?!")
+            .into_owned();
+
+        Some(Self { position, kind, nbt })
+    }
+
+    /// Attempt to parse a [`BlockEntity`] from [`RawEntityData`],
+    /// returning it along with its position in the world.
+    ///
+    /// Only the `x` and `z` coordinates of the returned [`BlockPos`] are
+    /// relative to the chunk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the block entity type is unknown,
+    /// or if the NBT data is invalid.
+    pub fn try_parse<V: BlockVersion>(&self) -> Result<(BlockPos, BlockEntity), ParseError> {
+        let kind =
+            V::blocks().get_block_entity_by_id(self.kind).ok_or(ParseError::InvalidBlockEntity)?;
+        let nbt = Nbt::try_from(&self.nbt).map_err(|()| ParseError::InvalidBlockEntity)?;
+
+        // The packed `y` coordinate is the signed world height, not the chunk height.
+        #[expect(clippy::cast_possible_wrap, reason = "Reinterpreting the packed height")]
+        let y = i32::from(self.position.y() as i16);
+        let position =
+            BlockPos::new_xyz(i32::from(self.position.x()), y, i32::from(self.position.z()));
+
+        Ok((position, BlockEntity::new(kind, nbt.into_compound())))
+    }
+}
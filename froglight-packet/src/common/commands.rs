@@ -0,0 +1,605 @@
+//! TODO
+
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+#[cfg(feature = "facet")]
+use facet::Facet;
+use froglight_common::prelude::Identifier;
+#[cfg(feature = "facet")]
+use froglight_facet::facet::prelude::*;
+
+use crate::common::text::NetworkText;
+
+/// The tree of commands available to the client.
+///
+/// Each node refers to other nodes by their index in [`CommandTree::nodes`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct CommandTree {
+    /// All nodes in the tree.
+    pub nodes: Vec<CommandNode>,
+    /// The index of the root node.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub root: u32,
+}
+
+impl CommandTree {
+    /// Get the root [`CommandNode`] of the tree.
+    #[inline]
+    #[must_use]
+    pub fn root(&self) -> Option<&CommandNode> { self.nodes.get(self.root as usize) }
+
+    /// Get the [`CommandNode`] at the given index.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: u32) -> Option<&CommandNode> { self.nodes.get(index as usize) }
+}
+
+/// A single node in a [`CommandTree`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[cfg_attr(feature = "facet", facet(mc::with = CommandNode::WITH))]
+pub struct CommandNode {
+    /// The type of node.
+    pub kind: CommandNodeKind,
+    /// Whether the command can be executed after this node.
+    pub executable: bool,
+    /// Whether the command requires elevated permissions.
+    pub restricted: bool,
+    /// The indices of this node's children.
+    pub children: Vec<u32>,
+    /// The index of the node this node redirects to.
+    pub redirect: Option<u32>,
+    /// The suggestion provider used for this node.
+    ///
+    /// Only used by argument nodes.
+    pub suggestions: Option<Identifier<'static>>,
+}
+
+impl CommandNode {
+    /// The identifier of the suggestion provider
+    /// that requests suggestions from the server.
+    pub const ASK_SERVER: Identifier<'static> = Identifier::new_static("minecraft:ask_server");
+
+    /// Get the name of this node, if it has one.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        match &self.kind {
+            CommandNodeKind::Root => None,
+            CommandNodeKind::Literal(name) | CommandNodeKind::Argument(name, _) => Some(name),
+        }
+    }
+
+    /// Returns `true` if suggestions for this node must be requested from the
+    /// server.
+    #[must_use]
+    pub fn asks_server(&self) -> bool {
+        self.suggestions.as_ref().is_some_and(|id| id == &Self::ASK_SERVER)
+    }
+}
+
+/// The type of [`CommandNode`].
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum CommandNodeKind {
+    /// The root of the tree.
+    Root,
+    /// A literal word.
+    Literal(String),
+    /// A named argument parsed using an [`ArgumentType`].
+    Argument(String, ArgumentType),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The parser used by an argument [`CommandNode`], and its properties.
+///
+/// Variants are ordered by their id in the `minecraft:command_argument_type`
+/// registry.
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[expect(missing_docs, reason = "Variants match their registry identifiers")]
+pub enum ArgumentType {
+    Bool,
+    Float { min: Option<f32>, max: Option<f32> },
+    Double { min: Option<f64>, max: Option<f64> },
+    Integer { min: Option<i32>, max: Option<i32> },
+    Long { min: Option<i64>, max: Option<i64> },
+    String(StringArgumentKind),
+    Entity { single: bool, players_only: bool },
+    GameProfile,
+    BlockPos,
+    ColumnPos,
+    Vec3,
+    Vec2,
+    BlockState,
+    BlockPredicate,
+    ItemStack,
+    ItemPredicate,
+    Color,
+    HexColor,
+    Component,
+    Style,
+    Message,
+    NbtCompoundTag,
+    NbtTag,
+    NbtPath,
+    Objective,
+    ObjectiveCriteria,
+    Operation,
+    Particle,
+    Angle,
+    Rotation,
+    ScoreboardSlot,
+    ScoreHolder { multiple: bool },
+    Swizzle,
+    Team,
+    ItemSlot,
+    ItemSlots,
+    ResourceLocation,
+    Function,
+    EntityAnchor,
+    IntRange,
+    FloatRange,
+    Dimension,
+    Gamemode,
+    Time { min: i32 },
+    ResourceOrTag { registry: Identifier<'static> },
+    ResourceOrTagKey { registry: Identifier<'static> },
+    Resource { registry: Identifier<'static> },
+    ResourceKey { registry: Identifier<'static> },
+    ResourceSelector { registry: Identifier<'static> },
+    TemplateMirror,
+    TemplateRotation,
+    Heightmap,
+    LootTable,
+    LootPredicate,
+    LootModifier,
+    Dialog,
+    Uuid,
+}
+
+/// The kind of string parsed by [`ArgumentType::String`].
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum StringArgumentKind {
+    /// A single word.
+    #[default]
+    SingleWord,
+    /// A single word, or a quoted phrase.
+    QuotablePhrase,
+    /// The entire remaining input.
+    GreedyPhrase,
+}
+
+impl ArgumentType {
+    /// The identifiers of all [`ArgumentType`]s, in registry order.
+    pub const IDENTIFIERS: &'static [&'static str] = &[
+        "brigadier:bool",
+        "brigadier:float",
+        "brigadier:double",
+        "brigadier:integer",
+        "brigadier:long",
+        "brigadier:string",
+        "minecraft:entity",
+        "minecraft:game_profile",
+        "minecraft:block_pos",
+        "minecraft:column_pos",
+        "minecraft:vec3",
+        "minecraft:vec2",
+        "minecraft:block_state",
+        "minecraft:block_predicate",
+        "minecraft:item_stack",
+        "minecraft:item_predicate",
+        "minecraft:color",
+        "minecraft:hex_color",
+        "minecraft:component",
+        "minecraft:style",
+        "minecraft:message",
+        "minecraft:nbt_compound_tag",
+        "minecraft:nbt_tag",
+        "minecraft:nbt_path",
+        "minecraft:objective",
+        "minecraft:objective_criteria",
+        "minecraft:operation",
+        "minecraft:particle",
+        "minecraft:angle",
+        "minecraft:rotation",
+        "minecraft:scoreboard_slot",
+        "minecraft:score_holder",
+        "minecraft:swizzle",
+        "minecraft:team",
+        "minecraft:item_slot",
+        "minecraft:item_slots",
+        "minecraft:resource_location",
+        "minecraft:function",
+        "minecraft:entity_anchor",
+        "minecraft:int_range",
+        "minecraft:float_range",
+        "minecraft:dimension",
+        "minecraft:gamemode",
+        "minecraft:time",
+        "minecraft:resource_or_tag",
+        "minecraft:resource_or_tag_key",
+        "minecraft:resource",
+        "minecraft:resource_key",
+        "minecraft:resource_selector",
+        "minecraft:template_mirror",
+        "minecraft:template_rotation",
+        "minecraft:heightmap",
+        "minecraft:loot_table",
+        "minecraft:loot_predicate",
+        "minecraft:loot_modifier",
+        "minecraft:dialog",
+        "minecraft:uuid",
+    ];
+
+    /// Get the registry id of this [`ArgumentType`].
+    #[must_use]
+    pub const fn id(&self) -> u32 {
+        // SAFETY: `ArgumentType` is `repr(u8)`,
+        // so the discriminant is stored in the first byte.
+        unsafe { *core::ptr::from_ref(self).cast::<u8>() as u32 }
+    }
+
+    /// Get the registry identifier of this [`ArgumentType`].
+    #[must_use]
+    pub const fn identifier(&self) -> &'static str { Self::IDENTIFIERS[self.id() as usize] }
+
+    /// Create an [`ArgumentType`] without properties from its registry id.
+    ///
+    /// Returns `None` if the id is unknown or the type requires properties.
+    #[must_use]
+    pub const fn from_id(id: u32) -> Option<Self> {
+        Some(match id {
+            0 => Self::Bool,
+            7 => Self::GameProfile,
+            8 => Self::BlockPos,
+            9 => Self::ColumnPos,
+            10 => Self::Vec3,
+            11 => Self::Vec2,
+            12 => Self::BlockState,
+            13 => Self::BlockPredicate,
+            14 => Self::ItemStack,
+            15 => Self::ItemPredicate,
+            16 => Self::Color,
+            17 => Self::HexColor,
+            18 => Self::Component,
+            19 => Self::Style,
+            20 => Self::Message,
+            21 => Self::NbtCompoundTag,
+            22 => Self::NbtTag,
+            23 => Self::NbtPath,
+            24 => Self::Objective,
+            25 => Self::ObjectiveCriteria,
+            26 => Self::Operation,
+            27 => Self::Particle,
+            28 => Self::Angle,
+            29 => Self::Rotation,
+            30 => Self::ScoreboardSlot,
+            32 => Self::Swizzle,
+            33 => Self::Team,
+            34 => Self::ItemSlot,
+            35 => Self::ItemSlots,
+            36 => Self::ResourceLocation,
+            37 => Self::Function,
+            38 => Self::EntityAnchor,
+            39 => Self::IntRange,
+            40 => Self::FloatRange,
+            41 => Self::Dimension,
+            42 => Self::Gamemode,
+            49 => Self::TemplateMirror,
+            50 => Self::TemplateRotation,
+            51 => Self::Heightmap,
+            52 => Self::LootTable,
+            53 => Self::LootPredicate,
+            54 => Self::LootModifier,
+            55 => Self::Dialog,
+            56 => Self::Uuid,
+            _ => return None,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A list of suggestions sent in response to a suggestion request.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct CommandSuggestionsContent {
+    /// The id of the request these suggestions are for.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub id: u32,
+    /// The start of the text to replace.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub start: u32,
+    /// The length of the text to replace.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub length: u32,
+    /// The suggested replacements.
+    pub matches: Vec<CommandSuggestion>,
+}
+
+/// A single suggestion in a [`CommandSuggestionsContent`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct CommandSuggestion {
+    /// The suggested text.
+    pub text: String,
+    /// An optional tooltip shown alongside the suggestion.
+    #[cfg_attr(feature = "facet", facet(mc::with = OptionTemplate::<SuggestionTooltip>::WITH))]
+    pub tooltip: Option<SuggestionTooltip>,
+}
+
+/// A tooltip attached to a [`CommandSuggestion`].
+pub type SuggestionTooltip = NetworkText;
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "facet")]
+mod template {
+    use alloc::{format, string::String, vec::Vec};
+
+    use froglight_common::prelude::Identifier;
+    use froglight_facet::facet::prelude::*;
+
+    use super::{ArgumentType, CommandNode, CommandNodeKind, StringArgumentKind};
+
+    const TYPE_MASK: u8 = 0b0000_0011;
+    const TYPE_LITERAL: u8 = 0b0000_0001;
+    const TYPE_ARGUMENT: u8 = 0b0000_0010;
+    const EXECUTABLE: u8 = 0b0000_0100;
+    const REDIRECT: u8 = 0b0000_1000;
+    const SUGGESTIONS: u8 = 0b0001_0000;
+    const RESTRICTED: u8 = 0b0010_0000;
+
+    const RANGE_MIN: u8 = 0b0000_0001;
+    const RANGE_MAX: u8 = 0b0000_0010;
+
+    impl FacetTemplate for CommandNode {
+        #[allow(clippy::cast_possible_truncation, reason = "Ignored")]
+        fn serialize(
+            item: SerializeItem<'_, '_>,
+            writer: &mut Writer<'_>,
+        ) -> Result<(), WriterError> {
+            let node = item.get::<CommandNode>()?;
+
+            let mut flags = match node.kind {
+                CommandNodeKind::Root => 0,
+                CommandNodeKind::Literal(_) => TYPE_LITERAL,
+                CommandNodeKind::Argument(..) => TYPE_ARGUMENT,
+            };
+            if node.executable {
+                flags |= EXECUTABLE;
+            }
+            if node.redirect.is_some() {
+                flags |= REDIRECT;
+            }
+            if node.suggestions.is_some() && matches!(node.kind, CommandNodeKind::Argument(..)) {
+                flags |= SUGGESTIONS;
+            }
+            if node.restricted {
+                flags |= RESTRICTED;
+            }
+            writer.write_byte(flags)?;
+
+            encode_u32_into(node.children.len() as u32, writer)?;
+            for child in &node.children {
+                encode_u32_into(*child, writer)?;
+            }
+            if let Some(redirect) = node.redirect {
+                encode_u32_into(redirect, writer)?;
+            }
+
+            match &node.kind {
+                CommandNodeKind::Root => {}
+                CommandNodeKind::Literal(name) => write_string(name, writer)?,
+                CommandNodeKind::Argument(name, argument) => {
+                    write_string(name, writer)?;
+                    write_argument(argument, writer)?;
+                    if let Some(suggestions) = &node.suggestions {
+                        write_string(suggestions.as_str(), writer)?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn deserialize<'facet, const BORROW: bool>(
+            item: DeserializeItem<'facet, BORROW>,
+            reader: &mut Reader<'_>,
+        ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+            let flags = reader.read_byte()?;
+
+            let count = decode_u32_from(reader)?;
+            let mut children = Vec::with_capacity(count.min(64) as usize);
+            for _ in 0..count {
+                children.push(decode_u32_from(reader)?);
+            }
+            let redirect =
+                if flags & REDIRECT != 0 { Some(decode_u32_from(reader)?) } else { None };
+
+            let mut suggestions = None;
+            let kind = match flags & TYPE_MASK {
+                0 => CommandNodeKind::Root,
+                TYPE_LITERAL => CommandNodeKind::Literal(read_string(reader)?),
+                TYPE_ARGUMENT => {
+                    let name = read_string(reader)?;
+                    let argument = read_argument(reader)?;
+                    if flags & SUGGESTIONS != 0 {
+                        let identifier = read_string(reader)?;
+                        suggestions = Some(
+                            Identifier::try_new_string(identifier).map_err(ReaderError::other)?,
+                        );
+                    }
+                    CommandNodeKind::Argument(name, argument)
+                }
+                other => {
+                    return Err(ReaderError::from_string(format!(
+                        "Invalid command node type `{other}`"
+                    )));
+                }
+            };
+
+            item.set(CommandNode {
+                kind,
+                executable: flags & EXECUTABLE != 0,
+                restricted: flags & RESTRICTED != 0,
+                children,
+                redirect,
+                suggestions,
+            })
+        }
+    }
+
+    // ---------------------------------------------------------------------------------------------
+
+    #[allow(clippy::cast_possible_truncation, reason = "Ignored")]
+    fn write_string(string: &str, writer: &mut Writer<'_>) -> Result<(), WriterError> {
+        encode_u32_into(string.len() as u32, writer)?;
+        writer.write_bytes(string.as_bytes())
+    }
+
+    fn read_string(reader: &mut Reader<'_>) -> Result<String, ReaderError> {
+        let length = decode_u32_from(reader)?;
+        let content = reader.read(length as usize)?;
+        str::from_utf8(content).map(String::from).map_err(ReaderError::other)
+    }
+
+    fn write_argument(argument: &ArgumentType, writer: &mut Writer<'_>) -> Result<(), WriterError> {
+        /// Write the flags and bounds of a numeric range.
+        macro_rules! write_range {
+            ($min:expr, $max:expr) => {{
+                let mut flags = 0u8;
+                if $min.is_some() {
+                    flags |= RANGE_MIN;
+                }
+                if $max.is_some() {
+                    flags |= RANGE_MAX;
+                }
+                writer.write_byte(flags)?;
+                if let Some(min) = $min {
+                    writer.write_bytes(&min.to_be_bytes())?;
+                }
+                if let Some(max) = $max {
+                    writer.write_bytes(&max.to_be_bytes())?;
+                }
+            }};
+        }
+
+        encode_u32_into(argument.id(), writer)?;
+        match argument {
+            ArgumentType::Float { min, max } => write_range!(min, max),
+            ArgumentType::Double { min, max } => write_range!(min, max),
+            ArgumentType::Integer { min, max } => write_range!(min, max),
+            ArgumentType::Long { min, max } => write_range!(min, max),
+            ArgumentType::String(kind) => encode_u32_into(*kind as u32, writer)?,
+            ArgumentType::Entity { single, players_only } => {
+                writer.write_byte(u8::from(*single) | (u8::from(*players_only) << 1))?;
+            }
+            ArgumentType::ScoreHolder { multiple } => writer.write_byte(u8::from(*multiple))?,
+            ArgumentType::Time { min } => writer.write_bytes(&min.to_be_bytes())?,
+            ArgumentType::ResourceOrTag { registry }
+            | ArgumentType::ResourceOrTagKey { registry }
+            | ArgumentType::Resource { registry }
+            | ArgumentType::ResourceKey { registry }
+            | ArgumentType::ResourceSelector { registry } => {
+                write_string(registry.as_str(), writer)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn read_argument(reader: &mut Reader<'_>) -> Result<ArgumentType, ReaderError> {
+        /// Read the flags and bounds of a numeric range.
+        macro_rules! read_range {
+            ($ty:ty) => {{
+                let flags = reader.read_byte()?;
+                let min = if flags & RANGE_MIN != 0 {
+                    Some(<$ty>::from_be_bytes(*reader.read_array()?))
+                } else {
+                    None
+                };
+                let max = if flags & RANGE_MAX != 0 {
+                    Some(<$ty>::from_be_bytes(*reader.read_array()?))
+                } else {
+                    None
+                };
+                (min, max)
+            }};
+        }
+
+        /// Read the registry of a resource argument.
+        macro_rules! read_registry {
+            () => {
+                Identifier::try_new_string(read_string(reader)?).map_err(ReaderError::other)?
+            };
+        }
+
+        let id = decode_u32_from(reader)?;
+        Ok(match id {
+            1 => {
+                let (min, max) = read_range!(f32);
+                ArgumentType::Float { min, max }
+            }
+            2 => {
+                let (min, max) = read_range!(f64);
+                ArgumentType::Double { min, max }
+            }
+            3 => {
+                let (min, max) = read_range!(i32);
+                ArgumentType::Integer { min, max }
+            }
+            4 => {
+                let (min, max) = read_range!(i64);
+                ArgumentType::Long { min, max }
+            }
+            5 => match decode_u32_from(reader)? {
+                0 => ArgumentType::String(StringArgumentKind::SingleWord),
+                1 => ArgumentType::String(StringArgumentKind::QuotablePhrase),
+                2 => ArgumentType::String(StringArgumentKind::GreedyPhrase),
+                other => {
+                    return Err(ReaderError::from_string(format!(
+                        "Invalid string argument kind `{other}`"
+                    )));
+                }
+            },
+            6 => {
+                let flags = reader.read_byte()?;
+                ArgumentType::Entity { single: flags & 0b01 != 0, players_only: flags & 0b10 != 0 }
+            }
+            31 => ArgumentType::ScoreHolder { multiple: reader.read_byte()? & 0b01 != 0 },
+            43 => ArgumentType::Time { min: i32::from_be_bytes(*reader.read_array()?) },
+            44 => ArgumentType::ResourceOrTag { registry: read_registry!() },
+            45 => ArgumentType::ResourceOrTagKey { registry: read_registry!() },
+            46 => ArgumentType::Resource { registry: read_registry!() },
+            47 => ArgumentType::ResourceKey { registry: read_registry!() },
+            48 => ArgumentType::ResourceSelector { registry: read_registry!() },
+            other => ArgumentType::from_id(other).ok_or_else(|| {
+                ReaderError::from_string(format!("Unknown command argument type `{other}`"))
+            })?,
+        })
+    }
+}
@@ -0,0 +1,43 @@
+//! TODO
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+#[cfg(feature = "facet")]
+use facet::Facet;
+
+/// The game mode of a player.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum GameMode {
+    /// Survival mode.
+    #[default]
+    Survival,
+    /// Creative mode.
+    Creative,
+    /// Adventure mode.
+    Adventure,
+    /// Spectator mode.
+    Spectator,
+}
+
+impl GameMode {
+    /// Get the [`GameMode`] with the given id.
+    ///
+    /// Unknown ids are treated as [`GameMode::Survival`], like vanilla.
+    #[must_use]
+    pub const fn from_id(id: u32) -> Self {
+        match id {
+            1 => Self::Creative,
+            2 => Self::Adventure,
+            3 => Self::Spectator,
+            _ => Self::Survival,
+        }
+    }
+
+    /// Get the id of this [`GameMode`].
+    #[must_use]
+    pub const fn id(self) -> u32 { self as u32 }
+}
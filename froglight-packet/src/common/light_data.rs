@@ -1,6 +1,12 @@
 //! TODO
 
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
+
+use froglight_world::{
+    chunk::{ChunkLight, SectionLight},
+    naive::ParseError,
+    prelude::Chunk,
+};
 
 /// Raw light data.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -21,3 +27,124 @@ pub struct RawLightData {
     /// The block light data for each section.
     pub block_light_array: Vec<Vec<u8>>,
 }
+
+impl RawLightData {
+    /// Attempt to parse [`ChunkLight`] from [`RawLightData`].
+    ///
+    /// `sections` is the number of sections in the chunk,
+    /// not including the extra section above and below it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the masks do not match the number of sections,
+    /// or if any light array is the wrong size.
+    pub fn try_parse(&self, sections: usize) -> Result<ChunkLight, ParseError> {
+        let sky = Self::parse_layer(
+            &self.sky_light_mask,
+            &self.empty_sky_light_mask,
+            &self.sky_light_array,
+            sections + 2,
+        )?;
+        let block = Self::parse_layer(
+            &self.block_light_mask,
+            &self.empty_block_light_mask,
+            &self.block_light_array,
+            sections + 2,
+        )?;
+        ChunkLight::new_from(sky, block).ok_or(ParseError::InvalidLight)
+    }
+
+    /// Create [`RawLightData`] from a [`Chunk`].
+    #[inline]
+    #[must_use]
+    pub fn from_chunk(chunk: &Chunk) -> Self { Self::from_light(chunk.light()) }
+
+    /// Create [`RawLightData`] from a [`ChunkLight`].
+    #[must_use]
+    pub fn from_light(light: &ChunkLight) -> Self {
+        let (sky_light_mask, empty_sky_light_mask, sky_light_array) =
+            Self::write_layer(light.sky());
+        let (block_light_mask, empty_block_light_mask, block_light_array) =
+            Self::write_layer(light.block());
+
+        Self {
+            sky_light_mask,
+            block_light_mask,
+            empty_sky_light_mask,
+            empty_block_light_mask,
+            sky_light_array,
+            block_light_array,
+        }
+    }
+
+    /// Parse one type of light for every section.
+    fn parse_layer(
+        mask: &[u64],
+        empty_mask: &[u64],
+        arrays: &[Vec<u8>],
+        sections: usize,
+    ) -> Result<Vec<SectionLight>, ParseError> {
+        // Reject masks that reference sections outside of the chunk.
+        let bits = mask.len().max(empty_mask.len()) * 64;
+        if (sections..bits).any(|index| get_bit(mask, index) || get_bit(empty_mask, index)) {
+            return Err(ParseError::InvalidLight);
+        }
+
+        let mut arrays = arrays.iter();
+        let mut layer = Vec::with_capacity(sections);
+        for index in 0..sections {
+            if get_bit(mask, index) {
+                let array = arrays.next().ok_or(ParseError::InvalidLight)?;
+                let array = <[u8; SectionLight::BYTES]>::try_from(array.as_slice())
+                    .map_err(|_| ParseError::InvalidLight)?;
+                layer.push(SectionLight::Levels(Arc::new(array)));
+            } else if get_bit(empty_mask, index) {
+                layer.push(SectionLight::Empty);
+            } else {
+                layer.push(SectionLight::Unknown);
+            }
+        }
+
+        // Every array must belong to a section.
+        if arrays.next().is_some() {
+            return Err(ParseError::InvalidLight);
+        }
+
+        Ok(layer)
+    }
+
+    /// Write one type of light for every section.
+    fn write_layer(layer: &[SectionLight]) -> (Vec<u64>, Vec<u64>, Vec<Vec<u8>>) {
+        let mut mask = Vec::new();
+        let mut empty_mask = Vec::new();
+        let mut arrays = Vec::new();
+
+        for (index, section) in layer.iter().enumerate() {
+            match section {
+                SectionLight::Unknown => {}
+                SectionLight::Empty => set_bit(&mut empty_mask, index),
+                SectionLight::Levels(levels) => {
+                    set_bit(&mut mask, index);
+                    arrays.push(levels.to_vec());
+                }
+            }
+        }
+
+        (mask, empty_mask, arrays)
+    }
+}
+
+/// Returns `true` if the bit at the given index is set.
+fn get_bit(mask: &[u64], index: usize) -> bool {
+    mask.get(index / 64).is_some_and(|long| long & (1 << (index % 64)) != 0)
+}
+
+/// Set the bit at the given index, growing the mask if necessary.
+///
+/// Masks never end with an empty [`u64`].
+fn set_bit(mask: &mut Vec<u64>, index: usize) {
+    if mask.len() <= index / 64 {
+        mask.resize(index / 64 + 1, 0);
+    }
+    mask[index / 64] |= 1 << (index % 64);
+}
@@ -1,15 +1,23 @@
 //! TODO
 
+pub mod boss_event;
 pub mod chunk_data;
 pub mod client_information;
+pub mod commands;
 pub mod entity_data;
+pub mod game_mode;
 pub mod handshake;
 pub mod known_packs;
 pub mod light_data;
 pub mod login;
 pub mod lpdvec3;
+pub mod player_info;
 pub mod position;
 pub mod registry;
+pub mod scoreboard;
 pub mod spawn_info;
+pub mod text;
+pub mod title;
 pub mod unsized_buffer;
 pub mod update_tags;
+pub mod world_border;
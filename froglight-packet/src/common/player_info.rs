@@ -0,0 +1,308 @@
+//! TODO
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+#[cfg(feature = "facet")]
+use facet::Facet;
+#[cfg(feature = "facet")]
+use froglight_facet::facet::prelude::*;
+use froglight_player::prelude::PlayerProfile;
+use uuid::Uuid;
+
+use crate::common::{game_mode::GameMode, text::NetworkText};
+
+/// An update to entries in the player list.
+///
+/// Every entry contains the fields selected by [`PlayerInfoActions`],
+/// all other fields are `None`.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[cfg_attr(feature = "facet", facet(mc::with = PlayerInfoUpdate::WITH))]
+pub struct PlayerInfoUpdate {
+    /// The fields included in each entry.
+    pub actions: PlayerInfoActions,
+    /// The entries to update.
+    pub entries: Vec<PlayerInfoEntry>,
+}
+
+/// The fields included in a [`PlayerInfoUpdate`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[expect(clippy::struct_excessive_bools, reason = "That's just how it is")]
+pub struct PlayerInfoActions {
+    /// Add a player to the list, see [`PlayerInfoEntry::profile`].
+    pub add_player: bool,
+    /// See [`PlayerInfoEntry::chat_session`].
+    pub initialize_chat: bool,
+    /// See [`PlayerInfoEntry::game_mode`].
+    pub update_game_mode: bool,
+    /// See [`PlayerInfoEntry::listed`].
+    pub update_listed: bool,
+    /// See [`PlayerInfoEntry::latency`].
+    pub update_latency: bool,
+    /// See [`PlayerInfoEntry::display_name`].
+    pub update_display_name: bool,
+    /// See [`PlayerInfoEntry::list_order`].
+    pub update_list_order: bool,
+    /// See [`PlayerInfoEntry::show_hat`].
+    pub update_hat: bool,
+}
+
+impl PlayerInfoActions {
+    /// [`PlayerInfoActions`] where every field is included.
+    pub const ALL: Self = Self {
+        add_player: true,
+        initialize_chat: true,
+        update_game_mode: true,
+        update_listed: true,
+        update_latency: true,
+        update_display_name: true,
+        update_list_order: true,
+        update_hat: true,
+    };
+
+    /// Get the [`PlayerInfoActions`] from their bitset.
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self {
+            add_player: bits & 0b0000_0001 != 0,
+            initialize_chat: bits & 0b0000_0010 != 0,
+            update_game_mode: bits & 0b0000_0100 != 0,
+            update_listed: bits & 0b0000_1000 != 0,
+            update_latency: bits & 0b0001_0000 != 0,
+            update_display_name: bits & 0b0010_0000 != 0,
+            update_list_order: bits & 0b0100_0000 != 0,
+            update_hat: bits & 0b1000_0000 != 0,
+        }
+    }
+
+    /// Get the bitset of these [`PlayerInfoActions`].
+    #[must_use]
+    pub const fn to_bits(self) -> u8 {
+        (self.add_player as u8)
+            | (self.initialize_chat as u8) << 1
+            | (self.update_game_mode as u8) << 2
+            | (self.update_listed as u8) << 3
+            | (self.update_latency as u8) << 4
+            | (self.update_display_name as u8) << 5
+            | (self.update_list_order as u8) << 6
+            | (self.update_hat as u8) << 7
+    }
+}
+
+/// A single entry of a [`PlayerInfoUpdate`].
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct PlayerInfoEntry {
+    /// The [`Uuid`] of the player.
+    pub uuid: Uuid,
+    /// The profile of the player being added.
+    pub profile: Option<PlayerProfile>,
+    /// The chat session of the player, or `Some(None)` if it was cleared.
+    pub chat_session: Option<Option<ChatSession>>,
+    /// The game mode of the player.
+    pub game_mode: Option<GameMode>,
+    /// Whether the player is shown in the player list.
+    pub listed: Option<bool>,
+    /// The latency of the player, in milliseconds.
+    pub latency: Option<i32>,
+    /// The name shown in the player list, or `Some(None)` if it was cleared.
+    pub display_name: Option<Option<NetworkText>>,
+    /// The position of the player in the list, lower values are shown first.
+    pub list_order: Option<i32>,
+    /// Whether the player's hat layer is shown.
+    pub show_hat: Option<bool>,
+}
+
+impl PlayerInfoEntry {
+    /// Create an empty [`PlayerInfoEntry`] for the given [`Uuid`].
+    #[must_use]
+    pub const fn new(uuid: Uuid) -> Self {
+        Self {
+            uuid,
+            profile: None,
+            chat_session: None,
+            game_mode: None,
+            listed: None,
+            latency: None,
+            display_name: None,
+            list_order: None,
+            show_hat: None,
+        }
+    }
+}
+
+/// A player's chat session, used to verify signed messages.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct ChatSession {
+    /// The id of the session.
+    pub session_id: Uuid,
+    /// When the public key expires, in milliseconds since the Unix epoch.
+    pub expires_at: i64,
+    /// The encoded public key.
+    pub public_key: Vec<u8>,
+    /// The signature of the public key.
+    pub key_signature: Vec<u8>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The header and footer of the player list.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct TabListText {
+    /// The text shown above the list.
+    pub header: NetworkText,
+    /// The text shown below the list.
+    pub footer: NetworkText,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "facet")]
+mod template {
+    use alloc::{format, string::String, vec::Vec};
+
+    use facet::Facet;
+    use froglight_facet::{facet::prelude::*, from_slice_remainder, to_vec};
+    use froglight_player::prelude::PlayerProfile;
+    use uuid::Uuid;
+
+    use super::{ChatSession, PlayerInfoActions, PlayerInfoEntry, PlayerInfoUpdate};
+    use crate::common::{game_mode::GameMode, text::NetworkText};
+
+    /// Write a value using its [`Facet`] implementation.
+    fn write<T: Facet<'static>>(value: &T, writer: &mut Writer<'_>) -> Result<(), WriterError> {
+        writer.write_bytes(&to_vec(value).map_err(WriterError::other)?)
+    }
+
+    /// Read a value using its [`Facet`] implementation.
+    fn read<T: Facet<'static>>(reader: &mut Reader<'_>) -> Result<T, ReaderError> {
+        let (value, rem) = from_slice_remainder::<T>(reader.remaining())
+            .map_err(|err| ReaderError::from_string(format!("Failed to decode entry: {err}")))?;
+        reader.consume(reader.remaining().len() - rem.len())?;
+        Ok(value)
+    }
+
+    /// Get a field that must be present because of the entry's actions.
+    fn required<T>(value: Option<T>) -> Result<T, WriterError> {
+        value.ok_or_else(|| {
+            WriterError::from_string(String::from(
+                "Entry is missing a field required by its actions",
+            ))
+        })
+    }
+
+    impl FacetTemplate for PlayerInfoUpdate {
+        #[allow(clippy::cast_possible_truncation, reason = "Ignored")]
+        #[allow(clippy::cast_sign_loss, reason = "Desired behavior")]
+        fn serialize(
+            item: SerializeItem<'_, '_>,
+            writer: &mut Writer<'_>,
+        ) -> Result<(), WriterError> {
+            let update = item.get::<PlayerInfoUpdate>()?;
+            let actions = update.actions;
+
+            writer.write_byte(actions.to_bits())?;
+            encode_u32_into(update.entries.len() as u32, writer)?;
+            for entry in &update.entries {
+                if actions.add_player {
+                    let profile = required(entry.profile.as_ref())?;
+                    if profile.uuid() != &entry.uuid {
+                        return Err(WriterError::from_string(String::from(
+                            "Profile does not match the entry's Uuid",
+                        )));
+                    }
+                    write(profile, writer)?;
+                } else {
+                    write(&entry.uuid, writer)?;
+                }
+                if actions.initialize_chat {
+                    write(required(entry.chat_session.as_ref())?, writer)?;
+                }
+                if actions.update_game_mode {
+                    encode_u32_into(required(entry.game_mode)?.id(), writer)?;
+                }
+                if actions.update_listed {
+                    writer.write_byte(u8::from(required(entry.listed)?))?;
+                }
+                if actions.update_latency {
+                    encode_u32_into(required(entry.latency)? as u32, writer)?;
+                }
+                if actions.update_display_name {
+                    write(required(entry.display_name.as_ref())?, writer)?;
+                }
+                if actions.update_list_order {
+                    encode_u32_into(required(entry.list_order)? as u32, writer)?;
+                }
+                if actions.update_hat {
+                    writer.write_byte(u8::from(required(entry.show_hat)?))?;
+                }
+            }
+
+            Ok(())
+        }
+
+        #[allow(clippy::cast_possible_truncation, reason = "Ignored")]
+        #[allow(clippy::cast_possible_wrap, reason = "Desired behavior")]
+        fn deserialize<'facet, const BORROW: bool>(
+            item: DeserializeItem<'facet, BORROW>,
+            reader: &mut Reader<'_>,
+        ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+            let actions = PlayerInfoActions::from_bits(reader.read_byte()?);
+
+            let count = decode_u32_from(reader)?;
+            let mut entries = Vec::with_capacity(count.min(64) as usize);
+            for _ in 0..count {
+                // The `Uuid` is the first field of a `PlayerProfile`.
+                let mut entry = if actions.add_player {
+                    let profile = read::<PlayerProfile>(reader)?;
+                    let mut entry = PlayerInfoEntry::new(*profile.uuid());
+                    entry.profile = Some(profile);
+                    entry
+                } else {
+                    PlayerInfoEntry::new(read::<Uuid>(reader)?)
+                };
+
+                if actions.initialize_chat {
+                    entry.chat_session = Some(read::<Option<ChatSession>>(reader)?);
+                }
+                if actions.update_game_mode {
+                    entry.game_mode = Some(GameMode::from_id(decode_u32_from(reader)?));
+                }
+                if actions.update_listed {
+                    entry.listed = Some(reader.read_byte()? != 0);
+                }
+                if actions.update_latency {
+                    entry.latency = Some(decode_u32_from(reader)? as i32);
+                }
+                if actions.update_display_name {
+                    entry.display_name = Some(read::<Option<NetworkText>>(reader)?);
+                }
+                if actions.update_list_order {
+                    entry.list_order = Some(decode_u32_from(reader)? as i32);
+                }
+                if actions.update_hat {
+                    entry.show_hat = Some(reader.read_byte()? != 0);
+                }
+
+                entries.push(entry);
+            }
+
+            item.set(PlayerInfoUpdate { actions, entries })
+        }
+    }
+}
@@ -0,0 +1,407 @@
+//! TODO
+
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+#[cfg(feature = "facet")]
+use facet::Facet;
+#[cfg(feature = "facet")]
+use froglight_facet::facet::prelude::*;
+use froglight_nbt::prelude::IndexedNbtCow;
+
+use crate::common::text::NetworkText;
+
+/// A change to a scoreboard objective.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct ObjectiveUpdate {
+    /// The name of the objective.
+    pub name: String,
+    /// The change to apply.
+    pub action: ObjectiveAction,
+}
+
+/// The action of an [`ObjectiveUpdate`].
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum ObjectiveAction {
+    /// Create a new objective.
+    Add(ObjectiveData),
+    /// Remove an existing objective.
+    Remove,
+    /// Update an existing objective.
+    Update(ObjectiveData),
+}
+
+/// The properties of a scoreboard objective.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct ObjectiveData {
+    /// The name shown to players.
+    pub display_name: NetworkText,
+    /// How scores are rendered in the player list.
+    pub render_type: ObjectiveRenderType,
+    /// The default format of scores, if any.
+    pub number_format: Option<NumberFormat>,
+}
+
+/// How the scores of an objective are rendered in the player list.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum ObjectiveRenderType {
+    /// Scores are shown as numbers.
+    #[default]
+    Integer,
+    /// Scores are shown as hearts.
+    Hearts,
+}
+
+/// How a score is formatted.
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum NumberFormat {
+    /// The score is hidden.
+    Blank,
+    /// The score is shown using a style, stored as NBT.
+    Styled(
+        #[cfg_attr(feature = "facet", facet(mc::with = IndexedNbtCow::WITH_UNNAMED))]
+        IndexedNbtCow<'static>,
+    ),
+    /// The score is replaced with fixed text.
+    Fixed(NetworkText),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A change to a score.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct ScoreUpdate {
+    /// The entity or player name that owns the score.
+    pub owner: String,
+    /// The name of the objective.
+    pub objective: String,
+    /// The value of the score.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub value: i32,
+    /// The name shown instead of the owner, if any.
+    #[cfg_attr(feature = "facet", facet(mc::with = OptionTemplate::<NetworkText>::WITH))]
+    pub display_name: Option<NetworkText>,
+    /// The format of the score, if any.
+    pub number_format: Option<NumberFormat>,
+}
+
+/// The removal of a score.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct ScoreReset {
+    /// The entity or player name that owns the score.
+    pub owner: String,
+    /// The objective to remove the score from,
+    /// or `None` to remove the owner from all objectives.
+    pub objective: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A place where an objective can be displayed.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum DisplaySlot {
+    /// The player list.
+    List,
+    /// The sidebar.
+    Sidebar,
+    /// Below player name tags.
+    BelowName,
+    /// The sidebar, for members of a team with the given color.
+    ///
+    /// Only the first 16 [`TeamColor`]s are valid.
+    TeamSidebar(TeamColor),
+}
+
+impl DisplaySlot {
+    /// Get the id of this [`DisplaySlot`].
+    #[must_use]
+    pub const fn id(self) -> u32 {
+        match self {
+            DisplaySlot::List => 0,
+            DisplaySlot::Sidebar => 1,
+            DisplaySlot::BelowName => 2,
+            DisplaySlot::TeamSidebar(color) => 3 + color as u32,
+        }
+    }
+
+    /// Get the [`DisplaySlot`] with the given id.
+    #[must_use]
+    pub const fn from_id(id: u32) -> Option<Self> {
+        match id {
+            0 => Some(DisplaySlot::List),
+            1 => Some(DisplaySlot::Sidebar),
+            2 => Some(DisplaySlot::BelowName),
+            3..=18 => match TeamColor::from_id(id - 3) {
+                Some(color) => Some(DisplaySlot::TeamSidebar(color)),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A change to the objective shown in a [`DisplaySlot`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct DisplayObjective {
+    /// The slot to change.
+    #[cfg_attr(feature = "facet", facet(mc::with = DisplayObjective::WITH))]
+    pub slot: DisplaySlot,
+    /// The name of the objective, or an empty string to clear the slot.
+    pub objective: String,
+}
+
+#[cfg(feature = "facet")]
+impl FacetTemplate for DisplayObjective {
+    fn serialize(item: SerializeItem<'_, '_>, writer: &mut Writer<'_>) -> Result<(), WriterError> {
+        encode_u32_into(item.get::<DisplaySlot>()?.id(), writer)
+    }
+
+    fn deserialize<'facet, const BORROW: bool>(
+        item: DeserializeItem<'facet, BORROW>,
+        reader: &mut Reader<'_>,
+    ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+        let id = decode_u32_from(reader)?;
+        let slot = DisplaySlot::from_id(id).ok_or_else(|| {
+            ReaderError::from_string(alloc::format!("Unknown display slot `{id}`"))
+        })?;
+        item.set(slot)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A change to a team.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct TeamUpdate {
+    /// The name of the team.
+    pub name: String,
+    /// The change to apply.
+    pub action: TeamAction,
+}
+
+/// The action of a [`TeamUpdate`].
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum TeamAction {
+    /// Create a new team with the given members.
+    Add(TeamParameters, Vec<String>),
+    /// Remove an existing team.
+    Remove,
+    /// Update the parameters of an existing team.
+    Update(TeamParameters),
+    /// Add members to an existing team.
+    Join(Vec<String>),
+    /// Remove members from an existing team.
+    Leave(Vec<String>),
+}
+
+/// The properties of a team.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct TeamParameters {
+    /// The name shown to players.
+    pub display_name: NetworkText,
+    /// Whether members can hurt or see each other.
+    pub options: TeamOptions,
+    /// Whose name tags are visible to members.
+    pub name_tag_visibility: TeamVisibility,
+    /// Who members collide with.
+    pub collision_rule: TeamCollisionRule,
+    /// The color of the team.
+    pub color: TeamColor,
+    /// Text shown before member names.
+    pub prefix: NetworkText,
+    /// Text shown after member names.
+    pub suffix: NetworkText,
+}
+
+/// Options that affect how members of a team interact.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[cfg_attr(feature = "facet", facet(mc::with = TeamOptions::WITH))]
+pub struct TeamOptions {
+    /// Whether members can hurt each other.
+    pub friendly_fire: bool,
+    /// Whether members can see invisible members.
+    pub see_friendly_invisibles: bool,
+}
+
+#[cfg(feature = "facet")]
+impl FacetTemplate for TeamOptions {
+    fn serialize(item: SerializeItem<'_, '_>, writer: &mut Writer<'_>) -> Result<(), WriterError> {
+        let data = item.get::<Self>()?;
+
+        let mut output = 0u8;
+        if data.friendly_fire {
+            output |= 0b0000_0001;
+        }
+        if data.see_friendly_invisibles {
+            output |= 0b0000_0010;
+        }
+
+        writer.write_byte(output)
+    }
+
+    fn deserialize<'facet, const BORROW: bool>(
+        item: DeserializeItem<'facet, BORROW>,
+        reader: &mut Reader<'_>,
+    ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+        let data = reader.read_byte()?;
+
+        item.set(Self {
+            friendly_fire: data & 0b0000_0001 != 0,
+            see_friendly_invisibles: data & 0b0000_0010 != 0,
+        })
+    }
+}
+
+/// Whose name tags are visible to members of a team.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum TeamVisibility {
+    /// All name tags are visible.
+    #[default]
+    Always,
+    /// No name tags are visible.
+    Never,
+    /// Name tags of other teams are hidden.
+    HideForOtherTeams,
+    /// Name tags of the same team are hidden.
+    HideForOwnTeam,
+}
+
+/// Who members of a team collide with.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub enum TeamCollisionRule {
+    /// Members collide with everyone.
+    #[default]
+    Always,
+    /// Members collide with no one.
+    Never,
+    /// Members only collide with their own team.
+    PushOtherTeams,
+    /// Members only collide with other teams.
+    PushOwnTeam,
+}
+
+/// The color or formatting of a team.
+///
+/// Variants are ordered by their id.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[expect(missing_docs, reason = "Variants match their formatting names")]
+pub enum TeamColor {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+    Obfuscated,
+    Bold,
+    Strikethrough,
+    Underline,
+    Italic,
+    #[default]
+    Reset,
+}
+
+impl TeamColor {
+    /// All [`TeamColor`]s, in order.
+    pub const ALL: [TeamColor; 22] = [
+        TeamColor::Black,
+        TeamColor::DarkBlue,
+        TeamColor::DarkGreen,
+        TeamColor::DarkAqua,
+        TeamColor::DarkRed,
+        TeamColor::DarkPurple,
+        TeamColor::Gold,
+        TeamColor::Gray,
+        TeamColor::DarkGray,
+        TeamColor::Blue,
+        TeamColor::Green,
+        TeamColor::Aqua,
+        TeamColor::Red,
+        TeamColor::LightPurple,
+        TeamColor::Yellow,
+        TeamColor::White,
+        TeamColor::Obfuscated,
+        TeamColor::Bold,
+        TeamColor::Strikethrough,
+        TeamColor::Underline,
+        TeamColor::Italic,
+        TeamColor::Reset,
+    ];
+
+    /// Get the [`TeamColor`] with the given id.
+    #[must_use]
+    pub const fn from_id(id: u32) -> Option<Self> {
+        if (id as usize) < Self::ALL.len() { Some(Self::ALL[id as usize]) } else { None }
+    }
+
+    /// Returns `true` if this is a color and not a formatting code.
+    #[must_use]
+    pub const fn is_color(self) -> bool { (self as u8) < 16 }
+}
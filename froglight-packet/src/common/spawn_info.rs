@@ -49,3 +49,50 @@ impl FacetTemplate for PlayerSpawnInfo {
         item.set(value)
     }
 }
+
+// -------------------------------------------------------------------------------------------------
+
+/// Which of the player's data is kept when respawning.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[cfg_attr(feature = "facet", facet(mc::with = PlayerDataKept::WITH))]
+pub struct PlayerDataKept {
+    /// Whether the player's attribute modifiers are kept.
+    pub attributes: bool,
+    /// Whether the player's entity data is kept.
+    pub entity_data: bool,
+}
+
+impl PlayerDataKept {
+    /// A [`PlayerDataKept`] where all data is kept.
+    pub const ALL: Self = Self { attributes: true, entity_data: true };
+    /// A [`PlayerDataKept`] where no data is kept.
+    pub const NONE: Self = Self { attributes: false, entity_data: false };
+}
+
+#[cfg(feature = "facet")]
+impl FacetTemplate for PlayerDataKept {
+    fn serialize(item: SerializeItem<'_, '_>, writer: &mut Writer<'_>) -> Result<(), WriterError> {
+        let data = item.get::<Self>()?;
+
+        let mut output = 0u8;
+        if data.attributes {
+            output |= 0b01;
+        }
+        if data.entity_data {
+            output |= 0b10;
+        }
+
+        writer.write_byte(output)
+    }
+
+    fn deserialize<'facet, const BORROW: bool>(
+        item: DeserializeItem<'facet, BORROW>,
+        reader: &mut Reader<'_>,
+    ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+        let data = reader.read_byte()?;
+        item.set(Self { attributes: data & 0b01 != 0, entity_data: data & 0b10 != 0 })
+    }
+}
@@ -0,0 +1,118 @@
+//! TODO
+
+use alloc::string::String;
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::Reflect;
+#[cfg(feature = "facet")]
+use facet::Facet;
+#[cfg(feature = "facet")]
+use froglight_facet::facet::prelude::*;
+use froglight_nbt::prelude::IndexedNbtCow;
+
+/// A text component sent over the network.
+///
+/// Plain text is sent as a single NBT string,
+/// everything else is sent as an unnamed NBT compound.
+#[repr(u8)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+#[cfg_attr(feature = "facet", facet(mc::with = NetworkText::WITH))]
+pub enum NetworkText {
+    /// A plain text component.
+    Text(String),
+    /// A text component stored as NBT.
+    Nbt(IndexedNbtCow<'static>),
+}
+
+impl NetworkText {
+    /// Get the plain text of this [`NetworkText`],
+    /// if it is a plain text component.
+    #[must_use]
+    pub const fn as_text(&self) -> Option<&str> {
+        match self {
+            NetworkText::Text(text) => Some(text.as_str()),
+            NetworkText::Nbt(_) => None,
+        }
+    }
+}
+
+impl Default for NetworkText {
+    fn default() -> Self { Self::Text(String::new()) }
+}
+
+impl From<String> for NetworkText {
+    fn from(value: String) -> Self { Self::Text(value) }
+}
+
+impl From<&str> for NetworkText {
+    fn from(value: &str) -> Self { Self::Text(String::from(value)) }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "facet")]
+mod template {
+    use alloc::format;
+
+    use froglight_facet::facet::prelude::*;
+    use froglight_mutf8::prelude::{MStr, MString};
+    use froglight_nbt::prelude::IndexedNbtCow;
+
+    use super::NetworkText;
+
+    const NBT_STRING: u8 = 8;
+    const NBT_COMPOUND: u8 = 10;
+
+    impl FacetTemplate for NetworkText {
+        #[allow(clippy::cast_possible_truncation, reason = "Ignored")]
+        fn serialize(
+            item: SerializeItem<'_, '_>,
+            writer: &mut Writer<'_>,
+        ) -> Result<(), WriterError> {
+            match item.get::<NetworkText>()? {
+                NetworkText::Text(text) => {
+                    let text = MString::from_utf8(text);
+                    writer.write_byte(NBT_STRING)?;
+                    writer.write_bytes(&(text.as_bytes().len() as u16).to_be_bytes())?;
+                    writer.write_bytes(text.as_bytes())
+                }
+                NetworkText::Nbt(nbt) => writer.write_bytes(nbt.as_slice()),
+            }
+        }
+
+        fn deserialize<'facet, const BORROW: bool>(
+            item: DeserializeItem<'facet, BORROW>,
+            reader: &mut Reader<'_>,
+        ) -> Result<DeserializeItem<'facet, BORROW>, ReaderError> {
+            match reader.remaining().first().copied() {
+                Some(NBT_STRING) => {
+                    reader.consume(1)?;
+                    let length = u16::from_be_bytes(*reader.read_array::<2>()?);
+                    let content = reader.read(usize::from(length))?;
+                    let content = MStr::from_mutf8(content).map_err(|err| {
+                        ReaderError::from_string(format!("Invalid MUTF-8 text: {err}"))
+                    })?;
+                    item.set(NetworkText::Text(content.to_utf8().into_owned()))
+                }
+                Some(NBT_COMPOUND) => {
+                    let (nbt, rem) =
+                        froglight_facet::from_slice_remainder::<IndexedNbtCow<'static>>(
+                            reader.remaining(),
+                        )
+                        .map_err(|err| {
+                            ReaderError::from_string(format!("Failed to decode text: {err}"))
+                        })?;
+                    reader.consume(reader.remaining().len() - rem.len())?;
+                    item.set(NetworkText::Nbt(nbt))
+                }
+                Some(other) => {
+                    Err(ReaderError::from_string(format!("Unsupported text NBT tag `{other}`")))
+                }
+                None => Err(ReaderError::EndOfInput(1)),
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! TODO
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+#[cfg(feature = "facet")]
+use facet::Facet;
+
+/// How long titles fade in, stay, and fade out, in ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct TitleTimes {
+    /// The number of ticks spent fading in.
+    pub fade_in: i32,
+    /// The number of ticks spent fully visible.
+    pub stay: i32,
+    /// The number of ticks spent fading out.
+    pub fade_out: i32,
+}
+
+impl TitleTimes {
+    /// The [`TitleTimes`] used when none are set.
+    pub const DEFAULT: Self = Self { fade_in: 10, stay: 70, fade_out: 20 };
+
+    /// Get the total number of ticks a title is shown.
+    #[must_use]
+    pub const fn total(&self) -> i32 { self.fade_in + self.stay + self.fade_out }
+}
+
+impl Default for TitleTimes {
+    fn default() -> Self { Self::DEFAULT }
+}
@@ -0,0 +1,55 @@
+//! TODO
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+#[cfg(feature = "facet")]
+use facet::Facet;
+#[cfg(feature = "facet")]
+use froglight_facet::facet::prelude::*;
+
+/// The initial state of the world border.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Default, Clone, PartialEq))]
+#[cfg_attr(feature = "facet", derive(Facet))]
+pub struct WorldBorderInit {
+    /// The X coordinate of the center of the border.
+    pub center_x: f64,
+    /// The Z coordinate of the center of the border.
+    pub center_z: f64,
+    /// The diameter of the border when the transition started.
+    pub old_size: f64,
+    /// The diameter of the border when the transition ends.
+    pub new_size: f64,
+    /// The duration of the transition, in milliseconds.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub lerp_time: u64,
+    /// The maximum distance from the center that portals can teleport to.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub absolute_max_size: u32,
+    /// The distance from the border at which the warning is shown, in blocks.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub warning_blocks: u32,
+    /// How long before the border reaches a player the warning is shown, in
+    /// seconds.
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub warning_time: u32,
+}
+
+impl WorldBorderInit {
+    /// The [`WorldBorderInit`] used when the server has not sent one.
+    pub const DEFAULT: Self = Self {
+        center_x: 0.0,
+        center_z: 0.0,
+        old_size: 59_999_968.0,
+        new_size: 59_999_968.0,
+        lerp_time: 0,
+        absolute_max_size: 29_999_984,
+        warning_blocks: 5,
+        warning_time: 15,
+    };
+}
+
+impl Default for WorldBorderInit {
+    fn default() -> Self { Self::DEFAULT }
+}
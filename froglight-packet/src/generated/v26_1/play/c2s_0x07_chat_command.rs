@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:chat_command"
+//!
+//! @manual packet for "minecraft:chat_command"
+
+use alloc::string::String;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct ChatCommandC2SPacket;
+pub struct ChatCommandC2SPacket {
+    pub command: String,
+}
@@ -1,9 +1,18 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:command_suggestion"
+//!
+//! @manual packet for "minecraft:command_suggestion"
+
+use alloc::string::String;
+
+#[cfg(feature = "facet")]
+use froglight_facet as mc;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct CommandSuggestionC2SPacket;
+pub struct CommandSuggestionC2SPacket {
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub id: u32,
+    pub command: String,
+}
@@ -1,9 +1,20 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:block_entity_data"
+//!
+//! @manual packet for "minecraft:block_entity_data"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg(feature = "facet")]
+use froglight_facet as mc;
+use froglight_nbt::prelude::IndexedNbtCow;
+use froglight_world::component::BlockPos;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(opaque, Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct BlockEntityDataS2CPacket {}
+pub struct BlockEntityDataS2CPacket {
+    #[cfg_attr(feature = "facet", facet(mc::with = BlockPos::WITH_PACKED))]
+    pub position: BlockPos,
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub kind: u32,
+    pub nbt: IndexedNbtCow<'static>,
+}
@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:boss_event"
+//!
+//! @manual packet for "minecraft:boss_event"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use crate::common::boss_event::BossEvent;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct BossEventS2CPacket;
+pub struct BossEventS2CPacket {
+    pub event: BossEvent,
+}
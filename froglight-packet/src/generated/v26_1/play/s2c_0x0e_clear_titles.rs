@@ -1,9 +1,11 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:clear_titles"
+//!
+//! @manual packet for "minecraft:clear_titles"
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct ClearTitlesS2CPacket;
+pub struct ClearTitlesS2CPacket {
+    pub reset: bool,
+}
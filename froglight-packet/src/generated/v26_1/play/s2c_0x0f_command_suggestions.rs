@@ -1,9 +1,11 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:command_suggestions"
+//!
+//! @manual packet for "minecraft:command_suggestions"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use crate::common::commands::CommandSuggestionsContent;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct CommandSuggestionsS2CPacket {}
+pub struct CommandSuggestionsS2CPacket(pub CommandSuggestionsContent);
@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:commands"
+//!
+//! @manual packet for "minecraft:commands"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use crate::common::commands::CommandTree;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct CommandsS2CPacket;
+pub struct CommandsS2CPacket {
+    pub tree: CommandTree,
+}
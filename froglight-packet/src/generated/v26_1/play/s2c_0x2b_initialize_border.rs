@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:initialize_border"
+//!
+//! @manual packet for "minecraft:initialize_border"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use crate::common::world_border::WorldBorderInit;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct InitializeBorderS2CPacket;
+pub struct InitializeBorderS2CPacket {
+    pub border: WorldBorderInit,
+}
@@ -1,9 +1,15 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:player_info_remove"
+//!
+//! @manual packet for "minecraft:player_info_remove"
+
+use alloc::vec::Vec;
+
+use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct PlayerInfoRemoveS2CPacket;
+pub struct PlayerInfoRemoveS2CPacket {
+    pub profiles: Vec<Uuid>,
+}
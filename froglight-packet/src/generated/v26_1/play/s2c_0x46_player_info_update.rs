@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:player_info_update"
+//!
+//! @manual packet for "minecraft:player_info_update"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use crate::common::player_info::PlayerInfoUpdate;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct PlayerInfoUpdateS2CPacket;
+pub struct PlayerInfoUpdateS2CPacket {
+    pub update: PlayerInfoUpdate,
+}
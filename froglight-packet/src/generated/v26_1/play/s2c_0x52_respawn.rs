@@ -1,9 +1,14 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:respawn"
+//!
+//! @manual packet for "minecraft:respawn"
+
+use crate::common::spawn_info::{PlayerDataKept, PlayerSpawnInfo};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct RespawnS2CPacket;
+pub struct RespawnS2CPacket {
+    pub spawn_info: PlayerSpawnInfo,
+    pub data_kept: PlayerDataKept,
+}
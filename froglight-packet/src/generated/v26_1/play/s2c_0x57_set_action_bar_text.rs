@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:set_action_bar_text"
+//!
+//! @manual packet for "minecraft:set_action_bar_text"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use crate::common::text::NetworkText;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct SetActionBarTextS2CPacket {}
+pub struct SetActionBarTextS2CPacket {
+    pub text: NetworkText,
+}
@@ -1,9 +1,17 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:set_border_lerp_size"
+//!
+//! @manual packet for "minecraft:set_border_lerp_size"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg(feature = "facet")]
+use froglight_facet as mc;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct SetBorderLerpSizeS2CPacket;
+pub struct SetBorderLerpSizeS2CPacket {
+    pub old_size: f64,
+    pub new_size: f64,
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub lerp_time: u64,
+}
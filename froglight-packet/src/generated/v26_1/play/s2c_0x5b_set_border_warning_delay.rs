@@ -1,9 +1,15 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:set_border_warning_delay"
+//!
+//! @manual packet for "minecraft:set_border_warning_delay"
+
+#[cfg(feature = "facet")]
+use froglight_facet as mc;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct SetBorderWarningDelayS2CPacket;
+pub struct SetBorderWarningDelayS2CPacket {
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub warning_time: u32,
+}
@@ -1,9 +1,15 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:set_border_warning_distance"
+//!
+//! @manual packet for "minecraft:set_border_warning_distance"
+
+#[cfg(feature = "facet")]
+use froglight_facet as mc;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct SetBorderWarningDistanceS2CPacket;
+pub struct SetBorderWarningDistanceS2CPacket {
+    #[cfg_attr(feature = "facet", facet(mc::variable))]
+    pub warning_blocks: u32,
+}
@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:set_objective"
+//!
+//! @manual packet for "minecraft:set_objective"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use crate::common::scoreboard::ObjectiveUpdate;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct SetObjectiveS2CPacket;
+pub struct SetObjectiveS2CPacket {
+    pub objective: ObjectiveUpdate,
+}
@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:set_titles_animation"
+//!
+//! @manual packet for "minecraft:set_titles_animation"
+
+use crate::common::title::TitleTimes;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct SetTitlesAnimationS2CPacket;
+pub struct SetTitlesAnimationS2CPacket {
+    pub times: TitleTimes,
+}
@@ -1,9 +1,13 @@
 //! This file is auto-generated. Disable this by adding a `manual` tag.
-//! 
-//! @generated packet for "minecraft:tab_list"
+//!
+//! @manual packet for "minecraft:tab_list"
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use crate::common::player_info::TabListText;
+
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
 #[cfg_attr(feature = "facet", derive(facet::Facet))]
-pub struct TabListS2CPacket {}
+pub struct TabListS2CPacket {
+    pub text: TabListText,
+}
@@ -7,6 +7,8 @@ extern crate std;
 
 pub mod common;
 pub mod generated;
+#[cfg(feature = "schema")]
+pub mod schema;
 pub mod version;
 
 pub mod prelude {
@@ -0,0 +1,46 @@
+//! Protocol schemas for every [`PacketVersion`].
+
+use facet::Facet;
+use froglight_common::prelude::Identifier;
+use froglight_facet::schema::{ProtocolSchema, SchemaBuilder, WireType};
+use froglight_nbt::prelude::IndexedNbtCow;
+
+use crate::version::{PacketState, PacketStateEnum, PacketVersion};
+
+/// Create a [`SchemaBuilder`] that knows about the
+/// custom serialized types used by packets.
+#[must_use]
+pub fn schema_builder() -> SchemaBuilder {
+    SchemaBuilder::new()
+        .with_override(Identifier::SHAPE, WireType::String)
+        .with_override(IndexedNbtCow::SHAPE, WireType::Nbt)
+        .with_override(
+            Option::<IndexedNbtCow>::SHAPE,
+            WireType::Optional { item: alloc::boxed::Box::new(WireType::Nbt) },
+        )
+}
+
+/// Describe every packet of a [`PacketVersion`], grouped by state.
+#[must_use]
+pub fn protocol_schema<V: PacketVersion>() -> ProtocolSchema {
+    /// Describe a single [`PacketState`].
+    fn state<V: PacketVersion, S: PacketState<V>>(
+        builder: &mut SchemaBuilder,
+        state: PacketStateEnum,
+    ) -> froglight_facet::schema::StateSchema {
+        builder.describe_state(
+            alloc::format!("{state}"),
+            S::Clientbound::SHAPE,
+            S::Serverbound::SHAPE,
+        )
+    }
+
+    let mut builder = schema_builder();
+    let mut schema = ProtocolSchema::new(V::PROTOCOL_ID);
+    schema.states.push(state::<V, V::Handshake>(&mut builder, PacketStateEnum::Handshake));
+    schema.states.push(state::<V, V::Status>(&mut builder, PacketStateEnum::Status));
+    schema.states.push(state::<V, V::Login>(&mut builder, PacketStateEnum::Login));
+    schema.states.push(state::<V, V::Config>(&mut builder, PacketStateEnum::Config));
+    schema.states.push(state::<V, V::Play>(&mut builder, PacketStateEnum::Play));
+    schema
+}
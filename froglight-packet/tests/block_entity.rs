@@ -0,0 +1,148 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use froglight_block::prelude::*;
+use froglight_common::{identifier::Identifier, version::V26_1};
+use froglight_facet::{from_slice, to_vec};
+use froglight_mutf8::prelude::MString;
+use froglight_nbt::prelude::*;
+use froglight_packet::{
+    common::chunk_data::RawEntityData, generated::v26_1::play::BlockEntityDataS2CPacket,
+};
+use froglight_world::{
+    component::ChunkBlockPos,
+    prelude::{BlockEntity, BlockPos, Chunk},
+};
+
+/// Create a sign with text on both sides.
+fn sign_nbt() -> NbtCompound {
+    let mut front = NbtCompound::new();
+    front.insert(
+        "messages",
+        NbtList::String(vec![
+            MString::from("Hello"),
+            MString::from("Frog"),
+            MString::new(),
+            MString::new(),
+        ]),
+    );
+    front.insert("color", MString::from("lime"));
+    front.insert("has_glowing_text", 1u8);
+
+    let mut line = NbtCompound::new();
+    line.insert("text", MString::from("Ribbit"));
+    line.insert("extra", NbtList::String(vec![MString::from("!")]));
+    let mut back = NbtCompound::new();
+    back.insert("messages", NbtList::Compound(vec![line]));
+
+    let mut nbt = NbtCompound::new();
+    nbt.insert("front_text", front);
+    nbt.insert("back_text", back);
+    nbt.insert("is_waxed", 0u8);
+    nbt
+}
+
+/// Convert a [`NbtCompound`] into network NBT.
+fn indexed(nbt: NbtCompound) -> IndexedNbtCow<'static> {
+    let bytes = Nbt::new(None, nbt).to_unnamed_bytes();
+    IndexedNbtSlice::new_unnamed(bytes.as_slice()).unwrap().into_owned()
+}
+
+#[test]
+fn block_entity_data() {
+    let kind = V26_1::blocks().get_block_entity_id(VanillaBlockEntity::Sign).unwrap();
+    assert_eq!(V26_1::blocks().get_block_entity_by_id(kind), Some(VanillaBlockEntity::Sign));
+
+    let packet = BlockEntityDataS2CPacket {
+        position: BlockPos::new_xyz(-12, -60, 300),
+        kind,
+        nbt: indexed(sign_nbt()),
+    };
+
+    let bytes = to_vec(&packet).unwrap();
+    let decoded = from_slice::<BlockEntityDataS2CPacket>(&bytes).unwrap();
+    assert_eq!(decoded, packet);
+    assert_eq!(Nbt::try_from(&decoded.nbt).unwrap().into_compound(), sign_nbt());
+}
+
+#[test]
+fn block_entity_views() {
+    let entity = BlockEntity::new(VanillaBlockEntity::Sign, sign_nbt());
+    assert!(entity.as_mob_spawner().is_none());
+
+    let sign = entity.as_sign().unwrap();
+    assert!(!sign.is_waxed());
+
+    let front = sign.front().unwrap();
+    assert_eq!(front.lines(), ["Hello", "Frog", "", ""].map(alloc::string::String::from));
+    assert_eq!(front.color().as_deref(), Some("lime"));
+    assert!(front.is_glowing());
+
+    let back = sign.back().unwrap();
+    assert_eq!(back.lines(), ["Ribbit!", "", "", ""].map(alloc::string::String::from));
+    assert!(!back.is_glowing());
+
+    // Hanging signs have their own view with the same accessors.
+    let entity = BlockEntity::new(VanillaBlockEntity::HangingSign, sign_nbt());
+    assert!(entity.as_sign().is_none());
+    let hanging = entity.as_hanging_sign().unwrap();
+    assert_eq!(hanging.front().unwrap().lines(), front.lines());
+    assert_eq!(hanging.data(), &sign_nbt());
+
+    // Every block entity type has a view.
+    let entity = BlockEntity::new(VanillaBlockEntity::Chest, NbtCompound::new());
+    assert!(entity.as_chest().is_some());
+    assert!(entity.as_trapped_chest().is_none());
+
+    let mut entity = NbtCompound::new();
+    entity.insert("id", MString::from("minecraft:zombie"));
+    let mut spawn_data = NbtCompound::new();
+    spawn_data.insert("entity", entity);
+    let mut nbt = NbtCompound::new();
+    nbt.insert("SpawnData", spawn_data);
+    nbt.insert("Delay", 20u16);
+
+    let entity = BlockEntity::new(VanillaBlockEntity::MobSpawner, nbt);
+    let spawner = entity.as_mob_spawner().unwrap();
+    assert_eq!(spawner.entity_type(), Some(Identifier::new_static("minecraft:zombie")));
+    assert_eq!(spawner.delay(), Some(20));
+}
+
+#[test]
+fn chunk_block_entities() {
+    #[expect(clippy::cast_sign_loss, reason = "Packed as the signed world height")]
+    let raw = RawEntityData {
+        position: ChunkBlockPos::new_xyz(4, -60i16 as u16, 13),
+        kind: V26_1::blocks().get_block_entity_id(VanillaBlockEntity::Sign).unwrap(),
+        nbt: indexed(sign_nbt()),
+    };
+
+    let (position, entity) = raw.try_parse::<V26_1>().unwrap();
+    assert_eq!(position, BlockPos::new_xyz(4, -60, 13));
+    assert_eq!(entity.kind(), VanillaBlockEntity::Sign);
+
+    let mut chunk = Chunk::empty_large::<V26_1>();
+    let sign = Block::new_default::<block::OakSign, V26_1>();
+    chunk.set_block(position, sign);
+    assert!(chunk.set_block_entity(position, entity.clone()).is_none());
+    assert_eq!(chunk.get_block_entity(position), Some(&entity));
+    assert_eq!(chunk.iter_block_entities().count(), 1);
+
+    // Changing the block's state keeps the block entity.
+    let mut rotated = sign;
+    rotated.set_attribute_str("rotation", "4").unwrap();
+    chunk.set_block(position, rotated);
+    assert_eq!(chunk.get_block_entity(position), Some(&entity));
+
+    // Changing the block's type removes the block entity.
+    chunk.set_block(position, Block::new_default::<block::Stone, V26_1>());
+    assert!(chunk.get_block_entity(position).is_none());
+
+    // Block entities outside of the chunk are rejected.
+    assert!(chunk.set_block_entity(BlockPos::new_xyz(0, 320, 0), entity).is_none());
+    assert_eq!(chunk.iter_block_entities().count(), 0);
+}
@@ -0,0 +1,328 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use froglight_biome::prelude::*;
+use froglight_block::prelude::*;
+use froglight_common::version::V26_1;
+use froglight_facet::{from_slice, to_vec};
+use froglight_nbt::prelude::*;
+use froglight_packet::{
+    common::{
+        chunk_data::{RawChunkData, RawEntityData, RawHeightMapData},
+        light_data::RawLightData,
+    },
+    generated::v26_1::play::LevelChunkWithLightS2CPacket,
+};
+use froglight_world::{
+    chunk::{Heightmap, SectionLight},
+    component::ChunkBlockPos,
+    prelude::{BlockPos, BlockPosIter, Chunk, HeightmapKind},
+    section::SectionPalette,
+};
+
+/// Write a variable-length integer.
+fn varint(mut value: u32, output: &mut Vec<u8>) {
+    while value >= 0x80 {
+        output.push(u8::try_from(value & 0x7F).unwrap() | 0x80);
+        value >>= 7;
+    }
+    output.push(u8::try_from(value).unwrap());
+}
+
+/// Pack values the way the server does, without spanning across longs.
+fn packed(values: &[u32], bits: usize, output: &mut Vec<u8>) {
+    let per_long = 64 / bits;
+    for chunk in values.chunks(per_long) {
+        let mut long = 0u64;
+        for (index, value) in chunk.iter().enumerate() {
+            long |= u64::from(*value) << (index * bits);
+        }
+        output.extend_from_slice(&long.to_be_bytes());
+    }
+}
+
+/// Write paletted data the way the server does.
+fn paletted(bits: u8, palette: Option<&[u32]>, values: &[u32], output: &mut Vec<u8>) {
+    output.push(bits);
+    if let Some(palette) = palette {
+        varint(u32::try_from(palette.len()).unwrap(), output);
+        for value in palette {
+            varint(*value, output);
+        }
+    }
+    packed(values, usize::from(bits), output);
+}
+
+/// The number of bits used by the global block and biome palettes.
+fn global_bits() -> (u8, u8) {
+    let states: usize =
+        V26_1::blocks().metadata().iter().map(|block| usize::from(block.state_count())).sum();
+    let biomes = V26_1::biomes().metadata().len();
+    (
+        u8::try_from(usize::BITS - (states - 1).leading_zeros()).unwrap(),
+        u8::try_from(usize::BITS - (biomes - 1).leading_zeros()).unwrap(),
+    )
+}
+
+/// Create chunk data using every type of palette.
+fn chunk_data() -> Vec<u8> {
+    let (block_bits, biome_bits) = global_bits();
+    let stone = Block::new_default::<block::Stone, V26_1>().global_id().into_inner();
+    let dirt = Block::new_default::<block::Dirt, V26_1>().global_id().into_inner();
+
+    let mut output = Vec::new();
+    for section in 0..24u32 {
+        match section % 4 {
+            // A single block and biome.
+            0 => {
+                output.extend_from_slice(&0u16.to_be_bytes());
+                output.extend_from_slice(&0u16.to_be_bytes());
+                output.extend_from_slice(&[0, 0, 0, 0]);
+            }
+            // A palette with an unused entry, and a small biome palette.
+            1 => {
+                let blocks: Vec<u32> = (0..4096).map(|index| (index % 3 == 0).into()).collect();
+                output.extend_from_slice(&1366u16.to_be_bytes());
+                output.extend_from_slice(&0u16.to_be_bytes());
+                paletted(4, Some(&[0, stone, dirt]), &blocks, &mut output);
+
+                let biomes: Vec<u32> = (0..64).map(|index| index % 2).collect();
+                paletted(1, Some(&[0, 1]), &biomes, &mut output);
+            }
+            // A palette needing more than 4 bits.
+            2 => {
+                let palette: Vec<u32> = (0..20).map(|index| stone + index * 7).collect();
+                let blocks: Vec<u32> = (0..4096).map(|index| index % 20).collect();
+                output.extend_from_slice(&4096u16.to_be_bytes());
+                output.extend_from_slice(&0u16.to_be_bytes());
+                paletted(5, Some(&palette), &blocks, &mut output);
+                output.extend_from_slice(&[0, 3]);
+            }
+            // The global palette.
+            _ => {
+                let blocks: Vec<u32> = (0..4096).map(|index| (index * 5) % 1000).collect();
+                output.extend_from_slice(&4000u16.to_be_bytes());
+                output.extend_from_slice(&0u16.to_be_bytes());
+                paletted(block_bits, None, &blocks, &mut output);
+
+                let biomes: Vec<u32> = (0..64).map(|index| index % 40).collect();
+                paletted(biome_bits, None, &biomes, &mut output);
+            }
+        }
+    }
+    output
+}
+
+/// Create a sign block entity.
+fn sign_nbt() -> IndexedNbtCow<'static> {
+    let mut nbt = NbtCompound::new();
+    nbt.insert("is_waxed", 1u8);
+    nbt.insert("front_text", NbtCompound::new());
+
+    let bytes = Nbt::new(None, nbt).to_unnamed_bytes();
+    IndexedNbtSlice::new_unnamed(bytes.as_slice()).unwrap().into_owned()
+}
+
+#[test]
+fn raw_round_trip() {
+    let mut heightmap = Heightmap::empty();
+    heightmap.set(2, 3, 100);
+
+    let raw = RawChunkData {
+        heightmaps: vec![
+            RawHeightMapData {
+                kind: HeightmapKind::WorldSurface.id(),
+                data: heightmap.to_packed(384),
+            },
+            RawHeightMapData {
+                kind: HeightmapKind::MotionBlocking.id(),
+                data: Heightmap::empty().to_packed(384),
+            },
+        ],
+        chunk_data: chunk_data(),
+        entity_data: vec![RawEntityData {
+            #[expect(clippy::cast_sign_loss, reason = "Packed as the signed world height")]
+            position: ChunkBlockPos::new_xyz(1, -20i16 as u16, 14),
+            kind: V26_1::blocks().get_block_entity_id(VanillaBlockEntity::Sign).unwrap(),
+            nbt: sign_nbt(),
+        }],
+    };
+
+    let chunk = raw.try_parse::<V26_1>(320, -64).unwrap();
+
+    // Values are read from the correct positions.
+    let stone = Block::new_default::<block::Stone, V26_1>();
+    let air = Block::new_default::<block::Air, V26_1>();
+    assert_eq!(chunk.get_block(BlockPos::new_xyz(0, -48, 0)), Some(stone));
+    assert_eq!(chunk.get_block(BlockPos::new_xyz(1, -48, 0)), Some(air));
+    assert_eq!(chunk.get_block(BlockPos::new_xyz(3, -48, 0)), Some(stone));
+    assert_eq!(chunk.get_block(BlockPos::new_xyz(15, -48, 0)), Some(stone));
+    assert_eq!(chunk.get_block(BlockPos::new_xyz(15, -33, 15)), Some(stone));
+    let section = &chunk.sections()[2];
+    assert_eq!(section.block_data().bits_per_entry(), 5);
+    assert_eq!(
+        section.get_raw_block(ChunkBlockPos::new_xyz(13, 0, 0).as_section_blockpos()),
+        stone.global_id().into_inner() + 91
+    );
+
+    // Writing the chunk reproduces the input exactly.
+    assert_eq!(RawChunkData::from_chunk(&chunk), raw);
+}
+
+/// A hand-encoded `level_chunk_with_light` packet for a default superflat
+/// chunk.
+///
+/// This is not a capture from a server, so it only checks that reading and
+/// writing agree with each other.
+static SUPERFLAT: &[u8] = include_bytes!("chunk/superflat.bin");
+
+#[test]
+fn superflat_hand_encoded() {
+    let packet = from_slice::<LevelChunkWithLightS2CPacket>(SUPERFLAT).unwrap();
+    assert_eq!((packet.chunk_x, packet.chunk_z), (0, 0));
+
+    let mut chunk = packet.chunk_data.try_parse::<V26_1>(320, -64).unwrap();
+    chunk.set_light(packet.light_data.try_parse(chunk.sections().len()).unwrap());
+
+    assert_eq!(
+        chunk.get_block(BlockPos::new_xyz(0, -64, 0)),
+        Some(Block::new_default::<block::Bedrock, V26_1>())
+    );
+    assert_eq!(
+        chunk.get_block(BlockPos::new_xyz(5, -61, 9)),
+        Some(Block::new_default::<block::GrassBlock, V26_1>())
+    );
+    assert_eq!(
+        chunk.get_block(BlockPos::new_xyz(5, -60, 9)),
+        Some(Block::new_default::<block::Air, V26_1>())
+    );
+    assert_eq!(chunk.get_sky_light(BlockPos::new_xyz(5, -60, 9)), Some(15));
+
+    // Writing the chunk reproduces the packet exactly.
+    let written = LevelChunkWithLightS2CPacket {
+        chunk_x: packet.chunk_x,
+        chunk_z: packet.chunk_z,
+        chunk_data: RawChunkData::from_chunk(&chunk),
+        light_data: RawLightData::from_chunk(&chunk),
+    };
+    assert_eq!(written, packet);
+    assert_eq!(to_vec(&written).unwrap(), SUPERFLAT);
+}
+
+#[test]
+fn invalid_block_entity() {
+    let sign = V26_1::blocks().get_block_entity_id(VanillaBlockEntity::Sign).unwrap();
+    #[expect(clippy::cast_sign_loss, reason = "Packed as the signed world height")]
+    let entity = |x: u8, y: i16, kind: u32| RawEntityData {
+        position: ChunkBlockPos::new_xyz(x, y as u16, 0),
+        kind,
+        nbt: sign_nbt(),
+    };
+
+    let raw = RawChunkData {
+        heightmaps: Vec::new(),
+        chunk_data: chunk_data(),
+        entity_data: vec![entity(0, 0, u32::MAX), entity(1, -100, sign), entity(2, 10, sign)],
+    };
+
+    // Invalid block entities are skipped instead of failing the whole chunk.
+    let chunk = raw.try_parse::<V26_1>(320, -64).unwrap();
+    assert_eq!(chunk.iter_block_entities().count(), 1);
+    let block_entity = chunk.get_block_entity(BlockPos::new_xyz(2, 10, 0)).unwrap();
+    assert_eq!(block_entity.kind(), VanillaBlockEntity::Sign);
+}
+
+#[test]
+fn edited() {
+    let (block_bits, _) = global_bits();
+    let stone = Block::new_default::<block::Stone, V26_1>();
+    let air = Block::new_default::<block::Air, V26_1>();
+
+    let mut chunk = Chunk::empty_large::<V26_1>();
+    chunk.set_block(BlockPos::new_xyz(4, 0, 4), stone);
+    chunk.set_block(BlockPos::new_xyz(5, 16, 4), stone);
+    chunk.set_block(BlockPos::new_xyz(5, 16, 4), air);
+
+    let raw = RawChunkData::from_chunk(&chunk);
+    let parsed = raw.try_parse::<V26_1>(320, -64).unwrap();
+    assert!(parsed.as_naive().iter_raw_blocks().eq(chunk.as_naive().iter_raw_blocks()));
+
+    // Edited sections are written using the smallest valid palette.
+    let section = &parsed.sections()[4];
+    assert_eq!(section.block_data().bits_per_entry(), 4);
+    assert_eq!(
+        section.block_data().palette(),
+        &SectionPalette::Vector(
+            [air, stone].map(|block| block.global_id().into_inner()).as_slice().into()
+        )
+    );
+
+    // Optimizing removes unused palette entries.
+    chunk.optimize();
+    assert_eq!(chunk.sections()[5].block_data().palette(), &SectionPalette::Single(0));
+    assert_eq!(RawChunkData::from_chunk(&chunk), raw);
+
+    // Sections with too many blocks use the global palette.
+    let mut chunk = Chunk::empty_normal::<V26_1>();
+    for (index, position) in
+        BlockPosIter::new_inclusive(BlockPos::new_xyz(0, 0, 0), BlockPos::new_xyz(15, 1, 15))
+            .enumerate()
+    {
+        let block = V26_1::blocks()
+            .get_block_by_state(GlobalStateId::new(u32::try_from(index).unwrap() * 3))
+            .unwrap();
+        chunk.set_block(position, block);
+    }
+    chunk.optimize();
+    assert_eq!(chunk.sections()[0].block_data().bits_per_entry(), usize::from(block_bits));
+    assert_eq!(chunk.sections()[0].block_data().palette(), &SectionPalette::Global);
+
+    let raw = RawChunkData::from_chunk(&chunk);
+    assert_eq!(raw.try_parse::<V26_1>(256, 0).unwrap(), chunk);
+    assert_eq!(
+        chunk.get_block(BlockPos::new_xyz(15, 1, 15)),
+        V26_1::blocks().get_block_by_state(GlobalStateId::new(511 * 3))
+    );
+}
+
+#[test]
+fn light() {
+    let mut levels = [0u8; SectionLight::BYTES];
+    levels[0] = 0xF3;
+    levels[2047] = 0x70;
+
+    let raw = RawLightData {
+        sky_light_mask: vec![0b1100],
+        block_light_mask: vec![1 << 24],
+        empty_sky_light_mask: vec![0b0011],
+        empty_block_light_mask: Vec::new(),
+        sky_light_array: vec![levels.to_vec(), vec![0xFF; SectionLight::BYTES]],
+        block_light_array: vec![levels.to_vec()],
+    };
+
+    let light = raw.try_parse(24).unwrap();
+    assert_eq!(light.sky().len(), 26);
+    assert_eq!(light.sky()[0], SectionLight::Empty);
+    assert_eq!(light.sky()[4], SectionLight::Unknown);
+    assert_eq!(light.get_sky(ChunkBlockPos::new_xyz(0, 16, 0)), Some(3));
+    assert_eq!(light.get_sky(ChunkBlockPos::new_xyz(1, 16, 0)), Some(15));
+    assert_eq!(light.get_sky(ChunkBlockPos::new_xyz(15, 31, 15)), Some(7));
+    assert_eq!(light.get_sky(ChunkBlockPos::new_xyz(15, 47, 15)), Some(15));
+    assert_eq!(light.get_block(ChunkBlockPos::new_xyz(15, 383, 15)), Some(7));
+    assert_eq!(light.get_block(ChunkBlockPos::new_xyz(0, 0, 0)), None);
+
+    // Writing the light reproduces the input exactly.
+    assert_eq!(RawLightData::from_light(&light), raw);
+
+    let mut chunk = Chunk::empty_large::<V26_1>();
+    chunk.set_light(light);
+    assert_eq!(chunk.get_sky_light(BlockPos::new_xyz(1, -48, 0)), Some(15));
+    assert_eq!(RawLightData::from_chunk(&chunk), raw);
+
+    // Light outside of the chunk is rejected.
+    assert!(raw.try_parse(16).is_err());
+}
@@ -0,0 +1,150 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use froglight_common::prelude::Identifier;
+use froglight_facet::{from_slice, to_vec};
+use froglight_packet::common::commands::{
+    ArgumentType, CommandNode, CommandNodeKind, CommandSuggestion, CommandSuggestionsContent,
+    CommandTree, StringArgumentKind, SuggestionTooltip,
+};
+
+fn node(kind: CommandNodeKind, children: Vec<u32>) -> CommandNode {
+    CommandNode {
+        kind,
+        executable: false,
+        restricted: false,
+        children,
+        redirect: None,
+        suggestions: None,
+    }
+}
+
+#[test]
+fn tree_decode() {
+    #[rustfmt::skip]
+    let bytes: &[u8] = &[
+        // 3 nodes
+        3,
+        // Root, 1 child
+        0x00, 1, 1,
+        // Literal "say", 1 child
+        0x01, 1, 2, 3, b's', b'a', b'y',
+        // Executable argument "message", `minecraft:message`
+        0x06, 0, 7, b'm', b'e', b's', b's', b'a', b'g', b'e', 20,
+        // Root index
+        0,
+    ];
+
+    let tree = from_slice::<CommandTree>(bytes).unwrap();
+    assert_eq!(tree.root, 0);
+    assert_eq!(tree.nodes.len(), 3);
+    assert_eq!(tree.root().unwrap().kind, CommandNodeKind::Root);
+    assert_eq!(tree.get(1).unwrap().name(), Some("say"));
+
+    let message = tree.get(2).unwrap();
+    assert!(message.executable);
+    assert_eq!(
+        message.kind,
+        CommandNodeKind::Argument("message".to_string(), ArgumentType::Message)
+    );
+
+    assert_eq!(to_vec(&tree).unwrap(), bytes);
+}
+
+#[test]
+fn tree_roundtrip() {
+    let mut amount = node(
+        CommandNodeKind::Argument(
+            "amount".to_string(),
+            ArgumentType::Integer { min: Some(1), max: None },
+        ),
+        vec![],
+    );
+    amount.executable = true;
+
+    let mut target = node(
+        CommandNodeKind::Argument(
+            "target".to_string(),
+            ArgumentType::Entity { single: false, players_only: true },
+        ),
+        vec![3],
+    );
+    target.suggestions = Some(CommandNode::ASK_SERVER);
+
+    let mut run = node(CommandNodeKind::Literal("run".to_string()), vec![]);
+    run.redirect = Some(0);
+    run.restricted = true;
+
+    let tree = CommandTree {
+        nodes: vec![
+            node(CommandNodeKind::Root, vec![1, 4]),
+            node(CommandNodeKind::Literal("give".to_string()), vec![2]),
+            target,
+            amount,
+            run,
+            node(
+                CommandNodeKind::Argument(
+                    "text".to_string(),
+                    ArgumentType::String(StringArgumentKind::GreedyPhrase),
+                ),
+                vec![],
+            ),
+            node(
+                CommandNodeKind::Argument(
+                    "registry".to_string(),
+                    ArgumentType::ResourceOrTag {
+                        registry: Identifier::new_static("minecraft:item"),
+                    },
+                ),
+                vec![],
+            ),
+            node(
+                CommandNodeKind::Argument(
+                    "range".to_string(),
+                    ArgumentType::Double { min: Some(-1.5), max: Some(2.0) },
+                ),
+                vec![],
+            ),
+            node(
+                CommandNodeKind::Argument("time".to_string(), ArgumentType::Time { min: 20 }),
+                vec![],
+            ),
+        ],
+        root: 0,
+    };
+
+    let bytes = to_vec(&tree).unwrap();
+    assert_eq!(from_slice::<CommandTree>(&bytes).unwrap(), tree);
+}
+
+#[test]
+fn argument_ids() {
+    assert_eq!(ArgumentType::IDENTIFIERS.len(), 57);
+    assert_eq!(ArgumentType::Bool.identifier(), "brigadier:bool");
+    assert_eq!(ArgumentType::Uuid.id(), 56);
+    assert_eq!(ArgumentType::from_id(20), Some(ArgumentType::Message));
+    assert_eq!(ArgumentType::from_id(1), None);
+}
+
+#[test]
+fn suggestions_roundtrip() {
+    let content = CommandSuggestionsContent {
+        id: 7,
+        start: 6,
+        length: 2,
+        matches: vec![
+            CommandSuggestion { text: "diamond".to_string(), tooltip: None },
+            CommandSuggestion {
+                text: "dirt".to_string(),
+                tooltip: Some(SuggestionTooltip::Text("A block".to_string())),
+            },
+        ],
+    };
+
+    let bytes = to_vec(&content).unwrap();
+    assert_eq!(from_slice::<CommandSuggestionsContent>(&bytes).unwrap(), content);
+}
@@ -0,0 +1,138 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use froglight_block::prelude::*;
+use froglight_common::version::V26_1;
+use froglight_packet::common::chunk_data::{RawChunkData, RawHeightMapData};
+use froglight_world::{
+    chunk::Heightmap,
+    prelude::{BlockPos, Chunk, HeightmapKind},
+};
+
+#[test]
+fn packed() {
+    let mut heightmap = Heightmap::empty();
+    for z in 0..16 {
+        for x in 0..16 {
+            heightmap.set(x, z, u16::from(z) * 16 + u16::from(x));
+        }
+    }
+
+    // 9 bits per column, 7 columns per long.
+    let packed = heightmap.to_packed(384);
+    assert_eq!(packed.len(), 37);
+    assert_eq!(Heightmap::from_packed(&packed, 384), Some(heightmap.clone()));
+
+    // Both 256 and 384 need 9 bits per column.
+    let packed = heightmap.to_packed(256);
+    assert_eq!(packed.len(), 37);
+    assert_eq!(Heightmap::from_packed(&packed, 256), Some(heightmap.clone()));
+
+    // Incorrect lengths and heights are rejected.
+    assert_eq!(Heightmap::from_packed(&packed[1..], 256), None);
+    assert_eq!(Heightmap::from_packed(&[u64::MAX; 37], 384), None);
+
+    // 8 bits per column, 8 columns per long, but taller than the chunk.
+    let packed = heightmap.to_packed(128);
+    assert_eq!(packed.len(), 32);
+    assert_eq!(Heightmap::from_packed(&packed, 128), None);
+}
+
+#[test]
+fn incremental() {
+    let mut chunk = Chunk::empty_large::<V26_1>();
+    assert_eq!(chunk.height_at(HeightmapKind::WorldSurface, 3, 5), None);
+
+    chunk.recompute_heightmaps();
+    for kind in HeightmapKind::ALL {
+        assert_eq!(chunk.height_at(kind, 3, 5), Some(-64));
+    }
+
+    let heights =
+        |chunk: &Chunk| HeightmapKind::ALL.map(|kind| chunk.height_at(kind, 3, 5).unwrap());
+
+    // [WorldSurface, OceanFloor, MotionBlocking, MotionBlockingNoLeaves]
+    chunk.set_block(BlockPos::new_xyz(3, 10, 5), Block::new_default::<block::Stone, V26_1>());
+    assert_eq!(heights(&chunk), [11, 11, 11, 11]);
+
+    chunk.set_block(BlockPos::new_xyz(3, 20, 5), Block::new_default::<block::Water, V26_1>());
+    assert_eq!(heights(&chunk), [21, 11, 21, 21]);
+
+    chunk.set_block(BlockPos::new_xyz(3, 30, 5), Block::new_default::<block::OakLeaves, V26_1>());
+    assert_eq!(heights(&chunk), [31, 31, 31, 21]);
+
+    chunk.set_block(BlockPos::new_xyz(3, 40, 5), Block::new_default::<block::Torch, V26_1>());
+    assert_eq!(heights(&chunk), [41, 31, 31, 21]);
+
+    // Carpets have collision, but are too thin to block motion.
+    chunk.set_block(BlockPos::new_xyz(3, 50, 5), Block::new_default::<block::WhiteCarpet, V26_1>());
+    assert_eq!(heights(&chunk), [51, 31, 31, 21]);
+    chunk.set_block(BlockPos::new_xyz(3, 50, 5), Block::new_default::<block::Air, V26_1>());
+
+    // Removing the highest block searches downwards.
+    let air = Block::new_default::<block::Air, V26_1>();
+    chunk.set_block(BlockPos::new_xyz(3, 40, 5), air);
+    chunk.set_block(BlockPos::new_xyz(3, 30, 5), air);
+    assert_eq!(heights(&chunk), [21, 11, 21, 21]);
+
+    // Incremental updates match a full recomputation.
+    let mut recomputed = chunk.clone();
+    recomputed.recompute_heightmaps();
+    for kind in HeightmapKind::ALL {
+        assert_eq!(chunk.get_heightmap(kind), recomputed.get_heightmap(kind));
+    }
+
+    chunk.set_block(BlockPos::new_xyz(3, 20, 5), air);
+    chunk.set_block(BlockPos::new_xyz(3, 10, 5), air);
+    assert_eq!(heights(&chunk), [-64; 4]);
+}
+
+#[test]
+fn parse() {
+    // An empty section, filled with air and the first biome.
+    let section = [0u8, 0, 0, 0, 0, 0, 0, 0];
+
+    let mut heightmap = Heightmap::empty();
+    heightmap.set(15, 15, 128);
+
+    let mut raw = RawChunkData {
+        heightmaps: vec![
+            RawHeightMapData { kind: 0, data: Vec::new() },
+            RawHeightMapData {
+                kind: HeightmapKind::MotionBlocking.id(),
+                data: heightmap.to_packed(384),
+            },
+        ],
+        chunk_data: section.repeat(24),
+        entity_data: Vec::new(),
+    };
+
+    let chunk = raw.try_parse::<V26_1>(320, -64).unwrap();
+    assert_eq!(chunk.get_heightmap(HeightmapKind::MotionBlocking), Some(&heightmap));
+    assert_eq!(chunk.height_at(HeightmapKind::MotionBlocking, 15, 15), Some(64));
+    assert_eq!(chunk.height_at(HeightmapKind::WorldSurface, 15, 15), None);
+
+    // Heightmaps that do not match the chunk's height are rejected.
+    raw.heightmaps[1].data.pop();
+    assert!(raw.try_parse::<V26_1>(320, -64).is_err());
+}
+
+#[test]
+fn leaves_tag() {
+    let leaves = Block::new_default::<block::OakLeaves, V26_1>();
+    let stone = Block::new_default::<block::Stone, V26_1>();
+
+    // Without tags, leaves are detected using their attributes.
+    assert!(!HeightmapKind::MotionBlockingNoLeaves.matches(&leaves));
+    assert!(HeightmapKind::MotionBlockingNoLeaves.matches(&stone));
+
+    // With tags, only blocks in the leaves tag are ignored.
+    let kind = HeightmapKind::MotionBlockingNoLeaves;
+    assert!(kind.matches_with(&leaves, |_| false));
+    assert!(!kind.matches_with(&stone, |tag| *tag == HeightmapKind::LEAVES_TAG));
+    assert!(HeightmapKind::MotionBlocking.matches_with(&stone, |_| true));
+}
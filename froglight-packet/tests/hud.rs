@@ -0,0 +1,130 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use froglight_facet::{from_slice, to_vec};
+use froglight_packet::common::{
+    boss_event::{
+        BossBarColor, BossBarData, BossBarFlags, BossBarOverlay, BossEvent, BossEventAction,
+    },
+    text::NetworkText,
+    title::TitleTimes,
+    world_border::WorldBorderInit,
+};
+use uuid::Uuid;
+
+fn roundtrip<T>(value: &T)
+where
+    T: facet::Facet<'static> + PartialEq + core::fmt::Debug,
+{
+    let bytes = to_vec(value).unwrap();
+    assert_eq!(&from_slice::<T>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn boss_event_decode() {
+    #[rustfmt::skip]
+    let bytes: &[u8] = &[
+        // Uuid
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        // Add
+        0,
+        // Name, a string tag "Boss"
+        8, 0, 4, b'B', b'o', b's', b's',
+        // Progress, 0.5
+        0x3F, 0, 0, 0,
+        // Red, Notched10
+        2, 2,
+        // Darken screen and create fog
+        0b0000_0101,
+    ];
+
+    let event = from_slice::<BossEvent>(bytes).unwrap();
+    assert_eq!(event.id, Uuid::from_u128(1));
+    let BossEventAction::Add(data) = &event.action else { panic!("Expected `Add`") };
+    assert_eq!(data.name.as_text(), Some("Boss"));
+    assert!((data.progress - 0.5).abs() < f32::EPSILON);
+    assert_eq!(data.color, BossBarColor::Red);
+    assert_eq!(data.overlay, BossBarOverlay::Notched10);
+    assert_eq!(
+        data.flags,
+        BossBarFlags { darken_screen: true, play_music: false, create_fog: true }
+    );
+
+    assert_eq!(to_vec(&event).unwrap(), bytes);
+}
+
+#[test]
+fn boss_event_roundtrip() {
+    let id = Uuid::from_u128(0x1234_5678);
+
+    roundtrip(&BossEvent {
+        id,
+        action: BossEventAction::Add(BossBarData {
+            name: NetworkText::from("Wither"),
+            progress: 1.0,
+            color: BossBarColor::Purple,
+            overlay: BossBarOverlay::Notched20,
+            flags: BossBarFlags { play_music: true, ..BossBarFlags::default() },
+        }),
+    });
+    roundtrip(&BossEvent { id, action: BossEventAction::Remove });
+    roundtrip(&BossEvent { id, action: BossEventAction::UpdateProgress(0.25) });
+    roundtrip(&BossEvent { id, action: BossEventAction::UpdateName(NetworkText::from("Ender")) });
+    roundtrip(&BossEvent {
+        id,
+        action: BossEventAction::UpdateStyle(BossBarColor::White, BossBarOverlay::Progress),
+    });
+    roundtrip(&BossEvent {
+        id,
+        action: BossEventAction::UpdateProperties(BossBarFlags {
+            darken_screen: true,
+            play_music: true,
+            create_fog: true,
+        }),
+    });
+}
+
+#[test]
+fn world_border_decode() {
+    #[rustfmt::skip]
+    let bytes: &[u8] = &[
+        // Center, 8.0 and -8.0
+        0x40, 0x20, 0, 0, 0, 0, 0, 0,
+        0xC0, 0x20, 0, 0, 0, 0, 0, 0,
+        // Sizes, 256.0 and 16.0
+        0x40, 0x70, 0, 0, 0, 0, 0, 0,
+        0x40, 0x30, 0, 0, 0, 0, 0, 0,
+        // Lerp time, 60000ms
+        0xE0, 0xD4, 0x03,
+        // Absolute max size, 29999984
+        0xF0, 0x86, 0xA7, 0x0E,
+        // Warning blocks and time
+        5, 15,
+    ];
+
+    let border = from_slice::<WorldBorderInit>(bytes).unwrap();
+    assert_eq!(
+        border,
+        WorldBorderInit {
+            center_x: 8.0,
+            center_z: -8.0,
+            old_size: 256.0,
+            new_size: 16.0,
+            lerp_time: 60_000,
+            ..WorldBorderInit::DEFAULT
+        }
+    );
+
+    assert_eq!(to_vec(&border).unwrap(), bytes);
+}
+
+#[test]
+fn title_times() {
+    let times = TitleTimes { fade_in: 5, stay: 40, fade_out: -1 };
+    assert_eq!(to_vec(&times).unwrap(), [0, 0, 0, 5, 0, 0, 0, 40, 0xFF, 0xFF, 0xFF, 0xFF]);
+    roundtrip(&times);
+
+    assert_eq!(TitleTimes::default().total(), 100);
+}
@@ -0,0 +1,59 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use froglight_common::identifier::Identifier;
+use froglight_facet::{from_slice, to_vec};
+use froglight_packet::{
+    common::spawn_info::{PlayerDataKept, PlayerSpawnInfo},
+    generated::v26_1::play::RespawnS2CPacket,
+};
+
+#[test]
+fn respawn() {
+    #[rustfmt::skip]
+    let bytes: &[u8] = &[
+        // Dimension type 2
+        2,
+        // Dimension "minecraft:the_end"
+        17, b'm', b'i', b'n', b'e', b'c', b'r', b'a', b'f', b't', b':',
+        b't', b'h', b'e', b'_', b'e', b'n', b'd',
+        // Seed
+        0, 0, 0, 0, 0, 0, 0, 42,
+        // Survival, no previous gamemode
+        0, 0xFF,
+        // Not debug, not flat, no last death
+        0, 0, 0,
+        // Portal cooldown, sea level
+        20, 0,
+        // Keep entity data
+        0b10,
+    ];
+
+    let packet = from_slice::<RespawnS2CPacket>(bytes).unwrap();
+    assert_eq!(
+        packet,
+        RespawnS2CPacket {
+            spawn_info: PlayerSpawnInfo {
+                dimension_type: 2,
+                dimension: Identifier::new_static("minecraft:the_end"),
+                seed: 42,
+                gamemode: 0,
+                previous_gamemode: None,
+                is_debug: false,
+                is_flat: false,
+                last_death: None,
+                portal_cooldown: 20,
+                sea_level: 0,
+            },
+            data_kept: PlayerDataKept { attributes: false, entity_data: true },
+        }
+    );
+    assert_eq!(to_vec(&packet).unwrap(), bytes);
+
+    for data_kept in [PlayerDataKept::NONE, PlayerDataKept::ALL] {
+        let packet = RespawnS2CPacket { data_kept, ..packet.clone() };
+        assert_eq!(from_slice::<RespawnS2CPacket>(&to_vec(&packet).unwrap()).unwrap(), packet);
+    }
+}
@@ -0,0 +1,35 @@
+//! TODO
+
+use froglight_common::version::{V26_1, Version};
+use froglight_packet::schema::protocol_schema;
+
+#[test]
+fn v26_1() {
+    let schema = protocol_schema::<V26_1>();
+    assert_eq!(schema.protocol, V26_1::PROTOCOL_ID);
+
+    let names: Vec<_> = schema.states.iter().map(|state| state.name.as_str()).collect();
+    assert_eq!(names, ["Handshake", "Status", "Login", "Config", "Play"]);
+
+    // Check the handshake's `Intention` packet.
+    let handshake = &schema.states[0];
+    assert_eq!(handshake.serverbound.len(), 1);
+    assert_eq!(handshake.serverbound[0].id, 0x00);
+    assert_eq!(handshake.serverbound[0].name, "Intention");
+
+    // Check that every state has packets with unique ids.
+    for state in &schema.states[1..] {
+        for packets in [&state.clientbound, &state.serverbound] {
+            assert!(!packets.is_empty(), "{} has no packets", state.name);
+
+            let mut ids: Vec<_> = packets.iter().map(|packet| packet.id).collect();
+            ids.sort_unstable();
+            ids.dedup();
+            assert_eq!(ids.len(), packets.len(), "{} has duplicate ids", state.name);
+        }
+    }
+
+    // Check that the schema can be exported.
+    let json = schema.to_json().unwrap();
+    assert!(json.contains("\"Intention\""));
+}
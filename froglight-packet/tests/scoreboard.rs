@@ -0,0 +1,150 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use froglight_facet::{from_slice, to_vec};
+use froglight_packet::common::{
+    game_mode::GameMode,
+    player_info::{PlayerInfoActions, PlayerInfoEntry, PlayerInfoUpdate, TabListText},
+    scoreboard::{
+        DisplayObjective, DisplaySlot, NumberFormat, ObjectiveAction, ObjectiveData,
+        ObjectiveRenderType, ObjectiveUpdate, ScoreReset, ScoreUpdate, TeamAction, TeamColor,
+        TeamOptions, TeamParameters, TeamUpdate,
+    },
+    text::NetworkText,
+};
+use froglight_player::prelude::{PlayerProfile, Username};
+use uuid::Uuid;
+
+fn roundtrip<T>(value: &T)
+where
+    T: facet::Facet<'static> + PartialEq + core::fmt::Debug,
+{
+    let bytes = to_vec(value).unwrap();
+    assert_eq!(&from_slice::<T>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn objective_decode() {
+    #[rustfmt::skip]
+    let bytes: &[u8] = &[
+        // Name "obj"
+        3, b'o', b'b', b'j',
+        // Add
+        0,
+        // Display name, a string tag "Obj"
+        8, 0, 3, b'O', b'b', b'j',
+        // Integer, no number format
+        0, 0,
+    ];
+
+    let update = from_slice::<ObjectiveUpdate>(bytes).unwrap();
+    assert_eq!(update.name, "obj");
+    let ObjectiveAction::Add(data) = &update.action else { panic!("Expected `Add`") };
+    assert_eq!(data.display_name.as_text(), Some("Obj"));
+    assert_eq!(data.render_type, ObjectiveRenderType::Integer);
+    assert_eq!(data.number_format, None);
+
+    assert_eq!(to_vec(&update).unwrap(), bytes);
+}
+
+#[test]
+fn scores_roundtrip() {
+    roundtrip(&ObjectiveUpdate {
+        name: String::from("kills"),
+        action: ObjectiveAction::Update(ObjectiveData {
+            display_name: NetworkText::from("Kills"),
+            render_type: ObjectiveRenderType::Hearts,
+            number_format: Some(NumberFormat::Fixed(NetworkText::from("-"))),
+        }),
+    });
+    roundtrip(&ObjectiveUpdate { name: String::from("kills"), action: ObjectiveAction::Remove });
+
+    roundtrip(&ScoreUpdate {
+        owner: String::from("Steve"),
+        objective: String::from("kills"),
+        value: -12,
+        display_name: Some(NetworkText::from("Not Steve")),
+        number_format: Some(NumberFormat::Blank),
+    });
+    roundtrip(&ScoreReset { owner: String::from("Steve"), objective: None });
+}
+
+#[test]
+fn display_slot() {
+    let display = DisplayObjective {
+        slot: DisplaySlot::TeamSidebar(TeamColor::Red),
+        objective: String::from("kills"),
+    };
+    let bytes = to_vec(&display).unwrap();
+    assert_eq!(bytes[0], 3 + TeamColor::Red as u8);
+    assert_eq!(from_slice::<DisplayObjective>(&bytes).unwrap(), display);
+
+    assert!(from_slice::<DisplayObjective>(&[64, 0]).is_err());
+}
+
+#[test]
+fn team_roundtrip() {
+    let parameters = TeamParameters {
+        display_name: NetworkText::from("Red"),
+        options: TeamOptions { friendly_fire: false, see_friendly_invisibles: true },
+        color: TeamColor::Red,
+        prefix: NetworkText::from("[R] "),
+        ..TeamParameters::default()
+    };
+
+    roundtrip(&TeamUpdate {
+        name: String::from("red"),
+        action: TeamAction::Add(parameters.clone(), vec![String::from("Steve")]),
+    });
+    roundtrip(&TeamUpdate { name: String::from("red"), action: TeamAction::Update(parameters) });
+    roundtrip(&TeamUpdate {
+        name: String::from("red"),
+        action: TeamAction::Leave(vec![String::from("Steve"), String::from("Alex")]),
+    });
+}
+
+#[test]
+fn player_info_roundtrip() {
+    let profile = PlayerProfile::new_offline(Username::new_from("Steve"));
+
+    let mut added = PlayerInfoEntry::new(*profile.uuid());
+    added.profile = Some(profile);
+    added.chat_session = Some(None);
+    added.game_mode = Some(GameMode::Creative);
+    added.listed = Some(true);
+    added.latency = Some(42);
+    added.display_name = Some(Some(NetworkText::from("Steve!")));
+    added.list_order = Some(-1);
+    added.show_hat = Some(false);
+    roundtrip(&PlayerInfoUpdate { actions: PlayerInfoActions::ALL, entries: vec![added] });
+
+    let mut latency = PlayerInfoEntry::new(Uuid::from_u128(1));
+    latency.latency = Some(100);
+    let actions = PlayerInfoActions { update_latency: true, ..PlayerInfoActions::default() };
+    let update = PlayerInfoUpdate { actions, entries: vec![latency] };
+
+    let bytes = to_vec(&update).unwrap();
+    assert_eq!(bytes[..2], [0b0001_0000, 1]);
+    assert_eq!(from_slice::<PlayerInfoUpdate>(&bytes).unwrap(), update);
+
+    // Entries must contain every field selected by the actions.
+    let missing = PlayerInfoUpdate { actions, entries: vec![PlayerInfoEntry::new(Uuid::nil())] };
+    assert!(to_vec(&missing).is_err());
+}
+
+#[test]
+fn tab_list_roundtrip() {
+    roundtrip(&TabListText {
+        header: NetworkText::from("Welcome!"),
+        footer: NetworkText::default(),
+    });
+
+    let bits: Vec<u8> = (0..=u8::MAX).collect();
+    for bits in bits {
+        assert_eq!(PlayerInfoActions::from_bits(bits).to_bits(), bits);
+    }
+}
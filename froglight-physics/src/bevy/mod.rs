@@ -5,16 +5,21 @@ use alloc::vec::Vec;
 use bevy_app::{App, Plugin};
 #[cfg(feature = "tracing")]
 use bevy_ecs::entity::EntityNotSpawnedError;
-use bevy_ecs::{entity::UniqueEntityArray, prelude::*, world::DeferredWorld};
+use bevy_ecs::{
+    entity::{EntityHashMap, UniqueEntityArray},
+    prelude::*,
+    world::DeferredWorld,
+};
 use bevy_tasks::ComputeTaskPool;
 use froglight_entity::{bevy::EntityBundleEvent, prelude::EntityBundle};
-use froglight_instance::prelude::SessionInstance;
+use froglight_instance::prelude::{PartOfInstance, SessionInstance};
 use parking_lot::Mutex;
 
 use crate::prelude::*;
 
 pub mod colliding;
 pub mod collision_cache;
+pub mod spatial;
 
 /// A [`Plugin`] that adds physics components and systems.
 ///
@@ -50,8 +55,10 @@ impl Plugin for PhysicsPlugin {
 
         app.register_type::<EntityCollisions>().init_resource::<EntityCollisions>();
         app.register_type::<CollidingWith>();
+        app.register_type::<SpatialIndex>();
 
         app.add_observer(PhysicsPlugin::on_entity_bundle);
+        app.add_observer(PhysicsPlugin::on_session_instance);
     }
 }
 
@@ -108,14 +115,79 @@ impl PhysicsPlugin {
         });
     }
 
+    /// An [`Observer`] that inserts a [`SpatialIndex`] when a
+    /// [`SessionInstance`] is added.
+    pub fn on_session_instance(trigger: On<Add, SessionInstance>, mut commands: Commands) {
+        commands.entity(trigger.entity).insert_if_new(SpatialIndex::new());
+    }
+
+    /// A [`System`] that updates each [`SessionInstance`]'s [`SpatialIndex`]
+    /// based on entity [`Position`]s and [`Collider`]s.
+    ///
+    /// # Note
+    ///
+    /// This [`System`] is not scheduled by default! You must add it manually!
+    ///
+    /// It should run after [`PhysicsPlugin::update_colliders`].
+    #[expect(clippy::type_complexity, reason = "Query filters")]
+    pub fn update_spatial_index(
+        mut indexes: Query<&mut SpatialIndex>,
+        changed: Query<
+            (Entity, &PartOfInstance, &Position, Option<&Collider>, Option<&EntityBundle>),
+            Or<(
+                Changed<PartOfInstance>,
+                Changed<Position>,
+                Changed<Collider>,
+                Changed<EntityBundle>,
+            )>,
+        >,
+        mut removed_position: RemovedComponents<Position>,
+        mut removed_instance: RemovedComponents<PartOfInstance>,
+        mut tracked: Local<EntityHashMap<Entity>>,
+    ) {
+        // Remove entities that were despawned or left their instance.
+        for entity in removed_position.read().chain(removed_instance.read()) {
+            if let Some(instance) = tracked.remove(&entity)
+                && let Ok(mut index) = indexes.get_mut(instance)
+            {
+                index.remove(entity);
+            }
+        }
+
+        // Insert entities that moved or changed instances.
+        for (entity, instance, position, collider, bundle) in &changed {
+            let instance = instance.instance();
+            if let Some(previous) = tracked.remove(&entity)
+                && previous != instance
+                && let Ok(mut index) = indexes.get_mut(previous)
+            {
+                index.remove(entity);
+            }
+
+            if let Ok(mut index) = indexes.get_mut(instance) {
+                let entry = SpatialEntry::new(
+                    position.to_vec3a(),
+                    collider.copied(),
+                    bundle.map(EntityBundle::entity_ty),
+                );
+                index.insert(entity, entry);
+                tracked.insert(entity, instance);
+            }
+        }
+    }
+
     /// A [`System`] that updates [`EntityCollisions`] and [`CollidingWith`]
     /// based on entity [`Collider`]s.
     ///
+    /// Only entities in nearby sections of the [`SpatialIndex`] are compared.
+    ///
     /// # Note
     ///
     /// This [`System`] is not scheduled by default! You must add it manually!
+    ///
+    /// It should run after [`PhysicsPlugin::update_spatial_index`].
     pub fn update_collisions(
-        instances: Query<(Entity, &SessionInstance)>,
+        instances: Query<(Entity, &SpatialIndex)>,
         mut colliders: Query<(Entity, &Collider, &mut CollidingWith)>,
         mut collisions: ResMut<EntityCollisions>,
         mut cache: Local<Mutex<Vec<UniqueEntityArray<2>>>>,
@@ -124,25 +196,22 @@ impl PhysicsPlugin {
         let collider_lens = collider_lens.query_inner();
 
         // Calculate all collisions in parallel.
-        instances.par_iter().for_each(|(_entity, instance)| {
+        instances.par_iter().for_each(|(_entity, index)| {
             #[cfg(feature = "tracing")]
             #[allow(clippy::used_underscore_binding, reason = "Used for tracing")]
             let _span = tracing::info_span!(target: "froglight_physics", "par_update_collisions", instance = %_entity).entered();
 
-            let mut entities = Vec::<&Entity>::with_capacity(instance.iter_entity().len());
-            entities.extend(instance.iter_entity());
+            for (a, _) in index.iter() {
+                let Ok((_, collider_a)) = collider_lens.get(a) else { continue };
 
-            for (start, a) in entities.iter().enumerate() {
-                for b in entities.iter().skip(start + 1) {
-                    // Skip checking collisions with itself.
-                    if a == b {
+                for (b, _) in index.within_aabb(*collider_a) {
+                    // Check each pair once, and skip checking collisions with itself.
+                    if a >= b {
                         continue;
                     }
 
-                    let (a, b) = (**a, **b);
-                    if let (Ok((_, collider_a)), Ok((_, collider_b))) =
-                        (collider_lens.get(a), collider_lens.get(b))
-                        && collider_a.intersects( collider_b)
+                    if let Ok((_, collider_b)) = collider_lens.get(b)
+                        && collider_a.intersects(collider_b)
                     {
                         // SAFETY: Already checked that `a` and `b` are not equal.
                         cache
@@ -0,0 +1,371 @@
+//! TODO
+
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use bevy_ecs::{component::Component, entity::EntityHashMap, prelude::*};
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+use foldhash::fast::RandomState;
+use glam::{IVec3, Vec3A};
+use hashbrown::HashMap;
+
+use crate::components::Collider;
+
+/// A [`Component`] that buckets every [`Entity`] in a
+/// [`SessionInstance`](froglight_instance::prelude::SessionInstance)
+/// by the section it is in.
+///
+/// Added to every instance by the
+/// [`PhysicsPlugin`](crate::bevy::PhysicsPlugin), and kept in sync by
+/// [`PhysicsPlugin::update_spatial_index`](crate::bevy::PhysicsPlugin::update_spatial_index).
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use froglight_instance::prelude::PartOfInstance;
+/// use froglight_physics::prelude::*;
+///
+/// fn print_nearby(
+///     query: Query<(Entity, &Position, &PartOfInstance)>,
+///     indexes: Query<&SpatialIndex>,
+/// ) {
+///     for (entity, position, instance) in query {
+///         let Ok(index) = indexes.get(instance.instance()) else { continue };
+///         for (other, _entry) in index.within_radius(position.to_vec3a(), 8.0) {
+///             if other != entity {
+///                 println!("{other} is within 8 blocks of {entity}!");
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(opaque, Debug, Default, Clone, Component)]
+pub struct SpatialIndex {
+    sections: HashMap<IVec3, SpatialSection, RandomState>,
+    entities: EntityHashMap<SpatialEntry>,
+    extent: Vec3A,
+}
+
+/// The entities in a section of a [`SpatialIndex`].
+#[derive(Debug, Default, Clone)]
+struct SpatialSection {
+    entities: Vec<Entity>,
+    /// The largest distance from any entity's position to its [`Collider`].
+    extent: Vec3A,
+}
+
+impl SpatialIndex {
+    /// The size of each bucket, in blocks.
+    pub const SECTION_SIZE: f32 = 16.0;
+
+    /// Create a new, empty [`SpatialIndex`].
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Get the number of entities in the [`SpatialIndex`].
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize { self.entities.len() }
+
+    /// Returns `true` if the [`SpatialIndex`] contains no entities.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.entities.is_empty() }
+
+    /// Returns `true` if the [`SpatialIndex`] contains the given [`Entity`].
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool { self.entities.contains_key(&entity) }
+
+    /// Get the [`SpatialEntry`] of the given [`Entity`].
+    #[inline]
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> Option<&SpatialEntry> { self.entities.get(&entity) }
+
+    /// Get the largest distance from any entity's position to its
+    /// [`Collider`].
+    ///
+    /// AABB and ray queries check sections this far outside of their bounds.
+    #[inline]
+    #[must_use]
+    pub const fn extent(&self) -> Vec3A { self.extent }
+
+    /// Iterate over all entities in the [`SpatialIndex`].
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &SpatialEntry)> + '_ {
+        self.entities.iter().map(|(entity, entry)| (*entity, entry))
+    }
+
+    /// Insert or move an [`Entity`] in the [`SpatialIndex`].
+    ///
+    /// Returns the previous [`SpatialEntry`] of the entity, if any.
+    pub fn insert(&mut self, entity: Entity, entry: SpatialEntry) -> Option<SpatialEntry> {
+        let previous = self.entities.insert(entity, entry);
+        if let Some(previous) = &previous {
+            self.remove_from_section(entity, previous);
+        }
+
+        let extent = entry.extent();
+        let section = self.sections.entry(Self::section_of(entry.position)).or_default();
+        section.entities.push(entity);
+        section.extent = section.extent.max(extent);
+        self.extent = self.extent.max(extent);
+
+        previous
+    }
+
+    /// Remove an [`Entity`] from the [`SpatialIndex`].
+    ///
+    /// Returns the [`SpatialEntry`] of the entity, if it was present.
+    pub fn remove(&mut self, entity: Entity) -> Option<SpatialEntry> {
+        let entry = self.entities.remove(&entity)?;
+        self.remove_from_section(entity, &entry);
+        Some(entry)
+    }
+
+    /// Remove all entities from the [`SpatialIndex`].
+    pub fn clear(&mut self) {
+        self.sections.clear();
+        self.entities.clear();
+        self.extent = Vec3A::ZERO;
+    }
+
+    /// Iterate over all entities whose [`Position`](crate::prelude::Position)
+    /// is within `radius` blocks of `center`.
+    pub fn within_radius(
+        &self,
+        center: Vec3A,
+        radius: f32,
+    ) -> impl Iterator<Item = (Entity, &SpatialEntry)> + '_ {
+        let radius_sq = radius * radius;
+        self.candidates(center - radius, center + radius)
+            .filter(move |(_, entry)| entry.position.distance_squared(center) <= radius_sq)
+    }
+
+    /// Iterate over all entities whose [`Collider`] intersects `aabb`.
+    pub fn within_aabb(
+        &self,
+        aabb: Collider,
+    ) -> impl Iterator<Item = (Entity, &SpatialEntry)> + '_ {
+        self.candidates(aabb.min - self.extent, aabb.max + self.extent)
+            .filter(move |(_, entry)| entry.collider.intersects(&aabb))
+    }
+
+    /// Find all entities whose [`Collider`] is hit by a ray,
+    /// sorted by the distance along the ray.
+    ///
+    /// Returns an empty list if `direction` is zero.
+    #[must_use]
+    pub fn raycast(
+        &self,
+        origin: Vec3A,
+        direction: Vec3A,
+        max_distance: f32,
+    ) -> Vec<(Entity, f32)> {
+        let direction = direction.normalize_or_zero();
+        if direction == Vec3A::ZERO {
+            return Vec::new();
+        }
+
+        let end = origin + direction * max_distance;
+        let mut hits: Vec<_> = self
+            .candidates(origin.min(end) - self.extent, origin.max(end) + self.extent)
+            .filter_map(|(entity, entry)| {
+                ray_distance(origin, direction, &entry.collider)
+                    .filter(|distance| *distance <= max_distance)
+                    .map(|distance| (entity, distance))
+            })
+            .collect();
+
+        sort_by_distance(&mut hits);
+        hits
+    }
+
+    /// Find up to `count` entities within `max_distance` blocks of `position`
+    /// that match the filter, sorted by distance.
+    #[must_use]
+    pub fn nearest(
+        &self,
+        position: Vec3A,
+        count: usize,
+        max_distance: f32,
+        mut filter: impl FnMut(Entity, &SpatialEntry) -> bool,
+    ) -> Vec<(Entity, f32)> {
+        let mut nearest: Vec<_> = self
+            .within_radius(position, max_distance)
+            .filter(|(entity, entry)| filter(*entity, entry))
+            .map(|(entity, entry)| (entity, entry.position.distance(position)))
+            .collect();
+
+        sort_by_distance(&mut nearest);
+        nearest.truncate(count);
+        nearest
+    }
+
+    /// Find up to `count` entities of type `E` within `max_distance` blocks of
+    /// `position`, sorted by distance.
+    ///
+    /// See [`EntityBundle::is_entity`](froglight_entity::prelude::EntityBundle::is_entity)
+    /// for details.
+    #[inline]
+    #[must_use]
+    pub fn nearest_of<E: 'static>(
+        &self,
+        position: Vec3A,
+        count: usize,
+        max_distance: f32,
+    ) -> Vec<(Entity, f32)> {
+        self.nearest(position, count, max_distance, |_, entry| entry.is_entity::<E>())
+    }
+
+    /// Get the section containing the given position.
+    #[must_use]
+    fn section_of(position: Vec3A) -> IVec3 { (position / Self::SECTION_SIZE).floor().as_ivec3() }
+
+    /// Remove an [`Entity`] from the section of its previous [`SpatialEntry`],
+    /// removing the section if it is empty.
+    ///
+    /// If the entry was the largest in its section or the whole index,
+    /// their extents are recomputed.
+    fn remove_from_section(&mut self, entity: Entity, previous: &SpatialEntry) {
+        let position = Self::section_of(previous.position);
+        let Some(section) = self.sections.get_mut(&position) else { return };
+
+        if let Some(index) = section.entities.iter().position(|other| *other == entity) {
+            section.entities.swap_remove(index);
+        }
+
+        // Removing an entry without an extent can never shrink one.
+        let extent = previous.extent();
+        let largest = |current: Vec3A| (extent.cmpge(current) & extent.cmpgt(Vec3A::ZERO)).any();
+
+        if section.entities.is_empty() {
+            self.sections.remove(&position);
+        } else if largest(section.extent) {
+            section.extent = section
+                .entities
+                .iter()
+                .filter_map(|entity| self.entities.get(entity))
+                .fold(Vec3A::ZERO, |extent, entry| extent.max(entry.extent()));
+        }
+
+        if largest(self.extent) {
+            self.extent = self
+                .sections
+                .values()
+                .fold(Vec3A::ZERO, |extent, section| extent.max(section.extent));
+        }
+    }
+
+    /// Iterate over all entities in the sections overlapping a region.
+    fn candidates(&self, min: Vec3A, max: Vec3A) -> impl Iterator<Item = (Entity, &SpatialEntry)> {
+        let (min, max) = (Self::section_of(min), Self::section_of(max));
+
+        // Check each section in the region, or every section if there are fewer.
+        let volume = (max.as_i64vec3() - min.as_i64vec3() + 1)
+            .max(glam::I64Vec3::ZERO)
+            .to_array()
+            .into_iter()
+            .fold(1i64, i64::saturating_mul);
+        let sections = i64::try_from(self.sections.len()).unwrap_or(i64::MAX);
+
+        let ranged = (volume <= sections).then(move || {
+            (min.y..=max.y)
+                .flat_map(move |y| {
+                    (min.z..=max.z)
+                        .flat_map(move |z| (min.x..=max.x).map(move |x| IVec3::new(x, y, z)))
+                })
+                .filter_map(|section| self.sections.get(&section))
+                .map(|section| &section.entities)
+        });
+        let all = (volume > sections).then(move || {
+            self.sections
+                .iter()
+                .filter(move |(section, _)| section.cmpge(min).all() && section.cmple(max).all())
+                .map(|(_, section)| &section.entities)
+        });
+
+        ranged
+            .into_iter()
+            .flatten()
+            .chain(all.into_iter().flatten())
+            .flatten()
+            .filter_map(|entity| self.entities.get(entity).map(|entry| (*entity, entry)))
+    }
+}
+
+/// Get the distance along a ray to where it enters a [`Collider`].
+///
+/// Returns `0.0` if the ray starts inside of the [`Collider`].
+fn ray_distance(origin: Vec3A, direction: Vec3A, collider: &Collider) -> Option<f32> {
+    let inverse = direction.recip();
+    let a = (collider.min - origin) * inverse;
+    let b = (collider.max - origin) * inverse;
+
+    let near = a.min(b).max_element().max(0.0);
+    let far = a.max(b).min_element();
+    (far >= near).then_some(near)
+}
+
+/// Sort entities by distance, then by [`Entity`] to keep the order stable.
+fn sort_by_distance(entities: &mut [(Entity, f32)]) {
+    entities.sort_by(|(a, a_dist), (b, b_dist)| a_dist.total_cmp(b_dist).then(a.cmp(b)));
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An [`Entity`] stored in a [`SpatialIndex`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialEntry {
+    position: Vec3A,
+    collider: Collider,
+    entity_ty: Option<TypeId>,
+}
+
+impl SpatialEntry {
+    /// Create a new [`SpatialEntry`].
+    ///
+    /// Entities without a [`Collider`] are treated as a single point.
+    #[must_use]
+    pub const fn new(
+        position: Vec3A,
+        collider: Option<Collider>,
+        entity_ty: Option<TypeId>,
+    ) -> Self {
+        let collider = match collider {
+            Some(collider) => collider,
+            None => Collider::new(position, position),
+        };
+        Self { position, collider, entity_ty }
+    }
+
+    /// Get the position of the entity.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Vec3A { self.position }
+
+    /// Get the [`Collider`] of the entity.
+    #[inline]
+    #[must_use]
+    pub const fn collider(&self) -> &Collider { &self.collider }
+
+    /// Get the [`TypeId`] of the entity's type, if it has an
+    /// [`EntityBundle`](froglight_entity::prelude::EntityBundle).
+    #[inline]
+    #[must_use]
+    pub const fn entity_ty(&self) -> Option<TypeId> { self.entity_ty }
+
+    /// Returns `true` if the entity is of type `E`.
+    #[inline]
+    #[must_use]
+    pub fn is_entity<E: 'static>(&self) -> bool { self.entity_ty == Some(TypeId::of::<E>()) }
+
+    /// Get the largest distance from the entity's position to its
+    /// [`Collider`].
+    #[must_use]
+    fn extent(&self) -> Vec3A {
+        (self.position - self.collider.min).max(self.collider.max - self.position)
+    }
+}
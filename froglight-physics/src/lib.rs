@@ -18,6 +18,7 @@ pub mod prelude {
     pub use crate::bevy::{
         colliding::Colliding,
         collision_cache::{CollidingWith, EntityCollisions},
+        spatial::{SpatialEntry, SpatialIndex},
     };
     pub use crate::components::*;
 }
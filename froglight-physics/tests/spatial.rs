@@ -0,0 +1,147 @@
+//! TODO
+
+use core::any::TypeId;
+
+use bevy_app::{App, Update};
+use bevy_ecs::prelude::*;
+use froglight_common::{prelude::Identifier, version::V26_1};
+use froglight_instance::{bevy::InstancePlugin, prelude::*};
+use froglight_physics::{bevy::PhysicsPlugin, prelude::*};
+use glam::Vec3A;
+
+/// Marker types used as entity types.
+struct Item;
+struct Zombie;
+
+#[test]
+fn queries() {
+    let mut world = World::new();
+    let [item_a, item_b, zombie, far] = core::array::from_fn(|_| world.spawn_empty().id());
+
+    let mut index = SpatialIndex::new();
+    let entry = |x: f32, z: f32, ty: TypeId| {
+        let position = Vec3A::new(x, 64.0, z);
+        let collider = Collider::new_centered(position + Vec3A::Y * 0.5, Vec3A::splat(0.5));
+        SpatialEntry::new(position, Some(collider), Some(ty))
+    };
+    index.insert(item_a, entry(1.0, 0.0, TypeId::of::<Item>()));
+    index.insert(item_b, entry(-3.0, -4.0, TypeId::of::<Item>()));
+    index.insert(zombie, entry(0.0, 2.0, TypeId::of::<Zombie>()));
+    index.insert(far, entry(100.0, -100.0, TypeId::of::<Item>()));
+    assert_eq!(index.len(), 4);
+
+    // Radius queries only include nearby entities.
+    let origin = Vec3A::new(0.0, 64.0, 0.0);
+    let mut nearby: Vec<_> = index.within_radius(origin, 8.0).map(|(entity, _)| entity).collect();
+    nearby.sort();
+    let mut expected = vec![item_a, item_b, zombie];
+    expected.sort();
+    assert_eq!(nearby, expected);
+
+    // AABB queries use each entity's collider.
+    let aabb = Collider::new(Vec3A::new(0.5, 64.8, -0.5), Vec3A::new(2.0, 66.0, 0.5));
+    assert_eq!(index.within_aabb(aabb).map(|(entity, _)| entity).collect::<Vec<_>>(), [item_a]);
+
+    // Nearest queries are sorted and filtered by type.
+    let nearest = index.nearest(origin, 2, 8.0, |_, _| true);
+    assert_eq!(nearest, [(item_a, 1.0), (zombie, 2.0)]);
+    let nearest = index.nearest_of::<Item>(origin, 5, f32::INFINITY);
+    assert_eq!(
+        nearest.iter().map(|(entity, _)| *entity).collect::<Vec<_>>(),
+        [item_a, item_b, far]
+    );
+    assert!(index.nearest_of::<Zombie>(origin, 5, 1.5).is_empty());
+
+    // Raycasts are sorted by distance along the ray.
+    let hits = index.raycast(Vec3A::new(-5.0, 64.5, 0.0), Vec3A::X, 10.0);
+    assert_eq!(hits, [(item_a, 5.5)]);
+    let hits = index.raycast(Vec3A::new(0.0, 64.5, 10.0), Vec3A::NEG_Z, 20.0);
+    assert_eq!(hits, [(zombie, 7.5)]);
+    assert!(index.raycast(origin, Vec3A::ZERO, 10.0).is_empty());
+
+    // Moving an entity updates its section.
+    index.insert(far, entry(0.0, -1.5, TypeId::of::<Item>()));
+    assert_eq!(index.nearest_of::<Item>(origin, 1, 8.0), [(item_a, 1.0)]);
+    assert_eq!(index.within_radius(origin, 1.5).count(), 2);
+
+    index.remove(item_a);
+    assert!(!index.contains(item_a));
+    assert_eq!(index.nearest_of::<Item>(origin, 1, 8.0), [(far, 1.5)]);
+
+    // The extent shrinks when large entities are moved or removed.
+    assert_eq!(index.extent(), Vec3A::new(0.5, 1.0, 0.5));
+    let position = Vec3A::new(40.0, 64.0, 40.0);
+    let large = SpatialEntry::new(
+        position,
+        Some(Collider::new_centered(position, Vec3A::splat(4.0))),
+        None,
+    );
+    index.insert(zombie, large);
+    assert_eq!(index.extent(), Vec3A::splat(4.0));
+    index.insert(zombie, entry(0.0, 2.0, TypeId::of::<Zombie>()));
+    assert_eq!(index.extent(), Vec3A::new(0.5, 1.0, 0.5));
+    index.insert(item_b, large);
+    index.remove(item_b);
+    assert_eq!(index.extent(), Vec3A::new(0.5, 1.0, 0.5));
+
+    index.clear();
+    assert_eq!(index.extent(), Vec3A::ZERO);
+}
+
+#[test]
+fn collisions() {
+    let mut app = App::new();
+    app.add_plugins((InstancePlugin, PhysicsPlugin));
+    app.add_systems(
+        Update,
+        (
+            PhysicsPlugin::update_colliders,
+            PhysicsPlugin::update_spatial_index,
+            PhysicsPlugin::update_collisions,
+        )
+            .chain(),
+    );
+
+    let world = app.world_mut();
+    let instance = world
+        .spawn(SessionInstance::new::<V26_1>(
+            Identifier::new_static("minecraft:overworld"),
+            320,
+            -64,
+        ))
+        .id();
+    world.flush();
+    assert!(world.get::<SpatialIndex>(instance).is_some());
+
+    let collider = Collider::new_centered(Vec3A::ZERO, Vec3A::splat(0.3));
+    let mut spawn = |x: f32| {
+        world.spawn((PartOfInstance::new(instance), Position::new_xyz(x, 64.0, 0.0), collider)).id()
+    };
+    let (a, b, c) = (spawn(0.0), spawn(0.5), spawn(40.0));
+    app.update();
+
+    let world = app.world();
+    assert_eq!(world.get::<SpatialIndex>(instance).unwrap().len(), 3);
+    assert!(world.resource::<EntityCollisions>().are_colliding(a, b));
+    assert!(world.get::<CollidingWith>(c).unwrap().is_empty());
+
+    // Moving an entity updates the index and its collisions.
+    **app.world_mut().get_mut::<Position>(c).unwrap() = Vec3A::new(0.25, 64.0, 0.25);
+    app.update();
+
+    let world = app.world();
+    let index = world.get::<SpatialIndex>(instance).unwrap();
+    assert_eq!(index.get(c).unwrap().position(), Vec3A::new(0.25, 64.0, 0.25));
+    assert!(world.resource::<EntityCollisions>().are_colliding(a, c));
+    assert!(world.resource::<EntityCollisions>().are_colliding(b, c));
+
+    // Despawned entities are removed from the index.
+    app.world_mut().despawn(c);
+    app.update();
+
+    let world = app.world();
+    let index = world.get::<SpatialIndex>(instance).unwrap();
+    assert_eq!(index.len(), 2);
+    assert!(!index.contains(c));
+    assert!(world.resource::<EntityCollisions>().are_colliding(a, b));
+}
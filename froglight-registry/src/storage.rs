@@ -5,7 +5,7 @@ use core::any::TypeId;
 
 use foldhash::fast::RandomState;
 use froglight_common::identifier::Identifier;
-use froglight_nbt::prelude::IndexedNbtCow;
+use froglight_nbt::{prelude::IndexedNbtCow, types::indexed::entry::IndexedValue};
 use indexmap::IndexMap;
 
 use crate::{
@@ -71,6 +71,20 @@ impl RegistryStorage {
             .map(|(identifier, values)| TagRef::new(identifier.reborrow(), values))
     }
 
+    /// Get the height range of a dimension type as `(height_max, height_min)`.
+    ///
+    /// Uses the `height` and `min_y` values of the dimension type with the
+    /// given network id in the `minecraft:dimension_type` registry.
+    #[must_use]
+    pub fn get_dimension_height(&self, dimension_type: u32) -> Option<(u32, i32)> {
+        let registry = self.get_nbt_by_identifier("minecraft:dimension_type")?;
+        let dimension = registry.get_by_index(dimension_type as usize)?;
+
+        let min_y = dimension.get("min_y").and_then(IndexedValue::into_int)?.cast_signed();
+        let height = dimension.get("height").and_then(IndexedValue::into_int)?;
+        Some((height.checked_add_signed(min_y)?, min_y))
+    }
+
     /// Get the [`TypeId`] of the [`Version`] this storage is for.
     #[inline]
     #[must_use]
@@ -81,3 +81,36 @@ fn registry() {
     assert!(registry_ref.get_by_identifier("test:example_b_1").is_some());
     assert_eq!(registry_ref.get_by_index(1).unwrap().identifier(), "test:example_b_1");
 }
+
+#[test]
+fn dimension_height() {
+    use froglight_nbt::prelude::*;
+
+    /// Create a dimension type with the given `min_y` and `height`.
+    fn dimension(min_y: i32, height: u32) -> IndexedNbtCow<'static> {
+        let mut nbt = NbtCompound::new();
+        nbt.insert("min_y", min_y.cast_unsigned());
+        nbt.insert("height", height);
+        nbt.insert("logical_height", height / 2);
+
+        let bytes = Nbt::new(None, nbt).to_unnamed_bytes();
+        IndexedNbtSlice::new_unnamed(bytes.as_slice()).unwrap().into_owned()
+    }
+
+    let registry = RegistryStorage::build::<TestVersion>(
+        vec![],
+        vec![(
+            Identifier::new_static("minecraft:dimension_type"),
+            vec![
+                (Identifier::new_static("minecraft:overworld"), dimension(-64, 384)),
+                (Identifier::new_static("minecraft:the_nether"), dimension(0, 256)),
+                (Identifier::new_static("test:broken"), IndexedNbtCow::default()),
+            ],
+        )],
+    );
+
+    assert_eq!(registry.get_dimension_height(0), Some((320, -64)));
+    assert_eq!(registry.get_dimension_height(1), Some((256, 0)));
+    assert_eq!(registry.get_dimension_height(2), None);
+    assert_eq!(registry.get_dimension_height(3), None);
+}
@@ -33,7 +33,7 @@ pub trait DeserializeSnbt<'facet>: Facet<'facet> + Sized {
         'static: 'facet,
     {
         IndexedSnbtSlice::new_ref(string)
-            .map_or_else(|()| Err(DeserializeError), |snbt| Self::from_snbt(&snbt))
+            .map_or_else(|_| Err(DeserializeError), |snbt| Self::from_snbt(&snbt))
     }
 
     /// Deserialize a value from an [`IndexedNbtSlice`].
@@ -129,6 +129,7 @@ pub fn deserialize_owned_core<'facet, const BORROW: bool>(
                     todo!()
                 }
             }
+            Item::Pending(..) => Err(ReaderError::from_str("Unexpected pending item")),
         }
     }
 }
@@ -198,6 +199,7 @@ pub fn deserialize_borrowed_core<'facet>(
                     todo!()
                 }
             }
+            Item::Pending(..) => Err(ReaderError::from_str("Unexpected pending item")),
         }
     }
 }
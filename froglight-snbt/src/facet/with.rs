@@ -43,7 +43,7 @@ impl<'_f, T: Facet<'_f>> FacetTemplate for SnbtTemplate<'_f, T> {
         let content = str::from_utf8(content).map_err(ReaderError::other)?;
 
         let snbt = IndexedSnbtSlice::new_ref(content)
-            .map_err(|()| ReaderError::from_str("Invalid SNBT"))?;
+            .map_err(|err| ReaderError::from_string(alloc::format!("Invalid SNBT: {err}")))?;
 
         item.scoped(|partial| {
             let mut core = super::deserialize::deserialize_owned_core(&snbt);
@@ -67,7 +67,7 @@ impl<'_f, T: Facet<'_f>> FacetBorrowedTemplate for SnbtTemplate<'_f, T> {
 
         item.scoped(|partial| {
             let snbt = IndexedSnbtSlice::new_ref(content)
-                .map_err(|()| ReaderError::from_str("Invalid SNBT"))?;
+                .map_err(|err| ReaderError::from_string(alloc::format!("Invalid SNBT: {err}")))?;
 
             // TODO: Do some lifetime trickery and use `deserialize_borrowed_core`.
             let mut core = super::deserialize::deserialize_owned_core(&snbt);
@@ -7,8 +7,11 @@ extern crate std;
 
 #[cfg(feature = "facet")]
 pub mod facet;
+#[cfg(feature = "nbt")]
+pub mod path;
 pub mod types;
 pub mod unicode;
+pub mod write;
 
 pub mod prelude {
     //! Re-exports of common types, traits, and macros.
@@ -20,8 +23,13 @@ pub mod prelude {
         deserialize::{DeserializeSnbt, functions::*},
         serialize::{SerializeSnbt, functions::*},
     };
-    pub use crate::types::indexed::{
-        IndexedSnbt,
-        core::{IndexedSnbtCow, IndexedSnbtSlice},
+    #[cfg(feature = "nbt")]
+    pub use crate::path::NbtPath;
+    pub use crate::{
+        types::indexed::{
+            IndexedSnbt,
+            core::{IndexedSnbtCow, IndexedSnbtSlice},
+        },
+        write::{SnbtWriter, WriteSnbt},
     };
 }
@@ -0,0 +1,240 @@
+//! Evaluating [`NbtPath`]s against [`IndexedNbt`]s.
+//!
+//! Values are selected without copying them out of the underlying data.
+//!
+//! Since indexed data cannot be resized in place, modifying a
+//! [`IndexedNbtCow`] converts it into a structured [`Nbt`] and back.
+
+use alloc::vec::Vec;
+
+use froglight_nbt::types::{
+    indexed::{
+        IndexedNbt,
+        compound::IndexedCompound,
+        core::{IndexCore, IndexedNbtCow, IndexedNbtSlice, Ref},
+        entry::IndexedValue,
+        reference::ValueReference,
+    },
+    structured::{Nbt, NbtCompound, NbtValue, ValueRef},
+};
+
+use super::{NbtPath, NbtPathError, NbtPathNode, structured::resolve_index};
+
+impl NbtPath {
+    /// Get all values selected by this path,
+    /// without copying them out of the [`IndexedNbt`].
+    ///
+    /// An empty path selects the root compound.
+    #[must_use]
+    pub fn get_indexed<'a, C: IndexCore<Ref>>(
+        &self,
+        nbt: &'a IndexedNbt<C>,
+    ) -> Vec<ValueReference<'a, Ref, C>> {
+        let mut current = alloc::vec![nbt.as_value()];
+        for node in &self.0 {
+            let mut next = Vec::new();
+            for value in current {
+                select_node(node, value, &mut next);
+            }
+            current = next;
+        }
+        current
+    }
+
+    /// Count the number of values selected by this path.
+    #[must_use]
+    pub fn count_indexed<C: IndexCore<Ref>>(&self, nbt: &IndexedNbt<C>) -> usize {
+        self.get_indexed(nbt).len()
+    }
+
+    /// Set all values selected by this path.
+    ///
+    /// See [`NbtPath::set`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value could not be set.
+    pub fn set_indexed(
+        &self,
+        nbt: &mut IndexedNbtCow<'_>,
+        value: &NbtValue,
+    ) -> Result<usize, NbtPathError> {
+        modify(nbt, |structured| self.set(structured, value))
+    }
+
+    /// Remove all values selected by this path.
+    ///
+    /// See [`NbtPath::remove`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the values could not be removed.
+    pub fn remove_indexed(&self, nbt: &mut IndexedNbtCow<'_>) -> Result<usize, NbtPathError> {
+        modify(nbt, |structured| self.remove(structured))
+    }
+
+    /// Merge a compound into all compounds selected by this path.
+    ///
+    /// See [`NbtPath::merge`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the compound could not be merged.
+    pub fn merge_indexed(
+        &self,
+        nbt: &mut IndexedNbtCow<'_>,
+        other: &NbtCompound,
+    ) -> Result<usize, NbtPathError> {
+        modify(nbt, |structured| self.merge(structured, other))
+    }
+}
+
+/// Modify an [`IndexedNbtCow`] as a structured [`Nbt`],
+/// re-indexing it if anything was changed.
+fn modify(
+    nbt: &mut IndexedNbtCow<'_>,
+    f: impl FnOnce(&mut NbtCompound) -> Result<usize, NbtPathError>,
+) -> Result<usize, NbtPathError> {
+    let named = nbt.name().is_some();
+    let mut structured = Nbt::try_from(&*nbt).expect("IndexedNbt is always valid NBT");
+
+    let count = f(structured.compound_mut())?;
+    if count != 0 {
+        let bytes = if named { structured.to_named_bytes() } else { structured.to_unnamed_bytes() };
+        let indexed = if named {
+            IndexedNbtSlice::new_named(&bytes)
+        } else {
+            IndexedNbtSlice::new_unnamed(&bytes)
+        };
+        *nbt = indexed.map(IndexedNbtSlice::into_owned).expect("Written NBT is always valid");
+    }
+
+    Ok(count)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn select_node<'a, C: IndexCore<Ref>>(
+    node: &NbtPathNode,
+    value: ValueReference<'a, Ref, C>,
+    output: &mut Vec<ValueReference<'a, Ref, C>>,
+) {
+    match (node, value) {
+        (NbtPathNode::MatchRoot(pattern), ValueReference::Compound(compound)) => {
+            if matches_compound(pattern, compound) {
+                output.push(value);
+            }
+        }
+        (NbtPathNode::Key(key), ValueReference::Compound(compound)) => {
+            output.extend(compound.get(key.as_str()).map(IndexedValue::into_value));
+        }
+        (NbtPathNode::MatchKey(key, pattern), ValueReference::Compound(compound)) => {
+            if let Some(ValueReference::Compound(inner)) =
+                compound.get(key.as_str()).map(IndexedValue::into_value)
+                && matches_compound(pattern, inner)
+            {
+                output.push(ValueReference::Compound(inner));
+            }
+        }
+        (NbtPathNode::Index(index), _) => {
+            if let Some(index) = resolve_index(*index, len(value)) {
+                output.extend(element(value, index));
+            }
+        }
+        (NbtPathNode::All, _) => output.extend(elements(value)),
+        (NbtPathNode::MatchAll(pattern), _) => {
+            output.extend(elements(value).filter(|element| {
+                matches!(element, ValueReference::Compound(compound) if matches_compound(pattern, *compound))
+            }));
+        }
+        _ => {}
+    }
+}
+
+/// Get the number of elements in a list or array.
+fn len<C: IndexCore<Ref>>(value: ValueReference<'_, Ref, C>) -> usize {
+    match value {
+        ValueReference::List(list) => list.len(),
+        ValueReference::ByteArray(array) => array.len(),
+        ValueReference::IntArray(array) => array.len(),
+        ValueReference::LongArray(array) => array.len(),
+        _ => 0,
+    }
+}
+
+/// Get an element of a list or array.
+fn element<C: IndexCore<Ref>>(
+    value: ValueReference<'_, Ref, C>,
+    index: usize,
+) -> Option<ValueReference<'_, Ref, C>> {
+    match value {
+        ValueReference::List(list) => list.get(index),
+        ValueReference::ByteArray(array) => array.get_indexed(index).map(ValueReference::Byte),
+        ValueReference::IntArray(array) => array.get_indexed(index).map(ValueReference::Int),
+        ValueReference::LongArray(array) => array.get_indexed(index).map(ValueReference::Long),
+        _ => None,
+    }
+}
+
+/// Iterate over the elements of a list or array.
+fn elements<C: IndexCore<Ref>>(
+    value: ValueReference<'_, Ref, C>,
+) -> impl Iterator<Item = ValueReference<'_, Ref, C>> {
+    (0..len(value)).filter_map(move |index| element(value, index))
+}
+
+/// Returns `true` if `actual` contains everything in `pattern`.
+fn matches<C: IndexCore<Ref>>(pattern: ValueRef<'_>, actual: ValueReference<'_, Ref, C>) -> bool {
+    /// Compare an array pattern against an indexed array.
+    macro_rules! match_array {
+        ($pattern:expr, $array:expr) => {
+            $pattern.len() == $array.len()
+                && $pattern.iter().enumerate().all(|(index, expected)| {
+                    $array.get_indexed(index).is_some_and(|element| element.get() == *expected)
+                })
+        };
+    }
+
+    match (pattern, actual) {
+        (ValueRef::Byte(pattern), ValueReference::Byte(actual)) => pattern == actual.get(),
+        (ValueRef::Short(pattern), ValueReference::Short(actual)) => pattern == actual.get(),
+        (ValueRef::Int(pattern), ValueReference::Int(actual)) => pattern == actual.get(),
+        (ValueRef::Long(pattern), ValueReference::Long(actual)) => pattern == actual.get(),
+        #[expect(clippy::float_cmp, reason = "Values must match exactly")]
+        (ValueRef::Float(pattern), ValueReference::Float(actual)) => pattern == actual.get(),
+        #[expect(clippy::float_cmp, reason = "Values must match exactly")]
+        (ValueRef::Double(pattern), ValueReference::Double(actual)) => pattern == actual.get(),
+        (ValueRef::ByteArray(pattern), ValueReference::ByteArray(actual)) => {
+            pattern == actual.get()
+        }
+        (ValueRef::String(pattern), ValueReference::String(actual)) => pattern == actual.get(),
+        (ValueRef::IntArray(pattern), ValueReference::IntArray(actual)) => {
+            match_array!(pattern, actual)
+        }
+        (ValueRef::LongArray(pattern), ValueReference::LongArray(actual)) => {
+            match_array!(pattern, actual)
+        }
+        (ValueRef::Compound(pattern), ValueReference::Compound(actual)) => {
+            matches_compound(pattern, actual)
+        }
+        (ValueRef::List(..), ValueReference::List(..)) => {
+            let patterns = pattern.elements().unwrap_or_default();
+            if patterns.is_empty() {
+                len(actual) == 0
+            } else {
+                patterns.into_iter().all(|pattern| elements(actual).any(|e| matches(pattern, e)))
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `actual` contains everything in `pattern`.
+fn matches_compound<C: IndexCore<Ref>>(
+    pattern: &NbtCompound,
+    actual: IndexedCompound<'_, Ref, C>,
+) -> bool {
+    pattern.iter().all(|(key, pattern)| {
+        actual.get(key).is_some_and(|value| matches(pattern.into(), value.into_value()))
+    })
+}
@@ -0,0 +1,141 @@
+//! NBT paths, as used by the `/data` command.
+//!
+//! A [`NbtPath`] selects values inside of a NBT structure,
+//! and can be evaluated against both [`Nbt`](froglight_nbt::prelude::Nbt) and
+//! [`IndexedNbt`](froglight_nbt::prelude::IndexedNbt).
+//!
+//! ```rust
+//! use froglight_nbt::prelude::*;
+//! use froglight_snbt::path::NbtPath;
+//!
+//! let mut nbt = NbtCompound::new();
+//! let path: NbtPath = "Inventory[{Slot:0b}].count".parse().unwrap();
+//!
+//! // Create the item and set its count.
+//! path.set(&mut nbt, &NbtValue::Int(64)).unwrap();
+//! assert_eq!(path.get(&nbt), [NbtValue::Int(64)]);
+//!
+//! // The filter matches the newly created item.
+//! let slots: NbtPath = "Inventory[].Slot".parse().unwrap();
+//! assert_eq!(slots.get(&nbt), [NbtValue::Byte(0)]);
+//! ```
+use alloc::{string::String, vec::Vec};
+use core::{error::Error, fmt, str::FromStr};
+
+use froglight_nbt::types::structured::NbtCompound;
+
+mod indexed;
+mod parse;
+mod snbt;
+mod structured;
+
+/// A path to values inside of a NBT structure.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NbtPath(pub Vec<NbtPathNode>);
+
+/// A single node of a [`NbtPath`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum NbtPathNode {
+    /// Match the root compound against a pattern.
+    ///
+    /// Only valid as the first node of a path, e.g. `{Invisible:1b}`.
+    MatchRoot(NbtCompound),
+    /// Select the value of a key, e.g. `Inventory`.
+    Key(String),
+    /// Select the value of a key if it matches a pattern,
+    /// e.g. `Item{id:"minecraft:stone"}`.
+    MatchKey(String, NbtCompound),
+    /// Select an element of a list or array, e.g. `[0]`.
+    ///
+    /// Negative indexes count from the end of the list.
+    Index(i32),
+    /// Select all elements of a list or array, e.g. `[]`.
+    All,
+    /// Select all elements of a list that match a pattern, e.g. `[{Slot:0b}]`.
+    MatchAll(NbtCompound),
+}
+
+impl NbtPath {
+    /// Parse a [`NbtPath`] from the start of the given string,
+    /// returning the path and the remaining input.
+    ///
+    /// Parsing stops at the first space outside of a key or pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string does not start with a valid path.
+    pub fn parse_prefix(input: &str) -> Result<(Self, &str), NbtPathError> {
+        parse::parse_path(input)
+    }
+}
+
+impl FromStr for NbtPath {
+    type Err = NbtPathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::parse_prefix(s)? {
+            (path, "") => Ok(path),
+            _ => Err(NbtPathError::InvalidPath),
+        }
+    }
+}
+
+impl fmt::Display for NbtPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, node) in self.0.iter().enumerate() {
+            if i != 0 && matches!(node, NbtPathNode::Key(..) | NbtPathNode::MatchKey(..)) {
+                f.write_str(".")?;
+            }
+            fmt::Display::fmt(node, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for NbtPathNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MatchRoot(pattern) => snbt::write_compound(f, pattern),
+            Self::Key(key) => parse::write_key(f, key),
+            Self::MatchKey(key, pattern) => {
+                parse::write_key(f, key)?;
+                snbt::write_compound(f, pattern)
+            }
+            Self::Index(index) => write!(f, "[{index}]"),
+            Self::All => f.write_str("[]"),
+            Self::MatchAll(pattern) => {
+                f.write_str("[")?;
+                snbt::write_compound(f, pattern)?;
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An error that can occur while parsing or evaluating a [`NbtPath`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NbtPathError {
+    /// The path could not be parsed.
+    InvalidPath,
+    /// The path is empty.
+    EmptyPath,
+    /// A value could not be placed at the path,
+    /// as it does not match the type of its container.
+    TypeMismatch,
+    /// The operation is not supported by the last node of the path.
+    Unsupported,
+}
+
+impl Error for NbtPathError {}
+impl fmt::Display for NbtPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPath => f.write_str("invalid NBT path"),
+            Self::EmptyPath => f.write_str("empty NBT path"),
+            Self::TypeMismatch => f.write_str("value does not match the type of its container"),
+            Self::Unsupported => f.write_str("operation not supported by this NBT path"),
+        }
+    }
+}
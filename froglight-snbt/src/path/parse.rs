@@ -0,0 +1,177 @@
+//! Parsing [`NbtPath`]s from strings.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use super::{NbtPath, NbtPathError, NbtPathNode, snbt};
+
+/// Parse a [`NbtPath`] from the start of the given string.
+pub(super) fn parse_path(input: &str) -> Result<(NbtPath, &str), NbtPathError> {
+    let mut reader = Reader::new(input);
+    let mut nodes = Vec::new();
+
+    while reader.peek().is_some_and(|c| c != ' ') {
+        nodes.push(parse_node(&mut reader, nodes.is_empty())?);
+
+        // Nodes are separated by `.`, unless followed by a list or pattern.
+        if let Some(c) = reader.peek()
+            && !matches!(c, ' ' | '[' | '{')
+        {
+            reader.expect('.')?;
+        }
+    }
+
+    if nodes.is_empty() {
+        return Err(NbtPathError::EmptyPath);
+    }
+
+    Ok((NbtPath(nodes), reader.remaining()))
+}
+
+fn parse_node(reader: &mut Reader<'_>, first: bool) -> Result<NbtPathNode, NbtPathError> {
+    match reader.peek() {
+        Some('{') if first => snbt::read_compound(reader).map(NbtPathNode::MatchRoot),
+        Some('[') => {
+            reader.skip();
+            let node = match reader.peek() {
+                Some('{') => NbtPathNode::MatchAll(snbt::read_compound(reader)?),
+                Some(']') => NbtPathNode::All,
+                _ => NbtPathNode::Index(reader.read_int()?),
+            };
+            reader.expect(']')?;
+            Ok(node)
+        }
+        Some('"' | '\'') => {
+            let key = reader.read_quoted()?;
+            parse_key(reader, key)
+        }
+        _ => {
+            let key =
+                reader.read_while(|c| !matches!(c, ' ' | '"' | '\'' | '[' | ']' | '.' | '{' | '}'));
+            if key.is_empty() {
+                return Err(NbtPathError::InvalidPath);
+            }
+            parse_key(reader, String::from(key))
+        }
+    }
+}
+
+/// Parse an optional pattern following a key.
+fn parse_key(reader: &mut Reader<'_>, key: String) -> Result<NbtPathNode, NbtPathError> {
+    if reader.peek() == Some('{') {
+        snbt::read_compound(reader).map(|pattern| NbtPathNode::MatchKey(key, pattern))
+    } else {
+        Ok(NbtPathNode::Key(key))
+    }
+}
+
+/// Write a key, quoting it if required.
+pub(super) fn write_key(f: &mut fmt::Formatter<'_>, key: &str) -> fmt::Result {
+    if !key.is_empty()
+        && !key.contains(|c: char| {
+            c.is_whitespace() || matches!(c, '"' | '\'' | '[' | ']' | '.' | '{' | '}')
+        })
+    {
+        f.write_str(key)
+    } else {
+        write_quoted(f, key)
+    }
+}
+
+/// Write a double-quoted string, escaping it as required.
+pub(super) fn write_quoted(f: &mut fmt::Formatter<'_>, string: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in string.chars() {
+        if matches!(c, '"' | '\\') {
+            f.write_str("\\")?;
+        }
+        fmt::Write::write_char(f, c)?;
+    }
+    f.write_str("\"")
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// A cursor over a string being parsed.
+pub(super) struct Reader<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Create a new [`Reader`] at the start of the given string.
+    const fn new(input: &'a str) -> Self { Self { input, position: 0 } }
+
+    /// Get the remaining input.
+    pub(super) fn remaining(&self) -> &'a str { &self.input[self.position..] }
+
+    /// Peek at the next character without consuming it.
+    pub(super) fn peek(&self) -> Option<char> { self.remaining().chars().next() }
+
+    /// Consume the next character.
+    pub(super) fn skip(&mut self) {
+        if let Some(c) = self.peek() {
+            self.position += c.len_utf8();
+        }
+    }
+
+    /// Consume the next `length` bytes.
+    pub(super) fn advance(&mut self, length: usize) {
+        self.position = (self.position + length).min(self.input.len());
+    }
+
+    /// Consume the next character if it is `expected`,
+    /// otherwise return an error.
+    pub(super) fn expect(&mut self, expected: char) -> Result<(), NbtPathError> {
+        if self.peek() == Some(expected) {
+            self.skip();
+            Ok(())
+        } else {
+            Err(NbtPathError::InvalidPath)
+        }
+    }
+
+    /// Consume characters while `f` returns `true`.
+    pub(super) fn read_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let start = self.position;
+        while self.peek().is_some_and(&f) {
+            self.skip();
+        }
+        &self.input[start..self.position]
+    }
+
+    /// Read a signed integer.
+    fn read_int(&mut self) -> Result<i32, NbtPathError> {
+        self.read_while(|c| c.is_ascii_digit() || c == '-')
+            .parse()
+            .map_err(|_| NbtPathError::InvalidPath)
+    }
+
+    /// Read a single or double-quoted string.
+    pub(super) fn read_quoted(&mut self) -> Result<String, NbtPathError> {
+        let Some(quote @ ('"' | '\'')) = self.peek() else {
+            return Err(NbtPathError::InvalidPath);
+        };
+        self.skip();
+
+        let mut string = String::new();
+        loop {
+            match self.peek() {
+                Some('\\') => {
+                    self.skip();
+                    match self.peek() {
+                        Some(c @ ('"' | '\'' | '\\')) => string.push(c),
+                        _ => return Err(NbtPathError::InvalidPath),
+                    }
+                }
+                Some(c) if c == quote => {
+                    self.skip();
+                    return Ok(string);
+                }
+                Some(c) => string.push(c),
+                None => return Err(NbtPathError::InvalidPath),
+            }
+            self.skip();
+        }
+    }
+}
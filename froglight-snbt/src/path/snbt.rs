@@ -0,0 +1,59 @@
+//! Reading and writing the SNBT patterns used by [`NbtPath`](super::NbtPath).
+//!
+//! Patterns are parsed and written using the SNBT implementation in this crate.
+
+use core::fmt;
+
+use froglight_nbt::types::structured::{Nbt, NbtCompound};
+
+use super::{NbtPathError, parse::Reader};
+use crate::{prelude::IndexedSnbtSlice, write::SnbtWriter};
+
+/// Read a compound pattern, e.g. `{Slot:0b,id:"minecraft:stone"}`.
+pub(super) fn read_compound(reader: &mut Reader<'_>) -> Result<NbtCompound, NbtPathError> {
+    let input = reader.remaining();
+    let length = compound_length(input).ok_or(NbtPathError::InvalidPath)?;
+
+    let snbt =
+        IndexedSnbtSlice::new_ref(&input[..length]).map_err(|_| NbtPathError::InvalidPath)?;
+    let nbt = Nbt::try_from(&snbt).map_err(|()| NbtPathError::InvalidPath)?;
+
+    reader.advance(length);
+    Ok(nbt.into_compound())
+}
+
+/// Find the length of the compound at the start of the input,
+/// skipping over any brackets inside of quoted strings.
+fn compound_length(input: &str) -> Option<usize> {
+    if !input.starts_with('{') {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+
+    for (index, c) in input.char_indices() {
+        match (quote, c) {
+            (Some(_), _) if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '{' | '[') => depth += 1,
+            (None, '}' | ']') => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Write a compound as compact SNBT.
+pub(super) fn write_compound(f: &mut fmt::Formatter<'_>, compound: &NbtCompound) -> fmt::Result {
+    SnbtWriter::compact().write(compound, f)
+}
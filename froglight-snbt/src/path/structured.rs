@@ -0,0 +1,478 @@
+//! Evaluating [`NbtPath`]s against structured [`NbtCompound`]s.
+
+use alloc::{vec, vec::Vec};
+use core::mem;
+
+use froglight_nbt::types::structured::{NbtCompound, NbtList, NbtValue, ValueRef};
+
+use super::{NbtPath, NbtPathError, NbtPathNode};
+
+impl NbtPath {
+    /// Get all values selected by this path.
+    ///
+    /// An empty path selects the root compound.
+    #[must_use]
+    pub fn get(&self, nbt: &NbtCompound) -> Vec<NbtValue> {
+        select(&self.0, nbt).into_iter().map(ValueRef::to_value).collect()
+    }
+
+    /// Count the number of values selected by this path.
+    #[must_use]
+    pub fn count(&self, nbt: &NbtCompound) -> usize { select(&self.0, nbt).len() }
+
+    /// Set all values selected by this path,
+    /// creating any missing compounds and lists along the way.
+    ///
+    /// Returns the number of values that were changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is empty, ends with a
+    /// [`NbtPathNode::MatchRoot`], or the value does not match the type of the
+    /// list or array it is placed in.
+    pub fn set(&self, nbt: &mut NbtCompound, value: &NbtValue) -> Result<usize, NbtPathError> {
+        let Some((last, parents)) = self.0.split_last() else {
+            return Err(NbtPathError::EmptyPath);
+        };
+
+        with_root(nbt, |root| {
+            visit_mut(parents, Some(last), root, true, &mut |parent| set_node(last, parent, value))
+        })
+    }
+
+    /// Remove all values selected by this path.
+    ///
+    /// Returns the number of values that were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is empty or ends with a
+    /// [`NbtPathNode::MatchRoot`].
+    pub fn remove(&self, nbt: &mut NbtCompound) -> Result<usize, NbtPathError> {
+        let Some((last, parents)) = self.0.split_last() else {
+            return Err(NbtPathError::EmptyPath);
+        };
+
+        with_root(nbt, |root| {
+            visit_mut(parents, Some(last), root, false, &mut |parent| remove_node(last, parent))
+        })
+    }
+
+    /// Merge a compound into all compounds selected by this path,
+    /// creating any missing compounds and lists along the way.
+    ///
+    /// An empty path merges into the root compound.
+    ///
+    /// Returns the number of compounds that were changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the selected values are not compounds.
+    pub fn merge(&self, nbt: &mut NbtCompound, other: &NbtCompound) -> Result<usize, NbtPathError> {
+        with_root(nbt, |root| {
+            visit_mut(&self.0, None, root, true, &mut |target| match target {
+                NbtValue::Compound(compound) => Ok(usize::from(merge_compound(compound, other))),
+                _ => Err(NbtPathError::TypeMismatch),
+            })
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Select all values matching the given nodes.
+fn select<'a>(nodes: &[NbtPathNode], root: &'a NbtCompound) -> Vec<ValueRef<'a>> {
+    let mut current = vec![ValueRef::Compound(root)];
+    for node in nodes {
+        let mut next = Vec::new();
+        for value in current {
+            select_node(node, value, &mut next);
+        }
+        current = next;
+    }
+    current
+}
+
+fn select_node<'a>(node: &NbtPathNode, value: ValueRef<'a>, output: &mut Vec<ValueRef<'a>>) {
+    match (node, value) {
+        (NbtPathNode::MatchRoot(pattern), ValueRef::Compound(compound)) => {
+            if matches_compound(pattern, compound) {
+                output.push(value);
+            }
+        }
+        (NbtPathNode::Key(key), ValueRef::Compound(compound)) => {
+            output.extend(compound.get(key.as_str()).map(ValueRef::from));
+        }
+        (NbtPathNode::MatchKey(key, pattern), ValueRef::Compound(compound)) => {
+            if let Some(NbtValue::Compound(inner)) = compound.get(key.as_str())
+                && matches_compound(pattern, inner)
+            {
+                output.push(ValueRef::Compound(inner));
+            }
+        }
+        (NbtPathNode::Index(index), _) => {
+            if let Some(elements) = value.elements()
+                && let Some(index) = resolve_index(*index, elements.len())
+            {
+                output.push(elements[index]);
+            }
+        }
+        (NbtPathNode::All, _) => output.extend(value.elements().into_iter().flatten()),
+        (NbtPathNode::MatchAll(pattern), _) => {
+            output.extend(value.elements().into_iter().flatten().filter(|element| {
+                matches!(element, ValueRef::Compound(compound) if matches_compound(pattern, compound))
+            }));
+        }
+        _ => {}
+    }
+}
+
+/// Resolve a possibly negative index into a list of the given length.
+pub(super) fn resolve_index(index: i32, len: usize) -> Option<usize> {
+    let index = if index < 0 {
+        len.checked_sub(usize::try_from(index.unsigned_abs()).ok()?)?
+    } else {
+        usize::try_from(index).ok()?
+    };
+    (index < len).then_some(index)
+}
+
+/// Returns `true` if `actual` contains everything in `pattern`.
+///
+/// Compounds match if every key in the pattern matches,
+/// and lists match if every element in the pattern matches any element.
+fn matches(pattern: ValueRef<'_>, actual: ValueRef<'_>) -> bool {
+    match (pattern, actual) {
+        (ValueRef::Compound(pattern), ValueRef::Compound(actual)) => {
+            matches_compound(pattern, actual)
+        }
+        (ValueRef::List(..), ValueRef::List(..)) => {
+            let patterns = pattern.elements().unwrap_or_default();
+            let elements = actual.elements().unwrap_or_default();
+            if patterns.is_empty() {
+                elements.is_empty()
+            } else {
+                patterns.iter().all(|p| elements.iter().any(|element| matches(*p, *element)))
+            }
+        }
+        _ => pattern == actual,
+    }
+}
+
+/// Returns `true` if `actual` contains everything in `pattern`.
+fn matches_compound(pattern: &NbtCompound, actual: &NbtCompound) -> bool {
+    pattern.iter().all(|(key, pattern)| {
+        actual.get(key).is_some_and(|value| matches(pattern.into(), value.into()))
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Temporarily wrap the root compound in a [`NbtValue`].
+fn with_root<R>(root: &mut NbtCompound, f: impl FnOnce(&mut NbtValue) -> R) -> R {
+    let mut value = NbtValue::Compound(mem::take(root));
+    let result = f(&mut value);
+    if let NbtValue::Compound(compound) = value {
+        *root = compound;
+    }
+    result
+}
+
+/// Call `f` on every value matching the given nodes.
+///
+/// If `create` is set, missing values are created based on the node that
+/// follows them, with `last` following the final node.
+fn visit_mut(
+    nodes: &[NbtPathNode],
+    last: Option<&NbtPathNode>,
+    value: &mut NbtValue,
+    create: bool,
+    f: &mut dyn FnMut(&mut NbtValue) -> Result<usize, NbtPathError>,
+) -> Result<usize, NbtPathError> {
+    let Some((node, rest)) = nodes.split_first() else { return f(value) };
+    let next = rest.first().or(last);
+
+    match node {
+        NbtPathNode::MatchRoot(pattern) => match value {
+            NbtValue::Compound(compound) if matches_compound(pattern, compound) => {
+                visit_mut(rest, last, value, create, f)
+            }
+            _ => Ok(0),
+        },
+        NbtPathNode::Key(key) => {
+            let NbtValue::Compound(compound) = value else { return Ok(0) };
+            if create && compound.get(key.as_str()).is_none() {
+                compound.insert(key.as_str(), create_parent(next));
+            }
+
+            match compound.get_mut(key.as_str()) {
+                Some(child) => visit_mut(rest, last, child, create, f),
+                None => Ok(0),
+            }
+        }
+        NbtPathNode::MatchKey(key, pattern) => {
+            let NbtValue::Compound(compound) = value else { return Ok(0) };
+            if create && compound.get(key.as_str()).is_none() {
+                compound.insert(key.as_str(), pattern.clone());
+            }
+
+            match compound.get_mut(key.as_str()) {
+                Some(child @ NbtValue::Compound(..))
+                    if child
+                        .as_compound()
+                        .is_some_and(|inner| matches_compound(pattern, inner)) =>
+                {
+                    visit_mut(rest, last, child, create, f)
+                }
+                _ => Ok(0),
+            }
+        }
+        NbtPathNode::Index(index) => {
+            with_elements(value, |_, elements| match resolve_index(*index, elements.len()) {
+                Some(index) => visit_mut(rest, last, &mut elements[index], create, f),
+                None => Ok(0),
+            })
+        }
+        NbtPathNode::All => with_elements(value, |kind, elements| {
+            if create && kind == Elements::List && elements.is_empty() {
+                elements.push(create_parent(next));
+            }
+
+            let mut count = 0;
+            for element in elements {
+                count += visit_mut(rest, last, element, create, f)?;
+            }
+            Ok(count)
+        }),
+        NbtPathNode::MatchAll(pattern) => with_elements(value, |kind, elements| {
+            let is_match = |element: &NbtValue| {
+                element.as_compound().is_some_and(|compound| matches_compound(pattern, compound))
+            };
+
+            if create
+                && kind == Elements::List
+                && !elements.iter().any(is_match)
+                && elements.iter().all(|element| element.as_compound().is_some())
+            {
+                elements.push(NbtValue::Compound(pattern.clone()));
+            }
+
+            let mut count = 0;
+            for element in elements.iter_mut().filter(|element| is_match(element)) {
+                count += visit_mut(rest, last, element, create, f)?;
+            }
+            Ok(count)
+        }),
+    }
+}
+
+/// Create a value that can be traversed by the given node.
+fn create_parent(next: Option<&NbtPathNode>) -> NbtValue {
+    match next {
+        Some(NbtPathNode::Index(..) | NbtPathNode::All | NbtPathNode::MatchAll(..)) => {
+            NbtValue::List(NbtList::Empty)
+        }
+        _ => NbtValue::Compound(NbtCompound::new()),
+    }
+}
+
+/// Set the children of `parent` selected by `node`.
+fn set_node(
+    node: &NbtPathNode,
+    parent: &mut NbtValue,
+    value: &NbtValue,
+) -> Result<usize, NbtPathError> {
+    match node {
+        NbtPathNode::MatchRoot(..) => Err(NbtPathError::Unsupported),
+        NbtPathNode::Key(key) => {
+            let NbtValue::Compound(compound) = parent else { return Ok(0) };
+            let previous = compound.insert(key.as_str(), value.clone());
+            Ok(usize::from(previous.as_ref() != Some(value)))
+        }
+        NbtPathNode::MatchKey(key, pattern) => {
+            let NbtValue::Compound(compound) = parent else { return Ok(0) };
+            match compound.get_mut(key.as_str()) {
+                Some(existing)
+                    if existing
+                        .as_compound()
+                        .is_some_and(|inner| matches_compound(pattern, inner))
+                        && existing != value =>
+                {
+                    *existing = value.clone();
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+        NbtPathNode::Index(index) => {
+            replace_elements(parent, value, |i, _, len| resolve_index(*index, len) == Some(i))
+        }
+        NbtPathNode::All => {
+            // Setting all elements of an empty list adds the value.
+            if let NbtValue::List(list @ NbtList::Empty) = parent {
+                *list = NbtList::try_from(vec![value.clone()]).unwrap_or(NbtList::Empty);
+                return Ok(1);
+            }
+            replace_elements(parent, value, |_, _, _| true)
+        }
+        NbtPathNode::MatchAll(pattern) => replace_elements(parent, value, |_, element, _| {
+            element.as_compound().is_some_and(|compound| matches_compound(pattern, compound))
+        }),
+    }
+}
+
+/// Remove the children of `parent` selected by `node`.
+fn remove_node(node: &NbtPathNode, parent: &mut NbtValue) -> Result<usize, NbtPathError> {
+    match node {
+        NbtPathNode::MatchRoot(..) => Err(NbtPathError::Unsupported),
+        NbtPathNode::Key(key) => match parent {
+            NbtValue::Compound(compound) => {
+                Ok(usize::from(compound.remove(key.as_str()).is_some()))
+            }
+            _ => Ok(0),
+        },
+        NbtPathNode::MatchKey(key, pattern) => match parent {
+            NbtValue::Compound(compound)
+                if compound
+                    .get(key.as_str())
+                    .and_then(NbtValue::as_compound)
+                    .is_some_and(|inner| matches_compound(pattern, inner)) =>
+            {
+                compound.remove(key.as_str());
+                Ok(1)
+            }
+            _ => Ok(0),
+        },
+        NbtPathNode::Index(index) => with_elements(parent, |_, elements| {
+            Ok(resolve_index(*index, elements.len()).map_or(0, |index| {
+                elements.remove(index);
+                1
+            }))
+        }),
+        NbtPathNode::All => with_elements(parent, |_, elements| Ok(elements.drain(..).count())),
+        NbtPathNode::MatchAll(pattern) => with_elements(parent, |_, elements| {
+            let len = elements.len();
+            elements.retain(|element| {
+                !element.as_compound().is_some_and(|compound| matches_compound(pattern, compound))
+            });
+            Ok(len - elements.len())
+        }),
+    }
+}
+
+/// Recursively merge `other` into `compound`.
+///
+/// Returns `true` if `compound` was changed.
+fn merge_compound(compound: &mut NbtCompound, other: &NbtCompound) -> bool {
+    let mut changed = false;
+    for (key, value) in other.iter() {
+        match (compound.get_mut(key), value) {
+            (Some(NbtValue::Compound(inner)), NbtValue::Compound(other)) => {
+                changed |= merge_compound(inner, other);
+            }
+            (Some(existing), _) if existing == value => {}
+            _ => {
+                compound.insert(key, value.clone());
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The type of container that elements are stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Elements {
+    List,
+    ByteArray,
+    IntArray,
+    LongArray,
+}
+
+impl Elements {
+    /// Returns `true` if the value can be stored in this container.
+    fn accepts(self, value: &NbtValue) -> bool {
+        match self {
+            Self::List => true,
+            Self::ByteArray => matches!(value, NbtValue::Byte(..)),
+            Self::IntArray => matches!(value, NbtValue::Int(..)),
+            Self::LongArray => matches!(value, NbtValue::Long(..)),
+        }
+    }
+}
+
+/// Call `f` with the elements of a list or array as [`NbtValue`]s.
+///
+/// Returns `Ok(0)` if the value is not a list or array.
+///
+/// # Panics
+///
+/// Panics if `f` leaves elements of different types in a list.
+fn with_elements(
+    value: &mut NbtValue,
+    f: impl FnOnce(Elements, &mut Vec<NbtValue>) -> Result<usize, NbtPathError>,
+) -> Result<usize, NbtPathError> {
+    let (kind, mut elements): (_, Vec<NbtValue>) = match value {
+        NbtValue::List(list) => (Elements::List, mem::replace(list, NbtList::Empty).into()),
+        NbtValue::ByteArray(array) => {
+            (Elements::ByteArray, mem::take(array).into_iter().map(NbtValue::Byte).collect())
+        }
+        NbtValue::IntArray(array) => {
+            (Elements::IntArray, mem::take(array).into_iter().map(NbtValue::Int).collect())
+        }
+        NbtValue::LongArray(array) => {
+            (Elements::LongArray, mem::take(array).into_iter().map(NbtValue::Long).collect())
+        }
+        _ => return Ok(0),
+    };
+
+    let result = f(kind, &mut elements);
+
+    *value = match kind {
+        Elements::List => NbtValue::List(
+            NbtList::try_from(elements).expect("List elements must all be the same type"),
+        ),
+        Elements::ByteArray => {
+            NbtValue::ByteArray(elements.iter().filter_map(NbtValue::as_byte).copied().collect())
+        }
+        Elements::IntArray => {
+            NbtValue::IntArray(elements.iter().filter_map(NbtValue::as_int).copied().collect())
+        }
+        Elements::LongArray => {
+            NbtValue::LongArray(elements.iter().filter_map(NbtValue::as_long).copied().collect())
+        }
+    };
+
+    result
+}
+
+/// Replace the elements of a list or array selected by `select`.
+///
+/// Returns an error if the value would leave a list with mixed types.
+fn replace_elements(
+    parent: &mut NbtValue,
+    value: &NbtValue,
+    select: impl Fn(usize, &NbtValue, usize) -> bool,
+) -> Result<usize, NbtPathError> {
+    with_elements(parent, |kind, elements| {
+        let len = elements.len();
+        let compatible = kind.accepts(value)
+            && elements.iter().enumerate().all(|(i, element)| {
+                select(i, element, len) || mem::discriminant(element) == mem::discriminant(value)
+            });
+        if !compatible {
+            return Err(NbtPathError::TypeMismatch);
+        }
+
+        let mut count = 0;
+        for (i, element) in elements.iter_mut().enumerate() {
+            if select(i, element, len) && element != value {
+                *element = value.clone();
+                count += 1;
+            }
+        }
+        Ok(count)
+    })
+}
@@ -0,0 +1,137 @@
+//! Conversions between [`IndexedSnbt`] and NBT.
+//!
+//! Values keep their types, including typed arrays,
+//! but booleans become bytes and lists must contain a single type.
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+use froglight_mutf8::prelude::MString;
+use froglight_nbt::types::{
+    indexed::{
+        IndexedNbt,
+        core::{IndexCore as NbtIndexCore, IndexedNbtCow, IndexedNbtSlice, Ref},
+    },
+    structured::{Nbt, NbtCompound, NbtList, NbtValue},
+};
+
+use crate::{
+    types::indexed::{
+        IndexedSnbt,
+        compound::IndexedCompound,
+        core::{IndexCore, IndexedSnbtCow},
+        index::string::StringQuotes,
+        reference::ValueReference,
+    },
+    write::SnbtWriter,
+};
+
+impl<C: IndexCore> TryFrom<&IndexedSnbt<C>> for Nbt {
+    type Error = ();
+
+    fn try_from(snbt: &IndexedSnbt<C>) -> Result<Self, Self::Error> {
+        read_compound(snbt.root()).map(|root| Nbt::new(None, root))
+    }
+}
+
+impl<C: IndexCore> TryFrom<&IndexedSnbt<C>> for IndexedNbtCow<'static> {
+    type Error = ();
+
+    fn try_from(snbt: &IndexedSnbt<C>) -> Result<Self, Self::Error> {
+        let bytes = Nbt::try_from(snbt)?.to_unnamed_bytes();
+        IndexedNbtSlice::new_unnamed(&bytes).map(IndexedNbtSlice::into_owned)
+    }
+}
+
+impl TryFrom<&Nbt> for IndexedSnbtCow<'static> {
+    type Error = ();
+
+    fn try_from(nbt: &Nbt) -> Result<Self, Self::Error> {
+        let string = SnbtWriter::compact().to_string(nbt).map_err(|_| ())?;
+        IndexedSnbt::new_owned(string).map_err(|_| ())
+    }
+}
+
+impl<C: NbtIndexCore<Ref>> TryFrom<&IndexedNbt<C>> for IndexedSnbtCow<'static> {
+    type Error = ();
+
+    fn try_from(nbt: &IndexedNbt<C>) -> Result<Self, Self::Error> {
+        IndexedSnbtCow::try_from(&Nbt::try_from(nbt)?)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn read_compound<C: IndexCore>(compound: IndexedCompound<'_, C>) -> Result<NbtCompound, ()> {
+    compound
+        .into_iter()
+        .map(|entry| {
+            let (key, value) = entry.pair();
+            let key = unescape(key.get(), key.description().quotes())?;
+            Ok((MString::from(key.as_ref()), read_value(value)?))
+        })
+        .collect()
+}
+
+fn read_value<C: IndexCore>(value: ValueReference<'_, C>) -> Result<NbtValue, ()> {
+    Ok(match value {
+        ValueReference::Bool(value) => NbtValue::Byte(u8::from(value.get())),
+        ValueReference::Byte(value) => NbtValue::Byte(value.get()),
+        ValueReference::Short(value) => NbtValue::Short(value.get()),
+        ValueReference::Int(value) => NbtValue::Int(value.get()),
+        ValueReference::Long(value) => NbtValue::Long(value.get()),
+        ValueReference::Float(value) => NbtValue::Float(value.get()),
+        ValueReference::Double(value) => NbtValue::Double(value.get()),
+        ValueReference::String(value) => {
+            let string = unescape(value.get(), value.description().quotes())?;
+            NbtValue::String(MString::from(string.as_ref()))
+        }
+        ValueReference::List(list) => {
+            let values = list.into_iter().map(read_value).collect::<Result<Vec<_>, _>>()?;
+            NbtValue::List(NbtList::try_from(values).map_err(|_| ())?)
+        }
+        ValueReference::Compound(compound) => NbtValue::Compound(read_compound(compound)?),
+        ValueReference::ByteArray(array) => NbtValue::ByteArray(array.into_iter().collect()),
+        ValueReference::IntArray(array) => NbtValue::IntArray(array.into_iter().collect()),
+        ValueReference::LongArray(array) => NbtValue::LongArray(array.into_iter().collect()),
+    })
+}
+
+/// Remove the escape sequences from a quoted string.
+fn unescape(string: &str, quotes: StringQuotes) -> Result<Cow<'_, str>, ()> {
+    /// Read a hexadecimal character code of the given length.
+    fn read_hex(chars: &mut core::str::Chars<'_>, length: usize) -> Result<char, ()> {
+        let digits = chars.as_str().get(..length).ok_or(())?;
+        let code = u32::from_str_radix(digits, 16).map_err(|_| ())?;
+        chars.nth(length - 1);
+        char::from_u32(code).ok_or(())
+    }
+
+    if matches!(quotes, StringQuotes::None) || !string.contains('\\') {
+        return Ok(Cow::Borrowed(string));
+    }
+
+    let mut unescaped = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        unescaped.push(match chars.next().ok_or(())? {
+            c @ ('\\' | '\'' | '"') => c,
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            's' => ' ',
+            't' => '\t',
+            'x' => read_hex(&mut chars, 2)?,
+            'u' => read_hex(&mut chars, 4)?,
+            'U' => read_hex(&mut chars, 8)?,
+            _ => return Err(()),
+        });
+    }
+
+    Ok(Cow::Owned(unescaped))
+}
@@ -0,0 +1,237 @@
+//! Errors that can occur while parsing SNBT.
+//!
+//! Every [`SnbtError`] points at a byte range in the input,
+//! lists the tokens that would have been accepted there,
+//! and describes where in the structure the error occurred.
+
+use alloc::{string::String, vec::Vec};
+use core::{error::Error, fmt, range::Range};
+
+/// An error that occurred while parsing SNBT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnbtError {
+    kind: SnbtErrorKind,
+    span: Range<usize>,
+    expected: Vec<Expected>,
+    context: Vec<SnbtContext>,
+}
+
+/// The kind of [`SnbtError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnbtErrorKind {
+    /// An unexpected character was found.
+    UnexpectedChar(char),
+    /// The input ended unexpectedly.
+    UnexpectedEnd,
+    /// A quoted string was never closed.
+    UnterminatedString,
+    /// A closing bracket has no matching opening bracket.
+    UnmatchedBracket(char),
+    /// An opening bracket was never closed.
+    UnclosedBracket(char),
+    /// The input does not contain a compound.
+    MissingRoot,
+    /// A number is malformed or does not fit in its type.
+    InvalidNumber,
+    /// An array contains a value that does not fit in its type.
+    InvalidArrayElement,
+    /// The operation is not supported.
+    UnsupportedOperation,
+}
+
+/// A token that was expected when an [`SnbtError`] occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    /// A specific character.
+    Char(char),
+    /// A compound key.
+    Key,
+    /// Any value.
+    Value,
+    /// A numeric value.
+    Number,
+    /// An integer value.
+    Integer,
+}
+
+/// Where in the SNBT structure an [`SnbtError`] occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnbtContext {
+    /// Inside a compound, optionally at the given key.
+    Compound {
+        /// The key of the entry, if it was already read.
+        key: Option<String>,
+    },
+    /// Inside a list at the given index.
+    List {
+        /// The index of the element.
+        index: usize,
+    },
+    /// Inside a typed array at the given index.
+    Array {
+        /// The index of the element.
+        index: usize,
+    },
+}
+
+impl SnbtError {
+    /// Create a new [`SnbtError`] without any context.
+    #[must_use]
+    pub(crate) fn new(kind: SnbtErrorKind, span: Range<usize>, expected: &[Expected]) -> Self {
+        Self { kind, span, expected: expected.to_vec(), context: Vec::new() }
+    }
+
+    /// Get the [`SnbtErrorKind`] of this error.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> SnbtErrorKind { self.kind }
+
+    /// Get the byte range in the input where this error occurred.
+    #[inline]
+    #[must_use]
+    pub const fn span(&self) -> Range<usize> { self.span }
+
+    /// Get the tokens that would have been accepted instead.
+    #[inline]
+    #[must_use]
+    pub fn expected(&self) -> &[Expected] { &self.expected }
+
+    /// Get where in the SNBT structure this error occurred,
+    /// starting with the innermost compound or list.
+    #[inline]
+    #[must_use]
+    pub fn context(&self) -> &[SnbtContext] { &self.context }
+
+    /// Set the context of this error.
+    #[must_use]
+    pub(crate) fn with_context(mut self, context: Vec<SnbtContext>) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Shift the span of this error by `offset` bytes.
+    #[must_use]
+    pub(crate) const fn offset(mut self, offset: usize) -> Self {
+        self.span = Range { start: self.span.start + offset, end: self.span.end + offset };
+        self
+    }
+}
+
+impl Error for SnbtError {}
+impl fmt::Display for SnbtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.span.start)?;
+
+        if let Some((last, rest)) = self.expected.split_last() {
+            f.write_str(", expected ")?;
+            for (index, expected) in rest.iter().enumerate() {
+                if index != 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{expected}")?;
+            }
+            if !rest.is_empty() {
+                f.write_str(" or ")?;
+            }
+            write!(f, "{last}")?;
+        }
+
+        for context in &self.context {
+            write!(f, ", {context}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for SnbtErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedChar(c) => write!(f, "unexpected character {c:?}"),
+            Self::UnexpectedEnd => f.write_str("unexpected end of input"),
+            Self::UnterminatedString => f.write_str("unterminated string"),
+            Self::UnmatchedBracket(c) => write!(f, "unmatched closing bracket {c:?}"),
+            Self::UnclosedBracket(c) => write!(f, "unclosed bracket {c:?}"),
+            Self::MissingRoot => f.write_str("missing root compound"),
+            Self::InvalidNumber => f.write_str("invalid number"),
+            Self::InvalidArrayElement => f.write_str("invalid array element"),
+            Self::UnsupportedOperation => f.write_str("unsupported operation"),
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Char(c) => write!(f, "{c:?}"),
+            Self::Key => f.write_str("a key"),
+            Self::Value => f.write_str("a value"),
+            Self::Number => f.write_str("a number"),
+            Self::Integer => f.write_str("an integer"),
+        }
+    }
+}
+
+impl fmt::Display for SnbtContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Compound { key: Some(key) } => write!(f, "inside compound at key {key:?}"),
+            Self::Compound { key: None } => f.write_str("inside compound"),
+            Self::List { index } => write!(f, "inside list at index {index}"),
+            Self::Array { index } => write!(f, "inside array at index {index}"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "report")]
+impl SnbtError {
+    /// Create an [`ariadne::Report`] for this error.
+    ///
+    /// Spans are byte offsets into the parsed input.
+    #[must_use]
+    pub fn report(&self) -> ariadne::Report<'static, core::ops::Range<usize>> {
+        use core::fmt::Write;
+
+        use ariadne::{Config, IndexType, Label, Report, ReportKind};
+
+        let span = core::ops::Range::from(self.span);
+        let mut report = Report::build(ReportKind::Error, span.clone())
+            .with_config(Config::default().with_index_type(IndexType::Byte))
+            .with_message("failed to parse SNBT")
+            .with_label(Label::new(span).with_message(self.kind));
+
+        if !self.expected.is_empty() {
+            let mut help = String::from("expected ");
+            for (index, expected) in self.expected.iter().enumerate() {
+                let separator = if index == 0 { "" } else { ", " };
+                let _ = write!(help, "{separator}{expected}");
+            }
+            report = report.with_help(help);
+        }
+        for context in &self.context {
+            report = report.with_note(context);
+        }
+
+        report.finish()
+    }
+
+    /// Write a rendered report of this error for the given input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer fails.
+    pub fn write_report(&self, input: &str, writer: impl std::io::Write) -> std::io::Result<()> {
+        self.report().write(ariadne::Source::from(input), writer)
+    }
+
+    /// Print a rendered report of this error for the given input to `stderr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `stderr` fails.
+    pub fn eprint_report(&self, input: &str) -> std::io::Result<()> {
+        self.report().eprint(ariadne::Source::from(input))
+    }
+}
@@ -1,15 +1,17 @@
 //! TODO
-#![expect(clippy::result_unit_err, reason = "WIP")]
 
 use alloc::string::String;
 
 use ::core::{fmt, range::Range};
 
-use crate::types::indexed::core::SliceCore;
+use crate::{types::indexed::core::SliceCore, write::SnbtWriter};
 
 pub mod compound;
+#[cfg(feature = "nbt")]
+mod convert;
 pub mod core;
 pub mod entry;
+pub mod error;
 pub mod index;
 pub mod list;
 mod parse;
@@ -57,7 +59,9 @@ impl<'data> IndexedSnbt<core::SliceCore<'data>> {
     ///
     /// Returns an error if the string slice is not valid SNBT data.
     #[inline]
-    pub fn new_ref(string: &'data str) -> Result<Self, ()> { parse::parse_snbt(string) }
+    pub fn new_ref(string: &'data str) -> Result<Self, error::SnbtError> {
+        parse::parse_snbt(string)
+    }
 
     /// Take ownership of the SNBT data using a [`CowCore`](core::CowCore).
     #[inline]
@@ -73,7 +77,7 @@ impl<'data> IndexedSnbt<core::CowCore<'data>> {
     /// # Errors
     ///
     /// Returns an error if the string slice is not valid SNBT data.
-    pub fn new_owned_ref(string: &'data str) -> Result<Self, ()> {
+    pub fn new_owned_ref(string: &'data str) -> Result<Self, error::SnbtError> {
         let borrowed = parse::parse_snbt(string)?;
         Ok(IndexedSnbt::new(core::CowCore::from_slice(borrowed.core)))
     }
@@ -83,7 +87,9 @@ impl<'data> IndexedSnbt<core::CowCore<'data>> {
     /// # Errors
     ///
     /// Returns an error if the string slice is not valid SNBT data.
-    pub fn new_owned(string: String) -> Result<IndexedSnbt<core::CowCore<'static>>, ()> {
+    pub fn new_owned(
+        string: String,
+    ) -> Result<IndexedSnbt<core::CowCore<'static>>, error::SnbtError> {
         let borrowed = parse::parse_snbt(&string)?;
         let entries = borrowed.core.entries;
 
@@ -114,3 +120,12 @@ impl<C: core::IndexCore> fmt::Debug for IndexedSnbt<C> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&self.root(), f) }
 }
+
+/// Writes compact SNBT, or pretty-printed SNBT with the alternate flag
+/// (`{:#}`).
+impl<C: core::IndexCore> fmt::Display for IndexedSnbt<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let writer = if f.alternate() { SnbtWriter::pretty() } else { SnbtWriter::compact() };
+        writer.write(self, f)
+    }
+}
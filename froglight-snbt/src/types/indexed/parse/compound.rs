@@ -3,6 +3,7 @@ use core::range::Range;
 
 use crate::types::indexed::{
     entry::{EntryIndex, ValueIndex},
+    error::{Expected, SnbtError},
     index::Index,
     parse::{Cursor, value},
 };
@@ -14,7 +15,7 @@ pub(super) fn parse<'data>(
     strings: &[Range<usize>],
     entries: &mut Vec<EntryIndex>,
     queue: &mut VecDeque<(Cursor<'data>, usize, bool)>,
-) -> Result<ValueIndex, ()> {
+) -> Result<ValueIndex, SnbtError> {
     let start = entries.len();
     cursor.next_expect('{')?;
 
@@ -27,7 +28,8 @@ pub(super) fn parse<'data>(
 
     loop {
         // Name (String)
-        let name = value::parse_string::<false>(cursor.trim_start(), strings)?;
+        let name = value::parse_string::<false>(cursor.trim_start(), strings)
+            .map_err(|_| cursor.unexpected(&[Expected::Key]))?;
         let ValueIndex::String(name) = name else { unsafe { core::hint::unreachable_unchecked() } };
 
         // ':'
@@ -42,7 +44,7 @@ pub(super) fn parse<'data>(
         match cursor.trim_start().peek() {
             Some(',') => cursor.next(),
             Some('}') | None => break,
-            _ => return Err(()),
+            _ => return Err(cursor.unexpected(&[Expected::Char(','), Expected::Char('}')])),
         };
     }
 
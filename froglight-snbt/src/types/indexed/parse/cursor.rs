@@ -2,6 +2,8 @@
 
 use core::range::Range;
 
+use crate::types::indexed::error::{Expected, SnbtError, SnbtErrorKind};
+
 #[derive(Debug, Clone)]
 pub(super) struct Cursor<'data> {
     root: &'data str,
@@ -103,8 +105,30 @@ impl<'data> Cursor<'data> {
 
     /// Get the next character and check that it matches the expected character.
     #[inline]
-    pub(super) fn next_expect(&mut self, expected: char) -> Result<(), ()> {
-        if self.next() == Some(expected) { Ok(()) } else { Err(()) }
+    pub(super) fn next_expect(&mut self, expected: char) -> Result<(), SnbtError> {
+        if self.peek() == Some(expected) {
+            self.consume(expected.len_utf8());
+            Ok(())
+        } else {
+            Err(self.unexpected(&[Expected::Char(expected)]))
+        }
+    }
+
+    /// Create an error for the next character, which was not expected.
+    #[must_use]
+    pub(super) fn unexpected(&self, expected: &[Expected]) -> SnbtError {
+        match self.peek() {
+            Some(char) => self.error(SnbtErrorKind::UnexpectedChar(char), expected),
+            None => self.error(SnbtErrorKind::UnexpectedEnd, expected),
+        }
+    }
+
+    /// Create an error spanning the next character.
+    #[must_use]
+    pub(super) fn error(&self, kind: SnbtErrorKind, expected: &[Expected]) -> SnbtError {
+        let length = self.peek().map_or(0, char::len_utf8);
+        let span = Range { start: self.position, end: self.position + length };
+        SnbtError::new(kind, span, expected)
     }
 
     /// Advance the cursor until a closure return true,
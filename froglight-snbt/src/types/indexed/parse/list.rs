@@ -5,6 +5,7 @@ use core::range::Range;
 
 use crate::types::indexed::{
     entry::{EntryIndex, ValueIndex},
+    error::{Expected, SnbtError, SnbtErrorKind},
     index::{
         Index,
         bool::{BoolDescription, BooleanOperation},
@@ -20,7 +21,7 @@ pub(super) fn parse<'data>(
     strings: &[Range<usize>],
     entries: &mut Vec<EntryIndex>,
     queue: &mut VecDeque<(Cursor<'data>, usize, bool)>,
-) -> Result<ValueIndex, ()> {
+) -> Result<ValueIndex, SnbtError> {
     cursor.next_expect('[')?;
 
     let remaining = cursor.trim_start().remaining();
@@ -30,6 +31,12 @@ pub(super) fn parse<'data>(
 
     let start = entries.len();
 
+    // Empty list
+    if matches!(cursor.peek(), Some(']')) {
+        let range = Range { start, end: start };
+        return Ok(unsafe { ValueIndex::List(Index::new(range, ())) });
+    }
+
     loop {
         // Value
         let value =
@@ -43,7 +50,7 @@ pub(super) fn parse<'data>(
                 cursor.next_expect(',')?;
             }
             Some(']') => break,
-            _ => return Err(()),
+            _ => return Err(cursor.unexpected(&[Expected::Char(','), Expected::Char(']')])),
         }
     }
 
@@ -51,7 +58,10 @@ pub(super) fn parse<'data>(
     Ok(unsafe { ValueIndex::List(Index::new(range, ())) })
 }
 
-fn parse_array(cursor: &mut Cursor<'_>, entries: &mut Vec<EntryIndex>) -> Result<ValueIndex, ()> {
+fn parse_array(
+    cursor: &mut Cursor<'_>,
+    entries: &mut Vec<EntryIndex>,
+) -> Result<ValueIndex, SnbtError> {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     enum ListType {
         Byte,
@@ -60,40 +70,48 @@ fn parse_array(cursor: &mut Cursor<'_>, entries: &mut Vec<EntryIndex>) -> Result
     }
 
     let remaining = cursor.remaining();
-    let mut list_type = if remaining.starts_with("B;") {
+    let list_type = if remaining.starts_with("B;") {
         ListType::Byte
     } else if remaining.starts_with("I;") {
         ListType::Int
     } else if remaining.starts_with("L;") {
         ListType::Long
     } else {
-        return Err(());
+        return Err(cursor.unexpected(&[
+            Expected::Char('B'),
+            Expected::Char('I'),
+            Expected::Char('L'),
+        ]));
     };
     cursor.take_slice(2);
 
     let start = entries.len();
 
-    loop {
-        let value = value::parse_numeric(cursor.trim_start())?;
+    // Empty arrays have no values
+    if !matches!(cursor.trim_start().peek(), Some(']')) {
+        loop {
+            let position = cursor.trim_start().position();
+            let value = value::parse_numeric(cursor)?;
 
-        match list_type {
-            ListType::Byte if matches!(value, ValueIndex::Bool(..) | ValueIndex::Byte(..)) => {
-                let value = match value {
-                    ValueIndex::Bool(bool) => ValueIndex::Bool(bool),
-                    ValueIndex::Byte(byte) => ValueIndex::Byte(byte),
-                    _ => unsafe { core::hint::unreachable_unchecked() },
-                };
+            match list_type {
+                ListType::Byte if matches!(value, ValueIndex::Bool(..) | ValueIndex::Byte(..)) => {
+                    let value = match value {
+                        ValueIndex::Bool(bool) => ValueIndex::Bool(bool),
+                        ValueIndex::Byte(byte) => ValueIndex::Byte(byte),
+                        _ => unsafe { core::hint::unreachable_unchecked() },
+                    };
 
-                entries.push(EntryIndex::new(NULL_STRING, value));
-            }
-            ListType::Int => {
-                if matches!(
-                    value,
-                    ValueIndex::Bool(..)
-                        | ValueIndex::Byte(..)
-                        | ValueIndex::Short(..)
-                        | ValueIndex::Int(..)
-                ) {
+                    entries.push(EntryIndex::new(NULL_STRING, value));
+                }
+                ListType::Int
+                    if matches!(
+                        value,
+                        ValueIndex::Bool(..)
+                            | ValueIndex::Byte(..)
+                            | ValueIndex::Short(..)
+                            | ValueIndex::Int(..)
+                    ) =>
+                {
                     let value = match value {
                         ValueIndex::Bool(bool) => ValueIndex::Bool(bool),
                         ValueIndex::Byte(byte) => ValueIndex::Int(byte),
@@ -104,39 +122,43 @@ fn parse_array(cursor: &mut Cursor<'_>, entries: &mut Vec<EntryIndex>) -> Result
 
                     entries.push(EntryIndex::new(NULL_STRING, value));
                 }
+                ListType::Long
+                    if matches!(
+                        value,
+                        ValueIndex::Bool(..)
+                            | ValueIndex::Byte(..)
+                            | ValueIndex::Short(..)
+                            | ValueIndex::Int(..)
+                            | ValueIndex::Long(..)
+                    ) =>
+                {
+                    let value = match value {
+                        ValueIndex::Bool(bool) => ValueIndex::Bool(bool),
+                        ValueIndex::Byte(byte) => ValueIndex::Long(byte),
+                        ValueIndex::Short(short) => ValueIndex::Long(short),
+                        ValueIndex::Int(int) => ValueIndex::Long(int),
+                        ValueIndex::Long(long) => ValueIndex::Long(long),
+                        _ => unsafe { core::hint::unreachable_unchecked() },
+                    };
+
+                    // `Long`s can be any integer type.
+                    entries.push(EntryIndex::new(NULL_STRING, value));
+                }
+                _ => {
+                    let span = Range { start: position, end: cursor.position() };
+                    let kind = SnbtErrorKind::InvalidArrayElement;
+                    return Err(SnbtError::new(kind, span, &[Expected::Integer]));
+                }
             }
-            ListType::Long
-                if matches!(
-                    value,
-                    ValueIndex::Bool(..)
-                        | ValueIndex::Byte(..)
-                        | ValueIndex::Short(..)
-                        | ValueIndex::Int(..)
-                        | ValueIndex::Long(..)
-                ) =>
-            {
-                let value = match value {
-                    ValueIndex::Bool(bool) => ValueIndex::Bool(bool),
-                    ValueIndex::Byte(byte) => ValueIndex::Long(byte),
-                    ValueIndex::Short(short) => ValueIndex::Long(short),
-                    ValueIndex::Int(int) => ValueIndex::Long(int),
-                    ValueIndex::Long(long) => ValueIndex::Long(long),
-                    _ => unsafe { core::hint::unreachable_unchecked() },
-                };
-
-                // `Long`s can be any integer type.
-                entries.push(EntryIndex::new(NULL_STRING, value));
-            }
-            _ => return Err(()),
-        }
 
-        // ',' or ']'
-        match cursor.trim_start().peek() {
-            Some(',') => {
-                cursor.next_expect(',')?;
+            // ',' or ']'
+            match cursor.trim_start().peek() {
+                Some(',') => {
+                    cursor.next_expect(',')?;
+                }
+                Some(']') => break,
+                _ => return Err(cursor.unexpected(&[Expected::Char(','), Expected::Char(']')])),
             }
-            Some(']') => break,
-            _ => return Err(()),
         }
     }
 
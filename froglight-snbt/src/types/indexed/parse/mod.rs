@@ -1,7 +1,7 @@
 use alloc::{collections::VecDeque, string::String, vec::Vec};
 use core::range::Range;
 
-use memchr::{Memchr, Memchr2};
+use memchr::Memchr2;
 use smallvec::SmallVec;
 
 mod cursor;
@@ -15,22 +15,36 @@ use crate::types::indexed::{
     IndexedSnbt,
     core::SliceCore,
     entry::{EntryIndex, ValueIndex},
+    error::{Expected, SnbtContext, SnbtError, SnbtErrorKind},
     index::{
         Index,
         string::{StringDescription, StringQuotes},
     },
 };
 
-pub(super) fn parse_snbt(mut root: &str) -> Result<IndexedSnbt<SliceCore<'_>>, ()> {
-    let mut strings = string_ranges(root)?;
-    let mut compounds = create_ranges::<true>(b'{', b'}', root, &strings)?;
-    let mut lists = create_ranges::<false>(b'[', b']', root, &strings)?;
-    retain_ranges(&mut compounds, &mut lists, &mut strings);
+pub(super) fn parse_snbt(input: &str) -> Result<IndexedSnbt<SliceCore<'_>>, SnbtError> {
+    let mut strings = string_ranges(input)?;
+    let mut compounds = create_ranges::<true>(b'{', b'}', input, &strings)?;
+    let mut lists = create_ranges::<false>(b'[', b']', input, &strings)?;
+    let offset = retain_ranges(&mut compounds, &mut lists, &mut strings);
 
-    // Resize `root` to just the outer-most compound.
+    // Resize `root` to just the outer-most compound, including its closing brace.
     // SAFETY: `compounds` is guaranteed to have at least one element, the root.
-    root = unsafe { root.get_unchecked(*compounds.first().unwrap_unchecked()) };
+    let root =
+        unsafe { input.get_unchecked(offset..=offset + compounds.first().unwrap_unchecked().end) };
 
+    parse_entries(root, &compounds, &lists, &strings).map_err(|err| {
+        let position = err.span().start;
+        err.with_context(error_context(root, position, &compounds, &lists, &strings)).offset(offset)
+    })
+}
+
+fn parse_entries<'data>(
+    root: &'data str,
+    compounds: &[Range<usize>],
+    lists: &[Range<usize>],
+    strings: &[Range<usize>],
+) -> Result<IndexedSnbt<SliceCore<'data>>, SnbtError> {
     // Create a list of entries and a queue of cursors to process.
     let mut entries = Vec::with_capacity(4);
     let mut queue = VecDeque::<(Cursor<'_>, usize, bool)>::with_capacity(4);
@@ -41,9 +55,9 @@ pub(super) fn parse_snbt(mut root: &str) -> Result<IndexedSnbt<SliceCore<'_>>, (
 
     while let Some((cursor, callback, is_compound)) = queue.pop_back() {
         let index = if is_compound {
-            compound::parse(cursor, &compounds, &lists, &strings, &mut entries, &mut queue)?
+            compound::parse(cursor, compounds, lists, strings, &mut entries, &mut queue)?
         } else {
-            list::parse(cursor, &compounds, &lists, &strings, &mut entries, &mut queue)?
+            list::parse(cursor, compounds, lists, strings, &mut entries, &mut queue)?
         };
 
         // SAFETY: `callback` is guaranteed to be a valid index into `entries`.
@@ -90,68 +104,61 @@ fn retain_ranges(
     compounds: &mut Vec<Range<usize>>,
     lists: &mut Vec<Range<usize>>,
     strings: &mut Vec<Range<usize>>,
-) {
+) -> usize {
     // Remove any ranges not contained within the first compound.
     let first = unsafe { *compounds.first().unwrap_unchecked() };
 
     compounds.retain(|r| first.start <= r.start && r.end <= first.end);
     lists.retain(|r| first.start <= r.start && r.end <= first.end);
     strings.retain(|r| first.start <= r.start && r.end <= first.end);
+
+    // Make all ranges relative to the first compound.
+    for range in compounds.iter_mut().chain(lists.iter_mut()).chain(strings.iter_mut()) {
+        *range = Range { start: range.start - first.start, end: range.end - first.start };
+    }
+
+    first.start
 }
 
 #[inline(always)]
 #[expect(clippy::inline_always, reason = "Performance")]
-fn string_ranges(root: &str) -> Result<Vec<Range<usize>>, ()> {
+fn string_ranges(root: &str) -> Result<Vec<Range<usize>>, SnbtError> {
     let mut ranges = Vec::with_capacity(8);
-    let mut last = Option::<usize>::None;
-
-    for index in Memchr::new(b'\"', root.as_bytes()) {
-        // Check if the quote is escaped
-        if let Some(char) = index.checked_sub(1).and_then(|i| root.as_bytes().get(i))
-            && *char == b'\\'
-        {
-            continue;
-        }
-
-        if let Some(last) = last.take() {
-            // If we have a last index, this is the end of a string range
-            ranges.push(Range { start: last, end: index + 1 });
-        } else {
-            // Otherwise start a new string range
-            last = Some(index);
-        }
-    }
-
-    // TODO: Error type
-    if last.is_some() {
-        return Err(());
-    }
-
-    for index in Memchr::new(b'\'', root.as_bytes()) {
-        // Check if the quote is escaped
-        if let Some(char) = index.checked_sub(1).and_then(|i| root.as_bytes().get(i))
-            && *char == b'\\'
-        {
-            continue;
-        }
-
-        if let Some(last) = last.take() {
-            // If we have a last index, this is the end of a string range
-            ranges.push(Range { start: last, end: index + 1 });
-        } else {
+    let mut last = Option::<(usize, u8)>::None;
+
+    for index in Memchr2::new(b'\"', b'\'', root.as_bytes()) {
+        // SAFETY: `Memchr2` only returns valid indices.
+        let quote = unsafe { *root.as_bytes().get_unchecked(index) };
+
+        match last {
+            // Only the matching quote can end the current string range
+            Some((start, open)) if open == quote => {
+                // Check if the quote is escaped by an odd number of backslashes
+                let escapes = root.as_bytes()[start..index]
+                    .iter()
+                    .rev()
+                    .take_while(|&&byte| byte == b'\\')
+                    .count();
+                if escapes % 2 == 1 {
+                    continue;
+                }
+
+                ranges.push(Range { start, end: index + 1 });
+                last = None;
+            }
+            // Quotes of the other type are part of the string
+            Some(..) => {}
             // Otherwise start a new string range
-            last = Some(index);
+            None => last = Some((index, quote)),
         }
     }
 
-    // TODO: Error type
-    if last.is_some() {
-        return Err(());
+    if let Some((start, quote)) = last {
+        let span = Range { start, end: start + 1 };
+        let expected = [Expected::Char(char::from(quote))];
+        return Err(SnbtError::new(SnbtErrorKind::UnterminatedString, span, &expected));
     }
 
-    // Sort the ranges by their start index.
-    ranges.sort_unstable_by_key(|r| r.start);
-
     Ok(ranges)
 }
 
@@ -162,7 +169,7 @@ fn create_ranges<const REQUIRED: bool>(
     char_end: u8,
     root: &str,
     strings: &[Range<usize>],
-) -> Result<Vec<Range<usize>>, ()> {
+) -> Result<Vec<Range<usize>>, SnbtError> {
     let mut ranges = Vec::with_capacity(8);
     let mut queue = SmallVec::<[usize; 8]>::new_const();
 
@@ -187,19 +194,22 @@ fn create_ranges<const REQUIRED: bool>(
             // End the current compound range
             ranges.push(Range { start, end: index });
         } else {
-            // TODO: Error type
-            return Err(());
+            let span = Range { start: index, end: index + 1 };
+            let kind = SnbtErrorKind::UnmatchedBracket(char::from(char_end));
+            return Err(SnbtError::new(kind, span, &[]));
         }
     }
 
-    // TODO: Error type
-    if !queue.is_empty() {
-        return Err(());
+    if let Some(&start) = queue.last() {
+        let span = Range { start, end: start + 1 };
+        let kind = SnbtErrorKind::UnclosedBracket(char::from(char_start));
+        return Err(SnbtError::new(kind, span, &[Expected::Char(char::from(char_end))]));
     }
 
-    // TODO: Error type
     if REQUIRED && ranges.is_empty() {
-        return Err(());
+        let span = Range { start: 0, end: root.len() };
+        let expected = [Expected::Char(char::from(char_start))];
+        return Err(SnbtError::new(SnbtErrorKind::MissingRoot, span, &expected));
     }
 
     // Sort the ranges by their start index.
@@ -207,3 +217,73 @@ fn create_ranges<const REQUIRED: bool>(
 
     Ok(ranges)
 }
+
+// -------------------------------------------------------------------------------------------------
+
+/// Describe where in the structure `position` is, starting with the innermost
+/// compound or list.
+///
+/// Only called after an error, so this rescans the enclosing ranges instead of
+/// tracking state while parsing.
+fn error_context(
+    root: &str,
+    position: usize,
+    compounds: &[Range<usize>],
+    lists: &[Range<usize>],
+    strings: &[Range<usize>],
+) -> Vec<SnbtContext> {
+    let mut enclosing: Vec<(Range<usize>, bool)> = compounds
+        .iter()
+        .map(|range| (*range, true))
+        .chain(lists.iter().map(|range| (*range, false)))
+        .filter(|(range, _)| range.start < position && position <= range.end)
+        .collect();
+    enclosing.sort_unstable_by_key(|(range, _)| core::cmp::Reverse(range.start));
+
+    enclosing
+        .into_iter()
+        .map(|(range, is_compound)| {
+            let content = &root[range.start + 1..position.min(range.end)];
+
+            // Find the start of the last top-level element and its key separator,
+            // skipping over any nested values.
+            let (mut index, mut element, mut separator) = (0, 0, None);
+            let mut count = 0;
+            while let Some(&byte) = content.as_bytes().get(index) {
+                let absolute = range.start + 1 + index;
+                if let Some(nested) = compounds.iter().chain(lists).find(|r| r.start == absolute) {
+                    index += nested.end - nested.start + 1;
+                } else if let Some(string) = strings.iter().find(|r| r.start == absolute) {
+                    index += string.end - string.start;
+                } else {
+                    match byte {
+                        b',' => (element, separator, count) = (index + 1, None, count + 1),
+                        b':' if separator.is_none() => separator = Some(index),
+                        _ => {}
+                    }
+                    index += 1;
+                }
+            }
+
+            let trimmed = content.trim_start();
+            if is_compound {
+                let key = separator.map(|separator| {
+                    let key = content[element..separator].trim();
+                    let unquoted = key
+                        .strip_prefix(['"', '\''])
+                        .and_then(|key| key.strip_suffix(['"', '\'']))
+                        .unwrap_or(key);
+                    String::from(unquoted)
+                });
+                SnbtContext::Compound { key }
+            } else if trimmed.starts_with("B;")
+                || trimmed.starts_with("I;")
+                || trimmed.starts_with("L;")
+            {
+                SnbtContext::Array { index: count }
+            } else {
+                SnbtContext::List { index: count }
+            }
+        })
+        .collect()
+}
@@ -6,6 +6,7 @@ use core::range::Range;
 
 use crate::types::indexed::{
     entry::{EntryIndex, ValueIndex},
+    error::{Expected, SnbtError, SnbtErrorKind},
     index::{Index, bool::*, numeric::*, string::*},
     parse::{Cursor, PLACEHOLDER_COMPOUND, PLACEHOLDER_LIST},
 };
@@ -17,16 +18,18 @@ pub(super) fn parse_value<'root>(
     strings: &[Range<usize>],
     entries: &[EntryIndex],
     queue: &mut VecDeque<(Cursor<'root>, usize, bool)>,
-) -> Result<ValueIndex, ()> {
+) -> Result<ValueIndex, SnbtError> {
     // Handle operations
     let remaining = cursor.remaining();
     if remaining.starts_with("bool(") {
         return parse_bool(cursor).map(ValueIndex::Bool);
     } else if remaining.starts_with("uuid(") {
-        todo!();
+        // TODO: Support `uuid(...)`
+        let span = Range { start: cursor.position(), end: cursor.position() + 4 };
+        return Err(SnbtError::new(SnbtErrorKind::UnsupportedOperation, span, &[Expected::Value]));
     }
 
-    match cursor.peek().ok_or(())? {
+    match cursor.peek().ok_or_else(|| cursor.unexpected(&[Expected::Value]))? {
         // String
         'A'..='Z' | 'a'..='z' | '_' | '\"' | '\'' => parse_string::<true>(cursor, strings),
         // Numeric
@@ -71,13 +74,13 @@ pub(super) fn parse_value<'root>(
             Ok(PLACEHOLDER_COMPOUND)
         }
 
-        _ => Err(()),
+        _ => Err(cursor.unexpected(&[Expected::Value])),
     }
 }
 
 // -------------------------------------------------------------------------------------------------
 
-pub(super) fn parse_bool(cursor: &mut Cursor<'_>) -> Result<Index<bool>, ()> {
+pub(super) fn parse_bool(cursor: &mut Cursor<'_>) -> Result<Index<bool>, SnbtError> {
     let start = cursor.position();
     let remaining = cursor.remaining();
 
@@ -108,16 +111,16 @@ pub(super) fn parse_bool(cursor: &mut Cursor<'_>) -> Result<Index<bool>, ()> {
                 let desc = BoolDescription::Integer(value.description());
                 Ok(unsafe { Index::new(range, desc) })
             }
-            _ => Err(()),
+            _ => Err(invalid_bool(value)),
         }
     } else {
-        Err(())
+        Err(cursor.unexpected(&[Expected::Value]))
     }
 }
 
 // -------------------------------------------------------------------------------------------------
 
-pub(super) fn parse_numeric(cursor: &mut Cursor<'_>) -> Result<ValueIndex, ()> {
+pub(super) fn parse_numeric(cursor: &mut Cursor<'_>) -> Result<ValueIndex, SnbtError> {
     // Check for a boolean first.
     let remaining = cursor.remaining();
 
@@ -151,7 +154,7 @@ pub(super) fn parse_numeric(cursor: &mut Cursor<'_>) -> Result<ValueIndex, ()> {
                 let desc = BoolDescription::Integer(value.description());
                 return Ok(ValueIndex::Bool(unsafe { Index::new(range, desc) }));
             }
-            _ => return Err(()),
+            _ => return Err(invalid_bool(value)),
         }
     }
 
@@ -160,6 +163,9 @@ pub(super) fn parse_numeric(cursor: &mut Cursor<'_>) -> Result<ValueIndex, ()> {
     let slice = cursor.until_char::<false, false, _>(|c| {
         !matches!(c, '0'..='9' | '-' | '+' | '.' | '_' | 'b' | 'e' | 'x' | 'E')
     });
+    if slice.is_empty() {
+        return Err(cursor.unexpected(&[Expected::Number]));
+    }
 
     // Check for a signedness suffix if there is a type suffix after.
     let signedness =
@@ -167,19 +173,25 @@ pub(super) fn parse_numeric(cursor: &mut Cursor<'_>) -> Result<ValueIndex, ()> {
             match cursor.peek() {
                 Some('u') => {
                     cursor.next();
-                    Ok(IntegerSignness::Unsigned)
+                    IntegerSignness::Unsigned
                 }
                 Some('s') => {
                     cursor.next();
-                    Ok(IntegerSignness::Signed)
+                    IntegerSignness::Signed
                 }
 
-                Some(',') => Ok(IntegerSignness::None),
-                _ => Err(()),
+                // Otherwise the type suffix belongs to the next value.
+                _ => IntegerSignness::None,
             }
         } else {
-            Ok(IntegerSignness::None)
-        }?;
+            IntegerSignness::None
+        };
+
+    // Negative numbers without a signedness suffix are signed.
+    let signedness = match signedness {
+        IntegerSignness::None if slice.starts_with('-') => IntegerSignness::Signed,
+        other => other,
+    };
 
     // Check for a type suffix.
     match cursor.peek() {
@@ -219,7 +231,7 @@ pub(super) fn parse_integer(
     start: usize,
     mut ty: IntegerType,
     sign: IntegerSignness,
-) -> Result<ValueIndex, ()> {
+) -> Result<ValueIndex, SnbtError> {
     use lexical::parse_with_options as parse;
     const FALSE: BooleanOperation = BooleanOperation::False;
 
@@ -269,7 +281,7 @@ pub(super) fn parse_integer(
                     parse::<u8, &str, INTEGER_HEXADECIMAL_FORMAT>(slice, opt)
                 }
             }
-            .map_err(|_| ())?;
+            .map_err(|_| invalid_number(slice, start))?;
 
             Ok(unsafe {
                 ValueIndex::Byte(Index::new_from(
@@ -287,7 +299,7 @@ pub(super) fn parse_integer(
                     parse::<i8, &str, INTEGER_HEXADECIMAL_FORMAT>(slice, opt)
                 }
             }
-            .map_err(|_| ())?;
+            .map_err(|_| invalid_number(slice, start))?;
 
             Ok(unsafe {
                 ValueIndex::Byte(Index::new_from(
@@ -306,7 +318,7 @@ pub(super) fn parse_integer(
                     parse::<u16, &str, INTEGER_HEXADECIMAL_FORMAT>(slice, opt)
                 }
             }
-            .map_err(|_| ())?;
+            .map_err(|_| invalid_number(slice, start))?;
 
             Ok(unsafe {
                 ValueIndex::Short(Index::new_from(
@@ -324,7 +336,7 @@ pub(super) fn parse_integer(
                     parse::<i16, &str, INTEGER_HEXADECIMAL_FORMAT>(slice, opt)
                 }
             }
-            .map_err(|_| ())?;
+            .map_err(|_| invalid_number(slice, start))?;
 
             Ok(unsafe {
                 ValueIndex::Short(Index::new_from(
@@ -346,7 +358,7 @@ pub(super) fn parse_integer(
                     parse::<u32, &str, INTEGER_HEXADECIMAL_FORMAT>(slice, opt)
                 }
             }
-            .map_err(|_| ())?;
+            .map_err(|_| invalid_number(slice, start))?;
 
             Ok(unsafe {
                 ValueIndex::Int(Index::new_from(
@@ -364,7 +376,7 @@ pub(super) fn parse_integer(
                     parse::<i32, &str, INTEGER_HEXADECIMAL_FORMAT>(slice, opt)
                 }
             }
-            .map_err(|_| ())?;
+            .map_err(|_| invalid_number(slice, start))?;
 
             Ok(unsafe {
                 ValueIndex::Int(Index::new_from(
@@ -383,7 +395,7 @@ pub(super) fn parse_integer(
                     parse::<u64, &str, INTEGER_HEXADECIMAL_FORMAT>(slice, opt)
                 }
             }
-            .map_err(|_| ())?;
+            .map_err(|_| invalid_number(slice, start))?;
 
             Ok(unsafe {
                 ValueIndex::Long(Index::new_from(
@@ -401,7 +413,7 @@ pub(super) fn parse_integer(
                     parse::<i64, &str, INTEGER_HEXADECIMAL_FORMAT>(slice, opt)
                 }
             }
-            .map_err(|_| ())?;
+            .map_err(|_| invalid_number(slice, start))?;
 
             Ok(unsafe {
                 ValueIndex::Long(Index::new_from(
@@ -412,11 +424,15 @@ pub(super) fn parse_integer(
             })
         }
 
-        _ => Err(()),
+        _ => Err(invalid_number(slice, start)),
     }
 }
 
-pub(super) fn parse_float(slice: &str, start: usize, ty: FloatType) -> Result<ValueIndex, ()> {
+pub(super) fn parse_float(
+    slice: &str,
+    start: usize,
+    ty: FloatType,
+) -> Result<ValueIndex, SnbtError> {
     use lexical::parse_with_options as parse;
 
     let repr = if slice.contains(['e', 'E']) {
@@ -424,18 +440,20 @@ pub(super) fn parse_float(slice: &str, start: usize, ty: FloatType) -> Result<Va
     } else if slice.contains('.') {
         Ok(FloatRepresentation::Decimal)
     } else {
-        Err(())
+        Err(invalid_number(slice, start))
     }?;
 
     match ty {
         FloatType::Float => {
-            parse::<f32, &str, FLOAT_FORMAT>(slice, &FLOAT_OPTIONS).map_err(|_| ())?;
+            parse::<f32, &str, FLOAT_FORMAT>(slice, &FLOAT_OPTIONS)
+                .map_err(|_| invalid_number(slice, start))?;
 
             let desc = FloatDescription::new(repr, ty);
             Ok(unsafe { ValueIndex::Float(Index::new_from(slice, start, desc)) })
         }
         FloatType::Double => {
-            parse::<f64, &str, FLOAT_FORMAT>(slice, &FLOAT_OPTIONS).map_err(|_| ())?;
+            parse::<f64, &str, FLOAT_FORMAT>(slice, &FLOAT_OPTIONS)
+                .map_err(|_| invalid_number(slice, start))?;
 
             let desc = FloatDescription::new(repr, ty);
             Ok(unsafe { ValueIndex::Double(Index::new_from(slice, start, desc)) })
@@ -443,6 +461,18 @@ pub(super) fn parse_float(slice: &str, start: usize, ty: FloatType) -> Result<Va
     }
 }
 
+/// Create an error for a number that could not be parsed.
+fn invalid_number(slice: &str, start: usize) -> SnbtError {
+    let span = Range { start, end: start + slice.len() };
+    SnbtError::new(SnbtErrorKind::InvalidNumber, span, &[])
+}
+
+/// Create an error for a `bool(...)` operation that does not contain an
+/// integer.
+fn invalid_bool(value: ValueIndex) -> SnbtError {
+    SnbtError::new(SnbtErrorKind::InvalidNumber, value.range(), &[Expected::Integer])
+}
+
 // -------------------------------------------------------------------------------------------------
 
 /// # Generics
@@ -454,9 +484,9 @@ pub(super) fn parse_float(slice: &str, start: usize, ty: FloatType) -> Result<Va
 pub(super) fn parse_string<const BOOLEANS: bool>(
     cursor: &mut Cursor<'_>,
     strings: &[Range<usize>],
-) -> Result<ValueIndex, ()> {
+) -> Result<ValueIndex, SnbtError> {
     let start = cursor.position();
-    match cursor.peek().ok_or(())? {
+    match cursor.peek().ok_or_else(|| cursor.unexpected(&[Expected::Value]))? {
         // Unquoted string
         'A'..='Z' | '_' | 'a'..='z' => {
             let slice = cursor.until_char::<false, false, _>(
@@ -488,6 +518,6 @@ pub(super) fn parse_string<const BOOLEANS: bool>(
             let quotes = if c == '\"' { StringQuotes::Double } else { StringQuotes::Single };
             Ok(unsafe { ValueIndex::String(Index::new(range, StringDescription::new(quotes))) })
         }
-        _ => Err(()),
+        _ => Err(cursor.unexpected(&[Expected::Value])),
     }
 }
@@ -0,0 +1,93 @@
+//! Writing [`IndexedSnbt`] as SNBT.
+//!
+//! Strings keep their original quotes and escapes.
+
+use core::fmt::{self, Write};
+
+use super::{SnbtWriter, WriteSnbt, Writer};
+use crate::types::indexed::{
+    IndexedSnbt, compound::IndexedCompound, core::IndexCore, index::bool::BoolDescription,
+    list::IndexedList, reference::ValueReference,
+};
+
+impl<C: IndexCore> WriteSnbt for IndexedSnbt<C> {
+    fn write_snbt(&self, writer: &SnbtWriter, output: &mut dyn Write) -> fmt::Result {
+        write_compound(&mut Writer::new(writer, output), self.root())
+    }
+}
+
+impl<C: IndexCore> WriteSnbt for IndexedCompound<'_, C> {
+    fn write_snbt(&self, writer: &SnbtWriter, output: &mut dyn Write) -> fmt::Result {
+        write_compound(&mut Writer::new(writer, output), *self)
+    }
+}
+
+impl<C: IndexCore> WriteSnbt for ValueReference<'_, C> {
+    fn write_snbt(&self, writer: &SnbtWriter, output: &mut dyn Write) -> fmt::Result {
+        write_value(&mut Writer::new(writer, output), *self)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn write_compound<C: IndexCore>(
+    writer: &mut Writer<'_>,
+    compound: IndexedCompound<'_, C>,
+) -> fmt::Result {
+    writer.open("{")?;
+    for (index, entry) in compound.into_iter().enumerate() {
+        writer.element(index, true)?;
+
+        let (key, value) = entry.pair();
+        writer.raw_key(key.get(), key.description().quotes())?;
+        write_value(writer, value)?;
+    }
+    writer.close('}', compound.len(), true)
+}
+
+fn write_list<C: IndexCore>(
+    writer: &mut Writer<'_>,
+    prefix: &str,
+    list: IndexedList<'_, C>,
+) -> fmt::Result {
+    // Only place elements on separate lines if they contain other elements.
+    let multiline = list
+        .into_iter()
+        .any(|value| matches!(value, ValueReference::List(..) | ValueReference::Compound(..)));
+
+    writer.open(prefix)?;
+    for (index, value) in list.into_iter().enumerate() {
+        writer.element(index, multiline)?;
+        write_value(writer, value)?;
+    }
+    writer.close(']', list.len(), multiline)
+}
+
+fn write_value<C: IndexCore>(writer: &mut Writer<'_>, value: ValueReference<'_, C>) -> fmt::Result {
+    match value {
+        ValueReference::Bool(value) => match value.description() {
+            BoolDescription::Boolean => {
+                writer.output().write_str(if value.get() { "true" } else { "false" })
+            }
+            BoolDescription::Integer(description) => {
+                writer.output().write_str("bool(")?;
+                writer.integer(u8::from(value.get()).into(), description)?;
+                writer.output().write_char(')')
+            }
+        },
+        ValueReference::Byte(value) => writer.integer(value.get().into(), value.description()),
+        ValueReference::Short(value) => writer.integer(value.get().into(), value.description()),
+        ValueReference::Int(value) => writer.integer(value.get().into(), value.description()),
+        ValueReference::Long(value) => writer.integer(value.get().into(), value.description()),
+        ValueReference::Float(value) => writer.float(value.get().into()),
+        ValueReference::Double(value) => writer.float(value.get().into()),
+        ValueReference::String(value) => {
+            writer.raw_string(value.get(), value.description().quotes())
+        }
+        ValueReference::List(list) => write_list(writer, "[", list),
+        ValueReference::Compound(compound) => write_compound(writer, compound),
+        ValueReference::ByteArray(array) => write_list(writer, "[B;", array.into_list()),
+        ValueReference::IntArray(array) => write_list(writer, "[I;", array.into_list()),
+        ValueReference::LongArray(array) => write_list(writer, "[L;", array.into_list()),
+    }
+}
@@ -0,0 +1,336 @@
+//! Writing SNBT with configurable formatting.
+//!
+//! ```rust
+//! use froglight_snbt::{prelude::*, write::SnbtWriter};
+//!
+//! let snbt = IndexedSnbtSlice::new_ref("{ id: 'minecraft:stone', Count: 1b }").unwrap();
+//!
+//! let compact = SnbtWriter::compact().to_string(&snbt).unwrap();
+//! assert_eq!(compact, "{id:'minecraft:stone',Count:1b}");
+//!
+//! let pretty = SnbtWriter::pretty().with_indent(2).to_string(&snbt).unwrap();
+//! assert_eq!(pretty, "{\n  id: 'minecraft:stone',\n  Count: 1b\n}");
+//! ```
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+use crate::types::indexed::index::{
+    numeric::{FloatValue, IntegerDescription, IntegerSignness, IntegerType, IntegerValue},
+    string::StringQuotes,
+};
+
+mod indexed;
+#[cfg(feature = "nbt")]
+mod nbt;
+
+/// A writer for SNBT.
+///
+/// Integers are always written in decimal,
+/// but otherwise keep their type and signedness suffixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SnbtWriter {
+    indent: Option<usize>,
+    keys: KeyQuoting,
+    floats: FloatFormat,
+}
+
+/// When compound keys should be quoted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyQuoting {
+    /// Only quote keys that cannot be written unquoted.
+    #[default]
+    WhenRequired,
+    /// Always quote keys.
+    Always,
+}
+
+/// How floating-point values are written.
+///
+/// Non-finite values cannot be represented in SNBT,
+/// and writing them returns an error.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FloatFormat {
+    /// The shortest representation that reads back as the same value.
+    #[default]
+    Shortest,
+    /// A fixed number of digits after the decimal point.
+    Precision(usize),
+    /// Scientific notation, e.g. `1.5e3`.
+    Scientific,
+}
+
+/// A trait for types that can be written as SNBT.
+pub trait WriteSnbt {
+    /// Write this value as SNBT using the given [`SnbtWriter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output could not be written to,
+    /// or if the value cannot be represented as SNBT.
+    fn write_snbt(&self, writer: &SnbtWriter, output: &mut dyn Write) -> fmt::Result;
+}
+
+impl SnbtWriter {
+    /// The default indentation used by [`SnbtWriter::pretty`].
+    pub const DEFAULT_INDENT: usize = 4;
+
+    /// Create a new [`SnbtWriter`] that writes everything on a single line.
+    #[must_use]
+    pub const fn compact() -> Self {
+        Self { indent: None, keys: KeyQuoting::WhenRequired, floats: FloatFormat::Shortest }
+    }
+
+    /// Create a new [`SnbtWriter`] that writes entries on separate, indented
+    /// lines.
+    #[must_use]
+    pub const fn pretty() -> Self { Self::compact().with_indent(Self::DEFAULT_INDENT) }
+
+    /// Pretty-print using the given number of spaces per level.
+    #[must_use]
+    pub const fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Set when compound keys should be quoted.
+    #[must_use]
+    pub const fn with_key_quoting(mut self, keys: KeyQuoting) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Set how floating-point values are written.
+    #[must_use]
+    pub const fn with_float_format(mut self, floats: FloatFormat) -> Self {
+        self.floats = floats;
+        self
+    }
+
+    /// Returns `true` if this writer pretty-prints.
+    #[inline]
+    #[must_use]
+    pub const fn is_pretty(&self) -> bool { self.indent.is_some() }
+
+    /// Write a value as SNBT.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output could not be written to,
+    /// or if the value cannot be represented as SNBT.
+    #[inline]
+    pub fn write<T: WriteSnbt + ?Sized>(&self, value: &T, output: &mut dyn Write) -> fmt::Result {
+        value.write_snbt(self, output)
+    }
+
+    /// Write a value as an SNBT [`String`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be represented as SNBT,
+    /// such as a non-finite floating-point value.
+    pub fn to_string<T: WriteSnbt + ?Sized>(&self, value: &T) -> Result<String, fmt::Error> {
+        let mut string = String::new();
+        self.write(value, &mut string)?;
+        Ok(string)
+    }
+}
+
+impl Default for SnbtWriter {
+    #[inline]
+    fn default() -> Self { Self::compact() }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The state of an [`SnbtWriter`] while writing.
+pub(crate) struct Writer<'a> {
+    options: &'a SnbtWriter,
+    output: &'a mut dyn Write,
+    depth: usize,
+}
+
+impl<'a> Writer<'a> {
+    /// Create a new [`Writer`] with the given options and output.
+    pub(crate) const fn new(options: &'a SnbtWriter, output: &'a mut dyn Write) -> Self {
+        Self { options, output, depth: 0 }
+    }
+
+    /// Get the output of this writer.
+    pub(crate) fn output(&mut self) -> &mut dyn Write { self.output }
+
+    /// Open a compound, list, or array.
+    pub(crate) fn open(&mut self, prefix: &str) -> fmt::Result {
+        self.depth += 1;
+        self.output.write_str(prefix)
+    }
+
+    /// Prepare to write the element at `index`.
+    ///
+    /// Multiline elements are placed on their own indented line.
+    pub(crate) fn element(&mut self, index: usize, multiline: bool) -> fmt::Result {
+        if index != 0 {
+            self.output.write_char(',')?;
+        }
+        if multiline && self.options.is_pretty() {
+            self.newline()
+        } else if index != 0 && self.options.is_pretty() {
+            self.output.write_char(' ')
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Close a compound, list, or array with `len` elements.
+    pub(crate) fn close(&mut self, suffix: char, len: usize, multiline: bool) -> fmt::Result {
+        self.depth -= 1;
+        if multiline && len != 0 && self.options.is_pretty() {
+            self.newline()?;
+        }
+        self.output.write_char(suffix)
+    }
+
+    /// Write a newline and indentation.
+    fn newline(&mut self) -> fmt::Result {
+        self.output.write_char('\n')?;
+        let indent = self.options.indent.unwrap_or_default() * self.depth;
+        (0..indent).try_for_each(|_| self.output.write_char(' '))
+    }
+
+    /// Write a compound key and the separator following it.
+    #[cfg(feature = "nbt")]
+    pub(crate) fn key(&mut self, key: &str) -> fmt::Result {
+        if matches!(self.options.keys, KeyQuoting::WhenRequired) && is_unquoted_key(key) {
+            self.output.write_str(key)?;
+        } else {
+            write_quoted(self.output, key)?;
+        }
+        self.separator()
+    }
+
+    /// Write a compound key that is already escaped for the given quotes,
+    /// and the separator following it.
+    pub(crate) fn raw_key(&mut self, key: &str, quotes: StringQuotes) -> fmt::Result {
+        match (self.options.keys, quotes) {
+            (KeyQuoting::WhenRequired, _) if is_unquoted_key(key) => self.output.write_str(key),
+            // Unquoted keys never need to be escaped.
+            (_, StringQuotes::None) => self.raw_string(key, StringQuotes::Double),
+            (_, quotes) => self.raw_string(key, quotes),
+        }?;
+        self.separator()
+    }
+
+    /// Write the separator between a key and its value.
+    fn separator(&mut self) -> fmt::Result {
+        self.output.write_str(if self.options.is_pretty() { ": " } else { ":" })
+    }
+
+    /// Write a string that is already escaped for the given quotes.
+    pub(crate) fn raw_string(&mut self, string: &str, quotes: StringQuotes) -> fmt::Result {
+        let quote = match quotes {
+            StringQuotes::None => return self.output.write_str(string),
+            StringQuotes::Single => '\'',
+            StringQuotes::Double => '"',
+        };
+
+        self.output.write_char(quote)?;
+        self.output.write_str(string)?;
+        self.output.write_char(quote)
+    }
+
+    /// Write an integer with the suffixes described by `description`.
+    pub(crate) fn integer(
+        &mut self,
+        value: IntegerValue,
+        description: IntegerDescription,
+    ) -> fmt::Result {
+        let signed = matches!(description.signness(), IntegerSignness::Signed);
+        match (value, signed) {
+            (IntegerValue::Byte(value), false) => write!(self.output, "{value}"),
+            (IntegerValue::Byte(value), true) => write!(self.output, "{}", value.cast_signed()),
+            (IntegerValue::Short(value), false) => write!(self.output, "{value}"),
+            (IntegerValue::Short(value), true) => write!(self.output, "{}", value.cast_signed()),
+            (IntegerValue::Int(value), false) => write!(self.output, "{value}"),
+            (IntegerValue::Int(value), true) => write!(self.output, "{}", value.cast_signed()),
+            (IntegerValue::Long(value), false) => write!(self.output, "{value}"),
+            (IntegerValue::Long(value), true) => write!(self.output, "{}", value.cast_signed()),
+        }?;
+
+        let suffix = match description.ty() {
+            IntegerType::None | IntegerType::Bool => return Ok(()),
+            IntegerType::Byte => 'b',
+            IntegerType::Short => 's',
+            IntegerType::Int => 'i',
+            IntegerType::Long => 'l',
+        };
+        match description.signness() {
+            IntegerSignness::None => {}
+            IntegerSignness::Signed => self.output.write_char('s')?,
+            IntegerSignness::Unsigned => self.output.write_char('u')?,
+        }
+        self.output.write_char(suffix)
+    }
+
+    /// Write a floating-point value with its type suffix.
+    ///
+    /// Returns an error if the value is not finite,
+    /// as `NaN` and infinities cannot be represented in SNBT.
+    pub(crate) fn float(&mut self, value: FloatValue) -> fmt::Result {
+        let finite = match value {
+            FloatValue::Float(v) => v.is_finite(),
+            FloatValue::Double(v) => v.is_finite(),
+        };
+        if !finite {
+            return Err(fmt::Error);
+        }
+
+        let (mut string, suffix) = match (value, self.options.floats) {
+            (FloatValue::Float(v), FloatFormat::Shortest) => (alloc::format!("{v:?}"), 'f'),
+            (FloatValue::Float(v), FloatFormat::Precision(p)) => (alloc::format!("{v:.p$}"), 'f'),
+            (FloatValue::Float(v), FloatFormat::Scientific) => (alloc::format!("{v:e}"), 'f'),
+            (FloatValue::Double(v), FloatFormat::Shortest) => (alloc::format!("{v:?}"), 'd'),
+            (FloatValue::Double(v), FloatFormat::Precision(p)) => (alloc::format!("{v:.p$}"), 'd'),
+            (FloatValue::Double(v), FloatFormat::Scientific) => (alloc::format!("{v:e}"), 'd'),
+        };
+
+        // Floats must contain a decimal point or an exponent.
+        if !string.contains(['.', 'e']) {
+            string.push_str(".0");
+        }
+
+        self.output.write_str(&string)?;
+        self.output.write_char(suffix)
+    }
+}
+
+/// Returns `true` if the key can be written without quotes.
+fn is_unquoted_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+'))
+}
+
+/// Quote and escape a string.
+///
+/// Double quotes are preferred,
+/// unless the string contains double quotes and no single quotes.
+#[cfg(feature = "nbt")]
+pub(crate) fn write_quoted(output: &mut dyn Write, string: &str) -> fmt::Result {
+    let quote = if string.contains('"') && !string.contains('\'') { '\'' } else { '"' };
+
+    output.write_char(quote)?;
+    for c in string.chars() {
+        match c {
+            '\\' => output.write_str("\\\\"),
+            '\n' => output.write_str("\\n"),
+            '\r' => output.write_str("\\r"),
+            '\t' => output.write_str("\\t"),
+            c if c == quote => {
+                output.write_char('\\')?;
+                output.write_char(c)
+            }
+            c => output.write_char(c),
+        }?;
+    }
+    output.write_char(quote)
+}
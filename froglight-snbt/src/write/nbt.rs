@@ -0,0 +1,169 @@
+//! Writing [`Nbt`] as SNBT.
+//!
+//! Integers are written as signed values,
+//! with an explicit signedness suffix if they are negative.
+
+use core::fmt::{self, Write};
+
+use froglight_nbt::types::{
+    indexed::{
+        IndexedNbt,
+        core::{IndexCore, Ref},
+    },
+    structured::{Nbt, NbtCompound, NbtList, NbtValue},
+};
+
+use super::{SnbtWriter, WriteSnbt, Writer, write_quoted};
+use crate::types::indexed::index::{
+    bool::BooleanOperation,
+    numeric::{IntegerDescription, IntegerRadix, IntegerSignness, IntegerType, IntegerValue},
+};
+
+impl WriteSnbt for Nbt {
+    fn write_snbt(&self, writer: &SnbtWriter, output: &mut dyn Write) -> fmt::Result {
+        write_compound(&mut Writer::new(writer, output), self.compound())
+    }
+}
+
+impl WriteSnbt for NbtCompound {
+    fn write_snbt(&self, writer: &SnbtWriter, output: &mut dyn Write) -> fmt::Result {
+        write_compound(&mut Writer::new(writer, output), self)
+    }
+}
+
+impl WriteSnbt for NbtValue {
+    fn write_snbt(&self, writer: &SnbtWriter, output: &mut dyn Write) -> fmt::Result {
+        write_value(&mut Writer::new(writer, output), self)
+    }
+}
+
+impl<C: IndexCore<Ref>> WriteSnbt for IndexedNbt<C> {
+    fn write_snbt(&self, writer: &SnbtWriter, output: &mut dyn Write) -> fmt::Result {
+        let nbt = Nbt::try_from(self).map_err(|()| fmt::Error)?;
+        nbt.write_snbt(writer, output)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+fn write_compound(writer: &mut Writer<'_>, compound: &NbtCompound) -> fmt::Result {
+    writer.open("{")?;
+    for (index, (key, value)) in compound.iter().enumerate() {
+        writer.element(index, true)?;
+        writer.key(&key.to_utf8())?;
+        write_value(writer, value)?;
+    }
+    writer.close('}', compound.len(), true)
+}
+
+fn write_value(writer: &mut Writer<'_>, value: &NbtValue) -> fmt::Result {
+    match value {
+        NbtValue::Byte(value) => write_integer(writer, (*value).into(), IntegerType::Byte),
+        NbtValue::Short(value) => write_integer(writer, (*value).into(), IntegerType::Short),
+        NbtValue::Int(value) => write_integer(writer, (*value).into(), IntegerType::None),
+        NbtValue::Long(value) => write_integer(writer, (*value).into(), IntegerType::Long),
+        NbtValue::Float(value) => writer.float((*value).into()),
+        NbtValue::Double(value) => writer.float((*value).into()),
+        NbtValue::ByteArray(array) => write_array(writer, "[B;", array, IntegerType::Byte),
+        NbtValue::String(string) => write_quoted(writer.output(), &string.to_utf8()),
+        NbtValue::List(list) => write_list(writer, list),
+        NbtValue::Compound(compound) => write_compound(writer, compound),
+        NbtValue::IntArray(array) => write_array(writer, "[I;", array, IntegerType::None),
+        NbtValue::LongArray(array) => write_array(writer, "[L;", array, IntegerType::Long),
+    }
+}
+
+fn write_list(writer: &mut Writer<'_>, list: &NbtList) -> fmt::Result {
+    /// Write each element of a list using the given function.
+    fn elements<T>(
+        writer: &mut Writer<'_>,
+        values: &[T],
+        multiline: bool,
+        mut write: impl FnMut(&mut Writer<'_>, &T) -> fmt::Result,
+    ) -> fmt::Result {
+        values.iter().enumerate().try_for_each(|(index, value)| {
+            writer.element(index, multiline)?;
+            write(writer, value)
+        })
+    }
+
+    // Only place elements on separate lines if they contain other elements.
+    let multiline = matches!(list, NbtList::List(..) | NbtList::Compound(..));
+
+    writer.open("[")?;
+    match list {
+        NbtList::Empty => Ok(()),
+        NbtList::Byte(values) => elements(writer, values, multiline, |writer, value| {
+            write_integer(writer, (*value).into(), IntegerType::Byte)
+        }),
+        NbtList::Short(values) => elements(writer, values, multiline, |writer, value| {
+            write_integer(writer, (*value).into(), IntegerType::Short)
+        }),
+        NbtList::Int(values) => elements(writer, values, multiline, |writer, value| {
+            write_integer(writer, (*value).into(), IntegerType::None)
+        }),
+        NbtList::Long(values) => elements(writer, values, multiline, |writer, value| {
+            write_integer(writer, (*value).into(), IntegerType::Long)
+        }),
+        NbtList::Float(values) => {
+            elements(writer, values, multiline, |writer, value| writer.float((*value).into()))
+        }
+        NbtList::Double(values) => {
+            elements(writer, values, multiline, |writer, value| writer.float((*value).into()))
+        }
+        NbtList::ByteArray(values) => elements(writer, values, multiline, |writer, array| {
+            write_array(writer, "[B;", array, IntegerType::Byte)
+        }),
+        NbtList::String(values) => elements(writer, values, multiline, |writer, string| {
+            write_quoted(writer.output(), &string.to_utf8())
+        }),
+        NbtList::List(values) => elements(writer, values, multiline, write_list),
+        NbtList::Compound(values) => elements(writer, values, multiline, write_compound),
+        NbtList::IntArray(values) => elements(writer, values, multiline, |writer, array| {
+            write_array(writer, "[I;", array, IntegerType::None)
+        }),
+        NbtList::LongArray(values) => elements(writer, values, multiline, |writer, array| {
+            write_array(writer, "[L;", array, IntegerType::Long)
+        }),
+    }?;
+    writer.close(']', list.len(), multiline)
+}
+
+fn write_array<T: Copy + Into<IntegerValue>>(
+    writer: &mut Writer<'_>,
+    prefix: &str,
+    array: &[T],
+    ty: IntegerType,
+) -> fmt::Result {
+    writer.open(prefix)?;
+    for (index, value) in array.iter().enumerate() {
+        writer.element(index, false)?;
+        write_integer(writer, (*value).into(), ty)?;
+    }
+    writer.close(']', array.len(), false)
+}
+
+/// Write an integer as a signed value.
+///
+/// Negative values require both a type and signedness suffix to be read back.
+fn write_integer(writer: &mut Writer<'_>, value: IntegerValue, ty: IntegerType) -> fmt::Result {
+    let negative = match value {
+        IntegerValue::Byte(value) => value.cast_signed() < 0,
+        IntegerValue::Short(value) => value.cast_signed() < 0,
+        IntegerValue::Int(value) => value.cast_signed() < 0,
+        IntegerValue::Long(value) => value.cast_signed() < 0,
+    };
+
+    let (ty, signness) = if negative {
+        (
+            if matches!(ty, IntegerType::None) { IntegerType::Int } else { ty },
+            IntegerSignness::Signed,
+        )
+    } else {
+        (ty, IntegerSignness::None)
+    };
+
+    let description =
+        IntegerDescription::new(IntegerRadix::Decimal, ty, signness, BooleanOperation::False);
+    writer.integer(value, description)
+}
@@ -0,0 +1,119 @@
+//! TODO
+
+use froglight_snbt::{
+    prelude::*,
+    types::indexed::error::{Expected, SnbtContext, SnbtError, SnbtErrorKind},
+};
+
+fn error(snbt: &str) -> SnbtError { IndexedSnbtSlice::new_ref(snbt).unwrap_err() }
+
+fn span(error: &SnbtError) -> core::ops::Range<usize> { error.span().into() }
+
+#[test]
+fn prescan() {
+    let err = error("{a:'b}");
+    assert_eq!(err.kind(), SnbtErrorKind::UnterminatedString);
+    assert_eq!(span(&err), 3..4);
+    assert_eq!(err.expected(), [Expected::Char('\'')]);
+
+    let err = error("{a:[1,2}");
+    assert_eq!(err.kind(), SnbtErrorKind::UnclosedBracket('['));
+    assert_eq!(span(&err), 3..4);
+
+    let err = error("{a:1]}");
+    assert_eq!(err.kind(), SnbtErrorKind::UnmatchedBracket(']'));
+    assert_eq!(span(&err), 4..5);
+
+    let err = error("a: 1");
+    assert_eq!(err.kind(), SnbtErrorKind::MissingRoot);
+    assert_eq!(err.expected(), [Expected::Char('{')]);
+}
+
+#[test]
+fn expected() {
+    let err = error("{a:1 b:2}");
+    assert_eq!(err.kind(), SnbtErrorKind::UnexpectedChar('b'));
+    assert_eq!(span(&err), 5..6);
+    assert_eq!(err.expected(), [Expected::Char(','), Expected::Char('}')]);
+
+    let err = error("{a 1}");
+    assert_eq!(err.kind(), SnbtErrorKind::UnexpectedChar('1'));
+    assert_eq!(err.expected(), [Expected::Char(':')]);
+
+    let err = error("{a:}");
+    assert_eq!(err.kind(), SnbtErrorKind::UnexpectedChar('}'));
+    assert_eq!(err.expected(), [Expected::Value]);
+
+    let err = error("{a:1,:2}");
+    assert_eq!(err.kind(), SnbtErrorKind::UnexpectedChar(':'));
+    assert_eq!(err.expected(), [Expected::Key]);
+
+    let err = error("{a:[1 2]}");
+    assert_eq!(err.expected(), [Expected::Char(','), Expected::Char(']')]);
+}
+
+#[test]
+fn numbers() {
+    let err = error("{a:300b}");
+    assert_eq!(err.kind(), SnbtErrorKind::InvalidNumber);
+    assert_eq!(span(&err), 3..6);
+
+    let err = error("{a:[B;1b,2L]}");
+    assert_eq!(err.kind(), SnbtErrorKind::InvalidArrayElement);
+    assert_eq!(span(&err), 9..11);
+    assert_eq!(err.context()[0], SnbtContext::Array { index: 1 });
+
+    let err = error("{a:[I;1,2L]}");
+    assert_eq!(err.kind(), SnbtErrorKind::InvalidArrayElement);
+}
+
+#[test]
+fn context() {
+    let err = error("{list:[0,1,2,{x:1,y:}]}");
+    assert_eq!(err.kind(), SnbtErrorKind::UnexpectedChar('}'));
+    assert_eq!(
+        err.context(),
+        [
+            SnbtContext::Compound { key: Some(String::from("y")) },
+            SnbtContext::List { index: 3 },
+            SnbtContext::Compound { key: Some(String::from("list")) },
+        ]
+    );
+
+    let err = error("{'a:b': {c, d: 1}}");
+    assert_eq!(
+        err.context(),
+        [
+            SnbtContext::Compound { key: None },
+            SnbtContext::Compound { key: Some(String::from("a:b")) }
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "unexpected character ',' at byte 10, expected ':', inside compound, inside compound at key \"a:b\""
+    );
+}
+
+#[test]
+fn offset() {
+    // Spans are relative to the input, not the root compound.
+    let err = error("  \n {a:[1 2]}");
+    assert_eq!(span(&err), 10..11);
+    assert_eq!(
+        err.context(),
+        [SnbtContext::List { index: 0 }, SnbtContext::Compound { key: Some(String::from("a")) }]
+    );
+}
+
+#[test]
+#[cfg(feature = "report")]
+fn report() {
+    let input = "{list:[0,1,2,{x:1,y:}]}";
+
+    let mut output = Vec::new();
+    error(input).write_report(input, &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(output.contains("unexpected character '}'"));
+    assert!(output.contains("inside list at index 3"));
+}
@@ -0,0 +1,101 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use froglight_mutf8::prelude::MString;
+use froglight_nbt::prelude::*;
+use froglight_snbt::prelude::*;
+
+const TESTS: &str = include_str!("parse/tests.txt");
+static BIGTEST: &[u8] = include_bytes!("../../froglight-nbt/tests/nbt/bigtest.nbt");
+
+#[test]
+fn tests() {
+    for (name, snbt) in TESTS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once("=>"))
+        .map(|(name, snbt)| (name.trim(), snbt.trim()))
+    {
+        let snbt = IndexedSnbtSlice::new_ref(snbt).unwrap();
+        let nbt = Nbt::try_from(&snbt).unwrap();
+
+        // SNBT -> NBT -> SNBT -> NBT
+        let written = IndexedSnbtCow::try_from(&nbt).unwrap();
+        assert_eq!(Nbt::try_from(&written), Ok(nbt.clone()), "`{name}` did not round-trip");
+
+        // SNBT -> IndexedNBT -> NBT
+        let indexed = IndexedNbtCow::try_from(&snbt).unwrap();
+        assert_eq!(Nbt::try_from(&indexed), Ok(nbt), "`{name}` did not round-trip");
+    }
+}
+
+#[test]
+fn bigtest() {
+    let (nbt, _) = Nbt::read_named(BIGTEST).unwrap();
+
+    for writer in [SnbtWriter::compact(), SnbtWriter::pretty()] {
+        let snbt = IndexedSnbt::new_owned(writer.to_string(&nbt).unwrap()).unwrap();
+        assert_eq!(Nbt::try_from(&snbt).unwrap().compound(), nbt.compound());
+    }
+
+    let indexed = IndexedNbtSlice::new_named(BIGTEST).unwrap();
+    let snbt = IndexedSnbtCow::try_from(&indexed).unwrap();
+    assert_eq!(Nbt::try_from(&snbt).unwrap().compound(), nbt.compound());
+}
+
+#[test]
+fn values() {
+    let snbt = IndexedSnbtSlice::new_ref(
+        "{a:true,b:-1sb,c:255ub,d:[B;1b,2b],e:[I;1,-2si],f:[L;],g:[1s,2s],h:'it\\'s \\u00e9',i:[]}",
+    )
+    .unwrap();
+    let nbt = Nbt::try_from(&snbt).unwrap();
+
+    assert_eq!(nbt.get("a"), Some(&NbtValue::Byte(1)));
+    assert_eq!(nbt.get("b"), Some(&NbtValue::Byte(255)));
+    assert_eq!(nbt.get("c"), Some(&NbtValue::Byte(255)));
+    assert_eq!(nbt.get("d"), Some(&NbtValue::ByteArray(vec![1, 2])));
+    assert_eq!(nbt.get("e"), Some(&NbtValue::IntArray(vec![1, u32::MAX - 1])));
+    assert_eq!(nbt.get("f"), Some(&NbtValue::LongArray(vec![])));
+    assert_eq!(nbt.get("g"), Some(&NbtValue::List(NbtList::Short(vec![1, 2]))));
+    assert_eq!(nbt.get("h"), Some(&NbtValue::String(MString::from("it's é"))));
+    assert_eq!(nbt.get("i"), Some(&NbtValue::List(NbtList::Empty)));
+
+    // NBT values are signed, with suffixes for their type.
+    assert_eq!(
+        SnbtWriter::compact().to_string(&nbt).unwrap(),
+        "{a:1b,b:-1sb,c:-1sb,d:[B;1b,2b],e:[I;1,-2si],f:[L;],g:[1s,2s],h:\"it's é\",i:[]}"
+    );
+
+    // Negative values without a signedness suffix are signed.
+    let negative = IndexedSnbtSlice::new_ref("{a:-1b,b:-2,c:[L;1L,-2L]}").unwrap();
+    let negative = Nbt::try_from(&negative).unwrap();
+    assert_eq!(negative.get("a"), Some(&NbtValue::Byte(255)));
+    assert_eq!(negative.get("b"), Some(&NbtValue::Int(u32::MAX - 1)));
+    assert_eq!(negative.get("c"), Some(&NbtValue::LongArray(vec![1, u64::MAX - 1])));
+
+    // NBT lists can only contain a single type.
+    let mixed = IndexedSnbtSlice::new_ref("{a:[1b,2s]}").unwrap();
+    assert_eq!(Nbt::try_from(&mixed), Err(()));
+}
+
+#[test]
+fn non_finite() {
+    for value in [
+        NbtValue::Float(f32::NAN),
+        NbtValue::Float(f32::INFINITY),
+        NbtValue::Double(f64::NEG_INFINITY),
+        NbtValue::List(NbtList::Double(vec![1.0, f64::NAN])),
+    ] {
+        let mut nbt = NbtCompound::new();
+        nbt.insert("a", value);
+
+        // Non-finite values cannot be represented in SNBT.
+        assert!(SnbtWriter::compact().to_string(&nbt).is_err());
+        assert!(IndexedSnbtCow::try_from(&Nbt::new(None, nbt)).is_err());
+    }
+}
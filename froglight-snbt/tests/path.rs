@@ -0,0 +1,202 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use froglight_mutf8::prelude::MString;
+use froglight_nbt::prelude::*;
+use froglight_snbt::{
+    path::{NbtPathError, NbtPathNode},
+    prelude::NbtPath,
+};
+
+static BIGTEST: &[u8] = include_bytes!("../../froglight-nbt/tests/nbt/bigtest.nbt");
+
+fn path(input: &str) -> NbtPath { input.parse().unwrap() }
+
+fn string(value: &str) -> NbtValue { NbtValue::String(MString::from(value)) }
+
+fn compound<const N: usize>(entries: [(&str, NbtValue); N]) -> NbtCompound {
+    entries.into_iter().map(|(key, value)| (MString::from(key), value)).collect()
+}
+
+#[test]
+fn parse() {
+    let parsed = path("Inventory[{Slot:0b}].components.\"minecraft:custom_name\"");
+    let [
+        NbtPathNode::Key(inventory),
+        NbtPathNode::MatchAll(pattern),
+        NbtPathNode::Key(components),
+        NbtPathNode::Key(name),
+    ] = parsed.0.as_slice()
+    else {
+        panic!("Unexpected nodes: {parsed:?}");
+    };
+    assert_eq!(inventory, "Inventory");
+    assert_eq!(pattern, &compound([("Slot", NbtValue::Byte(0))]));
+    assert_eq!(components, "components");
+    assert_eq!(name, "minecraft:custom_name");
+
+    assert_eq!(path("Pos[-1]").0, [NbtPathNode::Key("Pos".into()), NbtPathNode::Index(-1)]);
+    assert!(matches!(
+        path("{a:1}.b{c:[I;1,2]}[]").0.as_slice(),
+        [NbtPathNode::MatchRoot(..), NbtPathNode::MatchKey(..), NbtPathNode::All]
+    ));
+
+    // Paths stop at the first space.
+    let (prefix, remaining) = NbtPath::parse_prefix("a.b[0] c").unwrap();
+    assert_eq!(prefix, path("a.b[0]"));
+    assert_eq!(remaining, " c");
+
+    for invalid in ["", "a..b", "a[0", "a.{b:1}", "a[x]", "a{b:}", "\"a"] {
+        assert!(invalid.parse::<NbtPath>().is_err(), "`{invalid}` should not parse");
+    }
+    assert_eq!("".parse::<NbtPath>(), Err(NbtPathError::EmptyPath));
+}
+
+#[test]
+fn display() {
+    for input in [
+        "Inventory[{Slot:0b}].components.\"minecraft:custom_name\"",
+        "{a:1,b:\"two\",c:[1.5d,2.0d]}.list[-1][]",
+        "a{b:[L;1L,-2L],c:{}}.\"with space\"[{d:3s}]",
+    ] {
+        let parsed = path(input);
+        assert_eq!(path(&parsed.to_string()), parsed, "`{input}` did not round-trip");
+    }
+
+    assert_eq!(path("a.b[0]").to_string(), "a.b[0]");
+    assert_eq!(path("'a b'{c:1b}[]").to_string(), "\"a b\"{c:1b}[]");
+}
+
+#[test]
+fn get() {
+    let (nbt, _) = Nbt::read_named(BIGTEST).unwrap();
+
+    assert_eq!(path("intTest").get(&nbt), [NbtValue::Int(2_147_483_647)]);
+    assert_eq!(path("\"nested compound test\".egg.name").get(&nbt), [string("Eggbert")]);
+    assert_eq!(path("\"listTest (long)\"[1]").get(&nbt), [NbtValue::Long(12)]);
+    assert_eq!(path("\"listTest (long)\"[-1]").get(&nbt), [NbtValue::Long(15)]);
+    assert_eq!(path("\"listTest (long)\"[]").count(&nbt), 5);
+    assert_eq!(
+        path("\"listTest (compound)\"[{name:\"Compound tag #1\"}].name").get(&nbt),
+        [string("Compound tag #1")]
+    );
+    assert_eq!(path("\"listTest (compound)\"[].name").count(&nbt), 2);
+
+    // Patterns only need to contain a subset of the value.
+    assert_eq!(path("{intTest:2147483647}").count(&nbt), 1);
+    assert_eq!(path("{intTest:0}").count(&nbt), 0);
+    assert_eq!(path("\"nested compound test\"{egg:{name:\"Eggbert\"}}.ham.value").count(&nbt), 1);
+    assert_eq!(path("\"listTest (long)\"[5]").count(&nbt), 0);
+    assert_eq!(path("missing.key").count(&nbt), 0);
+
+    // Arrays can be indexed like lists.
+    let array = "\"byteArrayTest (the first 1000 values of (n*n*255+n*7)%100, starting with n=0 \
+                 (0, 62, 34, 16, 8, ...))\"";
+    assert_eq!(path(&alloc::format!("{array}[1]")).get(&nbt), [NbtValue::Byte(62)]);
+    assert_eq!(path(&alloc::format!("{array}[]")).count(&nbt), 1000);
+}
+
+#[test]
+fn set() {
+    let mut nbt = NbtCompound::new();
+
+    // Missing compounds and lists are created.
+    let name = path("Inventory[{Slot:0b}].components.\"minecraft:custom_name\"");
+    assert_eq!(name.set(&mut nbt, &string("Frog")), Ok(1));
+    assert_eq!(name.set(&mut nbt, &string("Frog")), Ok(0));
+    assert_eq!(name.get(&nbt), [string("Frog")]);
+    assert_eq!(path("Inventory[0].Slot").get(&nbt), [NbtValue::Byte(0)]);
+
+    let pos = path("Pos");
+    pos.set(&mut nbt, &NbtValue::List(NbtList::Double(vec![1.0, 2.0, 3.0]))).unwrap();
+    assert_eq!(path("Pos[1]").set(&mut nbt, &NbtValue::Double(64.0)), Ok(1));
+    assert_eq!(pos.get(&nbt), [NbtValue::List(NbtList::Double(vec![1.0, 64.0, 3.0]))]);
+    assert_eq!(path("Pos[]").set(&mut nbt, &NbtValue::Double(0.0)), Ok(3));
+
+    // Lists can only contain a single type.
+    assert_eq!(path("Pos[0]").set(&mut nbt, &NbtValue::Int(1)), Err(NbtPathError::TypeMismatch));
+    assert_eq!(pos.get(&nbt), [NbtValue::List(NbtList::Double(vec![0.0, 0.0, 0.0]))]);
+
+    assert_eq!(path("{}").set(&mut nbt, &NbtValue::Int(1)), Err(NbtPathError::Unsupported));
+}
+
+#[test]
+fn remove() {
+    let (mut nbt, _) = Nbt::read_named(BIGTEST).unwrap();
+
+    assert_eq!(path("intTest").remove(&mut nbt), Ok(1));
+    assert_eq!(path("intTest").remove(&mut nbt), Ok(0));
+    assert_eq!(path("\"listTest (long)\"[0]").remove(&mut nbt), Ok(1));
+    assert_eq!(
+        path("\"listTest (long)\"").get(&nbt),
+        [NbtValue::List(NbtList::Long(vec![12, 13, 14, 15]))]
+    );
+
+    let list = path("\"listTest (compound)\"[]");
+    assert_eq!(path("\"listTest (compound)\"[{name:\"Compound tag #0\"}]").remove(&mut nbt), Ok(1));
+    assert_eq!(list.count(&nbt), 1);
+    assert_eq!(list.remove(&mut nbt), Ok(1));
+    assert_eq!(list.count(&nbt), 0);
+}
+
+#[test]
+fn merge() {
+    let mut nbt = compound([("a", NbtValue::Compound(compound([("b", NbtValue::Int(1))])))]);
+
+    let other = compound([("c", NbtValue::Int(2))]);
+    assert_eq!(path("a").merge(&mut nbt, &other), Ok(1));
+    assert_eq!(path("a").merge(&mut nbt, &other), Ok(0));
+    assert_eq!(path("a").count(&nbt), 1);
+    assert_eq!(path("a.b").get(&nbt), [NbtValue::Int(1)]);
+    assert_eq!(path("a.c").get(&nbt), [NbtValue::Int(2)]);
+
+    // An empty path merges into the root.
+    NbtPath::default().merge(&mut nbt, &other).unwrap();
+    assert_eq!(path("c").get(&nbt), [NbtValue::Int(2)]);
+
+    assert_eq!(path("a.b").merge(&mut nbt, &other), Err(NbtPathError::TypeMismatch));
+}
+
+#[test]
+fn indexed() {
+    let nbt = IndexedNbtSlice::new_named(BIGTEST).unwrap();
+
+    let values = path("\"nested compound test\".egg.name").get_indexed(&nbt);
+    let [value] = values.as_slice() else { panic!("Expected a single value: {values:?}") };
+    assert_eq!(value.as_string().unwrap().get(), "Eggbert");
+
+    let values = path("\"listTest (long)\"[-2]").get_indexed(&nbt);
+    assert_eq!(values[0].as_long().unwrap().get(), 14);
+
+    assert_eq!(path("\"listTest (compound)\"[{name:\"Compound tag #1\"}]").count_indexed(&nbt), 1);
+    assert_eq!(path("{shortTest:32767s}").count_indexed(&nbt), 1);
+    assert_eq!(path("{shortTest:32767}").count_indexed(&nbt), 0);
+
+    // The indexed and structured results should always be the same.
+    let (structured, _) = Nbt::read_named(BIGTEST).unwrap();
+    for input in ["intTest", "\"listTest (long)\"[]", "\"listTest (compound)\"[].name", "{}"] {
+        assert_eq!(path(input).count(&structured), path(input).count_indexed(&nbt));
+    }
+}
+
+#[test]
+fn indexed_modify() {
+    let mut nbt = IndexedNbtSlice::new_named(BIGTEST).unwrap().into_owned();
+
+    assert_eq!(
+        path("\"nested compound test\".egg.name").set_indexed(&mut nbt, &string("Egg")),
+        Ok(1)
+    );
+    assert_eq!(path("intTest").remove_indexed(&mut nbt), Ok(1));
+    assert_eq!(path("new").merge_indexed(&mut nbt, &compound([("a", NbtValue::Byte(1))])), Ok(1));
+
+    assert_eq!(nbt.name().unwrap().get(), "Level");
+    assert_eq!(path("intTest").count_indexed(&nbt), 0);
+    assert_eq!(path("new.a").get_indexed(&nbt)[0].as_byte().unwrap().get(), 1);
+    let values: Vec<_> = path("\"nested compound test\".egg.name").get_indexed(&nbt);
+    assert_eq!(values[0].as_string().unwrap().get(), "Egg");
+}
@@ -0,0 +1,112 @@
+//! TODO
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+use froglight_snbt::{
+    prelude::*,
+    write::{FloatFormat, KeyQuoting},
+};
+
+const TESTS: &str = include_str!("parse/tests.txt");
+
+/// Iterate over the named tests in `tests.txt`.
+fn tests() -> impl Iterator<Item = (&'static str, &'static str)> {
+    TESTS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once("=>"))
+        .map(|(name, snbt)| (name.trim(), snbt.trim()))
+}
+
+fn write(writer: SnbtWriter, snbt: &str) -> String {
+    writer.to_string(&IndexedSnbtSlice::new_ref(snbt).unwrap()).unwrap()
+}
+
+#[test]
+fn roundtrip() {
+    for (name, snbt) in tests() {
+        let compact = write(SnbtWriter::compact(), snbt);
+        assert_eq!(write(SnbtWriter::compact(), &compact), compact, "`{name}` did not round-trip");
+
+        let pretty = write(SnbtWriter::pretty(), snbt);
+        assert_eq!(write(SnbtWriter::compact(), &pretty), compact, "`{name}` did not round-trip");
+    }
+}
+
+#[test]
+fn suffixes() {
+    for snbt in [
+        "{a:-128sb,b:255ub,c:1s,d:-32768ss,e:1,f:-5si,g:4294967295ui,h:1l,i:-1sl}",
+        "{a:true,b:false,c:bool(1b),d:bool(0ul)}",
+        "{a:1.5f,b:-2.25d,c:100000.0d,d:1e-7d}",
+        "{a:[B;1b,2ub,-3sb],b:[I;1,2b,3s],c:[L;1l,2],d:[B;],e:[]}",
+    ] {
+        assert_eq!(write(SnbtWriter::compact(), snbt), snbt);
+    }
+
+    // Integers are always written in decimal.
+    assert_eq!(write(SnbtWriter::compact(), "{a:0x10b,b:0b101s}"), "{a:16b,b:5s}");
+}
+
+#[test]
+fn strings() {
+    // Strings keep their original quotes and escapes.
+    let snbt = "{a:plain,b:'single',c:\"double\",d:\"it's\",e:\"quote \\\"\"}";
+    assert_eq!(write(SnbtWriter::compact(), snbt), snbt);
+
+    // Keys are only quoted when required.
+    let snbt = "{\"a\":1,'b c':2,\"0\":3,d_e.f-g+h:4}";
+    assert_eq!(write(SnbtWriter::compact(), snbt), "{a:1,'b c':2,\"0\":3,d_e.f-g+h:4}");
+
+    let always = SnbtWriter::compact().with_key_quoting(KeyQuoting::Always);
+    assert_eq!(write(always, snbt), "{\"a\":1,'b c':2,\"0\":3,\"d_e.f-g+h\":4}");
+}
+
+#[test]
+fn floats() {
+    let snbt = "{a:1.5f,b:2.0d,c:1234.5678d}";
+
+    let precision = SnbtWriter::compact().with_float_format(FloatFormat::Precision(2));
+    assert_eq!(write(precision, snbt), "{a:1.50f,b:2.00d,c:1234.57d}");
+
+    let precision = SnbtWriter::compact().with_float_format(FloatFormat::Precision(0));
+    assert_eq!(write(precision, snbt), "{a:2.0f,b:2.0d,c:1235.0d}");
+
+    let scientific = SnbtWriter::compact().with_float_format(FloatFormat::Scientific);
+    assert_eq!(write(scientific, snbt), "{a:1.5e0f,b:2e0d,c:1.2345678e3d}");
+}
+
+#[test]
+fn pretty() {
+    let snbt = "{id:'minecraft:stone',count:1b,pos:[1.0d,2.0d,3.0d],tags:[{a:[]},{}],empty:{}}";
+
+    assert_eq!(
+        write(SnbtWriter::pretty(), snbt),
+        "{
+    id: 'minecraft:stone',
+    count: 1b,
+    pos: [1.0d, 2.0d, 3.0d],
+    tags: [
+        {
+            a: []
+        },
+        {}
+    ],
+    empty: {}
+}"
+    );
+
+    let indexed = IndexedSnbtSlice::new_ref(snbt).unwrap();
+    assert_eq!(format!("{indexed}"), write(SnbtWriter::compact(), snbt));
+    assert_eq!(format!("{indexed:#}"), write(SnbtWriter::pretty(), snbt));
+    assert_eq!(
+        SnbtWriter::pretty()
+            .with_indent(1)
+            .to_string(&indexed.root().get("tags").unwrap())
+            .unwrap(),
+        "[\n {\n  a: []\n },\n {}\n]"
+    );
+}
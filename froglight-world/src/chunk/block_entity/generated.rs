@@ -0,0 +1,55 @@
+//! Views for all vanilla [`BlockEntity`](super::BlockEntity) types.
+//!
+//! @generated
+
+generate! {
+    Banner => BannerView, as_banner,
+    Barrel => BarrelView, as_barrel,
+    Beacon => BeaconView, as_beacon,
+    Bed => BedView, as_bed,
+    Beehive => BeehiveView, as_beehive,
+    Bell => BellView, as_bell,
+    BlastFurnace => BlastFurnaceView, as_blast_furnace,
+    BrewingStand => BrewingStandView, as_brewing_stand,
+    BrushableBlock => BrushableBlockView, as_brushable_block,
+    CalibratedSculkSensor => CalibratedSculkSensorView, as_calibrated_sculk_sensor,
+    Campfire => CampfireView, as_campfire,
+    Chest => ChestView, as_chest,
+    ChiseledBookshelf => ChiseledBookshelfView, as_chiseled_bookshelf,
+    CommandBlock => CommandBlockView, as_command_block,
+    Comparator => ComparatorView, as_comparator,
+    Conduit => ConduitView, as_conduit,
+    CopperGolemStatue => CopperGolemStatueView, as_copper_golem_statue,
+    Crafter => CrafterView, as_crafter,
+    CreakingHeart => CreakingHeartView, as_creaking_heart,
+    DaylightDetector => DaylightDetectorView, as_daylight_detector,
+    DecoratedPot => DecoratedPotView, as_decorated_pot,
+    Dispenser => DispenserView, as_dispenser,
+    Dropper => DropperView, as_dropper,
+    EnchantingTable => EnchantingTableView, as_enchanting_table,
+    EndGateway => EndGatewayView, as_end_gateway,
+    EndPortal => EndPortalView, as_end_portal,
+    EnderChest => EnderChestView, as_ender_chest,
+    Furnace => FurnaceView, as_furnace,
+    HangingSign => HangingSignView, as_hanging_sign,
+    Hopper => HopperView, as_hopper,
+    Jigsaw => JigsawView, as_jigsaw,
+    Jukebox => JukeboxView, as_jukebox,
+    Lectern => LecternView, as_lectern,
+    MobSpawner => MobSpawnerView, as_mob_spawner,
+    Piston => PistonView, as_piston,
+    SculkCatalyst => SculkCatalystView, as_sculk_catalyst,
+    SculkSensor => SculkSensorView, as_sculk_sensor,
+    SculkShrieker => SculkShriekerView, as_sculk_shrieker,
+    Shelf => ShelfView, as_shelf,
+    ShulkerBox => ShulkerBoxView, as_shulker_box,
+    Sign => SignView, as_sign,
+    Skull => SkullView, as_skull,
+    Smoker => SmokerView, as_smoker,
+    StructureBlock => StructureBlockView, as_structure_block,
+    TestBlock => TestBlockView, as_test_block,
+    TestInstanceBlock => TestInstanceBlockView, as_test_instance_block,
+    TrappedChest => TrappedChestView, as_trapped_chest,
+    TrialSpawner => TrialSpawnerView, as_trial_spawner,
+    Vault => VaultView, as_vault
+}
@@ -0,0 +1,77 @@
+//! TODO
+
+use froglight_block::prelude::VanillaBlockEntity;
+use froglight_nbt::prelude::NbtCompound;
+
+/// Generate a view for each [`VanillaBlockEntity`] type.
+macro_rules! generate {
+    ($($ident:ident => $view:ident, $function:ident),* $(,)?) => {
+        use froglight_block::prelude::VanillaBlockEntity;
+        use froglight_nbt::prelude::NbtCompound;
+
+        use super::BlockEntity;
+
+        impl BlockEntity {
+            $(
+                #[doc = concat!("View this [`BlockEntity`] as a [`", stringify!($view), "`].")]
+                #[must_use]
+                pub fn $function(&self) -> Option<$view<'_>> {
+                    (self.kind == VanillaBlockEntity::$ident).then_some($view(&self.data))
+                }
+            )*
+        }
+
+        $(
+            #[doc = concat!("A view of a [`VanillaBlockEntity::", stringify!($ident), "`] [`BlockEntity`].")]
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            pub struct $view<'a>(&'a NbtCompound);
+
+            impl<'a> $view<'a> {
+                /// Get the data of the block entity.
+                #[inline]
+                #[must_use]
+                pub const fn data(&self) -> &'a NbtCompound { self.0 }
+            }
+        )*
+    };
+}
+
+mod generated;
+pub use generated::*;
+
+mod view;
+pub use view::SignText;
+
+/// A block entity stored in a [`Chunk`](super::Chunk).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockEntity {
+    kind: VanillaBlockEntity,
+    data: NbtCompound,
+}
+
+impl BlockEntity {
+    /// Create a new [`BlockEntity`] from its type and data.
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: VanillaBlockEntity, data: NbtCompound) -> Self { Self { kind, data } }
+
+    /// Get the type of this [`BlockEntity`].
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> VanillaBlockEntity { self.kind }
+
+    /// Get a reference to the data of this [`BlockEntity`].
+    #[inline]
+    #[must_use]
+    pub const fn data(&self) -> &NbtCompound { &self.data }
+
+    /// Get a mutable reference to the data of this [`BlockEntity`].
+    #[inline]
+    #[must_use]
+    pub const fn data_mut(&mut self) -> &mut NbtCompound { &mut self.data }
+
+    /// Get the data of this [`BlockEntity`].
+    #[inline]
+    #[must_use]
+    pub fn into_data(self) -> NbtCompound { self.data }
+}
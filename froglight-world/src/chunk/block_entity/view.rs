@@ -0,0 +1,209 @@
+//! TODO
+
+use alloc::{borrow::Cow, string::String};
+
+use froglight_common::prelude::Identifier;
+use froglight_nbt::prelude::{NbtCompound, NbtList, NbtValue};
+
+use super::{BannerView, BeaconView, HangingSignView, MobSpawnerView, SignView, SkullView};
+
+/// Implement accessors for sign and hanging sign views.
+macro_rules! sign_view {
+    ($($view:ident),*) => {
+        $(
+            impl<'a> $view<'a> {
+                /// Get the text on the front of the sign.
+                #[must_use]
+                pub fn front(&self) -> Option<SignText<'a>> {
+                    self.data().get("front_text").and_then(NbtValue::as_compound).map(SignText)
+                }
+
+                /// Get the text on the back of the sign.
+                #[must_use]
+                pub fn back(&self) -> Option<SignText<'a>> {
+                    self.data().get("back_text").and_then(NbtValue::as_compound).map(SignText)
+                }
+
+                /// Returns `true` if the sign has been waxed.
+                #[must_use]
+                pub fn is_waxed(&self) -> bool { get_bool(self.data(), "is_waxed") }
+            }
+        )*
+    };
+}
+
+sign_view!(SignView, HangingSignView);
+
+/// A view of one side of a sign [`BlockEntity`](super::BlockEntity).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignText<'a>(&'a NbtCompound);
+
+impl<'a> SignText<'a> {
+    /// Get the raw text components of each line.
+    #[must_use]
+    pub fn messages(&self) -> Option<&'a NbtList> {
+        self.0.get("messages").and_then(NbtValue::as_list)
+    }
+
+    /// Get the plain text of each line, ignoring any formatting.
+    ///
+    /// Lines that are missing or cannot be read are left empty.
+    #[must_use]
+    pub fn lines(&self) -> [String; 4] {
+        let mut lines = [const { String::new() }; 4];
+        match self.messages() {
+            Some(NbtList::String(messages)) => {
+                for (line, message) in lines.iter_mut().zip(messages) {
+                    line.push_str(&message.to_utf8());
+                }
+            }
+            Some(NbtList::Compound(messages)) => {
+                for (line, message) in lines.iter_mut().zip(messages) {
+                    push_compound_text(line, message);
+                }
+            }
+            _ => {}
+        }
+        lines
+    }
+
+    /// Get the color of the text.
+    #[must_use]
+    pub fn color(&self) -> Option<Cow<'a, str>> { get_string(self.0, "color") }
+
+    /// Returns `true` if the text is glowing.
+    #[must_use]
+    pub fn is_glowing(&self) -> bool { get_bool(self.0, "has_glowing_text") }
+}
+
+/// Append the plain text of a text component to a [`String`].
+fn push_text(string: &mut String, value: &NbtValue) {
+    match value {
+        NbtValue::String(text) => string.push_str(&text.to_utf8()),
+        NbtValue::Compound(compound) => push_compound_text(string, compound),
+        NbtValue::List(list) => push_list_text(string, list),
+        _ => {}
+    }
+}
+
+/// Append the plain text of a compound text component to a [`String`].
+fn push_compound_text(string: &mut String, compound: &NbtCompound) {
+    // Heterogeneous lists wrap their elements in a compound with an empty key.
+    if let Some(value) = compound.get("") {
+        push_text(string, value);
+    }
+    if let Some(NbtValue::String(text)) = compound.get("text") {
+        string.push_str(&text.to_utf8());
+    }
+    if let Some(NbtValue::List(extra)) = compound.get("extra") {
+        push_list_text(string, extra);
+    }
+}
+
+/// Append the plain text of a list of text components to a [`String`].
+fn push_list_text(string: &mut String, list: &NbtList) {
+    match list {
+        NbtList::String(texts) => {
+            for text in texts {
+                string.push_str(&text.to_utf8());
+            }
+        }
+        NbtList::Compound(compounds) => {
+            for compound in compounds {
+                push_compound_text(string, compound);
+            }
+        }
+        _ => {}
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl<'a> MobSpawnerView<'a> {
+    /// Get the type of entity the spawner will spawn next.
+    #[must_use]
+    pub fn entity_type(&self) -> Option<Identifier<'a>> {
+        let data = self.data().get("SpawnData").and_then(NbtValue::as_compound)?;
+        let entity = data.get("entity").and_then(NbtValue::as_compound)?;
+        get_identifier(entity, "id")
+    }
+
+    /// Get the number of ticks until the spawner spawns next.
+    #[must_use]
+    pub fn delay(&self) -> Option<u16> {
+        self.data().get("Delay").and_then(NbtValue::as_short).copied()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl<'a> SkullView<'a> {
+    /// Get the name of the player whose skull this is.
+    #[must_use]
+    pub fn profile_name(&self) -> Option<Cow<'a, str>> {
+        match self.data().get("profile")? {
+            NbtValue::String(name) => Some(name.to_utf8()),
+            NbtValue::Compound(profile) => get_string(profile, "name"),
+            _ => None,
+        }
+    }
+
+    /// Get the sound played by a note block placed above the skull.
+    #[must_use]
+    pub fn note_block_sound(&self) -> Option<Identifier<'a>> {
+        get_identifier(self.data(), "note_block_sound")
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl<'a> BannerView<'a> {
+    /// Get the patterns applied to the banner and their colors,
+    /// from bottom to top.
+    ///
+    /// Patterns defined inline instead of by [`Identifier`] are skipped.
+    pub fn patterns(&self) -> impl Iterator<Item = (Identifier<'a>, Cow<'a, str>)> + 'a {
+        let patterns = match self.data().get("patterns") {
+            Some(NbtValue::List(NbtList::Compound(patterns))) => patterns.as_slice(),
+            _ => &[],
+        };
+
+        patterns.iter().filter_map(|pattern| {
+            Some((get_identifier(pattern, "pattern")?, get_string(pattern, "color")?))
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+impl<'a> BeaconView<'a> {
+    /// Get the primary effect of the beacon.
+    #[must_use]
+    pub fn primary_effect(&self) -> Option<Identifier<'a>> {
+        get_identifier(self.data(), "primary_effect")
+    }
+
+    /// Get the secondary effect of the beacon.
+    #[must_use]
+    pub fn secondary_effect(&self) -> Option<Identifier<'a>> {
+        get_identifier(self.data(), "secondary_effect")
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Get a boolean stored as a byte, defaulting to `false`.
+fn get_bool(compound: &NbtCompound, key: &str) -> bool {
+    compound.get(key).and_then(NbtValue::as_byte).is_some_and(|byte| *byte != 0)
+}
+
+/// Get a string, converting it to UTF-8 if necessary.
+fn get_string<'a>(compound: &'a NbtCompound, key: &str) -> Option<Cow<'a, str>> {
+    compound.get(key).and_then(NbtValue::as_string).map(|string| string.to_utf8())
+}
+
+/// Get a string and parse it as an [`Identifier`].
+fn get_identifier<'a>(compound: &'a NbtCompound, key: &str) -> Option<Identifier<'a>> {
+    let string = compound.get(key).and_then(NbtValue::as_string)?;
+    Identifier::try_new(string.as_utf8().ok()?).ok()
+}
@@ -1,5 +1,6 @@
 //! TODO
 
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 use core::{any::TypeId, fmt, ops::Range};
 
 #[cfg(feature = "bevy")]
@@ -16,7 +17,9 @@ use froglight_common::prelude::Identifier;
 use smallvec::SmallVec;
 
 use crate::{
-    component::ChunkBlockPos,
+    CHUNK_LENGTH, CHUNK_WIDTH, SECTION_HEIGHT,
+    chunk::{BlockEntity, ChunkLight, Heightmap, HeightmapKind},
+    component::{ChunkBlockPos, SectionBlockPos},
     naive::storage::ChunkStorage,
     prelude::{BlockPos, NaiveChunk},
     section::{BiomeSection, Section, SectionPalette, SectionType},
@@ -31,6 +34,9 @@ pub struct Chunk {
     biomes: &'static BiomeStorage,
     blocks: &'static BlockStorage,
     naive: NaiveChunk,
+    block_entities: Arc<BTreeMap<ChunkBlockPos, BlockEntity>>,
+    heightmaps: Arc<BTreeMap<HeightmapKind, Heightmap>>,
+    light: ChunkLight,
 }
 
 impl Chunk {
@@ -38,7 +44,14 @@ impl Chunk {
     /// [`Version`](froglight_common::version::Version).
     #[must_use]
     pub fn new<V: BiomeVersion + BlockVersion>(naive: NaiveChunk) -> Self {
-        Self { biomes: V::biomes(), blocks: V::blocks(), naive }
+        Self {
+            biomes: V::biomes(),
+            blocks: V::blocks(),
+            light: ChunkLight::new(naive.sections().len()),
+            naive,
+            block_entities: Arc::new(BTreeMap::new()),
+            heightmaps: Arc::new(BTreeMap::new()),
+        }
     }
 
     /// Create a new empty large [`Chunk`].
@@ -152,24 +165,62 @@ impl Chunk {
 
     /// Set the [`Block`] at the given position within the chunk.
     ///
+    /// If the type of [`Block`] changes, any [`BlockEntity`] at the position
+    /// is removed.
+    ///
     /// Returns `None` if the position is out of bounds, or if the [`Block`]
     /// does not exist in this [`Version`](froglight_common::version::Version).
-    #[inline]
     pub fn set_block<P: Into<BlockPos>>(&mut self, position: P, block: Block) -> Option<Block> {
-        self.naive.set_block_using::<P>(position, block, self.blocks)
+        let position = ChunkBlockPos::try_from_blockpos(position.into(), self.height_offset())?;
+        self.set_block_pos(position, block)
     }
 
     /// Set the [`Block`] at the given position within the chunk.
     ///
+    /// If the type of [`Block`] changes, any [`BlockEntity`] at the position
+    /// is removed. Any loaded [`Heightmap`]s are updated.
+    ///
     /// Returns `None` if the position is out of bounds, or if the [`Block`]
     /// does not exist in this [`Version`](froglight_common::version::Version).
-    #[inline]
     pub fn set_block_pos<P: Into<ChunkBlockPos>>(
         &mut self,
         position: P,
         block: Block,
     ) -> Option<Block> {
-        self.naive.set_block_pos_using::<P>(position, block, self.blocks)
+        let position = position.into();
+        let previous = self.naive.set_block_pos_using(position, block, self.blocks)?;
+        if previous.block_ty() != block.block_ty() && self.block_entities.contains_key(&position) {
+            Arc::make_mut(&mut self.block_entities).remove(&position);
+        }
+        self.update_heightmaps(position, &block);
+        Some(previous)
+    }
+
+    /// Update all loaded [`Heightmap`]s after a [`Block`] was placed.
+    fn update_heightmaps(&mut self, position: ChunkBlockPos, block: &Block) {
+        if self.heightmaps.is_empty() {
+            return;
+        }
+        let (x, y, z) = (position.x(), position.y(), position.z());
+
+        for (kind, heightmap) in Arc::make_mut(&mut self.heightmaps) {
+            let Some(height) = heightmap.get(x, z) else { continue };
+
+            if kind.matches(block) {
+                // The block is now the highest in its column.
+                if y >= height {
+                    heightmap.set(x, z, y + 1);
+                }
+            } else if y + 1 == height {
+                // The highest block was replaced, search downwards for the next one.
+                let below = (0..y).rev().find(|&y| {
+                    self.naive
+                        .get_block_pos_using(ChunkBlockPos::new_xyz(x, y, z), self.blocks)
+                        .is_some_and(|block| kind.matches(&block))
+                });
+                heightmap.set(x, z, below.map_or(0, |y| y + 1));
+            }
+        }
     }
 
     /// Returns `true` if this [`Chunk`] contains the given [`Block`], including
@@ -188,6 +239,279 @@ impl Chunk {
         self.as_naive().contains_block_type(block.block_ty(), self.blocks)
     }
 
+    /// Get the [`BlockEntity`] at the given position within the chunk.
+    ///
+    /// Returns `None` if the position is out of bounds,
+    /// or if there is no [`BlockEntity`] at the position.
+    #[must_use]
+    pub fn get_block_entity<P: Into<BlockPos>>(&self, position: P) -> Option<&BlockEntity> {
+        let position = ChunkBlockPos::try_from_blockpos(position.into(), self.height_offset())?;
+        self.get_block_entity_pos(position)
+    }
+
+    /// Get the [`BlockEntity`] at the given position within the chunk.
+    ///
+    /// Returns `None` if there is no [`BlockEntity`] at the position.
+    #[inline]
+    #[must_use]
+    pub fn get_block_entity_pos<P: Into<ChunkBlockPos>>(
+        &self,
+        position: P,
+    ) -> Option<&BlockEntity> {
+        self.block_entities.get(&position.into())
+    }
+
+    /// Get a mutable reference to the [`BlockEntity`] at the given position
+    /// within the chunk, cloning the [`BlockEntity`]s if they are shared.
+    ///
+    /// Returns `None` if there is no [`BlockEntity`] at the position.
+    #[must_use]
+    pub fn get_block_entity_pos_mut<P: Into<ChunkBlockPos>>(
+        &mut self,
+        position: P,
+    ) -> Option<&mut BlockEntity> {
+        let position = position.into();
+        if !self.block_entities.contains_key(&position) {
+            return None;
+        }
+        Arc::make_mut(&mut self.block_entities).get_mut(&position)
+    }
+
+    /// Set the [`BlockEntity`] at the given position within the chunk.
+    ///
+    /// Returns the previous [`BlockEntity`] at the position, if any.
+    ///
+    /// Returns `None` if the position is out of bounds.
+    pub fn set_block_entity<P: Into<BlockPos>>(
+        &mut self,
+        position: P,
+        block_entity: BlockEntity,
+    ) -> Option<BlockEntity> {
+        let position = ChunkBlockPos::try_from_blockpos(position.into(), self.height_offset())?;
+        self.set_block_entity_pos(position, block_entity)
+    }
+
+    /// Set the [`BlockEntity`] at the given position within the chunk.
+    ///
+    /// Returns the previous [`BlockEntity`] at the position, if any.
+    ///
+    /// Returns `None` if the position is out of bounds.
+    pub fn set_block_entity_pos<P: Into<ChunkBlockPos>>(
+        &mut self,
+        position: P,
+        block_entity: BlockEntity,
+    ) -> Option<BlockEntity> {
+        let position = position.into();
+        if usize::from(position.y()) >= self.height_total() {
+            return None;
+        }
+        Arc::make_mut(&mut self.block_entities).insert(position, block_entity)
+    }
+
+    /// Remove the [`BlockEntity`] at the given position within the chunk.
+    ///
+    /// Returns `None` if the position is out of bounds,
+    /// or if there was no [`BlockEntity`] at the position.
+    pub fn remove_block_entity<P: Into<BlockPos>>(&mut self, position: P) -> Option<BlockEntity> {
+        let position = ChunkBlockPos::try_from_blockpos(position.into(), self.height_offset())?;
+        self.remove_block_entity_pos(position)
+    }
+
+    /// Remove the [`BlockEntity`] at the given position within the chunk.
+    ///
+    /// Returns `None` if there was no [`BlockEntity`] at the position.
+    pub fn remove_block_entity_pos<P: Into<ChunkBlockPos>>(
+        &mut self,
+        position: P,
+    ) -> Option<BlockEntity> {
+        let position = position.into();
+        if !self.block_entities.contains_key(&position) {
+            return None;
+        }
+        Arc::make_mut(&mut self.block_entities).remove(&position)
+    }
+
+    /// Iterate over all [`BlockEntity`]s in this [`Chunk`].
+    #[inline]
+    pub fn iter_block_entities(&self) -> impl Iterator<Item = (ChunkBlockPos, &BlockEntity)> {
+        self.block_entities.iter().map(|(position, entity)| (*position, entity))
+    }
+
+    /// Get the height of a column within the chunk.
+    ///
+    /// This is the lowest `y` above the highest [`Block`] matching the
+    /// [`HeightmapKind`], or the bottom of the chunk if there is none.
+    ///
+    /// Returns `None` if the position is out of bounds,
+    /// or if the [`Heightmap`] has not been loaded or computed.
+    #[must_use]
+    pub fn height_at(&self, kind: HeightmapKind, x: u8, z: u8) -> Option<i32> {
+        let height = self.heightmaps.get(&kind)?.get(x, z)?;
+        Some(i32::from(height) + self.height_offset())
+    }
+
+    /// Get the [`Heightmap`] of the given [`HeightmapKind`].
+    ///
+    /// Returns `None` if the [`Heightmap`] has not been loaded or computed.
+    #[inline]
+    #[must_use]
+    pub fn get_heightmap(&self, kind: HeightmapKind) -> Option<&Heightmap> {
+        self.heightmaps.get(&kind)
+    }
+
+    /// Set the [`Heightmap`] of the given [`HeightmapKind`].
+    ///
+    /// Returns the previous [`Heightmap`], if any.
+    pub fn set_heightmap(
+        &mut self,
+        kind: HeightmapKind,
+        heightmap: Heightmap,
+    ) -> Option<Heightmap> {
+        Arc::make_mut(&mut self.heightmaps).insert(kind, heightmap)
+    }
+
+    /// Iterate over all loaded [`Heightmap`]s in this [`Chunk`].
+    #[inline]
+    pub fn iter_heightmaps(&self) -> impl Iterator<Item = (HeightmapKind, &Heightmap)> {
+        self.heightmaps.iter().map(|(kind, heightmap)| (*kind, heightmap))
+    }
+
+    /// Compute every [`HeightmapKind`] from the blocks in this [`Chunk`],
+    /// replacing any existing [`Heightmap`]s.
+    ///
+    /// This should be called after modifying the sections directly,
+    /// or for chunks that were not sent with heightmaps.
+    pub fn recompute_heightmaps(&mut self) {
+        const ALL: u8 = (1 << HeightmapKind::ALL.len()) - 1;
+
+        // Only resolve each block state once.
+        let mut cache = BTreeMap::<u32, u8>::new();
+        let mut matches = |id: u32| -> u8 {
+            *cache.entry(id).or_insert_with(|| {
+                let Some(block) = self.blocks.get_block_by_state(GlobalStateId::new(id)) else {
+                    return 0;
+                };
+                HeightmapKind::ALL
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, kind)| kind.matches(&block))
+                    .fold(0, |mask, (index, _)| mask | (1 << index))
+            })
+        };
+
+        let mut heightmaps = HeightmapKind::ALL.map(|_| Heightmap::empty());
+        let sections = self.naive.sections();
+
+        for z in 0..CHUNK_WIDTH {
+            for x in 0..CHUNK_LENGTH {
+                let mut found = 0u8;
+
+                'column: for (index, section) in sections.iter().enumerate().rev() {
+                    // Skip sections filled with a single non-matching block.
+                    if let SectionPalette::Single(id) = section.block_data().palette()
+                        && matches(*id) & !found == 0
+                    {
+                        continue;
+                    }
+
+                    for y in (0..SECTION_HEIGHT).rev() {
+                        let id = section.get_raw_block(SectionBlockPos::new_xyz(x, y, z));
+                        let new = matches(id) & !found;
+                        if new == 0 {
+                            continue;
+                        }
+
+                        #[expect(
+                            clippy::cast_possible_truncation,
+                            reason = "Height will never be that large"
+                        )]
+                        let height =
+                            (index * usize::from(SECTION_HEIGHT)) as u16 + u16::from(y) + 1;
+                        for (bit, heightmap) in heightmaps.iter_mut().enumerate() {
+                            if new & (1 << bit) != 0 {
+                                heightmap.set(x, z, height);
+                            }
+                        }
+
+                        found |= new;
+                        if found == ALL {
+                            break 'column;
+                        }
+                    }
+                }
+            }
+        }
+
+        self.heightmaps = Arc::new(HeightmapKind::ALL.into_iter().zip(heightmaps).collect());
+    }
+
+    /// Get the [`ChunkLight`] of this [`Chunk`].
+    #[inline]
+    #[must_use]
+    pub const fn light(&self) -> &ChunkLight { &self.light }
+
+    /// Get the [`ChunkLight`] of this [`Chunk`] mutably.
+    #[inline]
+    #[must_use]
+    pub const fn light_mut(&mut self) -> &mut ChunkLight { &mut self.light }
+
+    /// Set the [`ChunkLight`] of this [`Chunk`], returning the previous one.
+    #[inline]
+    pub fn set_light(&mut self, light: ChunkLight) -> ChunkLight {
+        core::mem::replace(&mut self.light, light)
+    }
+
+    /// Get the sky light level at the given position within the chunk.
+    ///
+    /// Returns `None` if the position is out of bounds,
+    /// or if the light level is unknown.
+    #[must_use]
+    pub fn get_sky_light<P: Into<BlockPos>>(&self, position: P) -> Option<u8> {
+        let position = ChunkBlockPos::try_from_blockpos(position.into(), self.height_offset())?;
+        self.light.get_sky(position)
+    }
+
+    /// Get the block light level at the given position within the chunk.
+    ///
+    /// Returns `None` if the position is out of bounds,
+    /// or if the light level is unknown.
+    #[must_use]
+    pub fn get_block_light<P: Into<BlockPos>>(&self, position: P) -> Option<u8> {
+        let position = ChunkBlockPos::try_from_blockpos(position.into(), self.height_offset())?;
+        self.light.get_block(position)
+    }
+
+    /// Write the blocks and biomes of this [`Chunk`] to the given buffer.
+    ///
+    /// See [`SectionData::write_to`](crate::section::SectionData::write_to)
+    /// for how each palette is written.
+    pub fn write_to(&self, output: &mut Vec<u8>) {
+        self.naive.write_to(self.global_block_bits(), self.global_biome_bits(), output);
+    }
+
+    /// Re-encode every [`Section`] in this [`Chunk`] using the smallest
+    /// possible palettes.
+    ///
+    /// See [`SectionData::optimize`](crate::section::SectionData::optimize)
+    /// for details.
+    pub fn optimize(&mut self) {
+        let (block_bits, biome_bits) = (self.global_block_bits(), self.global_biome_bits());
+        self.naive.optimize(block_bits, biome_bits);
+    }
+
+    /// The number of bits per entry of the global block palette.
+    fn global_block_bits(&self) -> usize {
+        let states: usize =
+            self.blocks.metadata().iter().map(|block| usize::from(block.state_count())).sum();
+        (usize::BITS - states.saturating_sub(1).leading_zeros()) as usize
+    }
+
+    /// The number of bits per entry of the global biome palette.
+    fn global_biome_bits(&self) -> usize {
+        let biomes = self.biomes.metadata().len();
+        (usize::BITS - biomes.saturating_sub(1).leading_zeros()) as usize
+    }
+
     /// Get the [`Biome`] at the given position within the chunk.
     ///
     /// Returns `None` if the position is out of bounds, or if the [`Biome`]
@@ -502,7 +826,10 @@ impl fmt::Debug for Chunk {
     }
 }
 
-impl Eq for Chunk {}
 impl PartialEq for Chunk {
-    fn eq(&self, other: &Self) -> bool { self.eq_biomes(other) && self.eq_blocks(other) }
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_biomes(other)
+            && self.eq_blocks(other)
+            && self.block_entities == other.block_entities
+    }
 }
@@ -0,0 +1,200 @@
+//! TODO
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "bevy")]
+use bevy_reflect::Reflect;
+use froglight_block::block::Block;
+use froglight_common::prelude::Identifier;
+
+use crate::{CHUNK_LENGTH, CHUNK_WIDTH};
+
+/// A type of [`Heightmap`].
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq, Hash))]
+pub enum HeightmapKind {
+    /// The highest block that is not air.
+    WorldSurface = 1,
+    /// The highest block that blocks motion.
+    OceanFloor = 3,
+    /// The highest block that blocks motion or contains a fluid.
+    MotionBlocking = 4,
+    /// The highest block that blocks motion or contains a fluid,
+    /// ignoring leaves.
+    MotionBlockingNoLeaves = 5,
+}
+
+impl HeightmapKind {
+    /// All [`HeightmapKind`]s sent to the client.
+    pub const ALL: [Self; 4] =
+        [Self::WorldSurface, Self::OceanFloor, Self::MotionBlocking, Self::MotionBlockingNoLeaves];
+    /// The block tag ignored by [`HeightmapKind::MotionBlockingNoLeaves`].
+    pub const LEAVES_TAG: Identifier<'static> = Identifier::new_static("minecraft:leaves");
+
+    /// Get the [`HeightmapKind`] with the given network id.
+    ///
+    /// Returns `None` if the id is unknown or is only used during world
+    /// generation.
+    #[must_use]
+    pub const fn from_id(id: u32) -> Option<Self> {
+        match id {
+            1 => Some(Self::WorldSurface),
+            3 => Some(Self::OceanFloor),
+            4 => Some(Self::MotionBlocking),
+            5 => Some(Self::MotionBlockingNoLeaves),
+            _ => None,
+        }
+    }
+
+    /// Get the network id of this [`HeightmapKind`].
+    #[inline]
+    #[must_use]
+    pub const fn id(self) -> u32 { self as u32 }
+
+    /// Returns `true` if the [`Block`] counts towards this [`HeightmapKind`].
+    ///
+    /// Leaves are detected using the block's attributes,
+    /// use [`HeightmapKind::matches_with`] to use block tags instead.
+    #[must_use]
+    pub fn matches(self, block: &Block) -> bool {
+        self.matches_with(block, |_| block.get_attribute_str("persistent").is_some())
+    }
+
+    /// Returns `true` if the [`Block`] counts towards this [`HeightmapKind`].
+    ///
+    /// `in_tag` is called to check if the block is in the
+    /// [`HeightmapKind::LEAVES_TAG`] tag.
+    #[must_use]
+    pub fn matches_with(self, block: &Block, in_tag: impl FnOnce(&Identifier<'_>) -> bool) -> bool {
+        match self {
+            Self::WorldSurface => !block.is_air(),
+            Self::OceanFloor => blocks_motion(block),
+            Self::MotionBlocking => blocks_motion(block) || has_fluid(block),
+            Self::MotionBlockingNoLeaves => {
+                (blocks_motion(block) || has_fluid(block)) && !in_tag(&Self::LEAVES_TAG)
+            }
+        }
+    }
+}
+
+/// Returns `true` if the [`Block`] blocks motion.
+///
+/// Blocks with collision block motion if the bounds of their shape are
+/// large enough on average, or are a full block tall.
+fn blocks_motion(block: &Block) -> bool {
+    let mut aabbs = block.shape_of().as_slice().iter();
+    let Some(first) = aabbs.next().filter(|_| block.has_collision()) else { return false };
+    let (min, max) = aabbs
+        .fold((first.min, first.max), |(min, max), aabb| (min.min(aabb.min), max.max(aabb.max)));
+
+    let size = max - min;
+    (size.x + size.y + size.z) / 3.0 >= 0.729_166_666_666_666_6 || size.y >= 1.0
+}
+
+/// Returns `true` if the [`Block`] contains a fluid.
+fn has_fluid(block: &Block) -> bool {
+    block.is_liquid() || block.get_attribute_str("waterlogged") == Some("true")
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The height of each column in a [`Chunk`](super::Chunk).
+///
+/// Heights are stored as the lowest empty `y` above the highest matching
+/// block, relative to the bottom of the chunk.
+/// Columns without any matching blocks have a height of `0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, Clone, PartialEq))]
+pub struct Heightmap([u16; Self::COLUMNS]);
+
+impl Heightmap {
+    /// The number of columns in a [`Heightmap`].
+    pub const COLUMNS: usize = CHUNK_LENGTH as usize * CHUNK_WIDTH as usize;
+
+    /// Create a new [`Heightmap`] where every column is empty.
+    #[must_use]
+    pub const fn empty() -> Self { Self([0; Self::COLUMNS]) }
+
+    /// Get the height of the column at the given position.
+    ///
+    /// Returns `None` if the position is out of bounds.
+    #[must_use]
+    pub fn get(&self, x: u8, z: u8) -> Option<u16> { Self::index(x, z).map(|index| self.0[index]) }
+
+    /// Set the height of the column at the given position,
+    /// returning the previous height.
+    ///
+    /// Returns `None` if the position is out of bounds.
+    pub fn set(&mut self, x: u8, z: u8, height: u16) -> Option<u16> {
+        Self::index(x, z).map(|index| core::mem::replace(&mut self.0[index], height))
+    }
+
+    /// Get the heights of all columns, indexed by `x + z * 16`.
+    #[inline]
+    #[must_use]
+    pub const fn heights(&self) -> &[u16; Self::COLUMNS] { &self.0 }
+
+    /// Unpack a [`Heightmap`] sent by the server.
+    ///
+    /// Returns `None` if the data does not match the height of the chunk.
+    #[must_use]
+    pub fn from_packed(data: &[u64], height_total: usize) -> Option<Self> {
+        let (bits, per_long) = Self::packing(height_total);
+        if data.len() != Self::COLUMNS.div_ceil(per_long) {
+            return None;
+        }
+
+        let mask = (1u64 << bits) - 1;
+        let mut heights = [0; Self::COLUMNS];
+        for (index, height) in heights.iter_mut().enumerate() {
+            let long = data[index / per_long];
+            let value = (long >> ((index % per_long) * bits)) & mask;
+            if value > height_total as u64 {
+                return None;
+            }
+
+            #[expect(clippy::cast_possible_truncation, reason = "Checked against the height")]
+            {
+                *height = value as u16;
+            }
+        }
+
+        Some(Self(heights))
+    }
+
+    /// Pack this [`Heightmap`] the way the server sends it.
+    #[must_use]
+    pub fn to_packed(&self, height_total: usize) -> Vec<u64> {
+        let (bits, per_long) = Self::packing(height_total);
+
+        let mut data = alloc::vec![0u64; Self::COLUMNS.div_ceil(per_long)];
+        for (index, height) in self.0.iter().enumerate() {
+            data[index / per_long] |= u64::from(*height) << ((index % per_long) * bits);
+        }
+        data
+    }
+
+    /// Get the number of bits per column and columns per long
+    /// for a chunk of the given height.
+    const fn packing(height_total: usize) -> (usize, usize) {
+        let bits = (usize::BITS - height_total.leading_zeros()) as usize;
+        let bits = if bits == 0 { 1 } else { bits };
+        (bits, 64 / bits)
+    }
+
+    /// Get the index of the column at the given position.
+    const fn index(x: u8, z: u8) -> Option<usize> {
+        if x < CHUNK_LENGTH && z < CHUNK_WIDTH {
+            Some(x as usize + z as usize * CHUNK_LENGTH as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Heightmap {
+    fn default() -> Self { Self::empty() }
+}
@@ -0,0 +1,130 @@
+//! TODO
+
+use alloc::{sync::Arc, vec::Vec};
+
+use crate::component::{ChunkBlockPos, SectionBlockPos};
+
+/// The light levels of every section in a [`Chunk`](super::Chunk).
+///
+/// Includes one extra section below and above the chunk.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChunkLight {
+    sky: Vec<SectionLight>,
+    block: Vec<SectionLight>,
+}
+
+impl ChunkLight {
+    /// Create a new [`ChunkLight`] for a chunk with the given number of
+    /// sections, where the light of every section is unknown.
+    #[must_use]
+    pub fn new(sections: usize) -> Self {
+        let sections = sections + 2;
+        Self {
+            sky: (0..sections).map(|_| SectionLight::Unknown).collect(),
+            block: (0..sections).map(|_| SectionLight::Unknown).collect(),
+        }
+    }
+
+    /// Create a new [`ChunkLight`] from sky and block light.
+    ///
+    /// Returns `None` if the number of sections is different.
+    #[must_use]
+    pub fn new_from(sky: Vec<SectionLight>, block: Vec<SectionLight>) -> Option<Self> {
+        (sky.len() == block.len()).then_some(Self { sky, block })
+    }
+
+    /// Get the sky light of every section, from bottom to top.
+    #[inline]
+    #[must_use]
+    pub fn sky(&self) -> &[SectionLight] { &self.sky }
+
+    /// Get the sky light of every section mutably, from bottom to top.
+    #[inline]
+    #[must_use]
+    pub fn sky_mut(&mut self) -> &mut [SectionLight] { &mut self.sky }
+
+    /// Get the block light of every section, from bottom to top.
+    #[inline]
+    #[must_use]
+    pub fn block(&self) -> &[SectionLight] { &self.block }
+
+    /// Get the block light of every section mutably, from bottom to top.
+    #[inline]
+    #[must_use]
+    pub fn block_mut(&mut self) -> &mut [SectionLight] { &mut self.block }
+
+    /// Get the sky light level at the given position within the chunk.
+    ///
+    /// Returns `None` if the position is out of bounds,
+    /// or if the light level is unknown.
+    #[must_use]
+    pub fn get_sky(&self, position: ChunkBlockPos) -> Option<u8> {
+        self.sky.get(position.as_section_index() + 1)?.get(position.as_section_blockpos())
+    }
+
+    /// Get the block light level at the given position within the chunk.
+    ///
+    /// Returns `None` if the position is out of bounds,
+    /// or if the light level is unknown.
+    #[must_use]
+    pub fn get_block(&self, position: ChunkBlockPos) -> Option<u8> {
+        self.block.get(position.as_section_index() + 1)?.get(position.as_section_blockpos())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// The light levels of a single section.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum SectionLight {
+    /// The light levels are unknown.
+    #[default]
+    Unknown,
+    /// Every light level is `0`.
+    Empty,
+    /// The light level of every block, packed into nibbles.
+    ///
+    /// Levels are reference-counted and only cloned when modified while shared.
+    Levels(Arc<[u8; SectionLight::BYTES]>),
+}
+
+impl SectionLight {
+    /// The number of bytes used to store the light levels of a section.
+    pub const BYTES: usize = 2048;
+
+    /// Get the light level at the given position within the section.
+    ///
+    /// Returns `None` if the light level is unknown.
+    #[must_use]
+    pub fn get(&self, position: SectionBlockPos) -> Option<u8> {
+        match self {
+            Self::Unknown => None,
+            Self::Empty => Some(0),
+            Self::Levels(levels) => {
+                let index = usize::from(position.index());
+                Some((levels[index / 2] >> ((index % 2) * 4)) & 0xF)
+            }
+        }
+    }
+
+    /// Set the light level at the given position within the section,
+    /// returning the previous level.
+    ///
+    /// Returns `None` if the previous light level was unknown.
+    pub fn set(&mut self, position: SectionBlockPos, level: u8) -> Option<u8> {
+        let previous = self.get(position);
+        if let Self::Unknown | Self::Empty = self {
+            *self = Self::Levels(Arc::new([0; Self::BYTES]));
+        }
+
+        if let Self::Levels(levels) = self {
+            let levels = Arc::make_mut(levels);
+            let index = usize::from(position.index());
+            let shift = (index % 2) * 4;
+            let byte = &mut levels[index / 2];
+            *byte = (*byte & !(0xF << shift)) | ((level & 0xF) << shift);
+        }
+
+        previous
+    }
+}
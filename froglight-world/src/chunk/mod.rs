@@ -1,10 +1,25 @@
 //! TODO
 
+#[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
+mod block_entity;
+#[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
+pub use block_entity::*;
+
 #[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
 mod chunk;
 #[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
 pub use chunk::Chunk;
 
+#[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
+mod heightmap;
+#[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
+pub use heightmap::{Heightmap, HeightmapKind};
+
+#[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
+mod light;
+#[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
+pub use light::{ChunkLight, SectionLight};
+
 #[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
 mod shared;
 #[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
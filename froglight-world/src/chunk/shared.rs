@@ -20,8 +20,14 @@ use crate::chunk::Chunk;
 ///
 /// There is no shared mutable access to the [`Chunk`]!
 ///
-/// To modify it you must clone the existing [`Chunk`], modify that, and
-/// replace the [`SharedChunk`] contents via [`SharedChunk::store`].
+/// To modify it use [`SharedChunk::make_mut`], or clone the existing
+/// [`Chunk`], modify that, and replace the [`SharedChunk`] contents via
+/// [`SharedChunk::store`].
+///
+/// Each [`Section`](crate::section::Section) of a [`Chunk`] is
+/// reference-counted, so cloning a [`Chunk`] does not copy any block or biome
+/// data. Only the sections that are modified are copied, and existing readers
+/// keep seeing the [`Chunk`] as it was before the modification.
 #[repr(transparent)]
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "bevy", derive(Component, Reflect))]
@@ -59,6 +65,9 @@ impl SharedChunk {
 
     /// Get a mutable reference to the inner [`Chunk`], cloning if necessary.
     ///
+    /// Cloning a [`Chunk`] shares its block and biome data,
+    /// which is only copied for the sections that are then modified.
+    ///
     /// See [`Arc::make_mut`] for more details.
     #[inline]
     #[must_use]
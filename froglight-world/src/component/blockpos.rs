@@ -94,7 +94,10 @@ impl BlockPos {
     /// Create a [`ChunkPos`] from this [`BlockPos`].
     #[must_use]
     pub const fn into_chunk_pos(self) -> ChunkPos {
-        ChunkPos::new_xz(self.x() / CHUNK_LENGTH as i32, self.z() / CHUNK_WIDTH as i32)
+        ChunkPos::new_xz(
+            self.x().div_euclid(CHUNK_LENGTH as i32),
+            self.z().div_euclid(CHUNK_WIDTH as i32),
+        )
     }
 
     /// A `const` version of [`PartialEq::eq`].
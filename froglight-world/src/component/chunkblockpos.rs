@@ -3,7 +3,7 @@
     reason = "Triggered by deriving `Facet` and `Deserialize`"
 )]
 
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 
 #[cfg(feature = "bevy")]
 use bevy_reflect::Reflect;
@@ -103,14 +103,23 @@ impl ChunkBlockPos {
             None
         } else {
             #[expect(clippy::cast_possible_truncation, reason = "Verified within bounds above")]
-            Some(Self(
-                U8Vec2::new(position.x(), position.z()),
-                position.y() as u16 + total_height as u16,
-            ))
+            Some(Self(U8Vec2::new(position.x(), position.z()), total_height as u16))
         }
     }
 }
 
+impl Ord for ChunkBlockPos {
+    /// Orders positions by their `y`, `z`, and then `x` coordinates,
+    /// which matches the order blocks are stored in a chunk.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y(), self.z(), self.x()).cmp(&(other.y(), other.z(), other.x()))
+    }
+}
+impl PartialOrd for ChunkBlockPos {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
 impl fmt::Display for ChunkBlockPos {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ChunkBlockPos")
@@ -12,6 +12,8 @@ pub mod bevy;
 pub mod chunk;
 pub mod component;
 pub mod naive;
+#[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
+pub mod schematic;
 pub mod section;
 
 /// The length of a chunk.
@@ -32,7 +34,9 @@ pub mod prelude {
     //! Re-exports of common types, traits, and macros.
 
     #[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
-    pub use crate::chunk::{Chunk, SharedChunk};
+    pub use crate::chunk::{BlockEntity, Chunk, HeightmapKind, SharedChunk};
+    #[cfg(all(feature = "froglight-biome", feature = "froglight-block"))]
+    pub use crate::schematic::{Litematic, PlacementPlan, Schematic};
     pub use crate::{
         component::{BlockPos, BlockPosIter, ChunkPos},
         naive::NaiveChunk,
@@ -18,6 +18,8 @@ mod block;
 pub(super) mod parse;
 pub use parse::ParseError;
 
+mod write;
+
 pub mod storage;
 use storage::ChunkStorage;
 
@@ -17,6 +17,18 @@ use crate::{
 pub enum ParseError {
     /// The input data was not long enough
     EndOfInput,
+    /// The chunk height range was invalid
+    InvalidHeight,
+    /// The number of bits per entry was too large
+    InvalidBits,
+    /// The section data referenced a value outside of its palette
+    InvalidPalette,
+    /// A block entity had an unknown type, position, or invalid data
+    InvalidBlockEntity,
+    /// A heightmap did not match the height of the chunk
+    InvalidHeightmap,
+    /// The light data did not match the height of the chunk
+    InvalidLight,
 }
 
 impl NaiveChunk {
@@ -71,10 +83,13 @@ impl NaiveChunk {
         height_max: u32,
         height_min: i32,
     ) -> Result<(NaiveChunk, &[u8]), ParseError> {
-        let Some(total_height) = height_max.checked_sub_signed(height_min) else { todo!() };
+        let Some(total_height) = height_max.checked_sub_signed(height_min) else {
+            return Err(ParseError::InvalidHeight);
+        };
         let section_count = total_height / u32::from(SECTION_HEIGHT);
 
-        let mut sections = Vec::with_capacity(section_count as usize);
+        // Don't trust the height to allocate, every section takes at least 8 bytes.
+        let mut sections = Vec::with_capacity((section_count as usize).min(input.len() / 8));
         for _ in 0..section_count {
             let (section, remainder) = Section::parse_from_remainder(input)?;
             sections.push(section);
@@ -101,12 +116,15 @@ impl NaiveChunk {
         height_max: u32,
         height_min: i32,
     ) -> Result<(NaiveChunk, &[u8]), ParseError> {
-        let Some(total_height) = height_max.checked_sub_signed(height_min) else { todo!() };
+        let Some(total_height) = height_max.checked_sub_signed(height_min) else {
+            return Err(ParseError::InvalidHeight);
+        };
         let section_count = total_height / u32::from(SECTION_HEIGHT);
 
-        let mut sections = Vec::with_capacity(section_count as usize);
+        // Don't trust the height to allocate, every section takes at least 8 bytes.
+        let mut sections = Vec::with_capacity((section_count as usize).min(input.len() / 8));
         for _ in 0..section_count {
-            let (section, remainder) = Section::parse_from_remainder(input)?;
+            let (section, remainder) = Section::parse_from_legacy_remainder(input)?;
             sections.push(section);
             input = remainder;
         }
@@ -143,17 +161,22 @@ impl Section {
     ///
     /// Returns an error of the input is not a valid section.
     pub fn parse_from_remainder(input: &[u8]) -> Result<(Section, &[u8]), ParseError> {
-        let Some((block_count, input)) = input.split_first_chunk() else { todo!() };
+        let Some((block_count, input)) = input.split_first_chunk() else {
+            return Err(ParseError::EndOfInput);
+        };
         let block_count = u16::from_be_bytes(*block_count);
 
-        let Some((fluid_count, input)) = input.split_first_chunk() else { todo!() };
+        let Some((fluid_count, input)) = input.split_first_chunk() else {
+            return Err(ParseError::EndOfInput);
+        };
         let fluid_count = u16::from_be_bytes(*fluid_count);
 
         let (blocks, input) = SectionData::<BlockSection>::parse_from_remainder(input)?;
         let (biomes, input) = SectionData::<BiomeSection>::parse_from_remainder(input)?;
 
         // SAFETY: Input was parsed and is valid
-        unsafe { Ok((Section::new_unchecked(block_count, fluid_count, blocks, biomes), input)) }
+        let section = unsafe { Section::new_unchecked(block_count, fluid_count, blocks, biomes) };
+        Ok((section.into_shared(), input))
     }
 
     /// Attempt to parse a [`Section`] from the given data,
@@ -165,14 +188,17 @@ impl Section {
     ///
     /// Returns an error of the input is not a valid section.
     pub fn parse_from_legacy_remainder(input: &[u8]) -> Result<(Section, &[u8]), ParseError> {
-        let Some((block_count, input)) = input.split_first_chunk() else { todo!() };
+        let Some((block_count, input)) = input.split_first_chunk() else {
+            return Err(ParseError::EndOfInput);
+        };
         let block_count = u16::from_be_bytes(*block_count);
 
         let (blocks, input) = SectionData::<BlockSection>::parse_from_remainder(input)?;
         let (biomes, input) = SectionData::<BiomeSection>::parse_from_remainder(input)?;
 
         // SAFETY: Input was parsed and is valid
-        unsafe { Ok((Section::new_unchecked(block_count, 0, blocks, biomes), input)) }
+        let section = unsafe { Section::new_unchecked(block_count, 0, blocks, biomes) };
+        Ok((section.into_shared(), input))
     }
 }
 
@@ -193,22 +219,29 @@ impl<T: SectionType> SectionData<T> {
     ///
     /// Returns an error of the input is not a valid section data.
     pub fn parse_from_remainder(input: &[u8]) -> Result<(SectionData<T>, &[u8]), ParseError> {
-        let Some((&bits, mut input)) = input.split_first() else { todo!() };
+        let Some((&bits, mut input)) = input.split_first() else {
+            return Err(ParseError::EndOfInput);
+        };
+        if u32::from(bits) > u32::BITS {
+            return Err(ParseError::InvalidBits);
+        }
 
         let palette = match T::palette_for(bits) {
             SectionPaletteType::Single => {
-                let Some((value, remaining)) = bytes_to_variable(input) else { todo!() };
+                let (value, remaining) = bytes_to_variable(input).ok_or(ParseError::EndOfInput)?;
                 input = remaining;
 
                 SectionPalette::Single(value)
             }
             SectionPaletteType::Vector => {
-                let Some((length, remaining)) = bytes_to_variable(input) else { todo!() };
+                let (length, remaining) = bytes_to_variable(input).ok_or(ParseError::EndOfInput)?;
                 input = remaining;
 
-                let mut values = SmallVec::with_capacity(length as usize);
+                // Don't trust the length to allocate, every value takes at least 1 byte.
+                let mut values = SmallVec::with_capacity((length as usize).min(input.len()));
                 for _ in 0..length {
-                    let Some((value, remaining)) = bytes_to_variable(input) else { todo!() };
+                    let (value, remaining) =
+                        bytes_to_variable(input).ok_or(ParseError::EndOfInput)?;
                     input = remaining;
                     values.push(value);
                 }
@@ -218,15 +251,31 @@ impl<T: SectionType> SectionData<T> {
             SectionPaletteType::Global => SectionPalette::Global,
         };
 
-        // Get the number of `u64`s.
-        let length =
-            if bits == 0 { 0 } else { u32::from(T::VOLUME).div_ceil(u64::BITS / u32::from(bits)) };
         // Split the bitvec from the remaining input data.
-        let Some((data, input)) = input.split_at_checked(length as usize * 8) else { todo!() };
-        let data = BitVec::from_bytes_general(data);
+        let length = SectionData::<T>::long_count(usize::from(bits));
+        let Some((data, input)) = input.split_at_checked(length * 8) else {
+            return Err(ParseError::EndOfInput);
+        };
+
+        // Copy each big-endian `u64` into the bitvec.
+        let mut bitvec = BitVec::from_elem_general(length * 64, false);
+        // SAFETY: The bitvec has exactly `length` blocks, and every bit is in bounds.
+        for (block, bytes) in unsafe { bitvec.storage_mut() }.iter_mut().zip(data.as_chunks().0) {
+            *block = u64::from_be_bytes(*bytes);
+        }
+        let data = bitvec;
+
+        // SAFETY: Input was parsed, the palette is checked below.
+        let section = unsafe { Self::new_unchecked(usize::from(bits), palette, data) };
+
+        // Make sure every value is within the bounds of the palette.
+        if matches!(section.palette(), SectionPalette::Vector(_))
+            && !(0..usize::from(T::VOLUME)).all(|index| section.get_index(index).is_some())
+        {
+            return Err(ParseError::InvalidPalette);
+        }
 
-        // SAFETY: Input was parsed and is valid.
-        unsafe { Ok((Self::new_unchecked(usize::from(bits), palette, data), input)) }
+        Ok((section, input))
     }
 }
 
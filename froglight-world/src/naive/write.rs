@@ -0,0 +1,187 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use bit_vec::BitVec;
+use smallvec::SmallVec;
+
+use crate::{
+    prelude::*,
+    section::{Section, SectionData, SectionPalette, SectionPaletteType, SectionType},
+};
+
+impl NaiveChunk {
+    /// Write this [`NaiveChunk`] to the given buffer.
+    ///
+    /// Inputs:
+    ///   - `block_bits`: The number of bits per entry of the global block
+    ///     palette.
+    ///   - `biome_bits`: The number of bits per entry of the global biome
+    ///     palette.
+    ///
+    /// See [`SectionData::write_to`] for how each palette is written.
+    pub fn write_to(&self, block_bits: usize, biome_bits: usize, output: &mut Vec<u8>) {
+        for section in self.sections() {
+            section.write_to(block_bits, biome_bits, output);
+        }
+    }
+
+    /// Re-encode every [`Section`] in this [`NaiveChunk`] using the smallest
+    /// possible palette.
+    ///
+    /// See [`SectionData::optimize`] for details.
+    pub fn optimize(&mut self, block_bits: usize, biome_bits: usize) {
+        for section in self.sections_mut() {
+            section.optimize(block_bits, biome_bits);
+        }
+    }
+}
+
+impl Section {
+    /// Write this [`Section`] to the given buffer.
+    ///
+    /// See [`SectionData::write_to`] for how each palette is written.
+    pub fn write_to(&self, block_bits: usize, biome_bits: usize, output: &mut Vec<u8>) {
+        output.extend_from_slice(&self.solid_count().to_be_bytes());
+        output.extend_from_slice(&self.fluid_count().to_be_bytes());
+        self.block_data().write_to(block_bits, output);
+        self.biome_data().write_to(biome_bits, output);
+    }
+
+    /// Re-encode this [`Section`] using the smallest possible palettes.
+    ///
+    /// See [`SectionData::optimize`] for details.
+    pub fn optimize(&mut self, block_bits: usize, biome_bits: usize) {
+        self.block_data_mut().optimize(block_bits);
+        self.biome_data_mut().optimize(biome_bits);
+    }
+}
+
+impl<T: SectionType> SectionData<T> {
+    /// Write this [`SectionData`] to the given buffer.
+    ///
+    /// Data that is already encoded the way the client expects is written
+    /// unchanged, so data received from a server is written back exactly.
+    /// Anything else is written using the smallest possible palette,
+    /// see [`SectionData::optimize`].
+    pub fn write_to(&self, global_bits: usize, output: &mut Vec<u8>) {
+        if self.is_canonical(global_bits) {
+            self.write_unchecked(output);
+        } else {
+            let mut optimized = self.clone();
+            optimized.optimize(global_bits);
+            optimized.write_unchecked(output);
+        }
+    }
+
+    /// Returns `true` if this [`SectionData`] is encoded the way the client
+    /// expects, given the number of bits used by the global palette.
+    #[must_use]
+    pub fn is_canonical(&self, global_bits: usize) -> bool {
+        match self.palette() {
+            SectionPalette::Single(_) => self.bits_per_entry() == 0,
+            SectionPalette::Vector(values) => {
+                let bits = self.bits_per_entry();
+                u8::try_from(bits).is_ok_and(|bits| {
+                    bits >= T::VECTOR_BITS_MIN
+                        && T::palette_for(bits) == SectionPaletteType::Vector
+                        && !values.is_empty()
+                        && values.len() <= 1 << bits
+                })
+            }
+            SectionPalette::Global => self.bits_per_entry() == global_bits,
+        }
+    }
+
+    /// Re-encode this [`SectionData`] using the smallest possible palette.
+    ///
+    /// - A [`SectionPalette::Single`] if every value is the same.
+    /// - A [`SectionPalette::Vector`] of every unique value, in order of
+    ///   appearance, if it can be indexed with few enough bits.
+    /// - A [`SectionPalette::Global`] using `global_bits` otherwise.
+    pub fn optimize(&mut self, global_bits: usize) {
+        let values: Vec<u32> = self.iter().collect();
+
+        // Collect every unique value, in order of appearance.
+        let mut indices = BTreeMap::<u32, u32>::new();
+        let mut palette = SmallVec::<[u32; 8]>::new();
+        for &value in &values {
+            indices.entry(value).or_insert_with(|| {
+                palette.push(value);
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    reason = "Sections are never that large"
+                )]
+                {
+                    palette.len() as u32 - 1
+                }
+            });
+        }
+
+        if let [value] = palette.as_slice() {
+            // SAFETY: A single value does not use any data.
+            *self = unsafe {
+                Self::new_unchecked(0, SectionPalette::Single(*value), BitVec::new_general())
+            };
+            return;
+        }
+
+        let vector_bits = (usize::BITS - (palette.len() - 1).leading_zeros()) as usize;
+        let vector_bits = vector_bits.max(usize::from(T::VECTOR_BITS_MIN));
+
+        #[expect(clippy::cast_possible_truncation, reason = "Checked by `palette_for`")]
+        let (bits, palette) = match T::palette_for(vector_bits as u8) {
+            SectionPaletteType::Vector => (vector_bits, SectionPalette::Vector(palette)),
+            _ => (global_bits, SectionPalette::Global),
+        };
+
+        let mut data = BitVec::from_elem_general(Self::long_count(bits) * 64, false);
+        for (index, value) in values.into_iter().enumerate() {
+            let raw = match palette {
+                SectionPalette::Vector(_) => indices[&value],
+                _ => value,
+            };
+            Self::write_bitvec_index(&mut data, bits, index, raw);
+        }
+
+        // SAFETY: Every value was written using the new palette.
+        *self = unsafe { Self::new_unchecked(bits, palette, data) };
+    }
+
+    /// Write this [`SectionData`] to the given buffer exactly as it is stored.
+    fn write_unchecked(&self, output: &mut Vec<u8>) {
+        #[expect(clippy::cast_possible_truncation, reason = "Bits per entry never exceed 32")]
+        output.push(self.bits_per_entry() as u8);
+
+        match self.palette() {
+            SectionPalette::Single(value) => variable_to_bytes(*value, output),
+            SectionPalette::Vector(values) => {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    reason = "Palettes are never that large"
+                )]
+                variable_to_bytes(values.len() as u32, output);
+                for value in values {
+                    variable_to_bytes(*value, output);
+                }
+            }
+            SectionPalette::Global => {}
+        }
+
+        for long in self.data().storage() {
+            output.extend_from_slice(&long.to_be_bytes());
+        }
+    }
+}
+
+/// Write a variable-length integer to a buffer.
+fn variable_to_bytes(mut value: u32, output: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0b0111_1111) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            output.push(byte);
+            return;
+        }
+        output.push(byte | 0b1000_0000);
+    }
+}
@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use froglight_block::block::Block;
+
+use super::Schematic;
+use crate::{
+    chunk::Chunk,
+    prelude::{BlockPos, ChunkPos},
+};
+
+/// A single step needed to place a [`Schematic`] into a world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementAction {
+    /// Break the [`Block`] currently at the position.
+    Break {
+        /// The position of the block.
+        position: BlockPos,
+        /// The block currently at the position.
+        current: Block,
+    },
+    /// Place a [`Block`] at the position.
+    Place {
+        /// The position of the block.
+        position: BlockPos,
+        /// The block to place.
+        block: Block,
+    },
+}
+
+impl PlacementAction {
+    /// Get the position of this [`PlacementAction`].
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> BlockPos {
+        match self {
+            PlacementAction::Break { position, .. } | PlacementAction::Place { position, .. } => {
+                *position
+            }
+        }
+    }
+}
+
+/// The differences between a [`Schematic`] and the blocks in a world,
+/// ordered so they can be applied one after another.
+///
+/// Blocks are broken from the top down, so falling blocks are not disturbed.
+/// Blocks are then placed from the bottom up, with solid blocks placed before
+/// blocks that need support and liquids placed last.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PlacementPlan {
+    actions: Vec<PlacementAction>,
+    skipped: Vec<BlockPos>,
+}
+
+impl PlacementPlan {
+    /// Get the [`PlacementAction`]s in the order they should be applied.
+    #[inline]
+    #[must_use]
+    pub const fn actions(&self) -> &Vec<PlacementAction> { &self.actions }
+
+    /// Get the positions that could not be compared,
+    /// because their [`Chunk`] was not loaded, they were outside of the
+    /// world's height, or their block does not exist in the chunk's
+    /// [`Version`](froglight_common::version::Version).
+    #[inline]
+    #[must_use]
+    pub const fn skipped(&self) -> &Vec<BlockPos> { &self.skipped }
+
+    /// Returns `true` if the world already matches the [`Schematic`].
+    #[inline]
+    #[must_use]
+    pub const fn is_complete(&self) -> bool { self.actions.is_empty() && self.skipped.is_empty() }
+}
+
+impl Schematic {
+    /// Compare this [`Schematic`] placed at `origin` against the blocks in a
+    /// world, returning the [`PlacementPlan`] needed to build it.
+    ///
+    /// `chunks` is used to get the [`Chunk`] at each [`ChunkPos`],
+    /// and positions without a block in the schematic are ignored.
+    pub fn diff<'a>(
+        &self,
+        origin: BlockPos,
+        mut chunks: impl FnMut(ChunkPos) -> Option<&'a Chunk>,
+    ) -> PlacementPlan {
+        let mut breaking = Vec::new();
+        let mut placing = Vec::new();
+        let mut skipped = Vec::new();
+
+        let origin = origin + BlockPos::new(self.offset);
+        for (position, block) in self.iter_blocks() {
+            let position = origin + position;
+            let Some(chunk) = chunks(position.into_chunk_pos()) else {
+                skipped.push(position);
+                continue;
+            };
+            let (Some(current), Some(block)) =
+                (chunk.get_block(position), block.using_version_storage(chunk.blocks()))
+            else {
+                skipped.push(position);
+                continue;
+            };
+
+            if current == block {
+                continue;
+            }
+            if !current.is_air() && !current.is_liquid() {
+                breaking.push((position, current));
+            }
+            if !block.is_air() {
+                placing.push((position, block));
+            }
+        }
+
+        // Break from the top down.
+        breaking.sort_by_key(|(position, _)| (Reverse(position.y()), position.z(), position.x()));
+
+        // Place solid blocks, then blocks that need support, then liquids.
+        placing.sort_by_key(|(position, block)| {
+            let stage = u8::from(!block.is_solid()) + u8::from(block.is_liquid());
+            (stage, position.y(), position.z(), position.x())
+        });
+
+        let actions = breaking
+            .into_iter()
+            .map(|(position, current)| PlacementAction::Break { position, current })
+            .chain(
+                placing
+                    .into_iter()
+                    .map(|(position, block)| PlacementAction::Place { position, block }),
+            )
+            .collect();
+        PlacementPlan { actions, skipped }
+    }
+}
@@ -0,0 +1,286 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use froglight_block::{block::Block, storage::BlockStorage};
+use froglight_nbt::prelude::{Nbt, NbtCompound, NbtList, NbtValue};
+use glam::IVec3;
+use hashbrown::HashMap;
+
+use super::{
+    Schematic, SchematicError, get, get_string, read_block, read_block_entity, read_size,
+    write_block,
+};
+
+/// A Litematica `.litematic` file containing one or more regions.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Litematic {
+    /// The name of the litematic.
+    pub name: String,
+    /// The author of the litematic.
+    pub author: String,
+    /// The description of the litematic.
+    pub description: String,
+    /// When the litematic was created, in milliseconds since the Unix epoch.
+    pub time_created: u64,
+    /// When the litematic was last modified,
+    /// in milliseconds since the Unix epoch.
+    pub time_modified: u64,
+    /// The named regions of the litematic.
+    ///
+    /// Each region's [`offset`](Schematic::offset) is the position of its
+    /// minimum corner relative to the litematic's origin.
+    pub regions: Vec<(String, Schematic)>,
+}
+
+impl Litematic {
+    /// The latest supported version of the Litematica format.
+    pub const VERSION: u32 = 7;
+
+    /// Read a [`Litematic`] from a `.litematic` file.
+    ///
+    /// Positions containing air are read as `minecraft:air`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the litematic is invalid,
+    /// or if any block does not exist in the [`BlockStorage`].
+    pub fn read(root: &NbtCompound, blocks: &BlockStorage) -> Result<Self, SchematicError> {
+        let version = *get(root, "Version", NbtValue::as_int)?;
+        if !(4..=Self::VERSION).contains(&version) {
+            return Err(SchematicError::UnsupportedVersion(version));
+        }
+        let data_version =
+            root.get("MinecraftDataVersion").and_then(NbtValue::as_int).copied().unwrap_or(0);
+
+        let mut litematic = Self::default();
+        if let Some(metadata) = root.get("Metadata").and_then(NbtValue::as_compound) {
+            litematic.name = get_string(metadata, "Name").unwrap_or_default();
+            litematic.author = get_string(metadata, "Author").unwrap_or_default();
+            litematic.description = get_string(metadata, "Description").unwrap_or_default();
+            litematic.time_created =
+                metadata.get("TimeCreated").and_then(NbtValue::as_long).copied().unwrap_or(0);
+            litematic.time_modified =
+                metadata.get("TimeModified").and_then(NbtValue::as_long).copied().unwrap_or(0);
+        }
+
+        for (name, region) in get(root, "Regions", NbtValue::as_compound)?.iter() {
+            let region = region.as_compound().ok_or(SchematicError::MissingField("Regions"))?;
+            let schematic = read_region(region, data_version, blocks)?;
+            litematic.regions.push((name.to_utf8().into_owned(), schematic));
+        }
+
+        Ok(litematic)
+    }
+
+    /// Write this [`Litematic`] as a `.litematic` file.
+    ///
+    /// Positions without a block are written as `minecraft:air`.
+    ///
+    /// The data version is taken from the first region.
+    #[must_use]
+    pub fn write(&self) -> Nbt {
+        let mut regions = NbtCompound::new();
+        let (mut min, mut max) = (IVec3::MAX, IVec3::MIN);
+        let (mut volume, mut total) = (0u32, 0u32);
+
+        for (name, schematic) in &self.regions {
+            min = min.min(schematic.offset);
+            max = max.max(schematic.offset + schematic.size.as_ivec3());
+            volume = volume.saturating_add(schematic.size.element_product());
+            total = total.saturating_add(
+                u32::try_from(schematic.iter_blocks().filter(|(_, b)| !b.is_air()).count())
+                    .unwrap_or(u32::MAX),
+            );
+            regions.insert(name.as_str(), write_region(schematic));
+        }
+        let enclosing = if self.regions.is_empty() { IVec3::ZERO } else { max - min };
+
+        let mut metadata = NbtCompound::new();
+        metadata.insert("Name", NbtValue::String(self.name.as_str().into()));
+        metadata.insert("Author", NbtValue::String(self.author.as_str().into()));
+        metadata.insert("Description", NbtValue::String(self.description.as_str().into()));
+        #[expect(clippy::cast_possible_truncation, reason = "Region counts are small")]
+        metadata.insert("RegionCount", self.regions.len() as u32);
+        metadata.insert("TotalVolume", volume);
+        metadata.insert("TotalBlocks", total);
+        metadata.insert("TimeCreated", self.time_created);
+        metadata.insert("TimeModified", self.time_modified);
+        metadata.insert("EnclosingSize", vector(enclosing));
+
+        let data_version = self.regions.first().map_or(0, |(_, schematic)| schematic.data_version);
+        let mut root = NbtCompound::new();
+        root.insert("MinecraftDataVersion", data_version);
+        root.insert("Version", Self::VERSION);
+        root.insert("SubVersion", 1u32);
+        root.insert("Metadata", metadata);
+        root.insert("Regions", regions);
+        Nbt::new(Some("".into()), root)
+    }
+}
+
+/// Read a single region of a [`Litematic`].
+fn read_region(
+    region: &NbtCompound,
+    data_version: u32,
+    blocks: &BlockStorage,
+) -> Result<Schematic, SchematicError> {
+    let position = read_vector(get(region, "Position", NbtValue::as_compound)?)?;
+    let size = read_vector(get(region, "Size", NbtValue::as_compound)?)?;
+
+    // Negative sizes extend the region in the negative direction.
+    let min = position + IVec3::select(size.cmplt(IVec3::ZERO), size + IVec3::ONE, IVec3::ZERO);
+    let size =
+        read_size(size.x.saturating_abs(), size.y.saturating_abs(), size.z.saturating_abs())?;
+
+    // Read the palette and the packed block states.
+    let palette = match get(region, "BlockStatePalette", NbtValue::as_list)? {
+        NbtList::Compound(palette) if !palette.is_empty() => palette
+            .iter()
+            .map(|compound| read_block(blocks, compound))
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(SchematicError::MissingField("BlockStatePalette")),
+    };
+
+    let states = get(region, "BlockStates", NbtValue::as_long_array)?;
+    let bits = bits_for(palette.len());
+    if states.len() != (size.element_product() as usize * bits).div_ceil(64) {
+        return Err(SchematicError::InvalidBlockData);
+    }
+
+    let mut schematic = Schematic::new(size, data_version);
+    schematic.set_offset(min);
+    for (index, block) in schematic.blocks.iter_mut().enumerate() {
+        let id = read_packed(states, bits, index);
+        *block = Some(*palette.get(id).ok_or(SchematicError::InvalidBlockData)?);
+    }
+
+    // Read the block entities, which store their position as `x`, `y`, and `z`.
+    if let Some(NbtValue::List(NbtList::Compound(list))) = region.get("TileEntities") {
+        for compound in list {
+            let position = read_vector(compound)?;
+            let block_entity = read_block_entity(compound.clone(), "id", &["x", "y", "z"])?;
+            schematic
+                .set_block_entity(position.into(), block_entity)
+                .ok_or(SchematicError::InvalidBlockData)?;
+        }
+    }
+
+    if let Some(NbtValue::List(NbtList::Compound(entities))) = region.get("Entities") {
+        schematic.entities.clone_from(entities);
+    }
+
+    Ok(schematic)
+}
+
+/// Write a single region of a [`Litematic`].
+fn write_region(schematic: &Schematic) -> NbtCompound {
+    // Air is always the first entry in the palette.
+    let mut palette = Vec::<Option<Block>>::from([None]);
+    let mut indices = HashMap::<Option<Block>, usize>::from_iter([(None, 0)]);
+    let ids = schematic
+        .blocks
+        .iter()
+        .map(|block| {
+            let block = block.filter(|block| !block.is_air());
+            *indices.entry(block).or_insert_with(|| {
+                palette.push(block);
+                palette.len() - 1
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let bits = bits_for(palette.len());
+    let mut states = vec![0u64; (ids.len() * bits).div_ceil(64)];
+    for (index, id) in ids.into_iter().enumerate() {
+        write_packed(&mut states, bits, index, id);
+    }
+
+    let palette = palette
+        .into_iter()
+        .map(|block| {
+            block.map_or_else(
+                || {
+                    let mut air = NbtCompound::new();
+                    air.insert("Name", NbtValue::String("minecraft:air".into()));
+                    air
+                },
+                write_block,
+            )
+        })
+        .collect();
+
+    let block_entities = schematic
+        .iter_block_entities()
+        .map(|(position, block_entity)| {
+            let mut data = vector(position.as_ivec3());
+            data.insert("id", NbtValue::String(block_entity.kind().identifier().as_str().into()));
+            for (key, value) in block_entity.data().iter() {
+                data.insert(key, value.clone());
+            }
+            data
+        })
+        .collect();
+
+    let mut region = NbtCompound::new();
+    region.insert("Position", vector(schematic.offset));
+    region.insert("Size", vector(schematic.size.as_ivec3()));
+    region.insert("BlockStatePalette", NbtList::Compound(palette));
+    region.insert("BlockStates", states);
+    region.insert("TileEntities", NbtList::Compound(block_entities));
+    region.insert("Entities", NbtList::Compound(schematic.entities.clone()));
+    region.insert("PendingBlockTicks", NbtList::Empty);
+    region.insert("PendingFluidTicks", NbtList::Empty);
+    region
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Read a vector stored as `x`, `y`, and `z` integers.
+fn read_vector(compound: &NbtCompound) -> Result<IVec3, SchematicError> {
+    let [x, y, z] = ["x", "y", "z"].map(|key| compound.get(key).and_then(NbtValue::as_int));
+    match (x, y, z) {
+        (Some(x), Some(y), Some(z)) => {
+            Ok(IVec3::new(x.cast_signed(), y.cast_signed(), z.cast_signed()))
+        }
+        _ => Err(SchematicError::MissingField("x")),
+    }
+}
+
+/// Write a vector as `x`, `y`, and `z` integers.
+fn vector(vector: IVec3) -> NbtCompound {
+    let mut compound = NbtCompound::new();
+    compound.insert("x", vector.x.cast_unsigned());
+    compound.insert("y", vector.y.cast_unsigned());
+    compound.insert("z", vector.z.cast_unsigned());
+    compound
+}
+
+/// Get the number of bits used to store each palette index.
+fn bits_for(palette: usize) -> usize {
+    (usize::BITS - palette.saturating_sub(1).leading_zeros()).max(2) as usize
+}
+
+/// Read a value from a tightly packed array,
+/// where values may span two [`u64`]s.
+#[expect(clippy::cast_possible_truncation, reason = "Masked to the number of bits")]
+fn read_packed(states: &[u64], bits: usize, index: usize) -> usize {
+    let mask = (1u64 << bits) - 1;
+    let (start, offset) = ((index * bits) / 64, (index * bits) % 64);
+
+    let mut value = states[start] >> offset;
+    if offset + bits > 64 {
+        value |= states[start + 1] << (64 - offset);
+    }
+    (value & mask) as usize
+}
+
+/// Write a value into a tightly packed array,
+/// where values may span two [`u64`]s.
+fn write_packed(states: &mut [u64], bits: usize, index: usize, value: usize) {
+    let value = value as u64;
+    let (start, offset) = ((index * bits) / 64, (index * bits) % 64);
+
+    states[start] |= value << offset;
+    if offset + bits > 64 {
+        states[start + 1] |= value >> (64 - offset);
+    }
+}
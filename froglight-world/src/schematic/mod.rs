@@ -0,0 +1,344 @@
+//! TODO
+
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+use core::{error::Error, fmt};
+
+use froglight_block::{
+    block::Block, prelude::VanillaBlockEntity, storage::BlockStorage, version::BlockVersion,
+};
+use froglight_common::prelude::Identifier;
+use froglight_nbt::prelude::{NbtCompound, NbtValue};
+use glam::{IVec3, UVec3};
+
+use crate::{chunk::BlockEntity, prelude::BlockPos};
+
+mod diff;
+pub use diff::{PlacementAction, PlacementPlan};
+
+mod litematica;
+pub use litematica::Litematic;
+
+mod sponge;
+pub use sponge::SpongeVersion;
+
+mod structure;
+
+/// A region of blocks that can be saved, loaded, and placed into a world.
+///
+/// Blocks are stored relative to the schematic's minimum corner,
+/// and are placed at `origin + offset + position`.
+///
+/// Positions without a block are left unchanged when placing the schematic,
+/// like the vanilla `minecraft:structure_void` block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schematic {
+    size: UVec3,
+    offset: IVec3,
+    data_version: u32,
+    blocks: Vec<Option<Block>>,
+    block_entities: BTreeMap<usize, BlockEntity>,
+    entities: Vec<NbtCompound>,
+}
+
+impl Schematic {
+    /// The largest number of blocks a [`Schematic`] can be read with.
+    ///
+    /// Reading a larger schematic returns [`SchematicError::InvalidSize`].
+    pub const MAX_VOLUME: u32 = 1 << 26;
+
+    /// Create a new empty [`Schematic`] of the given size.
+    ///
+    /// Every position starts without a block.
+    #[must_use]
+    pub fn new(size: UVec3, data_version: u32) -> Self {
+        Self {
+            size,
+            offset: IVec3::ZERO,
+            data_version,
+            blocks: vec![None; size.element_product() as usize],
+            block_entities: BTreeMap::new(),
+            entities: Vec::new(),
+        }
+    }
+
+    /// Get the size of this [`Schematic`].
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> UVec3 { self.size }
+
+    /// Get the offset applied to this [`Schematic`] when it is placed.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> IVec3 { self.offset }
+
+    /// Set the offset applied to this [`Schematic`] when it is placed.
+    #[inline]
+    pub const fn set_offset(&mut self, offset: IVec3) { self.offset = offset; }
+
+    /// Get the data version this [`Schematic`] was saved with.
+    #[inline]
+    #[must_use]
+    pub const fn data_version(&self) -> u32 { self.data_version }
+
+    /// Returns `true` if the position is within this [`Schematic`].
+    #[must_use]
+    pub fn contains(&self, position: BlockPos) -> bool { self.index_of(position).is_some() }
+
+    /// Get the [`Block`] at the given position within the schematic.
+    ///
+    /// Returns `None` if the position is out of bounds,
+    /// or if there is no block at the position.
+    #[must_use]
+    pub fn get_block(&self, position: BlockPos) -> Option<Block> {
+        self.index_of(position).and_then(|index| self.blocks[index])
+    }
+
+    /// Set the [`Block`] at the given position within the schematic.
+    ///
+    /// If the type of [`Block`] changes, any [`BlockEntity`] at the position
+    /// is removed.
+    ///
+    /// Returns the previous [`Block`], or `None` if the position is out of
+    /// bounds or there was no block at the position.
+    pub fn set_block(&mut self, position: BlockPos, block: Block) -> Option<Block> {
+        let index = self.index_of(position)?;
+        let previous = self.blocks[index].replace(block);
+        if previous.is_none_or(|previous| previous.block_ty() != block.block_ty()) {
+            self.block_entities.remove(&index);
+        }
+        previous
+    }
+
+    /// Remove the [`Block`] at the given position within the schematic,
+    /// leaving the position unchanged when it is placed.
+    ///
+    /// Any [`BlockEntity`] at the position is also removed.
+    ///
+    /// Returns the previous [`Block`], or `None` if the position is out of
+    /// bounds or there was no block at the position.
+    pub fn clear_block(&mut self, position: BlockPos) -> Option<Block> {
+        let index = self.index_of(position)?;
+        self.block_entities.remove(&index);
+        self.blocks[index].take()
+    }
+
+    /// Iterate over all [`Block`]s in this [`Schematic`] and their positions.
+    ///
+    /// Positions are ordered by `y`, then `z`, then `x`.
+    pub fn iter_blocks(&self) -> impl Iterator<Item = (BlockPos, Block)> + '_ {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, block)| block.map(|block| (self.position_of(index), block)))
+    }
+
+    /// Get the [`BlockEntity`] at the given position within the schematic.
+    #[must_use]
+    pub fn get_block_entity(&self, position: BlockPos) -> Option<&BlockEntity> {
+        self.block_entities.get(&self.index_of(position)?)
+    }
+
+    /// Get a mutable reference to the [`BlockEntity`] at the given position
+    /// within the schematic.
+    #[must_use]
+    pub fn get_block_entity_mut(&mut self, position: BlockPos) -> Option<&mut BlockEntity> {
+        let index = self.index_of(position)?;
+        self.block_entities.get_mut(&index)
+    }
+
+    /// Set the [`BlockEntity`] at the given position within the schematic.
+    ///
+    /// Returns the previous [`BlockEntity`] at the position, if any.
+    ///
+    /// Returns `None` if the position is out of bounds.
+    pub fn set_block_entity(
+        &mut self,
+        position: BlockPos,
+        block_entity: BlockEntity,
+    ) -> Option<Option<BlockEntity>> {
+        let index = self.index_of(position)?;
+        Some(self.block_entities.insert(index, block_entity))
+    }
+
+    /// Remove the [`BlockEntity`] at the given position within the schematic.
+    pub fn remove_block_entity(&mut self, position: BlockPos) -> Option<BlockEntity> {
+        let index = self.index_of(position)?;
+        self.block_entities.remove(&index)
+    }
+
+    /// Iterate over all [`BlockEntity`]s in this [`Schematic`].
+    pub fn iter_block_entities(&self) -> impl Iterator<Item = (BlockPos, &BlockEntity)> {
+        self.block_entities.iter().map(|(index, entity)| (self.position_of(*index), entity))
+    }
+
+    /// Get the entities stored in this [`Schematic`].
+    ///
+    /// Entities are stored as they were read, in the schematic's format.
+    #[inline]
+    #[must_use]
+    pub const fn entities(&self) -> &Vec<NbtCompound> { &self.entities }
+
+    /// Get a mutable reference to the entities stored in this [`Schematic`].
+    #[inline]
+    #[must_use]
+    pub const fn entities_mut(&mut self) -> &mut Vec<NbtCompound> { &mut self.entities }
+
+    /// Attempt to migrate every [`Block`] to another [`BlockVersion`].
+    ///
+    /// Returns `None` if any block has no matching block in the version.
+    #[must_use]
+    pub fn using_version<V: BlockVersion>(&self) -> Option<Self> {
+        let blocks = self
+            .blocks
+            .iter()
+            .map(|block| block.map_or(Some(None), |block| block.using_version::<V>().map(Some)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self { blocks, data_version: V::DATA_VERSION, ..self.clone() })
+    }
+
+    /// Get the index of a position within the schematic.
+    fn index_of(&self, position: BlockPos) -> Option<usize> {
+        let position = position.as_ivec3();
+        if position.cmplt(IVec3::ZERO).any() || position.cmpge(self.size.as_ivec3()).any() {
+            return None;
+        }
+
+        let position = position.as_uvec3();
+        Some(((position.y * self.size.z + position.z) * self.size.x + position.x) as usize)
+    }
+
+    /// Get the position of an index within the schematic.
+    #[expect(clippy::cast_possible_truncation, reason = "Schematics are smaller than `u32::MAX`")]
+    fn position_of(&self, index: usize) -> BlockPos {
+        let index = index as u32;
+        let (x, rest) = (index % self.size.x, index / self.size.x);
+        let (z, y) = (rest % self.size.z, rest / self.size.z);
+        BlockPos::new(UVec3::new(x, y, z).as_ivec3())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// An error that occurs when reading a [`Schematic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchematicError {
+    /// A required field is missing or has the wrong type.
+    MissingField(&'static str),
+    /// The schematic's format version is not supported.
+    UnsupportedVersion(u32),
+    /// The schematic has an invalid size,
+    /// or is larger than [`Schematic::MAX_VOLUME`].
+    InvalidSize,
+    /// A block state could not be read.
+    InvalidBlock(String),
+    /// The block data does not match the schematic's size or palette.
+    InvalidBlockData,
+    /// A block entity has an unknown type or an invalid position.
+    InvalidBlockEntity(String),
+}
+
+impl fmt::Display for SchematicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchematicError::MissingField(field) => {
+                write!(f, "Missing or invalid field \"{field}\"")
+            }
+            SchematicError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported schematic version {version}")
+            }
+            SchematicError::InvalidSize => f.write_str("Invalid schematic size"),
+            SchematicError::InvalidBlock(block) => write!(f, "Invalid block state \"{block}\""),
+            SchematicError::InvalidBlockData => f.write_str("Invalid block data"),
+            SchematicError::InvalidBlockEntity(block_entity) => {
+                write!(f, "Invalid block entity \"{block_entity}\"")
+            }
+        }
+    }
+}
+
+impl Error for SchematicError {}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Get a value from a compound, or return a [`SchematicError::MissingField`].
+fn get<'a, T: ?Sized>(
+    compound: &'a NbtCompound,
+    key: &'static str,
+    f: impl FnOnce(&'a NbtValue) -> Option<&'a T>,
+) -> Result<&'a T, SchematicError> {
+    compound.get(key).and_then(f).ok_or(SchematicError::MissingField(key))
+}
+
+/// Get a string from a compound as UTF-8.
+fn get_string(compound: &NbtCompound, key: &'static str) -> Result<String, SchematicError> {
+    get(compound, key, NbtValue::as_string).map(|string| string.to_utf8().into_owned())
+}
+
+/// Read a size, rejecting empty, negative, or too large dimensions.
+fn read_size(x: i32, y: i32, z: i32) -> Result<UVec3, SchematicError> {
+    let size = IVec3::new(x, y, z);
+    if size.cmple(IVec3::ZERO).any()
+        || size.as_uvec3().as_u64vec3().element_product() > u64::from(Schematic::MAX_VOLUME)
+    {
+        return Err(SchematicError::InvalidSize);
+    }
+    Ok(size.as_uvec3())
+}
+
+/// Read a [`Block`] from a compound containing a `Name` and `Properties`.
+fn read_block(blocks: &BlockStorage, compound: &NbtCompound) -> Result<Block, SchematicError> {
+    let name = get_string(compound, "Name")?;
+    let identifier = Identifier::try_new(name.as_str())
+        .map_err(|_| SchematicError::InvalidBlock(name.clone()))?;
+    let mut block = blocks
+        .get_block_by_identifier(&identifier)
+        .ok_or_else(|| SchematicError::InvalidBlock(name.clone()))?;
+
+    if let Some(properties) = compound.get("Properties").and_then(NbtValue::as_compound) {
+        for (property, value) in properties.iter() {
+            let value =
+                value.as_string().ok_or_else(|| SchematicError::InvalidBlock(name.clone()))?;
+            block
+                .set_attribute_str(&property.to_utf8(), &value.to_utf8())
+                .ok_or_else(|| SchematicError::InvalidBlock(name.clone()))?;
+        }
+    }
+
+    Ok(block)
+}
+
+/// Write a [`Block`] as a compound containing a `Name` and `Properties`.
+fn write_block(block: Block) -> NbtCompound {
+    let mut compound = NbtCompound::new();
+    compound.insert("Name", NbtValue::String(block.identifier().as_str().into()));
+
+    let properties = block
+        .get_attributes()
+        .map(|(name, value)| (name.into(), NbtValue::String(value.into())))
+        .collect::<NbtCompound>();
+    if !properties.is_empty() {
+        compound.insert("Properties", NbtValue::Compound(properties));
+    }
+
+    compound
+}
+
+/// Read a [`BlockEntity`], removing its identifier and the given position keys.
+fn read_block_entity(
+    mut data: NbtCompound,
+    id_key: &'static str,
+    position_keys: &[&str],
+) -> Result<BlockEntity, SchematicError> {
+    let id = get_string(&data, id_key)?;
+    let kind = Identifier::try_new(id.as_str())
+        .ok()
+        .and_then(|identifier| VanillaBlockEntity::from_identifier(&identifier))
+        .ok_or(SchematicError::InvalidBlockEntity(id))?;
+
+    data.remove(id_key);
+    for key in position_keys {
+        data.remove(*key);
+    }
+    Ok(BlockEntity::new(kind, data))
+}
@@ -0,0 +1,247 @@
+use alloc::{collections::BTreeMap, string::ToString, vec, vec::Vec};
+
+use froglight_block::{block::Block, storage::BlockStorage};
+use froglight_nbt::prelude::{Nbt, NbtCompound, NbtList, NbtValue};
+use glam::IVec3;
+
+use super::{Schematic, SchematicError, get, read_block_entity, read_size};
+
+/// A version of the Sponge schematic format.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpongeVersion {
+    /// Sponge schematic version 2.
+    V2,
+    /// Sponge schematic version 3.
+    #[default]
+    V3,
+}
+
+impl Schematic {
+    /// Read a [`Schematic`] from a Sponge `.schem` file.
+    ///
+    /// Both version 2 and version 3 schematics are supported,
+    /// biomes are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schematic is invalid,
+    /// or if any block does not exist in the [`BlockStorage`].
+    pub fn read_sponge(root: &NbtCompound, blocks: &BlockStorage) -> Result<Self, SchematicError> {
+        // Version 3 wraps everything in a `Schematic` compound.
+        let root = root.get("Schematic").and_then(NbtValue::as_compound).unwrap_or(root);
+
+        let version = *get(root, "Version", NbtValue::as_int)?;
+        let (container, block_entities_key) = match version {
+            1 => (root, "TileEntities"),
+            2 => (root, "BlockEntities"),
+            3 => (get(root, "Blocks", NbtValue::as_compound)?, "BlockEntities"),
+            other => return Err(SchematicError::UnsupportedVersion(other)),
+        };
+
+        let dimension = |key| get(root, key, NbtValue::as_short).map(|value| i32::from(*value));
+        let size = read_size(dimension("Width")?, dimension("Height")?, dimension("Length")?)?;
+        let data_version = root.get("DataVersion").and_then(NbtValue::as_int).copied().unwrap_or(0);
+
+        // Every block takes between one and five bytes of data.
+        let data_key = if version == 3 { "Data" } else { "BlockData" };
+        let mut data = get(container, data_key, NbtValue::as_byte_array)?.as_slice();
+        let volume = size.element_product() as usize;
+        if data.len() < volume || data.len() > volume * 5 {
+            return Err(SchematicError::InvalidBlockData);
+        }
+
+        let mut schematic = Schematic::new(size, data_version);
+        if let Some(&[x, y, z]) =
+            root.get("Offset").and_then(NbtValue::as_int_array).map(Vec::as_slice)
+        {
+            schematic.set_offset(IVec3::new(x.cast_signed(), y.cast_signed(), z.cast_signed()));
+        }
+
+        // Read the palette, indexed by id.
+        let entries = get(container, "Palette", NbtValue::as_compound)?;
+        let mut palette = vec![None::<Block>; entries.len()];
+        for (state, id) in entries.iter() {
+            let state = state.to_utf8();
+            let id = id.as_int().ok_or(SchematicError::MissingField("Palette"))?;
+            let block = Block::from_str_using(blocks, &state)
+                .map_err(|_| SchematicError::InvalidBlock(state.to_string()))?;
+
+            // Ids must be smaller than the number of palette entries.
+            let entry = palette.get_mut(*id as usize).ok_or(SchematicError::InvalidBlockData)?;
+            *entry = Some(block);
+        }
+
+        // Read the block data, one varint per block.
+        for block in &mut schematic.blocks {
+            let id = read_varint(&mut data).ok_or(SchematicError::InvalidBlockData)?;
+            let id = palette.get(id as usize).copied().flatten();
+            *block = Some(id.ok_or(SchematicError::InvalidBlockData)?);
+        }
+        if !data.is_empty() {
+            return Err(SchematicError::InvalidBlockData);
+        }
+
+        // Read the block entities.
+        if let Some(NbtValue::List(NbtList::Compound(list))) = container.get(block_entities_key) {
+            for compound in list {
+                let position = get(compound, "Pos", NbtValue::as_int_array)?;
+                let &[x, y, z] = position.as_slice() else {
+                    return Err(SchematicError::MissingField("Pos"));
+                };
+
+                // Version 3 stores the data in a separate compound.
+                let block_entity = if version == 3 {
+                    let mut data = compound
+                        .get("Data")
+                        .and_then(NbtValue::as_compound)
+                        .cloned()
+                        .unwrap_or_default();
+                    data.insert("Id", get(compound, "Id", NbtValue::as_string)?.clone());
+                    read_block_entity(data, "Id", &["id"])?
+                } else {
+                    read_block_entity(compound.clone(), "Id", &["Pos", "id"])?
+                };
+
+                let position = IVec3::new(x.cast_signed(), y.cast_signed(), z.cast_signed());
+                schematic
+                    .set_block_entity(position.into(), block_entity)
+                    .ok_or(SchematicError::InvalidBlockData)?;
+            }
+        }
+
+        if let Some(NbtValue::List(NbtList::Compound(entities))) = root.get("Entities") {
+            schematic.entities.clone_from(entities);
+        }
+
+        Ok(schematic)
+    }
+
+    /// Write this [`Schematic`] as a Sponge `.schem` file.
+    ///
+    /// Positions without a block are written as `minecraft:air`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schematic is larger than `65535` blocks in any dimension.
+    #[must_use]
+    pub fn write_sponge(&self, version: SpongeVersion) -> Nbt {
+        // Build the palette and block data.
+        let mut palette = BTreeMap::<_, u32>::new();
+        let mut data = Vec::with_capacity(self.blocks.len());
+        for block in &self.blocks {
+            let state =
+                block.map_or_else(|| "minecraft:air".to_string(), |block| block.to_string());
+            let next = u32::try_from(palette.len()).expect("Palette is too large");
+            write_varint(&mut data, *palette.entry(state).or_insert(next));
+        }
+        let palette_max = palette.len();
+        let palette =
+            palette.into_iter().map(|(state, id)| (state.into(), id)).collect::<NbtCompound>();
+
+        // Write the block entities.
+        let block_entities = self
+            .iter_block_entities()
+            .map(|(position, block_entity)| {
+                let position = position.as_ivec3().to_array().map(i32::cast_unsigned).to_vec();
+                let id = block_entity.kind().identifier().as_str().into();
+
+                let mut compound = NbtCompound::new();
+                compound.insert("Pos", NbtValue::IntArray(position));
+                compound.insert("Id", NbtValue::String(id));
+                match version {
+                    SpongeVersion::V2 => {
+                        for (key, value) in block_entity.data().iter() {
+                            compound.insert(key, value.clone());
+                        }
+                    }
+                    SpongeVersion::V3 => {
+                        compound.insert("Data", block_entity.data().clone());
+                    }
+                }
+                compound
+            })
+            .collect::<Vec<_>>();
+
+        let mut root = NbtCompound::new();
+        let (width, height, length) = dimensions(self);
+        root.insert(
+            "Version",
+            match version {
+                SpongeVersion::V2 => 2u32,
+                SpongeVersion::V3 => 3u32,
+            },
+        );
+        root.insert("DataVersion", self.data_version);
+        root.insert("Width", width);
+        root.insert("Height", height);
+        root.insert("Length", length);
+        root.insert("Offset", self.offset.to_array().map(i32::cast_unsigned).to_vec());
+
+        match version {
+            SpongeVersion::V2 => {
+                root.insert(
+                    "PaletteMax",
+                    u32::try_from(palette_max).expect("Palette is too large"),
+                );
+                root.insert("Palette", palette);
+                root.insert("BlockData", data);
+                root.insert("BlockEntities", NbtList::Compound(block_entities));
+                if !self.entities.is_empty() {
+                    root.insert("Entities", NbtList::Compound(self.entities.clone()));
+                }
+                Nbt::new(Some("Schematic".into()), root)
+            }
+            SpongeVersion::V3 => {
+                let mut container = NbtCompound::new();
+                container.insert("Palette", palette);
+                container.insert("Data", data);
+                container.insert("BlockEntities", NbtList::Compound(block_entities));
+                root.insert("Blocks", container);
+                if !self.entities.is_empty() {
+                    root.insert("Entities", NbtList::Compound(self.entities.clone()));
+                }
+
+                let mut wrapper = NbtCompound::new();
+                wrapper.insert("Schematic", root);
+                Nbt::new(Some("".into()), wrapper)
+            }
+        }
+    }
+}
+
+/// Get the width, height, and length of a [`Schematic`] as [`u16`]s.
+fn dimensions(schematic: &Schematic) -> (u16, u16, u16) {
+    let [x, y, z] = schematic
+        .size
+        .to_array()
+        .map(|value| u16::try_from(value).expect("Schematic is too large"));
+    (x, y, z)
+}
+
+/// Read a variable-length [`u32`], advancing the slice.
+fn read_varint(data: &mut &[u8]) -> Option<u32> {
+    let mut value = 0u32;
+    for shift in (0..35).step_by(7) {
+        let (&byte, rest) = data.split_first()?;
+        *data = rest;
+
+        value |= u32::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Write a variable-length [`u32`].
+fn write_varint(data: &mut Vec<u8>, mut value: u32) {
+    #[expect(clippy::cast_possible_truncation, reason = "Masked to 7 bits")]
+    loop {
+        if value & !0x7F == 0 {
+            data.push(value as u8);
+            return;
+        }
+        data.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+}
@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+
+use froglight_block::{block::Block, storage::BlockStorage};
+use froglight_nbt::prelude::{Nbt, NbtCompound, NbtList, NbtValue};
+use glam::IVec3;
+use hashbrown::HashMap;
+
+use super::{
+    Schematic, SchematicError, get, read_block, read_block_entity, read_size, write_block,
+};
+
+impl Schematic {
+    /// Read a [`Schematic`] from a vanilla structure `.nbt` file.
+    ///
+    /// Positions without a block are treated as `minecraft:structure_void`.
+    /// Structures with multiple palettes use the first palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the structure is invalid,
+    /// or if any block does not exist in the [`BlockStorage`].
+    pub fn read_structure(
+        root: &NbtCompound,
+        blocks: &BlockStorage,
+    ) -> Result<Self, SchematicError> {
+        let &[x, y, z] = get(root, "size", int_list)?.as_slice() else {
+            return Err(SchematicError::MissingField("size"));
+        };
+        let size = read_size(x.cast_signed(), y.cast_signed(), z.cast_signed())?;
+        let data_version = root.get("DataVersion").and_then(NbtValue::as_int).copied().unwrap_or(0);
+
+        // Read the palette, or the first of multiple palettes.
+        let palette = match root.get("palette") {
+            Some(NbtValue::List(list)) => list,
+            _ => match root.get("palettes") {
+                Some(NbtValue::List(NbtList::List(palettes))) => {
+                    palettes.first().ok_or(SchematicError::MissingField("palettes"))?
+                }
+                _ => return Err(SchematicError::MissingField("palette")),
+            },
+        };
+        let palette = match palette {
+            NbtList::Empty => Vec::new(),
+            NbtList::Compound(palette) => palette
+                .iter()
+                .map(|compound| read_block(blocks, compound))
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Err(SchematicError::MissingField("palette")),
+        };
+
+        // Read the blocks and their block entities.
+        let list = match root.get("blocks") {
+            Some(NbtValue::List(NbtList::Compound(list))) => list.as_slice(),
+            Some(NbtValue::List(NbtList::Empty)) => &[],
+            _ => return Err(SchematicError::MissingField("blocks")),
+        };
+        // Every position can only contain one block.
+        if list.len() > size.element_product() as usize {
+            return Err(SchematicError::InvalidBlockData);
+        }
+
+        let mut schematic = Schematic::new(size, data_version);
+        for compound in list {
+            let state = *get(compound, "state", NbtValue::as_int)?;
+            let block = palette.get(state as usize).ok_or(SchematicError::InvalidBlockData)?;
+
+            let &[x, y, z] = get(compound, "pos", int_list)?.as_slice() else {
+                return Err(SchematicError::MissingField("pos"));
+            };
+            let position = IVec3::new(x.cast_signed(), y.cast_signed(), z.cast_signed()).into();
+            if !schematic.contains(position) {
+                return Err(SchematicError::InvalidBlockData);
+            }
+            schematic.set_block(position, *block);
+
+            if let Some(data) = compound.get("nbt").and_then(NbtValue::as_compound) {
+                let block_entity = read_block_entity(data.clone(), "id", &["x", "y", "z"])?;
+                schematic.set_block_entity(position, block_entity);
+            }
+        }
+
+        if let Some(NbtValue::List(NbtList::Compound(entities))) = root.get("entities") {
+            schematic.entities.clone_from(entities);
+        }
+
+        Ok(schematic)
+    }
+
+    /// Write this [`Schematic`] as a vanilla structure `.nbt` file.
+    ///
+    /// Positions without a block are left out of the structure.
+    #[must_use]
+    pub fn write_structure(&self) -> Nbt {
+        let mut palette = Vec::<Block>::new();
+        let mut indices = HashMap::<Block, usize>::new();
+        let mut list = Vec::new();
+        for (position, block) in self.iter_blocks() {
+            let state = *indices.entry(block).or_insert_with(|| {
+                palette.push(block);
+                palette.len() - 1
+            });
+
+            let mut compound = NbtCompound::new();
+            compound.insert("pos", position_list(position.as_ivec3()));
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "Palettes are smaller than `u32::MAX`"
+            )]
+            compound.insert("state", state as u32);
+            if let Some(block_entity) = self.get_block_entity(position) {
+                let mut data = block_entity.data().clone();
+                data.insert(
+                    "id",
+                    NbtValue::String(block_entity.kind().identifier().as_str().into()),
+                );
+                compound.insert("nbt", data);
+            }
+            list.push(compound);
+        }
+
+        let mut root = NbtCompound::new();
+        root.insert("size", position_list(self.size.as_ivec3()));
+        root.insert("palette", NbtList::Compound(palette.into_iter().map(write_block).collect()));
+        root.insert("blocks", NbtList::Compound(list));
+        root.insert("entities", NbtList::Compound(self.entities.clone()));
+        root.insert("DataVersion", self.data_version);
+        Nbt::new(Some("".into()), root)
+    }
+}
+
+/// Get a value as a list of integers.
+fn int_list(value: &NbtValue) -> Option<&Vec<u32>> { value.as_list().and_then(NbtList::as_int) }
+
+/// Write a position as a list of three integers.
+fn position_list(position: IVec3) -> NbtList {
+    NbtList::Int(position.to_array().map(i32::cast_unsigned).to_vec())
+}
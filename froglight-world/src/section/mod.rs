@@ -5,18 +5,25 @@ use core::marker::PhantomData;
 use bit_vec::BitVec;
 use smallvec::SmallVec;
 
+mod shared;
+use shared::SharedData;
+
 mod traits;
 pub use traits::*;
 
 use crate::{SECTION_HEIGHT, SECTION_WIDTH, component::SectionBlockPos};
 
 /// A piece of a chunk.
+///
+/// Block and biome data are reference-counted,
+/// so cloning a [`Section`] is cheap and data is only copied when it is
+/// modified while shared.
 #[derive(Default, Clone, PartialEq, Eq)]
 pub struct Section {
     solid_count: u16,
     fluid_count: u16,
-    blocks: SectionData<BlockSection>,
-    biomes: SectionData<BiomeSection>,
+    blocks: SharedData<SectionData<BlockSection>>,
+    biomes: SharedData<SectionData<BiomeSection>>,
 }
 
 impl Section {
@@ -27,8 +34,8 @@ impl Section {
         Self {
             solid_count: 0,
             fluid_count: 0,
-            blocks: SectionData::empty(),
-            biomes: SectionData::empty(),
+            blocks: SharedData::shared(SectionData::empty()),
+            biomes: SharedData::shared(SectionData::empty()),
         }
     }
 
@@ -37,6 +44,9 @@ impl Section {
     /// # Safety
     ///
     /// The caller must ensure that the provided data is valid.
+    ///
+    /// The data is owned by this [`Section`] and copied the first time it is
+    /// cloned, see [`Section::into_shared`] to avoid this.
     #[inline]
     #[must_use]
     pub const unsafe fn new_unchecked(
@@ -45,7 +55,22 @@ impl Section {
         blocks: SectionData<BlockSection>,
         biomes: SectionData<BiomeSection>,
     ) -> Self {
-        Self { solid_count, fluid_count, blocks, biomes }
+        Self {
+            solid_count,
+            fluid_count,
+            blocks: SharedData::owned(blocks),
+            biomes: SharedData::owned(biomes),
+        }
+    }
+
+    /// Make the block and biome data of this [`Section`] reference-counted,
+    /// so cloning it does not copy any data.
+    ///
+    /// Sections are only created with owned data by
+    /// [`Section::new_unchecked`].
+    #[must_use]
+    pub fn into_shared(self) -> Self {
+        Self { blocks: self.blocks.into_shared(), biomes: self.biomes.into_shared(), ..self }
     }
 
     /// Get the number of solid (non-air) blocks in this section.
@@ -61,33 +86,50 @@ impl Section {
     /// Get the [`SectionData`] for blocks.
     #[inline]
     #[must_use]
-    pub const fn block_data(&self) -> &SectionData<BlockSection> { &self.blocks }
+    pub const fn block_data(&self) -> &SectionData<BlockSection> { self.blocks.get() }
 
-    /// Get the [`SectionData`] for blocks mutably.
+    /// Get the [`SectionData`] for blocks mutably, cloning it if it is shared.
+    ///
+    /// See [`Arc::make_mut`](alloc::sync::Arc::make_mut) for more details.
     #[inline]
     #[must_use]
-    pub const fn block_data_mut(&mut self) -> &mut SectionData<BlockSection> { &mut self.blocks }
+    pub fn block_data_mut(&mut self) -> &mut SectionData<BlockSection> { self.blocks.get_mut() }
 
     /// Get the [`SectionData`] for biomes.
     #[inline]
     #[must_use]
-    pub const fn biome_data(&self) -> &SectionData<BiomeSection> { &self.biomes }
+    pub const fn biome_data(&self) -> &SectionData<BiomeSection> { self.biomes.get() }
 
-    /// Get the [`SectionData`] for biomes mutably.
+    /// Get the [`SectionData`] for biomes mutably, cloning it if it is shared.
+    ///
+    /// See [`Arc::make_mut`](alloc::sync::Arc::make_mut) for more details.
     #[inline]
     #[must_use]
-    pub const fn biome_data_mut(&mut self) -> &mut SectionData<BiomeSection> { &mut self.biomes }
+    pub fn biome_data_mut(&mut self) -> &mut SectionData<BiomeSection> { self.biomes.get_mut() }
+
+    /// Returns `true` if both [`Section`]s share the same block and biome
+    /// data, meaning neither has been modified since one was cloned from the
+    /// other.
+    #[inline]
+    #[must_use]
+    pub fn shares_data(&self, other: &Self) -> bool {
+        self.blocks.ptr_eq(&other.blocks) && self.biomes.ptr_eq(&other.biomes)
+    }
 
     /// Get the block id at the given position within the section.
     #[inline]
     #[must_use]
-    pub fn get_raw_block(&self, position: SectionBlockPos) -> u32 { self.blocks.get(position) }
+    pub fn get_raw_block(&self, position: SectionBlockPos) -> u32 {
+        self.block_data().get(position)
+    }
 
     /// Set the block id at the given position within the section,
     /// returning the previous id.
     ///
     /// The provided closures must return `true` if the block id corresponds
     /// with some form of air or fluid, respectively.
+    ///
+    /// Shared block data is only cloned if the block id changes.
     #[must_use]
     pub fn set_raw_block(
         &mut self,
@@ -96,19 +138,24 @@ impl Section {
         mut is_air: impl FnMut(u32) -> bool,
         mut is_fluid: impl FnMut(u32) -> bool,
     ) -> u32 {
-        let previous = self.blocks.set(position, block_id);
+        if self.block_data().get(position) == block_id {
+            return block_id;
+        }
+
+        let previous = self.block_data_mut().set(position, block_id);
+        // Counters are provided by the server and may be wrong, so never overflow.
         match (is_air(previous), is_air(block_id)) {
             // Non-air to air, decrement solid counter.
-            (false, true) => self.solid_count -= 1,
+            (false, true) => self.solid_count = self.solid_count.saturating_sub(1),
             // Air to non-air, increment solid counter.
-            (true, false) => self.solid_count += 1,
+            (true, false) => self.solid_count = self.solid_count.saturating_add(1),
             _ => {}
         }
         match (is_fluid(previous), is_fluid(block_id)) {
             // Non-fluid to fluid, increment fluid counter.
-            (false, true) => self.fluid_count += 1,
+            (false, true) => self.fluid_count = self.fluid_count.saturating_add(1),
             // Fluid to non-fluid, decrement fluid counter.
-            (true, false) => self.fluid_count -= 1,
+            (true, false) => self.fluid_count = self.fluid_count.saturating_sub(1),
             _ => {}
         }
         previous
@@ -116,34 +163,41 @@ impl Section {
 
     /// Create an iterator over all raw block ids in this section.
     #[inline]
-    pub fn iter_raw_blocks(&self) -> impl Iterator<Item = u32> + '_ { self.blocks.iter() }
+    pub fn iter_raw_blocks(&self) -> impl Iterator<Item = u32> + '_ { self.block_data().iter() }
 
     /// Returns `true` if the given block id is contained within this section.
     #[inline]
     #[must_use]
-    pub fn contains_raw_block(&self, id: u32) -> bool { self.blocks.contains(id) }
+    pub fn contains_raw_block(&self, id: u32) -> bool { self.block_data().contains(id) }
 
     /// Get the biome id at the given position within the section.
     #[inline]
     #[must_use]
-    pub fn get_raw_biome(&self, position: SectionBlockPos) -> u32 { self.biomes.get(position) }
+    pub fn get_raw_biome(&self, position: SectionBlockPos) -> u32 {
+        self.biome_data().get(position)
+    }
 
     /// Set the biome id at the given position within the section,
     /// returning the previous id.
-    #[inline]
+    ///
+    /// Shared biome data is only cloned if the biome id changes.
     #[must_use]
     pub fn set_raw_biome(&mut self, position: SectionBlockPos, id: u32) -> u32 {
-        self.biomes.set(position, id)
+        if self.biome_data().get(position) == id {
+            return id;
+        }
+
+        self.biome_data_mut().set(position, id)
     }
 
     /// Create an iterator over all raw biome ids in this section.
     #[inline]
-    pub fn iter_raw_biomes(&self) -> impl Iterator<Item = u32> + '_ { self.biomes.iter() }
+    pub fn iter_raw_biomes(&self) -> impl Iterator<Item = u32> + '_ { self.biome_data().iter() }
 
     /// Returns `true` if the given biome id is contained within this section.
     #[inline]
     #[must_use]
-    pub fn contains_raw_biome(&self, id: u32) -> bool { self.biomes.contains(id) }
+    pub fn contains_raw_biome(&self, id: u32) -> bool { self.biome_data().contains(id) }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -243,7 +297,7 @@ impl<T: SectionType> SectionData<T> {
     /// Returns `None` if the index is out of bounds.
     #[must_use]
     pub fn get_index(&self, index: usize) -> Option<u32> {
-        if index > usize::from(T::VOLUME) {
+        if index >= usize::from(T::VOLUME) {
             return None;
         }
 
@@ -270,7 +324,7 @@ impl<T: SectionType> SectionData<T> {
     /// Returns `None` if the index is out of bounds.
     #[allow(clippy::must_use_candidate, reason = "Not required")]
     pub fn set_index(&mut self, index: usize, id: u32) -> Option<u32> {
-        if index > usize::from(T::VOLUME) {
+        if index >= usize::from(T::VOLUME) {
             return None;
         }
 
@@ -297,6 +351,10 @@ impl<T: SectionType> SectionData<T> {
             // TODO: Convert `SectionPalette::Vector` to `SectionPalette::Global`.
             #[expect(clippy::cast_possible_truncation, reason = "Ignored")]
             SectionPalette::Vector(items) => {
+                // Read the previous value before the palette is modified.
+                let previous = Self::read_bitvec_index(&self.data, self.bits, index)
+                    .and_then(|raw| items.get(raw as usize).copied())?;
+
                 // Get the previous or assign a new index.
                 let id = items.iter().position(|v| *v == id).unwrap_or_else(|| {
                     items.push(id);
@@ -309,7 +367,6 @@ impl<T: SectionType> SectionData<T> {
                 }
 
                 // Write the raw value and return the previous.
-                let previous = self.read_raw_index(index)?;
                 self.write_raw_index(index, id as u32).then_some(previous)
             }
 
@@ -371,7 +428,7 @@ impl<T: SectionType> SectionData<T> {
     /// Returns `None` if the index is out of bounds.
     #[must_use]
     fn read_bitvec_index(bitvec: &BitVec<u64>, bits: usize, index: usize) -> Option<u32> {
-        let start = index * bits;
+        let start = Self::bit_offset(bits, index);
         let end = start + bits;
 
         if end > bitvec.len() {
@@ -401,8 +458,13 @@ impl<T: SectionType> SectionData<T> {
     ///
     /// Returns `false` if the index was out of bounds.
     #[allow(clippy::must_use_candidate, reason = "Not required")]
-    fn write_bitvec_index(bitvec: &mut BitVec<u64>, bits: usize, index: usize, value: u32) -> bool {
-        let start = index * bits;
+    pub(crate) fn write_bitvec_index(
+        bitvec: &mut BitVec<u64>,
+        bits: usize,
+        index: usize,
+        value: u32,
+    ) -> bool {
+        let start = Self::bit_offset(bits, index);
         let end = start + bits;
         if end > bitvec.len() {
             return false;
@@ -415,6 +477,29 @@ impl<T: SectionType> SectionData<T> {
         true
     }
 
+    /// Get the number of [`u64`]s needed to store every value
+    /// using the given number of bits per entry.
+    #[must_use]
+    pub(crate) const fn long_count(bits: usize) -> usize {
+        match (u64::BITS as usize).checked_div(bits) {
+            Some(per_long) => (T::VOLUME as usize).div_ceil(per_long),
+            None => 0,
+        }
+    }
+
+    /// Get the offset of the first bit of the value at the given index.
+    ///
+    /// Values are packed starting from the least significant bit of each
+    /// [`u64`], and never span across two [`u64`]s.
+    const fn bit_offset(bits: usize, index: usize) -> usize {
+        if bits == 0 {
+            return 0;
+        }
+
+        let per_long = u64::BITS as usize / bits;
+        (index / per_long) * u64::BITS as usize + (index % per_long) * bits
+    }
+
     /// Grow the underlying [`BitVec`] to the given number of bits per entry.
     ///
     /// # Panics
@@ -428,7 +513,7 @@ impl<T: SectionType> SectionData<T> {
             return;
         }
 
-        let mut output = BitVec::from_elem_general(usize::from(T::VOLUME) * bits, false);
+        let mut output = BitVec::from_elem_general(Self::long_count(bits) * 64, false);
 
         // Skip reading/writing if the current bitvec is empty.
         if self.bits == 0 {
@@ -0,0 +1,105 @@
+//! TODO
+
+use alloc::sync::Arc;
+use core::ptr::NonNull;
+
+/// Data that is either owned or reference-counted and copied on write.
+///
+/// Owned data allows creating [`Section`](super::Section)s in `const`
+/// contexts, and the cached pointer allows reading shared data in `const`
+/// contexts.
+pub(super) enum SharedData<T> {
+    /// Data owned by a single section.
+    Owned(T),
+    /// Data that may be shared with other sections.
+    Shared {
+        /// The reference-counted data.
+        arc: Arc<T>,
+        /// A pointer to the data inside of `arc`.
+        ptr: NonNull<T>,
+    },
+}
+
+// SAFETY: `ptr` always points into `arc`, so this is equivalent to `Arc<T>`.
+unsafe impl<T: Send + Sync> Send for SharedData<T> {}
+// SAFETY: `ptr` always points into `arc`, so this is equivalent to `Arc<T>`.
+unsafe impl<T: Send + Sync> Sync for SharedData<T> {}
+
+impl<T> SharedData<T> {
+    /// Create a new [`SharedData`] that owns its data.
+    #[inline]
+    #[must_use]
+    pub(super) const fn owned(data: T) -> Self { Self::Owned(data) }
+
+    /// Create a new [`SharedData`] that can be shared.
+    #[must_use]
+    pub(super) fn shared(data: T) -> Self {
+        let arc = Arc::new(data);
+        let ptr = NonNull::from(&*arc);
+        Self::Shared { arc, ptr }
+    }
+
+    /// Get a reference to the data.
+    #[inline]
+    #[must_use]
+    pub(super) const fn get(&self) -> &T {
+        match self {
+            Self::Owned(data) => data,
+            // SAFETY: `ptr` points into `arc`, which lives as long as `self`.
+            Self::Shared { ptr, .. } => unsafe { ptr.as_ref() },
+        }
+    }
+
+    /// Convert owned data into shared data, without copying it.
+    #[must_use]
+    pub(super) fn into_shared(self) -> Self {
+        match self {
+            Self::Owned(data) => Self::shared(data),
+            shared @ Self::Shared { .. } => shared,
+        }
+    }
+
+    /// Returns `true` if both share the same data.
+    #[must_use]
+    pub(super) fn ptr_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Shared { arc: a, .. }, Self::Shared { arc: b, .. }) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl<T: Clone> SharedData<T> {
+    /// Get a mutable reference to the data, cloning it if it is shared.
+    ///
+    /// See [`Arc::make_mut`] for more details.
+    #[must_use]
+    pub(super) fn get_mut(&mut self) -> &mut T {
+        match self {
+            Self::Owned(data) => data,
+            Self::Shared { arc, ptr } => {
+                *ptr = NonNull::from(Arc::make_mut(arc));
+                // SAFETY: `ptr` points into `arc`, which is now unique.
+                unsafe { ptr.as_mut() }
+            }
+        }
+    }
+}
+
+impl<T: Clone> Clone for SharedData<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Owned(data) => Self::shared(data.clone()),
+            Self::Shared { arc, ptr } => Self::Shared { arc: Arc::clone(arc), ptr: *ptr },
+        }
+    }
+}
+
+impl<T: Default> Default for SharedData<T> {
+    fn default() -> Self { Self::shared(T::default()) }
+}
+
+impl<T: PartialEq> PartialEq for SharedData<T> {
+    fn eq(&self, other: &Self) -> bool { self.ptr_eq(other) || self.get() == other.get() }
+}
+impl<T: Eq> Eq for SharedData<T> {}
@@ -12,6 +12,9 @@ pub trait SectionType: Debug + Default + Clone + Send + Sync + Sealed + 'static
         SECTION_VOLUME / (Self::QUANTIZATION * Self::QUANTIZATION * Self::QUANTIZATION) as u16;
     /// The quantization factor of this type of section.
     const QUANTIZATION: usize;
+    /// The minimum number of bits per entry used by a
+    /// [`SectionPaletteType::Vector`].
+    const VECTOR_BITS_MIN: u8;
 
     /// Get a [`SectionPaletteType`] for this number of bits.
     fn palette_for(bits: u8) -> SectionPaletteType;
@@ -42,6 +45,7 @@ pub struct BlockSection;
 impl Sealed for BlockSection {}
 impl SectionType for BlockSection {
     const QUANTIZATION: usize = 1;
+    const VECTOR_BITS_MIN: u8 = 4;
 
     fn palette_for(bits: u8) -> SectionPaletteType {
         match bits {
@@ -59,6 +63,7 @@ pub struct BiomeSection;
 impl Sealed for BiomeSection {}
 impl SectionType for BiomeSection {
     const QUANTIZATION: usize = 4;
+    const VECTOR_BITS_MIN: u8 = 1;
 
     fn palette_for(bits: u8) -> SectionPaletteType {
         match bits {
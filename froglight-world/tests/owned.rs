@@ -3,7 +3,7 @@
 use froglight_world::{
     component::{ChunkBlockPos, SectionBlockPos},
     prelude::{BlockPos, NaiveChunk},
-    section::Section,
+    section::{Section, SectionData},
 };
 
 #[test]
@@ -114,3 +114,55 @@ fn section() {
 fn is_air(id: u32) -> bool { id == 0 }
 
 fn is_fluid(_: u32) -> bool { false }
+
+#[test]
+fn shared() {
+    let mut chunk = NaiveChunk::new_empty_large();
+    chunk.set_raw_block(BlockPos::new_xyz(0, 0, 0), 1, is_air, is_fluid).unwrap();
+    let snapshot = chunk.clone();
+
+    // Cloning a chunk shares every section.
+    for (section, shared) in chunk.sections().iter().zip(snapshot.sections()) {
+        assert!(section.shares_data(shared));
+    }
+
+    // Setting a block to its current value does not copy its section.
+    chunk.set_raw_block(BlockPos::new_xyz(0, 0, 0), 1, is_air, is_fluid).unwrap();
+    assert!(chunk.sections()[4].shares_data(&snapshot.sections()[4]));
+
+    // Editing a block only copies its section.
+    let position = BlockPos::new_xyz(8, 8, 8);
+    chunk.set_raw_block(position, 2, is_air, is_fluid).unwrap();
+    for (index, (section, shared)) in chunk.sections().iter().zip(snapshot.sections()).enumerate() {
+        assert_eq!(section.shares_data(shared), index != 4);
+    }
+
+    // The snapshot is unchanged.
+    assert_eq!(chunk.get_raw_block(position), Some(2));
+    assert_eq!(snapshot.get_raw_block(position), Some(0));
+    assert_eq!(snapshot.get_raw_block(BlockPos::new_xyz(0, 0, 0)), Some(1));
+    assert_eq!(snapshot.sections()[4].solid_count(), 1);
+    assert_eq!(chunk.sections()[4].solid_count(), 2);
+}
+
+#[test]
+fn owned() {
+    // SAFETY: Empty data is always valid.
+    let mut section =
+        unsafe { Section::new_unchecked(0, 0, SectionData::empty(), SectionData::empty()) };
+
+    // Owned data is copied when cloned.
+    let copy = section.clone();
+    assert!(!section.shares_data(&copy));
+    assert!(section == copy);
+
+    assert_eq!(section.set_raw_block(SectionBlockPos::new_xyz(1, 2, 3), 1, is_air, is_fluid), 0);
+    assert_eq!(section.get_raw_block(SectionBlockPos::new_xyz(1, 2, 3)), 1);
+    assert_eq!(copy.get_raw_block(SectionBlockPos::new_xyz(1, 2, 3)), 0);
+
+    // Shared data is not.
+    let section = section.into_shared();
+    let shared = section.clone();
+    assert!(section.shares_data(&shared));
+    assert_eq!(shared.get_raw_block(SectionBlockPos::new_xyz(1, 2, 3)), 1);
+}
@@ -0,0 +1,222 @@
+//! TODO
+
+use froglight_block::prelude::*;
+use froglight_common::version::V26_1;
+use froglight_nbt::prelude::*;
+use froglight_world::{
+    prelude::{BlockEntity, BlockPos, Chunk, ChunkPos},
+    schematic::{Litematic, PlacementAction, Schematic, SchematicError, SpongeVersion},
+};
+use glam::{IVec3, UVec3};
+
+/// Parse a [`Block`] from a block state string.
+fn block(state: &str) -> Block { Block::from_str_using(V26_1::blocks(), state).unwrap() }
+
+/// Write and read back a structure, as if it were saved to a file.
+fn reread(nbt: &Nbt) -> Nbt {
+    let bytes = nbt.to_named_bytes();
+    let (nbt, length) = Nbt::read_named(&bytes).unwrap();
+    assert_eq!(length, bytes.len());
+    nbt
+}
+
+/// Create a schematic containing a chest and a few other blocks.
+fn blueprint() -> Schematic {
+    let mut schematic = Schematic::new(UVec3::new(3, 2, 2), 4000);
+    schematic.set_offset(IVec3::new(-1, 0, 2));
+
+    for (index, position) in
+        [[0, 0, 0], [1, 0, 0], [2, 0, 0], [0, 0, 1], [1, 0, 1], [2, 0, 1]].into_iter().enumerate()
+    {
+        let state = if index % 2 == 0 { "stone" } else { "oak_planks" };
+        schematic.set_block(BlockPos::from(position), block(state));
+    }
+    schematic.set_block(BlockPos::new_xyz(0, 1, 0), block("furnace[facing=south,lit=true]"));
+    schematic.set_block(BlockPos::new_xyz(1, 1, 0), block("air"));
+    schematic.set_block(BlockPos::new_xyz(2, 1, 1), block("chest[facing=east]"));
+
+    let mut data = NbtCompound::new();
+    data.insert("CustomName", NbtValue::String("Blueprint".into()));
+    let chest = BlockEntity::new(VanillaBlockEntity::Chest, data);
+    schematic.set_block_entity(BlockPos::new_xyz(2, 1, 1), chest).unwrap();
+
+    schematic
+}
+
+#[test]
+fn blocks() {
+    let mut schematic = blueprint();
+    assert_eq!(schematic.iter_blocks().count(), 9);
+    assert_eq!(schematic.get_block(BlockPos::new_xyz(0, 1, 1)), None);
+    assert_eq!(schematic.get_block(BlockPos::new_xyz(3, 0, 0)), None);
+    assert!(!schematic.contains(BlockPos::new_xyz(0, -1, 0)));
+
+    // Changing the type of block removes its block entity.
+    let chest = BlockPos::new_xyz(2, 1, 1);
+    assert!(schematic.get_block_entity(chest).is_some());
+    schematic.set_block(chest, block("chest[facing=west]"));
+    assert!(schematic.get_block_entity(chest).is_some());
+    schematic.set_block(chest, block("stone"));
+    assert!(schematic.get_block_entity(chest).is_none());
+
+    // Migrating to the same version keeps every block.
+    let schematic = blueprint();
+    assert_eq!(schematic.using_version::<V26_1>().unwrap().iter_blocks().count(), 9);
+}
+
+#[test]
+fn sponge() {
+    let mut schematic = blueprint();
+    // Sponge schematics cannot store positions without a block.
+    for position in [[2, 1, 0], [0, 1, 1], [1, 1, 1]] {
+        schematic.set_block(BlockPos::from(position), block("air"));
+    }
+
+    for version in [SpongeVersion::V2, SpongeVersion::V3] {
+        let nbt = reread(&schematic.write_sponge(version));
+        let read = Schematic::read_sponge(nbt.compound(), V26_1::blocks()).unwrap();
+        assert_eq!(read, schematic, "Sponge {version:?} should round-trip");
+    }
+
+    // Unknown blocks and versions are rejected.
+    let mut nbt = schematic.write_sponge(SpongeVersion::V2);
+    nbt.compound_mut().insert("Version", 9u32);
+    assert_eq!(
+        Schematic::read_sponge(nbt.compound(), V26_1::blocks()),
+        Err(SchematicError::UnsupportedVersion(9))
+    );
+
+    let mut nbt = schematic.write_sponge(SpongeVersion::V2);
+    let mut palette = NbtCompound::new();
+    palette.insert("minecraft:not_a_block", 0u32);
+    nbt.compound_mut().insert("Palette", palette);
+    assert_eq!(
+        Schematic::read_sponge(nbt.compound(), V26_1::blocks()),
+        Err(SchematicError::InvalidBlock("minecraft:not_a_block".into()))
+    );
+
+    // Palette ids and block data must match the palette and size.
+    let mut nbt = schematic.write_sponge(SpongeVersion::V2);
+    let mut palette = NbtCompound::new();
+    palette.insert("minecraft:stone", u32::MAX);
+    nbt.compound_mut().insert("Palette", palette);
+    assert_eq!(
+        Schematic::read_sponge(nbt.compound(), V26_1::blocks()),
+        Err(SchematicError::InvalidBlockData)
+    );
+
+    let mut nbt = schematic.write_sponge(SpongeVersion::V2);
+    nbt.compound_mut().insert("BlockData", vec![0u8; 4]);
+    assert_eq!(
+        Schematic::read_sponge(nbt.compound(), V26_1::blocks()),
+        Err(SchematicError::InvalidBlockData)
+    );
+
+    // Schematics larger than the maximum volume are rejected.
+    let mut nbt = schematic.write_sponge(SpongeVersion::V2);
+    for key in ["Width", "Height", "Length"] {
+        nbt.compound_mut().insert(key, u16::MAX);
+    }
+    assert_eq!(
+        Schematic::read_sponge(nbt.compound(), V26_1::blocks()),
+        Err(SchematicError::InvalidSize)
+    );
+}
+
+#[test]
+fn structure() {
+    let mut schematic = blueprint();
+    schematic.set_offset(IVec3::ZERO);
+
+    // Positions without a block are preserved.
+    let nbt = reread(&schematic.write_structure());
+    let read = Schematic::read_structure(nbt.compound(), V26_1::blocks()).unwrap();
+    assert_eq!(read, schematic);
+    assert_eq!(read.get_block(BlockPos::new_xyz(0, 1, 1)), None);
+
+    let chest = read.get_block_entity(BlockPos::new_xyz(2, 1, 1)).unwrap();
+    assert_eq!(chest.kind(), VanillaBlockEntity::Chest);
+    assert!(chest.data().get("id").is_none());
+}
+
+#[test]
+fn litematica() {
+    let mut schematic = blueprint();
+    // Litematica stores positions without a block as air.
+    for position in [[2, 1, 0], [0, 1, 1], [1, 1, 1]] {
+        schematic.set_block(BlockPos::from(position), block("air"));
+    }
+
+    let litematic = Litematic {
+        name: String::from("Blueprint"),
+        author: String::from("Frog"),
+        regions: vec![(String::from("Main"), schematic.clone())],
+        ..Litematic::default()
+    };
+    let nbt = reread(&litematic.write());
+    let read = Litematic::read(nbt.compound(), V26_1::blocks()).unwrap();
+    assert_eq!(read, litematic);
+
+    // Regions with a negative size extend from their position.
+    let mut nbt = litematic.write();
+    let regions = nbt.compound_mut().get_mut("Regions").unwrap().as_compound_mut().unwrap();
+    let region = regions.get_mut("Main").unwrap().as_compound_mut().unwrap();
+    let size = region.get_mut("Size").unwrap().as_compound_mut().unwrap();
+    size.insert("x", (-3i32).cast_unsigned());
+
+    let read = Litematic::read(nbt.compound(), V26_1::blocks()).unwrap();
+    assert_eq!(read.regions[0].1.offset(), IVec3::new(-3, 0, 2));
+    assert_eq!(read.regions[0].1.size(), UVec3::new(3, 2, 2));
+}
+
+#[test]
+fn diff() {
+    let mut chunk = Chunk::empty_large::<V26_1>();
+    let stone = block("stone");
+    let dirt = block("dirt");
+    let torch = block("torch");
+    let water = block("water");
+
+    // Place a dirt column that needs to be replaced.
+    chunk.set_block(BlockPos::new_xyz(0, 64, 0), stone);
+    chunk.set_block(BlockPos::new_xyz(0, 65, 0), dirt);
+    chunk.set_block(BlockPos::new_xyz(0, 66, 0), dirt);
+
+    let mut schematic = Schematic::new(UVec3::new(1, 4, 2), 0);
+    schematic.set_block(BlockPos::new_xyz(0, 0, 0), stone);
+    schematic.set_block(BlockPos::new_xyz(0, 1, 0), stone);
+    schematic.set_block(BlockPos::new_xyz(0, 2, 0), block("air"));
+    schematic.set_block(BlockPos::new_xyz(0, 3, 0), torch);
+    schematic.set_block(BlockPos::new_xyz(0, 0, 1), water);
+    schematic.set_block(BlockPos::new_xyz(0, 1, 1), stone);
+
+    let plan = schematic.diff(BlockPos::new_xyz(0, 64, 0), |position| {
+        (position == ChunkPos::new_xz(0, 0)).then_some(&chunk)
+    });
+    assert!(plan.skipped().is_empty());
+    assert_eq!(
+        plan.actions().as_slice(),
+        [
+            PlacementAction::Break { position: BlockPos::new_xyz(0, 66, 0), current: dirt },
+            PlacementAction::Break { position: BlockPos::new_xyz(0, 65, 0), current: dirt },
+            PlacementAction::Place { position: BlockPos::new_xyz(0, 65, 0), block: stone },
+            PlacementAction::Place { position: BlockPos::new_xyz(0, 65, 1), block: stone },
+            PlacementAction::Place { position: BlockPos::new_xyz(0, 67, 0), block: torch },
+            PlacementAction::Place { position: BlockPos::new_xyz(0, 64, 1), block: water },
+        ]
+    );
+
+    // Applying the plan completes the schematic.
+    for action in plan.actions() {
+        match *action {
+            PlacementAction::Break { position, .. } => chunk.set_block(position, block("air")),
+            PlacementAction::Place { position, block } => chunk.set_block(position, block),
+        };
+    }
+    let plan = schematic.diff(BlockPos::new_xyz(0, 64, 0), |_| Some(&chunk));
+    assert!(plan.is_complete());
+
+    // Unloaded chunks are skipped.
+    let plan = schematic.diff(BlockPos::new_xyz(0, 64, 0), |_| None);
+    assert_eq!(plan.skipped().len(), 6);
+}